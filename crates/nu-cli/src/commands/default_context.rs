@@ -20,6 +20,9 @@ pub fn add_cli_context(mut engine_state: EngineState) -> EngineState {
             KeybindingsDefault,
             KeybindingsList,
             KeybindingsListen,
+            NuEnv,
+            NuEnvTrust,
+            NuEnvUntrust,
         };
 
         working_set.render()
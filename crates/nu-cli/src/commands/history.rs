@@ -8,6 +8,7 @@ use reedline::{
     FileBackedHistory, History as ReedlineHistory, HistoryItem, SearchDirection, SearchQuery,
     SqliteBackedHistory,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct History;
@@ -99,7 +100,10 @@ impl Command for History {
                                 .into_iter()
                                 .enumerate()
                                 .map(move |(idx, entry)| Value::Record {
-                                    cols: vec!["command".to_string(), "index".to_string()],
+                                    cols: Arc::new(vec![
+                                        "command".to_string(),
+                                        "index".to_string(),
+                                    ]),
                                     vals: vec![
                                         Value::String {
                                             val: entry.command_line,
@@ -218,7 +222,7 @@ fn create_history_record(idx: usize, entry: HistoryItem, long: bool, head: Span)
     let index_value = Value::int(idx as i64, head);
     if long {
         Value::Record {
-            cols: vec![
+            cols: Arc::new(vec![
                 "item_id".into(),
                 "start_timestamp".into(),
                 "command".to_string(),
@@ -228,7 +232,7 @@ fn create_history_record(idx: usize, entry: HistoryItem, long: bool, head: Span)
                 "duration".into(),
                 "exit_status".into(),
                 "idx".to_string(),
-            ],
+            ]),
             vals: vec![
                 item_id_value,
                 start_timestamp_value,
@@ -244,13 +248,13 @@ fn create_history_record(idx: usize, entry: HistoryItem, long: bool, head: Span)
         }
     } else {
         Value::Record {
-            cols: vec![
+            cols: Arc::new(vec![
                 "start_timestamp".into(),
                 "command".to_string(),
                 "cwd".into(),
                 "duration".into(),
                 "exit_status".into(),
-            ],
+            ]),
             vals: vec![
                 start_timestamp_value,
                 command_value,
@@ -4,6 +4,7 @@ use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
 };
 use reedline::get_reedline_default_keybindings;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct KeybindingsDefault;
@@ -62,12 +63,12 @@ impl Command for KeybindingsDefault {
                 };
 
                 Value::Record {
-                    cols: vec![
+                    cols: Arc::new(vec![
                         "mode".to_string(),
                         "modifier".to_string(),
                         "code".to_string(),
                         "event".to_string(),
-                    ],
+                    ]),
                     vals: vec![mode, modifier, code, event],
                     span: call.head,
                 }
@@ -7,6 +7,7 @@ use reedline::{
     get_reedline_edit_commands, get_reedline_keybinding_modifiers, get_reedline_keycodes,
     get_reedline_prompt_edit_modes, get_reedline_reedline_events,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct KeybindingsList;
@@ -101,7 +102,7 @@ fn convert_to_record(edit: &str, entry_type: &str, span: &Span) -> Value {
     let name = Value::string(edit, *span);
 
     Value::Record {
-        cols: vec!["type".to_string(), "name".to_string()],
+        cols: Arc::new(vec!["type".to_string(), "name".to_string()]),
         vals: vec![entry_type, name],
         span: *span,
     }
@@ -6,6 +6,7 @@ use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
 };
 use std::io::{stdout, Write};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct KeybindingsListen;
@@ -106,12 +107,12 @@ fn print_events_helper(event: Event) -> Result<Value, ShellError> {
         match code {
             KeyCode::Char(c) => {
                 let record = Value::Record {
-                    cols: vec![
+                    cols: Arc::new(vec![
                         "char".into(),
                         "code".into(),
                         "modifier".into(),
                         "flags".into(),
-                    ],
+                    ]),
                     vals: vec![
                         Value::string(format!("{c}"), Span::unknown()),
                         Value::string(format!("{:#08x}", u32::from(c)), Span::unknown()),
@@ -124,7 +125,7 @@ fn print_events_helper(event: Event) -> Result<Value, ShellError> {
             }
             _ => {
                 let record = Value::Record {
-                    cols: vec!["code".into(), "modifier".into(), "flags".into()],
+                    cols: Arc::new(vec!["code".into(), "modifier".into(), "flags".into()]),
                     vals: vec![
                         Value::string(format!("{code:?}"), Span::unknown()),
                         Value::string(format!("{modifiers:?}"), Span::unknown()),
@@ -137,7 +138,7 @@ fn print_events_helper(event: Event) -> Result<Value, ShellError> {
         }
     } else {
         let record = Value::Record {
-            cols: vec!["event".into()],
+            cols: Arc::new(vec!["event".into()]),
             vals: vec![Value::string(format!("{event:?}"), Span::unknown())],
             span: Span::unknown(),
         };
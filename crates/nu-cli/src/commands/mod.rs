@@ -6,6 +6,9 @@ mod keybindings;
 mod keybindings_default;
 mod keybindings_list;
 mod keybindings_listen;
+mod nu_env;
+mod nu_env_trust;
+mod nu_env_untrust;
 
 pub use commandline::Commandline;
 pub use history::History;
@@ -14,5 +17,8 @@ pub use keybindings::Keybindings;
 pub use keybindings_default::KeybindingsDefault;
 pub use keybindings_list::KeybindingsList;
 pub use keybindings_listen::KeybindingsListen;
+pub use nu_env::NuEnv;
+pub use nu_env_trust::NuEnvTrust;
+pub use nu_env_untrust::NuEnvUntrust;
 
 pub use default_context::add_cli_context;
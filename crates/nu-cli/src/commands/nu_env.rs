@@ -0,0 +1,58 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct NuEnv;
+
+impl Command for NuEnv {
+    fn name(&self) -> &str {
+        "nu-env"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .category(Category::Env)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn usage(&self) -> &str {
+        "Commands for managing project-local `.nu-env.nu` environments."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"A directory can describe its environment in a `.nu-env.nu` file. When you `cd` into
+the directory the file is evaluated, and when you leave it again its environment changes are
+reverted. For safety, a `.nu-env.nu` is only evaluated after its contents have been trusted
+with `nu-env trust`.
+
+You must use one of the following subcommands. Using this command as-is will only produce this help message."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["direnv", "project", "autoload"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &NuEnv.signature(),
+                &NuEnv.examples(),
+                engine_state,
+                stack,
+                self.is_parser_keyword(),
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
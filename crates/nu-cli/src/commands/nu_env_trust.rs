@@ -0,0 +1,129 @@
+use crate::nu_env::{
+    content_hash, read_trust_entries, trust_file_path, write_trust_entries, ENV_FILE_NAME,
+};
+use nu_engine::env::current_dir_str;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct NuEnvTrust;
+
+impl Command for NuEnvTrust {
+    fn name(&self) -> &str {
+        "nu-env trust"
+    }
+
+    fn usage(&self) -> &str {
+        "Trust a directory's `.nu-env.nu` so it is evaluated when entering the directory."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .optional(
+                "directory",
+                SyntaxShape::Directory,
+                "The directory whose `.nu-env.nu` to trust (default: the current directory)",
+            )
+            .category(Category::Env)
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The current contents of the file are trusted; if the file changes afterwards, it has to be trusted again."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["direnv", "allow"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let file = nu_env_file_arg(engine_state, stack, call)?;
+
+        let contents = match std::fs::read(&file) {
+            Ok(contents) => contents,
+            Err(err) => {
+                return Err(ShellError::GenericError(
+                    format!("Could not read {}", file.display()),
+                    err.to_string(),
+                    Some(call.head),
+                    None,
+                    Vec::new(),
+                ));
+            }
+        };
+
+        let trust_path = trust_file(engine_state, stack, call)?;
+
+        let mut entries = read_trust_entries(&trust_path);
+        entries.insert(file.to_string_lossy().to_string(), content_hash(&contents));
+
+        if let Err(err) = write_trust_entries(&trust_path, &entries) {
+            return Err(ShellError::GenericError(
+                format!("Could not write {}", trust_path.display()),
+                err.to_string(),
+                Some(call.head),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Trust the `.nu-env.nu` of the current directory",
+            example: "nu-env trust",
+            result: None,
+        }]
+    }
+}
+
+/// Resolve the `.nu-env.nu` the optional directory argument refers to.
+pub(crate) fn nu_env_file_arg(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<std::path::PathBuf, ShellError> {
+    let cwd = current_dir_str(engine_state, stack)?;
+
+    let dir = if let Some(dir) = call.opt::<Spanned<String>>(engine_state, stack, 0)? {
+        match nu_path::canonicalize_with(&dir.item, &cwd) {
+            Ok(dir) => dir,
+            Err(_) => {
+                return Err(ShellError::DirectoryNotFound(dir.span, None));
+            }
+        }
+    } else {
+        std::path::PathBuf::from(cwd)
+    };
+
+    Ok(dir.join(ENV_FILE_NAME))
+}
+
+/// Resolve the trust file, erroring out if no location for it can be determined.
+pub(crate) fn trust_file(
+    engine_state: &EngineState,
+    stack: &Stack,
+    call: &Call,
+) -> Result<std::path::PathBuf, ShellError> {
+    trust_file_path(engine_state, stack).ok_or_else(|| {
+        ShellError::GenericError(
+            "Could not determine the trust file location".into(),
+            "config directory not found".into(),
+            Some(call.head),
+            Some("set $env.NU_ENV_TRUST_FILE to a file path".into()),
+            Vec::new(),
+        )
+    })
+}
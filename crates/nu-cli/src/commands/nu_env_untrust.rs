@@ -0,0 +1,79 @@
+use crate::commands::nu_env_trust::{nu_env_file_arg, trust_file};
+use crate::nu_env::{read_trust_entries, write_trust_entries};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type};
+
+#[derive(Clone)]
+pub struct NuEnvUntrust;
+
+impl Command for NuEnvUntrust {
+    fn name(&self) -> &str {
+        "nu-env untrust"
+    }
+
+    fn usage(&self) -> &str {
+        "Revoke trust in a directory's `.nu-env.nu` so it is no longer evaluated."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .optional(
+                "directory",
+                SyntaxShape::Directory,
+                "The directory whose `.nu-env.nu` to untrust (default: the current directory)",
+            )
+            .category(Category::Env)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["direnv", "deny"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let file = nu_env_file_arg(engine_state, stack, call)?;
+        let trust_path = trust_file(engine_state, stack, call)?;
+
+        let mut entries = read_trust_entries(&trust_path);
+
+        if entries
+            .remove(&file.to_string_lossy().to_string())
+            .is_none()
+        {
+            return Err(ShellError::GenericError(
+                format!("{} is not trusted", file.display()),
+                "nothing to untrust".into(),
+                Some(call.head),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        if let Err(err) = write_trust_entries(&trust_path, &entries) {
+            return Err(ShellError::GenericError(
+                format!("Could not write {}", trust_path.display()),
+                err.to_string(),
+                Some(call.head),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Untrust the `.nu-env.nu` of the current directory",
+            example: "nu-env untrust",
+            result: None,
+        }]
+    }
+}
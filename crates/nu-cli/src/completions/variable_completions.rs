@@ -250,9 +250,9 @@ fn nested_suggestions(
             span: _,
         } => {
             // Add all the columns as completion
-            for item in cols {
+            for item in cols.iter() {
                 output.push(Suggestion {
-                    value: item,
+                    value: item.clone(),
                     description: None,
                     extra: None,
                     span: current_span,
@@ -291,7 +291,7 @@ fn recursive_value(val: Value, sublevels: Vec<Vec<u8>>) -> Value {
                 vals,
                 span: _,
             } => {
-                for item in cols.into_iter().zip(vals.into_iter()) {
+                for item in cols.iter().zip(vals.into_iter()) {
                     // Check if index matches with sublevel
                     if item.0.as_bytes().to_vec() == next_sublevel {
                         // If matches try to fetch recursively the next
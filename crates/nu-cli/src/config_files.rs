@@ -114,6 +114,56 @@ pub fn eval_config_contents(
     }
 }
 
+/// Re-source env.nu and config.nu into the running session.
+///
+/// The files are evaluated against a copy of the session first; the copy only replaces the
+/// session when they all evaluate without errors, so a broken config leaves the running
+/// session untouched.
+pub fn reload_config(engine_state: &mut EngineState, stack: &mut Stack) -> bool {
+    let mut new_engine_state = engine_state.clone();
+    let mut new_stack = stack.clone();
+
+    for key in ["env-path", "config-path"] {
+        if let Some(path) = engine_state.get_config_path(key) {
+            let config_filename = path.to_string_lossy().to_string();
+
+            if let Ok(contents) = std::fs::read(path) {
+                if !eval_source(
+                    &mut new_engine_state,
+                    &mut new_stack,
+                    &contents,
+                    &config_filename,
+                    PipelineData::empty(),
+                    false,
+                ) {
+                    return false;
+                }
+            }
+        }
+    }
+
+    // Merge the environment in case env vars changed in the config
+    match nu_engine::env::current_dir(&new_engine_state, &new_stack) {
+        Ok(cwd) => {
+            if let Err(e) = new_engine_state.merge_env(&mut new_stack, cwd) {
+                let working_set = StateWorkingSet::new(&new_engine_state);
+                report_error(&working_set, &e);
+                return false;
+            }
+        }
+        Err(e) => {
+            let working_set = StateWorkingSet::new(&new_engine_state);
+            report_error(&working_set, &e);
+            return false;
+        }
+    }
+
+    *engine_state = new_engine_state;
+    *stack = new_stack;
+
+    true
+}
+
 pub(crate) fn get_history_path(storage_path: &str, mode: HistoryFileFormat) -> Option<PathBuf> {
     nu_path::config_dir().map(|mut history_path| {
         history_path.push(storage_path);
@@ -2,14 +2,14 @@ use crate::util::eval_source;
 use log::info;
 use log::trace;
 use miette::{IntoDiagnostic, Result};
-use nu_engine::{convert_env_values, current_dir};
+use nu_engine::{convert_env_values, current_dir, get_full_help};
 use nu_parser::parse;
 use nu_path::canonicalize_with;
 use nu_protocol::report_error;
 use nu_protocol::{
     ast::Call,
     engine::{EngineState, Stack, StateWorkingSet},
-    Config, PipelineData, ShellError, Span, Type, Value,
+    Config, PipelineData, ShellError, Signature, Span, Type, Value,
 };
 use nu_utils::stdout_write_all_and_flush;
 
@@ -98,9 +98,12 @@ pub fn evaluate_file(
     trace!("parsing file: {}", file_path_str);
     let _ = parse(&mut working_set, Some(file_path_str), &file, false);
 
-    if working_set.find_decl(b"main", &Type::Any).is_some() {
-        let args = format!("main {}", args.join(" "));
+    let has_main = working_set.find_decl(b"main", &Type::Any).is_some();
+    let has_main_subcommands = !working_set
+        .find_commands_by_predicate(|name| name.starts_with(b"main "))
+        .is_empty();
 
+    if has_main || has_main_subcommands {
         if !eval_source(
             engine_state,
             stack,
@@ -111,6 +114,24 @@ pub fn evaluate_file(
         ) {
             std::process::exit(1);
         }
+
+        // A script can consist of only `def "main <subcommand>"` definitions; there is no
+        // `main` to dispatch to then, so an aggregated help of the subcommands stands in
+        if !has_main && (args.is_empty() || args == ["--help"] || args == ["-h"]) {
+            let full_help = get_full_help(
+                &Signature::build("main").usage("Run one of the subcommands of this script"),
+                &[],
+                engine_state,
+                stack,
+                false,
+            );
+            let _ = stdout_write_all_and_flush(full_help).map_err(|err| eprintln!("{err}"));
+
+            return Ok(());
+        }
+
+        let args = format!("main {}", args.join(" "));
+
         if !eval_source(
             engine_state,
             stack,
@@ -4,10 +4,12 @@ mod config_files;
 mod eval_cmds;
 mod eval_file;
 mod menus;
+mod nu_env;
 mod nu_highlight;
 mod print;
 mod prompt;
 mod prompt_update;
+mod recorder;
 mod reedline_config;
 mod repl;
 mod syntax_highlight;
@@ -16,14 +18,16 @@ mod validation;
 
 pub use commands::add_cli_context;
 pub use completions::{FileCompletion, NuCompleter};
-pub use config_files::eval_config_contents;
+pub use config_files::{eval_config_contents, reload_config};
 pub use eval_cmds::evaluate_commands;
 pub use eval_file::evaluate_file;
 pub use menus::{DescriptionMenu, NuHelpCompleter};
 pub use nu_command::util::get_init_cwd;
+pub use nu_env::{maybe_switch_env, NuEnvState};
 pub use nu_highlight::NuHighlight;
 pub use print::Print;
 pub use prompt::NushellPrompt;
+pub use recorder::record_entry;
 pub use repl::evaluate_repl;
 pub use syntax_highlight::NuHighlighter;
 pub use util::{eval_source, gather_parent_env_vars};
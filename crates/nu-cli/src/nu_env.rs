@@ -0,0 +1,219 @@
+use crate::util::eval_source;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{PipelineData, Value};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// The file a project can place in its root to describe its environment.
+pub const ENV_FILE_NAME: &str = ".nu-env.nu";
+
+/// Environment variables the REPL maintains itself; they are never saved or reverted when
+/// switching project environments.
+const MANAGED_ENV_VARS: &[&str] = &[
+    "PWD",
+    "OLDPWD",
+    "CMD_DURATION_MS",
+    "LAST_EXIT_CODE",
+    "NUSHELL_SHELLS",
+    "NUSHELL_CURRENT_SHELL",
+    "NUSHELL_LAST_SHELL",
+];
+
+/// Tracks the project environment across REPL iterations: the last seen working directory and
+/// the currently loaded `.nu-env.nu`, if any, together with what is needed to revert it.
+#[derive(Default)]
+pub struct NuEnvState {
+    last_pwd: Option<PathBuf>,
+    active: Option<ActiveNuEnv>,
+}
+
+struct ActiveNuEnv {
+    /// The directory whose `.nu-env.nu` is loaded; leaving it (and its subdirectories)
+    /// reverts the environment
+    dir: PathBuf,
+    file: PathBuf,
+    saved_env: HashMap<String, Value>,
+    saved_overlays: Vec<String>,
+}
+
+/// The file that records which `.nu-env.nu` files the user has trusted:
+/// `$env.NU_ENV_TRUST_FILE` if set, otherwise `nu-env-trust.txt` next to the rest of the config.
+pub(crate) fn trust_file_path(engine_state: &EngineState, stack: &Stack) -> Option<PathBuf> {
+    if let Some(path) = stack.get_env_var(engine_state, "NU_ENV_TRUST_FILE") {
+        if let Ok(path) = path.as_string() {
+            return Some(PathBuf::from(path));
+        }
+    }
+
+    let mut path = nu_path::config_dir()?;
+    path.push("nushell");
+    path.push("nu-env-trust.txt");
+    Some(path)
+}
+
+pub(crate) fn content_hash(contents: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Each line of the trust file is `<content hash> <file path>`; unreadable lines are skipped.
+pub(crate) fn read_trust_entries(path: &Path) -> HashMap<String, u64> {
+    let mut entries = HashMap::new();
+
+    if let Ok(contents) = std::fs::read_to_string(path) {
+        for line in contents.lines() {
+            if let Some((hash, file)) = line.split_once(' ') {
+                if let Ok(hash) = u64::from_str_radix(hash, 16) {
+                    entries.insert(file.to_string(), hash);
+                }
+            }
+        }
+    }
+
+    entries
+}
+
+pub(crate) fn write_trust_entries(
+    path: &Path,
+    entries: &HashMap<String, u64>,
+) -> std::io::Result<()> {
+    let mut lines: Vec<String> = entries
+        .iter()
+        .map(|(file, hash)| format!("{hash:016x} {file}"))
+        .collect();
+    lines.sort();
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    std::fs::write(path, lines.join("\n") + "\n")
+}
+
+/// Load or unload a project's `.nu-env.nu` after the working directory changed.
+///
+/// Called once per REPL iteration. On leaving the directory of the loaded file (and its
+/// subdirectories), the environment variables and active overlays are restored to what they
+/// were before the load. On entering a directory that has a `.nu-env.nu` (possibly in a parent
+/// directory), the file is evaluated -- but only if the user has trusted its current contents
+/// with `nu-env trust`; otherwise a notice explains how to trust it.
+pub fn maybe_switch_env(engine_state: &mut EngineState, stack: &mut Stack, state: &mut NuEnvState) {
+    let pwd = if let Some(pwd) = stack.get_env_var(engine_state, "PWD") {
+        if let Ok(pwd) = pwd.as_string() {
+            PathBuf::from(pwd)
+        } else {
+            return;
+        }
+    } else {
+        return;
+    };
+
+    if state.last_pwd.as_ref() == Some(&pwd) {
+        return;
+    }
+    state.last_pwd = Some(pwd.clone());
+
+    if let Some(active) = &state.active {
+        if !pwd.starts_with(&active.dir) {
+            let active = state.active.take().expect("checked above");
+            revert_env(engine_state, stack, &active);
+            eprintln!("nu-env: unloaded {}", active.file.display());
+        }
+    }
+
+    if state.active.is_none() {
+        if let Some((dir, file)) = find_env_file(&pwd) {
+            load_env_file(engine_state, stack, state, dir, file);
+        }
+    }
+}
+
+/// Find the nearest `.nu-env.nu` in `pwd` or one of its parent directories.
+fn find_env_file(pwd: &Path) -> Option<(PathBuf, PathBuf)> {
+    pwd.ancestors().find_map(|dir| {
+        let file = dir.join(ENV_FILE_NAME);
+        file.is_file().then(|| (dir.to_path_buf(), file))
+    })
+}
+
+fn load_env_file(
+    engine_state: &mut EngineState,
+    stack: &mut Stack,
+    state: &mut NuEnvState,
+    dir: PathBuf,
+    file: PathBuf,
+) {
+    let contents = match std::fs::read(&file) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("nu-env: could not read {}: {err}", file.display());
+            return;
+        }
+    };
+
+    let trusted_hash = trust_file_path(engine_state, stack)
+        .map(|path| read_trust_entries(&path))
+        .and_then(|entries| entries.get(&file.to_string_lossy().to_string()).copied());
+
+    match trusted_hash {
+        Some(hash) if hash == content_hash(&contents) => {}
+        Some(_) => {
+            eprintln!(
+                "nu-env: {} has changed since it was trusted; run `nu-env trust` in that directory and re-enter it to load it",
+                file.display()
+            );
+            return;
+        }
+        None => {
+            eprintln!(
+                "nu-env: {} is not trusted; run `nu-env trust` in that directory and re-enter it to load it",
+                file.display()
+            );
+            return;
+        }
+    }
+
+    let saved_env = stack.get_env_vars(engine_state);
+    let saved_overlays = stack.active_overlays.clone();
+
+    eval_source(
+        engine_state,
+        stack,
+        &contents,
+        &file.to_string_lossy(),
+        PipelineData::empty(),
+        false,
+    );
+
+    eprintln!("nu-env: loaded {}", file.display());
+
+    state.active = Some(ActiveNuEnv {
+        dir,
+        file,
+        saved_env,
+        saved_overlays,
+    });
+}
+
+/// Restore the environment variables and active overlays recorded before the load. Definitions
+/// and modules the file added stay in the engine state; only their activation is reverted.
+fn revert_env(engine_state: &EngineState, stack: &mut Stack, active: &ActiveNuEnv) {
+    stack.active_overlays = active.saved_overlays.clone();
+
+    let current_env = stack.get_env_vars(engine_state);
+
+    for name in current_env.keys() {
+        if !MANAGED_ENV_VARS.contains(&name.as_str()) && !active.saved_env.contains_key(name) {
+            stack.remove_env_var(engine_state, name);
+        }
+    }
+
+    for (name, val) in &active.saved_env {
+        if !MANAGED_ENV_VARS.contains(&name.as_str()) && current_env.get(name) != Some(val) {
+            stack.add_env_var(name.clone(), val.clone());
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{report_error_new, ShellError};
+use std::io::Write;
+use std::time::Duration;
+
+/// Append one entry to the session recording requested with `nu --record` (which sets
+/// `$env.NU_RECORD_FILE`), as one JSON object per line.
+///
+/// Recording failures must not take down the session, so they are only reported.
+pub fn record_entry(
+    engine_state: &EngineState,
+    stack: &Stack,
+    command: &str,
+    cwd: &str,
+    duration: Duration,
+    output: Option<&str>,
+) {
+    let record_file = match stack.get_env_var(engine_state, "NU_RECORD_FILE") {
+        Some(value) => match value.as_string() {
+            Ok(path) => path,
+            Err(err) => {
+                report_error_new(engine_state, &err);
+                return;
+            }
+        },
+        None => return,
+    };
+
+    let exit_code = stack
+        .get_env_var(engine_state, "LAST_EXIT_CODE")
+        .and_then(|value| value.as_i64().ok())
+        .unwrap_or(0);
+
+    let mut entry = serde_json::json!({
+        "timestamp": chrono::Utc::now().to_rfc3339(),
+        "cwd": cwd,
+        "command": command,
+        "exit_code": exit_code,
+        "duration_ms": duration.as_millis() as u64,
+    });
+
+    // The plain REPL prints straight to the terminal, so the output is only available in
+    // contexts that collect it (e.g. the nu_repl testbin)
+    if let Some(output) = output {
+        entry["output"] = serde_json::Value::String(output.to_string());
+    }
+
+    let result = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&record_file)
+        .and_then(|mut file| writeln!(file, "{entry}"));
+
+    if let Err(err) = result {
+        report_error_new(
+            engine_state,
+            &ShellError::GenericError(
+                format!("Cannot record the session to {record_file}"),
+                err.to_string(),
+                None,
+                None,
+                Vec::new(),
+            ),
+        );
+    }
+}
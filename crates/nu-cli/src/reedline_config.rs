@@ -1000,7 +1000,7 @@ mod test {
 
         let event = Value::Record {
             vals,
-            cols,
+            cols: Arc::new(cols),
             span: Span::test_data(),
         };
         let config = Config::default();
@@ -1020,7 +1020,7 @@ mod test {
 
         let event = Value::Record {
             vals,
-            cols,
+            cols: Arc::new(cols),
             span: Span::test_data(),
         };
         let config = Config::default();
@@ -1046,7 +1046,7 @@ mod test {
 
         let event = Value::Record {
             vals,
-            cols,
+            cols: Arc::new(cols),
             span: Span::test_data(),
         };
         let config = Config::default();
@@ -1068,7 +1068,7 @@ mod test {
         ];
 
         let menu_event = Value::Record {
-            cols,
+            cols: Arc::new(cols),
             vals,
             span: Span::test_data(),
         };
@@ -1078,7 +1078,7 @@ mod test {
         let vals = vec![Value::test_string("Enter")];
 
         let enter_event = Value::Record {
-            cols,
+            cols: Arc::new(cols),
             vals,
             span: Span::test_data(),
         };
@@ -1095,7 +1095,7 @@ mod test {
         assert!(matches!(b, EventType::Until(_)));
 
         let event = Value::Record {
-            cols,
+            cols: Arc::new(cols),
             vals,
             span: Span::test_data(),
         };
@@ -1121,7 +1121,7 @@ mod test {
         ];
 
         let menu_event = Value::Record {
-            cols,
+            cols: Arc::new(cols),
             vals,
             span: Span::test_data(),
         };
@@ -1131,7 +1131,7 @@ mod test {
         let vals = vec![Value::test_string("Enter")];
 
         let enter_event = Value::Record {
-            cols,
+            cols: Arc::new(cols),
             vals,
             span: Span::test_data(),
         };
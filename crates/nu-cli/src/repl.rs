@@ -63,6 +63,8 @@ pub fn evaluate_repl(
 
     let mut nu_prompt = NushellPrompt::new();
 
+    let mut nu_env_state = crate::nu_env::NuEnvState::default();
+
     let start_time = std::time::Instant::now();
     // Translate environment variables from Strings to Values
     if let Some(e) = convert_env_values(engine_state, stack) {
@@ -415,6 +417,43 @@ pub fn evaluate_repl(
             use_color,
         );
 
+        start_time = std::time::Instant::now();
+        // Recompute the env vars whose NU_COMPUTED_ENV dependencies changed
+        if let Err(error) = hook::eval_computed_env(engine_state, stack) {
+            report_error_new(engine_state, &error)
+        }
+        perf(
+            "computed env",
+            start_time,
+            file!(),
+            line!(),
+            column!(),
+            use_color,
+        );
+
+        start_time = std::time::Instant::now();
+        // A `config reload` request re-sources env.nu and config.nu transactionally
+        if stack
+            .get_env_var(engine_state, "NU_RELOAD_CONFIG")
+            .is_some()
+        {
+            stack.remove_env_var(engine_state, "NU_RELOAD_CONFIG");
+            crate::config_files::reload_config(engine_state, stack);
+        }
+        perf(
+            "config reload",
+            start_time,
+            file!(),
+            line!(),
+            column!(),
+            use_color,
+        );
+
+        start_time = std::time::Instant::now();
+        // Load or unload a project's `.nu-env.nu` if the working directory changed
+        crate::nu_env::maybe_switch_env(engine_state, stack, &mut nu_env_state);
+        perf("nu-env", start_time, file!(), line!(), column!(), use_color);
+
         start_time = std::time::Instant::now();
         let config = &engine_state.get_config().clone();
         let prompt = prompt_update::update_prompt(config, engine_state, stack, &mut nu_prompt);
@@ -493,6 +532,9 @@ pub fn evaluate_repl(
                 // Check if this is a single call to a directory, if so auto-cd
                 let cwd = nu_engine::env::current_dir_str(engine_state, stack)?;
 
+                // The cwd the entry was run from, for the session recording
+                let entry_cwd = cwd.clone();
+
                 let mut orig = s.clone();
                 if orig.starts_with('`') {
                     orig = trim_quotes_str(&orig).to_string()
@@ -590,6 +632,17 @@ pub fn evaluate_repl(
                     },
                 );
 
+                if !s.trim().is_empty() {
+                    crate::recorder::record_entry(
+                        engine_state,
+                        stack,
+                        &s,
+                        &entry_cwd,
+                        cmd_duration,
+                        None,
+                    );
+                }
+
                 if history_supports_meta && !s.is_empty() && line_editor.has_last_command_context()
                 {
                     line_editor
@@ -292,6 +292,7 @@ fn find_matching_block_end_in_expr(
             Expr::Bool(_) => None,
             Expr::Int(_) => None,
             Expr::Float(_) => None,
+            Expr::Decimal(_) => None,
             Expr::Binary(_) => None,
             Expr::Range(..) => None,
             Expr::Var(_) => None,
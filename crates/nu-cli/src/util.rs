@@ -1,15 +1,17 @@
 use nu_command::hook::eval_hook;
 use nu_engine::{eval_block, eval_block_with_early_return};
 use nu_parser::{escape_quote_string, lex, parse, unescape_unquote_string, Token, TokenContents};
+use nu_protocol::ast::Call;
 use nu_protocol::engine::StateWorkingSet;
 use nu_protocol::{
     engine::{EngineState, Stack},
-    print_if_stream, PipelineData, ShellError, Span, Value,
+    print_if_stream, IntoPipelineData, PipelineData, ShellError, Span, Value,
 };
 use nu_protocol::{report_error, report_error_new};
 #[cfg(windows)]
 use nu_utils::enable_vt_processing;
 use nu_utils::utils::perf;
+use std::io::Write;
 use std::path::Path;
 
 // This will collect environment variables from std::env and adds them to a stack.
@@ -255,7 +257,23 @@ pub fn eval_source(
                 ..
             } = pipeline_data
             {
-                result = print_if_stream(stream, stderr_stream, false, exit_code);
+                result = print_if_stream(stream, stderr_stream, false, exit_code).map(
+                    |(exit_code, statuses)| {
+                        if !statuses.is_empty() {
+                            stack.add_env_var(
+                                "LAST_PIPELINE_STATUS".to_string(),
+                                Value::list(
+                                    statuses
+                                        .into_iter()
+                                        .map(|code| Value::int(code, Span::unknown()))
+                                        .collect(),
+                                    Span::unknown(),
+                                ),
+                            );
+                        }
+                        exit_code
+                    },
+                );
             } else if let Some(hook) = config.hooks.display_output.clone() {
                 match eval_hook(engine_state, stack, Some(pipeline_data), vec![], &hook) {
                     Err(err) => {
@@ -266,7 +284,7 @@ pub fn eval_source(
                     }
                 }
             } else {
-                result = pipeline_data.print(engine_state, stack, true, false);
+                result = print_or_page(engine_state, stack, pipeline_data);
             }
 
             match result {
@@ -310,6 +328,104 @@ pub fn eval_source(
     true
 }
 
+// When $env.config.pager.enable is set, output too tall for the terminal is
+// routed through a pager instead of scrolling past: either the external
+// command from $env.config.pager.command, or the internal `explore` pager
+// when no command is configured (which keeps a table's header row pinned
+// while scrolling).
+fn print_or_page(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    pipeline_data: PipelineData,
+) -> Result<i64, ShellError> {
+    let config = engine_state.get_config();
+    if !config.pager_enable
+        || !atty::is(atty::Stream::Stdout)
+        || matches!(pipeline_data, PipelineData::Empty)
+    {
+        return pipeline_data.print(engine_state, stack, true, false);
+    }
+
+    let term_height = match crossterm::terminal::size() {
+        Ok((_, height)) => height as usize,
+        Err(_) => return pipeline_data.print(engine_state, stack, true, false),
+    };
+
+    // Materialize the output so it can be both measured and handed over;
+    // a pager has to buffer the whole thing anyway.
+    let span = Span::unknown();
+    let value = pipeline_data.into_value(span);
+
+    let rendered = match engine_state.find_decl(b"table", &[]) {
+        Some(decl_id) => engine_state
+            .get_decl(decl_id)
+            .run(
+                engine_state,
+                stack,
+                &Call::new(span),
+                value.clone().into_pipeline_data(),
+            )?
+            .collect_string("", config)?,
+        None => value.clone().into_string("\n", config),
+    };
+
+    // The output fits on screen (leaving a line for the prompt), so there is
+    // nothing to page.
+    if rendered.lines().count() + 1 < term_height {
+        let stdout = std::io::stdout();
+        let mut stdout = stdout.lock();
+        let _ = stdout.write_all(rendered.as_bytes());
+        let _ = stdout.flush();
+        return Ok(0);
+    }
+
+    if config.pager_command.trim().is_empty() {
+        // The internal pager.
+        if let Some(decl_id) = engine_state.find_decl(b"explore", &[]) {
+            return engine_state
+                .get_decl(decl_id)
+                .run(
+                    engine_state,
+                    stack,
+                    &Call::new(span),
+                    value.into_pipeline_data(),
+                )?
+                .print(engine_state, stack, true, false);
+        }
+    } else {
+        let mut parts = config.pager_command.split_whitespace();
+        let command = parts.next().expect("checked non-empty above");
+        match std::process::Command::new(command)
+            .args(parts)
+            .stdin(std::process::Stdio::piped())
+            .spawn()
+        {
+            Ok(mut child) => {
+                if let Some(stdin) = child.stdin.take().as_mut() {
+                    let _ = stdin.write_all(rendered.as_bytes());
+                }
+                let _ = child.wait();
+                return Ok(0);
+            }
+            Err(err) => {
+                return Err(ShellError::GenericError(
+                    format!("Failed to run pager '{}'", config.pager_command),
+                    err.to_string(),
+                    None,
+                    Some("check $env.config.pager.command".into()),
+                    vec![],
+                ));
+            }
+        }
+    }
+
+    let stdout = std::io::stdout();
+    let mut stdout = stdout.lock();
+    let _ = stdout.write_all(rendered.as_bytes());
+    let _ = stdout.flush();
+    Ok(0)
+}
+
 fn set_last_exit_code(stack: &mut Stack, exit_code: i64) {
     stack.add_env_var(
         "LAST_EXIT_CODE".to_string(),
@@ -460,7 +460,7 @@ fn flag_completions() {
     // Test completions for the 'ls' flags
     let suggestions = completer.complete("ls -", 4);
 
-    assert_eq!(16, suggestions.len());
+    assert_eq!(18, suggestions.len());
 
     let expected: Vec<String> = vec![
         "--all".into(),
@@ -471,6 +471,7 @@ fn flag_completions() {
         "--long".into(),
         "--mime-type".into(),
         "--short-names".into(),
+        "--threads".into(),
         "-D".into(),
         "-a".into(),
         "-d".into(),
@@ -479,6 +480,7 @@ fn flag_completions() {
         "-l".into(),
         "-m".into(),
         "-s".into(),
+        "-t".into(),
     ];
 
     // Match results
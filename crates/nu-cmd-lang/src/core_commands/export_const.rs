@@ -0,0 +1,78 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ExportConst;
+
+impl Command for ExportConst {
+    fn name(&self) -> &str {
+        "export const"
+    }
+
+    fn usage(&self) -> &str {
+        "Create a parse-time constant and export it from a module."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("export const")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .allow_variants_without_examples(true)
+            .required("const_name", SyntaxShape::VarWithOptType, "constant name")
+            .required(
+                "initial_value",
+                SyntaxShape::Keyword(b"=".to_vec(), Box::new(SyntaxShape::MathExpression)),
+                "equals sign followed by constant value",
+            )
+            .category(Category::Core)
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"This command is a parser keyword. For details, check:
+  https://www.nushell.sh/book/thinking_in_nu.html"#
+    }
+
+    fn is_parser_keyword(&self) -> bool {
+        true
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["set", "let"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let var_id = call
+            .positional_nth(0)
+            .expect("checked through parser")
+            .as_var()
+            .expect("internal error: missing variable");
+
+        if let Some(constval) = engine_state.find_constant(var_id, &[]) {
+            stack.add_var(var_id, constval.clone());
+
+            Ok(PipelineData::empty())
+        } else {
+            Err(ShellError::NushellFailedSpanned {
+                msg: "Missing Constant".to_string(),
+                label: "constant not added by the parser".to_string(),
+                span: call.head,
+            })
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Define a constant in a module and use it",
+            example: r#"module spam { export const X = 10 }; use spam X; $X"#,
+            result: Some(Value::test_int(10)),
+        }]
+    }
+}
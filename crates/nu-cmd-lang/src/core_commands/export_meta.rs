@@ -0,0 +1,72 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ExportMeta;
+
+impl Command for ExportMeta {
+    fn name(&self) -> &str {
+        "export-meta"
+    }
+
+    fn usage(&self) -> &str {
+        "Declare metadata, such as a version, for the enclosing module."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("export-meta")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "metadata",
+                SyntaxShape::Record,
+                "the module metadata record (currently only `version` is supported)",
+            )
+            .category(Category::Core)
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"A module version lets `use <module> <version>` fail early when a script
+depends on a different release of a shared library.
+
+This command is a parser keyword. For details, check:
+  https://www.nushell.sh/book/thinking_in_nu.html"#
+    }
+
+    fn is_parser_keyword(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        _call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Declare a module version and import with a matching constraint",
+                example: r#"module spam { export-meta { version: "1.2.3" }; export def foo [] { "foo" } }; use spam 1.2 foo; foo"#,
+                result: Some(Value::test_string("foo")),
+            },
+            Example {
+                description: "Declare a module version",
+                example: r#"module spam { export-meta { version: "0.1.0" } }"#,
+                result: Some(Value::Nothing {
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["module", "version"]
+    }
+}
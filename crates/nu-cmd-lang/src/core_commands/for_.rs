@@ -4,6 +4,7 @@ use nu_protocol::engine::{Block, Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, ListStream, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct For;
@@ -92,7 +93,7 @@ impl Command for For {
                         var_id,
                         if numbered {
                             Value::Record {
-                                cols: vec!["index".into(), "item".into()],
+                                cols: Arc::new(vec!["index".into(), "item".into()]),
                                 vals: vec![Value::int(idx as i64, head), x],
                                 span: head,
                             }
@@ -136,7 +137,7 @@ impl Command for For {
                         var_id,
                         if numbered {
                             Value::Record {
-                                cols: vec!["index".into(), "item".into()],
+                                cols: Arc::new(vec!["index".into(), "item".into()]),
                                 vals: vec![Value::int(idx as i64, head), x],
                                 span: head,
                             }
@@ -9,7 +9,7 @@ use nu_ansi_term::{
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
-    engine::{Command, EngineState, Stack},
+    engine::{Command, EngineState, Stack, StateWorkingSet},
     span, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
     SyntaxShape, Type, Value,
 };
@@ -35,6 +35,11 @@ impl Command for Help {
                 "string to find in command names, usage, and search terms",
                 Some('f'),
             )
+            .switch(
+                "run",
+                "run the examples of the given command and verify their expected results",
+                None,
+            )
             .category(Category::Core)
     }
 
@@ -57,6 +62,10 @@ impl Command for Help {
         let find: Option<Spanned<String>> = call.get_flag(engine_state, stack, "find")?;
         let rest: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
 
+        if call.has_flag("run") {
+            return run_examples(engine_state, stack, &rest, head);
+        }
+
         if rest.is_empty() && find.is_none() {
             let msg = r#"Welcome to Nushell.
 
@@ -136,6 +145,108 @@ You can also learn more at https://www.nushell.sh/book/"#;
     }
 }
 
+/// Run the examples of a command and check their output against the expected results, if any.
+fn run_examples(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    rest: &[Spanned<String>],
+    head: Span,
+) -> Result<PipelineData, ShellError> {
+    let name = rest
+        .iter()
+        .map(|r| r.item.as_str())
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    let decl_id =
+        engine_state
+            .find_decl(name.as_bytes(), &[])
+            .ok_or(ShellError::CommandNotFound(span(
+                &rest.iter().map(|r| r.span).collect::<Vec<Span>>(),
+            )))?;
+
+    let config = engine_state.get_config();
+    let mut rows = vec![];
+
+    for example in engine_state.get_decl(decl_id).examples() {
+        let (actual, mut passed) = match eval_example(engine_state, stack, example.example) {
+            Ok(value) => (value.into_string(", ", config), true),
+            Err(err) => (err.to_string(), false),
+        };
+
+        let expected = example.result.as_ref().map(|r| r.into_string(", ", config));
+
+        if let Some(expected) = &expected {
+            passed = passed && expected == &actual;
+        }
+
+        rows.push(Value::Record {
+            cols: std::sync::Arc::new(vec![
+                "example".into(),
+                "description".into(),
+                "expected".into(),
+                "actual".into(),
+                "passed".into(),
+            ]),
+            vals: vec![
+                Value::string(example.example, head),
+                Value::string(example.description, head),
+                expected.map_or_else(|| Value::nothing(head), |e| Value::string(e, head)),
+                Value::string(actual, head),
+                Value::boolean(passed, head),
+            ],
+            span: head,
+        });
+    }
+
+    Ok(Value::List {
+        vals: rows,
+        span: head,
+    }
+    .into_pipeline_data())
+}
+
+/// Evaluate one example against a copy of the engine state, so that whatever it defines or
+/// loads does not leak into the session.
+fn eval_example(
+    engine_state: &EngineState,
+    stack: &Stack,
+    code: &str,
+) -> Result<Value, ShellError> {
+    let mut engine_state = engine_state.clone();
+
+    let (block, delta) = {
+        let mut working_set = StateWorkingSet::new(&engine_state);
+        let block = nu_parser::parse(&mut working_set, None, code.as_bytes(), false);
+
+        if let Some(err) = working_set.parse_errors.first() {
+            return Err(ShellError::GenericError(
+                "Failed to parse example".into(),
+                err.to_string(),
+                None,
+                None,
+                Vec::new(),
+            ));
+        }
+
+        (block, working_set.render())
+    };
+
+    engine_state.merge_delta(delta)?;
+
+    let mut stack = stack.clone();
+
+    nu_engine::eval_block(
+        &engine_state,
+        &mut stack,
+        &block,
+        PipelineData::empty(),
+        true,
+        true,
+    )
+    .map(|data| data.into_value(Span::unknown()))
+}
+
 pub fn highlight_search_in_table(
     table: Vec<Value>, // list of records
     search_string: &str,
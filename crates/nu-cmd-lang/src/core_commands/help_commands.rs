@@ -8,6 +8,7 @@ use nu_protocol::{
     Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 use std::borrow::Borrow;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct HelpCommands;
@@ -168,7 +169,11 @@ fn build_help_commands(engine_state: &EngineState, span: Span) -> Vec<Value> {
             span,
         });
 
-        found_cmds_vec.push(Value::Record { cols, vals, span });
+        found_cmds_vec.push(Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        });
     }
 
     found_cmds_vec
@@ -0,0 +1,276 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    span, Category, Example, IntoPipelineData, ModuleId, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+use std::borrow::Borrow;
+use std::fmt::Write;
+
+#[derive(Clone)]
+pub struct HelpGenerate;
+
+impl Command for HelpGenerate {
+    fn name(&self) -> &str {
+        "help generate"
+    }
+
+    fn usage(&self) -> &str {
+        "Generate documentation for a module and its commands."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The documentation is built from the doc comments of the module and its exported
+commands, including their `# Example:` blocks. Without a module name, documentation is
+generated for all modules in scope."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("help generate")
+            .category(Category::Core)
+            .rest(
+                "rest",
+                SyntaxShape::String,
+                "the name of the module to generate documentation for",
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "the output format (only 'md' is supported)",
+                None,
+            )
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .allow_variants_without_examples(true)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["documentation", "markdown"]
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "generate markdown documentation for a module",
+                example: "help generate --format md my-module",
+                result: None,
+            },
+            Example {
+                description: "generate documentation for all modules in scope",
+                example: "help generate",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        help_generate(engine_state, stack, call)
+    }
+}
+
+pub fn help_generate(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let format: Option<Spanned<String>> = call.get_flag(engine_state, stack, "format")?;
+    let rest: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
+
+    if let Some(format) = format {
+        if format.item != "md" {
+            return Err(ShellError::GenericError(
+                format!("Unsupported format: {}", format.item),
+                "only 'md' is supported".into(),
+                Some(format.span),
+                None,
+                Vec::new(),
+            ));
+        }
+    }
+
+    let modules = if rest.is_empty() {
+        let mut modules: Vec<(String, ModuleId)> = vec![];
+
+        for overlay_frame in engine_state.active_overlays(&[]) {
+            for (name_bytes, module_id) in &overlay_frame.modules {
+                let name = String::from_utf8_lossy(name_bytes).to_string();
+                modules.retain(|(known_name, _)| known_name != &name);
+                modules.push((name, *module_id));
+            }
+        }
+
+        modules.sort_by(|a, b| a.0.cmp(&b.0));
+        modules
+    } else {
+        let mut name = String::new();
+
+        for r in &rest {
+            if !name.is_empty() {
+                name.push(' ');
+            }
+            name.push_str(&r.item);
+        }
+
+        if let Some(module_id) = engine_state.find_module(name.as_bytes(), &[]) {
+            vec![(name, module_id)]
+        } else {
+            return Err(ShellError::ModuleNotFoundAtRuntime {
+                mod_name: name,
+                span: span(&rest.iter().map(|r| r.span).collect::<Vec<Span>>()),
+            });
+        }
+    };
+
+    let mut md = String::new();
+
+    for (name, module_id) in modules {
+        if !md.is_empty() {
+            md.push('\n');
+        }
+        md.push_str(&generate_module_docs(engine_state, &name, module_id));
+    }
+
+    Ok(Value::string(md, head).into_pipeline_data())
+}
+
+fn generate_module_docs(engine_state: &EngineState, name: &str, module_id: ModuleId) -> String {
+    let module = engine_state.get_module(module_id);
+
+    let mut md = format!("# Module `{name}`\n");
+
+    if let Some((usage, extra_usage)) = engine_state.build_module_usage(module_id) {
+        if !usage.is_empty() {
+            let _ = write!(md, "\n{usage}\n");
+        }
+        if !extra_usage.is_empty() {
+            let _ = write!(md, "\n{extra_usage}\n");
+        }
+    }
+
+    let mut decls = module.decls();
+    decls.sort_by(|a, b| a.0.cmp(&b.0));
+
+    if let Some(decl_id) = module.main {
+        decls.insert(0, (name.as_bytes().to_vec(), decl_id));
+    }
+
+    for (decl_name, decl_id) in decls {
+        // main is documented under the name of the module itself
+        let command_name = if decl_name == name.as_bytes() {
+            name.to_string()
+        } else {
+            format!("{name} {}", String::from_utf8_lossy(&decl_name))
+        };
+
+        md.push('\n');
+        md.push_str(&generate_command_docs(engine_state, &command_name, decl_id));
+    }
+
+    md
+}
+
+fn generate_command_docs(
+    engine_state: &EngineState,
+    name: &str,
+    decl_id: nu_protocol::DeclId,
+) -> String {
+    let decl = engine_state.get_decl(decl_id);
+    let sig = decl
+        .signature()
+        .update_from_command(name.into(), decl.borrow());
+
+    let mut md = format!("## `{}`\n", sig.name);
+
+    if !sig.usage.is_empty() {
+        let _ = write!(md, "\n{}\n", sig.usage);
+    }
+    if !sig.extra_usage.is_empty() {
+        let _ = write!(md, "\n{}\n", sig.extra_usage);
+    }
+
+    let mut usage_line = format!("> {}", sig.name);
+    for arg in &sig.required_positional {
+        let _ = write!(usage_line, " <{}>", arg.name);
+    }
+    for arg in &sig.optional_positional {
+        let _ = write!(usage_line, " ({})", arg.name);
+    }
+    if let Some(rest) = &sig.rest_positional {
+        let _ = write!(usage_line, " ...{}", rest.name);
+    }
+    if !sig.named.is_empty() {
+        usage_line.push_str(" {flags}");
+    }
+
+    let _ = write!(md, "\nUsage:\n\n```nu\n{usage_line}\n```\n");
+
+    let parameters: Vec<String> = sig
+        .required_positional
+        .iter()
+        .chain(&sig.optional_positional)
+        .chain(&sig.rest_positional)
+        .map(|arg| {
+            if arg.desc.is_empty() {
+                format!("- `{}` `<{}>`", arg.name, arg.shape)
+            } else {
+                format!("- `{}` `<{}>`: {}", arg.name, arg.shape, arg.desc)
+            }
+        })
+        .collect();
+
+    if !parameters.is_empty() {
+        let _ = write!(md, "\nParameters:\n\n{}\n", parameters.join("\n"));
+    }
+
+    let flags: Vec<String> = sig
+        .named
+        .iter()
+        .filter(|flag| flag.long != "help")
+        .map(|flag| {
+            let short = flag
+                .short
+                .map(|short| format!(" (`-{short}`)"))
+                .unwrap_or_default();
+            if flag.desc.is_empty() {
+                format!("- `--{}`{short}", flag.long)
+            } else {
+                format!("- `--{}`{short}: {}", flag.long, flag.desc)
+            }
+        })
+        .collect();
+
+    if !flags.is_empty() {
+        let _ = write!(md, "\nFlags:\n\n{}\n", flags.join("\n"));
+    }
+
+    let examples = decl.examples();
+    if !examples.is_empty() {
+        let _ = write!(md, "\nExamples:\n");
+        for example in examples {
+            md.push('\n');
+            if !example.description.is_empty() {
+                let _ = write!(md, "{}\n\n", example.description);
+            }
+            let _ = write!(md, "```nu\n> {}\n```\n", example.example);
+        }
+    }
+
+    md
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_examples() {
+        use super::HelpGenerate;
+        use crate::test_examples;
+        test_examples(HelpGenerate {})
+    }
+}
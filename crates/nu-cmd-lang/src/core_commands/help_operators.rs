@@ -3,6 +3,7 @@ use nu_protocol::{
     engine::{Command, EngineState, Stack},
     Category, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct HelpOperators;
@@ -48,7 +49,7 @@ impl Command for HelpOperators {
             cols.push("precedence".into());
             vals.push(Value::int(op.precedence, head));
             recs.push(Value::Record {
-                cols,
+                cols: Arc::new(cols),
                 vals,
                 span: head,
             })
@@ -11,9 +11,11 @@ mod echo;
 mod error_make;
 mod export;
 mod export_alias;
+mod export_const;
 mod export_def;
 mod export_def_env;
 mod export_extern;
+mod export_meta;
 mod export_use;
 mod extern_;
 mod for_;
@@ -21,6 +23,7 @@ pub mod help;
 pub mod help_aliases;
 pub mod help_commands;
 pub mod help_externs;
+pub mod help_generate;
 pub mod help_modules;
 mod help_operators;
 mod hide;
@@ -31,6 +34,7 @@ mod let_;
 mod loop_;
 mod match_;
 mod module;
+mod module_export_list;
 mod mut_;
 pub(crate) mod overlay;
 mod return_;
@@ -52,9 +56,11 @@ pub use echo::Echo;
 pub use error_make::ErrorMake;
 pub use export::ExportCommand;
 pub use export_alias::ExportAlias;
+pub use export_const::ExportConst;
 pub use export_def::ExportDef;
 pub use export_def_env::ExportDefEnv;
 pub use export_extern::ExportExtern;
+pub use export_meta::ExportMeta;
 pub use export_use::ExportUse;
 pub use extern_::Extern;
 pub use for_::For;
@@ -62,6 +68,7 @@ pub use help::Help;
 pub use help_aliases::HelpAliases;
 pub use help_commands::HelpCommands;
 pub use help_externs::HelpExterns;
+pub use help_generate::HelpGenerate;
 pub use help_modules::HelpModules;
 pub use help_operators::HelpOperators;
 pub use hide::Hide;
@@ -72,6 +79,7 @@ pub use let_::Let;
 pub use loop_::Loop;
 pub use match_::Match;
 pub use module::Module;
+pub use module_export_list::ModuleExportList;
 pub use mut_::Mut;
 pub use overlay::*;
 pub use return_::Return;
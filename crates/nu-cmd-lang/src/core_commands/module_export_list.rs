@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ModuleExportList;
+
+impl Command for ModuleExportList {
+    fn name(&self) -> &str {
+        "module export-list"
+    }
+
+    fn usage(&self) -> &str {
+        "List the exports of a module in scope."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("module export-list")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .required("module", SyntaxShape::String, "the name of the module")
+            .category(Category::Core)
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Each row names one export and its kind: command, alias, or const."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["exports", "public", "surface"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let head = call.head;
+
+        let module_id = if let Some(id) = engine_state.find_module(name.item.as_bytes(), &[]) {
+            id
+        } else {
+            return Err(ShellError::GenericError(
+                format!("Module '{}' not found", name.item),
+                "module not found in scope".into(),
+                Some(name.span),
+                None,
+                Vec::new(),
+            ));
+        };
+
+        let module = engine_state.get_module(module_id);
+
+        let mut vals = vec![];
+
+        if module.main.is_some() {
+            vals.push(export_row(&name.item, "command", head));
+        }
+
+        for (export_name, decl_id) in &module.decls {
+            let kind = if engine_state.get_decl(*decl_id).is_alias() {
+                "alias"
+            } else {
+                "command"
+            };
+
+            vals.push(export_row(
+                &String::from_utf8_lossy(export_name),
+                kind,
+                head,
+            ));
+        }
+
+        for const_name in module.const_names() {
+            vals.push(export_row(
+                &String::from_utf8_lossy(&const_name),
+                "const",
+                head,
+            ));
+        }
+
+        Ok(Value::List { vals, span: head }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List the exports of a module",
+            example: r#"module spam { export def foo [] {}; export const X = 10 }; module export-list spam"#,
+            result: None,
+        }]
+    }
+}
+
+fn export_row(name: &str, kind: &str, span: Span) -> Value {
+    Value::Record {
+        cols: Arc::new(vec!["name".to_string(), "kind".to_string()]),
+        vals: vec![Value::string(name, span), Value::string(kind, span)],
+        span,
+    }
+}
@@ -1,4 +1,4 @@
-use nu_engine::CallExt;
+use nu_engine::{redirect_env, CallExt};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
@@ -90,12 +90,26 @@ impl Command for OverlayHide {
             vec![]
         };
 
+        // Run the module's `on-deactivate` hook while the overlay is still active; its
+        // environment changes are merged only after the overlay is removed so they survive it
+        let hook_stack = super::eval_overlay_hook(
+            engine_state,
+            stack,
+            call,
+            &overlay_name.item,
+            b"on-deactivate",
+        )?;
+
         stack.remove_overlay(&overlay_name.item);
 
         for (name, val) in env_vars_to_keep {
             stack.add_env_var(name, val);
         }
 
+        if let Some(hook_stack) = hook_stack {
+            redirect_env(engine_state, stack, &hook_stack);
+        }
+
         Ok(PipelineData::empty())
     }
 
@@ -2,10 +2,80 @@ mod command;
 mod hide;
 mod list;
 mod new;
+mod restore;
+mod save;
 mod use_;
 
 pub use command::Overlay;
 pub use hide::OverlayHide;
 pub use list::OverlayList;
 pub use new::OverlayNew;
+pub use restore::OverlayRestore;
+pub use save::OverlaySave;
 pub use use_::OverlayUse;
+
+use nu_engine::eval_block;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{PipelineData, ShellError};
+use std::path::PathBuf;
+
+/// The file that `overlay save` and `overlay restore` persist the active overlay set to:
+/// `$env.NU_OVERLAY_STATE` if set, otherwise `overlay-state.txt` next to the rest of the config.
+pub(crate) fn overlay_state_file(engine_state: &EngineState, stack: &Stack) -> Option<PathBuf> {
+    if let Some(path) = stack.get_env_var(engine_state, "NU_OVERLAY_STATE") {
+        if let Ok(path) = path.as_string() {
+            return Some(PathBuf::from(path));
+        }
+    }
+
+    let mut path = nu_path::config_dir()?;
+    path.push("nushell");
+    path.push("overlay-state.txt");
+    Some(path)
+}
+
+/// Evaluate the `on-activate`/`on-deactivate` hook exported by an overlay's origin module, if
+/// there is one. Returns the stack the hook ran on so that the caller can decide when to merge
+/// its environment (`overlay hide` merges only after the overlay is removed, so that the hook's
+/// environment changes survive the removal).
+pub(crate) fn eval_overlay_hook(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    overlay_name: &str,
+    hook_name: &[u8],
+) -> Result<Option<Stack>, ShellError> {
+    let overlay_id = if let Some(id) = engine_state.find_overlay(overlay_name.as_bytes()) {
+        id
+    } else {
+        return Ok(None);
+    };
+
+    let module = engine_state.get_module(engine_state.get_overlay(overlay_id).origin);
+
+    let block_id = if let Some(decl_id) = module.get_decl_id(hook_name) {
+        if let Some(block_id) = engine_state.get_decl(decl_id).get_block_id() {
+            block_id
+        } else {
+            return Ok(None);
+        }
+    } else {
+        return Ok(None);
+    };
+
+    let block = engine_state.get_block(block_id);
+    let mut callee_stack = stack.gather_captures(&block.captures);
+
+    // Discard the hook's return value; it exists for its environment effects
+    let _ = eval_block(
+        engine_state,
+        &mut callee_stack,
+        block,
+        PipelineData::empty(),
+        call.redirect_stdout,
+        call.redirect_stderr,
+    )?;
+
+    Ok(Some(callee_stack))
+}
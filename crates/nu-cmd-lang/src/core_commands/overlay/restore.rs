@@ -0,0 +1,90 @@
+use nu_engine::redirect_env;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Type};
+
+use super::{eval_overlay_hook, overlay_state_file};
+
+#[derive(Clone)]
+pub struct OverlayRestore;
+
+impl Command for OverlayRestore {
+    fn name(&self) -> &str {
+        "overlay restore"
+    }
+
+    fn usage(&self) -> &str {
+        "Re-activate the overlays saved by `overlay save`."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("overlay restore")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .category(Category::Core)
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Every saved name must be a known overlay or a module in scope; overlays that are
+already active stay active. Each module's `on-activate` hook runs as it is re-activated.
+
+This command is a parser keyword. For details, check:
+  https://www.nushell.sh/book/thinking_in_nu.html"#
+    }
+
+    fn is_parser_keyword(&self) -> bool {
+        true
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["persist", "session"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        // The parser has already activated the overlays for definitions and reported any
+        // missing ones; here the same set is activated on the stack for the environment
+        let state_path = if let Some(path) = overlay_state_file(engine_state, stack) {
+            path
+        } else {
+            return Ok(PipelineData::empty());
+        };
+
+        let contents = match std::fs::read_to_string(state_path) {
+            Ok(contents) => contents,
+            Err(_) => return Ok(PipelineData::empty()),
+        };
+
+        for name in contents.lines().map(str::trim).filter(|s| !s.is_empty()) {
+            let name = name.to_string();
+
+            if engine_state.find_overlay(name.as_bytes()).is_none()
+                || stack.is_overlay_active(&name)
+            {
+                continue;
+            }
+
+            stack.add_overlay(name.clone());
+
+            if let Some(hook_stack) =
+                eval_overlay_hook(engine_state, stack, call, &name, b"on-activate")?
+            {
+                redirect_env(engine_state, stack, &hook_stack);
+            }
+        }
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Re-activate the overlays that were saved with `overlay save`",
+            example: r#"overlay restore"#,
+            result: None,
+        }]
+    }
+}
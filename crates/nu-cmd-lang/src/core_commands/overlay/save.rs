@@ -0,0 +1,93 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack, DEFAULT_OVERLAY_NAME};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Type};
+
+use super::overlay_state_file;
+
+#[derive(Clone)]
+pub struct OverlaySave;
+
+impl Command for OverlaySave {
+    fn name(&self) -> &str {
+        "overlay save"
+    }
+
+    fn usage(&self) -> &str {
+        "Save the set of active overlays so `overlay restore` can re-activate it later."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("overlay save")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .category(Category::Core)
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The overlay names are written to `overlay-state.txt` in the config directory, or to the
+file named by $env.NU_OVERLAY_STATE if it is set."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["persist", "session"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let state_path = if let Some(path) = overlay_state_file(engine_state, stack) {
+            path
+        } else {
+            return Err(ShellError::GenericError(
+                "Could not determine the overlay state file location".into(),
+                "config directory not found".into(),
+                Some(call.head),
+                Some("set $env.NU_OVERLAY_STATE to a file path".into()),
+                Vec::new(),
+            ));
+        };
+
+        // The default overlay is always active, so there is no point in saving it
+        let names: Vec<&str> = stack
+            .active_overlays
+            .iter()
+            .map(String::as_str)
+            .filter(|name| *name != DEFAULT_OVERLAY_NAME)
+            .collect();
+
+        let write_result = if let Some(parent) = state_path.parent() {
+            std::fs::create_dir_all(parent)
+                .and_then(|_| std::fs::write(&state_path, names.join("\n") + "\n"))
+        } else {
+            std::fs::write(&state_path, names.join("\n") + "\n")
+        };
+
+        if let Err(err) = write_result {
+            return Err(ShellError::GenericError(
+                format!(
+                    "Could not write the overlay state to {}",
+                    state_path.display()
+                ),
+                err.to_string(),
+                Some(call.head),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Save the active overlays for a later `overlay restore`",
+            example: r#"module spam { export def foo [] { "foo" } }
+    overlay use spam
+    overlay save"#,
+            result: None,
+        }]
+    }
+}
@@ -144,15 +144,26 @@ impl Command for OverlayUse {
                 );
 
                 // The export-env block should see the env vars *before* activating this overlay
-                caller_stack.add_overlay(overlay_name);
+                caller_stack.add_overlay(overlay_name.clone());
 
                 // Merge the block's environment to the current stack
                 redirect_env(engine_state, caller_stack, &callee_stack);
             } else {
-                caller_stack.add_overlay(overlay_name);
+                caller_stack.add_overlay(overlay_name.clone());
             }
         } else {
-            caller_stack.add_overlay(overlay_name);
+            caller_stack.add_overlay(overlay_name.clone());
+        }
+
+        // Run the module's `on-activate` hook (an exported command with that name), if any
+        if let Some(hook_stack) = super::eval_overlay_hook(
+            engine_state,
+            caller_stack,
+            call,
+            &overlay_name,
+            b"on-activate",
+        )? {
+            redirect_env(engine_state, caller_stack, &hook_stack);
         }
 
         Ok(PipelineData::empty())
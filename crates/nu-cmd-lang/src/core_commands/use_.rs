@@ -21,10 +21,15 @@ impl Command for Use {
         Signature::build("use")
             .input_output_types(vec![(Type::Nothing, Type::Nothing)])
             .required("module", SyntaxShape::String, "Module or module file")
-            .optional(
+            .rest(
                 "members",
                 SyntaxShape::Any,
-                "Which members of the module to import",
+                "An optional version constraint, which members of the module to import, and an optional trailing `as <name>` rename",
+            )
+            .switch(
+                "lazy",
+                "Do not load the module file until one of its commands is first used",
+                None,
             )
             .category(Category::Core)
     }
@@ -45,6 +50,12 @@ impl Command for Use {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
+        // A lazy import only records the module's location; the parser loads the module (and
+        // there is no `export-env` block to run) when one of its commands is first referenced.
+        if call.has_flag("lazy") {
+            return Ok(PipelineData::empty());
+        }
+
         let import_pattern = if let Some(Expression {
             expr: Expr::ImportPattern(pat),
             ..
@@ -127,6 +138,11 @@ impl Command for Use {
                 example: r#"module spam { export def foo [] { "foo" } }; use spam foo; foo"#,
                 result: Some(Value::test_string("foo")),
             },
+            Example {
+                description: "Define a custom command in a module and import it under a different name",
+                example: r#"module spam { export def foo [] { "foo" } }; use spam foo as bar; bar"#,
+                result: Some(Value::test_string("foo")),
+            },
             Example {
                 description: "Define a custom command that participates in the environment in a module and call it",
                 example: r#"module foo { export def-env bar [] { let-env FOO_BAR = "BAZ" } }; use foo bar; bar; $env.FOO_BAR"#,
@@ -2,6 +2,7 @@ use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value};
 use shadow_rs::shadow;
+use std::sync::Arc;
 
 shadow!(build);
 
@@ -137,7 +138,7 @@ pub fn version(
     });
 
     Ok(Value::Record {
-        cols,
+        cols: Arc::new(cols),
         vals,
         span: call.head,
     }
@@ -29,9 +29,11 @@ pub fn create_default_context() -> EngineState {
             ErrorMake,
             ExportAlias,
             ExportCommand,
+            ExportConst,
             ExportDef,
             ExportDefEnv,
             ExportExtern,
+            ExportMeta,
             ExportUse,
             Extern,
             For,
@@ -40,6 +42,7 @@ pub fn create_default_context() -> EngineState {
             HelpCommands,
             HelpModules,
             HelpExterns,
+            HelpGenerate,
             HelpOperators,
             Hide,
             HideEnv,
@@ -50,10 +53,13 @@ pub fn create_default_context() -> EngineState {
             OverlayList,
             OverlayNew,
             OverlayHide,
+            OverlaySave,
+            OverlayRestore,
             Let,
             Loop,
             Match,
             Module,
+            ModuleExportList,
             Mut,
             Return,
             Try,
@@ -203,6 +203,36 @@ impl<'a> StyleComputer<'a> {
                 _ => (),
             }
         }
+
+        // Per-column colors from $env.config.table.columns go under a prefixed key,
+        // so they can't collide with the primitive type names above.
+        for (column, rule) in &config.table_columns {
+            let color = match rule.get_data_by_key("color") {
+                Some(color) => color,
+                None => continue,
+            };
+
+            let key = format!("table_column_{column}");
+            match color {
+                Value::Closure { .. } => {
+                    map.insert(key, ComputableStyle::Closure(color));
+                }
+                Value::Record { .. } => {
+                    map.insert(
+                        key,
+                        ComputableStyle::Static(color_record_to_nustyle(&color)),
+                    );
+                }
+                Value::String { val, .. } => {
+                    map.insert(
+                        key,
+                        ComputableStyle::Static(lookup_ansi_color_style(val.as_str())),
+                    );
+                }
+                _ => (),
+            }
+        }
+
         StyleComputer::new(engine_state, stack, map)
     }
 }
@@ -0,0 +1,94 @@
+use nu_protocol::ShellError;
+use rayon::prelude::*;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+// This module holds the multi-threaded hash aggregation path shared by
+// group-by, uniq, and histogram. Batches are partitioned by key hash so every
+// occurrence of a key lands in the same partition; the partitions can then be
+// folded independently on the rayon thread pool and the resulting maps
+// chained without a merge step.
+
+/// Batches smaller than this are aggregated on a single thread; the scatter
+/// and thread-pool overhead only pays off on large inputs.
+pub const MIN_PARALLEL_AGG_ROWS: usize = 10_000;
+
+/// Compute the aggregation key of every item, in parallel for large batches.
+pub fn aggregation_keys<T, K, F>(items: &[T], key: F) -> Result<Vec<K>, ShellError>
+where
+    T: Sync,
+    K: Send,
+    F: Fn(&T) -> Result<K, ShellError> + Send + Sync,
+{
+    if items.len() < MIN_PARALLEL_AGG_ROWS {
+        items.iter().map(key).collect()
+    } else {
+        items.par_iter().map(key).collect()
+    }
+}
+
+/// Fold keyed items into maps, one partition per thread-pool worker. An
+/// item's partition is decided by its key hash, so the returned maps have
+/// disjoint key sets and can simply be chained.
+pub fn fold_partitioned<K, T, A, F>(keyed: Vec<(K, T)>, fold: F) -> Vec<HashMap<K, A>>
+where
+    K: Eq + Hash + Send,
+    T: Send,
+    A: Send,
+    F: Fn(&mut HashMap<K, A>, K, T) + Sync,
+{
+    if keyed.len() < MIN_PARALLEL_AGG_ROWS {
+        let mut map = HashMap::new();
+        for (key, item) in keyed {
+            fold(&mut map, key, item);
+        }
+        return vec![map];
+    }
+
+    let partitions = rayon::current_num_threads().max(1);
+    let mut buckets: Vec<Vec<(K, T)>> = Vec::new();
+    buckets.resize_with(partitions, Vec::new);
+    for (key, item) in keyed {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        let bucket = (hasher.finish() % partitions as u64) as usize;
+        buckets[bucket].push((key, item));
+    }
+
+    buckets
+        .into_par_iter()
+        .map(|bucket| {
+            let mut map = HashMap::new();
+            for (key, item) in bucket {
+                fold(&mut map, key, item);
+            }
+            map
+        })
+        .collect()
+}
+
+#[test]
+fn test_fold_partitioned_counts_across_partitions() {
+    // Push well past the parallel threshold so the partitioned path runs
+    let keyed: Vec<(u64, ())> = (0..(MIN_PARALLEL_AGG_ROWS as u64 * 2))
+        .map(|n| (n % 7, ()))
+        .collect();
+    let total = keyed.len();
+
+    let partitions = fold_partitioned(keyed, |map, key, ()| {
+        *map.entry(key).or_insert(0usize) += 1;
+    });
+
+    let mut seen = std::collections::HashSet::new();
+    let mut counted = 0;
+    for map in partitions {
+        for (key, count) in map {
+            // Every key must land in exactly one partition
+            assert!(seen.insert(key));
+            counted += count;
+        }
+    }
+    assert_eq!(seen.len(), 7);
+    assert_eq!(counted, total);
+}
@@ -4,7 +4,9 @@ use nu_protocol::ast::Call;
 use nu_protocol::ast::CellPath;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::Category;
-use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value};
+use nu_protocol::{
+    Example, PipelineData, RawStream, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
 
 struct Arguments {
     added_data: Vec<u8>,
@@ -75,7 +77,32 @@ impl Command for BytesAdd {
             end,
             cell_paths,
         };
-        operate(add, arg, input, call.head, engine_state.ctrlc.clone())
+
+        match input {
+            // Prepending or appending to a byte stream doesn't need the whole payload in
+            // memory; chain the added bytes around the stream. Inserting at an index still
+            // needs the collecting path below.
+            PipelineData::ExternalStream {
+                stdout: Some(stream),
+                stderr,
+                exit_code,
+                span,
+                metadata,
+                trim_end_newline,
+            } if arg.cell_paths.is_none() && arg.index.is_none() => {
+                let added =
+                    add_to_stream(stream, arg.added_data, arg.end, engine_state.ctrlc.clone());
+                Ok(PipelineData::ExternalStream {
+                    stdout: Some(added),
+                    stderr,
+                    exit_code,
+                    span,
+                    metadata,
+                    trim_end_newline,
+                })
+            }
+            input => operate(add, arg, input, call.head, engine_state.ctrlc.clone()),
+        }
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -182,3 +209,30 @@ mod tests {
         test_examples(BytesAdd {})
     }
 }
+
+/// Chain the added bytes before (or, with `--end`, after) a raw byte stream without
+/// collecting the stream into memory.
+fn add_to_stream(
+    stream: RawStream,
+    added_data: Vec<u8>,
+    end: bool,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> RawStream {
+    let span = stream.span;
+    let known_size = stream.known_size.map(|size| size + added_data.len() as u64);
+    let is_binary = stream.is_binary;
+
+    let leftover = (!stream.leftover.is_empty()).then_some(Ok(stream.leftover));
+    let source = leftover.into_iter().chain(stream.stream);
+    let added = std::iter::once(Ok(added_data));
+
+    let inner: Box<dyn Iterator<Item = Result<Vec<u8>, ShellError>> + Send + 'static> = if end {
+        Box::new(source.chain(added))
+    } else {
+        Box::new(added.chain(source))
+    };
+
+    let mut out = RawStream::new(inner, ctrlc, span, known_size);
+    out.is_binary = is_binary;
+    out
+}
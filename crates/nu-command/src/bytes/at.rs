@@ -6,8 +6,10 @@ use nu_engine::CallExt;
 use nu_protocol::{
     ast::{Call, CellPath},
     engine::{Command, EngineState, Stack},
-    Category, Example, PipelineData, Range, ShellError, Signature, Span, SyntaxShape, Type, Value,
+    Category, Example, PipelineData, Range, RawStream, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct BytesAt;
@@ -80,7 +82,33 @@ impl Command for BytesAt {
             cell_paths,
         };
 
-        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+        match input {
+            // Slice byte streams chunk by chunk instead of collecting the whole payload
+            // into memory first. Negative indexes need the total length, so those still
+            // fall through to the collecting path below.
+            PipelineData::ExternalStream {
+                stdout: Some(stream),
+                stderr,
+                exit_code,
+                span,
+                metadata,
+                trim_end_newline,
+            } if args.cell_paths.is_none()
+                && args.indexes.0 >= 0
+                && args.indexes.1 >= args.indexes.0 =>
+            {
+                let sliced = slice_stream(stream, args.indexes, engine_state.ctrlc.clone());
+                Ok(PipelineData::ExternalStream {
+                    stdout: Some(sliced),
+                    stderr,
+                    exit_code,
+                    span,
+                    metadata,
+                    trim_end_newline,
+                })
+            }
+            input => operate(action, args, input, call.head, engine_state.ctrlc.clone()),
+        }
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -123,7 +151,11 @@ impl Command for BytesAt {
                 example: r#" [[ColA ColB ColC]; [0x[11 12 13] 0x[14 15 16] 0x[17 18 19]]] | bytes at 1.. ColB ColC"#,
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ColA".to_string(), "ColB".to_string(), "ColC".to_string()],
+                        cols: Arc::new(vec![
+                            "ColA".to_string(),
+                            "ColB".to_string(),
+                            "ColC".to_string(),
+                        ]),
                         vals: vec![
                             Value::Binary {
                                 val: vec![0x11, 0x12, 0x13],
@@ -176,15 +208,14 @@ fn action(input: &Value, args: &Arguments, head: Span) -> Value {
                     },
                     Ordering::Less => Value::Binary {
                         val: {
-                            if end == isize::max_value() {
-                                val.iter().skip(start as usize).copied().collect()
+                            // Copy only the requested range instead of iterating byte by byte.
+                            let start = start as usize;
+                            let end = if end == isize::max_value() {
+                                val.len()
                             } else {
-                                val.iter()
-                                    .skip(start as usize)
-                                    .take((end - start) as usize)
-                                    .copied()
-                                    .collect()
-                            }
+                                cmp::min(end as usize, val.len())
+                            };
+                            val[cmp::min(start, end)..end].to_vec()
                         },
                         span: head,
                     },
@@ -210,3 +241,57 @@ fn action(input: &Value, args: &Arguments, head: Span) -> Value {
         },
     }
 }
+
+/// Lazily slice a raw byte stream to the given (non-negative) range, pulling no more
+/// chunks from the source than needed to reach the end of the range.
+fn slice_stream(
+    stream: RawStream,
+    indexes: Subbytes,
+    ctrlc: Option<std::sync::Arc<std::sync::atomic::AtomicBool>>,
+) -> RawStream {
+    use std::cmp;
+
+    let start = indexes.0 as usize;
+    let end = if indexes.1 == isize::max_value() {
+        usize::MAX
+    } else {
+        indexes.1 as usize
+    };
+    let span = stream.span;
+    let known_size = stream
+        .known_size
+        .map(|size| cmp::min(size, end.saturating_sub(start) as u64));
+
+    let leftover = (!stream.leftover.is_empty()).then_some(Ok(stream.leftover));
+    let inner = leftover
+        .into_iter()
+        .chain(stream.stream)
+        .scan(0usize, move |pos, chunk| {
+            if *pos >= end {
+                return None;
+            }
+            match chunk {
+                Ok(chunk) => {
+                    let chunk_start = *pos;
+                    let chunk_end = chunk_start + chunk.len();
+                    *pos = chunk_end;
+                    if chunk_end <= start {
+                        return Some(None);
+                    }
+                    let from = start.saturating_sub(chunk_start);
+                    let to = cmp::min(chunk.len(), end - chunk_start);
+                    if from == 0 && to == chunk.len() {
+                        Some(Some(Ok(chunk)))
+                    } else {
+                        Some(Some(Ok(chunk[from..to].to_vec())))
+                    }
+                }
+                Err(err) => Some(Some(Err(err))),
+            }
+        })
+        .flatten();
+
+    let mut out = RawStream::new(Box::new(inner), ctrlc, span, known_size);
+    out.is_binary = stream.is_binary;
+    out
+}
@@ -0,0 +1,180 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct BytesFind;
+
+impl Command for BytesFind {
+    fn name(&self) -> &str {
+        "bytes find"
+    }
+
+    fn usage(&self) -> &str {
+        "Find every occurrence of a pattern in binary data, returning the byte offsets."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Streamed input is searched chunk by chunk, so large files can be scanned
+without loading them into memory. Overlapping matches are all reported."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("bytes find")
+            .input_output_types(vec![
+                (Type::Binary, Type::List(Box::new(Type::Int))),
+                (Type::String, Type::List(Box::new(Type::Int))),
+            ])
+            .required(
+                "pattern",
+                SyntaxShape::Any,
+                "the binary or string pattern to search for",
+            )
+            .category(Category::Bytes)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["search", "offset", "binary", "forensics"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let pattern: Value = call.req(engine_state, stack, 0)?;
+        let pattern_span = pattern.span().unwrap_or(span);
+        let pattern = match pattern {
+            Value::Binary { val, .. } => val,
+            Value::String { val, .. } => val.into_bytes(),
+            other => {
+                return Err(ShellError::TypeMismatch {
+                    err_message: "the pattern must be binary or a string".into(),
+                    span: other.expect_span(),
+                })
+            }
+        };
+        if pattern.is_empty() {
+            return Err(ShellError::TypeMismatch {
+                err_message: "the pattern to find cannot be empty".to_string(),
+                span: pattern_span,
+            });
+        }
+
+        let mut offsets = Vec::new();
+
+        match input {
+            PipelineData::ExternalStream {
+                stdout: Some(stream),
+                ..
+            } => {
+                // Search chunk by chunk, carrying the tail of each chunk over so
+                // matches spanning a boundary are still found
+                let mut carry: Vec<u8> = Vec::new();
+                let mut base: usize = 0;
+
+                for chunk in stream {
+                    let chunk = chunk?;
+                    let bytes = match chunk {
+                        Value::Binary { val, .. } => val,
+                        Value::String { val, .. } => val.into_bytes(),
+                        _ => continue,
+                    };
+
+                    carry.extend_from_slice(&bytes);
+                    find_matches(&carry, &pattern, base, &mut offsets);
+
+                    let keep = pattern.len().saturating_sub(1).min(carry.len());
+                    base += carry.len() - keep;
+                    carry = carry.split_off(carry.len() - keep);
+                }
+            }
+            other => {
+                let bytes = match other.into_value(span) {
+                    Value::Binary { val, .. } => val,
+                    Value::String { val, .. } => val.into_bytes(),
+                    other => {
+                        return Err(ShellError::OnlySupportsThisInputType {
+                            exp_input_type: "binary or string".into(),
+                            wrong_type: other.get_type().to_string(),
+                            dst_span: span,
+                            src_span: other.expect_span(),
+                        })
+                    }
+                };
+                find_matches(&bytes, &pattern, 0, &mut offsets);
+            }
+        }
+
+        Ok(Value::List {
+            vals: offsets
+                .into_iter()
+                .map(|offset| Value::Int {
+                    val: offset as i64,
+                    span,
+                })
+                .collect(),
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Find the offsets of a byte pattern",
+                example: "0x[10 AA 10 BB 10] | bytes find 0x[10]",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(0), Value::test_int(2), Value::test_int(4)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Find the offsets of a string pattern, including overlaps",
+                example: "'aaa' | bytes find 'aa'",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(0), Value::test_int(1)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Find a string in a file without loading it whole",
+                example: "open --raw binary.dat | bytes find 'PNG'",
+                result: None,
+            },
+        ]
+    }
+}
+
+// Reports matches whose start lies within the searchable part of the buffer;
+// starts inside the final `pattern.len() - 1` bytes are left for the next chunk
+fn find_matches(buffer: &[u8], pattern: &[u8], base: usize, offsets: &mut Vec<usize>) {
+    if pattern.len() > buffer.len() {
+        return;
+    }
+
+    for start in 0..=(buffer.len() - pattern.len()) {
+        if &buffer[start..start + pattern.len()] == pattern {
+            offsets.push(base + start);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(BytesFind {})
+    }
+}
@@ -5,6 +5,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 struct Arguments {
     pattern: Vec<u8>,
@@ -108,7 +109,11 @@ impl Command for BytesIndexOf {
                 example: r#" [[ColA ColB ColC]; [0x[11 12 13] 0x[14 15 16] 0x[17 18 19]]] | bytes index-of 0x[11] ColA ColC"#,
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ColA".to_string(), "ColB".to_string(), "ColC".to_string()],
+                        cols: Arc::new(vec![
+                            "ColA".to_string(),
+                            "ColB".to_string(),
+                            "ColC".to_string(),
+                        ]),
                         vals: vec![
                             Value::test_int(0),
                             Value::Binary {
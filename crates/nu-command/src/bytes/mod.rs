@@ -4,12 +4,14 @@ mod build_;
 mod bytes_;
 mod collect;
 mod ends_with;
+mod find;
 mod index_of;
 mod length;
 mod remove;
 mod replace;
 mod reverse;
 mod starts_with;
+mod view;
 
 pub use add::BytesAdd;
 pub use at::BytesAt;
@@ -17,9 +19,11 @@ pub use build_::BytesBuild;
 pub use bytes_::Bytes;
 pub use collect::BytesCollect;
 pub use ends_with::BytesEndsWith;
+pub use find::BytesFind;
 pub use index_of::BytesIndexOf;
 pub use length::BytesLen;
 pub use remove::BytesRemove;
 pub use replace::BytesReplace;
 pub use reverse::BytesReverse;
 pub use starts_with::BytesStartsWith;
+pub use view::BytesView;
@@ -6,6 +6,7 @@ use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
     Value,
 };
+use std::sync::Arc;
 
 struct Arguments {
     pattern: Vec<u8>,
@@ -109,7 +110,7 @@ impl Command for BytesRemove {
                 example: "[[ColA ColB ColC]; [0x[11 12 13] 0x[14 15 16] 0x[17 18 19]]] | bytes remove 0x[11] ColA ColC",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ColA".to_string(), "ColB".to_string(), "ColC".to_string()],
+                        cols: Arc::new(vec!["ColA".to_string(), "ColB".to_string(), "ColC".to_string()]),
                         vals: vec![
                             Value::Binary {
                                 val: vec![0x12, 0x13],
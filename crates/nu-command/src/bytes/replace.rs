@@ -6,6 +6,7 @@ use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
     Value,
 };
+use std::sync::Arc;
 
 struct Arguments {
     find: Vec<u8>,
@@ -100,7 +101,7 @@ impl Command for BytesReplace {
                 example: "[[ColA ColB ColC]; [0x[11 12 13] 0x[14 15 16] 0x[17 18 19]]] | bytes replace -a 0x[11] 0x[13] ColA ColC",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ColA".to_string(), "ColB".to_string(), "ColC".to_string()],
+                        cols: Arc::new(vec!["ColA".to_string(), "ColB".to_string(), "ColC".to_string()]),
                         vals: vec![
                             Value::Binary {
                                 val: vec![0x13, 0x12, 0x13],
@@ -0,0 +1,201 @@
+use nu_ansi_term::{Color, Style};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct BytesView;
+
+impl Command for BytesView {
+    fn name(&self) -> &str {
+        "bytes view"
+    }
+
+    fn usage(&self) -> &str {
+        "Render binary data as a hex/ASCII dump with offset addressing."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The offset column is absolute, so a dump sliced with --offset keeps the
+addresses of the original data. Matches of --find are highlighted."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("bytes view")
+            .input_output_types(vec![
+                (Type::Binary, Type::String),
+                (Type::String, Type::String),
+            ])
+            .named(
+                "offset",
+                SyntaxShape::Int,
+                "start the dump at this byte offset",
+                Some('o'),
+            )
+            .named(
+                "length",
+                SyntaxShape::Int,
+                "show at most this many bytes",
+                Some('l'),
+            )
+            .named(
+                "find",
+                SyntaxShape::Any,
+                "highlight occurrences of this binary or string pattern",
+                Some('f'),
+            )
+            .category(Category::Bytes)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["hexdump", "xxd", "binary", "forensics"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let offset: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "offset")?;
+        let length: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "length")?;
+        let pattern: Option<Value> = call.get_flag(engine_state, stack, "find")?;
+
+        if let Some(ref offset) = offset {
+            if offset.item < 0 {
+                return Err(ShellError::NeedsPositiveValue(offset.span));
+            }
+        }
+        if let Some(ref length) = length {
+            if length.item < 0 {
+                return Err(ShellError::NeedsPositiveValue(length.span));
+            }
+        }
+
+        let bytes = input_bytes(input, span)?;
+        let pattern = pattern.map(|p| pattern_bytes(&p)).transpose()?;
+
+        let start = (offset.map_or(0, |o| o.item as usize)).min(bytes.len());
+        let end = length.map_or(bytes.len(), |l| (start + l.item as usize).min(bytes.len()));
+        let slice = &bytes[start..end];
+
+        let highlighted = match &pattern {
+            Some(pattern) => match_mask(slice, pattern),
+            None => vec![false; slice.len()],
+        };
+
+        Ok(Value::String {
+            val: render_dump(slice, &highlighted, start),
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "View the bytes of a file as a hex dump",
+                example: "open --raw binary.dat | bytes view",
+                result: None,
+            },
+            Example {
+                description: "View 64 bytes starting at offset 0x100, highlighting a magic number",
+                example: "open --raw binary.dat | bytes view --offset 256 --length 64 --find 0x[CAFEBABE]",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn input_bytes(input: PipelineData, span: nu_protocol::Span) -> Result<Vec<u8>, ShellError> {
+    match input.into_value(span) {
+        Value::Binary { val, .. } => Ok(val),
+        Value::String { val, .. } => Ok(val.into_bytes()),
+        other => Err(ShellError::OnlySupportsThisInputType {
+            exp_input_type: "binary or string".into(),
+            wrong_type: other.get_type().to_string(),
+            dst_span: span,
+            src_span: other.expect_span(),
+        }),
+    }
+}
+
+fn pattern_bytes(pattern: &Value) -> Result<Vec<u8>, ShellError> {
+    match pattern {
+        Value::Binary { val, .. } => Ok(val.clone()),
+        Value::String { val, .. } => Ok(val.clone().into_bytes()),
+        other => Err(ShellError::TypeMismatch {
+            err_message: "the pattern must be binary or a string".into(),
+            span: other.expect_span(),
+        }),
+    }
+}
+
+// Marks every byte that belongs to an occurrence of the pattern
+fn match_mask(bytes: &[u8], pattern: &[u8]) -> Vec<bool> {
+    let mut mask = vec![false; bytes.len()];
+    if pattern.is_empty() || pattern.len() > bytes.len() {
+        return mask;
+    }
+
+    for start in 0..=(bytes.len() - pattern.len()) {
+        if &bytes[start..start + pattern.len()] == pattern {
+            for flag in &mut mask[start..start + pattern.len()] {
+                *flag = true;
+            }
+        }
+    }
+    mask
+}
+
+fn render_dump(bytes: &[u8], highlighted: &[bool], base_offset: usize) -> String {
+    let highlight = Style::new().fg(Color::Red).bold();
+    let mut out = String::new();
+
+    for (row, chunk) in bytes.chunks(16).enumerate() {
+        let row_offset = base_offset + row * 16;
+        out.push_str(&format!("{row_offset:08x}  "));
+
+        for col in 0..16 {
+            match chunk.get(col) {
+                Some(byte) => {
+                    let hex = format!("{byte:02x}");
+                    if highlighted[row * 16 + col] {
+                        out.push_str(&highlight.paint(hex).to_string());
+                    } else {
+                        out.push_str(&hex);
+                    }
+                    out.push(' ');
+                }
+                None => out.push_str("   "),
+            }
+            if col == 7 {
+                out.push(' ');
+            }
+        }
+
+        out.push_str(" |");
+        for (col, byte) in chunk.iter().enumerate() {
+            let ch = if byte.is_ascii_graphic() || *byte == b' ' {
+                *byte as char
+            } else {
+                '.'
+            };
+            if highlighted[row * 16 + col] {
+                out.push_str(&highlight.paint(ch.to_string()).to_string());
+            } else {
+                out.push(ch);
+            }
+        }
+        out.push('|');
+        out.push('\n');
+    }
+
+    out
+}
@@ -9,6 +9,7 @@ use nu_protocol::{
 };
 use std::collections::HashMap;
 use std::iter;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Histogram;
@@ -29,6 +30,7 @@ impl Command for Histogram {
             .optional("column-name", SyntaxShape::String, "column name to calc frequency, no need to provide if input is just a list")
             .optional("frequency-column-name", SyntaxShape::String, "histogram's frequency column, default to be frequency column output")
             .named("percentage-type", SyntaxShape::String, "percentage calculate method, can be 'normalize' or 'relative', in 'normalize', defaults to be 'normalize'", Some('t'))
+            .switch("approx", "build a constant-memory approximate histogram from numeric input, binning nearby values instead of counting each distinct one", None)
     }
 
     fn usage(&self) -> &str {
@@ -53,7 +55,7 @@ impl Command for Histogram {
                 example: "[1 2 1] | histogram",
                 result: Some(Value::List {
                         vals: vec![Value::Record {
-                            cols: vec!["value".to_string(), "count".to_string(), "quantile".to_string(), "percentage".to_string(), "frequency".to_string()],
+                            cols: Arc::new(vec!["value".to_string(), "count".to_string(), "quantile".to_string(), "percentage".to_string(), "frequency".to_string()]),
                             vals: vec![
                                 Value::test_int(1),
                                 Value::test_int(2),
@@ -64,7 +66,7 @@ impl Command for Histogram {
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["value".to_string(), "count".to_string(), "quantile".to_string(), "percentage".to_string(), "frequency".to_string()],
+                            cols: Arc::new(vec!["value".to_string(), "count".to_string(), "quantile".to_string(), "percentage".to_string(), "frequency".to_string()]),
                             vals: vec![
                                 Value::test_int(2),
                                 Value::test_int(1),
@@ -135,6 +137,16 @@ impl Command for Histogram {
         };
 
         let span = call.head;
+        if call.has_flag("approx") {
+            return run_approx_histogram(
+                input,
+                column_name,
+                frequency_column_name,
+                calc_method,
+                span,
+            );
+        }
+
         let data_as_value = input.into_value(span);
         // `input` is not a list, here we can return an error.
         run_histogram(
@@ -195,8 +207,8 @@ fn run_histogram(
                 match v {
                     // parse record, and fill valid value to actual input.
                     Value::Record { cols, vals, .. } => {
-                        for (c, v) in iter::zip(cols, vals) {
-                            if &c == col_name {
+                        for (c, v) in iter::zip(cols.iter(), vals) {
+                            if c == col_name {
                                 if let Ok(v) = HashableValue::from_value(v, head_span) {
                                     inputs.push(v);
                                 }
@@ -231,6 +243,151 @@ fn run_histogram(
     ))
 }
 
+fn numeric_value(value: &Value) -> Option<f64> {
+    match value {
+        Value::Int { val, .. } => Some(*val as f64),
+        Value::Float { val, .. } => Some(*val),
+        Value::Filesize { val, .. } => Some(*val as f64),
+        Value::Duration { val, .. } => Some(*val as f64),
+        _ => None,
+    }
+}
+
+/// Approximate, constant-memory variant: numeric values are folded into a
+/// bounded quantile sketch as they stream in, and one row is emitted per
+/// sketch bin instead of per distinct value.
+fn run_approx_histogram(
+    input: PipelineData,
+    column_name: Option<Spanned<String>>,
+    freq_column: String,
+    calc_method: PercentageCalcMethod,
+    head_span: Span,
+) -> Result<PipelineData, ShellError> {
+    let mut sketch = crate::QuantileSketch::new(crate::DEFAULT_SKETCH_BINS);
+
+    match column_name {
+        None => {
+            for v in input.into_iter() {
+                match v {
+                    // Propagate existing errors.
+                    Value::Error { error } => return Err(*error),
+                    _ => match numeric_value(&v) {
+                        Some(val) => sketch.push(val),
+                        None => {
+                            let t = v.get_type();
+                            return Err(ShellError::UnsupportedInput(
+                                "--approx only supports numbers, filesizes, and durations."
+                                    .to_string(),
+                                format!("input type: {t:?}"),
+                                head_span,
+                                v.expect_span(),
+                            ));
+                        }
+                    },
+                }
+            }
+        }
+        Some(ref col) => {
+            // same skipping rules as the exact path: non-records and records
+            // without the column (or with a non-numeric value in it) are skipped
+            let col_name = &col.item;
+            for v in input.into_iter() {
+                match v {
+                    Value::Record { cols, vals, .. } => {
+                        for (c, v) in iter::zip(cols.iter(), vals) {
+                            if c == col_name {
+                                if let Some(val) = numeric_value(&v) {
+                                    sketch.push(val);
+                                }
+                            }
+                        }
+                    }
+                    // Propagate existing errors.
+                    Value::Error { error } => return Err(*error),
+                    _ => continue,
+                }
+            }
+
+            if sketch.is_empty() {
+                return Err(ShellError::CantFindColumn {
+                    col_name: col_name.clone(),
+                    span: head_span,
+                    src_span: col.span,
+                });
+            }
+        }
+    }
+
+    let value_column_name = column_name
+        .map(|x| x.item)
+        .unwrap_or_else(|| "value".to_string());
+    let result_cols = vec![
+        value_column_name,
+        "count".to_string(),
+        "quantile".to_string(),
+        "percentage".to_string(),
+        freq_column,
+    ];
+    let total_cnt = sketch.count();
+
+    // the sketch keeps repeated values in separate bins while under budget;
+    // coalesce neighbors with the same mean so each value gets one row
+    let mut bins: Vec<(f64, u64)> = Vec::with_capacity(sketch.bins().len());
+    for (mean, weight) in sketch.bins() {
+        match bins.last_mut() {
+            Some((last_mean, last_weight)) if last_mean == mean => *last_weight += weight,
+            _ => bins.push((*mean, *weight)),
+        }
+    }
+    let max_cnt = bins.iter().map(|(_, w)| *w).max().unwrap_or(0);
+
+    const MAX_FREQ_COUNT: f64 = 100.0;
+    let mut result = Vec::with_capacity(bins.len());
+    // bins are already sorted by their mean, so rows come out in value order
+    for (mean, weight) in &bins {
+        let quantile = match calc_method {
+            PercentageCalcMethod::Normalize => *weight as f64 / total_cnt as f64,
+            PercentageCalcMethod::Relative => *weight as f64 / max_cnt as f64,
+        };
+
+        let percentage = format!("{:.2}%", quantile * 100_f64);
+        let freq = "*".repeat((MAX_FREQ_COUNT * quantile).floor() as usize);
+
+        result.push(Value::Record {
+            cols: Arc::new(result_cols.clone()),
+            vals: vec![
+                Value::Float {
+                    val: *mean,
+                    span: head_span,
+                },
+                Value::Int {
+                    val: *weight as i64,
+                    span: head_span,
+                },
+                Value::Float {
+                    val: quantile,
+                    span: head_span,
+                },
+                Value::String {
+                    val: percentage,
+                    span: head_span,
+                },
+                Value::String {
+                    val: freq,
+                    span: head_span,
+                },
+            ],
+            span: head_span,
+        });
+    }
+
+    Ok(Value::List {
+        vals: result,
+        span: head_span,
+    }
+    .into_pipeline_data())
+}
+
 fn histogram_impl(
     inputs: Vec<HashableValue>,
     value_column_name: &str,
@@ -240,16 +397,19 @@ fn histogram_impl(
 ) -> PipelineData {
     // here we can make sure that inputs is not empty, and every elements
     // is a simple val and ok to make count.
-    let mut counter = HashMap::new();
-    let mut max_cnt = 0;
     let total_cnt = inputs.len();
-    for i in inputs {
-        let new_cnt = *counter.get(&i).unwrap_or(&0) + 1;
-        counter.insert(i, new_cnt);
-        if new_cnt > max_cnt {
-            max_cnt = new_cnt;
-        }
-    }
+    // Count occurrences partitioned by value hash, in parallel for large inputs
+    let partitions = crate::fold_partitioned(
+        inputs.into_iter().map(|i| (i, ())).collect(),
+        |counter: &mut HashMap<HashableValue, i64>, value, ()| {
+            *counter.entry(value).or_insert(0) += 1;
+        },
+    );
+    let counter: HashMap<HashableValue, i64> = partitions
+        .into_iter()
+        .flat_map(HashMap::into_iter)
+        .collect();
+    let max_cnt = counter.values().copied().max().unwrap_or(0);
 
     let mut result = vec![];
     let result_cols = vec![
@@ -272,7 +432,7 @@ fn histogram_impl(
         result.push((
             count, // attach count first for easily sorting.
             Value::Record {
-                cols: result_cols.clone(),
+                cols: Arc::new(result_cols.clone()),
                 vals: vec![
                     val.into_value(),
                     Value::Int { val: count, span },
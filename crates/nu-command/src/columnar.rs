@@ -0,0 +1,234 @@
+use nu_protocol::ast::{CellPath, PathMember};
+use nu_protocol::{Span, Value};
+use std::sync::Arc;
+
+// This module holds a columnar representation for homogeneous tables: rows
+// that all share the same record schema are transposed into one Vec per
+// column. Column-oriented operations (select, per-column math) then work on
+// whole columns instead of doing a per-row, per-column lookup, and rows are
+// only materialized back on demand.
+
+/// A table stored column-major. Built from rows whose records all share the
+/// same columns in the same order; anything else stays row-oriented.
+pub struct ColumnarTable {
+    cols: Arc<Vec<String>>,
+    columns: Vec<Vec<Value>>,
+    rows: usize,
+    span: Span,
+}
+
+impl ColumnarTable {
+    /// Transpose rows into columns. Every row must be a record with exactly
+    /// the same columns in the same order; otherwise the rows are handed back
+    /// untouched so the caller can fall back to the row-by-row path.
+    pub fn try_from_rows(rows: Vec<Value>, span: Span) -> Result<ColumnarTable, Vec<Value>> {
+        let cols = match rows.first() {
+            Some(Value::Record { cols, .. }) => cols.clone(),
+            _ => return Err(rows),
+        };
+
+        let homogeneous = rows.iter().all(|row| match row {
+            Value::Record { cols: row_cols, .. } => {
+                Arc::ptr_eq(row_cols, &cols) || **row_cols == *cols
+            }
+            _ => false,
+        });
+        if !homogeneous {
+            return Err(rows);
+        }
+
+        let row_count = rows.len();
+        let mut columns: Vec<Vec<Value>> =
+            cols.iter().map(|_| Vec::with_capacity(row_count)).collect();
+        for row in rows {
+            if let Value::Record { vals, .. } = row {
+                for (column, val) in columns.iter_mut().zip(vals) {
+                    column.push(val);
+                }
+            }
+        }
+
+        Ok(ColumnarTable {
+            cols,
+            columns,
+            rows: row_count,
+            span,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0
+    }
+
+    /// Borrow a whole column by name
+    pub fn column(&self, name: &str) -> Option<&[Value]> {
+        let idx = self.cols.iter().position(|col| col == name)?;
+        Some(&self.columns[idx])
+    }
+
+    /// Iterate over `(column name, column values)` pairs, consuming the table
+    pub fn into_columns(self) -> impl Iterator<Item = (String, Vec<Value>)> {
+        let cols = self.cols;
+        cols.iter()
+            .cloned()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .zip(self.columns)
+    }
+
+    /// Lazily materialize the rows back into records. The produced records
+    /// all share one column-name allocation.
+    pub fn into_rows(self) -> ColumnarRows {
+        ColumnarRows {
+            cols: self.cols,
+            columns: self.columns.into_iter().map(Vec::into_iter).collect(),
+            remaining: self.rows,
+            span: self.span,
+        }
+    }
+}
+
+/// Row iterator over a consumed [`ColumnarTable`]
+pub struct ColumnarRows {
+    cols: Arc<Vec<String>>,
+    columns: Vec<std::vec::IntoIter<Value>>,
+    remaining: usize,
+    span: Span,
+}
+
+impl Iterator for ColumnarRows {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let vals = self.columns.iter_mut().flat_map(Iterator::next).collect();
+        Some(Value::Record {
+            cols: self.cols.clone(),
+            vals,
+            span: self.span,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+/// Select whole columns out of a homogeneous table without touching each row.
+///
+/// Takes the columnar route only when every path is a plain column name and
+/// every non-optional column exists; in all other cases (row-oriented input,
+/// nested paths, a missing column that should error) the rows are returned so
+/// the caller's row-by-row path can run and report errors as before.
+pub fn columnar_select(
+    rows: Vec<Value>,
+    paths: &[CellPath],
+    span: Span,
+) -> Result<ColumnarRows, Vec<Value>> {
+    let mut names = Vec::with_capacity(paths.len());
+    for path in paths {
+        match &path.members[..] {
+            [PathMember::String { val, optional, .. }] => names.push((val.clone(), *optional)),
+            _ => return Err(rows),
+        }
+    }
+
+    let table = ColumnarTable::try_from_rows(rows, span)?;
+
+    if names
+        .iter()
+        .any(|(name, optional)| !optional && table.column(name).is_none())
+    {
+        return Err(table.into_rows().collect());
+    }
+
+    let row_count = table.len();
+    let ColumnarTable { cols, columns, .. } = table;
+    let mut source: Vec<Option<Vec<Value>>> = columns.into_iter().map(Some).collect();
+    let mut picked = Vec::with_capacity(names.len());
+    let mut out_cols = Vec::with_capacity(names.len());
+    let mut out_columns: Vec<Vec<Value>> = Vec::with_capacity(names.len());
+
+    for (name, _) in &names {
+        match cols.iter().position(|col| col == name) {
+            Some(idx) => {
+                let column = match source[idx].take() {
+                    Some(column) => column,
+                    // the same column was selected twice; copy the first pick
+                    None => {
+                        let first = picked
+                            .iter()
+                            .position(|picked_name| picked_name == name)
+                            .expect("internal error: duplicate column not yet selected");
+                        out_columns[first].clone()
+                    }
+                };
+                out_columns.push(column);
+            }
+            None => {
+                // checked above: only optional columns can be missing
+                out_columns.push(vec![Value::nothing(span); row_count]);
+            }
+        }
+        picked.push(name.clone());
+        // match the output naming of the row-by-row path
+        out_cols.push(name.replace('.', "_"));
+    }
+
+    Ok(ColumnarTable {
+        cols: Arc::new(out_cols),
+        columns: out_columns,
+        rows: row_count,
+        span,
+    }
+    .into_rows())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(cols: &[&str], vals: Vec<Value>) -> Value {
+        Value::Record {
+            cols: Arc::new(cols.iter().map(|s| s.to_string()).collect()),
+            vals,
+            span: Span::test_data(),
+        }
+    }
+
+    #[test]
+    fn transpose_and_materialize_round_trips() {
+        let rows = vec![
+            row(&["a", "b"], vec![Value::test_int(1), Value::test_int(2)]),
+            row(&["a", "b"], vec![Value::test_int(3), Value::test_int(4)]),
+        ];
+        let expected = rows.clone();
+
+        let table =
+            ColumnarTable::try_from_rows(rows, Span::test_data()).expect("rows share a schema");
+        assert_eq!(table.len(), 2);
+        assert_eq!(
+            table.column("b"),
+            Some(&[Value::test_int(2), Value::test_int(4)][..])
+        );
+        assert_eq!(table.into_rows().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn mixed_schemas_fall_back() {
+        let rows = vec![
+            row(&["a", "b"], vec![Value::test_int(1), Value::test_int(2)]),
+            row(&["a"], vec![Value::test_int(3)]),
+        ];
+
+        assert!(ColumnarTable::try_from_rows(rows, Span::test_data()).is_err());
+    }
+}
@@ -5,6 +5,7 @@ use nu_protocol::{
     engine::{Command, EngineState, Stack},
     Category, Example, PipelineData, ShellError, Signature, Span, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Fmt;
@@ -33,7 +34,7 @@ impl Command for Fmt {
             description: "Get a record containing multiple formats for the number 42",
             example: "42 | fmt",
             result: Some(Value::Record {
-                cols: vec![
+                cols: Arc::new(vec![
                     "binary".into(),
                     "debug".into(),
                     "display".into(),
@@ -42,7 +43,7 @@ impl Command for Fmt {
                     "octal".into(),
                     "upperexp".into(),
                     "upperhex".into(),
-                ],
+                ]),
                 vals: vec![
                     Value::test_string("0b101010"),
                     Value::test_string("42"),
@@ -128,7 +129,11 @@ fn fmt_it(num: i64, span: Span) -> Value {
     cols.push("upperhex".into());
     vals.push(Value::string(format!("{num:#X}"), span));
 
-    Value::Record { cols, vals, span }
+    Value::Record {
+        cols: Arc::new(cols),
+        vals,
+        span,
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,141 @@
+use crate::input_handler::{operate, CellPathOnlyArgs};
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::{Call, CellPath},
+    engine::{Command, EngineState, Stack},
+    BigInt, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "into bigint"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into bigint")
+            .input_output_types(vec![
+                (Type::Int, Type::BigInt),
+                (Type::String, Type::BigInt),
+                (Type::BigInt, Type::BigInt),
+            ])
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "for a data structure input, convert data at the given cell paths",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Convert value to a big integer."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Big integers have unlimited range, so arithmetic on them never overflows. Regular integer arithmetic promotes to bigint automatically when a result would overflow."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["convert", "number", "integer", "arbitrary", "precision"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let args = CellPathOnlyArgs::from(cell_paths);
+        operate(action, args, input, call.head, engine_state.ctrlc.clone())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert an integer to a bigint",
+                example: "10 | into bigint",
+                result: Some(Value::big_int(BigInt::from(10), Span::test_data())),
+            },
+            Example {
+                description: "Leave an existing bigint as-is",
+                example: "10 | into bigint | into bigint",
+                result: Some(Value::big_int(BigInt::from(10), Span::test_data())),
+            },
+            Example {
+                description: "Convert a string beyond the integer range to a bigint",
+                example: "'170141183460469231731687303715884105727' | into bigint",
+                result: Some(Value::big_int(
+                    "170141183460469231731687303715884105727"
+                        .parse()
+                        .expect("valid bigint literal"),
+                    Span::test_data(),
+                )),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, _args: &CellPathOnlyArgs, head: Span) -> Value {
+    match input {
+        Value::Int { val, .. } => Value::big_int(BigInt::from(*val), head),
+        Value::BigInt { val, .. } => Value::big_int(val.clone(), head),
+        Value::String { val, span } => match val.trim().parse::<BigInt>() {
+            Ok(x) => Value::big_int(x, head),
+            Err(reason) => Value::Error {
+                error: Box::new(ShellError::CantConvert {
+                    to_type: "bigint".to_string(),
+                    from_type: reason.to_string(),
+                    span: *span,
+                    help: None,
+                }),
+            },
+        },
+        // Propagate errors by explicitly matching them before the final case.
+        Value::Error { .. } => input.clone(),
+        other => Value::Error {
+            error: Box::new(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string, integer or bigint".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.expect_span(),
+            }),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn int_to_bigint() {
+        let actual = action(
+            &Value::test_int(42),
+            &CellPathOnlyArgs::from(vec![]),
+            Span::test_data(),
+        );
+
+        assert_eq!(actual, Value::big_int(BigInt::from(42), Span::test_data()));
+    }
+
+    #[test]
+    fn communicates_parsing_error_given_an_invalid_bigintlike_string() {
+        let actual = action(
+            &Value::test_string("12abc"),
+            &CellPathOnlyArgs::from(vec![]),
+            Span::test_data(),
+        );
+
+        assert_eq!(actual.get_type(), Type::Error);
+    }
+}
@@ -5,6 +5,7 @@ use nu_protocol::{
     engine::{Command, EngineState, Stack},
     Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -58,27 +59,27 @@ impl Command for SubCommand {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["value".to_string()],
+                            cols: Arc::new(vec!["value".to_string()]),
                             vals: vec![Value::boolean(false, span)],
                             span,
                         },
                         Value::Record {
-                            cols: vec!["value".to_string()],
+                            cols: Arc::new(vec!["value".to_string()]),
                             vals: vec![Value::boolean(true, span)],
                             span,
                         },
                         Value::Record {
-                            cols: vec!["value".to_string()],
+                            cols: Arc::new(vec!["value".to_string()]),
                             vals: vec![Value::boolean(false, span)],
                             span,
                         },
                         Value::Record {
-                            cols: vec!["value".to_string()],
+                            cols: Arc::new(vec!["value".to_string()]),
                             vals: vec![Value::boolean(true, span)],
                             span,
                         },
                         Value::Record {
-                            cols: vec!["value".to_string()],
+                            cols: Arc::new(vec!["value".to_string()]),
                             vals: vec![Value::boolean(true, span)],
                             span,
                         },
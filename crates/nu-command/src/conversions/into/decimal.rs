@@ -5,6 +5,7 @@ use nu_protocol::{
     engine::{Command, EngineState, Stack},
     Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -54,7 +55,7 @@ impl Command for SubCommand {
                 example: "[[num]; ['5.01']] | into decimal num",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["num".to_string()],
+                        cols: Arc::new(vec!["num".to_string()]),
                         vals: vec![Value::test_float(5.01)],
                         span: Span::test_data(),
                     }],
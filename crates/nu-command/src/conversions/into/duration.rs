@@ -6,6 +6,7 @@ use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Unit,
     Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -70,7 +71,7 @@ impl Command for SubCommand {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["value".to_string()],
+                            cols: Arc::new(vec!["value".to_string()]),
                             vals: vec![Value::Duration {
                                 val: 1000 * 1000 * 1000,
                                 span,
@@ -78,7 +79,7 @@ impl Command for SubCommand {
                             span,
                         },
                         Value::Record {
-                            cols: vec!["value".to_string()],
+                            cols: Arc::new(vec!["value".to_string()]),
                             vals: vec![Value::Duration {
                                 val: 2 * 60 * 1000 * 1000 * 1000,
                                 span,
@@ -86,7 +87,7 @@ impl Command for SubCommand {
                             span,
                         },
                         Value::Record {
-                            cols: vec!["value".to_string()],
+                            cols: Arc::new(vec!["value".to_string()]),
                             vals: vec![Value::Duration {
                                 val: 3 * 60 * 60 * 1000 * 1000 * 1000,
                                 span,
@@ -94,7 +95,7 @@ impl Command for SubCommand {
                             span,
                         },
                         Value::Record {
-                            cols: vec!["value".to_string()],
+                            cols: Arc::new(vec!["value".to_string()]),
                             vals: vec![Value::Duration {
                                 val: 4 * 24 * 60 * 60 * 1000 * 1000 * 1000,
                                 span,
@@ -102,7 +103,7 @@ impl Command for SubCommand {
                             span,
                         },
                         Value::Record {
-                            cols: vec!["value".to_string()],
+                            cols: Arc::new(vec!["value".to_string()]),
                             vals: vec![Value::Duration {
                                 val: 5 * 7 * 24 * 60 * 60 * 1000 * 1000 * 1000,
                                 span,
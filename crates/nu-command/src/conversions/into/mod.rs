@@ -1,3 +1,4 @@
+mod bigint;
 mod binary;
 mod bool;
 mod command;
@@ -11,6 +12,7 @@ mod string;
 
 pub use self::bool::SubCommand as IntoBool;
 pub use self::filesize::SubCommand as IntoFilesize;
+pub use bigint::SubCommand as IntoBigInt;
 pub use binary::SubCommand as IntoBinary;
 pub use command::Into;
 pub use datetime::SubCommand as IntoDatetime;
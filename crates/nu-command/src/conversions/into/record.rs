@@ -5,6 +5,7 @@ use nu_protocol::{
     engine::{Command, EngineState, Stack},
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
 };
+use std::sync::Arc;
 #[derive(Clone)]
 pub struct SubCommand;
 
@@ -51,7 +52,7 @@ impl Command for SubCommand {
                 description: "Convert from one row table to record",
                 example: "[[value]; [false]] | into record",
                 result: Some(Value::Record {
-                    cols: vec!["value".to_string()],
+                    cols: Arc::new(vec!["value".to_string()]),
                     vals: vec![Value::boolean(false, span)],
                     span,
                 }),
@@ -60,7 +61,7 @@ impl Command for SubCommand {
                 description: "Convert from list to record",
                 example: "[1 2 3] | into record",
                 result: Some(Value::Record {
-                    cols: vec!["0".to_string(), "1".to_string(), "2".to_string()],
+                    cols: Arc::new(vec!["0".to_string(), "1".to_string(), "2".to_string()]),
                     vals: vec![
                         Value::Int { val: 1, span },
                         Value::Int { val: 2, span },
@@ -73,7 +74,7 @@ impl Command for SubCommand {
                 description: "Convert from range to record",
                 example: "0..2 | into record",
                 result: Some(Value::Record {
-                    cols: vec!["0".to_string(), "1".to_string(), "2".to_string()],
+                    cols: Arc::new(vec!["0".to_string(), "1".to_string(), "2".to_string()]),
                     vals: vec![
                         Value::Int { val: 0, span },
                         Value::Int { val: 1, span },
@@ -86,13 +87,13 @@ impl Command for SubCommand {
                 description: "convert duration to record",
                 example: "-500day | into record",
                 result: Some(Value::Record {
-                    cols: vec![
+                    cols: Arc::new(vec![
                         "year".into(),
                         "month".into(),
                         "week".into(),
                         "day".into(),
                         "sign".into(),
-                    ],
+                    ]),
                     vals: vec![
                         Value::Int { val: 1, span },
                         Value::Int { val: 4, span },
@@ -110,7 +111,7 @@ impl Command for SubCommand {
                 description: "convert record to record",
                 example: "{a: 1, b: 2} | into record",
                 result: Some(Value::Record {
-                    cols: vec!["a".to_string(), "b".to_string()],
+                    cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                     vals: vec![Value::Int { val: 1, span }, Value::Int { val: 2, span }],
                     span,
                 }),
@@ -119,7 +120,7 @@ impl Command for SubCommand {
                 description: "convert date to record",
                 example: "2020-04-12T22:10:57+02:00 | into record",
                 result: Some(Value::Record {
-                    cols: vec![
+                    cols: Arc::new(vec![
                         "year".into(),
                         "month".into(),
                         "day".into(),
@@ -127,7 +128,7 @@ impl Command for SubCommand {
                         "minute".into(),
                         "second".into(),
                         "timezone".into(),
-                    ],
+                    ]),
                     vals: vec![
                         Value::Int { val: 2020, span },
                         Value::Int { val: 4, span },
@@ -167,7 +168,7 @@ fn into_record(
                     values.push(val);
                 }
                 Value::Record {
-                    cols,
+                    cols: Arc::new(cols),
                     vals: values,
                     span,
                 }
@@ -180,7 +181,11 @@ fn into_record(
                 cols.push(format!("{idx}"));
                 vals.push(val);
             }
-            Value::Record { cols, vals, span }
+            Value::Record {
+                cols: Arc::new(cols),
+                vals,
+                span,
+            }
         }
         Value::Record { cols, vals, span } => Value::Record { cols, vals, span },
         Value::Error { .. } => input,
@@ -238,7 +243,11 @@ fn parse_date_into_record(date: Result<DateTime<FixedOffset>, Value>, span: Span
                     span,
                 },
             ];
-            Value::Record { cols, vals, span }
+            Value::Record {
+                cols: Arc::new(cols),
+                vals,
+                span,
+            }
         }
         Err(e) => e,
     }
@@ -278,7 +287,11 @@ fn parse_duration_into_record(duration: i64, span: Span) -> Value {
         span,
     });
 
-    Value::Record { cols, vals, span }
+    Value::Record {
+        cols: Arc::new(cols),
+        vals,
+        span,
+    }
 }
 
 #[cfg(test)]
@@ -233,6 +233,8 @@ fn nu_value_to_string(value: Value, separator: &str) -> String {
         Value::Bool { val, .. } => val.to_string(),
         Value::Int { val, .. } => val.to_string(),
         Value::Float { val, .. } => val.to_string(),
+        Value::Decimal { val, .. } => val.to_string(),
+        Value::BigInt { val, .. } => val.to_string(),
         Value::Filesize { val, .. } => val.to_string(),
         Value::Duration { val, .. } => val.to_string(),
         Value::Date { val, .. } => val.to_string(),
@@ -306,7 +308,7 @@ fn get_columns_with_sqlite_types(input: &[Value]) -> Vec<(String, String)> {
         // );
 
         if let Value::Record { cols, vals, .. } = item {
-            for (c, v) in iter::zip(cols, vals) {
+            for (c, v) in iter::zip(cols.iter(), vals) {
                 if !columns.iter().any(|(name, _)| name == c) {
                     columns.push((
                         c.to_string(),
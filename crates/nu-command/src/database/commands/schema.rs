@@ -6,6 +6,7 @@ use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, Type, Value,
 };
 use rusqlite::Connection;
+use std::sync::Arc;
 #[derive(Clone)]
 pub struct SchemaDb;
 
@@ -97,12 +98,16 @@ impl Command for SchemaDb {
             });
 
             table_names.push(table.name);
-            table_values.push(Value::Record { cols, vals, span });
+            table_values.push(Value::Record {
+                cols: Arc::new(cols),
+                vals,
+                span,
+            });
         }
 
         cols.push("tables".into());
         vals.push(Value::Record {
-            cols: table_names,
+            cols: Arc::new(table_names),
             vals: table_values,
             span,
         });
@@ -110,7 +115,11 @@ impl Command for SchemaDb {
         // TODO: add views and triggers
 
         Ok(PipelineData::Value(
-            Value::Record { cols, vals, span },
+            Value::Record {
+                cols: Arc::new(cols),
+                vals,
+                span,
+            },
             None,
         ))
     }
@@ -156,7 +165,7 @@ fn get_table_columns(
             col_values.push(Value::string(v.clone(), span));
         }
         column_info.push(Value::Record {
-            cols: col_names.clone(),
+            cols: Arc::new(col_names.clone()),
             vals: col_values.clone(),
             span,
         });
@@ -191,7 +200,7 @@ fn get_table_constraints(
             con_vals.push(Value::string(v.clone(), span));
         }
         constraint_info.push(Value::Record {
-            cols: con_cols.clone(),
+            cols: Arc::new(con_cols.clone()),
             vals: con_vals.clone(),
             span,
         });
@@ -226,7 +235,7 @@ fn get_table_foreign_keys(
             fk_vals.push(Value::string(v.clone(), span));
         }
         foreign_key_info.push(Value::Record {
-            cols: fk_cols.clone(),
+            cols: Arc::new(fk_cols.clone()),
             vals: fk_vals.clone(),
             span,
         });
@@ -261,7 +270,7 @@ fn get_table_indexes(
             idx_vals.push(Value::string(v.clone(), span));
         }
         index_info.push(Value::Record {
-            cols: idx_cols.clone(),
+            cols: Arc::new(idx_cols.clone()),
             vals: idx_vals.clone(),
             span,
         });
@@ -432,7 +432,7 @@ fn read_entire_sqlite_db(
     }
 
     Ok(Value::Record {
-        cols: table_names,
+        cols: Arc::new(table_names),
         vals: tables,
         span: call_span,
     })
@@ -447,7 +447,7 @@ pub fn convert_sqlite_row_to_nu_value(row: &Row, span: Span, column_names: Vec<S
     }
 
     Value::Record {
-        cols: column_names,
+        cols: Arc::new(column_names),
         vals,
         span,
     }
@@ -489,7 +489,7 @@ mod test {
         let converted_db = read_entire_sqlite_db(db, Span::test_data(), None).unwrap();
 
         let expected = Value::Record {
-            cols: vec![],
+            cols: Arc::new(vec![]),
             vals: vec![],
             span: Span::test_data(),
         };
@@ -513,7 +513,7 @@ mod test {
         let converted_db = read_entire_sqlite_db(db, Span::test_data(), None).unwrap();
 
         let expected = Value::Record {
-            cols: vec!["person".to_string()],
+            cols: Arc::new(vec!["person".to_string()]),
             vals: vec![Value::List {
                 vals: vec![],
                 span: Span::test_data(),
@@ -547,16 +547,16 @@ mod test {
         let converted_db = read_entire_sqlite_db(db, span, None).unwrap();
 
         let expected = Value::Record {
-            cols: vec!["item".to_string()],
+            cols: Arc::new(vec!["item".to_string()]),
             vals: vec![Value::List {
                 vals: vec![
                     Value::Record {
-                        cols: vec!["id".to_string(), "name".to_string()],
+                        cols: Arc::new(vec!["id".to_string(), "name".to_string()]),
                         vals: vec![Value::Int { val: 123, span }, Value::Nothing { span }],
                         span,
                     },
                     Value::Record {
-                        cols: vec!["id".to_string(), "name".to_string()],
+                        cols: Arc::new(vec!["id".to_string(), "name".to_string()]),
                         vals: vec![
                             Value::Int { val: 456, span },
                             Value::String {
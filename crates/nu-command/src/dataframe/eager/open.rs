@@ -295,7 +295,14 @@ fn from_json(
         })?
         .into();
 
-    Ok(df.into_value(call.head))
+    if call.has_flag("lazy") {
+        // polars has no json scan, so the file is read eagerly; converting
+        // still lets the rest of the pipeline plan its operations lazily
+        let lazy = NuLazyFrame::from_dataframe(df);
+        lazy.into_value(call.head)
+    } else {
+        Ok(df.into_value(call.head))
+    }
 }
 
 fn from_csv(
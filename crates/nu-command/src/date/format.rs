@@ -11,6 +11,7 @@ use nu_utils::locale::get_system_locale_string;
 use std::fmt::{Display, Write};
 
 use super::utils::parse_date_from_string;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -419,7 +420,7 @@ pub(crate) fn generate_strftime_list(head: Span, show_parse_only_formats: bool)
     let mut records = specifications
         .iter()
         .map(|s| Value::Record {
-            cols: column_names.clone(),
+            cols: Arc::new(column_names.clone()),
             vals: vec![
                 Value::string(s.spec, head),
                 Value::string(now.format(s.spec).to_string(), head),
@@ -440,7 +441,7 @@ pub(crate) fn generate_strftime_list(head: Span, show_parse_only_formats: bool)
             .to_string();
 
         records.push(Value::Record {
-            cols: column_names,
+            cols: Arc::new(column_names),
             vals: vec![
                 Value::string("%#z", head),
                 Value::String {
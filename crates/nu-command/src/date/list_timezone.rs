@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
     Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -45,7 +46,11 @@ impl Command for SubCommand {
                     val: x.name().to_string(),
                     span,
                 }];
-                Value::Record { cols, vals, span }
+                Value::Record {
+                    cols: Arc::new(cols),
+                    vals,
+                    span,
+                }
             })
             .into_pipeline_data(engine_state.ctrlc.clone()))
     }
@@ -56,7 +61,7 @@ impl Command for SubCommand {
             description: "Show timezone(s) that contains 'Shanghai'",
             result: Some(Value::List {
                 vals: vec![Value::Record {
-                    cols: vec!["timezone".into()],
+                    cols: Arc::new(vec!["timezone".into()]),
                     vals: vec![Value::test_string("Asia/Shanghai")],
                     span: Span::test_data(),
                 }],
@@ -1,4 +1,4 @@
-use chrono::Local;
+use chrono::{FixedOffset, Local, Utc};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
@@ -15,6 +15,11 @@ impl Command for SubCommand {
     fn signature(&self) -> Signature {
         Signature::build("date now")
             .input_output_types(vec![(Type::Nothing, Type::Date)])
+            .switch(
+                "utc",
+                "return the current date in UTC instead of the local time zone",
+                Some('u'),
+            )
             .category(Category::Date)
     }
 
@@ -34,12 +39,13 @@ impl Command for SubCommand {
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
-        let dt = Local::now();
-        Ok(Value::Date {
-            val: dt.with_timezone(dt.offset()),
-            span: head,
-        }
-        .into_pipeline_data())
+        let val = if call.has_flag("utc") {
+            Utc::now().with_timezone(&FixedOffset::east_opt(0).expect("utc is a valid offset"))
+        } else {
+            let dt = Local::now();
+            dt.with_timezone(dt.offset())
+        };
+        Ok(Value::Date { val, span: head }.into_pipeline_data())
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -64,6 +70,11 @@ impl Command for SubCommand {
                 example: r#"date now | debug"#,
                 result: None,
             },
+            Example {
+                description: "Get the current date in UTC",
+                example: r#"date now --utc"#,
+                result: None,
+            },
         ]
     }
 }
@@ -7,6 +7,7 @@ use nu_protocol::{
     Signature, Span, Value,
 };
 use nu_protocol::{ShellError, Type};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -78,7 +79,11 @@ impl Command for SubCommand {
                     span,
                 },
             ];
-            Some(Value::Record { cols, vals, span })
+            Some(Value::Record {
+                cols: Arc::new(cols),
+                vals,
+                span,
+            })
         };
 
         vec![
@@ -131,7 +136,7 @@ fn parse_date_into_table(date: Result<DateTime<FixedOffset>, Value>, head: Span)
                 Value::string(x.offset().to_string(), head),
             ];
             Value::Record {
-                cols,
+                cols: Arc::new(cols),
                 vals,
                 span: head,
             }
@@ -7,6 +7,7 @@ use nu_protocol::{
     Signature, Span, Value,
 };
 use nu_protocol::{ShellError, Type};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -76,7 +77,11 @@ impl Command for SubCommand {
                 },
             ];
             Some(Value::List {
-                vals: vec![Value::Record { cols, vals, span }],
+                vals: vec![Value::Record {
+                    cols: Arc::new(cols),
+                    vals,
+                    span,
+                }],
                 span,
             })
         };
@@ -134,7 +139,7 @@ fn parse_date_into_table(date: Result<DateTime<FixedOffset>, Value>, head: Span)
             ];
             Value::List {
                 vals: vec![Value::Record {
-                    cols,
+                    cols: Arc::new(cols),
                     vals,
                     span: head,
                 }],
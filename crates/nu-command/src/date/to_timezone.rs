@@ -20,7 +20,12 @@ impl Command for SubCommand {
 
     fn signature(&self) -> Signature {
         Signature::build("date to-timezone")
-            .input_output_types(vec![(Type::Date, Type::Date), (Type::String, Type::Date)])
+            .input_output_types(vec![
+                (Type::Date, Type::Date),
+                (Type::String, Type::Date),
+                (Type::Table(vec![]), Type::Table(vec![])),
+                (Type::Record(vec![]), Type::Record(vec![])),
+            ])
             .allow_variants_without_examples(true) // https://github.com/nushell/nushell/issues/7032
             .required("time zone", SyntaxShape::String, "time zone description")
             .category(Category::Date)
@@ -105,6 +110,11 @@ impl Command for SubCommand {
             //     example: r#""2020-10-10 10:00:00 +02:00" | into datetime | date to-timezone "+0500""#,
             //     result: example_result_1(),
             // },
+            Example {
+                description: "Convert all date columns of a table",
+                example: "ls | date to-timezone UTC",
+                result: None,
+            },
         ]
     }
 }
@@ -123,6 +133,26 @@ fn helper(value: Value, head: Span, timezone: &Spanned<String>) -> Value {
             }
         }
 
+        // For tables and records, convert every date cell in place so the
+        // command can be applied to e.g. the output of `ls` directly.
+        Value::Record { cols, vals, span } => Value::Record {
+            cols,
+            vals: vals
+                .into_iter()
+                .map(|value| match value {
+                    Value::Date { .. } => helper(value, head, timezone),
+                    other => other,
+                })
+                .collect(),
+            span,
+        },
+        Value::List { vals, span } => Value::List {
+            vals: vals
+                .into_iter()
+                .map(|value| helper(value, head, timezone))
+                .collect(),
+            span,
+        },
         Value::Nothing { span: _ } => {
             let dt = Local::now();
             _to_timezone(dt.with_timezone(dt.offset()), timezone, head)
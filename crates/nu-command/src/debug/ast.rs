@@ -6,6 +6,7 @@ use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
     Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Ast;
@@ -54,7 +55,7 @@ impl Command for Ast {
             span: pipeline.span,
         };
         let output_record = Value::Record {
-            cols: vec!["block".to_string(), "error".to_string()],
+            cols: Arc::new(vec!["block".to_string(), "error".to_string()]),
             vals: vec![block_value, error_value],
             span: pipeline.span,
         };
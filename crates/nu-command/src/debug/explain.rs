@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
     SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Explain;
@@ -92,14 +93,14 @@ pub fn get_pipeline_elements(
             let command_name = command_name;
 
             let rec = Value::Record {
-                cols: vec![
+                cols: Arc::new(vec![
                     "cmd_index".to_string(),
                     "cmd_name".to_string(),
                     "type".to_string(),
                     "cmd_args".to_string(),
                     "span_start".to_string(),
                     "span_end".to_string(),
-                ],
+                ]),
                 vals: vec![
                     Value::string(index, span),
                     Value::string(command_name, value_span),
@@ -134,13 +135,13 @@ fn get_arguments(engine_state: &EngineState, stack: &mut Stack, call: Call) -> V
                 let arg_value_name_span_end = name.span.end as i64;
 
                 let rec = Value::Record {
-                    cols: vec![
+                    cols: Arc::new(vec![
                         "arg_type".to_string(),
                         "name".to_string(),
                         "type".to_string(),
                         "span_start".to_string(),
                         "span_end".to_string(),
-                    ],
+                    ]),
                     vals: vec![
                         Value::string(arg_type, span),
                         Value::string(arg_value_name, name.span),
@@ -159,13 +160,13 @@ fn get_arguments(engine_state: &EngineState, stack: &mut Stack, call: Call) -> V
                     let arg_value_name_span_end = shortcut.span.end as i64;
 
                     let rec = Value::Record {
-                        cols: vec![
+                        cols: Arc::new(vec![
                             "arg_type".to_string(),
                             "name".to_string(),
                             "type".to_string(),
                             "span_start".to_string(),
                             "span_end".to_string(),
-                        ],
+                        ]),
                         vals: vec![
                             Value::string(arg_type, span),
                             Value::string(arg_value_name, shortcut.span),
@@ -190,13 +191,13 @@ fn get_arguments(engine_state: &EngineState, stack: &mut Stack, call: Call) -> V
                     let arg_value_name_span_end = evaled_span.end as i64;
 
                     let rec = Value::Record {
-                        cols: vec![
+                        cols: Arc::new(vec![
                             "arg_type".to_string(),
                             "name".to_string(),
                             "type".to_string(),
                             "span_start".to_string(),
                             "span_end".to_string(),
-                        ],
+                        ]),
                         vals: vec![
                             Value::string(arg_type, span),
                             Value::string(arg_value_name, expression.span),
@@ -220,13 +221,13 @@ fn get_arguments(engine_state: &EngineState, stack: &mut Stack, call: Call) -> V
                 let arg_value_name_span_end = evaled_span.end as i64;
 
                 let rec = Value::Record {
-                    cols: vec![
+                    cols: Arc::new(vec![
                         "arg_type".to_string(),
                         "name".to_string(),
                         "type".to_string(),
                         "span_start".to_string(),
                         "span_end".to_string(),
-                    ],
+                    ]),
                     vals: vec![
                         Value::string(arg_type, span),
                         Value::string(arg_value_name, inner_expr.span),
@@ -248,13 +249,13 @@ fn get_arguments(engine_state: &EngineState, stack: &mut Stack, call: Call) -> V
                 let arg_value_name_span_end = evaled_span.end as i64;
 
                 let rec = Value::Record {
-                    cols: vec![
+                    cols: Arc::new(vec![
                         "arg_type".to_string(),
                         "name".to_string(),
                         "type".to_string(),
                         "span_start".to_string(),
                         "span_end".to_string(),
-                    ],
+                    ]),
                     vals: vec![
                         Value::string(arg_type, span),
                         Value::string(arg_value_name, inner_expr.span),
@@ -290,6 +291,8 @@ pub fn debug_string_without_formatting(value: &Value) -> String {
         Value::Bool { val, .. } => val.to_string(),
         Value::Int { val, .. } => val.to_string(),
         Value::Float { val, .. } => val.to_string(),
+        Value::Decimal { val, .. } => val.to_string(),
+        Value::BigInt { val, .. } => val.to_string(),
         Value::Filesize { val, .. } => val.to_string(),
         Value::Duration { val, .. } => val.to_string(),
         Value::Date { val, .. } => format!("{val:?}"),
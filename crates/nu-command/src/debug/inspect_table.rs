@@ -129,7 +129,7 @@ mod util {
     pub fn collect_input(value: Value) -> (Vec<String>, Vec<Vec<String>>) {
         match value {
             Value::Record { cols, vals, .. } => (
-                cols,
+                cols.to_vec(),
                 vec![vals
                     .into_iter()
                     .map(|s| debug_string_without_formatting(&s))
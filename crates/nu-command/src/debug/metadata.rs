@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, DataSource, Example, IntoPipelineData, PipelineData, PipelineMetadata, ShellError,
     Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Metadata;
@@ -99,7 +100,7 @@ impl Command for Metadata {
                 }
 
                 Ok(Value::Record {
-                    cols,
+                    cols: Arc::new(cols),
                     vals,
                     span: head,
                 }
@@ -135,7 +136,7 @@ fn build_metadata_record(
     if let Ok(span) = arg.span() {
         cols.push("span".into());
         vals.push(Value::Record {
-            cols: vec!["start".into(), "end".into()],
+            cols: Arc::new(vec!["start".into(), "end".into()]),
             vals: vec![
                 Value::Int {
                     val: span.start as i64,
@@ -174,7 +175,7 @@ fn build_metadata_record(
     }
 
     Value::Record {
-        cols,
+        cols: Arc::new(cols),
         vals,
         span: head,
     }
@@ -3,8 +3,9 @@ use nu_protocol::ast::Call;
 use nu_protocol::engine::{Closure, Command, EngineState, ProfilingConfig, Stack};
 use nu_protocol::{
     Category, DataSource, Example, IntoPipelineData, PipelineData, PipelineMetadata, Signature,
-    Spanned, SyntaxShape, Type, Value,
+    Span, Spanned, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Profile;
@@ -36,6 +37,16 @@ Current known limitations are:
             )
             .switch("source", "Collect source code in the report", None)
             .switch("values", "Collect values in the report", None)
+            .switch(
+                "summary",
+                "Aggregate the report into one row per pipeline element, sorted by total time",
+                None,
+            )
+            .switch(
+                "folded",
+                "Output folded stack lines (self time in microseconds) for flamegraph tools",
+                None,
+            )
             .named(
                 "max-depth",
                 SyntaxShape::Int,
@@ -70,10 +81,14 @@ Current known limitations are:
             }
         }
 
+        let summary = call.has_flag("summary");
+        let folded = call.has_flag("folded");
+
         stack.profiling_config = ProfilingConfig::new(
             call.get_flag::<i64>(engine_state, &mut stack, "max-depth")?
                 .unwrap_or(1),
-            call.has_flag("source"),
+            // The aggregated outputs label the rows with the element source
+            call.has_flag("source") || summary || folded,
             call.has_flag("values"),
         );
 
@@ -94,7 +109,13 @@ Current known limitations are:
         .metadata()
         .map(|m| *m)
         {
-            Value::list(values, call.head)
+            if folded {
+                Value::string(render_folded(&values), call.head)
+            } else if summary {
+                render_summary(&values, call.head)
+            } else {
+                Value::list(values, call.head)
+            }
         } else {
             Value::nothing(call.head)
         };
@@ -103,11 +124,165 @@ Current known limitations are:
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description:
-                "Profile some code, stepping into the `spam` command and collecting source.",
-            example: r#"def spam [] { "spam" }; profile {|| spam | str length } -d 2 --source"#,
-            result: None,
-        }]
+        vec![
+            Example {
+                description:
+                    "Profile some code, stepping into the `spam` command and collecting source.",
+                example: r#"def spam [] { "spam" }; profile {|| spam | str length } -d 2 --source"#,
+                result: None,
+            },
+            Example {
+                description: "Summarize where the time of a pipeline is spent.",
+                example: r#"profile {|| ls | sort-by size | first 5 } --summary"#,
+                result: None,
+            },
+            Example {
+                description: "Write folded stacks that flamegraph tools can render.",
+                example: r#"profile {|| ls | sort-by size } --folded -d 3 | save profile.folded"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn record_field<'a>(record: &'a Value, field: &str) -> Option<&'a Value> {
+    match record {
+        Value::Record { cols, vals, .. } => {
+            cols.iter().position(|col| col == field).map(|i| &vals[i])
+        }
+        _ => None,
+    }
+}
+
+fn field_int(record: &Value, field: &str) -> i64 {
+    match record_field(record, field) {
+        Some(Value::Int { val, .. }) | Some(Value::Duration { val, .. }) => *val,
+        _ => 0,
+    }
+}
+
+fn field_source(record: &Value) -> String {
+    match record_field(record, "source") {
+        Some(Value::String { val, .. }) => val.split_whitespace().collect::<Vec<_>>().join(" "),
+        _ => "<unknown>".to_string(),
+    }
+}
+
+/// For each profiling record, the time spent in the element itself, without the elements
+/// of the child blocks it stepped into. The records come in parent-before-children order,
+/// so the immediate children of record `i` are the following records one level deeper,
+/// up to the next record at the same or a shallower depth.
+fn self_times(values: &[Value]) -> Vec<i64> {
+    let depths: Vec<i64> = values.iter().map(|val| field_int(val, "depth")).collect();
+    let times: Vec<i64> = values.iter().map(|val| field_int(val, "time")).collect();
+
+    (0..values.len())
+        .map(|i| {
+            let mut child_time = 0;
+            for j in i + 1..values.len() {
+                if depths[j] <= depths[i] {
+                    break;
+                }
+                if depths[j] == depths[i] + 1 {
+                    child_time += times[j];
+                }
+            }
+            (times[i] - child_time).max(0)
+        })
+        .collect()
+}
+
+fn render_folded(values: &[Value]) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut stack: Vec<String> = Vec::new();
+
+    for (value, self_time) in values.iter().zip(self_times(values)) {
+        let depth = field_int(value, "depth").max(1) as usize;
+        stack.truncate(depth - 1);
+        stack.push(field_source(value).replace(';', ","));
+
+        lines.push(format!("{} {}", stack.join(";"), self_time / 1000));
+    }
+
+    lines.join(
+        "
+",
+    )
+}
+
+fn render_summary(values: &[Value], span: Span) -> Value {
+    struct Row {
+        source: String,
+        calls: i64,
+        total: i64,
+        allocs: i64,
+    }
+
+    let mut rows: Vec<Row> = Vec::new();
+    let mut top_total = 0;
+
+    for value in values {
+        let source = field_source(value);
+        let time = field_int(value, "time");
+        if field_int(value, "depth") <= 1 {
+            top_total += time;
+        }
+
+        match rows.iter_mut().find(|row| row.source == source) {
+            Some(row) => {
+                row.calls += 1;
+                row.total += time;
+                row.allocs += field_int(value, "allocs");
+            }
+            None => rows.push(Row {
+                source,
+                calls: 1,
+                total: time,
+                allocs: field_int(value, "allocs"),
+            }),
+        }
     }
+
+    rows.sort_by(|a, b| b.total.cmp(&a.total));
+
+    let cols = Arc::new(vec![
+        "source".to_string(),
+        "calls".to_string(),
+        "total".to_string(),
+        "mean".to_string(),
+        "allocs".to_string(),
+        "percent".to_string(),
+    ]);
+
+    let vals = rows
+        .into_iter()
+        .map(|row| {
+            let percent = if top_total > 0 {
+                (row.total as f64 / top_total as f64 * 10_000.0).round() / 100.0
+            } else {
+                0.0
+            };
+
+            Value::Record {
+                cols: cols.clone(),
+                vals: vec![
+                    Value::string(row.source, span),
+                    Value::int(row.calls, span),
+                    Value::Duration {
+                        val: row.total,
+                        span,
+                    },
+                    Value::Duration {
+                        val: row.total / row.calls,
+                        span,
+                    },
+                    Value::int(row.allocs, span),
+                    Value::float(percent, span),
+                ],
+                span,
+            }
+        })
+        .collect();
+
+    Value::List { vals, span }
 }
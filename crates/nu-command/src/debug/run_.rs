@@ -0,0 +1,197 @@
+use nu_engine::{eval_block, CallExt};
+use nu_parser::parse;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, DebugMode, EngineState, Stack, StateWorkingSet};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct DebugRun;
+
+impl Command for DebugRun {
+    fn name(&self) -> &str {
+        "debug run"
+    }
+
+    fn usage(&self) -> &str {
+        "Run a script under the step debugger."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Without breakpoints, the debugger stops before the first pipeline element;
+with breakpoints, it runs until one matches. At every stop it prints the element
+about to run and reads commands from stdin:
+  s, step      run this element, stop at the next one (entering blocks)
+  n, next      like step, but do not stop inside child blocks
+  c, continue  run until the next breakpoint
+  i, input     print the pipeline input of this element
+  v, vars      print the variables in scope with their values
+  q, quit      abort the script"#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("debug run")
+            .required("script", SyntaxShape::Filepath, "the script to debug")
+            .named(
+                "break-commands",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "stop when one of these commands is about to run",
+                None,
+            )
+            .named(
+                "break-lines",
+                SyntaxShape::List(Box::new(SyntaxShape::Int)),
+                "stop at elements starting on these lines of the script (1-based)",
+                None,
+            )
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .category(Category::Debug)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["debugger", "breakpoint", "step"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let script: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let break_commands: Option<Vec<String>> =
+            call.get_flag(engine_state, stack, "break-commands")?;
+        let break_lines: Option<Vec<Value>> = call.get_flag(engine_state, stack, "break-lines")?;
+
+        let cwd = nu_engine::env::current_dir(engine_state, stack)?;
+        let path = nu_path::expand_path_with(&script.item, cwd);
+        let contents = std::fs::read(&path).map_err(|_| {
+            ShellError::FileNotFoundCustom(format!("Could not read {}", script.item), script.span)
+        })?;
+        let filename = path.to_string_lossy().to_string();
+
+        // The parsed script needs to be merged into an engine state, so run it against a
+        // copy; the debug state is shared between the copies through its Arc
+        let mut working_engine = engine_state.clone();
+
+        let (block, delta) = {
+            let mut working_set = StateWorkingSet::new(&working_engine);
+            let block = parse(&mut working_set, Some(&filename), &contents, false);
+
+            if let Some(err) = working_set.parse_errors.first() {
+                return Err(ShellError::GenericError(
+                    format!("Failed to parse {}", script.item),
+                    err.to_string(),
+                    Some(script.span),
+                    None,
+                    Vec::new(),
+                ));
+            }
+
+            (block, working_set.render())
+        };
+
+        working_engine.merge_delta(delta)?;
+
+        let file_start = working_engine
+            .files()
+            .find(|(name, ..)| name == &filename)
+            .map(|(_, start, _)| *start)
+            .unwrap_or(0);
+
+        let break_spans = match break_lines {
+            Some(lines) => {
+                let lines = lines
+                    .into_iter()
+                    .map(|line| line.as_i64())
+                    .collect::<Result<Vec<i64>, ShellError>>()?;
+                line_spans(&contents, file_start, &lines)
+            }
+            None => Vec::new(),
+        };
+
+        let break_commands = break_commands.unwrap_or_default();
+
+        {
+            let mut state = working_engine
+                .debug_state
+                .lock()
+                .expect("debug state mutex");
+            state.active = true;
+            // Without breakpoints, stop right away at the first element
+            state.mode = if break_commands.is_empty() && break_spans.is_empty() {
+                DebugMode::Step
+            } else {
+                DebugMode::Continue
+            };
+            state.depth = 0;
+            state.break_commands = break_commands;
+            state.break_spans = break_spans;
+        }
+
+        let result = eval_block(
+            &working_engine,
+            stack,
+            &block,
+            input,
+            call.redirect_stdout,
+            call.redirect_stderr,
+        );
+
+        {
+            let mut state = working_engine
+                .debug_state
+                .lock()
+                .expect("debug state mutex");
+            state.active = false;
+            state.mode = DebugMode::Continue;
+            state.depth = 0;
+            state.break_commands = Vec::new();
+            state.break_spans = Vec::new();
+        }
+
+        result
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Step through a script from its first element",
+                example: "debug run myscript.nu",
+                result: None,
+            },
+            Example {
+                description: "Run a script until `save` is about to run",
+                example: "debug run myscript.nu --break-commands [save]",
+                result: None,
+            },
+            Example {
+                description: "Stop at line 17 of the script",
+                example: "debug run myscript.nu --break-lines [17]",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// The absolute spans of the given 1-based lines of the file starting at `file_start`.
+fn line_spans(contents: &[u8], file_start: usize, lines: &[i64]) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut line_start = 0;
+    let mut line_num = 1;
+
+    for line in String::from_utf8_lossy(contents).split_inclusive('\n') {
+        if lines.contains(&line_num) {
+            spans.push(Span::new(
+                file_start + line_start,
+                file_start + line_start + line.len(),
+            ));
+        }
+        line_start += line.len();
+        line_num += 1;
+    }
+
+    spans
+}
@@ -3,6 +3,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct ViewFiles;
@@ -37,12 +38,12 @@ impl Command for ViewFiles {
 
         for (file, start, end) in engine_state.files() {
             records.push(Value::Record {
-                cols: vec![
+                cols: Arc::new(vec![
                     "filename".to_string(),
                     "start".to_string(),
                     "end".to_string(),
                     "size".to_string(),
-                ],
+                ]),
                 vals: vec![
                     Value::string(file, call.head),
                     Value::int(*start as i64, call.head),
@@ -2,8 +2,8 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
-    Value,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
 };
 
 #[derive(Clone)]
@@ -15,14 +15,23 @@ impl Command for ViewSource {
     }
 
     fn usage(&self) -> &str {
-        "View a block, module, or a definition."
+        "View the source of a block, module, definition, alias, or extern."
     }
 
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("view source")
-            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .input_output_types(vec![
+                (Type::Nothing, Type::String),
+                (Type::Nothing, Type::Record(vec![])),
+            ])
             .required("item", SyntaxShape::Any, "name or block to view")
+            .switch(
+                "record",
+                "return a record with the source and its file/line metadata",
+                Some('r'),
+            )
             .category(Category::Debug)
+            .allow_variants_without_examples(true)
     }
 
     fn run(
@@ -33,122 +42,13 @@ impl Command for ViewSource {
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let arg: Value = call.req(engine_state, stack, 0)?;
-        let arg_span = arg.span()?;
 
-        match arg {
-            Value::Block { val: block_id, .. } | Value::Closure { val: block_id, .. } => {
-                let block = engine_state.get_block(block_id);
+        let (source, source_span) = get_source(engine_state, arg)?;
 
-                if let Some(span) = block.span {
-                    let contents = engine_state.get_span_contents(&span);
-                    Ok(Value::string(String::from_utf8_lossy(contents), call.head)
-                        .into_pipeline_data())
-                } else {
-                    Ok(Value::string("<internal command>", call.head).into_pipeline_data())
-                }
-            }
-            Value::String { val, .. } => {
-                if let Some(decl_id) = engine_state.find_decl(val.as_bytes(), &[]) {
-                    // arg is a command
-                    let decl = engine_state.get_decl(decl_id);
-                    let sig = decl.signature();
-                    let vec_of_required = &sig.required_positional;
-                    let vec_of_optional = &sig.optional_positional;
-                    let vec_of_flags = &sig.named;
-                    // gets vector of positionals.
-                    if let Some(block_id) = decl.get_block_id() {
-                        let block = engine_state.get_block(block_id);
-                        if let Some(block_span) = block.span {
-                            let contents = engine_state.get_span_contents(&block_span);
-                            let mut final_contents = String::from("def ");
-                            final_contents.push_str(&val);
-                            // The name of the function...
-                            final_contents.push_str(" [ ");
-                            for n in vec_of_required {
-                                final_contents.push_str(&n.name);
-                                // name of positional arg
-                                final_contents.push(':');
-                                final_contents.push_str(&n.shape.to_string());
-                                final_contents.push(' ');
-                            }
-                            for n in vec_of_optional {
-                                final_contents.push_str(&n.name);
-                                // name of positional arg
-                                final_contents.push_str("?:");
-                                final_contents.push_str(&n.shape.to_string());
-                                final_contents.push(' ');
-                            }
-                            for n in vec_of_flags {
-                                final_contents.push_str("--");
-                                final_contents.push_str(&n.long);
-                                final_contents.push(' ');
-                                if n.short.is_some() {
-                                    final_contents.push_str("(-");
-                                    final_contents.push(n.short.expect("this cannot trigger."));
-                                    final_contents.push(')');
-                                }
-                                if n.arg.is_some() {
-                                    final_contents.push_str(": ");
-                                    final_contents.push_str(
-                                        &n.arg.as_ref().expect("this cannot trigger.").to_string(),
-                                    );
-                                }
-                                final_contents.push(' ');
-                            }
-                            final_contents.push_str("] ");
-                            final_contents.push_str(&String::from_utf8_lossy(contents));
-                            Ok(Value::string(final_contents, call.head).into_pipeline_data())
-                        } else {
-                            Err(ShellError::GenericError(
-                                "Cannot view value".to_string(),
-                                "the command does not have a viewable block".to_string(),
-                                Some(arg_span),
-                                None,
-                                Vec::new(),
-                            ))
-                        }
-                    } else {
-                        Err(ShellError::GenericError(
-                            "Cannot view value".to_string(),
-                            "the command does not have a viewable block".to_string(),
-                            Some(arg_span),
-                            None,
-                            Vec::new(),
-                        ))
-                    }
-                } else if let Some(module_id) = engine_state.find_module(val.as_bytes(), &[]) {
-                    // arg is a module
-                    let module = engine_state.get_module(module_id);
-                    if let Some(module_span) = module.span {
-                        let contents = engine_state.get_span_contents(&module_span);
-                        Ok(Value::string(String::from_utf8_lossy(contents), call.head)
-                            .into_pipeline_data())
-                    } else {
-                        Err(ShellError::GenericError(
-                            "Cannot view value".to_string(),
-                            "the module does not have a viewable block".to_string(),
-                            Some(arg_span),
-                            None,
-                            Vec::new(),
-                        ))
-                    }
-                } else {
-                    Err(ShellError::GenericError(
-                        "Cannot view value".to_string(),
-                        "this name does not correspond to a viewable value".to_string(),
-                        Some(arg_span),
-                        None,
-                        Vec::new(),
-                    ))
-                }
-            }
-            _ => Err(ShellError::GenericError(
-                "Cannot view value".to_string(),
-                "this value cannot be viewed".to_string(),
-                Some(arg_span),
-                None,
-                Vec::new(),
-            )),
+        if call.has_flag("record") {
+            Ok(source_record(engine_state, source, source_span, call.head).into_pipeline_data())
+        } else {
+            Ok(Value::string(source, call.head).into_pipeline_data())
         }
     }
 
@@ -177,8 +77,213 @@ impl Command for ViewSource {
             Example {
                 description: "View the source of an alias",
                 example: r#"alias hello = echo hi; view source hello"#,
-                result: Some(Value::test_string("echo hi")),
+                result: Some(Value::test_string("alias hello = echo hi")),
+            },
+            Example {
+                description: "View the source of a definition together with its file and line",
+                example: r#"def hi [] { echo 'Hi!' }; view source --record hi"#,
+                result: None,
             },
         ]
     }
 }
+
+/// Get the source of the given item together with the span it came from, if it has one.
+fn get_source(
+    engine_state: &EngineState,
+    arg: Value,
+) -> Result<(String, Option<Span>), ShellError> {
+    let arg_span = arg.span()?;
+
+    match arg {
+        Value::Block { val: block_id, .. } | Value::Closure { val: block_id, .. } => {
+            let block = engine_state.get_block(block_id);
+
+            if let Some(span) = block.span {
+                let contents = engine_state.get_span_contents(&span);
+                Ok((String::from_utf8_lossy(contents).to_string(), Some(span)))
+            } else {
+                Ok(("<internal command>".to_string(), None))
+            }
+        }
+        Value::String { val, .. } => {
+            if let Some(decl_id) = engine_state.find_decl(val.as_bytes(), &[]) {
+                // arg is a command
+                let decl = engine_state.get_decl(decl_id);
+
+                if let Some(alias) = decl.as_alias() {
+                    let alias_span = alias.wrapped_call.span;
+                    let contents = engine_state.get_span_contents(&alias_span);
+
+                    Ok((
+                        format!("alias {} = {}", val, String::from_utf8_lossy(contents)),
+                        Some(alias_span),
+                    ))
+                } else if let Some(block_id) = decl.get_block_id() {
+                    let block = engine_state.get_block(block_id);
+
+                    if let Some(block_span) = block.span {
+                        let contents = engine_state.get_span_contents(&block_span);
+                        let mut final_contents = format!("def {val} ");
+                        final_contents.push_str(&signature_text(&decl.signature()));
+                        final_contents.push(' ');
+                        final_contents.push_str(&String::from_utf8_lossy(contents));
+
+                        Ok((final_contents, Some(block_span)))
+                    } else {
+                        Err(ShellError::GenericError(
+                            "Cannot view value".to_string(),
+                            "the command does not have a viewable block".to_string(),
+                            Some(arg_span),
+                            None,
+                            Vec::new(),
+                        ))
+                    }
+                } else if decl.is_known_external() {
+                    // externs do not keep their declaration span; rebuild it from the signature
+                    Ok((
+                        format!("extern {} {}", val, signature_text(&decl.signature())),
+                        None,
+                    ))
+                } else {
+                    Err(ShellError::GenericError(
+                        "Cannot view value".to_string(),
+                        "the command does not have a viewable block".to_string(),
+                        Some(arg_span),
+                        None,
+                        Vec::new(),
+                    ))
+                }
+            } else if let Some(module_id) = engine_state.find_module(val.as_bytes(), &[]) {
+                // arg is a module
+                let module = engine_state.get_module(module_id);
+
+                if let Some(module_span) = module.span {
+                    let contents = engine_state.get_span_contents(&module_span);
+                    Ok((
+                        String::from_utf8_lossy(contents).to_string(),
+                        Some(module_span),
+                    ))
+                } else {
+                    Err(ShellError::GenericError(
+                        "Cannot view value".to_string(),
+                        "the module does not have a viewable block".to_string(),
+                        Some(arg_span),
+                        None,
+                        Vec::new(),
+                    ))
+                }
+            } else {
+                Err(ShellError::GenericError(
+                    "Cannot view value".to_string(),
+                    "this name does not correspond to a viewable value".to_string(),
+                    Some(arg_span),
+                    None,
+                    Vec::new(),
+                ))
+            }
+        }
+        _ => Err(ShellError::GenericError(
+            "Cannot view value".to_string(),
+            "this value cannot be viewed".to_string(),
+            Some(arg_span),
+            None,
+            Vec::new(),
+        )),
+    }
+}
+
+/// Render the arguments and flags of a signature the way they would appear in a definition.
+fn signature_text(sig: &Signature) -> String {
+    let mut text = String::from("[ ");
+
+    for n in &sig.required_positional {
+        text.push_str(&n.name);
+        text.push(':');
+        text.push_str(&n.shape.to_string());
+        text.push(' ');
+    }
+    for n in &sig.optional_positional {
+        text.push_str(&n.name);
+        text.push_str("?:");
+        text.push_str(&n.shape.to_string());
+        text.push(' ');
+    }
+    for n in &sig.named {
+        text.push_str("--");
+        text.push_str(&n.long);
+        text.push(' ');
+        if let Some(short) = n.short {
+            text.push_str("(-");
+            text.push(short);
+            text.push(')');
+        }
+        if let Some(arg) = &n.arg {
+            text.push_str(": ");
+            text.push_str(&arg.to_string());
+        }
+        text.push(' ');
+    }
+    text.push(']');
+
+    text
+}
+
+/// Wrap a source and its span into a record with file/line metadata for tooling use.
+fn source_record(
+    engine_state: &EngineState,
+    source: String,
+    source_span: Option<Span>,
+    head: Span,
+) -> Value {
+    let mut filename = Value::nothing(head);
+    let mut start_line = Value::nothing(head);
+    let mut end_line = Value::nothing(head);
+    let mut span = Value::nothing(head);
+
+    if let Some(source_span) = source_span {
+        span = Value::Record {
+            cols: std::sync::Arc::new(vec!["start".into(), "end".into()]),
+            vals: vec![
+                Value::int(source_span.start as i64, head),
+                Value::int(source_span.end as i64, head),
+            ],
+            span: head,
+        };
+
+        for (file, start, end) in engine_state.files() {
+            if source_span.start >= *start && source_span.end <= *end {
+                let contents = engine_state.get_span_contents(&Span::new(*start, *end));
+                let line_at = |offset: usize| {
+                    1 + contents[..offset - start]
+                        .iter()
+                        .filter(|byte| **byte == b'\n')
+                        .count() as i64
+                };
+
+                filename = Value::string(file, head);
+                start_line = Value::int(line_at(source_span.start), head);
+                end_line = Value::int(line_at(source_span.end), head);
+                break;
+            }
+        }
+    }
+
+    Value::Record {
+        cols: std::sync::Arc::new(vec![
+            "source".into(),
+            "filename".into(),
+            "start_line".into(),
+            "end_line".into(),
+            "span".into(),
+        ]),
+        vals: vec![
+            Value::string(source, head),
+            filename,
+            start_line,
+            end_line,
+            span,
+        ],
+        span: head,
+    }
+}
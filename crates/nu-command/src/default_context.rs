@@ -102,6 +102,7 @@ pub fn create_default_context() -> EngineState {
 
         // Misc
         bind_command! {
+            LazyMake,
             Tutor,
         };
 
@@ -124,6 +125,7 @@ pub fn create_default_context() -> EngineState {
             Complete,
             External,
             NuCheck,
+            RunBatched,
             Sys,
         };
 
@@ -131,6 +133,7 @@ pub fn create_default_context() -> EngineState {
         bind_command! {
             Ast,
             Debug,
+            DebugRun,
             Explain,
             Inspect,
             Metadata,
@@ -143,10 +146,34 @@ pub fn create_default_context() -> EngineState {
         };
 
         #[cfg(unix)]
-        bind_command! { Exec }
+        bind_command! { Exec, Groups, Users, Whoami }
+
+        #[cfg(target_os = "linux")]
+        bind_command! { Journal }
+
+        #[cfg(target_os = "macos")]
+        bind_command! {
+            Defaults,
+            DefaultsDomains,
+            DefaultsRead,
+            DefaultsWrite,
+            Launchd,
+            LaunchdList,
+            LaunchdStart,
+            LaunchdStop,
+        }
 
         #[cfg(windows)]
-        bind_command! { RegistryQuery }
+        bind_command! { EventLog, RegistryDelete, RegistryQuery, RegistrySet }
+
+        #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+        bind_command! {
+            Service,
+            ServiceList,
+            ServiceRestart,
+            ServiceStart,
+            ServiceStop,
+        }
 
         #[cfg(any(
             target_os = "android",
@@ -169,6 +196,7 @@ pub fn create_default_context() -> EngineState {
             DecodeHex,
             EncodeHex,
             DetectColumns,
+            DetectEncoding,
             Format,
             FileSize,
             Parse,
@@ -228,6 +256,8 @@ pub fn create_default_context() -> EngineState {
             BytesCollect,
             BytesRemove,
             BytesBuild,
+            BytesFind,
+            BytesView,
         }
 
         // FileSystem
@@ -241,11 +271,37 @@ pub fn create_default_context() -> EngineState {
             Start,
             Rm,
             Save,
+            Stat,
             Touch,
             Glob,
             Watch,
         };
 
+        #[cfg(all(
+            feature = "trash-support",
+            any(
+                target_os = "windows",
+                all(
+                    unix,
+                    not(target_os = "macos"),
+                    not(target_os = "ios"),
+                    not(target_os = "android")
+                )
+            )
+        ))]
+        bind_command! {
+            Trash,
+            TrashList,
+            TrashRestore,
+        };
+
+        #[cfg(target_os = "linux")]
+        bind_command! {
+            Xattr,
+            XattrGet,
+            XattrSet,
+        };
+
         // Platform
         bind_command! {
             Ansi,
@@ -255,11 +311,18 @@ pub fn create_default_context() -> EngineState {
             Clear,
             Du,
             Input,
+            InputList,
             Kill,
             Sleep,
+            Term,
+            TermOnResize,
+            TermQuery,
             TermSize,
         };
 
+        #[cfg(unix)]
+        bind_command! { Ulimit }
+
         // Date
         bind_command! {
             Date,
@@ -285,14 +348,25 @@ pub fn create_default_context() -> EngineState {
         // Formats
         bind_command! {
             From,
+            FromClf,
             FromCsv,
+            FromEml,
+            FromIcs,
+            FromIni,
             FromJson,
+            FromMbox,
+            FromNdjson,
             FromNuon,
             FromOds,
+            FromPlist,
+            FromPrometheus,
+            FromProperties,
             FromSsv,
+            FromSyslog,
             FromToml,
             FromTsv,
             FromUrl,
+            FromVcf,
             FromXlsx,
             FromXml,
             FromYaml,
@@ -300,9 +374,12 @@ pub fn create_default_context() -> EngineState {
             To,
             ToCsv,
             ToHtml,
+            ToIni,
             ToJson,
             ToMd,
+            ToNdjson,
             ToNuon,
+            ToPlist,
             ToText,
             ToToml,
             ToTsv,
@@ -318,6 +395,7 @@ pub fn create_default_context() -> EngineState {
             Griddle,
             Table,
             Explore,
+            ViewImage,
         };
 
         // Conversions
@@ -328,6 +406,7 @@ pub fn create_default_context() -> EngineState {
             IntoBool,
             IntoBinary,
             IntoDatetime,
+            IntoBigInt,
             IntoDecimal,
             IntoDuration,
             IntoFilesize,
@@ -346,6 +425,9 @@ pub fn create_default_context() -> EngineState {
             ConfigNu,
             ConfigEnv,
             ConfigMeta,
+            ConfigCheck,
+            ConfigDiffDefault,
+            ConfigReload,
             ConfigReset,
         };
 
@@ -388,6 +470,8 @@ pub fn create_default_context() -> EngineState {
 
         // Network
         bind_command! {
+            Graphql,
+            GraphqlQuery,
             Http,
             HttpDelete,
             HttpGet,
@@ -395,12 +479,19 @@ pub fn create_default_context() -> EngineState {
             HttpPatch,
             HttpPost,
             HttpPut,
+            HttpSession,
+            HttpToken,
             Url,
             UrlBuildQuery,
             UrlEncode,
             UrlJoin,
             UrlParse,
             Port,
+            Net,
+            NetConnect,
+            NetSendUdp,
+            Ws,
+            WsConnect,
         }
 
         // Random
@@ -422,16 +513,58 @@ pub fn create_default_context() -> EngineState {
             SeqChar,
         };
 
+        // Logging
+        bind_command! {
+            Log,
+            LogCritical,
+            LogDebug,
+            LogError,
+            LogInfo,
+            LogWarning,
+        };
+
         // Hash
         bind_command! {
             Hash,
             HashMd5::default(),
             HashSha256::default(),
+            HashValue,
+        };
+
+        // Verify
+        bind_command! {
+            Verify,
+            VerifyFiles,
+        };
+
+        // Query
+        bind_command! {
+            Query,
+            QueryJson,
+            QueryWeb,
+            QueryXml,
+        };
+
+        // Version control
+        bind_command! {
+            Vcs,
+            VcsBlame,
+            VcsLog,
+            VcsStatus,
         };
 
         // Experimental
         bind_command! {
             IsAdmin,
+            Pkg,
+            PkgDir,
+            PkgInstall,
+            PkgList,
+            PkgRemove,
+            Secret,
+            SecretGetFrom,
+            SecretUnwrap,
+            SecretWrap,
         };
 
         // Deprecated
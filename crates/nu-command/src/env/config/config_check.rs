@@ -0,0 +1,81 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ConfigCheck;
+
+impl Command for ConfigCheck {
+    fn name(&self) -> &str {
+        "config check"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .category(Category::Env)
+            .input_output_types(vec![
+                (Type::Nothing, Type::String),
+                (Type::Record(vec![]), Type::String),
+            ])
+    }
+
+    fn usage(&self) -> &str {
+        "Validate a config record and report the invalid entries."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Checks the piped-in record, or $env.config if no input is given. Every entry that
+would be ignored at startup (a typo in a key, a value of the wrong type, ...) is
+reported with the span it came from."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let mut value = match input {
+            PipelineData::Value(value @ Value::Record { .. }, ..) => value,
+            PipelineData::Empty => stack.get_env_var(engine_state, "config").ok_or_else(|| {
+                ShellError::EnvVarNotFoundAtRuntime {
+                    envvar_name: "config".into(),
+                    span: call.head,
+                }
+            })?,
+            other => {
+                return Err(ShellError::TypeMismatch {
+                    err_message: "record".into(),
+                    span: other.span().unwrap_or(call.head),
+                });
+            }
+        };
+
+        let (_, error) = value.into_config(engine_state.get_config());
+
+        match error {
+            Some(error) => Err(error),
+            None => {
+                Ok(Value::string("No errors found in the config", call.head).into_pipeline_data())
+            }
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Validate the current $env.config",
+                example: "config check",
+                result: None,
+            },
+            Example {
+                description: "Validate a config record before applying it",
+                example: "{ show_banner: 'yes' } | config check",
+                result: None,
+            },
+        ]
+    }
+}
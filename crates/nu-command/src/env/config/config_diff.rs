@@ -0,0 +1,226 @@
+use nu_engine::eval_block;
+use nu_parser::parse;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack, StateWorkingSet};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ConfigDiffDefault;
+
+impl Command for ConfigDiffDefault {
+    fn name(&self) -> &str {
+        "config diff-default"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .category(Category::Env)
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+    }
+
+    fn usage(&self) -> &str {
+        "Show the entries of $env.config that differ from the default config."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The default record comes from evaluating the bundled default config file. Blocks
+and closures are not compared; a setting is listed when its value differs or when it
+exists on only one of the two sides."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let current = stack.get_env_var(engine_state, "config").ok_or_else(|| {
+            ShellError::EnvVarNotFoundAtRuntime {
+                envvar_name: "config".into(),
+                span: head,
+            }
+        })?;
+
+        let default = eval_default_config(engine_state, stack, head)?;
+
+        let mut current_entries = vec![];
+        flatten_config("".to_string(), &current, &mut current_entries);
+
+        let mut default_entries = vec![];
+        flatten_config("".to_string(), &default, &mut default_entries);
+
+        let mut paths: Vec<String> = current_entries
+            .iter()
+            .chain(default_entries.iter())
+            .map(|(path, _)| path.clone())
+            .collect();
+        paths.sort();
+        paths.dedup();
+
+        let mut rows = vec![];
+
+        for path in paths {
+            let current_value = current_entries
+                .iter()
+                .find(|(entry, _)| entry == &path)
+                .map(|(_, value)| value.clone());
+            let default_value = default_entries
+                .iter()
+                .find(|(entry, _)| entry == &path)
+                .map(|(_, value)| value.clone());
+
+            let differ = match (&default_value, &current_value) {
+                (Some(default_value), Some(current_value)) => {
+                    !values_equal(default_value, current_value)
+                }
+                _ => true,
+            };
+
+            if differ {
+                rows.push(Value::Record {
+                    cols: std::sync::Arc::new(vec![
+                        "path".into(),
+                        "default".into(),
+                        "current".into(),
+                    ]),
+                    vals: vec![
+                        Value::string(path, head),
+                        default_value.unwrap_or_else(|| Value::nothing(head)),
+                        current_value.unwrap_or_else(|| Value::nothing(head)),
+                    ],
+                    span: head,
+                });
+            }
+        }
+
+        Ok(Value::List {
+            vals: rows,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List the config settings changed from their defaults",
+            example: "config diff-default",
+            result: None,
+        }]
+    }
+}
+
+/// Evaluate the bundled default config file and return the `config` record it sets up.
+fn eval_default_config(
+    engine_state: &EngineState,
+    stack: &Stack,
+    head: Span,
+) -> Result<Value, ShellError> {
+    let mut engine_state = engine_state.clone();
+
+    let (block, delta) = {
+        let mut working_set = StateWorkingSet::new(&engine_state);
+        let output = parse(
+            &mut working_set,
+            Some("default_config.nu"),
+            nu_utils::get_default_config().as_bytes(),
+            false,
+        );
+
+        if let Some(err) = working_set.parse_errors.first() {
+            return Err(ShellError::GenericError(
+                "Failed to parse default config".into(),
+                err.to_string(),
+                Some(head),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        (output, working_set.render())
+    };
+
+    engine_state.merge_delta(delta)?;
+
+    let mut default_stack = stack.clone();
+
+    eval_block(
+        &engine_state,
+        &mut default_stack,
+        &block,
+        PipelineData::empty(),
+        false,
+        false,
+    )?;
+
+    default_stack
+        .get_env_var(&engine_state, "config")
+        .ok_or_else(|| {
+            ShellError::GenericError(
+                "Failed to evaluate default config".into(),
+                "the default config file did not set $env.config".into(),
+                Some(head),
+                None,
+                Vec::new(),
+            )
+        })
+}
+
+/// Flatten nested config records into `(dot.separated.path, value)` pairs.
+fn flatten_config(prefix: String, value: &Value, entries: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Record { cols, vals, .. } => {
+            for (col, val) in cols.iter().zip(vals.iter()) {
+                let path = if prefix.is_empty() {
+                    col.clone()
+                } else {
+                    format!("{prefix}.{col}")
+                };
+                flatten_config(path, val, entries);
+            }
+        }
+        _ => entries.push((prefix, value.clone())),
+    }
+}
+
+/// Compare two config values, treating blocks and closures as equal because their block
+/// ids are never comparable between two engine states.
+fn values_equal(lhs: &Value, rhs: &Value) -> bool {
+    match (lhs, rhs) {
+        (
+            Value::Block { .. } | Value::Closure { .. },
+            Value::Block { .. } | Value::Closure { .. },
+        ) => true,
+        (Value::List { vals: lhs, .. }, Value::List { vals: rhs, .. }) => {
+            lhs.len() == rhs.len()
+                && lhs
+                    .iter()
+                    .zip(rhs.iter())
+                    .all(|(lhs, rhs)| values_equal(lhs, rhs))
+        }
+        (
+            Value::Record {
+                cols: lhs_cols,
+                vals: lhs_vals,
+                ..
+            },
+            Value::Record {
+                cols: rhs_cols,
+                vals: rhs_vals,
+                ..
+            },
+        ) => {
+            lhs_cols == rhs_cols
+                && lhs_vals.len() == rhs_vals.len()
+                && lhs_vals
+                    .iter()
+                    .zip(rhs_vals.iter())
+                    .all(|(lhs, rhs)| values_equal(lhs, rhs))
+        }
+        _ => lhs == rhs,
+    }
+}
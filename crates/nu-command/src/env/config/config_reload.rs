@@ -0,0 +1,50 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Type, Value};
+
+#[derive(Clone)]
+pub struct ConfigReload;
+
+impl Command for ConfigReload {
+    fn name(&self) -> &str {
+        "config reload"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .category(Category::Env)
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+    }
+
+    fn usage(&self) -> &str {
+        "Re-source env.nu and config.nu into the running session."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The files are re-sourced before the next prompt. The new state only replaces the
+running session when they all evaluate without errors, so a broken config leaves the
+session untouched."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        // The actual reload needs mutable access to the engine state, so it is done by the
+        // REPL loop; this just leaves the request for it
+        stack.add_env_var("NU_RELOAD_CONFIG".into(), Value::boolean(true, call.head));
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Reload the config files after editing them",
+            example: "config reload",
+            result: None,
+        }]
+    }
+}
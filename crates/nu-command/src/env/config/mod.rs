@@ -1,9 +1,15 @@
 mod config_;
+mod config_check;
+mod config_diff;
 mod config_env;
 mod config_nu;
+mod config_reload;
 mod config_reset;
 mod utils;
 pub use config_::ConfigMeta;
+pub use config_check::ConfigCheck;
+pub use config_diff::ConfigDiffDefault;
 pub use config_env::ConfigEnv;
 pub use config_nu::ConfigNu;
+pub use config_reload::ConfigReload;
 pub use config_reset::ConfigReset;
@@ -73,5 +73,6 @@ pub(crate) fn gen_command(
         redirect_stderr: false,
         env_vars: env_vars_str,
         trim_end_newline: false,
+        pty: false,
     }
 }
@@ -4,6 +4,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct LoadEnv;
@@ -62,7 +63,7 @@ impl Command for LoadEnv {
             }
             None => match input {
                 PipelineData::Value(Value::Record { cols, vals, .. }, ..) => {
-                    for (env_var, rhs) in cols.into_iter().zip(vals) {
+                    for (env_var, rhs) in Arc::unwrap_or_clone(cols).into_iter().zip(vals) {
                         if env_var == "FILE_PWD" {
                             return Err(ShellError::AutomaticEnvVarSetManually {
                                 envvar_name: env_var,
@@ -5,9 +5,12 @@ mod load_env;
 mod source_env;
 mod with_env;
 
+pub use config::ConfigCheck;
+pub use config::ConfigDiffDefault;
 pub use config::ConfigEnv;
 pub use config::ConfigMeta;
 pub use config::ConfigNu;
+pub use config::ConfigReload;
 pub use config::ConfigReset;
 pub use export_env::ExportEnv;
 pub use let_env::LetEnv;
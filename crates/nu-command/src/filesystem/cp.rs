@@ -10,11 +10,11 @@ use nu_path::{canonicalize_with, expand_path_with};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
-    Spanned, SyntaxShape, Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 
-use super::util::try_interaction;
+use super::util::{dry_run_transfer, is_dry_run, try_interaction};
 
 use crate::filesystem::util::FileStructure;
 use crate::progress_bar;
@@ -66,6 +66,11 @@ impl Command for Cp {
                 Some('n'),
             )
             .switch("progress", "enable progress bar", Some('p'))
+            .switch(
+                "dry-run",
+                "report the copies that would be made without copying",
+                None,
+            )
             .category(Category::FileSystem)
     }
 
@@ -150,6 +155,25 @@ impl Command for Cp {
             ));
         }
 
+        if is_dry_run(engine_state, call) {
+            let vals = sources
+                .iter()
+                .flatten()
+                .map(|entry| {
+                    let destination = if destination.is_dir() {
+                        match entry.file_name() {
+                            Some(name) => destination.join(name),
+                            None => destination.clone(),
+                        }
+                    } else {
+                        destination.clone()
+                    };
+                    dry_run_transfer("cp", entry, &destination, span)
+                })
+                .collect();
+            return Ok(Value::List { vals, span }.into_pipeline_data());
+        }
+
         let mut result = Vec::new();
 
         for entry in sources.into_iter().flatten() {
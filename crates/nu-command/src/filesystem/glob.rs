@@ -3,10 +3,11 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Spanned,
-    SyntaxShape, Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
 };
-use wax::{Glob as WaxGlob, WalkBehavior};
+use std::sync::Arc;
+use wax::{Glob as WaxGlob, WalkBehavior, WalkEntry};
 
 #[derive(Clone)]
 pub struct Glob;
@@ -18,7 +19,10 @@ impl Command for Glob {
 
     fn signature(&self) -> Signature {
         Signature::build("glob")
-            .input_output_types(vec![(Type::Nothing, Type::List(Box::new(Type::String)))])
+            .input_output_types(vec![
+                (Type::Nothing, Type::List(Box::new(Type::String))),
+                (Type::Nothing, Type::Table(vec![])),
+            ])
             .required("glob", SyntaxShape::String, "the glob expression")
             .named(
                 "depth",
@@ -26,6 +30,17 @@ impl Command for Glob {
                 "directory depth to search",
                 Some('d'),
             )
+            .named(
+                "not",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "glob patterns to exclude from the results",
+                Some('n'),
+            )
+            .switch(
+                "structured",
+                "Return a table with the path, its type, and the part the glob matched, instead of plain paths",
+                None,
+            )
             .switch(
                 "no-dir",
                 "Whether to filter out directories from the returned paths",
@@ -101,6 +116,16 @@ impl Command for Glob {
                 example: r#"glob "[A-Z]*" --no-file --no-symlink"#,
                 result: None,
             },
+            Example {
+                description: "Search for rust files, excluding anything under a target directory",
+                example: r#"glob **/*.rs --not [**/target/**]"#,
+                result: None,
+            },
+            Example {
+                description: "Search for toml files and return a table with each path, its type, and the matched part",
+                example: "glob **/*.toml --structured",
+                result: None,
+            },
         ]
     }
 
@@ -119,10 +144,14 @@ impl Command for Glob {
         let path = current_dir(engine_state, stack)?;
         let glob_pattern: Spanned<String> = call.req(engine_state, stack, 0)?;
         let depth = call.get_flag(engine_state, stack, "depth")?;
+        let not_patterns: Vec<String> = call
+            .get_flag(engine_state, stack, "not")?
+            .unwrap_or_default();
 
         let no_dirs = call.has_flag("no-dir");
         let no_files = call.has_flag("no-file");
         let no_symlinks = call.has_flag("no-symlink");
+        let structured = call.has_flag("structured");
 
         if glob_pattern.item.is_empty() {
             return Err(ShellError::GenericError(
@@ -153,8 +182,7 @@ impl Command for Glob {
             }
         };
 
-        #[allow(clippy::needless_collect)]
-        let glob_results: Vec<Value> = glob
+        let walk = glob
             .walk_with_behavior(
                 path,
                 WalkBehavior {
@@ -162,22 +190,80 @@ impl Command for Glob {
                     ..Default::default()
                 },
             )
-            .flatten()
-            .filter(|entry| {
+            .into_owned();
+
+        // apply any negation patterns while walking, so that excluded
+        // directory trees are never read from the file system
+        let entries: Box<dyn Iterator<Item = WalkEntry<'static>> + Send> =
+            if not_patterns.is_empty() {
+                Box::new(walk.flatten())
+            } else {
+                match walk.not(not_patterns.iter().map(String::as_str)) {
+                    Ok(walk) => Box::new(walk.flatten()),
+                    Err(e) => {
+                        return Err(ShellError::GenericError(
+                            "error with glob pattern passed to --not".to_string(),
+                            format!("{e}"),
+                            Some(span),
+                            None,
+                            Vec::new(),
+                        ))
+                    }
+                }
+            };
+
+        Ok(entries
+            .filter(move |entry| {
                 let file_type = entry.file_type();
 
                 !(no_dirs && file_type.is_dir()
                     || no_files && file_type.is_file()
                     || no_symlinks && file_type.is_symlink())
             })
-            .map(|entry| Value::String {
-                val: entry.into_path().to_string_lossy().to_string(),
-                span,
+            .map(move |entry| {
+                if structured {
+                    structured_entry(entry, span)
+                } else {
+                    Value::String {
+                        val: entry.into_path().to_string_lossy().to_string(),
+                        span,
+                    }
+                }
             })
-            .collect();
-
-        Ok(glob_results
-            .into_iter()
             .into_pipeline_data(engine_state.ctrlc.clone()))
     }
 }
+
+fn structured_entry(entry: WalkEntry, span: Span) -> Value {
+    let file_type = entry.file_type();
+    let entry_type = if file_type.is_dir() {
+        "dir"
+    } else if file_type.is_symlink() {
+        "symlink"
+    } else if file_type.is_file() {
+        "file"
+    } else {
+        "unknown"
+    };
+    let matched = entry.matched().complete().to_string();
+
+    Value::Record {
+        cols: Arc::new(vec![
+            "path".to_string(),
+            "type".to_string(),
+            "matched".to_string(),
+        ]),
+        vals: vec![
+            Value::String {
+                val: entry.into_path().to_string_lossy().to_string(),
+                span,
+            },
+            Value::String {
+                val: entry_type.to_string(),
+                span,
+            },
+            Value::String { val: matched, span },
+        ],
+        span,
+    }
+}
@@ -12,6 +12,7 @@ use nu_protocol::{
     PipelineMetadata, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 use pathdiff::diff_paths;
+use rayon::prelude::*;
 
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
@@ -63,6 +64,12 @@ impl Command for Ls {
                 Some('D'),
             )
             .switch("mime-type", "Show mime-type in type column instead of 'file' (based on filenames only; files' contents are not examined)", Some('m'))
+            .named(
+                "threads",
+                SyntaxShape::Int,
+                "the number of threads to use for reading entry metadata",
+                Some('t'),
+            )
             .category(Category::FileSystem)
     }
 
@@ -80,6 +87,7 @@ impl Command for Ls {
         let du = call.has_flag("du");
         let directory = call.has_flag("directory");
         let use_mime_type = call.has_flag("mime-type");
+        let threads: Option<usize> = call.get_flag(engine_state, stack, "threads")?;
         let ctrl_c = engine_state.ctrlc.clone();
         let call_span = call.head;
         let cwd = current_dir(engine_state, stack)?;
@@ -177,102 +185,116 @@ impl Command for Ls {
 
         let mut hidden_dirs = vec![];
 
-        Ok(paths_peek
-            .filter_map(move |x| match x {
-                Ok(path) => {
-                    let metadata = match std::fs::symlink_metadata(&path) {
-                        Ok(metadata) => Some(metadata),
-                        Err(_) => None,
-                    };
-                    if path_contains_hidden_folder(&path, &hidden_dirs) {
-                        return None;
-                    }
+        // Stage one walks the glob results and works out display names; the
+        // stat-heavy work is deferred to `materialize_entry` so metadata is
+        // only read for entries that survive filtering, in parallel when
+        // --threads is given.
+        let entries = paths_peek.filter_map(move |x| match x {
+            Ok(path) => {
+                if path_contains_hidden_folder(&path, &hidden_dirs) {
+                    return None;
+                }
 
-                    if !all && !hidden_dir_specified && is_hidden_dir(&path) {
-                        if path.is_dir() {
-                            hidden_dirs.push(path);
-                        }
-                        return None;
+                if !all && !hidden_dir_specified && is_hidden_dir(&path) {
+                    if path.is_dir() {
+                        hidden_dirs.push(path);
                     }
+                    return None;
+                }
 
-                    let display_name = if short_names {
-                        path.file_name().map(|os| os.to_string_lossy().to_string())
-                    } else if full_paths || absolute_path {
-                        Some(path.to_string_lossy().to_string())
-                    } else if let Some(prefix) = &prefix {
-                        if let Ok(remainder) = path.strip_prefix(prefix) {
-                            if directory {
-                                // When the path is the same as the cwd, path_diff should be "."
-                                let path_diff =
-                                    if let Some(path_diff_not_dot) = diff_paths(&path, &cwd) {
-                                        let path_diff_not_dot = path_diff_not_dot.to_string_lossy();
-                                        if path_diff_not_dot.is_empty() {
-                                            ".".to_string()
-                                        } else {
-                                            path_diff_not_dot.to_string()
-                                        }
-                                    } else {
-                                        path.to_string_lossy().to_string()
-                                    };
-
-                                Some(path_diff)
-                            } else {
-                                let new_prefix = if let Some(pfx) = diff_paths(prefix, &cwd) {
-                                    pfx
+                let display_name = if short_names {
+                    path.file_name().map(|os| os.to_string_lossy().to_string())
+                } else if full_paths || absolute_path {
+                    Some(path.to_string_lossy().to_string())
+                } else if let Some(prefix) = &prefix {
+                    if let Ok(remainder) = path.strip_prefix(prefix) {
+                        if directory {
+                            // When the path is the same as the cwd, path_diff should be "."
+                            let path_diff = if let Some(path_diff_not_dot) = diff_paths(&path, &cwd)
+                            {
+                                let path_diff_not_dot = path_diff_not_dot.to_string_lossy();
+                                if path_diff_not_dot.is_empty() {
+                                    ".".to_string()
                                 } else {
-                                    prefix.to_path_buf()
-                                };
+                                    path_diff_not_dot.to_string()
+                                }
+                            } else {
+                                path.to_string_lossy().to_string()
+                            };
 
-                                Some(new_prefix.join(remainder).to_string_lossy().to_string())
-                            }
+                            Some(path_diff)
                         } else {
-                            Some(path.to_string_lossy().to_string())
+                            let new_prefix = if let Some(pfx) = diff_paths(prefix, &cwd) {
+                                pfx
+                            } else {
+                                prefix.to_path_buf()
+                            };
+
+                            Some(new_prefix.join(remainder).to_string_lossy().to_string())
                         }
                     } else {
                         Some(path.to_string_lossy().to_string())
                     }
-                    .ok_or_else(|| {
+                } else {
+                    Some(path.to_string_lossy().to_string())
+                }
+                .ok_or_else(|| {
+                    ShellError::GenericError(
+                        format!("Invalid file name: {:}", path.to_string_lossy()),
+                        "invalid file name".into(),
+                        Some(call_span),
+                        None,
+                        Vec::new(),
+                    )
+                });
+
+                match display_name {
+                    Ok(name) => Some(PendingEntry::Stat { path, name }),
+                    Err(err) => Some(PendingEntry::Ready(Value::Error {
+                        error: Box::new(err),
+                    })),
+                }
+            }
+            _ => Some(PendingEntry::Ready(Value::Nothing { span: call_span })),
+        });
+
+        let metadata = Box::new(PipelineMetadata {
+            data_source: DataSource::Ls,
+        });
+        let materialize = move |entry: PendingEntry| {
+            materialize_entry(entry, call_span, long, du, ctrl_c.clone(), use_mime_type)
+        };
+
+        match threads {
+            Some(threads) => {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .map_err(|e| {
                         ShellError::GenericError(
-                            format!("Invalid file name: {:}", path.to_string_lossy()),
-                            "invalid file name".into(),
+                            "Error creating thread pool".into(),
+                            e.to_string(),
                             Some(call_span),
                             None,
                             Vec::new(),
                         )
-                    });
-
-                    match display_name {
-                        Ok(name) => {
-                            let entry = dir_entry_dict(
-                                &path,
-                                &name,
-                                metadata.as_ref(),
-                                call_span,
-                                long,
-                                du,
-                                ctrl_c.clone(),
-                                use_mime_type,
-                            );
-                            match entry {
-                                Ok(value) => Some(value),
-                                Err(err) => Some(Value::Error {
-                                    error: Box::new(err),
-                                }),
-                            }
-                        }
-                        Err(err) => Some(Value::Error {
-                            error: Box::new(err),
-                        }),
-                    }
+                    })?;
+                // a few batches in flight per thread keeps the pool busy
+                // without buffering the whole listing
+                let batch_size = pool.current_num_threads().max(1) * 16;
+                Ok(ParallelEntries {
+                    input: entries,
+                    pool,
+                    batch_size,
+                    buffer: Vec::new().into_iter(),
+                    materialize,
                 }
-                _ => Some(Value::Nothing { span: call_span }),
-            })
-            .into_pipeline_data_with_metadata(
-                Box::new(PipelineMetadata {
-                    data_source: DataSource::Ls,
-                }),
-                engine_state.ctrlc.clone(),
-            ))
+                .into_pipeline_data_with_metadata(metadata, engine_state.ctrlc.clone()))
+            }
+            None => Ok(entries
+                .map(materialize)
+                .into_pipeline_data_with_metadata(metadata, engine_state.ctrlc.clone())),
+        }
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -322,6 +344,85 @@ impl Command for Ls {
     }
 }
 
+/// A listing row whose metadata has not been read yet. Rows that failed
+/// before the stat stage carry their value (or error) along as-is.
+enum PendingEntry {
+    Ready(Value),
+    Stat { path: PathBuf, name: String },
+}
+
+fn materialize_entry(
+    entry: PendingEntry,
+    span: Span,
+    long: bool,
+    du: bool,
+    ctrl_c: Option<Arc<AtomicBool>>,
+    use_mime_type: bool,
+) -> Value {
+    match entry {
+        PendingEntry::Ready(value) => value,
+        PendingEntry::Stat { path, name } => {
+            let metadata = std::fs::symlink_metadata(&path).ok();
+            match dir_entry_dict(
+                &path,
+                &name,
+                metadata.as_ref(),
+                span,
+                long,
+                du,
+                ctrl_c,
+                use_mime_type,
+            ) {
+                Ok(value) => value,
+                Err(err) => Value::Error {
+                    error: Box::new(err),
+                },
+            }
+        }
+    }
+}
+
+/// Materializes entries a batch at a time on a thread pool, yielding rows in
+/// the original glob order while keeping the listing itself streaming.
+struct ParallelEntries<I, F> {
+    input: I,
+    pool: rayon::ThreadPool,
+    batch_size: usize,
+    buffer: std::vec::IntoIter<Value>,
+    materialize: F,
+}
+
+impl<I, F> Iterator for ParallelEntries<I, F>
+where
+    I: Iterator<Item = PendingEntry>,
+    F: Fn(PendingEntry) -> Value + Send + Sync,
+{
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.buffer.next() {
+                return Some(value);
+            }
+
+            let batch: Vec<PendingEntry> = self.input.by_ref().take(self.batch_size).collect();
+            if batch.is_empty() {
+                return None;
+            }
+            let materialize = &self.materialize;
+            self.buffer = self
+                .pool
+                .install(|| {
+                    batch
+                        .into_par_iter()
+                        .map(materialize)
+                        .collect::<Vec<Value>>()
+                })
+                .into_iter();
+        }
+    }
+}
+
 fn permission_denied(dir: impl AsRef<Path>) -> bool {
     match dir.as_ref().read_dir() {
         Err(e) => matches!(e.kind(), std::io::ErrorKind::PermissionDenied),
@@ -544,7 +645,7 @@ pub(crate) fn dir_entry_dict(
 
         if md.is_dir() {
             if du {
-                let params = DirBuilder::new(Span::new(0, 2), None, false, None, false);
+                let params = DirBuilder::new(Span::new(0, 2), None, false, None, false, false);
                 let dir_size = DirInfo::new(filename, &params, None, ctrl_c).get_size();
 
                 vals.push(Value::Filesize {
@@ -642,7 +743,11 @@ pub(crate) fn dir_entry_dict(
         vals.push(Value::nothing(span));
     }
 
-    Ok(Value::Record { cols, vals, span })
+    Ok(Value::Record {
+        cols: Arc::new(cols),
+        vals,
+        span,
+    })
 }
 
 // TODO: can we get away from local times in `ls`? internals might be cleaner if we worked in UTC
@@ -1,12 +1,13 @@
 use std::collections::VecDeque;
 
+use super::util::{dry_run_target, is_dry_run};
 use nu_engine::env::current_dir;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature,
-    SyntaxShape, Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -26,6 +27,11 @@ impl Command for Mkdir {
                 "the name(s) of the path(s) to create",
             )
             .switch("verbose", "print created path(s).", Some('v'))
+            .switch(
+                "dry-run",
+                "report the directories that would be created without creating them",
+                None,
+            )
             .category(Category::FileSystem)
     }
 
@@ -61,6 +67,17 @@ impl Command for Mkdir {
             });
         }
 
+        if is_dry_run(engine_state, call) {
+            let vals = directories
+                .map(|dir| dry_run_target("mkdir", &dir, call.head))
+                .collect();
+            return Ok(Value::List {
+                vals,
+                span: call.head,
+            }
+            .into_pipeline_data());
+        }
+
         for (i, dir) in directories.enumerate() {
             let span = call
                 .positional_nth(i)
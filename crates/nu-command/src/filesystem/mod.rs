@@ -9,9 +9,25 @@ mod open;
 mod rm;
 mod save;
 mod start;
+mod stat;
 mod touch;
+#[cfg(all(
+    feature = "trash-support",
+    any(
+        target_os = "windows",
+        all(
+            unix,
+            not(target_os = "macos"),
+            not(target_os = "ios"),
+            not(target_os = "android")
+        )
+    )
+))]
+mod trash;
 mod util;
 mod watch;
+#[cfg(target_os = "linux")]
+mod xattr;
 
 pub use self::open::Open;
 pub use cd::Cd;
@@ -24,5 +40,21 @@ pub use mv::Mv;
 pub use rm::Rm;
 pub use save::Save;
 pub use start::Start;
+pub use stat::Stat;
 pub use touch::Touch;
+#[cfg(all(
+    feature = "trash-support",
+    any(
+        target_os = "windows",
+        all(
+            unix,
+            not(target_os = "macos"),
+            not(target_os = "ios"),
+            not(target_os = "android")
+        )
+    )
+))]
+pub use trash::{Trash, TrashList, TrashRestore};
 pub use watch::Watch;
+#[cfg(target_os = "linux")]
+pub use xattr::{Xattr, XattrGet, XattrSet};
@@ -1,13 +1,13 @@
 use std::path::{Path, PathBuf};
 
-use super::util::try_interaction;
+use super::util::{dry_run_transfer, is_dry_run, try_interaction};
 use nu_engine::env::current_dir;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
-    Spanned, SyntaxShape, Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 
 const GLOB_PARAMS: nu_glob::MatchOptions = nu_glob::MatchOptions {
@@ -53,6 +53,11 @@ impl Command for Mv {
             )
             .switch("force", "overwrite the destination.", Some('f'))
             .switch("interactive", "ask user to confirm action", Some('i'))
+            .switch(
+                "dry-run",
+                "report the moves that would be made without moving",
+                None,
+            )
             .category(Category::FileSystem)
     }
 
@@ -177,6 +182,27 @@ impl Command for Mv {
         }
 
         let span = call.head;
+
+        if is_dry_run(engine_state, call) {
+            let vals = sources
+                .iter()
+                .flatten()
+                .map(|entry| {
+                    // Mirror move_file: moving into a directory keeps the file name
+                    let destination = if destination.is_dir() {
+                        match entry.file_name() {
+                            Some(name) => destination.join(name),
+                            None => destination.clone(),
+                        }
+                    } else {
+                        destination.clone()
+                    };
+                    dry_run_transfer("mv", entry, &destination, span)
+                })
+                .collect();
+            return Ok(Value::List { vals, span }.into_pipeline_data());
+        }
+
         sources
             .into_iter()
             .flatten()
@@ -4,9 +4,13 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::util::BufferedReader;
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, RawStream, ShellError,
-    Signature, Spanned, SyntaxShape, Type, Value,
+    Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
-use std::io::BufReader;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Duration;
 
 #[cfg(feature = "sqlite")]
 use crate::database::SQLiteDatabase;
@@ -44,6 +48,11 @@ impl Command for Open {
                 "optional additional files to open",
             )
             .switch("raw", "open file as raw binary", Some('r'))
+            .switch(
+                "follow",
+                "stream new lines as the file grows, detecting log rotation",
+                Some('f'),
+            )
             .category(Category::FileSystem)
     }
 
@@ -85,6 +94,49 @@ impl Command for Open {
             path_params.insert(0, filename);
         }
 
+        if call.has_flag("follow") {
+            if path_params.len() > 1 {
+                return Err(ShellError::GenericError(
+                    "open --follow takes a single file".into(),
+                    "can only follow one file at a time".into(),
+                    Some(path_params[1].span),
+                    None,
+                    Vec::new(),
+                ));
+            }
+
+            let path = Spanned {
+                item: nu_utils::strip_ansi_string_unlikely(path_params.remove(0).item),
+                span: call_span,
+            };
+            let full_path = nu_path::expand_path_with(&path.item, &cwd);
+
+            // Surface a missing or unreadable file immediately rather than
+            // silently waiting for it to appear
+            std::fs::File::open(&full_path).map_err(|err| {
+                ShellError::GenericError(
+                    format!("Could not open {}", full_path.display()),
+                    err.to_string(),
+                    Some(path.span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+
+            let follower = FollowFile {
+                path: full_path,
+                reader: None,
+                pos: 0,
+                #[cfg(unix)]
+                inode: 0,
+                pending: String::new(),
+                span: call_span,
+                ctrlc: ctrlc.clone(),
+            };
+
+            return Ok(follower.into_pipeline_data(ctrlc));
+        }
+
         let mut output = vec![];
 
         for path in path_params.into_iter() {
@@ -162,11 +214,20 @@ impl Command for Open {
                         trim_end_newline: false,
                     };
 
+                    // `file_formats` in the config can redirect an extension to
+                    // any `from ...` command, and extension-less files get a
+                    // shot at content-based detection.
                     let ext = if raw {
                         None
                     } else {
-                        path.extension()
-                            .map(|name| name.to_string_lossy().to_string())
+                        let config = engine_state.get_config();
+                        match path.extension() {
+                            Some(name) => {
+                                let ext = name.to_string_lossy().to_string();
+                                Some(config.file_formats.get(&ext).cloned().unwrap_or(ext))
+                            }
+                            None => detect_format_from_content(path).map(String::from),
+                        }
                     };
 
                     if let Some(ext) = ext {
@@ -241,10 +302,137 @@ impl Command for Open {
                 example: "open myfile.txt --raw | decode utf-8",
                 result: None,
             },
+            Example {
+                description: "Follow a log file, streaming each appended line",
+                example: "open --follow app.log | parse '{level} {message}' | where level == ERROR",
+                result: None,
+            },
         ]
     }
 }
 
+// Streams the lines of a file as they are appended, reopening the file when it
+// is rotated away (new inode or truncation). Lines are only emitted once they
+// are newline-terminated so half-written entries stay intact.
+struct FollowFile {
+    path: PathBuf,
+    reader: Option<BufReader<std::fs::File>>,
+    pos: u64,
+    #[cfg(unix)]
+    inode: u64,
+    pending: String,
+    span: Span,
+    ctrlc: Option<Arc<AtomicBool>>,
+}
+
+impl FollowFile {
+    fn reopen(&mut self) {
+        self.pos = 0;
+        self.pending.clear();
+        match std::fs::File::open(&self.path) {
+            Ok(file) => {
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::MetadataExt;
+                    self.inode = file.metadata().map(|md| md.ino()).unwrap_or(0);
+                }
+                self.reader = Some(BufReader::new(file));
+            }
+            // The file may be gone mid-rotation; keep waiting for it
+            Err(_) => self.reader = None,
+        }
+    }
+
+    fn rotated(&self) -> bool {
+        match std::fs::metadata(&self.path) {
+            Ok(md) => {
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::MetadataExt;
+                    if md.ino() != self.inode {
+                        return true;
+                    }
+                }
+                md.len() < self.pos
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl Iterator for FollowFile {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        loop {
+            if nu_utils::ctrl_c::was_pressed(&self.ctrlc) {
+                return None;
+            }
+
+            if self.reader.is_none() {
+                self.reopen();
+            }
+
+            if let Some(reader) = &mut self.reader {
+                let mut line = String::new();
+                match reader.read_line(&mut line) {
+                    Ok(0) => {
+                        if self.rotated() {
+                            self.reader = None;
+                            continue;
+                        }
+                    }
+                    Ok(n) => {
+                        self.pos += n as u64;
+                        self.pending.push_str(&line);
+                        if self.pending.ends_with('\n') {
+                            let mut line = std::mem::take(&mut self.pending);
+                            while line.ends_with('\n') || line.ends_with('\r') {
+                                line.pop();
+                            }
+                            return Some(Value::String {
+                                val: line,
+                                span: self.span,
+                            });
+                        }
+                        continue;
+                    }
+                    Err(_) => self.reader = None,
+                }
+            }
+
+            std::thread::sleep(Duration::from_millis(100));
+        }
+    }
+}
+
+// Sniff the format of a file without an extension from its first bytes. Only
+// unambiguous prefixes are claimed; anything else stays raw as before.
+fn detect_format_from_content(path: &Path) -> Option<&'static str> {
+    use std::io::Read;
+
+    let mut file = std::fs::File::open(path).ok()?;
+    let mut buffer = [0u8; 1024];
+    let n = file.read(&mut buffer).ok()?;
+    let prefix = String::from_utf8_lossy(&buffer[..n]);
+    let trimmed = prefix.trim_start();
+
+    if trimmed.starts_with("<?xml") {
+        Some("xml")
+    } else if trimmed.starts_with('{') {
+        Some("json")
+    } else if let Some(rest) = trimmed.strip_prefix('[') {
+        // A lone '[' could just as well start an ini-style section header, so
+        // only claim json when the first element looks like a json value.
+        match rest.trim_start().chars().next() {
+            Some('{' | '[' | '"' | '-' | '0'..='9') => Some("json"),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
 fn permission_denied(dir: impl AsRef<Path>) -> bool {
     match dir.as_ref().read_dir() {
         Err(e) => matches!(e.kind(), std::io::ErrorKind::PermissionDenied),
@@ -9,15 +9,15 @@ use std::io::ErrorKind;
 use std::os::unix::prelude::FileTypeExt;
 use std::path::PathBuf;
 
-use super::util::try_interaction;
+use super::util::{dry_run_target, is_dry_run, try_interaction};
 
 use nu_engine::env::current_dir;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
-    Spanned, SyntaxShape, Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
 
 const GLOB_PARAMS: nu_glob::MatchOptions = nu_glob::MatchOptions {
@@ -70,6 +70,11 @@ impl Command for Rm {
                 "ask user to confirm action only once",
                 Some('I'),
             )
+            .switch(
+                "dry-run",
+                "report the files that would be deleted without deleting them",
+                None,
+            )
             .rest(
                 "rest",
                 SyntaxShape::GlobPattern,
@@ -299,6 +304,17 @@ fn rm(
         ));
     }
 
+    if is_dry_run(engine_state, call) {
+        // Sort the targets so the report is deterministic
+        let mut targets: Vec<PathBuf> = all_targets.into_keys().collect();
+        targets.sort();
+        let vals = targets
+            .iter()
+            .map(|target| dry_run_target("rm", target, span))
+            .collect();
+        return Ok(Value::List { vals, span }.into_pipeline_data());
+    }
+
     if interactive_once {
         let (interaction, confirmed) = try_interaction(
             interactive_once,
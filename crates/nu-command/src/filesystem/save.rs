@@ -1,9 +1,10 @@
+use super::util::{dry_run_target, is_dry_run};
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, RawStream, ShellError, Signature, Span, Spanned, SyntaxShape,
-    Type, Value,
+    Category, Example, IntoPipelineData, PipelineData, RawStream, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
 };
 use std::fs::File;
 use std::io::{BufWriter, Write};
@@ -51,6 +52,11 @@ impl Command for Save {
             .switch("append", "append input to the end of the file", Some('a'))
             .switch("force", "overwrite the destination", Some('f'))
             .switch("progress", "enable progress bar", Some('p'))
+            .switch(
+                "dry-run",
+                "report the file that would be written without writing it",
+                None,
+            )
             .category(Category::FileSystem)
     }
 
@@ -71,6 +77,15 @@ impl Command for Save {
         let path = call.req::<Spanned<String>>(engine_state, stack, 0)?;
         let stderr_path = call.get_flag::<Spanned<String>>(engine_state, stack, "stderr")?;
 
+        if is_dry_run(engine_state, call) {
+            // The input is dropped unwritten
+            let mut vals = vec![dry_run_target("save", Path::new(&path.item), span)];
+            if let Some(stderr_path) = &stderr_path {
+                vals.push(dry_run_target("save", Path::new(&stderr_path.item), span));
+            }
+            return Ok(Value::List { vals, span }.into_pipeline_data());
+        }
+
         match input {
             PipelineData::ExternalStream { stdout: None, .. } => {
                 // Open files to possibly truncate them
@@ -213,6 +228,13 @@ fn convert_to_extension(
     input: PipelineData,
     span: Span,
 ) -> Result<Vec<u8>, ShellError> {
+    // Honor the same extension -> format registry as `open`
+    let extension = engine_state
+        .get_config()
+        .file_formats
+        .get(extension)
+        .map(|format| format.as_str())
+        .unwrap_or(extension);
     let converter = engine_state.find_decl(format!("to {extension}").as_bytes(), &[]);
 
     let output = match converter {
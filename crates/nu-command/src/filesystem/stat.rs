@@ -0,0 +1,286 @@
+use chrono::{DateTime, Local};
+use nu_engine::{current_dir, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+#[derive(Clone)]
+pub struct Stat;
+
+impl Command for Stat {
+    fn name(&self) -> &str {
+        "stat"
+    }
+
+    fn usage(&self) -> &str {
+        "Show detailed filesystem metadata for a path."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The record goes beyond the `ls` columns: it includes the inode, the device,
+ownership, the filesystem and the extended attributes where the platform
+provides them. Use `xattr get` to read an individual attribute value."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("stat")
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .required("path", SyntaxShape::Filepath, "the path to inspect")
+            .switch(
+                "deref",
+                "Dereference symlinks and report on their targets",
+                Some('d'),
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["file info", "metadata", "inode", "xattr"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let deref = call.has_flag("deref");
+        let span = call.head;
+        let cwd = current_dir(engine_state, stack)?;
+        let full_path = nu_path::expand_path_with(&path.item, cwd);
+
+        let metadata = if deref {
+            std::fs::metadata(&full_path)
+        } else {
+            std::fs::symlink_metadata(&full_path)
+        }
+        .map_err(|err| {
+            ShellError::GenericError(
+                format!("Could not stat {}", full_path.display()),
+                err.to_string(),
+                Some(path.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let mut cols: Vec<String> = vec![];
+        let mut vals: Vec<Value> = vec![];
+
+        cols.push("path".into());
+        vals.push(Value::String {
+            val: full_path.display().to_string(),
+            span,
+        });
+
+        cols.push("type".into());
+        vals.push(Value::String {
+            val: file_type_name(&metadata.file_type()).into(),
+            span,
+        });
+
+        cols.push("size".into());
+        vals.push(Value::Filesize {
+            val: metadata.len() as i64,
+            span,
+        });
+
+        cols.push("readonly".into());
+        vals.push(Value::Bool {
+            val: metadata.permissions().readonly(),
+            span,
+        });
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+            cols.push("mode".into());
+            vals.push(Value::String {
+                val: umask::Mode::from(metadata.permissions().mode()).to_string(),
+                span,
+            });
+
+            cols.push("num_links".into());
+            vals.push(Value::Int {
+                val: metadata.nlink() as i64,
+                span,
+            });
+
+            cols.push("inode".into());
+            vals.push(Value::Int {
+                val: metadata.ino() as i64,
+                span,
+            });
+
+            cols.push("device".into());
+            vals.push(Value::Int {
+                val: metadata.dev() as i64,
+                span,
+            });
+
+            cols.push("uid".into());
+            vals.push(Value::Int {
+                val: metadata.uid() as i64,
+                span,
+            });
+
+            cols.push("user".into());
+            vals.push(match users::get_user_by_uid(metadata.uid()) {
+                Some(user) => Value::String {
+                    val: user.name().to_string_lossy().into(),
+                    span,
+                },
+                None => Value::Nothing { span },
+            });
+
+            cols.push("gid".into());
+            vals.push(Value::Int {
+                val: metadata.gid() as i64,
+                span,
+            });
+
+            cols.push("group".into());
+            vals.push(match users::get_group_by_gid(metadata.gid()) {
+                Some(group) => Value::String {
+                    val: group.name().to_string_lossy().into(),
+                    span,
+                },
+                None => Value::Nothing { span },
+            });
+        }
+
+        cols.push("created".into());
+        vals.push(date_value(metadata.created().ok(), span));
+
+        cols.push("accessed".into());
+        vals.push(date_value(metadata.accessed().ok(), span));
+
+        cols.push("modified".into());
+        vals.push(date_value(metadata.modified().ok(), span));
+
+        #[cfg(target_os = "linux")]
+        {
+            cols.push("filesystem".into());
+            vals.push(match filesystem_name(&full_path) {
+                Some(name) => Value::String { val: name, span },
+                None => Value::Nothing { span },
+            });
+
+            let xattrs = super::xattr::list_xattrs(&full_path).unwrap_or_default();
+
+            cols.push("acl".into());
+            vals.push(Value::Bool {
+                val: xattrs
+                    .iter()
+                    .any(|name| name.starts_with("system.posix_acl_")),
+                span,
+            });
+
+            cols.push("xattrs".into());
+            vals.push(Value::List {
+                vals: xattrs
+                    .into_iter()
+                    .map(|val| Value::String { val, span })
+                    .collect(),
+                span,
+            });
+        }
+
+        Ok(Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the metadata of a file",
+                example: "stat Cargo.toml",
+                result: None,
+            },
+            Example {
+                description: "Show the inode of a file",
+                example: "stat Cargo.toml | get inode",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn file_type_name(file_type: &std::fs::FileType) -> &'static str {
+    if file_type.is_dir() {
+        return "dir";
+    }
+    if file_type.is_symlink() {
+        return "symlink";
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileTypeExt;
+        if file_type.is_fifo() {
+            return "pipe";
+        }
+        if file_type.is_socket() {
+            return "socket";
+        }
+        if file_type.is_block_device() {
+            return "block device";
+        }
+        if file_type.is_char_device() {
+            return "char device";
+        }
+    }
+
+    "file"
+}
+
+fn date_value(time: Option<SystemTime>, span: Span) -> Value {
+    match time {
+        Some(time) => {
+            let local: DateTime<Local> = time.into();
+            Value::Date {
+                val: local.with_timezone(local.offset()),
+                span,
+            }
+        }
+        None => Value::Nothing { span },
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn filesystem_name(path: &std::path::Path) -> Option<String> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let path = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut buf: libc::statfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statfs(path.as_ptr(), &mut buf) } != 0 {
+        return None;
+    }
+
+    Some(match buf.f_type {
+        0xEF53 => "ext2/ext3/ext4".into(),
+        0x9123_683E => "btrfs".into(),
+        0x5846_5342 => "xfs".into(),
+        0x0102_1994 => "tmpfs".into(),
+        0x794C_7630 => "overlayfs".into(),
+        0x6969 => "nfs".into(),
+        0x5346_544E => "ntfs".into(),
+        0x4D44 => "vfat".into(),
+        0x7371_7368 => "squashfs".into(),
+        0xF2F5_2010 => "f2fs".into(),
+        0x2FC1_2FC1 => "zfs".into(),
+        other => format!("{other:#x}"),
+    })
+}
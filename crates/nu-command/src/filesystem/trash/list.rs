@@ -0,0 +1,109 @@
+use chrono::{Local, TimeZone};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct TrashList;
+
+impl Command for TrashList {
+    fn name(&self) -> &str {
+        "trash list"
+    }
+
+    fn usage(&self) -> &str {
+        "List the items that are currently in the platform trash."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The id column identifies an item for `trash restore`."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("trash list")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .category(Category::FileSystem)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["recycle bin", "deleted"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let mut items = trash::os_limited::list().map_err(|err| {
+            ShellError::GenericError(
+                "Could not list the trash".into(),
+                err.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        // Most recently deleted first; ties broken by name for a deterministic order
+        items.sort_by(|a, b| {
+            b.time_deleted
+                .cmp(&a.time_deleted)
+                .then(a.name.cmp(&b.name))
+        });
+
+        let vals = items
+            .into_iter()
+            .map(|item| {
+                let deleted = match Local.timestamp_opt(item.time_deleted, 0) {
+                    chrono::LocalResult::Single(time) => Value::Date {
+                        val: time.into(),
+                        span,
+                    },
+                    _ => Value::Nothing { span },
+                };
+
+                Value::Record {
+                    cols: Arc::new(vec![
+                        "id".into(),
+                        "name".into(),
+                        "original_path".into(),
+                        "deleted".into(),
+                    ]),
+                    vals: vec![
+                        Value::String {
+                            val: item.id.to_string_lossy().into_owned(),
+                            span,
+                        },
+                        Value::String {
+                            val: item.name.clone(),
+                            span,
+                        },
+                        Value::String {
+                            val: item.original_path().to_string_lossy().into_owned(),
+                            span,
+                        },
+                        deleted,
+                    ],
+                    span,
+                }
+            })
+            .collect();
+
+        Ok(Value::List { vals, span }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List the trashed items, most recently deleted first",
+            example: "trash list",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,7 @@
+mod list;
+mod restore;
+mod trash_;
+
+pub use list::TrashList;
+pub use restore::TrashRestore;
+pub use trash_::Trash;
@@ -0,0 +1,93 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct TrashRestore;
+
+impl Command for TrashRestore {
+    fn name(&self) -> &str {
+        "trash restore"
+    }
+
+    fn usage(&self) -> &str {
+        "Restore an item from the platform trash to its original location."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Use `trash list` to look up the id of the item to restore."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("trash restore")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "id",
+                SyntaxShape::String,
+                "the id of the item to restore, as reported by `trash list`",
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["recycle bin", "undelete", "undo"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let id: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let span = call.head;
+
+        let items = trash::os_limited::list().map_err(|err| {
+            ShellError::GenericError(
+                "Could not list the trash".into(),
+                err.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let Some(item) = items
+            .into_iter()
+            .find(|item| item.id.to_string_lossy() == id.item)
+        else {
+            return Err(ShellError::GenericError(
+                format!("No trash item with id {}", id.item),
+                "not found in the trash".into(),
+                Some(id.span),
+                Some("use `trash list` to see the available ids".into()),
+                Vec::new(),
+            ));
+        };
+
+        let original_path = item.original_path();
+        trash::os_limited::restore_all([item]).map_err(|err| {
+            ShellError::GenericError(
+                format!("Could not restore to {}", original_path.to_string_lossy()),
+                err.to_string(),
+                Some(id.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Restore the most recently deleted item",
+            example: "trash restore (trash list | get 0.id)",
+            result: None,
+        }]
+    }
+}
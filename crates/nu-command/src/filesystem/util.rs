@@ -1,9 +1,11 @@
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use nu_engine::env::current_dir_str;
 use nu_path::canonicalize_with;
+use nu_protocol::ast::Call;
 use nu_protocol::engine::{EngineState, Stack};
-use nu_protocol::ShellError;
+use nu_protocol::{ShellError, Span, Value};
 
 use dialoguer::Input;
 use std::error::Error;
@@ -132,3 +134,53 @@ fn get_interactive_confirmation(prompt: String) -> Result<bool, Box<dyn Error>>
         Ok(false)
     }
 }
+
+/// Whether the command should only report its planned operations; enabled either
+/// engine-wide with `nu --dry-run` or per call with a `--dry-run` flag.
+pub fn is_dry_run(engine_state: &EngineState, call: &Call) -> bool {
+    engine_state.dry_run || call.has_flag("dry-run")
+}
+
+/// One row of a dry-run report for an operation on a single path.
+pub fn dry_run_target(operation: &str, target: &Path, span: Span) -> Value {
+    Value::Record {
+        cols: Arc::new(vec!["operation".into(), "target".into()]),
+        vals: vec![
+            Value::String {
+                val: operation.into(),
+                span,
+            },
+            Value::String {
+                val: target.to_string_lossy().into_owned(),
+                span,
+            },
+        ],
+        span,
+    }
+}
+
+/// One row of a dry-run report for an operation from a source to a destination.
+pub fn dry_run_transfer(operation: &str, source: &Path, destination: &Path, span: Span) -> Value {
+    Value::Record {
+        cols: Arc::new(vec![
+            "operation".into(),
+            "source".into(),
+            "destination".into(),
+        ]),
+        vals: vec![
+            Value::String {
+                val: operation.into(),
+                span,
+            },
+            Value::String {
+                val: source.to_string_lossy().into_owned(),
+                span,
+            },
+            Value::String {
+                val: destination.to_string_lossy().into_owned(),
+                span,
+            },
+        ],
+        span,
+    }
+}
@@ -0,0 +1,85 @@
+use nu_engine::{current_dir, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct XattrGet;
+
+impl Command for XattrGet {
+    fn name(&self) -> &str {
+        "xattr get"
+    }
+
+    fn usage(&self) -> &str {
+        "Get the value of an extended attribute of a file."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The value is returned as a string when it is valid UTF-8 and as binary data otherwise."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("xattr get")
+            .input_output_types(vec![
+                (Type::Nothing, Type::String),
+                (Type::Nothing, Type::Binary),
+            ])
+            .required("path", SyntaxShape::Filepath, "the file to read from")
+            .required(
+                "name",
+                SyntaxShape::String,
+                "the name of the extended attribute",
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["extended attributes", "metadata"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let name: Spanned<String> = call.req(engine_state, stack, 1)?;
+        let span = call.head;
+        let cwd = current_dir(engine_state, stack)?;
+        let full_path = nu_path::expand_path_with(&path.item, cwd);
+
+        let value = super::get_xattr(&full_path, &name.item).map_err(|err| {
+            ShellError::GenericError(
+                format!("Could not get the {} attribute", name.item),
+                err.to_string(),
+                Some(path.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let value = match String::from_utf8(value) {
+            Ok(val) => Value::String { val, span },
+            Err(err) => Value::Binary {
+                val: err.into_bytes(),
+                span,
+            },
+        };
+
+        Ok(value.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the value of the user.comment attribute of a file",
+            example: "xattr get file.txt user.comment",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,74 @@
+use nu_engine::{current_dir, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+
+#[derive(Clone)]
+pub struct XattrSet;
+
+impl Command for XattrSet {
+    fn name(&self) -> &str {
+        "xattr set"
+    }
+
+    fn usage(&self) -> &str {
+        "Set an extended attribute of a file."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "User attributes must be named with the `user.` prefix, e.g. `user.comment`."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("xattr set")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("path", SyntaxShape::Filepath, "the file to write to")
+            .required(
+                "name",
+                SyntaxShape::String,
+                "the name of the extended attribute",
+            )
+            .required("value", SyntaxShape::String, "the value to set")
+            .category(Category::FileSystem)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["extended attributes", "metadata"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let name: Spanned<String> = call.req(engine_state, stack, 1)?;
+        let value: Spanned<String> = call.req(engine_state, stack, 2)?;
+        let cwd = current_dir(engine_state, stack)?;
+        let full_path = nu_path::expand_path_with(&path.item, cwd);
+
+        super::set_xattr(&full_path, &name.item, value.item.as_bytes()).map_err(|err| {
+            ShellError::GenericError(
+                format!("Could not set the {} attribute", name.item),
+                err.to_string(),
+                Some(path.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Attach a comment to a file",
+            example: "xattr set file.txt user.comment 'reviewed'",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,51 @@
+use nu_engine::get_full_help;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value};
+
+#[derive(Clone)]
+pub struct Xattr;
+
+impl Command for Xattr {
+    fn name(&self) -> &str {
+        "xattr"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("xattr")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .category(Category::FileSystem)
+    }
+
+    fn usage(&self) -> &str {
+        "Various commands for working with extended file attributes."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["extended attributes", "metadata"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &Self.signature(),
+                &Self.examples(),
+                engine_state,
+                stack,
+                self.is_parser_keyword(),
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
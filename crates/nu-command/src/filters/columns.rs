@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
     Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Columns;
@@ -131,7 +132,7 @@ fn getcol(
             })
             .into_pipeline_data(ctrlc)
             .set_metadata(metadata)),
-        PipelineData::Value(Value::Record { cols, .. }, ..) => Ok(cols
+        PipelineData::Value(Value::Record { cols, .. }, ..) => Ok(Arc::unwrap_or_clone(cols)
             .into_iter()
             .map(move |x| Value::String { val: x, span: head })
             .into_pipeline_data(ctrlc)
@@ -3,6 +3,7 @@ use nu_protocol::{
     ast::Call, engine::Command, engine::EngineState, engine::Stack, Category, Example,
     PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Compact;
@@ -64,7 +65,7 @@ impl Command for Compact {
                 example: r#"[["Hello" "World"]; [null 3]] | compact World"#,
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["Hello".into(), "World".into()],
+                        cols: Arc::new(vec!["Hello".into(), "World".into()]),
                         vals: vec![Value::nothing(Span::test_data()), Value::test_int(3)],
                         span: Span::test_data(),
                     }],
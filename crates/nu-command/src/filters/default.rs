@@ -4,6 +4,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Default;
@@ -104,7 +105,7 @@ fn default(
                     }
 
                     if !found {
-                        cols.push(column.item.clone());
+                        Arc::make_mut(&mut cols).push(column.item.clone());
                         vals.push(value.clone());
                     }
 
@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, FromValue, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData,
     ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct DropColumn;
@@ -67,12 +68,12 @@ impl Command for DropColumn {
             result: Some(Value::List {
                 vals: vec![
                     Value::Record {
-                        cols: vec!["lib".into()],
+                        cols: Arc::new(vec!["lib".into()]),
                         vals: vec![Value::test_string("nu-lib")],
                         span: Span::test_data(),
                     },
                     Value::Record {
-                        cols: vec!["lib".into()],
+                        cols: Arc::new(vec!["lib".into()]),
                         vals: vec![Value::test_string("nu-core")],
                         span: Span::test_data(),
                     },
@@ -109,11 +110,15 @@ fn dropcol(
                 let mut vals = vec![];
 
                 for path in &keep_columns {
-                    let fetcher = input_val.clone().follow_cell_path(&path.members, false)?;
+                    let fetcher = input_val.follow_cell_path(&path.members, false)?;
                     cols.push(path.into_string());
                     vals.push(fetcher);
                 }
-                output.push(Value::Record { cols, vals, span })
+                output.push(Value::Record {
+                    cols: Arc::new(cols),
+                    vals,
+                    span,
+                })
             }
 
             Ok(output
@@ -133,11 +138,15 @@ fn dropcol(
                 let mut vals = vec![];
 
                 for path in &keep_columns {
-                    let fetcher = input_val.clone().follow_cell_path(&path.members, false)?;
+                    let fetcher = input_val.follow_cell_path(&path.members, false)?;
                     cols.push(path.into_string());
                     vals.push(fetcher);
                 }
-                output.push(Value::Record { cols, vals, span })
+                output.push(Value::Record {
+                    cols: Arc::new(cols),
+                    vals,
+                    span,
+                })
             }
 
             Ok(output
@@ -149,13 +158,18 @@ fn dropcol(
             let mut vals = vec![];
 
             for cell_path in &keep_columns {
-                let result = v.clone().follow_cell_path(&cell_path.members, false)?;
+                let result = v.follow_cell_path(&cell_path.members, false)?;
 
                 cols.push(cell_path.into_string());
                 vals.push(result);
             }
 
-            Ok(Value::Record { cols, vals, span }.into_pipeline_data())
+            Ok(Value::Record {
+                cols: Arc::new(cols),
+                vals,
+                span,
+            }
+            .into_pipeline_data())
         }
         x => Ok(x),
     }
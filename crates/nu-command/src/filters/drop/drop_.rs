@@ -6,6 +6,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
     SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Drop;
@@ -72,7 +73,7 @@ impl Command for Drop {
                 example: "[[a, b]; [1, 2] [3, 4]] | drop 1",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["a".to_string(), "b".to_string()],
+                        cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                         vals: vec![Value::test_int(1), Value::test_int(2)],
                         span: Span::test_data(),
                     }],
@@ -1,4 +1,5 @@
 use super::utils::chain_error_with_input;
+use crate::progress_bar;
 use nu_engine::{eval_block_with_early_return, CallExt};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Closure, Command, EngineState, Stack};
@@ -48,6 +49,7 @@ with 'transpose' first."#
                 "the closure to run",
             )
             .switch("keep-empty", "keep empty result cells", Some('k'))
+            .switch("progress", "enable progress bar", Some('p'))
             .category(Category::Filters)
     }
 
@@ -123,6 +125,19 @@ with 'transpose' first."#
         let capture_block: Closure = call.req(engine_state, stack, 0)?;
 
         let keep_empty = call.has_flag("keep-empty");
+        let progress = call.has_flag("progress");
+
+        // We only know how many iterations are coming when the input is a
+        // fully-realized list; streams keep a plain counter instead.
+        let bar = if progress {
+            let total_items = match &input {
+                PipelineData::Value(Value::List { vals, .. }, ..) => Some(vals.len() as u64),
+                _ => None,
+            };
+            Some(progress_bar::NuProgressBar::new_counter(total_items))
+        } else {
+            None
+        };
 
         let metadata = input.metadata();
         let ctrlc = engine_state.ctrlc.clone();
@@ -148,6 +163,10 @@ with 'transpose' first."#
                     // Hence, a 'cd' in the first loop won't affect the next loop.
                     stack.with_env(&orig_env_vars, &orig_env_hidden);
 
+                    if let Some(bar) = &bar {
+                        bar.incr();
+                    }
+
                     if let Some(var) = block.signature.get_positional(0) {
                         if let Some(var_id) = &var.var_id {
                             stack.add_var(*var_id, x.clone());
@@ -186,6 +205,10 @@ with 'transpose' first."#
                     // Hence, a 'cd' in the first loop won't affect the next loop.
                     stack.with_env(&orig_env_vars, &orig_env_hidden);
 
+                    if let Some(bar) = &bar {
+                        bar.incr();
+                    }
+
                     let x = match x {
                         Ok(x) => x,
                         Err(ShellError::Break(_)) => return None,
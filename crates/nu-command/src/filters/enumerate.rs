@@ -4,6 +4,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
     Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Enumerate;
@@ -34,17 +35,17 @@ impl Command for Enumerate {
             result: Some(Value::List {
                 vals: vec![
                     Value::Record {
-                        cols: vec!["index".into(), "item".into()],
+                        cols: Arc::new(vec!["index".into(), "item".into()]),
                         vals: vec![Value::test_int(0), Value::test_string("a")],
                         span: Span::test_data(),
                     },
                     Value::Record {
-                        cols: vec!["index".into(), "item".into()],
+                        cols: Arc::new(vec!["index".into(), "item".into()]),
                         vals: vec![Value::test_int(1), Value::test_string("b")],
                         span: Span::test_data(),
                     },
                     Value::Record {
-                        cols: vec!["index".into(), "item".into()],
+                        cols: Arc::new(vec!["index".into(), "item".into()]),
                         vals: vec![Value::test_int(2), Value::test_string("c")],
                         span: Span::test_data(),
                     },
@@ -69,7 +70,7 @@ impl Command for Enumerate {
             .into_iter()
             .enumerate()
             .map(move |(idx, x)| Value::Record {
-                cols: vec!["index".into(), "item".into()],
+                cols: Arc::new(vec!["index".into(), "item".into()]),
                 vals: vec![
                     Value::Int {
                         val: idx as i64,
@@ -6,6 +6,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
     Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Filter;
@@ -205,7 +206,7 @@ a variable. On the other hand, the "row condition" syntax is not supported."#
                 example: "[{a: 1} {a: 2}] | filter {|x| $x.a > 1}",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["a".to_string()],
+                        cols: Arc::new(vec!["a".to_string()]),
                         vals: vec![Value::test_int(2)],
                         span: Span::test_data(),
                     }],
@@ -217,7 +218,7 @@ a variable. On the other hand, the "row condition" syntax is not supported."#
                 example: "let cond = {|x| $x.a > 1}; [{a: 1} {a: 2}] | filter $cond",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["a".to_string()],
+                        cols: Arc::new(vec!["a".to_string()]),
                         vals: vec![Value::test_int(2)],
                         span: Span::test_data(),
                     }],
@@ -1,6 +1,5 @@
 use nu_cmd_lang::help::highlight_search_string;
 
-use fancy_regex::Regex;
 use lscolors::{Color as LsColors_Color, LsColors, Style as LsColors_Style};
 use nu_ansi_term::{Color, Style};
 use nu_color_config::StyleComputer;
@@ -12,6 +11,7 @@ use nu_protocol::{
     PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 use nu_utils::get_ls_colors;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Find;
@@ -190,10 +190,12 @@ fn find_with_regex(
 
     let regex = flags.to_string() + regex.as_str();
 
-    let re = Regex::new(regex.as_str()).map_err(|e| ShellError::TypeMismatch {
-        err_message: format!("invalid regex: {e}"),
-        span,
-    })?;
+    let re = engine_state
+        .compiled_regex(regex.as_str())
+        .map_err(|e| ShellError::TypeMismatch {
+            err_message: format!("invalid regex: {e}"),
+            span,
+        })?;
 
     input.filter(
         move |value| match value {
@@ -286,7 +288,7 @@ fn highlight_terms_in_record(
         }
     }
     Value::Record {
-        cols: cols.to_vec(),
+        cols: Arc::new(cols.to_vec()),
         vals: output,
         span: *span,
     }
@@ -334,7 +336,7 @@ fn find_with_rest_and_highlight(
             .map(
                 move |mut x| match &mut x {
                     Value::Record { cols, vals, span } => highlight_terms_in_record(
-                        cols,
+                        Arc::make_mut(cols).as_mut_slice(),
                         vals,
                         span,
                         &config,
@@ -362,6 +364,8 @@ fn find_with_rest_and_highlight(
                         | Value::Date { .. }
                         | Value::Range { .. }
                         | Value::Float { .. }
+                        | Value::Decimal { .. }
+                        | Value::BigInt { .. }
                         | Value::Block { .. }
                         | Value::Closure { .. }
                         | Value::Nothing { .. }
@@ -418,7 +422,7 @@ fn find_with_rest_and_highlight(
             stream
                 .map(move |mut x| match &mut x {
                     Value::Record { cols, vals, span } => highlight_terms_in_record(
-                        cols,
+                        Arc::make_mut(cols).as_mut_slice(),
                         vals,
                         span,
                         &config,
@@ -443,6 +447,8 @@ fn find_with_rest_and_highlight(
                         | Value::Date { .. }
                         | Value::Range { .. }
                         | Value::Float { .. }
+                        | Value::Decimal { .. }
+                        | Value::BigInt { .. }
                         | Value::Block { .. }
                         | Value::Closure { .. }
                         | Value::Nothing { .. }
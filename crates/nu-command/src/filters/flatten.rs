@@ -6,6 +6,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Flatten;
@@ -85,22 +86,22 @@ impl Command for Flatten {
                 result: Some(Value::List{
                     vals: vec![
                         Value::Record{
-                            cols: vec!["a".to_string(), "d".to_string(), "e".to_string()],
+                            cols: Arc::new(vec!["a".to_string(), "d".to_string(), "e".to_string()]),
                             vals: vec![Value::test_string("b"), Value::test_int(1), Value::List{vals: vec![Value::test_int(4), Value::test_int(3)], span: Span::test_data()}                            ],
                             span: Span::test_data()
                         },
                         Value::Record{
-                            cols: vec!["a".to_string(), "d".to_string(), "e".to_string()],
+                            cols: Arc::new(vec!["a".to_string(), "d".to_string(), "e".to_string()]),
                             vals: vec![Value::test_string("b"), Value::test_int(2), Value::List{vals: vec![Value::test_int(4), Value::test_int(3)], span: Span::test_data()}                            ],
                             span: Span::test_data()
                         },
                         Value::Record{
-                            cols: vec!["a".to_string(), "d".to_string(), "e".to_string()],
+                            cols: Arc::new(vec!["a".to_string(), "d".to_string(), "e".to_string()]),
                             vals: vec![Value::test_string("b"), Value::test_int(3), Value::List{vals: vec![Value::test_int(4), Value::test_int(3)], span: Span::test_data()}                            ],
                             span: Span::test_data()
                         },
                         Value::Record{
-                            cols: vec!["a".to_string(), "d".to_string(), "e".to_string()],
+                            cols: Arc::new(vec!["a".to_string(), "d".to_string(), "e".to_string()]),
                             vals: vec![Value::test_string("b"), Value::test_int(4), Value::List{vals: vec![Value::test_int(4), Value::test_int(3)], span: Span::test_data()}                            ],
                             span: Span::test_data()
                         }
@@ -332,7 +333,7 @@ fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span, all: bool) ->
                             record_vals.push(entry.clone());
                         }
                         let record = Value::Record {
-                            cols: record_cols,
+                            cols: Arc::new(record_cols),
                             vals: record_vals,
                             span: tag,
                         };
@@ -382,7 +383,7 @@ fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span, all: bool) ->
                             }
                         }
                         let record = Value::Record {
-                            cols: record_cols,
+                            cols: Arc::new(record_cols),
                             vals: record_vals,
                             span: tag,
                         };
@@ -391,7 +392,7 @@ fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span, all: bool) ->
                 }
                 None => {
                     let record = Value::Record {
-                        cols: out.keys().map(|f| f.to_string()).collect::<Vec<_>>(),
+                        cols: Arc::new(out.keys().map(|f| f.to_string()).collect::<Vec<_>>()),
                         vals: out.values().cloned().collect(),
                         span: tag,
                     };
@@ -85,7 +85,7 @@ If multiple cell paths are given, this will produce a list of values."#
             let input = input.into_value(span);
 
             for path in paths {
-                let val = input.clone().follow_cell_path(&path.members, !sensitive);
+                let val = input.follow_cell_path(&path.members, !sensitive);
 
                 output.push(val?);
             }
@@ -6,7 +6,9 @@ use nu_protocol::{
     Type, Value,
 };
 
-use indexmap::IndexMap;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct GroupBy;
@@ -54,7 +56,7 @@ impl Command for GroupBy {
                 description: "You can also group by raw values by leaving out the argument",
                 example: "['1' '3' '1' '3' '2' '1' '1'] | group-by",
                 result: Some(Value::Record {
-                    cols: vec!["1".to_string(), "3".to_string(), "2".to_string()],
+                    cols: Arc::new(vec!["1".to_string(), "3".to_string(), "2".to_string()]),
                     vals: vec![
                         Value::List {
                             vals: vec![
@@ -153,7 +155,7 @@ pub fn group_by(
                                 Some(return_value) => return_value.clone(),
                             };
 
-                            keys.push(value.as_string());
+                            keys.push(group_key_string(&value));
                         }
                         Err(_) => {
                             keys.push(Ok(error_key.into()));
@@ -186,7 +188,7 @@ pub fn group_by(
             let block = Box::new(move |idx: usize, row: &Value| match map.get(idx) {
                 Some(Ok(key)) => Ok(key.clone()),
                 Some(Err(reason)) => Err(reason.clone()),
-                None => row.as_string(),
+                None => group_key_string(row),
             });
 
             data_group(&value_list, &Some(block), name)
@@ -197,34 +199,73 @@ pub fn group_by(
     Ok(PipelineData::Value(group_value?, None))
 }
 
+// Render a value as a group label. Values with no natural string form (like
+// records or lists) are labelled by their canonical hash, so composite values
+// can still be grouped.
+fn group_key_string(value: &Value) -> Result<String, ShellError> {
+    match value.as_string() {
+        Ok(key) => Ok(key),
+        Err(_) => Ok(format!("{:016x}", value.canonical_hash(false)?)),
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub fn data_group(
     values: &Value,
-    grouper: &Option<Box<dyn Fn(usize, &Value) -> Result<String, ShellError> + Send>>,
+    grouper: &Option<Box<dyn Fn(usize, &Value) -> Result<String, ShellError> + Send + Sync>>,
     span: Span,
 ) -> Result<Value, ShellError> {
-    let mut groups: IndexMap<String, Vec<Value>> = IndexMap::new();
+    let rows: Vec<Value> = values.clone().into_pipeline_data().into_iter().collect();
 
-    for (idx, value) in values.clone().into_pipeline_data().into_iter().enumerate() {
-        let group_key = if let Some(ref grouper) = grouper {
-            grouper(idx, &value)
+    // Computing the group key (stringifying or hashing whole rows) is the
+    // expensive part, so spread it over the thread pool for large batches
+    let key_of = |(idx, value): (usize, &Value)| {
+        if let Some(ref grouper) = grouper {
+            grouper(idx, value)
         } else {
-            value.as_string()
-        };
-
-        let group = groups.entry(group_key?).or_default();
-        group.push(value);
-    }
+            group_key_string(value)
+        }
+    };
+    let keys: Vec<String> = if rows.len() < crate::MIN_PARALLEL_AGG_ROWS {
+        rows.iter()
+            .enumerate()
+            .map(key_of)
+            .collect::<Result<_, _>>()
+    } else {
+        rows.par_iter()
+            .enumerate()
+            .map(key_of)
+            .collect::<Result<_, _>>()
+    }?;
+
+    // Fold rows into groups partitioned by key hash; each group remembers the
+    // index of its first row so first-seen ordering survives the merge
+    let partitions = crate::fold_partitioned(
+        keys.into_iter().zip(rows.into_iter().enumerate()).collect(),
+        |groups: &mut HashMap<String, (usize, Vec<Value>)>, key, (idx, row)| {
+            let (_, members) = groups.entry(key).or_insert_with(|| (idx, Vec::new()));
+            members.push(row);
+        },
+    );
+    let mut groups: Vec<(String, (usize, Vec<Value>))> = partitions
+        .into_iter()
+        .flat_map(HashMap::into_iter)
+        .collect();
+    groups.sort_by_key(|(_, (first_idx, _))| *first_idx);
 
     let mut cols = vec![];
     let mut vals = vec![];
 
-    for (k, v) in groups {
-        cols.push(k.to_string());
+    for (k, (_, v)) in groups {
+        cols.push(k);
         vals.push(Value::List { vals: v, span });
     }
 
-    Ok(Value::Record { cols, vals, span })
+    Ok(Value::Record {
+        cols: Arc::new(cols),
+        vals,
+        span,
+    })
 }
 
 pub fn group(
@@ -247,7 +288,7 @@ pub fn group(
                     return Err(*error.clone());
                 };
                 match row.get_data_by_key(&column_name.item) {
-                    Some(group_key) => Ok(group_key.as_string()?),
+                    Some(group_key) => group_key_string(&group_key),
                     None => Err(ShellError::CantFindColumn {
                         col_name: column_name.item.to_string(),
                         span: column_name.span,
@@ -259,7 +300,7 @@ pub fn group(
             data_group(values, &Some(block), name)
         }
         Grouper::ByColumn(None) => {
-            let block = Box::new(move |_, row: &Value| row.as_string());
+            let block = Box::new(move |_, row: &Value| group_key_string(row));
 
             data_group(values, &Some(block), name)
         }
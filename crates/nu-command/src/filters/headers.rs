@@ -4,6 +4,7 @@ use nu_protocol::{
     Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type,
     Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Headers;
@@ -38,7 +39,7 @@ impl Command for Headers {
                 example: r#""a b c|1 2 3" | split row "|" | split column " " | headers"#,
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: columns.clone(),
+                        cols: Arc::new(columns.clone()),
                         vals: vec![
                             Value::test_string("1"),
                             Value::test_string("2"),
@@ -55,7 +56,7 @@ impl Command for Headers {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: columns.clone(),
+                            cols: Arc::new(columns.clone()),
                             vals: vec![
                                 Value::test_string("1"),
                                 Value::test_string("2"),
@@ -64,7 +65,7 @@ impl Command for Headers {
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: columns,
+                            cols: Arc::new(columns),
                             vals: vec![
                                 Value::test_string("1"),
                                 Value::test_string("2"),
@@ -101,7 +102,7 @@ fn replace_headers(value: Value, headers: &[String]) -> Result<Value, ShellError
         Value::Record { vals, span, .. } => {
             let vals = vals.into_iter().take(headers.len()).collect();
             Ok(Value::Record {
-                cols: headers.to_owned(),
+                cols: Arc::new(headers.to_owned()),
                 vals,
                 span,
             })
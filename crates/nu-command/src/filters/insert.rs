@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, FromValue, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData,
     ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Insert;
@@ -56,7 +57,7 @@ impl Command for Insert {
             description: "Insert a new entry into a single record",
             example: "{'name': 'nu', 'stars': 5} | insert alias 'Nushell'",
             result: Some(Value::Record {
-                cols: vec!["name".into(), "stars".into(), "alias".into()],
+                cols: Arc::new(vec!["name".into(), "stars".into(), "alias".into()]),
                 vals: vec![
                     Value::test_string("nu"),
                     Value::test_int(5),
@@ -68,7 +69,7 @@ impl Command for Insert {
         Example {
             description: "Insert a new column into a table, populating all rows",
             example: "[[project, lang]; ['Nushell', 'Rust']] | insert type 'shell'",
-            result: Some(Value::List { vals: vec![Value::Record { cols: vec!["project".into(), "lang".into(), "type".into()],
+            result: Some(Value::List { vals: vec![Value::Record { cols: Arc::new(vec!["project".into(), "lang".into(), "type".into()]),
             vals: vec![Value::test_string("Nushell"), Value::test_string("Rust"), Value::test_string("shell")], span: Span::test_data()}], span: Span::test_data()}),
         },
         Example {
@@ -76,7 +77,7 @@ impl Command for Insert {
             example: "[[foo]; [7] [8] [9]] | enumerate | insert bar {|e| $e.item.foo + $e.index } | flatten",
             result: Some(Value::List {
                 vals: vec![Value::Record {
-                    cols: vec!["index".into(), "foo".into(), "bar".into()],
+                    cols: Arc::new(vec!["index".into(), "foo".into(), "bar".into()]),
                     vals: vec![
                         Value::test_int(0),
                         Value::test_int(7),
@@ -84,7 +85,7 @@ impl Command for Insert {
                     ],
                     span: Span::test_data(),
                 }, Value::Record {
-                    cols: vec!["index".into(),"foo".into(), "bar".into()],
+                    cols: Arc::new(vec!["index".into(),"foo".into(), "bar".into()]),
                     vals: vec![
                         Value::test_int(1),
                         Value::test_int(8),
@@ -92,7 +93,7 @@ impl Command for Insert {
                     ],
                     span: Span::test_data(),
                 }, Value::Record {
-                    cols: vec!["index".into(), "foo".into(), "bar".into()],
+                    cols: Arc::new(vec!["index".into(), "foo".into(), "bar".into()]),
                     vals: vec![
                         Value::test_int(2),
                         Value::test_int(9),
@@ -1,11 +1,10 @@
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{
-    Config, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
-};
+use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value};
 use std::cmp::max;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Join;
@@ -88,7 +87,7 @@ impl Command for Join {
                 Value::String { val: l_on, .. },
                 Value::String { val: r_on, .. },
             ) => {
-                let result = join(rows_1, rows_2, l_on, r_on, join_type, span);
+                let result = join(rows_1, rows_2, l_on, r_on, join_type, span)?;
                 Ok(PipelineData::Value(result, None))
             }
             _ => Err(ShellError::UnsupportedInput(
@@ -112,7 +111,7 @@ impl Command for Join {
             example: "[{a: 1 b: 2}] | join [{a: 1 c: 3}] a",
             result: Some(Value::List {
                 vals: vec![Value::Record {
-                    cols: vec!["a".into(), "b".into(), "c".into()],
+                    cols: Arc::new(vec!["a".into(), "b".into(), "c".into()]),
                     vals: vec![
                         Value::Int {
                             val: 1,
@@ -162,7 +161,7 @@ fn join(
     right_join_key: &str,
     join_type: JoinType,
     span: Span,
-) -> Value {
+) -> Result<Value, ShellError> {
     // Inner / Right Join
     // ------------------
     // Make look-up table from rows on left
@@ -188,8 +187,6 @@ fn join(
     // Perform Left Join procedure
     // Perform Right Join procedure, but excluding rows in Inner Join
 
-    let config = Config::default();
-    let sep = ",";
     let cap = max(left.len(), right.len());
     let shared_join_key = if left_join_key == right_join_key {
         Some(left_join_key)
@@ -205,7 +202,7 @@ fn join(
         JoinType::Left | JoinType::Outer => (
             left,
             left_join_key,
-            lookup_table(right, right_join_key, sep, cap, &config),
+            lookup_table(right, right_join_key, cap)?,
             column_names(right),
             // For Outer we do a Left pass and a Right pass; this is the Left
             // pass.
@@ -214,7 +211,7 @@ fn join(
         JoinType::Inner | JoinType::Right => (
             right,
             right_join_key,
-            lookup_table(left, left_join_key, sep, cap, &config),
+            lookup_table(left, left_join_key, cap)?,
             column_names(left),
             join_type,
         ),
@@ -228,15 +225,13 @@ fn join(
         shared_join_key,
         &join_type,
         IncludeInner::Yes,
-        sep,
-        &config,
         span,
-    );
+    )?;
     if is_outer {
         let (this, this_join_key, other, other_names, join_type) = (
             right,
             right_join_key,
-            lookup_table(left, left_join_key, sep, cap, &config),
+            lookup_table(left, left_join_key, cap)?,
             column_names(left),
             JoinType::Right,
         );
@@ -249,12 +244,10 @@ fn join(
             shared_join_key,
             &join_type,
             IncludeInner::No,
-            sep,
-            &config,
             span,
-        );
+        )?;
     }
-    Value::List { vals: result, span }
+    Ok(Value::List { vals: result, span })
 }
 
 // Join rows of `this` (a nushell table) to rows of `other` (a lookup-table
@@ -264,15 +257,13 @@ fn join_rows(
     result: &mut Vec<Value>,
     this: &Vec<Value>,
     this_join_key: &str,
-    other: HashMap<String, RowEntries>,
+    other: HashMap<u64, RowEntries>,
     other_keys: &Vec<String>,
     shared_join_key: Option<&str>,
     join_type: &JoinType,
     include_inner: IncludeInner,
-    sep: &str,
-    config: &Config,
     span: Span,
-) {
+) -> Result<(), ShellError> {
     for this_row in this {
         if let Value::Record {
             cols: this_cols,
@@ -281,7 +272,7 @@ fn join_rows(
         } = this_row
         {
             if let Some(this_valkey) = this_row.get_data_by_key(this_join_key) {
-                if let Some(other_rows) = other.get(&this_valkey.into_string(sep, config)) {
+                if let Some(other_rows) = other.get(&this_valkey.canonical_hash(false)?) {
                     if matches!(include_inner, IncludeInner::Yes) {
                         for (other_cols, other_vals) in other_rows {
                             // `other` table contains rows matching `this` row on the join column
@@ -299,7 +290,7 @@ fn join_rows(
                                 _ => panic!("not implemented"),
                             };
                             result.push(Value::Record {
-                                cols: res_cols,
+                                cols: Arc::new(res_cols),
                                 vals: res_vals,
                                 span,
                             })
@@ -336,7 +327,7 @@ fn join_rows(
                     };
 
                     result.push(Value::Record {
-                        cols: res_cols,
+                        cols: Arc::new(res_cols),
                         vals: res_vals,
                         span,
                     })
@@ -344,6 +335,7 @@ fn join_rows(
             } // else { a row is missing a value for the join column }
         };
     }
+    Ok(())
 }
 
 // Return column names (i.e. ordered keys from the first row; we assume that
@@ -352,7 +344,7 @@ fn column_names(table: &[Value]) -> &Vec<String> {
     table
         .iter()
         .find_map(|val| match val {
-            Value::Record { cols, .. } => Some(cols),
+            Value::Record { cols, .. } => Some(cols.as_ref()),
             _ => None,
         })
         .unwrap_or(EMPTY_COL_NAMES)
@@ -363,20 +355,21 @@ fn column_names(table: &[Value]) -> &Vec<String> {
 fn lookup_table<'a>(
     rows: &'a Vec<Value>,
     on: &str,
-    sep: &str,
     cap: usize,
-    config: &Config,
-) -> HashMap<String, RowEntries<'a>> {
-    let mut map = HashMap::<String, RowEntries>::with_capacity(cap);
+) -> Result<HashMap<u64, RowEntries<'a>>, ShellError> {
+    let mut map = HashMap::<u64, RowEntries>::with_capacity(cap);
     for row in rows {
         if let Value::Record { cols, vals, .. } = row {
             if let Some(val) = &row.get_data_by_key(on) {
-                let valkey = val.into_string(sep, config);
-                map.entry(valkey).or_default().push((cols, vals));
+                // Keying on the canonical hash keeps values of different types
+                // distinct, so e.g. 1 no longer matches "1"
+                map.entry(val.canonical_hash(false)?)
+                    .or_default()
+                    .push((cols, vals));
             }
         };
     }
-    map
+    Ok(map)
 }
 
 // Merge `left` and `right` records, renaming keys in `right` where they clash
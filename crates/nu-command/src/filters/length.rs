@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
     Signature, Span, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Length;
@@ -127,7 +128,12 @@ fn getcol(
         PipelineData::Value(..) | PipelineData::ExternalStream { .. } => {
             let cols = vec![];
             let vals = vec![];
-            Ok(Value::Record { cols, vals, span }.into_pipeline_data())
+            Ok(Value::Record {
+                cols: Arc::new(cols),
+                vals,
+                span,
+            }
+            .into_pipeline_data())
         }
     }
 }
@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
     Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Merge;
@@ -68,7 +69,7 @@ repeating this process with row 1, and so on."#
                 example: "{a: 1, b: 2} | merge {c: 3}",
                 description: "Merge two records",
                 result: Some(Value::Record {
-                    cols: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                    cols: Arc::new(vec!["a".to_string(), "b".to_string(), "c".to_string()]),
                     vals: vec![Value::test_int(1), Value::test_int(2), Value::test_int(3)],
                     span: Span::test_data(),
                 }),
@@ -120,7 +121,7 @@ repeating this process with row 1, and so on."#
                                             (to_merge_cols.to_vec(), to_merge_vals.to_vec()),
                                         );
                                         Value::Record {
-                                            cols,
+                                            cols: Arc::new(cols),
                                             vals,
                                             span: call.head,
                                         }
@@ -163,7 +164,7 @@ repeating this process with row 1, and so on."#
                     (to_merge_cols.to_vec(), to_merge_vals.to_vec()),
                 );
                 Ok(Value::Record {
-                    cols,
+                    cols: Arc::new(cols),
                     vals,
                     span: call.head,
                 }
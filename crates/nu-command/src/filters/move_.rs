@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
     Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone, Debug)]
 enum BeforeOrAfter {
@@ -301,7 +302,7 @@ fn move_record_columns(
     }
 
     Ok(Value::Record {
-        cols: out_cols,
+        cols: Arc::new(out_cols),
         vals: out_vals,
         span,
     })
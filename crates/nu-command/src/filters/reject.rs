@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
     Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Reject;
@@ -60,7 +61,7 @@ impl Command for Reject {
                 example: "[[a, b]; [1, 2]] | reject a",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["b".to_string()],
+                        cols: Arc::new(vec!["b".to_string()]),
                         vals: vec![Value::test_int(2)],
                         span: Span::test_data(),
                     }],
@@ -71,7 +72,7 @@ impl Command for Reject {
                 description: "Reject the specified field in a record",
                 example: "{a: 1, b: 2} | reject a",
                 result: Some(Value::Record {
-                    cols: vec!["b".into()],
+                    cols: Arc::new(vec!["b".into()]),
                     vals: vec![Value::test_int(2)],
                     span: Span::test_data(),
                 }),
@@ -80,9 +81,9 @@ impl Command for Reject {
                 description: "Reject a nested field in a record",
                 example: "{a: {b: 3, c: 5}} | reject a.b",
                 result: Some(Value::Record {
-                    cols: vec!["a".into()],
+                    cols: Arc::new(vec!["a".into()]),
                     vals: vec![Value::Record {
-                        cols: vec!["c".into()],
+                        cols: Arc::new(vec!["c".into()]),
                         vals: vec![Value::test_int(5)],
                         span: Span::test_data(),
                     }],
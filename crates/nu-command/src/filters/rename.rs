@@ -4,6 +4,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Rename;
@@ -50,7 +51,7 @@ impl Command for Rename {
                 example: "[[a, b]; [1, 2]] | rename my_column",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["my_column".to_string(), "b".to_string()],
+                        cols: Arc::new(vec!["my_column".to_string(), "b".to_string()]),
                         vals: vec![Value::test_int(1), Value::test_int(2)],
                         span: Span::test_data(),
                     }],
@@ -62,7 +63,11 @@ impl Command for Rename {
                 example: "[[a, b, c]; [1, 2, 3]] | rename eggs ham bacon",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["eggs".to_string(), "ham".to_string(), "bacon".to_string()],
+                        cols: Arc::new(vec![
+                            "eggs".to_string(),
+                            "ham".to_string(),
+                            "bacon".to_string(),
+                        ]),
                         vals: vec![Value::test_int(1), Value::test_int(2), Value::test_int(3)],
                         span: Span::test_data(),
                     }],
@@ -74,7 +79,7 @@ impl Command for Rename {
                 example: "[[a, b, c]; [1, 2, 3]] | rename -c [a ham]",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ham".to_string(), "b".to_string(), "c".to_string()],
+                        cols: Arc::new(vec!["ham".to_string(), "b".to_string(), "c".to_string()]),
                         vals: vec![Value::test_int(1), Value::test_int(2), Value::test_int(3)],
                         span: Span::test_data(),
                     }],
@@ -85,7 +90,7 @@ impl Command for Rename {
                 description: "Rename the fields of a record",
                 example: "{a: 1 b: 2} | rename x y",
                 result: Some(Value::Record {
-                    cols: vec!["x".to_string(), "y".to_string()],
+                    cols: Arc::new(vec!["x".to_string(), "y".to_string()]),
                     vals: vec![Value::test_int(1), Value::test_int(2)],
                     span: Span::test_data(),
                 }),
@@ -154,14 +159,15 @@ fn rename(
                                     )),
                                 };
                             }
-                            for (idx, val) in cols.iter_mut().enumerate() {
+                            for val in Arc::make_mut(&mut cols).iter_mut() {
                                 if *val == c[0] {
-                                    cols[idx] = c[1].to_string();
+                                    *val = c[1].to_string();
                                     break;
                                 }
                             }
                         }
                         None => {
+                            let cols = Arc::make_mut(&mut cols);
                             for (idx, val) in columns.iter().enumerate() {
                                 if idx >= cols.len() {
                                     // skip extra new columns names if we already reached the final column
@@ -10,6 +10,7 @@ pub use roll_down::RollDown;
 pub use roll_left::RollLeft;
 pub use roll_right::RollRight;
 pub use roll_up::RollUp;
+use std::sync::Arc;
 
 enum VerticalDirection {
     Up,
@@ -65,14 +66,14 @@ fn horizontal_rotate_value(
             let columns = if cells_only {
                 cols
             } else {
-                let columns = cols.as_mut_slice();
+                let columns = Arc::make_mut(&mut cols);
 
                 match direction {
                     HorizontalDirection::Right => columns.rotate_right(rotations),
                     HorizontalDirection::Left => columns.rotate_left(rotations),
                 }
 
-                columns.to_owned()
+                cols
             };
 
             let values = vals.as_mut_slice();
@@ -7,6 +7,7 @@ use nu_protocol::{
 };
 
 use super::{vertical_rotate_value, VerticalDirection};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct RollDown;
@@ -40,17 +41,17 @@ impl Command for RollDown {
             result: Some(Value::List {
                 vals: vec![
                     Value::Record {
-                        cols: columns.clone(),
+                        cols: Arc::new(columns.clone()),
                         vals: vec![Value::test_int(5), Value::test_int(6)],
                         span: Span::test_data(),
                     },
                     Value::Record {
-                        cols: columns.clone(),
+                        cols: Arc::new(columns.clone()),
                         vals: vec![Value::test_int(1), Value::test_int(2)],
                         span: Span::test_data(),
                     },
                     Value::Record {
-                        cols: columns,
+                        cols: Arc::new(columns),
                         vals: vec![Value::test_int(3), Value::test_int(4)],
                         span: Span::test_data(),
                     },
@@ -7,6 +7,7 @@ use nu_protocol::{
 };
 
 use super::{horizontal_rotate_value, HorizontalDirection};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct RollRight;
@@ -52,7 +53,7 @@ impl Command for RollRight {
                 description: "Rolls columns of a record to the right",
                 example: "{a:1 b:2 c:3} | roll right",
                 result: Some(Value::Record {
-                    cols: rotated_columns.clone(),
+                    cols: Arc::new(rotated_columns.clone()),
                     vals: vec![Value::test_int(3), Value::test_int(1), Value::test_int(2)],
                     span: Span::test_data(),
                 }),
@@ -63,12 +64,12 @@ impl Command for RollRight {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: rotated_columns.clone(),
+                            cols: Arc::new(rotated_columns.clone()),
                             vals: vec![Value::test_int(3), Value::test_int(1), Value::test_int(2)],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: rotated_columns,
+                            cols: Arc::new(rotated_columns),
                             vals: vec![Value::test_int(6), Value::test_int(4), Value::test_int(5)],
                             span: Span::test_data(),
                         },
@@ -82,12 +83,12 @@ impl Command for RollRight {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: columns.clone(),
+                            cols: Arc::new(columns.clone()),
                             vals: vec![Value::test_int(3), Value::test_int(1), Value::test_int(2)],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: columns,
+                            cols: Arc::new(columns),
                             vals: vec![Value::test_int(6), Value::test_int(4), Value::test_int(5)],
                             span: Span::test_data(),
                         },
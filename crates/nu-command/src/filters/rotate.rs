@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
     Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Rotate;
@@ -41,12 +42,12 @@ impl Command for Rotate {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["column0".to_string(), "column1".to_string()],
+                            cols: Arc::new(vec!["column0".to_string(), "column1".to_string()]),
                             vals: vec![Value::test_int(1), Value::test_string("a")],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["column0".to_string(), "column1".to_string()],
+                            cols: Arc::new(vec!["column0".to_string(), "column1".to_string()]),
                             vals: vec![Value::test_int(2), Value::test_string("b")],
                             span: Span::test_data(),
                         },
@@ -60,12 +61,12 @@ impl Command for Rotate {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec![
+                            cols: Arc::new(vec![
                                 "column0".to_string(),
                                 "column1".to_string(),
                                 "column2".to_string(),
                                 "column3".to_string(),
-                            ],
+                            ]),
                             vals: vec![
                                 Value::test_int(5),
                                 Value::test_int(3),
@@ -75,12 +76,12 @@ impl Command for Rotate {
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec![
+                            cols: Arc::new(vec![
                                 "column0".to_string(),
                                 "column1".to_string(),
                                 "column2".to_string(),
                                 "column3".to_string(),
-                            ],
+                            ]),
                             vals: vec![
                                 Value::test_int(6),
                                 Value::test_int(4),
@@ -99,12 +100,12 @@ impl Command for Rotate {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["col_a".to_string(), "col_b".to_string()],
+                            cols: Arc::new(vec!["col_a".to_string(), "col_b".to_string()]),
                             vals: vec![Value::test_int(1), Value::test_string("a")],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["col_a".to_string(), "col_b".to_string()],
+                            cols: Arc::new(vec!["col_a".to_string(), "col_b".to_string()]),
                             vals: vec![Value::test_int(2), Value::test_string("b")],
                             span: Span::test_data(),
                         },
@@ -118,12 +119,12 @@ impl Command for Rotate {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["column0".to_string(), "column1".to_string()],
+                            cols: Arc::new(vec!["column0".to_string(), "column1".to_string()]),
                             vals: vec![Value::test_string("b"), Value::test_int(2)],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["column0".to_string(), "column1".to_string()],
+                            cols: Arc::new(vec!["column0".to_string(), "column1".to_string()]),
                             vals: vec![Value::test_string("a"), Value::test_int(1)],
                             span: Span::test_data(),
                         },
@@ -137,12 +138,12 @@ impl Command for Rotate {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec![
+                            cols: Arc::new(vec![
                                 "column0".to_string(),
                                 "column1".to_string(),
                                 "column2".to_string(),
                                 "column3".to_string(),
-                            ],
+                            ]),
                             vals: vec![
                                 Value::test_string("b"),
                                 Value::test_int(2),
@@ -152,12 +153,12 @@ impl Command for Rotate {
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec![
+                            cols: Arc::new(vec![
                                 "column0".to_string(),
                                 "column1".to_string(),
                                 "column2".to_string(),
                                 "column3".to_string(),
-                            ],
+                            ]),
                             vals: vec![
                                 Value::test_string("a"),
                                 Value::test_int(1),
@@ -176,12 +177,12 @@ impl Command for Rotate {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["col_a".to_string(), "col_b".to_string()],
+                            cols: Arc::new(vec!["col_a".to_string(), "col_b".to_string()]),
                             vals: vec![Value::test_string("b"), Value::test_int(2)],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["col_a".to_string(), "col_b".to_string()],
+                            cols: Arc::new(vec!["col_a".to_string(), "col_b".to_string()]),
                             vals: vec![Value::test_string("a"), Value::test_int(1)],
                             span: Span::test_data(),
                         },
@@ -227,7 +228,7 @@ pub fn rotate(
         for val in values.into_iter() {
             match val {
                 Value::Record { cols, vals, .. } => {
-                    old_column_names = cols;
+                    old_column_names = cols.to_vec();
                     for v in vals {
                         new_values.push(v)
                     }
@@ -287,7 +288,7 @@ pub fn rotate(
     if not_a_record {
         return Ok(Value::List {
             vals: vec![Value::Record {
-                cols: new_column_names,
+                cols: Arc::new(new_column_names),
                 vals: new_values,
                 span: call.head,
             }],
@@ -334,7 +335,7 @@ pub fn rotate(
             res.to_vec()
         };
         final_values.push(Value::Record {
-            cols: new_column_names.clone(),
+            cols: Arc::new(new_column_names.clone()),
             vals: new_vals,
             span: call.head,
         })
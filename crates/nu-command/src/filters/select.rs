@@ -6,6 +6,7 @@ use nu_protocol::{
     PipelineIterator, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 use std::collections::HashSet;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Select;
@@ -165,6 +166,22 @@ fn select(
             metadata,
             ..,
         ) => {
+            // A homogeneous table with plain column selections can be
+            // transposed and have whole columns picked out, instead of doing
+            // a per-row lookup for every selected column
+            let input_vals = if columns.is_empty() {
+                input_vals
+            } else {
+                match crate::columnar_select(input_vals, &columns, span) {
+                    Ok(rows) => {
+                        return Ok(rows
+                            .into_pipeline_data(engine_state.ctrlc.clone())
+                            .set_metadata(metadata))
+                    }
+                    Err(input_vals) => input_vals,
+                }
+            };
+
             let mut output = vec![];
             let mut columns_with_value = Vec::new();
             for input_val in input_vals {
@@ -173,7 +190,7 @@ fn select(
                     let mut vals = vec![];
                     for path in &columns {
                         //FIXME: improve implementation to not clone
-                        match input_val.clone().follow_cell_path(&path.members, false) {
+                        match input_val.follow_cell_path(&path.members, false) {
                             Ok(fetcher) => {
                                 cols.push(path.into_string().replace('.', "_"));
                                 vals.push(fetcher);
@@ -187,7 +204,11 @@ fn select(
                         }
                     }
 
-                    output.push(Value::Record { cols, vals, span })
+                    output.push(Value::Record {
+                        cols: Arc::new(cols),
+                        vals,
+                        span,
+                    })
                 } else {
                     output.push(input_val)
                 }
@@ -199,15 +220,29 @@ fn select(
                 .set_metadata(metadata))
         }
         PipelineData::ListStream(stream, metadata, ..) => {
+            let collected: Vec<Value> = stream.collect();
+            let collected = if columns.is_empty() {
+                collected
+            } else {
+                match crate::columnar_select(collected, &columns, call_span) {
+                    Ok(rows) => {
+                        return Ok(rows
+                            .into_pipeline_data(engine_state.ctrlc.clone())
+                            .set_metadata(metadata))
+                    }
+                    Err(collected) => collected,
+                }
+            };
+
             let mut values = vec![];
 
-            for x in stream {
+            for x in collected {
                 if !columns.is_empty() {
                     let mut cols = vec![];
                     let mut vals = vec![];
                     for path in &columns {
                         //FIXME: improve implementation to not clone
-                        match x.clone().follow_cell_path(&path.members, false) {
+                        match x.follow_cell_path(&path.members, false) {
                             Ok(value) => {
                                 cols.push(path.into_string().replace('.', "_"));
                                 vals.push(value);
@@ -216,7 +251,7 @@ fn select(
                         }
                     }
                     values.push(Value::Record {
-                        cols,
+                        cols: Arc::new(cols),
                         vals,
                         span: call_span,
                     });
@@ -236,7 +271,7 @@ fn select(
 
                 for cell_path in columns {
                     // FIXME: remove clone
-                    match v.clone().follow_cell_path(&cell_path.members, false) {
+                    match v.follow_cell_path(&cell_path.members, false) {
                         Ok(result) => {
                             cols.push(cell_path.into_string().replace('.', "_"));
                             vals.push(result);
@@ -246,7 +281,7 @@ fn select(
                 }
 
                 Ok(Value::Record {
-                    cols,
+                    cols: Arc::new(cols),
                     vals,
                     span: call_span,
                 }
@@ -7,6 +7,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
     Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Skip;
@@ -56,7 +57,7 @@ impl Command for Skip {
                 example: "[[editions]; [2015] [2018] [2021]] | skip 2",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["editions".to_owned()],
+                        cols: Arc::new(vec!["editions".to_owned()]),
                         vals: vec![Value::test_int(2021)],
                         span: Span::test_data(),
                     }],
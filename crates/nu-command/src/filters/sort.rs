@@ -6,6 +6,7 @@ use nu_protocol::{
     Signature, Span, Type, Value,
 };
 use std::cmp::Ordering;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Sort;
@@ -114,7 +115,7 @@ impl Command for Sort {
                 description: "Sort record by key (case-insensitive)",
                 example: "{b: 3, a: 4} | sort",
                 result: Some(Value::Record {
-                    cols: vec!["a".to_string(), "b".to_string()],
+                    cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                     vals: vec![Value::test_int(4), Value::test_int(3)],
                     span: Span::test_data(),
                 }),
@@ -123,7 +124,7 @@ impl Command for Sort {
                 description: "Sort record by value",
                 example: "{b: 4, a: 3, c:1} | sort -v",
                 result: Some(Value::Record {
-                    cols: vec!["c".to_string(), "a".to_string(), "b".to_string()],
+                    cols: Arc::new(vec!["c".to_string(), "a".to_string(), "b".to_string()]),
                     vals: vec![Value::test_int(1), Value::test_int(3), Value::test_int(4)],
                     span: Span::test_data(),
                 }),
@@ -148,7 +149,7 @@ impl Command for Sort {
             PipelineData::Value(Value::Record { cols, vals, span }, ..) => {
                 let sort_by_value = call.has_flag("values");
                 let record = sort_record(
-                    cols,
+                    cols.to_vec(),
                     vals,
                     span,
                     sort_by_value,
@@ -259,7 +260,7 @@ fn sort_record(
         new_vals.reverse();
     }
     Value::Record {
-        cols: new_cols,
+        cols: Arc::new(new_cols),
         vals: new_vals,
         span: rec_span,
     }
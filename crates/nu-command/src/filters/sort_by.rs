@@ -1,3 +1,4 @@
+use crate::{Comparator, SortColumn};
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
@@ -17,7 +18,11 @@ impl Command for SortBy {
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("sort-by")
             .input_output_types(vec![(Type::Table(vec![]), Type::Table(vec![]))])
-            .rest("columns", SyntaxShape::Any, "the column(s) to sort by")
+            .rest(
+                "columns",
+                SyntaxShape::Any,
+                "the column(s) to sort by; a record like {column: name, comparator: version} picks a comparator for that column",
+            )
             .switch("reverse", "Sort in reverse order", Some('r'))
             .switch(
                 "ignore-case",
@@ -29,6 +34,16 @@ impl Command for SortBy {
                 "Sort alphanumeric string-based columns naturally (1, 9, 10, 99, 100, ...)",
                 Some('n'),
             )
+            .switch(
+                "locale",
+                "Sort string-based columns using locale-style collation (case-insensitive, accented letters next to their base letter)",
+                Some('l'),
+            )
+            .switch(
+                "version",
+                "Sort string-based columns as semantic versions (1.2.0 before 1.10.0)",
+                Some('v'),
+            )
             .category(Category::Filters)
     }
 
@@ -69,6 +84,35 @@ impl Command for SortBy {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Sort file names naturally, so file10 comes after file2",
+                example: "[[name]; [file10] [file2]] | sort-by name --natural",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_record(vec!["name"], vec![Value::test_string("file2")]),
+                        Value::test_record(vec!["name"], vec![Value::test_string("file10")]),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Sort one column as versions, picking the comparator per column",
+                example:
+                    "[[pkg ver]; [b 1.10.0] [a 1.2.0]] | sort-by {column: ver, comparator: version}",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_record(
+                            vec!["pkg", "ver"],
+                            vec![Value::test_string("a"), Value::test_string("1.2.0")],
+                        ),
+                        Value::test_record(
+                            vec!["pkg", "ver"],
+                            vec![Value::test_string("b"), Value::test_string("1.10.0")],
+                        ),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 
@@ -79,12 +123,12 @@ impl Command for SortBy {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let columns: Vec<String> = call.rest(engine_state, stack, 0)?;
+        let columns: Vec<Value> = call.rest(engine_state, stack, 0)?;
         let reverse = call.has_flag("reverse");
         let insensitive = call.has_flag("ignore-case");
-        let natural = call.has_flag("natural");
+        let default_comparator = default_comparator(call)?;
         let metadata = &input.metadata();
-        let mut vec: Vec<_> = input.into_iter_strict(call.head)?.collect();
+        let input = input.into_iter_strict(call.head)?;
 
         if columns.is_empty() {
             return Err(ShellError::MissingParameter {
@@ -93,13 +137,29 @@ impl Command for SortBy {
             });
         }
 
-        crate::sort(&mut vec, columns, call.head, insensitive, natural)?;
+        let columns = columns
+            .into_iter()
+            .map(|col| sort_column(col, default_comparator))
+            .collect::<Result<Vec<SortColumn>, ShellError>>()?;
 
-        if reverse {
-            vec.reverse()
+        // The sorter buffers rows in memory up to the configured
+        // sort_spill_threshold, then spills sorted runs to disk and merges
+        // them, so inputs larger than RAM can still be sorted
+        let mut sorter = crate::ExternalSorter::new(
+            columns,
+            call.head,
+            insensitive,
+            reverse,
+            engine_state.get_config(),
+        );
+        for value in input {
+            if nu_utils::ctrl_c::was_pressed(&engine_state.ctrlc) {
+                break;
+            }
+            sorter.push(value)?;
         }
 
-        let iter = vec.into_iter();
+        let iter = sorter.finish()?;
         match metadata {
             Some(m) => {
                 Ok(iter.into_pipeline_data_with_metadata(m.clone(), engine_state.ctrlc.clone()))
@@ -109,6 +169,72 @@ impl Command for SortBy {
     }
 }
 
+// The global comparator flags are mutually exclusive; a per-column record
+// overrides whichever one is chosen.
+fn default_comparator(call: &Call) -> Result<Comparator, ShellError> {
+    let flags = [
+        ("natural", Comparator::Natural),
+        ("locale", Comparator::Locale),
+        ("version", Comparator::Version),
+    ];
+
+    let mut chosen: Option<(&str, Comparator)> = None;
+    for (name, comparator) in flags {
+        if call.has_flag(name) {
+            if let Some((other, _)) = chosen {
+                return Err(ShellError::IncompatibleParametersSingle {
+                    msg: format!("Incompatible flags: --{other} and --{name}"),
+                    span: call.head,
+                });
+            }
+            chosen = Some((name, comparator));
+        }
+    }
+
+    Ok(chosen.map_or(Comparator::Default, |(_, comparator)| comparator))
+}
+
+fn sort_column(col: Value, default: Comparator) -> Result<SortColumn, ShellError> {
+    match col {
+        Value::Record { cols, vals, span } => {
+            let mut name = None;
+            let mut comparator = default;
+            for (col, val) in cols.iter().zip(vals.iter()) {
+                match col.as_str() {
+                    "column" => name = Some(val.as_string()?),
+                    "comparator" => {
+                        comparator =
+                            Comparator::from_name(&val.as_string()?, val.span().unwrap_or(span))?
+                    }
+                    _ => {
+                        return Err(ShellError::TypeMismatch {
+                            err_message: format!(
+                                "unexpected column spec key '{col}' (expected column, comparator)"
+                            ),
+                            span,
+                        })
+                    }
+                }
+            }
+            match name {
+                Some(name) => Ok(SortColumn { name, comparator }),
+                None => Err(ShellError::MissingParameter {
+                    param_name: "column".into(),
+                    span,
+                }),
+            }
+        }
+        Value::CellPath { val, .. } => Ok(SortColumn {
+            name: val.into_string(),
+            comparator: default,
+        }),
+        other => Ok(SortColumn {
+            name: other.as_string()?,
+            comparator: default,
+        }),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
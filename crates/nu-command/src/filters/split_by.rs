@@ -5,6 +5,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SplitBy;
@@ -47,17 +48,17 @@ impl Command for SplitBy {
         ]
     } | split-by lang"#,
             result: Some(Value::Record {
-                cols: vec!["rb".to_string(), "rs".to_string()],
+                cols: Arc::new(vec!["rb".to_string(), "rs".to_string()]),
                 vals: vec![
                     Value::Record {
-                        cols: vec!["2019".to_string()],
+                        cols: Arc::new(vec!["2019".to_string()]),
                         vals: vec![Value::List {
                             vals: vec![Value::Record {
-                                cols: vec![
+                                cols: Arc::new(vec![
                                     "name".to_string(),
                                     "lang".to_string(),
                                     "year".to_string(),
-                                ],
+                                ]),
                                 vals: vec![
                                     Value::test_string("andres"),
                                     Value::test_string("rb"),
@@ -70,15 +71,15 @@ impl Command for SplitBy {
                         span: Span::test_data(),
                     },
                     Value::Record {
-                        cols: vec!["2019".to_string(), "2021".to_string()],
+                        cols: Arc::new(vec!["2019".to_string(), "2021".to_string()]),
                         vals: vec![
                             Value::List {
                                 vals: vec![Value::Record {
-                                    cols: vec![
+                                    cols: Arc::new(vec![
                                         "name".to_string(),
                                         "lang".to_string(),
                                         "year".to_string(),
-                                    ],
+                                    ]),
                                     vals: vec![
                                         Value::test_string("jt"),
                                         Value::test_string("rs"),
@@ -90,11 +91,11 @@ impl Command for SplitBy {
                             },
                             Value::List {
                                 vals: vec![Value::Record {
-                                    cols: vec![
+                                    cols: Arc::new(vec![
                                         "name".to_string(),
                                         "lang".to_string(),
                                         "year".to_string(),
-                                    ],
+                                    ]),
                                     vals: vec![
                                         Value::test_string("storm"),
                                         Value::test_string("rs"),
@@ -185,7 +186,7 @@ pub fn split(
 #[allow(clippy::type_complexity)]
 pub fn data_split(
     value: PipelineData,
-    splitter: &Option<Box<dyn Fn(usize, &Value) -> Result<String, ShellError> + Send>>,
+    splitter: &Option<Box<dyn Fn(usize, &Value) -> Result<String, ShellError> + Send + Sync>>,
     span: Span,
 ) -> Result<PipelineData, ShellError> {
     let mut splits = indexmap::IndexMap::new();
@@ -246,14 +247,18 @@ pub fn data_split(
         }
 
         vals.push(Value::Record {
-            cols: sub_cols,
+            cols: Arc::new(sub_cols),
             vals: sub_vals,
             span,
         });
     }
 
     Ok(PipelineData::Value(
-        Value::Record { cols, vals, span },
+        Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        },
         None,
     ))
 }
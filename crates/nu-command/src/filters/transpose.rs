@@ -6,6 +6,7 @@ use nu_protocol::{
     Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span, Spanned,
     SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Transpose;
@@ -89,12 +90,12 @@ impl Command for Transpose {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["column0".to_string(), "column1".to_string()],
+                            cols: Arc::new(vec!["column0".to_string(), "column1".to_string()]),
                             vals: vec![Value::test_string("c1"), Value::test_int(1)],
                             span,
                         },
                         Value::Record {
-                            cols: vec!["column0".to_string(), "column1".to_string()],
+                            cols: Arc::new(vec!["column0".to_string(), "column1".to_string()]),
                             vals: vec![Value::test_string("c2"), Value::test_int(2)],
                             span,
                         },
@@ -108,12 +109,12 @@ impl Command for Transpose {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["key".to_string(), "val".to_string()],
+                            cols: Arc::new(vec!["key".to_string(), "val".to_string()]),
                             vals: vec![Value::test_string("c1"), Value::test_int(1)],
                             span,
                         },
                         Value::Record {
-                            cols: vec!["key".to_string(), "val".to_string()],
+                            cols: Arc::new(vec!["key".to_string(), "val".to_string()]),
                             vals: vec![Value::test_string("c2"), Value::test_int(2)],
                             span,
                         },
@@ -128,12 +129,12 @@ impl Command for Transpose {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["val".to_string()],
+                            cols: Arc::new(vec!["val".to_string()]),
                             vals: vec![Value::test_int(1)],
                             span,
                         },
                         Value::Record {
-                            cols: vec!["val".to_string()],
+                            cols: Arc::new(vec!["val".to_string()]),
                             vals: vec![Value::test_int(2)],
                             span,
                         },
@@ -145,7 +146,7 @@ impl Command for Transpose {
                 description: "Transfer back to record with -d flag",
                 example: "{c1: 1, c2: 2} | transpose | transpose -i -r -d",
                 result: Some(Value::Record {
-                    cols: vec!["c1".to_string(), "c2".to_string()],
+                    cols: Arc::new(vec!["c1".to_string(), "c2".to_string()]),
                     vals: vec![Value::test_int(1), Value::test_int(2)],
                     span,
                 }),
@@ -340,7 +341,7 @@ pub fn transpose(
             }
 
             Value::Record {
-                cols,
+                cols: Arc::new(cols),
                 vals,
                 span: name,
             }
@@ -1,4 +1,3 @@
-use crate::formats::value_to_string;
 use itertools::Itertools;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
@@ -6,8 +5,8 @@ use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, PipelineMetadata, ShellError, Signature,
     Span, Type, Value,
 };
-use std::collections::hash_map::IntoIter;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Uniq;
@@ -123,12 +122,12 @@ impl Command for Uniq {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["value".to_string(), "count".to_string()],
+                            cols: Arc::new(vec!["value".to_string(), "count".to_string()]),
                             vals: vec![Value::test_int(1), Value::test_int(1)],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["value".to_string(), "count".to_string()],
+                            cols: Arc::new(vec!["value".to_string(), "count".to_string()]),
                             vals: vec![Value::test_int(2), Value::test_int(2)],
                             span: Span::test_data(),
                         },
@@ -213,45 +212,11 @@ fn clone_to_lowercase(value: &Value) -> Value {
     }
 }
 
-fn sort_attributes(val: Value) -> Value {
-    match val {
-        Value::Record { cols, vals, span } => {
-            let sorted = cols
-                .into_iter()
-                .zip(vals)
-                .sorted_by(|a, b| a.0.cmp(&b.0))
-                .collect_vec();
-
-            let sorted_cols = sorted.clone().into_iter().map(|a| a.0).collect_vec();
-            let sorted_vals = sorted
-                .into_iter()
-                .map(|a| sort_attributes(a.1))
-                .collect_vec();
-
-            Value::Record {
-                cols: sorted_cols,
-                vals: sorted_vals,
-                span,
-            }
-        }
-        Value::List { vals, span } => Value::List {
-            vals: vals.into_iter().map(sort_attributes).collect_vec(),
-            span,
-        },
-        other => other,
-    }
-}
-
-fn generate_key(item: &ValueCounter) -> Result<String, ShellError> {
-    let value = sort_attributes(item.val_to_compare.clone()); //otherwise, keys could be different for Records
-    value_to_string(&value, Span::unknown(), 0, &None)
-}
-
 fn generate_results_with_count(head: Span, uniq_values: Vec<ValueCounter>) -> Vec<Value> {
     uniq_values
         .into_iter()
         .map(|item| Value::Record {
-            cols: vec!["value".to_string(), "count".to_string()],
+            cols: Arc::new(vec!["value".to_string(), "count".to_string()]),
             vals: vec![item.val, Value::int(item.count, head)],
             span: head,
         })
@@ -273,7 +238,7 @@ pub fn uniq(
     let flag_ignore_case = call.has_flag("ignore-case");
     let flag_only_uniques = call.has_flag("unique");
 
-    let uniq_values = input
+    let items: Vec<ValueCounter> = input
         .into_iter()
         .enumerate()
         .map_while(|(index, item)| {
@@ -286,37 +251,35 @@ pub fn uniq(
                 index,
             }))
         })
-        .try_fold(
-            HashMap::<String, ValueCounter>::new(),
-            |mut counter, item| {
-                let key = generate_key(&item);
-
-                match key {
-                    Ok(key) => {
-                        match counter.get_mut(&key) {
-                            Some(x) => x.count += 1,
-                            None => {
-                                counter.insert(key, item);
-                            }
-                        };
-                        Ok(counter)
-                    }
-                    Err(err) => Err(err),
-                }
-            },
-        );
-
-    let mut uniq_values: HashMap<String, ValueCounter> = uniq_values?;
+        .collect();
+
+    // canonical_hash is order-insensitive for records, so records that differ
+    // only in column order count as the same value
+    let keys = crate::aggregation_keys(&items, |item: &ValueCounter| {
+        item.val_to_compare.canonical_hash(false)
+    })?;
+
+    let counted = crate::fold_partitioned(
+        keys.into_iter().zip(items).collect(),
+        |counter: &mut HashMap<u64, ValueCounter>, key, item| match counter.get_mut(&key) {
+            Some(x) => x.count += 1,
+            None => {
+                counter.insert(key, item);
+            }
+        },
+    );
+    let mut uniq_values: Vec<ValueCounter> =
+        counted.into_iter().flat_map(HashMap::into_values).collect();
 
     if flag_show_repeated {
-        uniq_values.retain(|_v, value_count_pair| value_count_pair.count > 1);
+        uniq_values.retain(|value_count_pair| value_count_pair.count > 1);
     }
 
     if flag_only_uniques {
-        uniq_values.retain(|_v, value_count_pair| value_count_pair.count == 1);
+        uniq_values.retain(|value_count_pair| value_count_pair.count == 1);
     }
 
-    let uniq_values = sort(uniq_values.into_iter());
+    let uniq_values = sort(uniq_values);
 
     let result = if flag_show_count {
         generate_results_with_count(head, uniq_values)
@@ -332,8 +295,9 @@ pub fn uniq(
     .set_metadata(metadata))
 }
 
-fn sort(iter: IntoIter<String, ValueCounter>) -> Vec<ValueCounter> {
-    iter.map(|item| item.1)
+fn sort(values: Vec<ValueCounter>) -> Vec<ValueCounter> {
+    values
+        .into_iter()
         .sorted_by(|a, b| a.index.cmp(&b.index))
         .collect()
 }
@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, FromValue, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData,
     ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Update;
@@ -53,7 +54,7 @@ impl Command for Update {
                 description: "Update a column value",
                 example: "{'name': 'nu', 'stars': 5} | update name 'Nushell'",
                 result: Some(Value::Record {
-                    cols: vec!["name".into(), "stars".into()],
+                    cols: Arc::new(vec!["name".into(), "stars".into()]),
                     vals: vec![Value::test_string("Nushell"), Value::test_int(5)],
                     span: Span::test_data(),
                 }),
@@ -63,7 +64,7 @@ impl Command for Update {
                 example: "[[count fruit]; [1 'apple']] | enumerate | update item.count {|e| ($e.item.fruit | str length) + $e.index } | get item",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["count".into(), "fruit".into()],
+                        cols: Arc::new(vec!["count".into(), "fruit".into()]),
                         vals: vec![Value::test_int(5), Value::test_string("apple")],
                         span: Span::test_data(),
                     }],
@@ -73,7 +74,7 @@ impl Command for Update {
             Example {
                 description: "Alter each value in the 'authors' column to use a single string instead of a list",
                 example: "[[project, authors]; ['nu', ['Andrés', 'JT', 'Yehuda']]] | update authors {|row| $row.authors | str join ','}",
-                result: Some(Value::List { vals: vec![Value::Record { cols: vec!["project".into(), "authors".into()], vals: vec![Value::test_string("nu"), Value::test_string("Andrés,JT,Yehuda")], span: Span::test_data()}], span: Span::test_data()}),
+                result: Some(Value::List { vals: vec![Value::Record { cols: Arc::new(vec!["project".into(), "authors".into()]), vals: vec![Value::test_string("nu"), Value::test_string("Andrés,JT,Yehuda")], span: Span::test_data()}], span: Span::test_data()}),
             },
         ]
     }
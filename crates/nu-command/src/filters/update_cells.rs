@@ -7,6 +7,7 @@ use nu_protocol::{
 };
 use std::collections::HashSet;
 use std::iter::FromIterator;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct UpdateCells;
@@ -53,7 +54,7 @@ impl Command for UpdateCells {
     }"#,
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec![
+                        cols: Arc::new(vec![
                             "2021-04-16".into(),
                             "2021-06-10".into(),
                             "2021-09-18".into(),
@@ -61,7 +62,7 @@ impl Command for UpdateCells {
                             "2021-11-16".into(),
                             "2021-11-17".into(),
                             "2021-11-18".into(),
-                        ],
+                        ]),
                         vals: vec![
                             Value::test_int(37),
                             Value::test_string(""),
@@ -90,7 +91,7 @@ impl Command for UpdateCells {
     }"#,
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec![
+                        cols: Arc::new(vec![
                             "2021-04-16".into(),
                             "2021-06-10".into(),
                             "2021-09-18".into(),
@@ -98,7 +99,7 @@ impl Command for UpdateCells {
                             "2021-11-16".into(),
                             "2021-11-17".into(),
                             "2021-11-18".into(),
-                        ],
+                        ]),
                         vals: vec![
                             Value::test_int(37),
                             Value::test_int(0),
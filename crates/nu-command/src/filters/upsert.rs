@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, FromValue, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData,
     ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Upsert;
@@ -55,25 +56,25 @@ impl Command for Upsert {
         vec![Example {
             description: "Update a record's value",
             example: "{'name': 'nu', 'stars': 5} | upsert name 'Nushell'",
-            result: Some(Value::Record { cols: vec!["name".into(), "stars".into()], vals: vec![Value::test_string("Nushell"), Value::test_int(5)], span: Span::test_data()}),
+            result: Some(Value::Record { cols: Arc::new(vec!["name".into(), "stars".into()]), vals: vec![Value::test_string("Nushell"), Value::test_int(5)], span: Span::test_data()}),
         },
         Example {
             description: "Update each row of a table",
             example: "[[name lang]; [Nushell ''] [Reedline '']] | upsert lang 'Rust'",
             result: Some(Value::List { vals: vec![
-                Value::Record { cols: vec!["name".into(), "lang".into()], vals: vec![Value::test_string("Nushell"), Value::test_string("Rust")], span: Span::test_data()},
-                Value::Record { cols: vec!["name".into(), "lang".into()], vals: vec![Value::test_string("Reedline"), Value::test_string("Rust")], span: Span::test_data()}
+                Value::Record { cols: Arc::new(vec!["name".into(), "lang".into()]), vals: vec![Value::test_string("Nushell"), Value::test_string("Rust")], span: Span::test_data()},
+                Value::Record { cols: Arc::new(vec!["name".into(), "lang".into()]), vals: vec![Value::test_string("Reedline"), Value::test_string("Rust")], span: Span::test_data()}
                 ], span: Span::test_data()}),
         },
         Example {
             description: "Insert a new entry into a single record",
             example: "{'name': 'nu', 'stars': 5} | upsert language 'Rust'",
-            result: Some(Value::Record { cols: vec!["name".into(), "stars".into(), "language".into()], vals: vec![Value::test_string("nu"), Value::test_int(5), Value::test_string("Rust")], span: Span::test_data()}),
+            result: Some(Value::Record { cols: Arc::new(vec!["name".into(), "stars".into(), "language".into()]), vals: vec![Value::test_string("nu"), Value::test_int(5), Value::test_string("Rust")], span: Span::test_data()}),
         }, Example {
             description: "Use in closure form for more involved updating logic",
             example: "[[count fruit]; [1 'apple']] | enumerate | upsert item.count {|e| ($e.item.fruit | str length) + $e.index } | get item",
             result: Some(Value::List { vals: vec![
-                Value::Record { cols: vec!["count".into(), "fruit".into()], vals: vec![Value::test_int(5), Value::test_string("apple")], span: Span::test_data()}],
+                Value::Record { cols: Arc::new(vec!["count".into(), "fruit".into()]), vals: vec![Value::test_int(5), Value::test_string("apple")], span: Span::test_data()}],
                 span: Span::test_data()}),
         },
         Example {
@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
     Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Where;
@@ -113,7 +114,7 @@ not supported."#
                 example: "[{a: 1} {a: 2}] | where a > 1",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["a".to_string()],
+                        cols: Arc::new(vec!["a".to_string()]),
                         vals: vec![Value::test_int(2)],
                         span: Span::test_data(),
                     }],
@@ -5,6 +5,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
     Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Wrap;
@@ -46,21 +47,21 @@ impl Command for Wrap {
             | PipelineData::ListStream { .. } => Ok(input
                 .into_iter()
                 .map(move |x| Value::Record {
-                    cols: vec![name.clone()],
+                    cols: Arc::new(vec![name.clone()]),
                     vals: vec![x],
                     span,
                 })
                 .into_pipeline_data(engine_state.ctrlc.clone())
                 .set_metadata(metadata)),
             PipelineData::ExternalStream { .. } => Ok(Value::Record {
-                cols: vec![name],
+                cols: Arc::new(vec![name]),
                 vals: vec![input.into_value(call.head)],
                 span,
             }
             .into_pipeline_data()
             .set_metadata(metadata)),
             PipelineData::Value(input, ..) => Ok(Value::Record {
-                cols: vec![name],
+                cols: Arc::new(vec![name]),
                 vals: vec![input],
                 span,
             }
@@ -77,17 +78,17 @@ impl Command for Wrap {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["num".into()],
+                            cols: Arc::new(vec!["num".into()]),
                             vals: vec![Value::test_int(1)],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["num".into()],
+                            cols: Arc::new(vec!["num".into()]),
                             vals: vec![Value::test_int(2)],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["num".into()],
+                            cols: Arc::new(vec!["num".into()]),
                             vals: vec![Value::test_int(3)],
                             span: Span::test_data(),
                         },
@@ -101,17 +102,17 @@ impl Command for Wrap {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["num".into()],
+                            cols: Arc::new(vec!["num".into()]),
                             vals: vec![Value::test_int(1)],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["num".into()],
+                            cols: Arc::new(vec!["num".into()]),
                             vals: vec![Value::test_int(2)],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["num".into()],
+                            cols: Arc::new(vec!["num".into()]),
                             vals: vec![Value::test_int(3)],
                             span: Span::test_data(),
                         },
@@ -0,0 +1,220 @@
+use chrono::DateTime;
+use indexmap::map::IndexMap;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct FromClf;
+
+impl Command for FromClf {
+    fn name(&self) -> &str {
+        "from clf"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from clf")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as the common or combined log format of Apache and NGINX and create table."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["log", "apache", "nginx", "access"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_clf(input, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: r#"'127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif HTTP/1.0" 200 2326' | from clf"#,
+            description: "Parse a common log format line",
+            result: Some(Value::List {
+                vals: vec![Value::test_record(
+                    vec![
+                        "host",
+                        "ident",
+                        "authuser",
+                        "timestamp",
+                        "request",
+                        "status",
+                        "bytes",
+                        "referer",
+                        "user_agent",
+                    ],
+                    vec![
+                        Value::test_string("127.0.0.1"),
+                        Value::test_nothing(),
+                        Value::test_string("frank"),
+                        Value::Date {
+                            val: DateTime::parse_from_str(
+                                "10/Oct/2000:13:55:36 -0700",
+                                "%d/%b/%Y:%H:%M:%S %z",
+                            )
+                            .expect("valid clf timestamp"),
+                            span: Span::test_data(),
+                        },
+                        Value::test_string("GET /apache_pb.gif HTTP/1.0"),
+                        Value::test_int(200),
+                        Value::test_int(2326),
+                        Value::test_nothing(),
+                        Value::test_nothing(),
+                    ],
+                )],
+                span: Span::test_data(),
+            }),
+        }]
+    }
+}
+
+fn from_clf(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let (input_string, span, metadata) = input.collect_string_strict(head)?;
+
+    let mut entries = vec![];
+    for line in input_string.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        entries.push(parse_entry(line, head, span)?);
+    }
+
+    Ok(Value::List {
+        vals: entries,
+        span: head,
+    }
+    .into_pipeline_data_with_metadata(metadata))
+}
+
+fn parse_entry(line: &str, head: Span, span: Span) -> Result<Value, ShellError> {
+    let parse_error = |reason: &str| {
+        ShellError::UnsupportedInput(
+            format!("input cannot be parsed as a common log format entry ({reason}: {line:?})"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    };
+
+    let fields = tokenize(line).ok_or_else(|| parse_error("unbalanced quotes or brackets"))?;
+    if fields.len() < 7 {
+        return Err(parse_error("too few fields"));
+    }
+
+    // '-' marks a field the server had no value for
+    let dashable = |field: &str| {
+        if field == "-" {
+            Value::nothing(head)
+        } else {
+            Value::string(field, head)
+        }
+    };
+    let timestamp = Value::Date {
+        val: DateTime::parse_from_str(&fields[3], "%d/%b/%Y:%H:%M:%S %z")
+            .map_err(|_| parse_error("invalid timestamp"))?,
+        span: head,
+    };
+    let status = Value::Int {
+        val: fields[5]
+            .parse()
+            .map_err(|_| parse_error("invalid status code"))?,
+        span: head,
+    };
+    let bytes = match fields[6].as_str() {
+        "-" => Value::nothing(head),
+        bytes => Value::Int {
+            val: bytes
+                .parse()
+                .map_err(|_| parse_error("invalid byte count"))?,
+            span: head,
+        },
+    };
+
+    let mut record = IndexMap::new();
+    record.insert("host".to_string(), dashable(&fields[0]));
+    record.insert("ident".to_string(), dashable(&fields[1]));
+    record.insert("authuser".to_string(), dashable(&fields[2]));
+    record.insert("timestamp".to_string(), timestamp);
+    record.insert("request".to_string(), dashable(&fields[4]));
+    record.insert("status".to_string(), status);
+    record.insert("bytes".to_string(), bytes);
+    record.insert(
+        "referer".to_string(),
+        fields
+            .get(7)
+            .map(|f| dashable(f))
+            .unwrap_or_else(|| Value::nothing(head)),
+    );
+    record.insert(
+        "user_agent".to_string(),
+        fields
+            .get(8)
+            .map(|f| dashable(f))
+            .unwrap_or_else(|| Value::nothing(head)),
+    );
+    Ok(Value::from(Spanned {
+        item: record,
+        span: head,
+    }))
+}
+
+// Splits on spaces, keeping '[...]' and '"..."' groups (with backslash escapes) together
+fn tokenize(line: &str) -> Option<Vec<String>> {
+    let mut fields = vec![];
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let mut field = String::new();
+        match c {
+            ' ' => continue,
+            '[' => loop {
+                match chars.next()? {
+                    ']' => break,
+                    c => field.push(c),
+                }
+            },
+            '"' => loop {
+                match chars.next()? {
+                    '\\' => field.push(chars.next()?),
+                    '"' => break,
+                    c => field.push(c),
+                }
+            },
+            c => {
+                field.push(c);
+                while let Some(c) = chars.next_if(|c| *c != ' ') {
+                    field.push(c);
+                }
+            }
+        }
+        fields.push(field);
+    }
+    Some(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromClf {})
+    }
+}
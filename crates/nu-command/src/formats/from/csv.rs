@@ -1,4 +1,6 @@
-use super::delimited::{from_delimited_data, trim_from_str, DelimitedReaderConfig};
+use super::delimited::{
+    from_delimited_data, infer_limit_from_call, trim_from_str, DelimitedReaderConfig,
+};
 
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
@@ -6,6 +8,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct FromCsv;
@@ -53,6 +56,18 @@ impl Command for FromCsv {
                 None,
             )
             .switch("no-infer", "no field type inferencing", None)
+            .named(
+                "infer",
+                SyntaxShape::Int,
+                "infer column types from the first NUM rows and coerce the remaining rows to match",
+                None,
+            )
+            .named(
+                "types",
+                SyntaxShape::Record,
+                "a record of column name to type (int, float, bool or string), overriding inference for those columns",
+                None,
+            )
             .named(
                 "trim",
                 SyntaxShape::String,
@@ -83,7 +98,7 @@ impl Command for FromCsv {
                 example: "\"ColA,ColB\n1,2\" | from csv",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ColA".to_string(), "ColB".to_string()],
+                        cols: Arc::new(vec!["ColA".to_string(), "ColB".to_string()]),
                         vals: vec![
                             Value::test_int(1),
                             Value::test_int(2),
@@ -108,6 +123,16 @@ impl Command for FromCsv {
                 example: "open data.txt | from csv --comment '#'",
                 result: None,
             },
+            Example {
+                description: "Convert comma-separated data to a table, inferring column types from the first 1000 rows",
+                example: "open data.txt | from csv --infer 1000",
+                result: None,
+            },
+            Example {
+                description: "Convert comma-separated data to a table, forcing the 'id' column to be parsed as a string",
+                example: "open data.txt | from csv --types {id: string}",
+                result: None,
+            },
             Example {
                 description: "Convert comma-separated data to a table, dropping all possible whitespaces around header names and field values",
                 example: "open data.txt | from csv --trim all",
@@ -154,6 +179,8 @@ fn from_csv(
         .map(|v: Value| v.as_char())
         .transpose()?;
     let no_infer = call.has_flag("no-infer");
+    let infer_limit = infer_limit_from_call(engine_state, stack, call)?;
+    let column_types: Option<Value> = call.get_flag(engine_state, stack, "types")?;
     let noheaders = call.has_flag("noheaders");
     let flexible = call.has_flag("flexible");
     let trim = trim_from_str(call.get_flag(engine_state, stack, "trim")?)?;
@@ -166,6 +193,8 @@ fn from_csv(
         noheaders,
         flexible,
         no_infer,
+        infer_limit,
+        column_types,
         trim,
     };
 
@@ -1,5 +1,162 @@
 use csv::{ReaderBuilder, Trim};
-use nu_protocol::{IntoPipelineData, PipelineData, ShellError, Span, Value};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{IntoPipelineData, PipelineData, ShellError, Span, Spanned, Value};
+use std::sync::Arc;
+
+// Reads the `--infer` row limit shared by `from csv` and `from tsv`.
+pub(super) fn infer_limit_from_call(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<Option<usize>, ShellError> {
+    match call.get_flag::<Spanned<i64>>(engine_state, stack, "infer")? {
+        Some(Spanned { item, span }) => {
+            if item < 0 {
+                Err(ShellError::NeedsPositiveValue(span))
+            } else {
+                Ok(Some(item as usize))
+            }
+        }
+        None => Ok(None),
+    }
+}
+
+// The type a whole column is coerced to, either requested via `--types` or
+// decided by scanning the first `--infer` rows.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ColumnType {
+    Int,
+    Float,
+    Bool,
+    String,
+}
+
+impl ColumnType {
+    fn name(&self) -> &'static str {
+        match self {
+            ColumnType::Int => "int",
+            ColumnType::Float => "float",
+            ColumnType::Bool => "bool",
+            ColumnType::String => "string",
+        }
+    }
+}
+
+fn column_type_from_str(name: &str, span: Span) -> Result<ColumnType, ShellError> {
+    match name {
+        "int" => Ok(ColumnType::Int),
+        "float" => Ok(ColumnType::Float),
+        "bool" => Ok(ColumnType::Bool),
+        "string" => Ok(ColumnType::String),
+        _ => Err(ShellError::TypeMismatch {
+            err_message: format!(
+                "the only possible column types are 'int', 'float', 'bool' and 'string', got '{name}'"
+            ),
+            span,
+        }),
+    }
+}
+
+// Coerce one cell to a decided column type. Empty cells become null rather
+// than failing, since sparse columns are common in real-world files.
+fn coerce_cell(
+    value: &str,
+    column_type: ColumnType,
+    row: usize,
+    column: &str,
+    span: Span,
+) -> Result<Value, ShellError> {
+    if value.is_empty() && column_type != ColumnType::String {
+        return Ok(Value::Nothing { span });
+    }
+
+    let cant_convert = || ShellError::CantConvert {
+        to_type: column_type.name().to_string(),
+        from_type: "string".to_string(),
+        span,
+        help: Some(format!(
+            "invalid value '{value}' at row {row}, column '{column}'"
+        )),
+    };
+
+    match column_type {
+        ColumnType::Int => value
+            .parse::<i64>()
+            .map(|val| Value::Int { val, span })
+            .map_err(|_| cant_convert()),
+        ColumnType::Float => value
+            .parse::<f64>()
+            .map(|val| Value::Float { val, span })
+            .map_err(|_| cant_convert()),
+        ColumnType::Bool => match value {
+            "true" => Ok(Value::Bool { val: true, span }),
+            "false" => Ok(Value::Bool { val: false, span }),
+            _ => Err(cant_convert()),
+        },
+        ColumnType::String => Ok(Value::String {
+            val: value.into(),
+            span,
+        }),
+    }
+}
+
+// Turn the `--types` record into per-column overrides, erroring on column
+// names that don't exist so typos aren't silently ignored.
+fn column_type_overrides(
+    types: &Value,
+    headers: &[String],
+) -> Result<Vec<Option<ColumnType>>, ShellError> {
+    let mut overrides = vec![None; headers.len()];
+    let (cols, vals) = match types {
+        Value::Record { cols, vals, .. } => (cols, vals),
+        other => {
+            return Err(ShellError::TypeMismatch {
+                err_message: "--types expects a record of column name to type".into(),
+                span: other.span()?,
+            })
+        }
+    };
+
+    for (col, val) in cols.iter().zip(vals.iter()) {
+        let type_name = val.as_string()?;
+        let column_type = column_type_from_str(&type_name, val.span()?)?;
+        match headers.iter().position(|h| h == col) {
+            Some(index) => overrides[index] = Some(column_type),
+            None => {
+                return Err(ShellError::TypeMismatch {
+                    err_message: format!("unknown column '{col}' in --types"),
+                    span: val.span()?,
+                })
+            }
+        }
+    }
+
+    Ok(overrides)
+}
+
+// Pick the narrowest type that fits every sampled cell of one column: int if
+// everything parses as an integer, float if everything is numeric, otherwise
+// string. Empty cells don't widen the type.
+fn infer_column_type(records: &[csv::StringRecord], index: usize, limit: usize) -> ColumnType {
+    let mut column_type = ColumnType::Int;
+    for record in records.iter().take(limit) {
+        let value = match record.get(index) {
+            Some(value) if !value.is_empty() => value,
+            _ => continue,
+        };
+        if value.parse::<i64>().is_ok() {
+            continue;
+        }
+        if value.parse::<f64>().is_ok() {
+            column_type = ColumnType::Float;
+            continue;
+        }
+        return ColumnType::String;
+    }
+    column_type
+}
 
 fn from_delimited_string_to_value(
     DelimitedReaderConfig {
@@ -10,11 +167,18 @@ fn from_delimited_string_to_value(
         noheaders,
         flexible,
         no_infer,
+        infer_limit,
+        column_types,
         trim,
     }: DelimitedReaderConfig,
     s: String,
     span: Span,
-) -> Result<Value, csv::Error> {
+) -> Result<Value, ShellError> {
+    let delimiter_error = |err: csv::Error| ShellError::DelimiterError {
+        msg: err.to_string(),
+        span,
+    };
+
     let mut reader = ReaderBuilder::new()
         .has_headers(!noheaders)
         .flexible(flexible)
@@ -25,35 +189,100 @@ fn from_delimited_string_to_value(
         .trim(trim)
         .from_reader(s.as_bytes());
 
-    let headers = if noheaders {
-        (1..=reader.headers()?.len())
-            .map(|i| format!("column{i}"))
-            .collect::<Vec<String>>()
+    // All rows share one reference-counted copy of the column names instead of
+    // cloning them per row, which matters for tables with many rows.
+    let headers: Arc<Vec<String>> = if noheaders {
+        Arc::new(
+            (1..=reader.headers().map_err(delimiter_error)?.len())
+                .map(|i| format!("column{i}"))
+                .collect::<Vec<String>>(),
+        )
     } else {
-        reader.headers()?.iter().map(String::from).collect()
+        Arc::new(
+            reader
+                .headers()
+                .map_err(delimiter_error)?
+                .iter()
+                .map(String::from)
+                .collect(),
+        )
     };
 
+    // Without `--infer` or `--types` every cell is typed on its own, so a
+    // column can mix ints and strings. The columnar path below decides one
+    // type per column up front and coerces every row to it.
+    if infer_limit.is_none() && column_types.is_none() {
+        let mut rows = vec![];
+        for row in reader.records() {
+            let mut output_row = vec![];
+            for value in row.map_err(delimiter_error)?.iter() {
+                if no_infer {
+                    output_row.push(Value::String {
+                        span,
+                        val: value.into(),
+                    });
+                    continue;
+                }
+
+                if let Ok(i) = value.parse::<i64>() {
+                    output_row.push(Value::Int { val: i, span });
+                } else if let Ok(f) = value.parse::<f64>() {
+                    output_row.push(Value::Float { val: f, span });
+                } else {
+                    output_row.push(Value::String {
+                        val: value.into(),
+                        span,
+                    });
+                }
+            }
+            rows.push(Value::Record {
+                cols: headers.clone(),
+                vals: output_row,
+                span,
+            });
+        }
+
+        return Ok(Value::List { vals: rows, span });
+    }
+
+    let records = reader
+        .records()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(delimiter_error)?;
+
+    let overrides = match &column_types {
+        Some(types) => column_type_overrides(types, &headers)?,
+        None => vec![None; headers.len()],
+    };
+    let limit = infer_limit.unwrap_or(records.len());
+    let types: Vec<ColumnType> = overrides
+        .iter()
+        .enumerate()
+        .map(|(index, overridden)| match overridden {
+            Some(column_type) => *column_type,
+            None if no_infer => ColumnType::String,
+            None => infer_column_type(&records, index, limit),
+        })
+        .collect();
+
     let mut rows = vec![];
-    for row in reader.records() {
+    for (row_index, record) in records.iter().enumerate() {
         let mut output_row = vec![];
-        for value in row?.iter() {
-            if no_infer {
-                output_row.push(Value::String {
+        for (index, value) in record.iter().enumerate() {
+            match types.get(index) {
+                Some(column_type) => output_row.push(coerce_cell(
+                    value,
+                    *column_type,
+                    row_index + 1,
+                    &headers[index],
                     span,
-                    val: value.into(),
-                });
-                continue;
-            }
-
-            if let Ok(i) = value.parse::<i64>() {
-                output_row.push(Value::Int { val: i, span });
-            } else if let Ok(f) = value.parse::<f64>() {
-                output_row.push(Value::Float { val: f, span });
-            } else {
-                output_row.push(Value::String {
+                )?),
+                // `--flexible` rows can be wider than the header row; those
+                // trailing cells have no column to type, so keep them strings.
+                None => output_row.push(Value::String {
                     val: value.into(),
                     span,
-                });
+                }),
             }
         }
         rows.push(Value::Record {
@@ -74,6 +303,8 @@ pub(super) struct DelimitedReaderConfig {
     pub noheaders: bool,
     pub flexible: bool,
     pub no_infer: bool,
+    pub infer_limit: Option<usize>,
+    pub column_types: Option<Value>,
     pub trim: Trim,
 }
 
@@ -84,11 +315,7 @@ pub(super) fn from_delimited_data(
 ) -> Result<PipelineData, ShellError> {
     let (concat_string, _span, metadata) = input.collect_string_strict(name)?;
 
-    Ok(from_delimited_string_to_value(config, concat_string, name)
-        .map_err(|x| ShellError::DelimiterError {
-            msg: x.to_string(),
-            span: name,
-        })?
+    Ok(from_delimited_string_to_value(config, concat_string, name)?
         .into_pipeline_data_with_metadata(metadata))
 }
 
@@ -0,0 +1,507 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use eml_parser::eml::{EmailAddress, HeaderField, HeaderFieldValue};
+use eml_parser::EmlParser;
+use indexmap::map::IndexMap;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+const DEFAULT_BODY_PREVIEW: usize = 50;
+
+#[derive(Clone)]
+pub struct FromEml;
+
+impl Command for FromEml {
+    fn name(&self) -> &str {
+        "from eml"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from eml")
+            .input_output_types(vec![(Type::String, Type::Record(vec![]))])
+            .named(
+                "preview-body",
+                SyntaxShape::Int,
+                "How many bytes of the body to preview",
+                Some('b'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as .eml and create record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"MIME multipart messages are decoded: the first text part becomes the
+'Body' column and the remaining parts come back under 'Attachments' as
+records of Name, Type, and binary Data."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let body_preview = preview_flag(engine_state, stack, call)?;
+        let (input_string, _span, metadata) = input.collect_string_strict(head)?;
+        let value = eml_string_to_value(&input_string, body_preview, head)?;
+        Ok(value.into_pipeline_data_with_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "'From: test@email.com
+Subject: Welcome
+To: someone@somewhere.com
+
+Test' | from eml",
+                description: "Convert eml structured data into record",
+                result: Some(Value::test_record(
+                    vec!["Subject", "From", "To", "Body"],
+                    vec![
+                        Value::test_string("Welcome"),
+                        Value::test_record(
+                            vec!["Name", "Address"],
+                            vec![Value::test_nothing(), Value::test_string("test@email.com")],
+                        ),
+                        Value::test_record(
+                            vec!["Name", "Address"],
+                            vec![
+                                Value::test_nothing(),
+                                Value::test_string("someone@somewhere.com"),
+                            ],
+                        ),
+                        Value::test_string("Test"),
+                    ],
+                )),
+            },
+            Example {
+                example: "'From: test@email.com
+Subject: Welcome
+To: someone@somewhere.com
+
+Test' | from eml -b 1",
+                description: "Convert eml structured data into record, keeping one body byte",
+                result: Some(Value::test_record(
+                    vec!["Subject", "From", "To", "Body"],
+                    vec![
+                        Value::test_string("Welcome"),
+                        Value::test_record(
+                            vec!["Name", "Address"],
+                            vec![Value::test_nothing(), Value::test_string("test@email.com")],
+                        ),
+                        Value::test_record(
+                            vec!["Name", "Address"],
+                            vec![
+                                Value::test_nothing(),
+                                Value::test_string("someone@somewhere.com"),
+                            ],
+                        ),
+                        Value::test_string("T"),
+                    ],
+                )),
+            },
+        ]
+    }
+}
+
+pub(crate) fn preview_flag(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<usize, ShellError> {
+    Ok(call
+        .get_flag::<i64>(engine_state, stack, "preview-body")?
+        .map(|len| len.max(0) as usize)
+        .unwrap_or(DEFAULT_BODY_PREVIEW))
+}
+
+fn emailaddress_to_value(span: Span, email_address: &EmailAddress) -> Value {
+    let (name, address) = match email_address {
+        EmailAddress::AddressOnly { address } => {
+            (Value::nothing(span), Value::string(address, span))
+        }
+        EmailAddress::NameAndEmailAddress { name, address } => {
+            (Value::string(name, span), Value::string(address, span))
+        }
+    };
+
+    Value::record(
+        vec!["Name".into(), "Address".into()],
+        vec![name, address],
+        span,
+    )
+}
+
+fn headerfieldvalue_to_value(head: Span, value: &HeaderFieldValue) -> Value {
+    use HeaderFieldValue::*;
+
+    match value {
+        SingleEmailAddress(address) => emailaddress_to_value(head, address),
+        MultipleEmailAddresses(addresses) => Value::list(
+            addresses
+                .iter()
+                .map(|a| emailaddress_to_value(head, a))
+                .collect(),
+            head,
+        ),
+        Unstructured(s) => Value::string(s, head),
+        Empty => Value::nothing(head),
+    }
+}
+
+pub(crate) fn eml_string_to_value(
+    text: &str,
+    body_preview: usize,
+    head: Span,
+) -> Result<Value, ShellError> {
+    let eml = EmlParser::from_string(text.to_string())
+        .with_body()
+        .parse()
+        .map_err(|_| ShellError::CantConvert {
+            to_type: "structured eml data".into(),
+            from_type: "string".into(),
+            span: head,
+            help: None,
+        })?;
+
+    let mut collected = IndexMap::new();
+
+    if let Some(subj) = eml.subject {
+        collected.insert("Subject".to_string(), Value::string(subj, head));
+    }
+
+    if let Some(from) = eml.from {
+        collected.insert("From".to_string(), headerfieldvalue_to_value(head, &from));
+    }
+
+    if let Some(to) = eml.to {
+        collected.insert("To".to_string(), headerfieldvalue_to_value(head, &to));
+    }
+
+    let mut content_type = None;
+    let mut transfer_encoding = None;
+    for HeaderField { name, value } in &eml.headers {
+        if let HeaderFieldValue::Unstructured(raw) = value {
+            if name.eq_ignore_ascii_case("Content-Type") {
+                content_type = Some(raw.clone());
+            } else if name.eq_ignore_ascii_case("Content-Transfer-Encoding") {
+                transfer_encoding = Some(raw.clone());
+            }
+        }
+        collected.insert(name.to_string(), headerfieldvalue_to_value(head, value));
+    }
+
+    if let Some(raw_body) = eml.body {
+        let mut body = None;
+        let mut attachments = Vec::new();
+
+        match content_type.as_deref().and_then(boundary_param) {
+            Some(boundary) => {
+                collect_parts(&raw_body, &boundary, &mut body, &mut attachments, head)
+            }
+            None => {
+                let decoded = decode_transfer_encoding(&raw_body, transfer_encoding.as_deref());
+                if is_text(content_type.as_deref()) {
+                    body = Some(String::from_utf8_lossy(&decoded).into_owned());
+                } else {
+                    collected.insert("Body".to_string(), Value::binary(decoded, head));
+                }
+            }
+        }
+
+        if let Some(body) = body {
+            collected.insert(
+                "Body".to_string(),
+                Value::string(preview(&body, body_preview), head),
+            );
+        }
+        if !attachments.is_empty() {
+            collected.insert("Attachments".to_string(), Value::list(attachments, head));
+        }
+    }
+
+    Ok(Value::from(Spanned {
+        item: collected,
+        span: head,
+    }))
+}
+
+fn is_text(content_type: Option<&str>) -> bool {
+    match content_type {
+        Some(mime) => {
+            let mime = mime.trim_start();
+            mime.starts_with("text/") || mime.starts_with("message/")
+        }
+        None => true,
+    }
+}
+
+/// Extract the boundary parameter of a multipart Content-Type header
+fn boundary_param(content_type: &str) -> Option<String> {
+    if !content_type.trim_start().starts_with("multipart/") {
+        return None;
+    }
+    content_type.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.trim().split_once('=')?;
+        if key.trim().eq_ignore_ascii_case("boundary") {
+            Some(value.trim().trim_matches('"').to_string())
+        } else {
+            None
+        }
+    })
+}
+
+fn collect_parts(
+    raw_body: &str,
+    boundary: &str,
+    body: &mut Option<String>,
+    attachments: &mut Vec<Value>,
+    head: Span,
+) {
+    let open = format!("--{boundary}");
+    let close = format!("--{boundary}--");
+
+    let mut part: Option<Vec<&str>> = None;
+    for line in raw_body.lines() {
+        let marker = line.trim_end();
+        if marker == open || marker == close {
+            if let Some(lines) = part.take() {
+                collect_part(&lines.join("\n"), body, attachments, head);
+            }
+            if marker == close {
+                break;
+            }
+            part = Some(Vec::new());
+        } else if let Some(lines) = part.as_mut() {
+            lines.push(line);
+        }
+    }
+    if let Some(lines) = part.take() {
+        collect_part(&lines.join("\n"), body, attachments, head);
+    }
+}
+
+fn collect_part(part: &str, body: &mut Option<String>, attachments: &mut Vec<Value>, head: Span) {
+    let (headers, content) = split_part(part);
+    let content_type = part_header(&headers, "Content-Type");
+    let disposition = part_header(&headers, "Content-Disposition");
+    let encoding = part_header(&headers, "Content-Transfer-Encoding");
+
+    // A multipart part may itself be multipart; flatten it
+    if let Some(inner) = content_type.as_deref().and_then(boundary_param) {
+        collect_parts(content, &inner, body, attachments, head);
+        return;
+    }
+
+    let decoded = decode_transfer_encoding(content, encoding.as_deref());
+    let attached = disposition
+        .as_deref()
+        .map_or(false, |d| d.trim_start().starts_with("attachment"));
+
+    if !attached && body.is_none() && is_text(content_type.as_deref()) {
+        *body = Some(String::from_utf8_lossy(&decoded).into_owned());
+        return;
+    }
+
+    let name = disposition
+        .as_deref()
+        .and_then(|d| part_param(d, "filename"))
+        .or_else(|| content_type.as_deref().and_then(|c| part_param(c, "name")))
+        .map_or_else(|| Value::nothing(head), |n| Value::string(n, head));
+    let mime = content_type.map_or_else(
+        || Value::nothing(head),
+        |c| Value::string(c.split(';').next().unwrap_or("").trim(), head),
+    );
+
+    attachments.push(Value::record(
+        vec!["Name".into(), "Type".into(), "Data".into()],
+        vec![name, mime, Value::binary(decoded, head)],
+        head,
+    ));
+}
+
+/// Split a MIME part into its folded header lines and its content
+fn split_part(part: &str) -> (Vec<String>, &str) {
+    let mut headers: Vec<String> = Vec::new();
+    let mut consumed = 0;
+    for line in part.split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        if trimmed.is_empty() {
+            consumed += line.len();
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            if let Some(last) = headers.last_mut() {
+                last.push(' ');
+                last.push_str(trimmed.trim_start());
+                consumed += line.len();
+                continue;
+            }
+        }
+        headers.push(trimmed.to_string());
+        consumed += line.len();
+    }
+    (headers, &part[consumed..])
+}
+
+fn part_header(headers: &[String], name: &str) -> Option<String> {
+    headers.iter().find_map(|header| {
+        let (key, value) = header.split_once(':')?;
+        if key.trim().eq_ignore_ascii_case(name) {
+            Some(value.trim().to_string())
+        } else {
+            None
+        }
+    })
+}
+
+fn part_param(header: &str, name: &str) -> Option<String> {
+    header.split(';').skip(1).find_map(|param| {
+        let (key, value) = param.trim().split_once('=')?;
+        if key.trim().eq_ignore_ascii_case(name) {
+            Some(value.trim().trim_matches('"').to_string())
+        } else {
+            None
+        }
+    })
+}
+
+fn decode_transfer_encoding(content: &str, encoding: Option<&str>) -> Vec<u8> {
+    match encoding.map(|e| e.trim().to_ascii_lowercase()).as_deref() {
+        Some("base64") => {
+            let packed: String = content.split_whitespace().collect();
+            STANDARD
+                .decode(packed)
+                .unwrap_or_else(|_| content.as_bytes().to_vec())
+        }
+        Some("quoted-printable") => decode_quoted_printable(content),
+        _ => content.as_bytes().to_vec(),
+    }
+}
+
+fn decode_quoted_printable(content: &str) -> Vec<u8> {
+    let mut out = Vec::with_capacity(content.len());
+    let mut bytes = content.bytes().peekable();
+    while let Some(b) = bytes.next() {
+        if b != b'=' {
+            out.push(b);
+            continue;
+        }
+        match (bytes.next(), bytes.peek()) {
+            // Soft line break: '=' at end of line joins with the next line
+            (Some(b'\r'), Some(b'\n')) => {
+                bytes.next();
+            }
+            (Some(b'\n'), _) => {}
+            (Some(high), Some(&low)) => {
+                let pair = [high, low];
+                match u8::from_str_radix(std::str::from_utf8(&pair).unwrap_or(""), 16) {
+                    Ok(decoded) => {
+                        bytes.next();
+                        out.push(decoded);
+                    }
+                    Err(_) => {
+                        out.push(b'=');
+                        out.push(high);
+                    }
+                }
+            }
+            (Some(other), None) => {
+                out.push(b'=');
+                out.push(other);
+            }
+            (None, _) => out.push(b'='),
+        }
+    }
+    out
+}
+
+fn preview(body: &str, limit: usize) -> String {
+    if limit == 0 || body.len() <= limit {
+        body.to_string()
+    } else {
+        let mut cut = limit;
+        while !body.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        body[..cut].to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromEml {})
+    }
+
+    #[test]
+    fn multipart_body_and_attachment_are_split() {
+        let message = "From: a@b.c\n\
+            Subject: hi\n\
+            Content-Type: multipart/mixed; boundary=\"sep\"\n\
+            \n\
+            --sep\n\
+            Content-Type: text/plain\n\
+            \n\
+            hello there\n\
+            --sep\n\
+            Content-Type: application/octet-stream\n\
+            Content-Disposition: attachment; filename=\"raw.bin\"\n\
+            Content-Transfer-Encoding: base64\n\
+            \n\
+            AQID\n\
+            --sep--\n";
+
+        let value = eml_string_to_value(message, 50, Span::test_data()).expect("valid eml");
+        assert_eq!(
+            value.get_data_by_key("Body"),
+            Some(Value::test_string("hello there"))
+        );
+        let attachment = value
+            .get_data_by_key("Attachments")
+            .and_then(|list| list.as_list().ok().map(|vals| vals[0].clone()))
+            .expect("one attachment");
+        assert_eq!(
+            attachment.get_data_by_key("Name"),
+            Some(Value::test_string("raw.bin"))
+        );
+        assert_eq!(
+            attachment.get_data_by_key("Data"),
+            Some(Value::Binary {
+                val: vec![1, 2, 3],
+                span: Span::test_data()
+            })
+        );
+    }
+
+    #[test]
+    fn quoted_printable_bodies_are_decoded() {
+        let message = "From: a@b.c\n\
+            Content-Type: text/plain\n\
+            Content-Transfer-Encoding: quoted-printable\n\
+            \n\
+            caf=C3=A9=\n\
+            !\n";
+
+        let value = eml_string_to_value(message, 50, Span::test_data()).expect("valid eml");
+        assert_eq!(
+            value.get_data_by_key("Body"),
+            Some(Value::test_string("café!\n"))
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use indexmap::map::IndexMap;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct FromIni;
+
+impl Command for FromIni {
+    fn name(&self) -> &str {
+        "from ini"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from ini")
+            .input_output_types(vec![(Type::String, Type::Record(vec![]))])
+            .switch(
+                "keep-comments",
+                "keep comment lines as '#comment<n>' entries so 'to ini' can write them back",
+                None,
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as .ini and create record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Sections come back in file order as records of string values; keys before
+the first section header go into a section named ''. A key that appears
+twice in one section becomes a list of its values, in order, and 'to ini'
+writes such a list back as repeated keys."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let keep_comments = call.has_flag("keep-comments");
+        let (input_string, span, metadata) = input.collect_string_strict(head)?;
+        let value = from_ini_string(&input_string, keep_comments, head, span)?;
+        Ok(value.into_pipeline_data_with_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "'[package]
+name = nushell
+version = 0.78.0' | from ini",
+            description: "Converts ini formatted string to record",
+            result: Some(Value::test_record(
+                vec!["package"],
+                vec![Value::test_record(
+                    vec!["name", "version"],
+                    vec![Value::test_string("nushell"), Value::test_string("0.78.0")],
+                )],
+            )),
+        }]
+    }
+}
+
+pub fn from_ini_string(
+    text: &str,
+    keep_comments: bool,
+    head: Span,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let mut sections: IndexMap<String, IndexMap<String, Value>> = IndexMap::new();
+    let mut current = String::new();
+    let mut comment_count = 0usize;
+
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with(';') || line.starts_with('#') {
+            if keep_comments {
+                comment_count += 1;
+                sections.entry(current.clone()).or_default().insert(
+                    format!("#comment{comment_count}"),
+                    Value::string(line, span),
+                );
+            }
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            current = name.trim().to_string();
+            sections.entry(current.clone()).or_default();
+            continue;
+        }
+
+        let (key, value) = line.split_once('=').ok_or_else(|| {
+            ShellError::UnsupportedInput(
+                format!(
+                    "line {} is not a key=value pair, section header, or comment",
+                    number + 1
+                ),
+                "value originates from here".into(),
+                head,
+                span,
+            )
+        })?;
+        let key = key.trim().to_string();
+        let value = Value::string(unquote(value.trim()), span);
+
+        let section = sections.entry(current.clone()).or_default();
+        // Duplicate keys are collected into a list, in file order
+        match section.get_mut(&key) {
+            Some(Value::List { vals, .. }) => vals.push(value),
+            Some(existing) => {
+                let first = existing.clone();
+                *existing = Value::list(vec![first, value], span);
+            }
+            None => {
+                section.insert(key, value);
+            }
+        }
+    }
+
+    let record: IndexMap<String, Value> = sections
+        .into_iter()
+        .map(|(name, entries)| {
+            (
+                name,
+                Value::from(Spanned {
+                    item: entries,
+                    span,
+                }),
+            )
+        })
+        .collect();
+    Ok(Value::from(Spanned { item: record, span }))
+}
+
+fn unquote(value: &str) -> &str {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next_back()) {
+        (Some('\''), Some('\'')) | (Some('"'), Some('"')) if value.len() >= 2 => {
+            &value[1..value.len() - 1]
+        }
+        _ => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromIni {})
+    }
+
+    #[test]
+    fn duplicate_keys_become_a_list() {
+        let value = from_ini_string(
+            "[s]\nkey = a\nkey = b\n",
+            false,
+            Span::test_data(),
+            Span::test_data(),
+        )
+        .expect("valid ini");
+
+        assert_eq!(
+            value,
+            Value::test_record(
+                vec!["s"],
+                vec![Value::test_record(
+                    vec!["key"],
+                    vec![Value::list(
+                        vec![Value::test_string("a"), Value::test_string("b")],
+                        Span::test_data()
+                    )]
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn keys_before_a_section_go_into_the_unnamed_section() {
+        let value = from_ini_string(
+            "global = 1\n[s]\nkey = 2\n",
+            false,
+            Span::test_data(),
+            Span::test_data(),
+        )
+        .expect("valid ini");
+
+        assert_eq!(
+            value,
+            Value::test_record(
+                vec!["", "s"],
+                vec![
+                    Value::test_record(vec!["global"], vec![Value::test_string("1")]),
+                    Value::test_record(vec!["key"], vec![Value::test_string("2")]),
+                ]
+            )
+        );
+    }
+}
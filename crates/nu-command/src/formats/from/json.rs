@@ -4,6 +4,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
     Signature, Span, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct FromJson;
@@ -21,6 +22,11 @@ impl Command for FromJson {
         Signature::build("from json")
             .input_output_types(vec![(Type::String, Type::Any)])
             .switch("objects", "treat each line as a separate value", Some('o'))
+            .switch(
+                "stream",
+                "parse the input incrementally, emitting each top-level array element or concatenated value as it is read",
+                Some('s'),
+            )
             .category(Category::Formats)
     }
 
@@ -30,7 +36,7 @@ impl Command for FromJson {
                 example: r#"'{ "a": 1 }' | from json"#,
                 description: "Converts json formatted string to table",
                 result: Some(Value::Record {
-                    cols: vec!["a".to_string()],
+                    cols: Arc::new(vec!["a".to_string()]),
                     vals: vec![Value::test_int(1)],
                     span: Span::test_data(),
                 }),
@@ -39,7 +45,7 @@ impl Command for FromJson {
                 example: r#"'{ "a": 1, "b": [1, 2] }' | from json"#,
                 description: "Converts json formatted string to table",
                 result: Some(Value::Record {
-                    cols: vec!["a".to_string(), "b".to_string()],
+                    cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                     vals: vec![
                         Value::test_int(1),
                         Value::List {
@@ -50,6 +56,28 @@ impl Command for FromJson {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                example: r#"'[ { "a": 1 }, { "a": 2 } ]' | from json --stream"#,
+                description: "Parse a json array incrementally, streaming one element at a time",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_record(vec!["a"], vec![Value::test_int(1)]),
+                        Value::test_record(vec!["a"], vec![Value::test_int(2)]),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                example: "'{ \"a\": 1 }\n{ \"a\": 2 }' | from json --stream",
+                description: "Parse newline-delimited json incrementally",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_record(vec!["a"], vec![Value::test_int(1)]),
+                        Value::test_record(vec!["a"], vec![Value::test_int(2)]),
+                    ],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 
@@ -61,6 +89,36 @@ impl Command for FromJson {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let span = call.head;
+
+        if call.has_flag("stream") {
+            let metadata = input.metadata();
+            let chunks: Box<dyn Iterator<Item = Result<Value, ShellError>> + Send> = match input {
+                PipelineData::Empty | PipelineData::ExternalStream { stdout: None, .. } => {
+                    return Ok(PipelineData::new_with_metadata(metadata, span))
+                }
+                PipelineData::ExternalStream {
+                    stdout: Some(stream),
+                    ..
+                } => Box::new(stream),
+                PipelineData::Value(Value::String { val, span }, ..) => {
+                    Box::new(std::iter::once(Ok(Value::String { val, span })))
+                }
+                PipelineData::Value(Value::Binary { val, span }, ..) => {
+                    Box::new(std::iter::once(Ok(Value::Binary { val, span })))
+                }
+                input => {
+                    let (string_input, span, _) = input.collect_string_strict(span)?;
+                    Box::new(std::iter::once(Ok(Value::String {
+                        val: string_input,
+                        span,
+                    })))
+                }
+            };
+
+            return Ok(JsonStreamer::new(chunks, span)
+                .into_pipeline_data_with_metadata(metadata, engine_state.ctrlc.clone()));
+        }
+
         let (string_input, span, metadata) = input.collect_string_strict(span)?;
 
         if string_input.is_empty() {
@@ -116,7 +174,11 @@ fn convert_nujson_to_value(value: &nu_json::Value, span: Span) -> Value {
                 vals.push(convert_nujson_to_value(item.1, span));
             }
 
-            Value::Record { cols, vals, span }
+            Value::Record {
+                cols: Arc::new(cols),
+                vals,
+                span,
+            }
         }
         nu_json::Value::U64(u) => {
             if *u > i64::MAX as u64 {
@@ -162,7 +224,10 @@ fn convert_row_column_to_span(row: usize, col: usize, contents: &str) -> Span {
     Span::new(contents.len(), contents.len())
 }
 
-fn convert_string_to_value(string_input: String, span: Span) -> Result<Value, ShellError> {
+pub(crate) fn convert_string_to_value(
+    string_input: String,
+    span: Span,
+) -> Result<Value, ShellError> {
     let result: Result<nu_json::Value, nu_json::Error> = nu_json::from_str(&string_input);
     match result {
         Ok(value) => Ok(convert_nujson_to_value(&value, span)),
@@ -194,6 +259,219 @@ fn convert_string_to_value(string_input: String, span: Span) -> Result<Value, Sh
     }
 }
 
+#[derive(Clone, Copy)]
+enum StreamMode {
+    /// The input is one top-level array; elements are split at the commas
+    /// between them.
+    Array,
+    /// The input is a sequence of concatenated values (e.g. NDJSON); values
+    /// are split where bracket depth returns to zero.
+    Concatenated,
+}
+
+/// Splits incoming chunks of JSON text into complete top-level values and
+/// parses them one at a time, so consumers like `first` can stop reading long
+/// before the whole input has been parsed.
+///
+/// The splitter only tracks strings (with escapes) and bracket depth; each
+/// complete element is handed to the regular parser, so a malformed element
+/// becomes a `Value::Error` without stopping the stream.
+struct JsonStreamer {
+    chunks: Box<dyn Iterator<Item = Result<Value, ShellError>> + Send>,
+    span: Span,
+    buffer: Vec<u8>,
+    pos: usize,
+    element: Vec<u8>,
+    mode: Option<StreamMode>,
+    in_string: bool,
+    escaped: bool,
+    depth: usize,
+    chunks_done: bool,
+    closed: bool,
+}
+
+impl JsonStreamer {
+    fn new(chunks: Box<dyn Iterator<Item = Result<Value, ShellError>> + Send>, span: Span) -> Self {
+        JsonStreamer {
+            chunks,
+            span,
+            buffer: Vec::new(),
+            pos: 0,
+            element: Vec::new(),
+            mode: None,
+            in_string: false,
+            escaped: false,
+            depth: 0,
+            chunks_done: false,
+            closed: false,
+        }
+    }
+
+    /// Parse and clear the element gathered so far, if it holds anything
+    fn complete_element(&mut self) -> Option<Value> {
+        let element = std::mem::take(&mut self.element);
+        let text = String::from_utf8_lossy(&element);
+        let text = text.trim();
+        if text.is_empty() {
+            return None;
+        }
+
+        match convert_string_to_value(text.to_string(), self.span) {
+            Ok(value) => Some(value),
+            Err(error) => Some(Value::Error {
+                error: Box::new(error),
+            }),
+        }
+    }
+
+    /// Advance the splitter by one byte, returning a value if it completes one
+    fn feed(&mut self, b: u8) -> Option<Value> {
+        let mode = match self.mode {
+            Some(mode) => mode,
+            None => {
+                if b.is_ascii_whitespace() {
+                    return None;
+                }
+                if b == b'[' {
+                    self.mode = Some(StreamMode::Array);
+                    return None;
+                }
+                self.mode = Some(StreamMode::Concatenated);
+                StreamMode::Concatenated
+            }
+        };
+
+        if self.in_string {
+            self.element.push(b);
+            if self.escaped {
+                self.escaped = false;
+            } else if b == b'\\' {
+                self.escaped = true;
+            } else if b == b'"' {
+                self.in_string = false;
+                if matches!(mode, StreamMode::Concatenated) && self.depth == 0 {
+                    return self.complete_element();
+                }
+            }
+            return None;
+        }
+
+        match mode {
+            StreamMode::Array => match b {
+                b'"' => {
+                    self.element.push(b);
+                    self.in_string = true;
+                }
+                b'[' | b'{' => {
+                    self.depth += 1;
+                    self.element.push(b);
+                }
+                b'}' => {
+                    self.depth = self.depth.saturating_sub(1);
+                    self.element.push(b);
+                }
+                b']' => {
+                    if self.depth == 0 {
+                        // closing bracket of the top-level array; anything
+                        // after it is ignored
+                        self.closed = true;
+                        return self.complete_element();
+                    }
+                    self.depth -= 1;
+                    self.element.push(b);
+                }
+                b',' if self.depth == 0 => return self.complete_element(),
+                _ => self.element.push(b),
+            },
+            StreamMode::Concatenated => match b {
+                b'"' => {
+                    self.element.push(b);
+                    self.in_string = true;
+                }
+                b'[' | b'{' => {
+                    self.depth += 1;
+                    self.element.push(b);
+                }
+                b']' | b'}' => {
+                    self.depth = self.depth.saturating_sub(1);
+                    self.element.push(b);
+                    if self.depth == 0 {
+                        return self.complete_element();
+                    }
+                }
+                _ if b.is_ascii_whitespace() => {
+                    if self.depth == 0 {
+                        return self.complete_element();
+                    }
+                    self.element.push(b);
+                }
+                _ => self.element.push(b),
+            },
+        }
+
+        None
+    }
+}
+
+impl Iterator for JsonStreamer {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.closed {
+                return None;
+            }
+
+            while self.pos < self.buffer.len() {
+                let b = self.buffer[self.pos];
+                self.pos += 1;
+                if let Some(value) = self.feed(b) {
+                    return Some(value);
+                }
+                if self.closed {
+                    return None;
+                }
+            }
+
+            if self.chunks_done {
+                self.closed = true;
+                // an unterminated trailing element still gets parsed; if it
+                // is incomplete this produces a parse error value
+                return self.complete_element();
+            }
+
+            match self.chunks.next() {
+                Some(Ok(Value::String { val, .. })) => {
+                    self.buffer = val.into_bytes();
+                    self.pos = 0;
+                }
+                Some(Ok(Value::Binary { val, .. })) => {
+                    self.buffer = val;
+                    self.pos = 0;
+                }
+                Some(Ok(other)) => {
+                    self.closed = true;
+                    return Some(Value::Error {
+                        error: Box::new(ShellError::OnlySupportsThisInputType {
+                            exp_input_type: "string or binary".into(),
+                            wrong_type: other.get_type().to_string(),
+                            dst_span: self.span,
+                            src_span: other.expect_span(),
+                        }),
+                    });
+                }
+                Some(Err(error)) => {
+                    self.closed = true;
+                    return Some(Value::Error {
+                        error: Box::new(error),
+                    });
+                }
+                None => self.chunks_done = true,
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
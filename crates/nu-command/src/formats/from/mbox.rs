@@ -0,0 +1,261 @@
+use super::eml::{eml_string_to_value, preview_flag};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, RawStream,
+    ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromMbox;
+
+impl Command for FromMbox {
+    fn name(&self) -> &str {
+        "from mbox"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from mbox")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .named(
+                "preview-body",
+                SyntaxShape::Int,
+                "How many bytes of each body to preview",
+                Some('b'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as an mbox mail archive and create a table of messages."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each message becomes a record with the same shape as 'from eml'.
+Raw input (for example from 'open --raw') is parsed message by message
+as it arrives, so large archives do not have to fit in memory."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let ctrlc = engine_state.ctrlc.clone();
+        let body_preview = preview_flag(engine_state, stack, call)?;
+
+        if let PipelineData::ExternalStream {
+            stdout: Some(raw), ..
+        } = input
+        {
+            let messages = MessageScanner::new(raw, body_preview, head);
+            return Ok(messages.into_pipeline_data(ctrlc));
+        }
+
+        let text = match input.into_value(head) {
+            Value::String { val, .. } => val,
+            Value::Error { error } => return Err(*error),
+            other => {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "string or raw data".into(),
+                    wrong_type: other.get_type().to_string(),
+                    dst_span: head,
+                    src_span: other.expect_span(),
+                })
+            }
+        };
+
+        let mut vals = Vec::new();
+        let mut buffer = text.as_str();
+        while let Some(message) = take_message(&mut buffer, true) {
+            vals.push(message_to_value(&message, body_preview, head));
+        }
+        Ok(Value::List { vals, span: head }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "'From alice Thu Jan  1 00:00:00 1970
+From: alice@example.com
+Subject: one
+
+hi
+From bob Thu Jan  1 00:01:00 1970
+From: bob@example.com
+Subject: two
+
+bye' | from mbox",
+            description: "Convert an mbox archive into a table of messages",
+            result: Some(Value::list(
+                vec![
+                    Value::test_record(
+                        vec!["Subject", "From", "Body"],
+                        vec![
+                            Value::test_string("one"),
+                            Value::test_record(
+                                vec!["Name", "Address"],
+                                vec![
+                                    Value::test_nothing(),
+                                    Value::test_string("alice@example.com"),
+                                ],
+                            ),
+                            Value::test_string("hi\n"),
+                        ],
+                    ),
+                    Value::test_record(
+                        vec!["Subject", "From", "Body"],
+                        vec![
+                            Value::test_string("two"),
+                            Value::test_record(
+                                vec!["Name", "Address"],
+                                vec![Value::test_nothing(), Value::test_string("bob@example.com")],
+                            ),
+                            Value::test_string("bye"),
+                        ],
+                    ),
+                ],
+                Span::test_data(),
+            )),
+        }]
+    }
+}
+
+// An mbox message runs from one "From " envelope line to the next. When
+// `at_end` is false the final chunk is kept in the buffer, since more of
+// it may still arrive.
+fn take_message(buffer: &mut &str, at_end: bool) -> Option<String> {
+    let start = if buffer.starts_with("From ") {
+        0
+    } else {
+        buffer.find("\nFrom ").map(|at| at + 1)?
+    };
+    let rest = &buffer[start..];
+    match rest.find("\nFrom ") {
+        Some(end) => {
+            let message = unescape_message(&rest[..end + 1]);
+            *buffer = &rest[end + 1..];
+            Some(message)
+        }
+        None if at_end && !rest.is_empty() => {
+            let message = unescape_message(rest);
+            *buffer = "";
+            Some(message)
+        }
+        None => None,
+    }
+}
+
+// Strip the envelope line and undo ">From " quoting in the body.
+fn unescape_message(message: &str) -> String {
+    let body = match message.split_once('\n') {
+        Some((_envelope, body)) => body,
+        None => "",
+    };
+    let mut out = String::with_capacity(body.len());
+    for line in body.split_inclusive('\n') {
+        let quoted = line.trim_start_matches('>');
+        if quoted.starts_with("From ") {
+            out.push_str(&line[1..]);
+        } else {
+            out.push_str(line);
+        }
+    }
+    out
+}
+
+fn message_to_value(message: &str, body_preview: usize, span: Span) -> Value {
+    match eml_string_to_value(message, body_preview, span) {
+        Ok(value) => value,
+        Err(error) => Value::Error {
+            error: Box::new(error),
+        },
+    }
+}
+
+struct MessageScanner {
+    inner: RawStream,
+    inner_complete: bool,
+    buffer: String,
+    body_preview: usize,
+    span: Span,
+}
+
+impl MessageScanner {
+    fn new(inner: RawStream, body_preview: usize, span: Span) -> Self {
+        Self {
+            inner,
+            inner_complete: false,
+            buffer: String::new(),
+            body_preview,
+            span,
+        }
+    }
+
+    fn take_buffered(&mut self) -> Option<String> {
+        let mut rest = self.buffer.as_str();
+        let message = take_message(&mut rest, self.inner_complete)?;
+        let consumed = self.buffer.len() - rest.len();
+        self.buffer.drain(..consumed);
+        Some(message)
+    }
+}
+
+impl Iterator for MessageScanner {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(message) = self.take_buffered() {
+                return Some(message_to_value(&message, self.body_preview, self.span));
+            }
+
+            if self.inner_complete {
+                return None;
+            }
+            match self.inner.next() {
+                Some(Ok(Value::String { val, .. })) => self.buffer.push_str(&val),
+                Some(Ok(Value::Binary { val, .. })) => {
+                    self.buffer.push_str(&String::from_utf8_lossy(&val))
+                }
+                Some(Ok(Value::Error { error })) => return Some(Value::Error { error }),
+                Some(Err(error)) => {
+                    return Some(Value::Error {
+                        error: Box::new(error),
+                    })
+                }
+                Some(Ok(_)) => {}
+                None => self.inner_complete = true,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromMbox {})
+    }
+
+    #[test]
+    fn quoted_from_lines_are_unescaped() {
+        let mut text = "From a\nSubject: s\n\n>From the start\n";
+        let message = take_message(&mut text, true).expect("one message");
+        assert!(message.contains("\nFrom the start\n"));
+    }
+
+    #[test]
+    fn partial_trailing_message_waits_for_more_input() {
+        let mut text = "From a\nSubject: one\n\nbody\nFrom b\nSubject: two\n\nbo";
+        assert!(take_message(&mut text, false).is_some());
+        assert!(take_message(&mut text, false).is_none());
+        assert!(take_message(&mut text, true).is_some());
+    }
+}
@@ -1,13 +1,24 @@
+mod clf;
 mod command;
 mod csv;
 mod delimited;
+mod eml;
+mod ics;
+mod ini;
 mod json;
+mod mbox;
+mod ndjson;
 mod nuon;
 mod ods;
+mod plist;
+mod prometheus;
+mod properties;
 mod ssv;
+mod syslog;
 mod toml;
 mod tsv;
 mod url;
+mod vcf;
 mod xlsx;
 mod xml;
 mod yaml;
@@ -15,13 +26,29 @@ mod yaml;
 pub use self::csv::FromCsv;
 pub use self::toml::FromToml;
 pub use self::url::FromUrl;
+pub use clf::FromClf;
 pub use command::From;
+pub use eml::FromEml;
+pub use ics::FromIcs;
+pub use ini::FromIni;
+pub(crate) use json::convert_string_to_value;
 pub use json::FromJson;
+pub use mbox::FromMbox;
+pub use ndjson::FromNdjson;
+pub(crate) use nuon::from_nuon_string;
 pub use nuon::FromNuon;
 pub use ods::FromOds;
+#[cfg(target_os = "macos")]
+pub(crate) use plist::from_xml_plist;
+pub use plist::FromPlist;
+pub use prometheus::FromPrometheus;
+pub use properties::FromProperties;
 pub use ssv::FromSsv;
+pub use syslog::FromSyslog;
 pub use tsv::FromTsv;
+pub use vcf::FromVcf;
 pub use xlsx::FromXlsx;
+pub(crate) use xml::xml_element_to_value;
 pub use xml::FromXml;
 pub use yaml::FromYaml;
 pub use yaml::FromYml;
@@ -0,0 +1,192 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, RawStream,
+    ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct FromNdjson;
+
+impl Command for FromNdjson {
+    fn name(&self) -> &str {
+        "from ndjson"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from ndjson")
+            .input_output_types(vec![(Type::String, Type::Any)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as newline-delimited JSON (JSON lines) and create a list."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Blank lines are skipped. Raw input (for example from 'open --raw') is
+parsed line by line as it arrives instead of being collected first, and
+a line that is not valid JSON reports its line number."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let ctrlc = engine_state.ctrlc.clone();
+
+        if let PipelineData::ExternalStream {
+            stdout: Some(raw), ..
+        } = input
+        {
+            let lines = LineScanner::new(raw, head);
+            return Ok(lines.into_pipeline_data(ctrlc));
+        }
+
+        let text = match input.into_value(head) {
+            Value::String { val, .. } => val,
+            Value::Error { error } => return Err(*error),
+            other => {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "string or raw data".into(),
+                    wrong_type: other.get_type().to_string(),
+                    dst_span: head,
+                    src_span: other.expect_span(),
+                })
+            }
+        };
+
+        let vals = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(at, line)| parse_line(line, at + 1, head))
+            .collect();
+        Ok(Value::List { vals, span: head }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: r#"'{"a": 1}
+{"a": 2}' | from ndjson"#,
+            description: "Converts json lines to a table",
+            result: Some(Value::list(
+                vec![
+                    Value::test_record(vec!["a"], vec![Value::test_int(1)]),
+                    Value::test_record(vec!["a"], vec![Value::test_int(2)]),
+                ],
+                Span::test_data(),
+            )),
+        }]
+    }
+}
+
+fn parse_line(line: &str, number: usize, span: Span) -> Value {
+    match super::json::convert_string_to_value(line.to_string(), span) {
+        Ok(value) => value,
+        Err(err) => Value::Error {
+            error: Box::new(ShellError::GenericError(
+                format!("line {number} is not valid JSON"),
+                err.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )),
+        },
+    }
+}
+
+struct LineScanner {
+    inner: RawStream,
+    inner_complete: bool,
+    buffer: String,
+    line_number: usize,
+    span: Span,
+}
+
+impl LineScanner {
+    fn new(inner: RawStream, span: Span) -> Self {
+        Self {
+            inner,
+            inner_complete: false,
+            buffer: String::new(),
+            line_number: 0,
+            span,
+        }
+    }
+
+    fn take_line(&mut self) -> Option<String> {
+        match self.buffer.find('\n') {
+            Some(at) => {
+                let line = self.buffer[..at].trim_end_matches('\r').to_string();
+                self.buffer.drain(..at + 1);
+                Some(line)
+            }
+            None if self.inner_complete && !self.buffer.is_empty() => {
+                Some(std::mem::take(&mut self.buffer))
+            }
+            None => None,
+        }
+    }
+}
+
+impl Iterator for LineScanner {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(line) = self.take_line() {
+                self.line_number += 1;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                return Some(parse_line(&line, self.line_number, self.span));
+            }
+
+            if self.inner_complete {
+                return None;
+            }
+            match self.inner.next() {
+                Some(Ok(Value::String { val, .. })) => self.buffer.push_str(&val),
+                Some(Ok(Value::Binary { val, .. })) => {
+                    self.buffer.push_str(&String::from_utf8_lossy(&val))
+                }
+                Some(Ok(Value::Error { error })) => return Some(Value::Error { error }),
+                Some(Err(error)) => {
+                    return Some(Value::Error {
+                        error: Box::new(error),
+                    })
+                }
+                Some(Ok(_)) => {}
+                None => self.inner_complete = true,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromNdjson {})
+    }
+
+    #[test]
+    fn bad_lines_report_their_line_number() {
+        let value = parse_line("{", 3, Span::test_data());
+        match value {
+            Value::Error { error } => {
+                assert!(error.to_string().contains("line 3"), "got: {error:?}")
+            }
+            other => panic!("expected an error value, got {other:?}"),
+        }
+    }
+}
@@ -4,6 +4,7 @@ use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, Range, ShellError, Signature, Span, Type,
     Unit, Value,
 };
+use std::sync::Arc;
 #[derive(Clone)]
 pub struct FromNuon;
 
@@ -28,7 +29,7 @@ impl Command for FromNuon {
                 example: "'{ a:1 }' | from nuon",
                 description: "Converts nuon formatted string to table",
                 result: Some(Value::Record {
-                    cols: vec!["a".to_string()],
+                    cols: Arc::new(vec!["a".to_string()]),
                     vals: vec![Value::test_int(1)],
                     span: Span::test_data(),
                 }),
@@ -37,7 +38,7 @@ impl Command for FromNuon {
                 example: "'{ a:1, b: [1, 2] }' | from nuon",
                 description: "Converts nuon formatted string to table",
                 result: Some(Value::Record {
-                    cols: vec!["a".to_string(), "b".to_string()],
+                    cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                     vals: vec![
                         Value::test_int(1),
                         Value::List {
@@ -61,6 +62,19 @@ impl Command for FromNuon {
         let head = call.head;
         let (string_input, _span, metadata) = input.collect_string_strict(head)?;
 
+        Ok(from_nuon_string(engine_state, string_input, head)?
+            .into_pipeline_data_with_metadata(metadata))
+    }
+}
+
+/// Load a nuon string into a [`Value`], also used outside of `from nuon` for
+/// things like package manifests.
+pub fn from_nuon_string(
+    engine_state: &EngineState,
+    string_input: String,
+    head: Span,
+) -> Result<Value, ShellError> {
+    {
         let engine_state = engine_state.clone();
 
         let mut working_set = StateWorkingSet::new(&engine_state);
@@ -162,7 +176,7 @@ impl Command for FromNuon {
         let result = convert_to_value(expr, head, &string_input);
 
         match result {
-            Ok(result) => Ok(result.into_pipeline_data_with_metadata(metadata)),
+            Ok(result) => Ok(result),
             Err(err) => Err(ShellError::GenericError(
                 "error when loading nuon text".into(),
                 "could not load nuon text".into(),
@@ -228,6 +242,7 @@ fn convert_to_value(
         Expr::Filepath(val) => Ok(Value::String { val, span }),
         Expr::Directory(val) => Ok(Value::String { val, span }),
         Expr::Float(val) => Ok(Value::Float { val, span }),
+        Expr::Decimal(val) => Ok(Value::Decimal { val, span }),
         Expr::FullCellPath(full_cell_path) => {
             if !full_cell_path.tail.is_empty() {
                 Err(ShellError::OutsideSpannedLabeledError(
@@ -341,7 +356,11 @@ fn convert_to_value(
                 vals.push(value);
             }
 
-            Ok(Value::Record { cols, vals, span })
+            Ok(Value::Record {
+                cols: Arc::new(cols),
+                vals,
+                span,
+            })
         }
         Expr::RowCondition(..) => Err(ShellError::OutsideSpannedLabeledError(
             original_text.to_string(),
@@ -389,6 +408,9 @@ fn convert_to_value(
                 cols.push(key_str);
             }
 
+            // one shared copy of the column names for every row
+            let cols = Arc::new(cols);
+
             for row in cells {
                 let mut vals = vec![];
 
@@ -7,6 +7,7 @@ use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 use std::io::Cursor;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct FromOds;
@@ -162,7 +163,7 @@ fn from_ods(
                         });
 
                 let record = Value::Record {
-                    cols,
+                    cols: Arc::new(cols),
                     vals,
                     span: head,
                 };
@@ -194,7 +195,7 @@ fn from_ods(
     });
 
     let record = Value::Record {
-        cols,
+        cols: Arc::new(cols),
         vals,
         span: head,
     };
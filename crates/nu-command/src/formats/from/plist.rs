@@ -0,0 +1,400 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{Duration, TimeZone, Utc};
+use indexmap::map::IndexMap;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct FromPlist;
+
+impl Command for FromPlist {
+    fn name(&self) -> &str {
+        "from plist"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from plist")
+            .input_output_types(vec![(Type::String, Type::Any), (Type::Binary, Type::Any)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse Apple property list data (XML or binary) and create a value."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Both the XML and the binary (bplist00) variants are recognized, so both
+'open file.plist' output and 'defaults export' output can be piped in."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let value = match input.into_value(head) {
+            Value::String { val, .. } => from_xml_plist(&val, head)?,
+            Value::Binary { val, .. } => {
+                if val.starts_with(b"bplist") {
+                    from_binary_plist(&val, head)?
+                } else {
+                    let text = String::from_utf8_lossy(&val);
+                    from_xml_plist(&text, head)?
+                }
+            }
+            Value::Error { error } => return Err(*error),
+            other => {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "string or binary".into(),
+                    wrong_type: other.get_type().to_string(),
+                    dst_span: head,
+                    src_span: other.expect_span(),
+                })
+            }
+        };
+        Ok(value.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert an XML property list to a record",
+                example: "'<plist version=\"1.0\"><dict><key>a</key><integer>3</integer></dict></plist>' | from plist",
+                result: Some(Value::test_record(vec!["a"], vec![Value::test_int(3)])),
+            },
+            Example {
+                description: "Convert a binary property list (here, one containing 'true')",
+                example: "0x[62 70 6C 69 73 74 30 30 09 08 00 00 00 00 00 00 01 01 00 00 00 00 00 00 00 01 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 09] | from plist",
+                result: Some(Value::test_bool(true)),
+            },
+        ]
+    }
+}
+
+fn cant_convert(help: impl Into<String>, span: Span) -> ShellError {
+    ShellError::CantConvert {
+        from_type: "plist".to_string(),
+        to_type: Type::Any.to_string(),
+        span,
+        help: Some(help.into()),
+    }
+}
+
+// Seconds between the Unix epoch and the Apple epoch (2001-01-01T00:00:00Z),
+// which plist dates are relative to.
+const APPLE_EPOCH_OFFSET: i64 = 978_307_200;
+
+fn apple_seconds_to_date(seconds: f64, span: Span) -> Value {
+    let date = Utc
+        .timestamp_opt(APPLE_EPOCH_OFFSET, 0)
+        .single()
+        .unwrap_or_default()
+        + Duration::milliseconds((seconds * 1000.0) as i64);
+    Value::Date {
+        val: date.into(),
+        span,
+    }
+}
+
+pub(crate) fn from_xml_plist(text: &str, span: Span) -> Result<Value, ShellError> {
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..Default::default()
+    };
+    let document = roxmltree::Document::parse_with_options(text, options)
+        .map_err(|err| cant_convert(err.to_string(), span))?;
+
+    let root = document.root_element();
+    let node = if root.tag_name().name() == "plist" {
+        root.children()
+            .find(|n| n.is_element())
+            .ok_or_else(|| cant_convert("the <plist> element is empty", span))?
+    } else {
+        root
+    };
+    xml_node_to_value(&node, span)
+}
+
+fn xml_node_to_value(n: &roxmltree::Node, span: Span) -> Result<Value, ShellError> {
+    let text = || n.text().unwrap_or("").trim().to_string();
+    match n.tag_name().name() {
+        "dict" => {
+            let mut record = IndexMap::new();
+            let mut elements = n.children().filter(|c| c.is_element());
+            while let Some(key) = elements.next() {
+                if key.tag_name().name() != "key" {
+                    return Err(cant_convert("expected a <key> element in <dict>", span));
+                }
+                let value = elements
+                    .next()
+                    .ok_or_else(|| cant_convert("<key> element without a value", span))?;
+                record.insert(
+                    key.text().unwrap_or("").to_string(),
+                    xml_node_to_value(&value, span)?,
+                );
+            }
+            Ok(Value::from(Spanned { item: record, span }))
+        }
+        "array" => {
+            let vals = n
+                .children()
+                .filter(|c| c.is_element())
+                .map(|c| xml_node_to_value(&c, span))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::list(vals, span))
+        }
+        "string" => Ok(Value::string(n.text().unwrap_or(""), span)),
+        "integer" => text()
+            .parse()
+            .map(|val| Value::Int { val, span })
+            .map_err(|_| cant_convert(format!("'{}' is not an integer", text()), span)),
+        "real" => text()
+            .parse()
+            .map(|val| Value::Float { val, span })
+            .map_err(|_| cant_convert(format!("'{}' is not a real", text()), span)),
+        "true" => Ok(Value::boolean(true, span)),
+        "false" => Ok(Value::boolean(false, span)),
+        "date" => {
+            let text = text();
+            chrono::DateTime::parse_from_rfc3339(&text)
+                .map(|val| Value::Date { val, span })
+                .map_err(|_| cant_convert(format!("'{text}' is not a plist date"), span))
+        }
+        "data" => {
+            let encoded: String = text().split_whitespace().collect();
+            STANDARD
+                .decode(encoded)
+                .map(|val| Value::Binary { val, span })
+                .map_err(|_| cant_convert("<data> is not valid base64", span))
+        }
+        other => Err(cant_convert(
+            format!("unsupported plist element <{other}>"),
+            span,
+        )),
+    }
+}
+
+struct BinaryPlist<'a> {
+    bytes: &'a [u8],
+    offsets: Vec<usize>,
+    ref_size: usize,
+    span: Span,
+}
+
+fn from_binary_plist(bytes: &[u8], span: Span) -> Result<Value, ShellError> {
+    if bytes.len() < 40 {
+        return Err(cant_convert("binary plist is truncated", span));
+    }
+    let trailer = &bytes[bytes.len() - 32..];
+    let offset_size = trailer[6] as usize;
+    let ref_size = trailer[7] as usize;
+    let num_objects = be_uint(&trailer[8..16]) as usize;
+    let top_object = be_uint(&trailer[16..24]) as usize;
+    let table_offset = be_uint(&trailer[24..32]) as usize;
+
+    if offset_size == 0 || ref_size == 0 || offset_size > 8 || ref_size > 8 {
+        return Err(cant_convert("binary plist trailer is malformed", span));
+    }
+
+    let mut offsets = Vec::with_capacity(num_objects);
+    for i in 0..num_objects {
+        let at = table_offset + i * offset_size;
+        let entry = bytes
+            .get(at..at + offset_size)
+            .ok_or_else(|| cant_convert("binary plist offset table is truncated", span))?;
+        offsets.push(be_uint(entry) as usize);
+    }
+
+    let plist = BinaryPlist {
+        bytes,
+        offsets,
+        ref_size,
+        span,
+    };
+    plist.decode(top_object, 0)
+}
+
+fn be_uint(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |acc, b| (acc << 8) | u64::from(*b))
+}
+
+impl BinaryPlist<'_> {
+    fn error(&self, help: impl Into<String>) -> ShellError {
+        cant_convert(help, self.span)
+    }
+
+    fn decode(&self, object: usize, depth: usize) -> Result<Value, ShellError> {
+        if depth > 512 {
+            return Err(self.error("binary plist is nested too deeply"));
+        }
+        let offset = *self
+            .offsets
+            .get(object)
+            .ok_or_else(|| self.error("object reference out of range"))?;
+        let marker = *self
+            .bytes
+            .get(offset)
+            .ok_or_else(|| self.error("object offset out of range"))?;
+        let info = (marker & 0x0f) as usize;
+        let span = self.span;
+
+        match marker >> 4 {
+            0x0 => match marker {
+                0x00 => Ok(Value::nothing(span)),
+                0x08 => Ok(Value::boolean(false, span)),
+                0x09 => Ok(Value::boolean(true, span)),
+                _ => Err(self.error(format!("unsupported marker {marker:#04x}"))),
+            },
+            0x1 => {
+                let val = be_uint(self.slice(offset + 1, 1 << info)?) as i64;
+                Ok(Value::Int { val, span })
+            }
+            0x2 => {
+                let data = self.slice(offset + 1, 1 << info)?;
+                let val = match data.len() {
+                    4 => f32::from_be_bytes(data.try_into().expect("checked length")) as f64,
+                    8 => f64::from_be_bytes(data.try_into().expect("checked length")),
+                    _ => return Err(self.error("unsupported real width")),
+                };
+                Ok(Value::Float { val, span })
+            }
+            0x3 => {
+                let data = self.slice(offset + 1, 8)?;
+                let seconds = f64::from_be_bytes(data.try_into().expect("checked length"));
+                Ok(apple_seconds_to_date(seconds, span))
+            }
+            0x4 => {
+                let (len, start) = self.length(offset, info)?;
+                let val = self.slice(start, len)?.to_vec();
+                Ok(Value::Binary { val, span })
+            }
+            0x5 => {
+                let (len, start) = self.length(offset, info)?;
+                let val = String::from_utf8_lossy(self.slice(start, len)?).into_owned();
+                Ok(Value::String { val, span })
+            }
+            0x6 => {
+                let (len, start) = self.length(offset, info)?;
+                let units: Vec<u16> = self
+                    .slice(start, len * 2)?
+                    .chunks_exact(2)
+                    .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                    .collect();
+                Ok(Value::string(String::from_utf16_lossy(&units), span))
+            }
+            0x8 => {
+                let val = be_uint(self.slice(offset + 1, info + 1)?) as i64;
+                Ok(Value::Int { val, span })
+            }
+            0xa => {
+                let (len, start) = self.length(offset, info)?;
+                let vals = (0..len)
+                    .map(|i| {
+                        let reference =
+                            be_uint(self.slice(start + i * self.ref_size, self.ref_size)?);
+                        self.decode(reference as usize, depth + 1)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Value::list(vals, span))
+            }
+            0xd => {
+                let (len, start) = self.length(offset, info)?;
+                let mut record = IndexMap::new();
+                for i in 0..len {
+                    let key_ref = be_uint(self.slice(start + i * self.ref_size, self.ref_size)?);
+                    let val_ref =
+                        be_uint(self.slice(start + (len + i) * self.ref_size, self.ref_size)?);
+                    let key = match self.decode(key_ref as usize, depth + 1)? {
+                        Value::String { val, .. } => val,
+                        other => other.into_string("", &nu_protocol::Config::default()),
+                    };
+                    record.insert(key, self.decode(val_ref as usize, depth + 1)?);
+                }
+                Ok(Value::from(Spanned { item: record, span }))
+            }
+            _ => Err(self.error(format!("unsupported marker {marker:#04x}"))),
+        }
+    }
+
+    fn slice(&self, start: usize, len: usize) -> Result<&[u8], ShellError> {
+        self.bytes
+            .get(start..start + len)
+            .ok_or_else(|| self.error("binary plist object is truncated"))
+    }
+
+    // Collection and blob sizes of 15 or more are stored as a trailing int
+    // object right after the marker.
+    fn length(&self, offset: usize, info: usize) -> Result<(usize, usize), ShellError> {
+        if info != 0x0f {
+            return Ok((info, offset + 1));
+        }
+        let marker = *self
+            .bytes
+            .get(offset + 1)
+            .ok_or_else(|| self.error("binary plist object is truncated"))?;
+        if marker >> 4 != 0x1 {
+            return Err(self.error("expected an int for an extended length"));
+        }
+        let width = 1 << (marker & 0x0f);
+        let len = be_uint(self.slice(offset + 2, width)?) as usize;
+        Ok((len, offset + 2 + width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromPlist {})
+    }
+
+    #[test]
+    fn parses_every_xml_scalar() {
+        let value = from_xml_plist(
+            r#"<plist version="1.0"><array>
+                <string>hi</string>
+                <integer>-2</integer>
+                <real>1.5</real>
+                <true/>
+                <false/>
+                <data>aGk=</data>
+            </array></plist>"#,
+            Span::test_data(),
+        )
+        .expect("valid plist");
+
+        assert_eq!(
+            value,
+            Value::list(
+                vec![
+                    Value::test_string("hi"),
+                    Value::test_int(-2),
+                    Value::test_float(1.5),
+                    Value::test_bool(true),
+                    Value::test_bool(false),
+                    Value::Binary {
+                        val: b"hi".to_vec(),
+                        span: Span::test_data(),
+                    },
+                ],
+                Span::test_data()
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_binary_plists() {
+        assert!(from_binary_plist(b"bplist00", Span::test_data()).is_err());
+    }
+}
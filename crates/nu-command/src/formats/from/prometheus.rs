@@ -0,0 +1,214 @@
+use indexmap::map::IndexMap;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct FromPrometheus;
+
+impl Command for FromPrometheus {
+    fn name(&self) -> &str {
+        "from prometheus"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from prometheus")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text in Prometheus/OpenMetrics exposition format and create table."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_prometheus(input, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: r#"'# TYPE http_requests_total counter
+http_requests_total{method="post",code="200"} 1027 1395066363000
+http_requests_total{method="post",code="400"} 3 1395066363000' | from prometheus"#,
+            description: "Converts metrics exposition text to table",
+            result: Some(Value::List {
+                vals: vec![
+                    Value::test_record(
+                        vec!["name", "labels", "value", "timestamp"],
+                        vec![
+                            Value::test_string("http_requests_total"),
+                            Value::test_record(
+                                vec!["method", "code"],
+                                vec![Value::test_string("post"), Value::test_string("200")],
+                            ),
+                            Value::test_float(1027.0),
+                            Value::test_int(1395066363000),
+                        ],
+                    ),
+                    Value::test_record(
+                        vec!["name", "labels", "value", "timestamp"],
+                        vec![
+                            Value::test_string("http_requests_total"),
+                            Value::test_record(
+                                vec!["method", "code"],
+                                vec![Value::test_string("post"), Value::test_string("400")],
+                            ),
+                            Value::test_float(3.0),
+                            Value::test_int(1395066363000),
+                        ],
+                    ),
+                ],
+                span: Span::test_data(),
+            }),
+        }]
+    }
+}
+
+fn from_prometheus(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let (input_string, span, metadata) = input.collect_string_strict(head)?;
+
+    let mut samples = vec![];
+    for line in input_string.lines() {
+        let line = line.trim();
+        // '#' introduces comments as well as HELP/TYPE metadata
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        samples.push(parse_sample(line, head, span)?);
+    }
+
+    Ok(Value::List {
+        vals: samples,
+        span: head,
+    }
+    .into_pipeline_data_with_metadata(metadata))
+}
+
+fn parse_sample(line: &str, head: Span, span: Span) -> Result<Value, ShellError> {
+    let parse_error = |reason: &str| {
+        ShellError::UnsupportedInput(
+            format!("input cannot be parsed as metrics exposition text ({reason}: {line:?})"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    };
+
+    let name_end = line
+        .find(|c: char| c == '{' || c.is_whitespace())
+        .ok_or_else(|| parse_error("sample has no value"))?;
+    let name = &line[..name_end];
+    if name.is_empty() {
+        return Err(parse_error("sample has no metric name"));
+    }
+
+    let (labels, rest) = if line[name_end..].starts_with('{') {
+        parse_labels(&line[name_end + 1..], head).ok_or_else(|| parse_error("unclosed labels"))?
+    } else {
+        (IndexMap::new(), &line[name_end..])
+    };
+
+    let mut parts = rest.split_whitespace();
+    let value = parts
+        .next()
+        .ok_or_else(|| parse_error("sample has no value"))?;
+    let value = parse_value(value, head).ok_or_else(|| parse_error("invalid sample value"))?;
+    let timestamp = match parts.next() {
+        Some(ts) => Value::Int {
+            val: ts
+                .parse()
+                .map_err(|_| parse_error("invalid sample timestamp"))?,
+            span: head,
+        },
+        None => Value::nothing(head),
+    };
+
+    let mut record = IndexMap::new();
+    record.insert("name".to_string(), Value::string(name, head));
+    record.insert(
+        "labels".to_string(),
+        Value::from(Spanned {
+            item: labels,
+            span: head,
+        }),
+    );
+    record.insert("value".to_string(), value);
+    record.insert("timestamp".to_string(), timestamp);
+    Ok(Value::from(Spanned {
+        item: record,
+        span: head,
+    }))
+}
+
+// Parses 'name="value",...}' (the text after the opening brace), returning the
+// labels and the rest of the line; label values may escape '\', '"' and '\n'.
+fn parse_labels(text: &str, head: Span) -> Option<(IndexMap<String, Value>, &str)> {
+    let mut labels = IndexMap::new();
+    let mut chars = text.char_indices();
+
+    'pairs: loop {
+        let mut name = String::new();
+        loop {
+            match chars.next()? {
+                (at, '}') if name.trim().is_empty() => return Some((labels, &text[at + 1..])),
+                (_, '=') => break,
+                (_, c) => name.push(c),
+            }
+        }
+        chars.next().filter(|(_, c)| *c == '"')?;
+
+        let mut value = String::new();
+        loop {
+            match chars.next()? {
+                (_, '\\') => match chars.next()? {
+                    (_, 'n') => value.push('\n'),
+                    (_, c) => value.push(c),
+                },
+                (_, '"') => break,
+                (_, c) => value.push(c),
+            }
+        }
+        labels.insert(name.trim().to_string(), Value::string(value, head));
+
+        loop {
+            match chars.next()? {
+                (at, '}') => return Some((labels, &text[at + 1..])),
+                (_, ',') => continue 'pairs,
+                (_, c) if c.is_whitespace() => continue,
+                _ => return None,
+            }
+        }
+    }
+}
+
+fn parse_value(text: &str, head: Span) -> Option<Value> {
+    let val = match text {
+        "+Inf" => f64::INFINITY,
+        "-Inf" => f64::NEG_INFINITY,
+        "NaN" => f64::NAN,
+        _ => text.parse().ok()?,
+    };
+    Some(Value::Float { val, span: head })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromPrometheus {})
+    }
+}
@@ -0,0 +1,184 @@
+use indexmap::map::IndexMap;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct FromProperties;
+
+impl Command for FromProperties {
+    fn name(&self) -> &str {
+        "from properties"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from properties")
+            .input_output_types(vec![(Type::String, Type::Record(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as Java .properties and create record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Keys and values are split on the first unescaped '=' or ':', lines
+ending in '\' continue on the next line, and \t, \n, \r, \\ and \uXXXX
+escapes are decoded. Comment lines start with '#' or '!'. A duplicate
+key becomes a list of its values, like 'from ini'."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let (input_string, _span, metadata) = input.collect_string_strict(head)?;
+        let value = from_properties_string(&input_string, head);
+        Ok(value.into_pipeline_data_with_metadata(metadata))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: r#"'user = admin
+retries: 3
+motd = hello!' | from properties"#,
+            description: "Converts Java properties formatted string to record",
+            result: Some(Value::test_record(
+                vec!["user", "retries", "motd"],
+                vec![
+                    Value::test_string("admin"),
+                    Value::test_string("3"),
+                    Value::test_string("hello!"),
+                ],
+            )),
+        }]
+    }
+}
+
+fn from_properties_string(text: &str, span: Span) -> Value {
+    let mut record: IndexMap<String, Value> = IndexMap::new();
+
+    let mut lines = text.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim_start();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+
+        // Join continuation lines before splitting key from value
+        let mut logical = line.to_string();
+        while ends_with_odd_backslashes(&logical) {
+            logical.pop();
+            match lines.next() {
+                Some(next) => logical.push_str(next.trim_start()),
+                None => break,
+            }
+        }
+
+        let (key, value) = split_key_value(&logical);
+        let key = unescape(key.trim_end());
+        let value = Value::string(unescape(value.trim_start()), span);
+
+        match record.get_mut(&key) {
+            Some(Value::List { vals, .. }) => vals.push(value),
+            Some(existing) => {
+                let first = existing.clone();
+                *existing = Value::list(vec![first, value], span);
+            }
+            None => {
+                record.insert(key, value);
+            }
+        }
+    }
+
+    Value::from(Spanned { item: record, span })
+}
+
+fn ends_with_odd_backslashes(line: &str) -> bool {
+    line.chars().rev().take_while(|c| *c == '\\').count() % 2 == 1
+}
+
+fn split_key_value(line: &str) -> (&str, &str) {
+    let mut escaped = false;
+    for (at, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' => escaped = true,
+            '=' | ':' => return (&line[..at], &line[at + c.len_utf8()..]),
+            _ => {}
+        }
+    }
+    (line, "")
+}
+
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('f') => out.push('\u{c}'),
+            Some('u') => {
+                let code: String = chars.by_ref().take(4).collect();
+                match u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                    Some(decoded) => out.push(decoded),
+                    None => {
+                        out.push_str("\\u");
+                        out.push_str(&code);
+                    }
+                }
+            }
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromProperties {})
+    }
+
+    #[test]
+    fn continuation_lines_are_joined() {
+        let value = from_properties_string("key = one \\\n    two\n", Span::test_data());
+
+        assert_eq!(
+            value,
+            Value::test_record(vec!["key"], vec![Value::test_string("one two")])
+        );
+    }
+
+    #[test]
+    fn escaped_separators_stay_in_the_key() {
+        let value = from_properties_string("a\\=b = c\n", Span::test_data());
+
+        assert_eq!(
+            value,
+            Value::test_record(vec!["a=b"], vec![Value::test_string("c")])
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDateTime, TimeZone, Utc};
+use indexmap::map::IndexMap;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct FromSyslog;
+
+impl Command for FromSyslog {
+    fn name(&self) -> &str {
+        "from syslog"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("from syslog")
+            .input_output_types(vec![(Type::String, Type::Table(vec![]))])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Parse text as syslog (RFC 5424 or the older RFC 3164) and create table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "RFC 3164 timestamps carry no year or timezone; they are read as UTC in the current year."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["logging", "rfc5424", "rfc3164"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        from_syslog(input, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: r#"'<34>1 2003-10-11T22:14:15.003Z mymachine.example.com su 123 ID47 - su root failed on /dev/pts/8' | from syslog"#,
+            description: "Parse an RFC 5424 syslog line",
+            result: Some(Value::List {
+                vals: vec![Value::test_record(
+                    vec![
+                        "timestamp",
+                        "host",
+                        "app",
+                        "pid",
+                        "facility",
+                        "severity",
+                        "message",
+                    ],
+                    vec![
+                        Value::Date {
+                            val: DateTime::parse_from_rfc3339("2003-10-11T22:14:15.003Z")
+                                .expect("valid rfc3339 timestamp"),
+                            span: Span::test_data(),
+                        },
+                        Value::test_string("mymachine.example.com"),
+                        Value::test_string("su"),
+                        Value::test_int(123),
+                        Value::test_string("auth"),
+                        Value::test_string("crit"),
+                        Value::test_string("su root failed on /dev/pts/8"),
+                    ],
+                )],
+                span: Span::test_data(),
+            }),
+        }]
+    }
+}
+
+const SEVERITIES: [&str; 8] = [
+    "emerg", "alert", "crit", "err", "warning", "notice", "info", "debug",
+];
+
+const FACILITIES: [&str; 24] = [
+    "kern", "user", "mail", "daemon", "auth", "syslog", "lpr", "news", "uucp", "cron", "authpriv",
+    "ftp", "ntp", "audit", "alert", "clock", "local0", "local1", "local2", "local3", "local4",
+    "local5", "local6", "local7",
+];
+
+fn from_syslog(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+    let (input_string, span, metadata) = input.collect_string_strict(head)?;
+
+    let mut messages = vec![];
+    for line in input_string.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        messages.push(parse_message(line, head, span)?);
+    }
+
+    Ok(Value::List {
+        vals: messages,
+        span: head,
+    }
+    .into_pipeline_data_with_metadata(metadata))
+}
+
+fn parse_message(line: &str, head: Span, span: Span) -> Result<Value, ShellError> {
+    let parse_error = |reason: &str| {
+        ShellError::UnsupportedInput(
+            format!("input cannot be parsed as syslog ({reason}: {line:?})"),
+            "value originates from here".into(),
+            head,
+            span,
+        )
+    };
+
+    let (pri, rest) = match line.strip_prefix('<') {
+        Some(rest) => {
+            let (pri, rest) = rest
+                .split_once('>')
+                .ok_or_else(|| parse_error("unclosed priority"))?;
+            let pri: u8 = pri
+                .parse()
+                .map_err(|_| parse_error("invalid priority value"))?;
+            (Some(pri), rest)
+        }
+        None => (None, line),
+    };
+
+    let fields = match rest.strip_prefix("1 ") {
+        Some(rest) => parse_rfc5424(rest, head).ok_or_else(|| parse_error("truncated message"))?,
+        None => parse_rfc3164(rest, head).ok_or_else(|| parse_error("invalid timestamp"))?,
+    };
+
+    let mut record = IndexMap::new();
+    record.insert("timestamp".to_string(), fields.timestamp);
+    record.insert("host".to_string(), fields.host);
+    record.insert("app".to_string(), fields.app);
+    record.insert("pid".to_string(), fields.pid);
+    let (facility, severity) = match pri {
+        Some(pri) => (
+            match FACILITIES.get(pri as usize / 8) {
+                Some(facility) => Value::string(*facility, head),
+                None => Value::nothing(head),
+            },
+            Value::string(SEVERITIES[pri as usize % 8], head),
+        ),
+        None => (Value::nothing(head), Value::nothing(head)),
+    };
+    record.insert("facility".to_string(), facility);
+    record.insert("severity".to_string(), severity);
+    record.insert("message".to_string(), fields.message);
+    Ok(Value::from(Spanned {
+        item: record,
+        span: head,
+    }))
+}
+
+struct Fields {
+    timestamp: Value,
+    host: Value,
+    app: Value,
+    pid: Value,
+    message: Value,
+}
+
+// '-' stands for an absent field in RFC 5424
+fn nillable(field: &str, head: Span) -> Value {
+    if field == "-" {
+        Value::nothing(head)
+    } else {
+        Value::string(field, head)
+    }
+}
+
+// VERSION was already consumed: TIMESTAMP HOSTNAME APP-NAME PROCID MSGID STRUCTURED-DATA MSG
+fn parse_rfc5424(rest: &str, head: Span) -> Option<Fields> {
+    let mut parts = rest.splitn(6, ' ');
+    let timestamp = parts.next()?;
+    let timestamp = if timestamp == "-" {
+        Value::nothing(head)
+    } else {
+        Value::Date {
+            val: DateTime::parse_from_rfc3339(timestamp).ok()?,
+            span: head,
+        }
+    };
+    let host = nillable(parts.next()?, head);
+    let app = nillable(parts.next()?, head);
+    let pid = match parts.next()? {
+        "-" => Value::nothing(head),
+        pid => match pid.parse() {
+            Ok(val) => Value::Int { val, span: head },
+            Err(_) => Value::string(pid, head),
+        },
+    };
+    let _msgid = parts.next()?;
+    let message = skip_structured_data(parts.next()?)?;
+
+    Some(Fields {
+        timestamp,
+        host,
+        app,
+        pid,
+        message: Value::string(message.trim_start(), head),
+    })
+}
+
+// Steps over '-' or one or more '[id param="value" ...]' groups and returns the message
+fn skip_structured_data(rest: &str) -> Option<&str> {
+    if let Some(message) = rest.strip_prefix("- ") {
+        return Some(message);
+    }
+    if rest == "-" {
+        return Some("");
+    }
+    let mut chars = rest.char_indices();
+    loop {
+        chars.next().filter(|(_, c)| *c == '[')?;
+        let mut quoted = false;
+        loop {
+            match chars.next()? {
+                (_, '\\') if quoted => {
+                    chars.next()?;
+                }
+                (_, '"') => quoted = !quoted,
+                (_, ']') if !quoted => break,
+                _ => {}
+            }
+        }
+        match chars.clone().next() {
+            None => return Some(""),
+            // another SD element follows; re-enter the loop on it
+            Some((_, '[')) => continue,
+            Some((at, _)) => return Some(&rest[at..]),
+        }
+    }
+}
+
+// TIMESTAMP(15 chars, 'Mmm dd hh:mm:ss') HOSTNAME TAG[PID]: MSG
+fn parse_rfc3164(rest: &str, head: Span) -> Option<Fields> {
+    if rest.len() < 16 {
+        return None;
+    }
+    let naive = NaiveDateTime::parse_from_str(
+        &format!("{} {}", Local::now().year(), &rest[..15]),
+        "%Y %b %e %H:%M:%S",
+    )
+    .ok()?;
+    let timestamp = Value::Date {
+        val: Utc
+            .from_utc_datetime(&naive)
+            .with_timezone(&FixedOffset::east_opt(0)?),
+        span: head,
+    };
+
+    let rest = rest[15..].trim_start();
+    let (host, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+    let (tag, message) = match rest.split_once(':') {
+        Some((tag, message)) if !tag.contains(' ') => (tag, message.trim_start()),
+        _ => ("", rest),
+    };
+    let (app, pid) = match tag.split_once('[') {
+        Some((app, pid)) => match pid.strip_suffix(']').and_then(|pid| pid.parse().ok()) {
+            Some(val) => (app, Value::Int { val, span: head }),
+            None => (tag, Value::nothing(head)),
+        },
+        None => (tag, Value::nothing(head)),
+    };
+
+    Some(Fields {
+        timestamp,
+        host: Value::string(host, head),
+        app: if app.is_empty() {
+            Value::nothing(head)
+        } else {
+            Value::string(app, head)
+        },
+        pid,
+        message: Value::string(message, head),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FromSyslog {})
+    }
+}
@@ -3,6 +3,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct FromToml;
@@ -28,7 +29,7 @@ impl Command for FromToml {
                 example: "'a = 1' | from toml",
                 description: "Converts toml formatted string to record",
                 result: Some(Value::Record {
-                    cols: vec!["a".to_string()],
+                    cols: Arc::new(vec!["a".to_string()]),
                     vals: vec![Value::test_int(1)],
                     span: Span::test_data(),
                 }),
@@ -38,7 +39,7 @@ impl Command for FromToml {
 b = [1, 2]' | from toml",
                 description: "Converts toml formatted string to record",
                 result: Some(Value::Record {
-                    cols: vec!["a".to_string(), "b".to_string()],
+                    cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                     vals: vec![
                         Value::test_int(1),
                         Value::List {
@@ -88,7 +89,11 @@ fn convert_toml_to_value(value: &toml::Value, span: Span) -> Value {
                 vals.push(convert_toml_to_value(item.1, span));
             }
 
-            Value::Record { cols, vals, span }
+            Value::Record {
+                cols: Arc::new(cols),
+                vals,
+                span,
+            }
         }
         toml::Value::String(s) => Value::String {
             val: s.clone(),
@@ -1,6 +1,7 @@
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct FromUrl;
@@ -36,12 +37,12 @@ impl Command for FromUrl {
             example: "'bread=baguette&cheese=comt%C3%A9&meat=ham&fat=butter' | from url",
             description: "Convert url encoded string into a record",
             result: Some(Value::Record {
-                cols: vec![
+                cols: Arc::new(vec![
                     "bread".to_string(),
                     "cheese".to_string(),
                     "meat".to_string(),
                     "fat".to_string(),
-                ],
+                ]),
                 vals: vec![
                     Value::test_string("baguette"),
                     Value::test_string("comté"),
@@ -70,7 +71,7 @@ fn from_url(input: PipelineData, head: Span) -> Result<PipelineData, ShellError>
 
             Ok(PipelineData::Value(
                 Value::Record {
-                    cols,
+                    cols: Arc::new(cols),
                     vals,
                     span: head,
                 },
@@ -7,6 +7,7 @@ use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 use std::io::Cursor;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct FromXlsx;
@@ -161,7 +162,7 @@ fn from_xlsx(
                         });
 
                 let record = Value::Record {
-                    cols,
+                    cols: Arc::new(cols),
                     vals,
                     span: head,
                 };
@@ -193,7 +194,7 @@ fn from_xlsx(
     });
 
     let record = Value::Record {
-        cols,
+        cols: Arc::new(cols),
         vals,
         span: head,
     };
@@ -7,6 +7,7 @@ use nu_protocol::{
     Value,
 };
 use roxmltree::NodeType;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct FromXml;
@@ -25,6 +26,11 @@ impl Command for FromXml {
                 "add processing instruction nodes to result",
                 None,
             )
+            .switch(
+                "keep-ns",
+                "keep namespace prefixes on tag and attribute names, and xmlns declarations in attributes",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -54,10 +60,12 @@ string. This way content of every tag is always a table and is easier to parse"#
         let head = call.head;
         let keep_comments = call.has_flag("keep-comments");
         let keep_processing_instructions = call.has_flag("keep-pi");
+        let keep_namespaces = call.has_flag("keep-ns");
         let info = ParsingInfo {
             span: head,
             keep_comments,
             keep_processing_instructions,
+            keep_namespaces,
         };
         from_xml(input, &info)
     }
@@ -109,12 +117,45 @@ struct ParsingInfo {
     span: Span,
     keep_comments: bool,
     keep_processing_instructions: bool,
+    keep_namespaces: bool,
 }
 
-fn from_attributes_to_value(attributes: &[roxmltree::Attribute], info: &ParsingInfo) -> Value {
+// Reattach the namespace prefix the document used for this name
+fn prefixed_name(namespace: Option<&str>, local: &str, n: &roxmltree::Node) -> String {
+    match namespace.and_then(|uri| n.lookup_prefix(uri)) {
+        Some(prefix) if !prefix.is_empty() => format!("{prefix}:{local}"),
+        _ => String::from(local),
+    }
+}
+
+fn from_attributes_to_value(n: &roxmltree::Node, info: &ParsingInfo) -> Value {
     let mut collected = IndexMap::new();
-    for a in attributes {
-        collected.insert(String::from(a.name()), Value::string(a.value(), info.span));
+    if info.keep_namespaces {
+        // Re-emit the xmlns declarations made on this element, which
+        // roxmltree does not report as ordinary attributes. namespaces()
+        // is the in-scope set, so skip whatever the parent already has.
+        for ns in n.namespaces() {
+            let declared_by_parent = n.parent_element().map_or(false, |parent| {
+                parent
+                    .namespaces()
+                    .any(|p| p.name() == ns.name() && p.uri() == ns.uri())
+            });
+            if !declared_by_parent {
+                let key = match ns.name() {
+                    Some(prefix) => format!("xmlns:{prefix}"),
+                    None => String::from("xmlns"),
+                };
+                collected.insert(key, Value::string(ns.uri(), info.span));
+            }
+        }
+    }
+    for a in n.attributes() {
+        let name = if info.keep_namespaces {
+            prefixed_name(a.namespace(), a.name(), n)
+        } else {
+            String::from(a.name())
+        };
+        collected.insert(name, Value::string(a.value(), info.span));
     }
 
     let (cols, vals) = collected
@@ -126,7 +167,7 @@ fn from_attributes_to_value(attributes: &[roxmltree::Attribute], info: &ParsingI
         });
 
     Value::Record {
-        cols,
+        cols: Arc::new(cols),
         vals,
         span: info.span,
     }
@@ -136,7 +177,11 @@ fn element_to_value(n: &roxmltree::Node, info: &ParsingInfo) -> Value {
     let span = info.span;
     let mut node = IndexMap::new();
 
-    let tag = n.tag_name().name().trim().to_string();
+    let tag = if info.keep_namespaces {
+        prefixed_name(n.tag_name().namespace(), n.tag_name().name().trim(), n)
+    } else {
+        n.tag_name().name().trim().to_string()
+    };
     let tag = Value::string(tag, span);
 
     let content: Vec<Value> = n
@@ -145,7 +190,7 @@ fn element_to_value(n: &roxmltree::Node, info: &ParsingInfo) -> Value {
         .collect();
     let content = Value::list(content, span);
 
-    let attributes = from_attributes_to_value(&n.attributes().collect::<Vec<_>>(), info);
+    let attributes = from_attributes_to_value(n, info);
 
     node.insert(String::from(COLUMN_TAG_NAME), tag);
     node.insert(String::from(COLUMN_ATTRS_NAME), attributes);
@@ -154,6 +199,18 @@ fn element_to_value(n: &roxmltree::Node, info: &ParsingInfo) -> Value {
     Value::from(Spanned { item: node, span })
 }
 
+/// Convert a single element into the `from xml` record shape, for reuse by
+/// commands that select elements out of a parsed document (e.g. `query xml`).
+pub(crate) fn xml_element_to_value(n: &roxmltree::Node, span: Span) -> Value {
+    let info = ParsingInfo {
+        span,
+        keep_comments: false,
+        keep_processing_instructions: false,
+        keep_namespaces: false,
+    };
+    element_to_value(n, &info)
+}
+
 fn text_to_value(n: &roxmltree::Node, info: &ParsingInfo) -> Option<Value> {
     let span = info.span;
     let text = n.text().expect("Non-text node supplied to text_to_value");
@@ -386,6 +443,17 @@ mod tests {
             span: Span::test_data(),
             keep_comments: false,
             keep_processing_instructions: false,
+            keep_namespaces: false,
+        };
+        from_xml_string_to_value(xml.to_string(), &info)
+    }
+
+    fn parse_keep_ns(xml: &str) -> Result<Value, roxmltree::Error> {
+        let info = ParsingInfo {
+            span: Span::test_data(),
+            keep_comments: false,
+            keep_processing_instructions: false,
+            keep_namespaces: true,
         };
         from_xml_string_to_value(xml.to_string(), &info)
     }
@@ -491,6 +559,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn keep_ns_preserves_prefixes_and_declarations() -> Result<(), roxmltree::Error> {
+        let source = "<a:nu xmlns:a=\"http://example.com/a\" a:version=\"2.0\"><a:dev/></a:nu>";
+
+        assert_eq!(
+            parse_keep_ns(source)?,
+            content_tag(
+                "a:nu",
+                indexmap! {"xmlns:a" => "http://example.com/a", "a:version" => "2.0"},
+                &[content_tag("a:dev", indexmap! {}, &[])]
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn keep_ns_emits_default_declaration_only_where_declared() -> Result<(), roxmltree::Error> {
+        let source = "<nu xmlns=\"http://example.com/a\"><dev/></nu>";
+
+        assert_eq!(
+            parse_keep_ns(source)?,
+            content_tag(
+                "nu",
+                indexmap! {"xmlns" => "http://example.com/a"},
+                &[content_tag("dev", indexmap! {}, &[])]
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn without_keep_ns_prefixes_are_stripped() -> Result<(), roxmltree::Error> {
+        let source = "<a:nu xmlns:a=\"http://example.com/a\" a:version=\"2.0\"></a:nu>";
+
+        assert_eq!(
+            parse(source)?,
+            content_tag("nu", indexmap! {"version" => "2.0"}, &[])
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_examples() {
         use crate::test_examples;
@@ -7,6 +7,7 @@ use nu_protocol::{
 };
 use serde::de::Deserialize;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct FromYaml;
@@ -19,6 +20,11 @@ impl Command for FromYaml {
     fn signature(&self) -> Signature {
         Signature::build("from yaml")
             .input_output_types(vec![(Type::String, Type::Any)])
+            .switch(
+                "docs",
+                "always return a list with one entry per document",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -26,6 +32,12 @@ impl Command for FromYaml {
         "Parse text as .yaml/.yml and create table."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"A multi-document stream comes back as a list of documents; pass --docs
+to get a list even when there is a single document. Anchors and aliases
+are resolved on read: an alias becomes a copy of the anchored value."#
+    }
+
     fn examples(&self) -> Vec<Example> {
         get_examples()
     }
@@ -38,7 +50,7 @@ impl Command for FromYaml {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
-        from_yaml(input, head)
+        from_yaml(input, call.has_flag("docs"), head)
     }
 }
 
@@ -53,6 +65,11 @@ impl Command for FromYml {
     fn signature(&self) -> Signature {
         Signature::build("from yml")
             .input_output_types(vec![(Type::String, Type::Any)])
+            .switch(
+                "docs",
+                "always return a list with one entry per document",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -68,7 +85,7 @@ impl Command for FromYml {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
-        from_yaml(input, head)
+        from_yaml(input, call.has_flag("docs"), head)
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -205,6 +222,7 @@ fn convert_yaml_value_to_nu_value(
 
 pub fn from_yaml_string_to_value(
     s: String,
+    docs: bool,
     span: Span,
     val_span: Span,
 ) -> Result<Value, ShellError> {
@@ -222,6 +240,12 @@ pub fn from_yaml_string_to_value(
         documents.push(convert_yaml_value_to_nu_value(&v, span, val_span)?);
     }
 
+    if docs {
+        return Ok(Value::List {
+            vals: documents,
+            span,
+        });
+    }
     match documents.len() {
         0 => Ok(Value::nothing(span)),
         1 => Ok(documents.remove(0)),
@@ -238,23 +262,45 @@ pub fn get_examples() -> Vec<Example<'static>> {
             example: "'a: 1' | from yaml",
             description: "Converts yaml formatted string to table",
             result: Some(Value::Record {
-                cols: vec!["a".to_string()],
+                cols: Arc::new(vec!["a".to_string()]),
                 vals: vec![Value::test_int(1)],
                 span: Span::test_data(),
             }),
         },
+        Example {
+            example: "'---
+a: 1
+---
+a: 2' | from yaml --docs",
+            description: "Converts a multi-document yaml stream to a list of documents",
+            result: Some(Value::List {
+                vals: vec![
+                    Value::Record {
+                        cols: Arc::new(vec!["a".to_string()]),
+                        vals: vec![Value::test_int(1)],
+                        span: Span::test_data(),
+                    },
+                    Value::Record {
+                        cols: Arc::new(vec!["a".to_string()]),
+                        vals: vec![Value::test_int(2)],
+                        span: Span::test_data(),
+                    },
+                ],
+                span: Span::test_data(),
+            }),
+        },
         Example {
             example: "'[ a: 1, b: [1, 2] ]' | from yaml",
             description: "Converts yaml formatted string to table",
             result: Some(Value::List {
                 vals: vec![
                     Value::Record {
-                        cols: vec!["a".to_string()],
+                        cols: Arc::new(vec!["a".to_string()]),
                         vals: vec![Value::test_int(1)],
                         span: Span::test_data(),
                     },
                     Value::Record {
-                        cols: vec!["b".to_string()],
+                        cols: Arc::new(vec!["b".to_string()]),
                         vals: vec![Value::List {
                             vals: vec![Value::test_int(1), Value::test_int(2)],
                             span: Span::test_data(),
@@ -268,10 +314,10 @@ pub fn get_examples() -> Vec<Example<'static>> {
     ]
 }
 
-fn from_yaml(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+fn from_yaml(input: PipelineData, docs: bool, head: Span) -> Result<PipelineData, ShellError> {
     let (concat_string, span, metadata) = input.collect_string_strict(head)?;
 
-    match from_yaml_string_to_value(concat_string, head, span) {
+    match from_yaml_string_to_value(concat_string, docs, head, span) {
         Ok(x) => Ok(x.into_pipeline_data_with_metadata(metadata)),
         Err(other) => Err(other),
     }
@@ -294,7 +340,7 @@ mod test {
                 description: "Double Curly Braces With Quotes",
                 input: r#"value: "{{ something }}""#,
                 expected: Ok(Value::Record {
-                    cols: vec!["value".to_string()],
+                    cols: Arc::new(vec!["value".to_string()]),
                     vals: vec![Value::test_string("{{ something }}")],
                     span: Span::test_data(),
                 }),
@@ -303,7 +349,7 @@ mod test {
                 description: "Double Curly Braces Without Quotes",
                 input: r#"value: {{ something }}"#,
                 expected: Ok(Value::Record {
-                    cols: vec!["value".to_string()],
+                    cols: Arc::new(vec!["value".to_string()]),
                     vals: vec![Value::test_string("{{ something }}")],
                     span: Span::test_data(),
                 }),
@@ -313,6 +359,7 @@ mod test {
         for tc in tt {
             let actual = from_yaml_string_to_value(
                 tc.input.to_owned(),
+                false,
                 Span::test_data(),
                 Span::test_data(),
             );
@@ -339,6 +386,25 @@ mod test {
         test_examples(FromYaml {})
     }
 
+    #[test]
+    fn docs_flag_wraps_a_single_document_in_a_list() {
+        let actual = from_yaml_string_to_value(
+            "a: 1".to_string(),
+            true,
+            Span::test_data(),
+            Span::test_data(),
+        )
+        .expect("valid yaml");
+
+        assert_eq!(
+            actual,
+            Value::list(
+                vec![Value::test_record(vec!["a"], vec![Value::test_int(1)])],
+                Span::test_data()
+            )
+        );
+    }
+
     #[test]
     fn test_convert_yaml_value_to_nu_value_for_tagged_values() {
         struct TestCase {
@@ -350,7 +416,7 @@ mod test {
             TestCase {
                 input: "Key: !Value ${TEST}-Test-role",
                 expected: Ok(Value::Record {
-                    cols: vec!["Key".to_string()],
+                    cols: Arc::new(vec!["Key".to_string()]),
                     vals: vec![Value::test_string("!Value ${TEST}-Test-role")],
                     span: Span::test_data(),
                 }),
@@ -358,7 +424,7 @@ mod test {
             TestCase {
                 input: "Key: !Value test-${TEST}",
                 expected: Ok(Value::Record {
-                    cols: vec!["Key".to_string()],
+                    cols: Arc::new(vec!["Key".to_string()]),
                     vals: vec![Value::test_string("!Value test-${TEST}")],
                     span: Span::test_data(),
                 }),
@@ -366,7 +432,7 @@ mod test {
             TestCase {
                 input: "Key: !Value",
                 expected: Ok(Value::Record {
-                    cols: vec!["Key".to_string()],
+                    cols: Arc::new(vec!["Key".to_string()]),
                     vals: vec![Value::test_string("!Value")],
                     span: Span::test_data(),
                 }),
@@ -374,7 +440,7 @@ mod test {
             TestCase {
                 input: "Key: !True",
                 expected: Ok(Value::Record {
-                    cols: vec!["Key".to_string()],
+                    cols: Arc::new(vec!["Key".to_string()]),
                     vals: vec![Value::test_string("!True")],
                     span: Span::test_data(),
                 }),
@@ -382,7 +448,7 @@ mod test {
             TestCase {
                 input: "Key: !123",
                 expected: Ok(Value::Record {
-                    cols: vec!["Key".to_string()],
+                    cols: Arc::new(vec!["Key".to_string()]),
                     vals: vec![Value::test_string("!123")],
                     span: Span::test_data(),
                 }),
@@ -1,4 +1,4 @@
-use crate::formats::to::delimited::to_delimited_data;
+use crate::formats::to::delimited::{to_delimited_data, DelimitedOptions};
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
@@ -32,6 +32,20 @@ impl Command for ToCsv {
                 "do not output the columns names as the first row",
                 Some('n'),
             )
+            .named(
+                "quoting",
+                SyntaxShape::String,
+                "quoting style: always, minimal (default), or never",
+                Some('q'),
+            )
+            .switch("bom", "output a UTF-8 byte order mark", None)
+            .switch("crlf", "use CRLF (\\r\\n) line endings", None)
+            .named(
+                "null",
+                SyntaxShape::String,
+                "the text to output for null values, defaults to an empty cell",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -52,6 +66,16 @@ impl Command for ToCsv {
                 example: "{a: 1 b: 2} | to csv",
                 result: Some(Value::test_string("a,b\n1,2\n")),
             },
+            Example {
+                description: "Outputs an CSV string quoting every field",
+                example: "[[foo bar]; [1 2]] | to csv --quoting always",
+                result: Some(Value::test_string("\"foo\",\"bar\"\n\"1\",\"2\"\n")),
+            },
+            Example {
+                description: "Outputs an CSV string with a custom null representation",
+                example: "[[foo bar]; [1 null]] | to csv --null NA",
+                result: Some(Value::test_string("foo,bar\n1,NA\n")),
+            },
         ]
     }
 
@@ -69,8 +93,9 @@ impl Command for ToCsv {
         let head = call.head;
         let noheaders = call.has_flag("noheaders");
         let separator: Option<Spanned<String>> = call.get_flag(engine_state, stack, "separator")?;
+        let options = DelimitedOptions::from_call(engine_state, stack, call)?;
         let config = engine_state.get_config();
-        to_csv(input, noheaders, separator, head, config)
+        to_csv(input, noheaders, separator, options, head, config)
     }
 }
 
@@ -78,6 +103,7 @@ fn to_csv(
     input: PipelineData,
     noheaders: bool,
     separator: Option<Spanned<String>>,
+    options: DelimitedOptions,
     head: Span,
     config: &Config,
 ) -> Result<PipelineData, ShellError> {
@@ -100,7 +126,7 @@ fn to_csv(
         _ => ',',
     };
 
-    to_delimited_data(noheaders, sep, "CSV", input, head, config)
+    to_delimited_data(noheaders, sep, options, "CSV", input, head, config)
 }
 
 #[cfg(test)]
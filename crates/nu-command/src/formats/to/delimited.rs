@@ -1,20 +1,95 @@
-use csv::{Writer, WriterBuilder};
+use csv::{QuoteStyle, Terminator, Writer, WriterBuilder};
 use indexmap::{indexset, IndexSet};
-use nu_protocol::{Config, IntoPipelineData, PipelineData, ShellError, Span, Value};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{Config, IntoPipelineData, PipelineData, ShellError, Span, Spanned, Value};
 use std::collections::VecDeque;
 use std::error::Error;
 
+// Writer knobs shared by `to csv` and `to tsv`, mostly there to satisfy
+// Excel and legacy ingestion tools.
+#[derive(Clone)]
+pub struct DelimitedOptions {
+    pub quote_style: QuoteStyle,
+    pub crlf: bool,
+    pub bom: bool,
+    pub null_value: String,
+}
+
+impl Default for DelimitedOptions {
+    fn default() -> Self {
+        DelimitedOptions {
+            quote_style: QuoteStyle::Necessary,
+            crlf: false,
+            bom: false,
+            null_value: String::new(),
+        }
+    }
+}
+
+impl DelimitedOptions {
+    // Reads the writer flags shared by `to csv` and `to tsv`.
+    pub fn from_call(
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+    ) -> Result<DelimitedOptions, ShellError> {
+        let quoting: Option<Spanned<String>> = call.get_flag(engine_state, stack, "quoting")?;
+        let quote_style = match &quoting {
+            Some(Spanned { item, span }) => match item.as_str() {
+                "always" => QuoteStyle::Always,
+                "minimal" => QuoteStyle::Necessary,
+                "never" => QuoteStyle::Never,
+                other => {
+                    return Err(ShellError::TypeMismatch {
+                        err_message: format!(
+                            "Expected 'always', 'minimal' or 'never' from --quoting, got '{other}'"
+                        ),
+                        span: *span,
+                    });
+                }
+            },
+            None => QuoteStyle::Necessary,
+        };
+
+        Ok(DelimitedOptions {
+            quote_style,
+            crlf: call.has_flag("crlf"),
+            bom: call.has_flag("bom"),
+            null_value: call
+                .get_flag(engine_state, stack, "null")?
+                .unwrap_or_default(),
+        })
+    }
+}
+
+fn delimited_writer(separator: char, options: &DelimitedOptions) -> Writer<Vec<u8>> {
+    WriterBuilder::new()
+        .delimiter(separator as u8)
+        .quote_style(options.quote_style)
+        .terminator(if options.crlf {
+            Terminator::CRLF
+        } else {
+            Terminator::Any(b'\n')
+        })
+        .from_writer(vec![])
+}
+
 fn from_value_to_delimited_string(
     value: &Value,
     separator: char,
+    options: &DelimitedOptions,
     config: &Config,
     head: Span,
 ) -> Result<String, ShellError> {
     match value {
         Value::Record { cols, vals, span } => {
-            record_to_delimited(cols, vals, span, separator, config, head)
+            record_to_delimited(cols, vals, span, separator, options, config, head)
+        }
+        Value::List { vals, span } => {
+            table_to_delimited(vals, span, separator, options, config, head)
         }
-        Value::List { vals, span } => table_to_delimited(vals, span, separator, config, head),
         // Propagate errors by explicitly matching them before the final case.
         Value::Error { error } => Err(*error.clone()),
         v => Err(make_unsupported_input_error(v, head, v.expect_span())),
@@ -26,19 +101,18 @@ fn record_to_delimited(
     vals: &[Value],
     span: &Span,
     separator: char,
+    options: &DelimitedOptions,
     config: &Config,
     head: Span,
 ) -> Result<String, ShellError> {
-    let mut wtr = WriterBuilder::new()
-        .delimiter(separator as u8)
-        .from_writer(vec![]);
+    let mut wtr = delimited_writer(separator, options);
     let mut fields: VecDeque<String> = VecDeque::new();
     let mut values: VecDeque<String> = VecDeque::new();
 
     for (k, v) in cols.iter().zip(vals.iter()) {
         fields.push_back(k.clone());
 
-        values.push_back(to_string_tagged_value(v, config, head, *span)?);
+        values.push_back(to_string_tagged_value(v, options, config, head, *span)?);
     }
 
     wtr.write_record(fields).expect("can not write.");
@@ -51,6 +125,7 @@ fn table_to_delimited(
     vals: &Vec<Value>,
     span: &Span,
     separator: char,
+    options: &DelimitedOptions,
     config: &Config,
     head: Span,
 ) -> Result<String, ShellError> {
@@ -58,9 +133,7 @@ fn table_to_delimited(
         return Err(make_unsupported_input_error(val, head, *span));
     }
 
-    let mut wtr = WriterBuilder::new()
-        .delimiter(separator as u8)
-        .from_writer(vec![]);
+    let mut wtr = delimited_writer(separator, options);
 
     let merged_descriptors = merge_descriptors(vals);
 
@@ -68,7 +141,8 @@ fn table_to_delimited(
         let vals = vals
             .iter()
             .map(|ele| {
-                to_string_tagged_value(ele, config, head, *span).unwrap_or_else(|_| String::new())
+                to_string_tagged_value(ele, options, config, head, *span)
+                    .unwrap_or_else(|_| String::new())
             })
             .collect::<Vec<_>>();
         wtr.write_record(vals).expect("can not write");
@@ -80,7 +154,7 @@ fn table_to_delimited(
             let mut row = vec![];
             for desc in &merged_descriptors {
                 row.push(match l.to_owned().get_data_by_key(desc) {
-                    Some(s) => to_string_tagged_value(&s, config, head, *span)?,
+                    Some(s) => to_string_tagged_value(&s, options, config, head, *span)?,
                     None => String::new(),
                 });
             }
@@ -105,6 +179,7 @@ fn make_conversion_error(type_from: &str, span: &Span) -> ShellError {
 
 fn to_string_tagged_value(
     v: &Value,
+    options: &DelimitedOptions,
     config: &Config,
     span: Span,
     head: Span,
@@ -120,7 +195,7 @@ fn to_string_tagged_value(
         | Value::CellPath { .. }
         | Value::Float { .. } => Ok(v.clone().into_abbreviated_string(config)),
         Value::Date { val, .. } => Ok(val.to_string()),
-        Value::Nothing { .. } => Ok(String::new()),
+        Value::Nothing { .. } => Ok(options.null_value.clone()),
         // Propagate existing errors
         Value::Error { error } => Err(*error.clone()),
         _ => Err(make_unsupported_input_error(v, head, span)),
@@ -147,7 +222,7 @@ pub fn merge_descriptors(values: &[Value]) -> Vec<String> {
     let mut seen: IndexSet<String> = indexset! {};
     for value in values {
         let data_descriptors = match value {
-            Value::Record { cols, .. } => cols.to_owned(),
+            Value::Record { cols, .. } => cols.to_vec(),
             _ => vec!["".to_string()],
         };
         for desc in data_descriptors {
@@ -163,13 +238,14 @@ pub fn merge_descriptors(values: &[Value]) -> Vec<String> {
 pub fn to_delimited_data(
     noheaders: bool,
     sep: char,
+    options: DelimitedOptions,
     format_name: &'static str,
     input: PipelineData,
     span: Span,
     config: &Config,
 ) -> Result<PipelineData, ShellError> {
     let value = input.into_value(span);
-    let output = match from_value_to_delimited_string(&value, sep, config, span) {
+    let output = match from_value_to_delimited_string(&value, sep, &options, config, span) {
         Ok(mut x) => {
             if noheaders {
                 if let Some(second_line) = x.find('\n') {
@@ -177,6 +253,10 @@ pub fn to_delimited_data(
                     x.replace_range(0..start, "");
                 }
             }
+            // The BOM goes in front of whatever ends up being the first line.
+            if options.bom {
+                x.insert(0, '\u{feff}');
+            }
             Ok(x)
         }
         Err(_) => Err(ShellError::CantConvert {
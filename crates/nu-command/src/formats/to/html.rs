@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::Write;
+use std::sync::Arc;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct HtmlThemes {
@@ -103,6 +104,22 @@ impl Command for ToHtml {
                 "only output the html for the content itself",
                 Some('p'),
             )
+            .switch(
+                "styled",
+                "embed theme-derived css so the table itself is styled (borders, striped rows, colored header)",
+                Some('s'),
+            )
+            .named(
+                "escape",
+                SyntaxShape::String,
+                "how cell text is escaped: minimal (default), attribute, or none",
+                None,
+            )
+            .switch(
+                "expand",
+                "render nested records and tables as collapsible <details> blocks instead of abbreviating them",
+                Some('e'),
+            )
             .named(
                 "theme",
                 SyntaxShape::String,
@@ -162,6 +179,29 @@ impl Command for ToHtml {
     }
 }
 
+// How text ends up inside the generated markup.
+#[derive(Debug, Clone, Copy)]
+enum EscapeMode {
+    Minimal,
+    Attribute,
+    None,
+}
+
+// The per-call rendering options shared by html_table(), html_list() and html_value().
+#[derive(Debug, Clone, Copy)]
+struct HtmlOptions {
+    escape: EscapeMode,
+    expand: bool,
+}
+
+fn escape_html(text: &str, escape: EscapeMode) -> String {
+    match escape {
+        EscapeMode::Minimal => htmlescape::encode_minimal(text),
+        EscapeMode::Attribute => htmlescape::encode_attribute(text),
+        EscapeMode::None => text.to_string(),
+    }
+}
+
 fn get_theme_from_asset_file(
     is_dark: bool,
     theme: &Option<Spanned<String>>,
@@ -242,10 +282,32 @@ fn to_html(
     let no_color = call.has_flag("no-color");
     let dark = call.has_flag("dark");
     let partial = call.has_flag("partial");
+    let styled = call.has_flag("styled");
+    let expand = call.has_flag("expand");
     let list = call.has_flag("list");
     let theme: Option<Spanned<String>> = call.get_flag(engine_state, stack, "theme")?;
+    let escape: Option<Spanned<String>> = call.get_flag(engine_state, stack, "escape")?;
     let config = engine_state.get_config();
 
+    let escape = match &escape {
+        None => EscapeMode::Minimal,
+        Some(v) => match v.item.as_str() {
+            "minimal" => EscapeMode::Minimal,
+            "attribute" => EscapeMode::Attribute,
+            "none" => EscapeMode::None,
+            other => {
+                return Err(ShellError::GenericError(
+                    format!("unknown escape mode '{other}'"),
+                    "expected 'minimal', 'attribute' or 'none'".to_string(),
+                    Some(v.span),
+                    None,
+                    Vec::new(),
+                ))
+            }
+        },
+    };
+    let options = HtmlOptions { escape, expand };
+
     let vec_of_values = input.into_iter().collect::<Vec<Value>>();
     let headers = merge_descriptors(&vec_of_values);
     let headers = Some(headers)
@@ -310,7 +372,7 @@ fn to_html(
                 .collect();
 
                 Value::Record {
-                    cols: cols.clone(),
+                    cols: Arc::new(cols.clone()),
                     vals,
                     span: head,
                 }
@@ -343,29 +405,46 @@ fn to_html(
             }
         };
 
+        // css for the table itself, driven by the theme colors
+        let table_css = if styled {
+            format!(
+                "<style>table, th, td {{ border: 1px solid {fg}; border-collapse: collapse; padding: 4px; }} thead tr {{ background-color:{fg};color:{bg}; }} tbody tr:nth-child(even) {{ background-color: rgba(128, 128, 128, 0.2); }}</style>",
+                fg = color_hm
+                    .get("foreground")
+                    .expect("Error getting foreground color"),
+                bg = color_hm
+                    .get("background")
+                    .expect("Error getting background color"),
+            )
+        } else {
+            String::new()
+        };
+
         // change the color of the page
         if !partial {
             write!(
                 &mut output_string,
-                r"<html><style>body {{ background-color:{};color:{}; }}</style><body>",
+                r"<html><style>body {{ background-color:{};color:{}; }}</style>{}<body>",
                 color_hm
                     .get("background")
                     .expect("Error getting background color"),
                 color_hm
                     .get("foreground")
-                    .expect("Error getting foreground color")
+                    .expect("Error getting foreground color"),
+                table_css
             )
             .unwrap();
         } else {
             write!(
                 &mut output_string,
-                "<div style=\"background-color:{};color:{};\">",
+                "<div style=\"background-color:{};color:{};\">{}",
                 color_hm
                     .get("background")
                     .expect("Error getting background color"),
                 color_hm
                     .get("foreground")
-                    .expect("Error getting foreground color")
+                    .expect("Error getting foreground color"),
+                table_css
             )
             .unwrap();
         }
@@ -373,15 +452,15 @@ fn to_html(
         let inner_value = match vec_of_values.len() {
             0 => String::default(),
             1 => match headers {
-                Some(headers) => html_table(vec_of_values, headers, config),
+                Some(headers) => html_table(vec_of_values, headers, config, &options),
                 None => {
                     let value = &vec_of_values[0];
-                    html_value(value.clone(), config)
+                    html_value(value.clone(), config, &options)
                 }
             },
             _ => match headers {
-                Some(headers) => html_table(vec_of_values, headers, config),
-                None => html_list(vec_of_values, config),
+                Some(headers) => html_table(vec_of_values, headers, config, &options),
+                None => html_list(vec_of_values, config, &options),
             },
         };
 
@@ -405,19 +484,24 @@ fn to_html(
     Ok(Value::string(output_string, head).into_pipeline_data())
 }
 
-fn html_list(list: Vec<Value>, config: &Config) -> String {
+fn html_list(list: Vec<Value>, config: &Config, options: &HtmlOptions) -> String {
     let mut output_string = String::new();
     output_string.push_str("<ol>");
     for value in list {
         output_string.push_str("<li>");
-        output_string.push_str(&html_value(value, config));
+        output_string.push_str(&html_value(value, config, options));
         output_string.push_str("</li>");
     }
     output_string.push_str("</ol>");
     output_string
 }
 
-fn html_table(table: Vec<Value>, headers: Vec<String>, config: &Config) -> String {
+fn html_table(
+    table: Vec<Value>,
+    headers: Vec<String>,
+    config: &Config,
+    options: &HtmlOptions,
+) -> String {
     let mut output_string = String::new();
 
     output_string.push_str("<table>");
@@ -425,7 +509,7 @@ fn html_table(table: Vec<Value>, headers: Vec<String>, config: &Config) -> Strin
     output_string.push_str("<thead><tr>");
     for header in &headers {
         output_string.push_str("<th>");
-        output_string.push_str(&htmlescape::encode_minimal(header));
+        output_string.push_str(&escape_html(header, options.escape));
         output_string.push_str("</th>");
     }
     output_string.push_str("</tr></thead><tbody>");
@@ -439,6 +523,7 @@ fn html_table(table: Vec<Value>, headers: Vec<String>, config: &Config) -> Strin
                 output_string.push_str(&html_value(
                     data.unwrap_or_else(|| Value::nothing(span)),
                     config,
+                    options,
                 ));
                 output_string.push_str("</td>");
             }
@@ -450,7 +535,7 @@ fn html_table(table: Vec<Value>, headers: Vec<String>, config: &Config) -> Strin
     output_string
 }
 
-fn html_value(value: Value, config: &Config) -> String {
+fn html_value(value: Value, config: &Config, options: &HtmlOptions) -> String {
     let mut output_string = String::new();
     match value {
         Value::Binary { val, .. } => {
@@ -459,8 +544,47 @@ fn html_value(value: Value, config: &Config) -> String {
             output_string.push_str(&output);
             output_string.push_str("</pre>");
         }
+        Value::List { ref vals, .. } if options.expand => {
+            let summary = escape_html(
+                &value.clone().into_abbreviated_string(config),
+                options.escape,
+            );
+            let headers = merge_descriptors(vals);
+            let inner = if !headers.is_empty() && (headers.len() > 1 || !headers[0].is_empty()) {
+                html_table(vals.clone(), headers, config, options)
+            } else {
+                html_list(vals.clone(), config, options)
+            };
+            write!(
+                &mut output_string,
+                "<details><summary>{summary}</summary>{inner}</details>"
+            )
+            .unwrap();
+        }
+        Value::Record {
+            ref cols, ref vals, ..
+        } if options.expand => {
+            let summary = escape_html(
+                &value.clone().into_abbreviated_string(config),
+                options.escape,
+            );
+            let mut inner = String::from("<table><tbody>");
+            for (col, val) in cols.iter().zip(vals) {
+                inner.push_str("<tr><th>");
+                inner.push_str(&escape_html(col, options.escape));
+                inner.push_str("</th><td>");
+                inner.push_str(&html_value(val.clone(), config, options));
+                inner.push_str("</td></tr>");
+            }
+            inner.push_str("</tbody></table>");
+            write!(
+                &mut output_string,
+                "<details><summary>{summary}</summary>{inner}</details>"
+            )
+            .unwrap();
+        }
         other => output_string.push_str(
-            &htmlescape::encode_minimal(&other.into_abbreviated_string(config))
+            &escape_html(&other.into_abbreviated_string(config), options.escape)
                 .replace('\n', "<br>"),
         ),
     }
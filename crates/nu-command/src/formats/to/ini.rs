@@ -0,0 +1,139 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ToIni;
+
+impl Command for ToIni {
+    fn name(&self) -> &str {
+        "to ini"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to ini")
+            .input_output_types(vec![(Type::Record(vec![]), Type::String)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert record into .ini text."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Record values become sections in record order; an entry under the ''
+key (as produced by 'from ini' for keys before the first section) is
+written without a header. List values become repeated keys and
+'#comment<n>' entries (from 'from ini --keep-comments') become comment
+lines again, so files round trip."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let value = input.into_value(head);
+        if let Value::Error { error } = value {
+            return Err(*error);
+        }
+        let text = to_ini_string(&value, head)?;
+        Ok(Value::string(text, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "{package: {name: nushell, version: '0.78.0'}} | to ini",
+            description: "Outputs an INI string representing the contents of this record",
+            result: Some(Value::test_string(
+                "[package]
+name=nushell
+version=0.78.0
+",
+            )),
+        }]
+    }
+}
+
+fn to_ini_string(value: &Value, span: Span) -> Result<String, ShellError> {
+    let (cols, vals) = match value {
+        Value::Record { cols, vals, .. } => (cols, vals),
+        other => {
+            return Err(ShellError::CantConvert {
+                from_type: other.get_type().to_string(),
+                to_type: "INI".to_string(),
+                span,
+                help: None,
+            })
+        }
+    };
+
+    let mut out = String::new();
+    let mut first = true;
+    for (col, val) in cols.iter().zip(vals) {
+        if let Value::Record { cols, vals, .. } = val {
+            if !first {
+                out.push('\n');
+            }
+            first = false;
+            if !col.is_empty() {
+                out.push_str(&format!("[{col}]\n"));
+            }
+            for (key, value) in cols.iter().zip(vals) {
+                write_entry(key, value, &mut out, span)?;
+            }
+        } else {
+            // Scalars at the top level are entries without a section header
+            first = false;
+            write_entry(col, val, &mut out, span)?;
+        }
+    }
+    Ok(out)
+}
+
+fn write_entry(key: &str, value: &Value, out: &mut String, span: Span) -> Result<(), ShellError> {
+    if key.starts_with("#comment") {
+        if let Value::String { val, .. } = value {
+            out.push_str(val);
+            out.push('\n');
+            return Ok(());
+        }
+    }
+    match value {
+        Value::List { vals, .. } => {
+            for val in vals {
+                write_entry(key, val, out, span)?;
+            }
+        }
+        Value::String { val, .. } => out.push_str(&format!("{key}={val}\n")),
+        Value::Int { val, .. } => out.push_str(&format!("{key}={val}\n")),
+        Value::Float { val, .. } => out.push_str(&format!("{key}={val}\n")),
+        Value::Bool { val, .. } => out.push_str(&format!("{key}={val}\n")),
+        other => {
+            return Err(ShellError::CantConvert {
+                from_type: other.get_type().to_string(),
+                to_type: "INI".to_string(),
+                span,
+                help: Some(format!("value of key '{key}' cannot be written as INI")),
+            })
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToIni {})
+    }
+}
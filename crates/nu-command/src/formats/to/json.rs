@@ -112,6 +112,8 @@ pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
         Value::Duration { val, .. } => nu_json::Value::I64(*val),
         Value::Date { val, .. } => nu_json::Value::String(val.to_string()),
         Value::Float { val, .. } => nu_json::Value::F64(*val),
+        Value::Decimal { val, .. } => nu_json::Value::String(val.to_string()),
+        Value::BigInt { val, .. } => nu_json::Value::String(val.to_string()),
         Value::Int { val, .. } => nu_json::Value::I64(*val),
         Value::Nothing { .. } => nu_json::Value::Null,
         Value::String { val, .. } => nu_json::Value::String(val.to_string()),
@@ -121,6 +123,12 @@ pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
                 .map(|x| match &x {
                     PathMember::String { val, .. } => Ok(nu_json::Value::String(val.clone())),
                     PathMember::Int { val, .. } => Ok(nu_json::Value::U64(*val as u64)),
+                    PathMember::Dynamic { span, .. } => Err(ShellError::CantConvert {
+                        to_type: "JSON".into(),
+                        from_type: "computed cell path member".into(),
+                        span: *span,
+                        help: None,
+                    }),
                 })
                 .collect::<Result<Vec<nu_json::Value>, ShellError>>()?,
         ),
@@ -1,11 +1,13 @@
 use crate::formats::to::delimited::merge_descriptors;
 use indexmap::map::IndexMap;
+use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type,
-    Value,
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct ToMd;
@@ -28,6 +30,12 @@ impl Command for ToMd {
                 "treat each row as markdown syntax element",
                 Some('e'),
             )
+            .named(
+                "align",
+                SyntaxShape::Any,
+                "column alignment (left, center, right) as a single value for every column, or a record of column names to alignments",
+                Some('a'),
+            )
             .category(Category::Formats)
     }
 
@@ -61,28 +69,46 @@ impl Command for ToMd {
                 example: "[0 1 2] | to md --pretty",
                 result: Some(Value::test_string("0\n1\n2")),
             },
+            Example {
+                description: "Set the alignment of each column",
+                example: "[[foo bar]; [1 2]] | to md --pretty --align {foo: center, bar: right}",
+                result: Some(Value::test_string(
+                    "| foo | bar |\n| :-: | --: |\n|  1  |   2 |\n",
+                )),
+            },
         ]
     }
 
     fn run(
         &self,
         engine_state: &EngineState,
-        _stack: &mut Stack,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
         let pretty = call.has_flag("pretty");
         let per_element = call.has_flag("per-element");
+        let align: Option<Value> = call.get_flag(engine_state, stack, "align")?;
         let config = engine_state.get_config();
-        to_md(input, pretty, per_element, config, head)
+        to_md(input, pretty, per_element, &align, config, head)
     }
 }
 
+// How a column is aligned in the delimiter row ("-" means unspecified,
+// ":-" left, ":-:" center and "-:" right).
+#[derive(Debug, Clone, Copy)]
+enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
 fn to_md(
     input: PipelineData,
     pretty: bool,
     per_element: bool,
+    align: &Option<Value>,
     config: &Config,
     head: Span,
 ) -> Result<PipelineData, ShellError> {
@@ -92,8 +118,8 @@ fn to_md(
             grouped_input
                 .into_iter()
                 .map(move |val| match val {
-                    Value::List { .. } => table(val.into_pipeline_data(), pretty, config),
-                    other => fragment(other, pretty, config),
+                    Value::List { .. } => table(val.into_pipeline_data(), pretty, align, config),
+                    other => fragment(other, pretty, align, config),
                 })
                 .collect::<Vec<String>>()
                 .join(""),
@@ -101,13 +127,13 @@ fn to_md(
         )
         .into_pipeline_data());
     }
-    Ok(Value::string(table(grouped_input, pretty, config), head).into_pipeline_data())
+    Ok(Value::string(table(grouped_input, pretty, align, config), head).into_pipeline_data())
 }
 
-fn fragment(input: Value, pretty: bool, config: &Config) -> String {
+fn fragment(input: Value, pretty: bool, align: &Option<Value>, config: &Config) -> String {
     let headers = match input {
         Value::Record { ref cols, .. } => cols.to_owned(),
-        _ => vec![],
+        _ => Arc::new(vec![]),
     };
     let mut out = String::new();
 
@@ -118,7 +144,7 @@ fn fragment(input: Value, pretty: bool, config: &Config) -> String {
             "h3" => "### ".to_string(),
             "blockquote" => "> ".to_string(),
 
-            _ => return table(input.into_pipeline_data(), pretty, config),
+            _ => return table(input.into_pipeline_data(), pretty, align, config),
         };
 
         out.push_str(&markup);
@@ -128,7 +154,7 @@ fn fragment(input: Value, pretty: bool, config: &Config) -> String {
         };
         out.push_str(&data.into_string("|", config));
     } else if let Value::Record { .. } = input {
-        out = table(input.into_pipeline_data(), pretty, config)
+        out = table(input.into_pipeline_data(), pretty, align, config)
     } else {
         out = input.into_string("|", config)
     }
@@ -143,7 +169,7 @@ fn collect_headers(headers: &[String]) -> (Vec<String>, Vec<usize>) {
 
     if !headers.is_empty() && (headers.len() > 1 || !headers[0].is_empty()) {
         for header in headers {
-            let escaped_header_string = htmlescape::encode_minimal(header);
+            let escaped_header_string = escape_cell(&htmlescape::encode_minimal(header));
             column_widths.push(escaped_header_string.len());
             escaped_headers.push(escaped_header_string);
         }
@@ -154,11 +180,45 @@ fn collect_headers(headers: &[String]) -> (Vec<String>, Vec<usize>) {
     (escaped_headers, column_widths)
 }
 
-fn table(input: PipelineData, pretty: bool, config: &Config) -> String {
+// A GitHub flavored table cell can't hold an unescaped '|' or a line break.
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+fn lookup_alignment(text: &str) -> Option<Alignment> {
+    match text.to_ascii_lowercase().as_str() {
+        "l" | "left" => Some(Alignment::Left),
+        "c" | "center" | "centre" => Some(Alignment::Center),
+        "r" | "right" => Some(Alignment::Right),
+        _ => None,
+    }
+}
+
+fn collect_alignments(align: &Option<Value>, headers: &[String]) -> Vec<Option<Alignment>> {
+    match align {
+        Some(Value::String { val, .. }) => vec![lookup_alignment(val); headers.len()],
+        Some(value @ Value::Record { .. }) => headers
+            .iter()
+            .map(|header| {
+                value
+                    .get_data_by_key(header)
+                    .and_then(|v| v.as_string().ok())
+                    .as_deref()
+                    .and_then(lookup_alignment)
+            })
+            .collect(),
+        _ => vec![None; headers.len()],
+    }
+}
+
+fn table(input: PipelineData, pretty: bool, align: &Option<Value>, config: &Config) -> String {
     let vec_of_values = input.into_iter().collect::<Vec<Value>>();
     let headers = merge_descriptors(&vec_of_values);
 
     let (escaped_headers, mut column_widths) = collect_headers(&headers);
+    let alignments = collect_alignments(align, &headers);
 
     let mut escaped_rows: Vec<Vec<String>> = Vec::new();
 
@@ -169,9 +229,11 @@ fn table(input: PipelineData, pretty: bool, config: &Config) -> String {
             Value::Record { span, .. } => {
                 for i in 0..headers.len() {
                     let data = row.get_data_by_key(&headers[i]);
-                    let value_string = data
-                        .unwrap_or_else(|| Value::nothing(span))
-                        .into_string(", ", config);
+                    let value_string = escape_cell(
+                        &data
+                            .unwrap_or_else(|| Value::nothing(span))
+                            .into_string(", ", config),
+                    );
                     let new_column_width = value_string.len();
 
                     escaped_row.push(value_string);
@@ -183,7 +245,7 @@ fn table(input: PipelineData, pretty: bool, config: &Config) -> String {
             }
             p => {
                 let value_string = htmlescape::encode_minimal(&p.into_abbreviated_string(config));
-                escaped_row.push(value_string);
+                escaped_row.push(escape_cell(&value_string));
             }
         }
 
@@ -195,9 +257,15 @@ fn table(input: PipelineData, pretty: bool, config: &Config) -> String {
     {
         String::from("")
     } else {
-        get_output_string(&escaped_headers, &escaped_rows, &column_widths, pretty)
-            .trim()
-            .to_string()
+        get_output_string(
+            &escaped_headers,
+            &escaped_rows,
+            &column_widths,
+            &alignments,
+            pretty,
+        )
+        .trim()
+        .to_string()
     };
 
     output_string
@@ -247,6 +315,7 @@ fn get_output_string(
     headers: &[String],
     rows: &[Vec<String>],
     column_widths: &[usize],
+    alignments: &[Option<Alignment>],
     pretty: bool,
 ) -> String {
     let mut output_string = String::new();
@@ -272,13 +341,19 @@ fn get_output_string(
 
         output_string.push_str("\n|");
 
-        for &col_width in column_widths.iter().take(headers.len()) {
+        for (i, &col_width) in column_widths.iter().take(headers.len()).enumerate() {
+            let alignment = alignments.get(i).copied().flatten();
             if pretty {
                 output_string.push(' ');
-                output_string.push_str(&get_padded_string(String::from("-"), col_width, '-'));
+                output_string.push_str(&get_delimiter_cell(col_width, alignment));
                 output_string.push(' ');
             } else {
-                output_string.push('-');
+                output_string.push_str(match alignment {
+                    None => "-",
+                    Some(Alignment::Left) => ":-",
+                    Some(Alignment::Center) => ":-:",
+                    Some(Alignment::Right) => "-:",
+                });
             }
 
             output_string.push('|');
@@ -294,8 +369,13 @@ fn get_output_string(
 
         for i in 0..row.len() {
             if pretty && column_widths.get(i).is_some() {
+                let alignment = alignments.get(i).copied().flatten();
                 output_string.push(' ');
-                output_string.push_str(&get_padded_string(row[i].clone(), column_widths[i], ' '));
+                output_string.push_str(&get_aligned_string(
+                    row[i].clone(),
+                    column_widths[i],
+                    alignment,
+                ));
                 output_string.push(' ');
             } else {
                 output_string.push_str(&row[i]);
@@ -312,6 +392,37 @@ fn get_output_string(
     output_string
 }
 
+// The dashes-and-colons cell of the delimiter row, stretched to the column width.
+fn get_delimiter_cell(desired_length: usize, alignment: Option<Alignment>) -> String {
+    match alignment {
+        None => get_padded_string(String::from("-"), desired_length, '-'),
+        Some(Alignment::Left) => get_padded_string(String::from(":-"), desired_length, '-'),
+        Some(Alignment::Center) => {
+            let dashes =
+                get_padded_string(String::from("-"), desired_length.saturating_sub(2), '-');
+            format!(":{dashes}:")
+        }
+        Some(Alignment::Right) => {
+            let dashes =
+                get_padded_string(String::from("-"), desired_length.saturating_sub(1), '-');
+            format!("{dashes}:")
+        }
+    }
+}
+
+fn get_aligned_string(text: String, desired_length: usize, alignment: Option<Alignment>) -> String {
+    let pad = desired_length.saturating_sub(text.len());
+    match alignment {
+        None | Some(Alignment::Left) => format!("{}{}", text, " ".repeat(pad)),
+        Some(Alignment::Right) => format!("{}{}", " ".repeat(pad), text),
+        Some(Alignment::Center) => {
+            let left = pad / 2;
+            let right = pad - left;
+            format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+        }
+    }
+}
+
 fn get_padded_string(text: String, desired_length: usize, padding_character: char) -> String {
     let repeat_length = if text.len() > desired_length {
         0
@@ -352,45 +463,57 @@ mod tests {
     #[test]
     fn render_h1() {
         let value = Value::Record {
-            cols: vec!["H1".to_string()],
+            cols: Arc::new(vec!["H1".to_string()]),
             vals: vec![Value::test_string("Ecuador")],
             span: Span::test_data(),
         };
 
-        assert_eq!(fragment(value, false, &Config::default()), "# Ecuador\n");
+        assert_eq!(
+            fragment(value, false, &None, &Config::default()),
+            "# Ecuador\n"
+        );
     }
 
     #[test]
     fn render_h2() {
         let value = Value::Record {
-            cols: vec!["H2".to_string()],
+            cols: Arc::new(vec!["H2".to_string()]),
             vals: vec![Value::test_string("Ecuador")],
             span: Span::test_data(),
         };
 
-        assert_eq!(fragment(value, false, &Config::default()), "## Ecuador\n");
+        assert_eq!(
+            fragment(value, false, &None, &Config::default()),
+            "## Ecuador\n"
+        );
     }
 
     #[test]
     fn render_h3() {
         let value = Value::Record {
-            cols: vec!["H3".to_string()],
+            cols: Arc::new(vec!["H3".to_string()]),
             vals: vec![Value::test_string("Ecuador")],
             span: Span::test_data(),
         };
 
-        assert_eq!(fragment(value, false, &Config::default()), "### Ecuador\n");
+        assert_eq!(
+            fragment(value, false, &None, &Config::default()),
+            "### Ecuador\n"
+        );
     }
 
     #[test]
     fn render_blockquote() {
         let value = Value::Record {
-            cols: vec!["BLOCKQUOTE".to_string()],
+            cols: Arc::new(vec!["BLOCKQUOTE".to_string()]),
             vals: vec![Value::test_string("Ecuador")],
             span: Span::test_data(),
         };
 
-        assert_eq!(fragment(value, false, &Config::default()), "> Ecuador\n");
+        assert_eq!(
+            fragment(value, false, &None, &Config::default()),
+            "> Ecuador\n"
+        );
     }
 
     #[test]
@@ -398,17 +521,17 @@ mod tests {
         let value = Value::List {
             vals: vec![
                 Value::Record {
-                    cols: vec!["country".to_string()],
+                    cols: Arc::new(vec!["country".to_string()]),
                     vals: vec![Value::test_string("Ecuador")],
                     span: Span::test_data(),
                 },
                 Value::Record {
-                    cols: vec!["country".to_string()],
+                    cols: Arc::new(vec!["country".to_string()]),
                     vals: vec![Value::test_string("New Zealand")],
                     span: Span::test_data(),
                 },
                 Value::Record {
-                    cols: vec!["country".to_string()],
+                    cols: Arc::new(vec!["country".to_string()]),
                     vals: vec![Value::test_string("USA")],
                     span: Span::test_data(),
                 },
@@ -420,6 +543,7 @@ mod tests {
             table(
                 value.clone().into_pipeline_data(),
                 false,
+                &None,
                 &Config::default()
             ),
             one(r#"
@@ -432,7 +556,7 @@ mod tests {
         );
 
         assert_eq!(
-            table(value.into_pipeline_data(), true, &Config::default()),
+            table(value.into_pipeline_data(), true, &None, &Config::default()),
             one(r#"
             | country     |
             | ----------- |
@@ -2,9 +2,12 @@ mod command;
 mod csv;
 mod delimited;
 mod html;
+mod ini;
 mod json;
 mod md;
+mod ndjson;
 mod nuon;
+mod plist;
 mod text;
 mod toml;
 mod tsv;
@@ -15,10 +18,13 @@ pub use self::csv::ToCsv;
 pub use self::toml::ToToml;
 pub use command::To;
 pub use html::ToHtml;
+pub use ini::ToIni;
 pub use json::ToJson;
 pub use md::ToMd;
+pub use ndjson::ToNdjson;
 pub use nuon::value_to_string;
 pub use nuon::ToNuon;
+pub use plist::ToPlist;
 pub use text::ToText;
 pub use tsv::ToTsv;
 pub use xml::ToXml;
@@ -0,0 +1,125 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, ListStream, PipelineData, RawStream, ShellError,
+    Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ToNdjson;
+
+impl Command for ToNdjson {
+    fn name(&self) -> &str {
+        "to ndjson"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to ndjson")
+            .input_output_types(vec![(Type::Any, Type::String)])
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a list into newline-delimited JSON (JSON lines) text."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each row becomes one JSON line. Streaming input is written out row by
+row rather than being collected first."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let input = input.try_expand_range()?;
+
+        if let PipelineData::ListStream(stream, _) = input {
+            return Ok(PipelineData::ExternalStream {
+                stdout: Some(RawStream::new(
+                    Box::new(ListStreamIterator { stream, head }),
+                    engine_state.ctrlc.clone(),
+                    head,
+                    None,
+                )),
+                stderr: None,
+                exit_code: None,
+                span: head,
+                metadata: None,
+                trim_end_newline: false,
+            });
+        }
+
+        let value = input.into_value(head);
+        let single = std::slice::from_ref(&value);
+        let rows = match &value {
+            Value::List { vals, .. } => vals.as_slice(),
+            _ => single,
+        };
+
+        let mut out = String::new();
+        for row in rows {
+            out.push_str(&json_line(row, head)?);
+            out.push('\n');
+        }
+        Ok(Value::String {
+            val: out,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "[{a: 1} {a: 2}] | to ndjson",
+            description: "Outputs a JSON line for every row of this table",
+            result: Some(Value::test_string("{\"a\": 1}\n{\"a\": 2}\n")),
+        }]
+    }
+}
+
+fn json_line(value: &Value, span: Span) -> Result<String, ShellError> {
+    let json_value = super::json::value_to_json_value(value)?;
+    nu_json::to_string_raw(&json_value).map_err(|_| ShellError::CantConvert {
+        to_type: "JSON".into(),
+        from_type: value.get_type().to_string(),
+        span,
+        help: None,
+    })
+}
+
+struct ListStreamIterator {
+    stream: ListStream,
+    head: Span,
+}
+
+impl Iterator for ListStreamIterator {
+    type Item = Result<Vec<u8>, ShellError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.stream.next()?;
+        match json_line(&item, self.head) {
+            Ok(mut line) => {
+                line.push('\n');
+                Some(Ok(line.into_bytes()))
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToNdjson {})
+    }
+}
@@ -192,6 +192,8 @@ pub fn value_to_string(
                 Ok(format!("{}", *val))
             }
         }
+        Value::Decimal { val, .. } => Ok(format!("{}d", *val)),
+        Value::BigInt { val, .. } => Ok(format!("{}", *val)),
         Value::Int { val, .. } => Ok(format!("{}", *val)),
         Value::List { vals, .. } => {
             let headers = get_columns(vals);
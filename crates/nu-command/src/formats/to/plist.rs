@@ -0,0 +1,352 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ToPlist;
+
+impl Command for ToPlist {
+    fn name(&self) -> &str {
+        "to plist"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to plist")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .switch(
+                "binary",
+                "produce the binary (bplist00) variant instead of XML",
+                Some('b'),
+            )
+            .category(Category::Formats)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert a value to Apple property list text (or binary with --binary)."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let binary = call.has_flag("binary");
+        let value = input.into_value(head);
+        if let Value::Error { error } = value {
+            return Err(*error);
+        }
+
+        let output = if binary {
+            Value::Binary {
+                val: to_binary_plist(&value, head)?,
+                span: head,
+            }
+        } else {
+            Value::string(to_xml_plist(&value, head)?, head)
+        };
+        Ok(output.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert a record to an XML property list",
+                example: "{ a: 3 } | to plist",
+                result: Some(Value::test_string(
+                    r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>a</key>
+	<integer>3</integer>
+</dict>
+</plist>
+"#,
+                )),
+            },
+            Example {
+                description: "Convert a record to a binary property list",
+                example: "{ a: 3 } | to plist --binary",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn cant_convert(value: &Value, span: Span) -> ShellError {
+    ShellError::CantConvert {
+        from_type: value.get_type().to_string(),
+        to_type: "plist".to_string(),
+        span,
+        help: None,
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+// Seconds between the Unix epoch and the Apple epoch (2001-01-01T00:00:00Z).
+const APPLE_EPOCH_OFFSET: i64 = 978_307_200;
+
+fn to_xml_plist(value: &Value, span: Span) -> Result<String, ShellError> {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+         <plist version=\"1.0\">\n",
+    );
+    write_xml_value(value, 0, &mut out, span)?;
+    out.push_str("</plist>\n");
+    Ok(out)
+}
+
+fn write_xml_value(
+    value: &Value,
+    indent: usize,
+    out: &mut String,
+    span: Span,
+) -> Result<(), ShellError> {
+    let pad = "\t".repeat(indent);
+    match value {
+        Value::Record { cols, vals, .. } => {
+            out.push_str(&format!("{pad}<dict>\n"));
+            for (col, val) in cols.iter().zip(vals) {
+                out.push_str(&format!("{pad}\t<key>{}</key>\n", escape(col)));
+                write_xml_value(val, indent + 1, out, span)?;
+            }
+            out.push_str(&format!("{pad}</dict>\n"));
+        }
+        Value::List { vals, .. } => {
+            out.push_str(&format!("{pad}<array>\n"));
+            for val in vals {
+                write_xml_value(val, indent + 1, out, span)?;
+            }
+            out.push_str(&format!("{pad}</array>\n"));
+        }
+        Value::String { val, .. } => {
+            out.push_str(&format!("{pad}<string>{}</string>\n", escape(val)))
+        }
+        Value::Int { val, .. } => out.push_str(&format!("{pad}<integer>{val}</integer>\n")),
+        Value::Filesize { val, .. } => out.push_str(&format!("{pad}<integer>{val}</integer>\n")),
+        Value::Float { val, .. } => out.push_str(&format!("{pad}<real>{val}</real>\n")),
+        Value::Bool { val, .. } => out.push_str(&format!("{pad}<{val}/>\n")),
+        Value::Date { val, .. } => out.push_str(&format!(
+            "{pad}<date>{}</date>\n",
+            val.with_timezone(&Utc).format("%Y-%m-%dT%H:%M:%SZ")
+        )),
+        Value::Binary { val, .. } => {
+            out.push_str(&format!("{pad}<data>{}</data>\n", STANDARD.encode(val)))
+        }
+        other => return Err(cant_convert(other, span)),
+    }
+    Ok(())
+}
+
+// Flattened object graph: arrays and dicts hold indexes into the same list,
+// the way the bplist00 format stores references.
+enum FlatObject<'a> {
+    Scalar(&'a Value),
+    Key(&'a str),
+    Array(Vec<usize>),
+    Dict(Vec<usize>, Vec<usize>),
+}
+
+fn to_binary_plist(value: &Value, span: Span) -> Result<Vec<u8>, ShellError> {
+    let mut objects = Vec::new();
+    flatten(value, &mut objects, span)?;
+
+    let ref_size = int_width(objects.len() as u64);
+    let mut out = b"bplist00".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for object in &objects {
+        offsets.push(out.len() as u64);
+        write_binary_object(object, ref_size, &mut out, span)?;
+    }
+
+    let table_offset = out.len() as u64;
+    let offset_size = int_width(table_offset);
+    for offset in &offsets {
+        write_be_uint(*offset, offset_size, &mut out);
+    }
+
+    // Trailer: 5 unused bytes, sort version, offset size, ref size, then
+    // object count, top object and offset table start as big-endian u64.
+    out.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+    out.push(offset_size as u8);
+    out.push(ref_size as u8);
+    out.extend_from_slice(&(objects.len() as u64).to_be_bytes());
+    out.extend_from_slice(&0u64.to_be_bytes());
+    out.extend_from_slice(&table_offset.to_be_bytes());
+    Ok(out)
+}
+
+fn flatten<'a>(
+    value: &'a Value,
+    objects: &mut Vec<FlatObject<'a>>,
+    span: Span,
+) -> Result<usize, ShellError> {
+    let id = objects.len();
+    match value {
+        Value::Record { cols, vals, .. } => {
+            objects.push(FlatObject::Dict(Vec::new(), Vec::new()));
+            let mut keys = Vec::with_capacity(cols.len());
+            let mut values = Vec::with_capacity(vals.len());
+            for col in cols.iter() {
+                keys.push(objects.len());
+                objects.push(FlatObject::Key(col));
+            }
+            for val in vals {
+                values.push(flatten(val, objects, span)?);
+            }
+            objects[id] = FlatObject::Dict(keys, values);
+        }
+        Value::List { vals, .. } => {
+            objects.push(FlatObject::Array(Vec::new()));
+            let mut items = Vec::with_capacity(vals.len());
+            for val in vals {
+                items.push(flatten(val, objects, span)?);
+            }
+            objects[id] = FlatObject::Array(items);
+        }
+        _ => objects.push(FlatObject::Scalar(value)),
+    }
+    Ok(id)
+}
+
+fn write_binary_object(
+    object: &FlatObject,
+    ref_size: usize,
+    out: &mut Vec<u8>,
+    span: Span,
+) -> Result<(), ShellError> {
+    match object {
+        FlatObject::Scalar(value) => write_binary_scalar(value, out, span),
+        FlatObject::Key(key) => {
+            write_binary_string(key, out);
+            Ok(())
+        }
+        FlatObject::Array(items) => {
+            write_marker(0xa0, items.len(), out);
+            for item in items {
+                write_be_uint(*item as u64, ref_size, out);
+            }
+            Ok(())
+        }
+        FlatObject::Dict(keys, values) => {
+            write_marker(0xd0, keys.len(), out);
+            for key in keys {
+                write_be_uint(*key as u64, ref_size, out);
+            }
+            for value in values {
+                write_be_uint(*value as u64, ref_size, out);
+            }
+            Ok(())
+        }
+    }
+}
+
+fn write_binary_scalar(value: &Value, out: &mut Vec<u8>, span: Span) -> Result<(), ShellError> {
+    match value {
+        Value::Nothing { .. } => out.push(0x00),
+        Value::Bool { val, .. } => out.push(if *val { 0x09 } else { 0x08 }),
+        Value::Int { val, .. } => write_binary_int(*val, out),
+        Value::Filesize { val, .. } => write_binary_int(*val, out),
+        Value::Float { val, .. } => {
+            out.push(0x23);
+            out.extend_from_slice(&val.to_be_bytes());
+        }
+        Value::Date { val, .. } => {
+            out.push(0x33);
+            out.extend_from_slice(&date_to_apple_seconds(val).to_be_bytes());
+        }
+        Value::String { val, .. } => write_binary_string(val, out),
+        Value::Binary { val, .. } => {
+            write_marker(0x40, val.len(), out);
+            out.extend_from_slice(val);
+        }
+        other => return Err(cant_convert(other, span)),
+    }
+    Ok(())
+}
+
+fn date_to_apple_seconds(date: &DateTime<FixedOffset>) -> f64 {
+    let apple_epoch = Utc
+        .timestamp_opt(APPLE_EPOCH_OFFSET, 0)
+        .single()
+        .unwrap_or_default();
+    let elapsed = date.with_timezone(&Utc) - apple_epoch;
+    elapsed.num_milliseconds() as f64 / 1000.0
+}
+
+fn write_binary_int(val: i64, out: &mut Vec<u8>) {
+    // Negative ints always take the full 8 bytes; the format has no sign bit
+    // in narrower widths.
+    if val < 0 {
+        out.push(0x13);
+        out.extend_from_slice(&val.to_be_bytes());
+    } else {
+        let width = int_width(val as u64);
+        out.push(0x10 | width.trailing_zeros() as u8);
+        write_be_uint(val as u64, width, out);
+    }
+}
+
+fn write_binary_string(text: &str, out: &mut Vec<u8>) {
+    if text.is_ascii() {
+        write_marker(0x50, text.len(), out);
+        out.extend_from_slice(text.as_bytes());
+    } else {
+        let units: Vec<u16> = text.encode_utf16().collect();
+        write_marker(0x60, units.len(), out);
+        for unit in units {
+            out.extend_from_slice(&unit.to_be_bytes());
+        }
+    }
+}
+
+fn write_marker(marker: u8, len: usize, out: &mut Vec<u8>) {
+    if len < 0x0f {
+        out.push(marker | len as u8);
+    } else {
+        out.push(marker | 0x0f);
+        write_binary_int(len as i64, out);
+    }
+}
+
+// The narrowest power-of-two byte width that holds `val`.
+fn int_width(val: u64) -> usize {
+    if val <= u8::MAX as u64 {
+        1
+    } else if val <= u16::MAX as u64 {
+        2
+    } else if val <= u32::MAX as u64 {
+        4
+    } else {
+        8
+    }
+}
+
+fn write_be_uint(val: u64, width: usize, out: &mut Vec<u8>) {
+    out.extend_from_slice(&val.to_be_bytes()[8 - width..]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ToPlist {})
+    }
+}
@@ -118,6 +118,8 @@ fn local_into_string(value: Value, separator: &str, config: &Config) -> String {
         Value::Bool { val, .. } => val.to_string(),
         Value::Int { val, .. } => val.to_string(),
         Value::Float { val, .. } => val.to_string(),
+        Value::Decimal { val, .. } => val.to_string(),
+        Value::BigInt { val, .. } => val.to_string(),
         Value::Filesize { val, .. } => format_filesize_from_conf(val, config),
         Value::Duration { val, .. } => format_duration(val),
         Value::Date { val, .. } => {
@@ -53,6 +53,8 @@ fn helper(engine_state: &EngineState, v: &Value) -> Result<toml::Value, ShellErr
         Value::Date { val, .. } => toml::Value::String(val.to_string()),
         Value::Range { .. } => toml::Value::String("<Range>".to_string()),
         Value::Float { val, .. } => toml::Value::Float(*val),
+        Value::Decimal { val, .. } => toml::Value::String(val.to_string()),
+        Value::BigInt { val, .. } => toml::Value::String(val.to_string()),
         Value::String { val, .. } => toml::Value::String(val.clone()),
         Value::Record { cols, vals, .. } => {
             let mut m = toml::map::Map::new();
@@ -89,6 +91,12 @@ fn helper(engine_state: &EngineState, v: &Value) -> Result<toml::Value, ShellErr
                 .map(|x| match &x {
                     PathMember::String { val, .. } => Ok(toml::Value::String(val.clone())),
                     PathMember::Int { val, .. } => Ok(toml::Value::Integer(*val as i64)),
+                    PathMember::Dynamic { span, .. } => Err(ShellError::CantConvert {
+                        to_type: "TOML".into(),
+                        from_type: "computed cell path member".into(),
+                        span: *span,
+                        help: None,
+                    }),
                 })
                 .collect::<Result<Vec<toml::Value>, ShellError>>()?,
         ),
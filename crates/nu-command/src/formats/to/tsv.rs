@@ -1,8 +1,8 @@
-use crate::formats::to::delimited::to_delimited_data;
+use crate::formats::to::delimited::{to_delimited_data, DelimitedOptions};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Config, Example, PipelineData, ShellError, Signature, Span, Type, Value,
+    Category, Config, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 
 #[derive(Clone)]
@@ -24,6 +24,20 @@ impl Command for ToTsv {
                 "do not output the column names as the first row",
                 Some('n'),
             )
+            .named(
+                "quoting",
+                SyntaxShape::String,
+                "quoting style: always, minimal (default), or never",
+                Some('q'),
+            )
+            .switch("bom", "output a UTF-8 byte order mark", None)
+            .switch("crlf", "use CRLF (\\r\\n) line endings", None)
+            .named(
+                "null",
+                SyntaxShape::String,
+                "the text to output for null values, defaults to an empty cell",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -49,24 +63,26 @@ impl Command for ToTsv {
     fn run(
         &self,
         engine_state: &EngineState,
-        _stack: &mut Stack,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
         let noheaders = call.has_flag("noheaders");
+        let options = DelimitedOptions::from_call(engine_state, stack, call)?;
         let config = engine_state.get_config();
-        to_tsv(input, noheaders, head, config)
+        to_tsv(input, noheaders, options, head, config)
     }
 }
 
 fn to_tsv(
     input: PipelineData,
     noheaders: bool,
+    options: DelimitedOptions,
     head: Span,
     config: &Config,
 ) -> Result<PipelineData, ShellError> {
-    to_delimited_data(noheaders, '\t', "TSV", input, head, config)
+    to_delimited_data(noheaders, '\t', options, "TSV", input, head, config)
 }
 
 #[cfg(test)]
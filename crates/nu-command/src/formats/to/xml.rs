@@ -209,7 +209,7 @@ fn to_tag_like<W: Write>(
         // alternatives like {tag: a attributes: {} content: []}, {tag: a attribbutes: null
         // content: null}, {tag: a}. See to_xml_entry for more
         let (attr_cols, attr_values) = match attrs {
-            Value::Record { cols, vals, .. } => (cols, vals),
+            Value::Record { cols, vals, .. } => (cols.to_vec(), vals),
             Value::Nothing { .. } => (Vec::new(), Vec::new()),
             _ => {
                 return Err(ShellError::CantConvert {
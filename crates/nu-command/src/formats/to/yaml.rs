@@ -15,6 +15,11 @@ impl Command for ToYaml {
     fn signature(&self) -> Signature {
         Signature::build("to yaml")
             .input_output_types(vec![(Type::Any, Type::String)])
+            .switch(
+                "docs",
+                "write each row of a list as its own document, separated by '---'",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -23,11 +28,18 @@ impl Command for ToYaml {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Outputs an YAML string representing the contents of this table",
-            example: r#"[[foo bar]; ["1" "2"]] | to yaml"#,
-            result: Some(Value::test_string("- foo: '1'\n  bar: '2'\n")),
-        }]
+        vec![
+            Example {
+                description: "Outputs an YAML string representing the contents of this table",
+                example: r#"[[foo bar]; ["1" "2"]] | to yaml"#,
+                result: Some(Value::test_string("- foo: '1'\n  bar: '2'\n")),
+            },
+            Example {
+                description: "Outputs a multi-document YAML stream with one document per row",
+                example: "[{a: 1} {a: 2}] | to yaml --docs",
+                result: Some(Value::test_string("---\na: 1\n---\na: 2\n")),
+            },
+        ]
     }
 
     fn run(
@@ -39,7 +51,7 @@ impl Command for ToYaml {
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
         let input = input.try_expand_range()?;
-        to_yaml(input, head)
+        to_yaml(input, call.has_flag("docs"), head)
     }
 }
 
@@ -52,6 +64,8 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
         Value::Date { val, .. } => serde_yaml::Value::String(val.to_string()),
         Value::Range { .. } => serde_yaml::Value::Null,
         Value::Float { val, .. } => serde_yaml::Value::Number(serde_yaml::Number::from(*val)),
+        Value::Decimal { val, .. } => serde_yaml::Value::String(val.to_string()),
+        Value::BigInt { val, .. } => serde_yaml::Value::String(val.to_string()),
         Value::String { val, .. } => serde_yaml::Value::String(val.clone()),
         Value::Record { cols, vals, .. } => {
             let mut m = serde_yaml::Mapping::new();
@@ -93,6 +107,12 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
                     PathMember::Int { val, .. } => {
                         Ok(serde_yaml::Value::Number(serde_yaml::Number::from(*val)))
                     }
+                    PathMember::Dynamic { span, .. } => Err(ShellError::CantConvert {
+                        to_type: "YAML".into(),
+                        from_type: "computed cell path member".into(),
+                        span: *span,
+                        help: None,
+                    }),
                 })
                 .collect::<Result<Vec<serde_yaml::Value>, ShellError>>()?,
         ),
@@ -101,9 +121,13 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
     })
 }
 
-fn to_yaml(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+fn to_yaml(input: PipelineData, docs: bool, head: Span) -> Result<PipelineData, ShellError> {
     let value = input.into_value(head);
 
+    if docs {
+        return to_yaml_docs(&value, head);
+    }
+
     let yaml_value = value_to_yaml_value(&value)?;
     match serde_yaml::to_string(&yaml_value) {
         Ok(serde_yaml_string) => Ok(Value::String {
@@ -123,6 +147,35 @@ fn to_yaml(input: PipelineData, head: Span) -> Result<PipelineData, ShellError>
     }
 }
 
+// Each list item becomes its own document; any other value is a stream of one.
+fn to_yaml_docs(value: &Value, head: Span) -> Result<PipelineData, ShellError> {
+    let single = std::slice::from_ref(value);
+    let documents = match value {
+        Value::List { vals, .. } => vals.as_slice(),
+        _ => single,
+    };
+
+    let mut out = String::new();
+    for document in documents {
+        let yaml_value = value_to_yaml_value(document)?;
+        let serialized =
+            serde_yaml::to_string(&yaml_value).map_err(|_| ShellError::CantConvert {
+                to_type: "YAML".into(),
+                from_type: document.get_type().to_string(),
+                span: head,
+                help: None,
+            })?;
+        out.push_str("---\n");
+        out.push_str(&serialized);
+    }
+
+    Ok(Value::String {
+        val: out,
+        span: head,
+    }
+    .into_pipeline_data())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -8,6 +8,7 @@ use nu_protocol::{
     SyntaxShape, Type, Value,
 };
 use std::collections::VecDeque;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Cal;
@@ -367,7 +368,7 @@ fn add_month_to_table(
             }
         }
         calendar_vec_deque.push_back(Value::Record {
-            cols,
+            cols: Arc::new(cols),
             vals,
             span: tag,
         })
@@ -0,0 +1,92 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct HashValue;
+
+impl Command for HashValue {
+    fn name(&self) -> &str {
+        "hash value"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("hash value")
+            .category(Category::Hash)
+            .input_output_types(vec![(Type::Any, Type::String)])
+            .switch(
+                "order-sensitive",
+                "make record column order affect the hash",
+                Some('o'),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Compute a stable structural hash of the input value, hex-encoded."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The hash is deterministic across sessions and platforms, so it can be stored and compared later. Records hash the same regardless of column order unless --order-sensitive is given."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["digest", "fingerprint", "structural"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let order_sensitive = call.has_flag("order-sensitive");
+
+        let value = input.into_value(head);
+        let hash = value.canonical_hash(order_sensitive)?;
+
+        Ok(Value::string(format!("{hash:016x}"), head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Hash a record",
+                example: "{a: 1, b: 2} | hash value",
+                result: Some(Value::String {
+                    val: "7f79ba2dc26c4b90".to_owned(),
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Column order does not change the hash",
+                example: "{b: 2, a: 1} | hash value",
+                result: Some(Value::String {
+                    val: "7f79ba2dc26c4b90".to_owned(),
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Hash a list of values",
+                example: "[1 2 3] | hash value",
+                result: Some(Value::String {
+                    val: "64eaf2b027ae0ae6".to_owned(),
+                    span: Span::test_data(),
+                }),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        crate::test_examples(HashValue)
+    }
+}
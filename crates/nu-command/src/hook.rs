@@ -20,28 +20,35 @@ pub fn eval_env_change_hook(
                 ..
             } => {
                 for (env_name, hook_value) in env_names.iter().zip(hook_values.iter()) {
-                    let before = engine_state
-                        .previous_env_vars
-                        .get(env_name)
-                        .cloned()
-                        .unwrap_or_default();
-
-                    let after = stack
-                        .get_env_var(engine_state, env_name)
-                        .unwrap_or_default();
-
-                    if before != after {
-                        eval_hook(
-                            engine_state,
-                            stack,
-                            None,
-                            vec![("$before".into(), before), ("$after".into(), after.clone())],
-                            hook_value,
-                        )?;
+                    if is_glob_pattern(env_name) {
+                        let pattern = match nu_glob::Pattern::new(env_name) {
+                            Ok(pattern) => pattern,
+                            Err(_) => {
+                                return Err(ShellError::TypeMismatch {
+                                    err_message: format!(
+                                        "valid glob pattern for the 'env_change' hook, got '{env_name}'"
+                                    ),
+                                    span: hook_value.span()?,
+                                });
+                            }
+                        };
+
+                        // Both current and previously seen variables can match so that a
+                        // removed variable still fires the hook once with an empty $after
+                        let mut matching: Vec<String> = stack
+                            .get_env_var_names(engine_state)
+                            .into_iter()
+                            .chain(engine_state.previous_env_vars.keys().cloned())
+                            .filter(|name| pattern.matches(name))
+                            .collect();
+                        matching.sort();
+                        matching.dedup();
 
-                        engine_state
-                            .previous_env_vars
-                            .insert(env_name.to_string(), after);
+                        for name in matching {
+                            run_env_change_hook(&name, hook_value, engine_state, stack, true)?;
+                        }
+                    } else {
+                        run_env_change_hook(env_name, hook_value, engine_state, stack, false)?;
                     }
                 }
             }
@@ -57,6 +64,145 @@ pub fn eval_env_change_hook(
     Ok(())
 }
 
+fn is_glob_pattern(name: &str) -> bool {
+    name.contains(['*', '?', '['])
+}
+
+fn run_env_change_hook(
+    env_name: &str,
+    hook_value: &Value,
+    engine_state: &mut EngineState,
+    stack: &mut Stack,
+    with_name: bool,
+) -> Result<(), ShellError> {
+    let before = engine_state
+        .previous_env_vars
+        .get(env_name)
+        .cloned()
+        .unwrap_or_default();
+
+    let after = stack
+        .get_env_var(engine_state, env_name)
+        .unwrap_or_default();
+
+    if before != after {
+        let mut arguments = vec![("$before".into(), before), ("$after".into(), after.clone())];
+
+        if with_name {
+            // A hook subscribed by pattern also needs to know which variable changed
+            arguments.push(("$name".into(), Value::string(env_name, Span::unknown())));
+        }
+
+        eval_hook(engine_state, stack, None, arguments, hook_value)?;
+
+        engine_state
+            .previous_env_vars
+            .insert(env_name.to_string(), after);
+    }
+
+    Ok(())
+}
+
+/// Recompute the env vars described by `$env.NU_COMPUTED_ENV` whose dependencies changed.
+///
+/// NU_COMPUTED_ENV is a record of the form:
+/// {
+///     WORKSPACE: {
+///         deps: [PWD]
+///         code: {|| ... }  # a block or closure; its output becomes the new value
+///     }
+/// }
+/// A computed var is (re)evaluated when it is not set yet or when any of the env vars it
+/// depends on changed since the last evaluation.
+pub fn eval_computed_env(
+    engine_state: &mut EngineState,
+    stack: &mut Stack,
+) -> Result<(), ShellError> {
+    let computed = if let Some(value) = stack.get_env_var(engine_state, "NU_COMPUTED_ENV") {
+        value
+    } else {
+        return Ok(());
+    };
+
+    let (names, specs) = match computed {
+        Value::Record { cols, vals, .. } => (cols, vals),
+        x => {
+            return Err(ShellError::TypeMismatch {
+                err_message: "record for NU_COMPUTED_ENV".to_string(),
+                span: x.span()?,
+            });
+        }
+    };
+
+    for (name, spec) in names.iter().zip(specs.iter()) {
+        let spec_span = spec.span()?;
+
+        let deps_path = PathMember::String {
+            val: "deps".to_string(),
+            span: spec_span,
+            optional: false,
+        };
+
+        let deps: Vec<String> = if let Ok(deps) = spec.follow_cell_path(&[deps_path], false) {
+            deps.as_list()?
+                .iter()
+                .map(|dep| dep.as_string())
+                .collect::<Result<_, _>>()?
+        } else {
+            vec![]
+        };
+
+        let mut changed = stack.get_env_var(engine_state, name).is_none();
+        let mut dep_values = vec![];
+
+        for dep in &deps {
+            let key = format!("{name}:{dep}");
+            let before = engine_state
+                .computed_env_deps
+                .get(&key)
+                .cloned()
+                .unwrap_or_default();
+            let after = stack.get_env_var(engine_state, dep).unwrap_or_default();
+
+            if before != after {
+                changed = true;
+            }
+
+            dep_values.push((key, after));
+        }
+
+        if changed {
+            let code_path = PathMember::String {
+                val: "code".to_string(),
+                span: spec_span,
+                optional: false,
+            };
+
+            let code = spec.follow_cell_path(&[code_path], false)?;
+
+            match code {
+                Value::Block { .. } | Value::Closure { .. } => {
+                    let output = eval_hook(engine_state, stack, None, vec![], &code)?;
+                    stack.add_env_var(name.clone(), output.into_value(spec_span));
+                }
+                other => {
+                    return Err(ShellError::UnsupportedConfigValue(
+                        "block or closure for the 'code' field of a computed env var".to_string(),
+                        format!("{}", other.get_type()),
+                        other.span()?,
+                    ));
+                }
+            }
+
+            for (key, after) in dep_values {
+                engine_state.computed_env_deps.insert(key, after);
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub fn eval_hook(
     engine_state: &mut EngineState,
     stack: &mut Stack,
@@ -94,7 +240,7 @@ pub fn eval_hook(
         }
         Value::Record { .. } => {
             let do_run_hook =
-                if let Ok(condition) = value.clone().follow_cell_path(&[condition_path], false) {
+                if let Ok(condition) = value.follow_cell_path(&[condition_path], false) {
                     match condition {
                         Value::Block {
                             val: block_id,
@@ -146,7 +292,7 @@ pub fn eval_hook(
                 };
 
             if do_run_hook {
-                match value.clone().follow_cell_path(&[code_path], false)? {
+                match value.follow_cell_path(&[code_path], false)? {
                     Value::String {
                         val,
                         span: source_span,
@@ -1,6 +1,8 @@
+mod agg_utils;
 mod bits;
 mod bytes;
 mod charting;
+mod columnar;
 mod conversions;
 mod date;
 mod debug;
@@ -16,23 +18,32 @@ mod generators;
 mod hash;
 pub mod hook;
 mod input_handler;
+mod logging;
 mod math;
 mod misc;
 mod network;
 mod path;
+mod pkg;
 mod platform;
 mod progress_bar;
+mod query;
 mod random;
+mod secret;
 mod shells;
+mod sketch_utils;
 mod sort_utils;
 mod strings;
 mod system;
 pub mod util;
+mod vcs;
+mod verify;
 mod viewers;
 
+pub use agg_utils::*;
 pub use bits::*;
 pub use bytes::*;
 pub use charting::*;
+pub use columnar::*;
 pub use conversions::*;
 pub use date::*;
 pub use debug::*;
@@ -48,17 +59,24 @@ pub use formats::*;
 pub use generators::*;
 pub use hash::*;
 pub use hook::*;
+pub use logging::*;
 pub use math::*;
 pub use misc::*;
 pub use network::*;
 pub use path::*;
+pub use pkg::*;
 pub use platform::*;
+pub use query::*;
 pub use random::*;
+pub use secret::*;
 pub use shells::*;
+pub use sketch_utils::*;
 pub use sort_utils::*;
 pub use strings::*;
 pub use system::*;
 pub use util::*;
+pub use vcs::*;
+pub use verify::*;
 pub use viewers::*;
 
 #[cfg(feature = "dataframe")]
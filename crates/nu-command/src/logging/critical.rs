@@ -0,0 +1,44 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Example, PipelineData, ShellError, Signature};
+
+use super::{log_signature, run_log, CRITICAL};
+
+#[derive(Clone)]
+pub struct LogCritical;
+
+impl Command for LogCritical {
+    fn name(&self) -> &str {
+        "log critical"
+    }
+
+    fn signature(&self) -> Signature {
+        log_signature(self.name())
+    }
+
+    fn usage(&self) -> &str {
+        "Log a message at the critical level."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["logging", "fatal"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        run_log(&CRITICAL, engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Log a critical message",
+            example: r#"log critical "disk full, cannot continue""#,
+            result: None,
+        }]
+    }
+}
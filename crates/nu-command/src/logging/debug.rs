@@ -0,0 +1,44 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Example, PipelineData, ShellError, Signature};
+
+use super::{log_signature, run_log, DEBUG};
+
+#[derive(Clone)]
+pub struct LogDebug;
+
+impl Command for LogDebug {
+    fn name(&self) -> &str {
+        "log debug"
+    }
+
+    fn signature(&self) -> Signature {
+        log_signature(self.name())
+    }
+
+    fn usage(&self) -> &str {
+        "Log a message at the debug level."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["logging", "trace"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        run_log(&DEBUG, engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Log a debug message, tagged with a module for filtering",
+            example: r#"log debug --module net "got response""#,
+            result: None,
+        }]
+    }
+}
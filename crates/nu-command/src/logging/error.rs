@@ -0,0 +1,44 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Example, PipelineData, ShellError, Signature};
+
+use super::{log_signature, run_log, ERROR};
+
+#[derive(Clone)]
+pub struct LogError;
+
+impl Command for LogError {
+    fn name(&self) -> &str {
+        "log error"
+    }
+
+    fn signature(&self) -> Signature {
+        log_signature(self.name())
+    }
+
+    fn usage(&self) -> &str {
+        "Log a message at the error level."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["logging", "failure"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        run_log(&ERROR, engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Log an error message",
+            example: r#"log error "upload failed""#,
+            result: None,
+        }]
+    }
+}
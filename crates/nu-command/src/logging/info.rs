@@ -0,0 +1,44 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Example, PipelineData, ShellError, Signature};
+
+use super::{log_signature, run_log, INFO};
+
+#[derive(Clone)]
+pub struct LogInfo;
+
+impl Command for LogInfo {
+    fn name(&self) -> &str {
+        "log info"
+    }
+
+    fn signature(&self) -> Signature {
+        log_signature(self.name())
+    }
+
+    fn usage(&self) -> &str {
+        "Log a message at the info level."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["logging"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        run_log(&INFO, engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Log an informational message",
+            example: r#"log info "starting the backup""#,
+            result: None,
+        }]
+    }
+}
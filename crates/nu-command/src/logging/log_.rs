@@ -0,0 +1,59 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Log;
+
+impl Command for Log {
+    fn name(&self) -> &str {
+        "log"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("log")
+            .category(Category::Debug)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn usage(&self) -> &str {
+        "Log messages from scripts with levels, targets and timestamps."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Logging is controlled with environment variables:
+  NU_LOG_LEVEL    the lowest level that is written (CRITICAL, ERROR, WARNING, INFO
+                  or DEBUG, or a number; INFO by default)
+  NU_LOG_MODULES  when set (comma-separated), only messages tagged with one of these
+                  modules via --module are written
+  NU_LOG_TARGET   'stderr' (default) or 'file'
+  NU_LOG_FILE     the file appended to when NU_LOG_TARGET is 'file'
+  NU_LOG_FORMAT   'text' (default) or 'ndjson'
+
+You must use one of the following subcommands. Using this command as-is will only
+produce this help message."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &Log.signature(),
+                &Log.examples(),
+                engine_state,
+                stack,
+                self.is_parser_keyword(),
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
@@ -0,0 +1,195 @@
+mod critical;
+mod debug;
+mod error;
+mod info;
+mod log_;
+mod warning;
+
+pub use critical::LogCritical;
+pub use debug::LogDebug;
+pub use error::LogError;
+pub use info::LogInfo;
+pub use log_::Log;
+pub use warning::LogWarning;
+
+use nu_ansi_term::{Color, Style};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{PipelineData, ShellError, Signature, SyntaxShape, Type};
+use std::io::Write;
+
+/// One severity level of the `log` family.
+pub(crate) struct LogLevel {
+    pub name: &'static str,
+    pub abbr: &'static str,
+    pub severity: i64,
+}
+
+impl LogLevel {
+    fn style(&self) -> Style {
+        match self.abbr {
+            "CRT" => Style::new().fg(Color::Red).bold(),
+            "ERR" => Style::new().fg(Color::Red),
+            "WRN" => Style::new().fg(Color::Yellow),
+            "DBG" => Style::new().dimmed(),
+            _ => Style::new(),
+        }
+    }
+}
+
+pub(crate) const CRITICAL: LogLevel = LogLevel {
+    name: "CRITICAL",
+    abbr: "CRT",
+    severity: 50,
+};
+
+pub(crate) const ERROR: LogLevel = LogLevel {
+    name: "ERROR",
+    abbr: "ERR",
+    severity: 40,
+};
+
+pub(crate) const WARNING: LogLevel = LogLevel {
+    name: "WARNING",
+    abbr: "WRN",
+    severity: 30,
+};
+
+pub(crate) const INFO: LogLevel = LogLevel {
+    name: "INFO",
+    abbr: "INF",
+    severity: 20,
+};
+
+pub(crate) const DEBUG: LogLevel = LogLevel {
+    name: "DEBUG",
+    abbr: "DBG",
+    severity: 10,
+};
+
+/// The signature shared by the `log <level>` commands.
+pub(crate) fn log_signature(name: &str) -> Signature {
+    Signature::build(name)
+        .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+        .required("message", SyntaxShape::String, "the message to log")
+        .named(
+            "module",
+            SyntaxShape::String,
+            "the module the message belongs to, for filtering with NU_LOG_MODULES",
+            None,
+        )
+        .category(nu_protocol::Category::Debug)
+}
+
+/// The shared `run` of the `log <level>` commands: filter by `NU_LOG_LEVEL` and
+/// `NU_LOG_MODULES`, then write the message to the target selected by `NU_LOG_TARGET`
+/// (stderr or file) in the format selected by `NU_LOG_FORMAT` (text or ndjson).
+pub(crate) fn run_log(
+    level: &LogLevel,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    let message: String = call.req(engine_state, stack, 0)?;
+    let module: Option<String> = call.get_flag(engine_state, stack, "module")?;
+
+    if current_log_level(engine_state, stack) > level.severity {
+        return Ok(PipelineData::empty());
+    }
+
+    // When NU_LOG_MODULES is set, messages tagged with a module not on the list are
+    // dropped; untagged messages always pass
+    if let (Some(module), Some(value)) =
+        (&module, stack.get_env_var(engine_state, "NU_LOG_MODULES"))
+    {
+        if let Ok(modules) = value.as_string() {
+            if !modules.split(',').any(|m| m.trim() == module) {
+                return Ok(PipelineData::empty());
+            }
+        }
+    }
+
+    let timestamp = chrono::Local::now()
+        .format("%Y-%m-%dT%H:%M:%S%.3f")
+        .to_string();
+
+    let format = env_string(engine_state, stack, "NU_LOG_FORMAT");
+    let line = if format.as_deref() == Some("ndjson") {
+        let mut entry = serde_json::json!({
+            "timestamp": timestamp,
+            "level": level.name,
+            "message": message,
+        });
+        if let Some(module) = &module {
+            entry["module"] = serde_json::Value::String(module.clone());
+        }
+        entry.to_string()
+    } else {
+        match &module {
+            Some(module) => format!("{}|{timestamp}|{module}|{message}", level.abbr),
+            None => format!("{}|{timestamp}|{message}", level.abbr),
+        }
+    };
+
+    let target = env_string(engine_state, stack, "NU_LOG_TARGET");
+    if target.as_deref() == Some("file") {
+        let file = env_string(engine_state, stack, "NU_LOG_FILE").ok_or_else(|| {
+            ShellError::GenericError(
+                "NU_LOG_FILE is not set".into(),
+                "NU_LOG_TARGET is 'file', which needs NU_LOG_FILE to point at the log file".into(),
+                Some(call.head),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&file)
+            .and_then(|mut f| writeln!(f, "{line}"))
+            .map_err(|err| {
+                ShellError::GenericError(
+                    format!("Cannot write the log to {file}"),
+                    err.to_string(),
+                    Some(call.head),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+    } else if engine_state.get_config().use_ansi_coloring && format.as_deref() != Some("ndjson") {
+        eprintln!("{}", level.style().paint(line));
+    } else {
+        eprintln!("{line}");
+    }
+
+    Ok(PipelineData::empty())
+}
+
+fn env_string(engine_state: &EngineState, stack: &Stack, name: &str) -> Option<String> {
+    stack
+        .get_env_var(engine_state, name)
+        .and_then(|value| value.as_string().ok())
+}
+
+fn current_log_level(engine_state: &EngineState, stack: &Stack) -> i64 {
+    match stack.get_env_var(engine_state, "NU_LOG_LEVEL") {
+        Some(value) => {
+            if let Ok(level) = value.as_i64() {
+                level
+            } else if let Ok(level) = value.as_string() {
+                match level.to_uppercase().as_str() {
+                    "CRITICAL" | "CRIT" => CRITICAL.severity,
+                    "ERROR" => ERROR.severity,
+                    "WARNING" | "WARN" => WARNING.severity,
+                    "DEBUG" => DEBUG.severity,
+                    _ => INFO.severity,
+                }
+            } else {
+                INFO.severity
+            }
+        }
+        None => INFO.severity,
+    }
+}
@@ -0,0 +1,44 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Example, PipelineData, ShellError, Signature};
+
+use super::{log_signature, run_log, WARNING};
+
+#[derive(Clone)]
+pub struct LogWarning;
+
+impl Command for LogWarning {
+    fn name(&self) -> &str {
+        "log warning"
+    }
+
+    fn signature(&self) -> Signature {
+        log_signature(self.name())
+    }
+
+    fn usage(&self) -> &str {
+        "Log a message at the warning level."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["logging", "caution"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        run_log(&WARNING, engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Log a warning message",
+            example: r#"log warning "retrying the request""#,
+            result: None,
+        }]
+    }
+}
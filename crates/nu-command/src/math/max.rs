@@ -3,6 +3,7 @@ use crate::math::utils::run_with_function;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -50,7 +51,7 @@ impl Command for SubCommand {
                 description: "Find the maxima of the columns of a table",
                 example: "[{a: 1 b: 3} {a: 2 b: -1}] | math max",
                 result: Some(Value::Record {
-                    cols: vec!["a".to_string(), "b".to_string()],
+                    cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                     vals: vec![Value::test_int(2), Value::test_int(3)],
                     span: Span::test_data(),
                 }),
@@ -2,9 +2,13 @@ use std::cmp::Ordering;
 
 use crate::math::avg::average;
 use crate::math::utils::run_with_function;
+use crate::{QuantileSketch, DEFAULT_SKETCH_BINS};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -20,6 +24,11 @@ impl Command for SubCommand {
                 (Type::List(Box::new(Type::Number)), Type::Number),
                 (Type::Table(vec![]), Type::Record(vec![])),
             ])
+            .switch(
+                "approx",
+                "estimate the median with a constant-memory streaming sketch, so unbounded streams don't require buffering everything",
+                None,
+            )
             .category(Category::Math)
     }
 
@@ -38,6 +47,9 @@ impl Command for SubCommand {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
+        if call.has_flag("approx") {
+            return approx_median(call, input);
+        }
         run_with_function(call, input, median)
     }
 
@@ -48,11 +60,16 @@ impl Command for SubCommand {
                 example: "[3 8 9 12 12 15] | math median",
                 result: Some(Value::test_float(10.5)),
             },
+            Example {
+                description: "Estimate the median of a long stream without buffering it",
+                example: "[3 8 9 12 12 15] | math median --approx",
+                result: Some(Value::test_float(10.5)),
+            },
             Example {
                 description: "Compute the medians of the columns of a table",
                 example: "[{a: 1 b: 3} {a: 2 b: -1} {a: -3 b: 5}] | math median",
                 result: Some(Value::Record {
-                    cols: vec!["a".to_string(), "b".to_string()],
+                    cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                     vals: vec![Value::test_int(1), Value::test_int(3)],
                     span: Span::test_data(),
                 }),
@@ -61,6 +78,74 @@ impl Command for SubCommand {
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum ApproxKind {
+    Number,
+    Filesize,
+    Duration,
+}
+
+/// Streaming median estimate: every value is folded into a bounded sketch as
+/// it arrives, so memory stays constant no matter how long the input is.
+fn approx_median(call: &Call, input: PipelineData) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let mut sketch = QuantileSketch::new(DEFAULT_SKETCH_BINS);
+    let mut kind = None;
+
+    for value in input.into_iter() {
+        let (val, value_kind) = match &value {
+            Value::Int { val, .. } => (*val as f64, ApproxKind::Number),
+            Value::Float { val, .. } => (*val, ApproxKind::Number),
+            Value::Filesize { val, .. } => (*val as f64, ApproxKind::Filesize),
+            Value::Duration { val, .. } => (*val as f64, ApproxKind::Duration),
+            Value::Error { error } => return Err(*error.clone()),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    "Only numbers, filesizes, and durations are supported with --approx"
+                        .to_string(),
+                    "value originates from here".into(),
+                    head,
+                    other.expect_span(),
+                ))
+            }
+        };
+        match kind {
+            None => kind = Some(value_kind),
+            Some(k) if k == value_kind => {}
+            Some(_) => {
+                return Err(ShellError::UnsupportedInput(
+                    "--approx can't mix numbers with filesizes or durations".to_string(),
+                    "value originates from here".into(),
+                    head,
+                    value.expect_span(),
+                ))
+            }
+        }
+        sketch.push(val);
+    }
+
+    let estimate = sketch
+        .quantile(0.5)
+        .ok_or(ShellError::PipelineEmpty { dst_span: head })?;
+
+    let result = match kind.expect("sketch was not empty") {
+        ApproxKind::Number => Value::Float {
+            val: estimate,
+            span: head,
+        },
+        ApproxKind::Filesize => Value::Filesize {
+            val: estimate.round() as i64,
+            span: head,
+        },
+        ApproxKind::Duration => Value::Duration {
+            val: estimate.round() as i64,
+            span: head,
+        },
+    };
+
+    Ok(result.into_pipeline_data())
+}
+
 enum Pick {
     MedianAverage,
     Median,
@@ -94,7 +94,10 @@ pub fn sum(data: Vec<Value>, span: Span, head: Span) -> Result<Value, ShellError
             val: 0,
             span: *span,
         }),
-        Some(Value::Int { span, .. }) | Some(Value::Float { span, .. }) => Ok(Value::int(0, *span)),
+        Some(Value::Int { span, .. })
+        | Some(Value::Float { span, .. })
+        | Some(Value::Decimal { span, .. })
+        | Some(Value::BigInt { span, .. }) => Ok(Value::int(0, *span)),
         None => Err(ShellError::UnsupportedInput(
             "Empty input".to_string(),
             "value originates from here".into(),
@@ -108,6 +111,8 @@ pub fn sum(data: Vec<Value>, span: Span, head: Span) -> Result<Value, ShellError
         match value {
             Value::Int { .. }
             | Value::Float { .. }
+            | Value::Decimal { .. }
+            | Value::BigInt { .. }
             | Value::Filesize { .. }
             | Value::Duration { .. } => {
                 acc = acc.add(head, value, head)?;
@@ -130,7 +135,10 @@ pub fn product(data: Vec<Value>, span: Span, head: Span) -> Result<Value, ShellE
     let initial_value = data.get(0);
 
     let mut acc = match initial_value {
-        Some(Value::Int { span, .. }) | Some(Value::Float { span, .. }) => Ok(Value::int(1, *span)),
+        Some(Value::Int { span, .. })
+        | Some(Value::Float { span, .. })
+        | Some(Value::Decimal { span, .. })
+        | Some(Value::BigInt { span, .. }) => Ok(Value::int(1, *span)),
         None => Err(ShellError::UnsupportedInput(
             "Empty input".to_string(),
             "value originates from here".into(),
@@ -142,7 +150,10 @@ pub fn product(data: Vec<Value>, span: Span, head: Span) -> Result<Value, ShellE
 
     for value in &data {
         match value {
-            Value::Int { .. } | Value::Float { .. } => {
+            Value::Int { .. }
+            | Value::Float { .. }
+            | Value::Decimal { .. }
+            | Value::BigInt { .. } => {
                 acc = acc.mul(head, value, head)?;
             }
             Value::Error { error } => return Err(*error.clone()),
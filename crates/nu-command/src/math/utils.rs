@@ -24,20 +24,31 @@ fn helper_for_tables(
     // If we are not dealing with Primitives, then perhaps we are dealing with a table
     // Create a key for each column name
     let mut column_values = IndexMap::new();
-    for val in values {
-        match val {
-            Value::Record { cols, vals, .. } => {
-                for (key, value) in cols.iter().zip(vals.iter()) {
-                    column_values
-                        .entry(key.clone())
-                        .and_modify(|v: &mut Vec<Value>| v.push(value.clone()))
-                        .or_insert_with(|| vec![value.clone()]);
-                }
+    // Fast path: a homogeneous table transposes wholesale into its columns,
+    // instead of growing a map entry per cell
+    if matches!(values.first(), Some(Value::Record { .. })) {
+        if let Ok(table) = crate::ColumnarTable::try_from_rows(values.to_vec(), val_span) {
+            for (col_name, col_vals) in table.into_columns() {
+                column_values.insert(col_name, col_vals);
             }
-            Value::Error { error } => return Err(*error.clone()),
-            _ => {
-                //Turns out we are not dealing with a table
-                return mf(values, val.expect_span(), &name);
+        }
+    }
+    if column_values.is_empty() {
+        for val in values {
+            match val {
+                Value::Record { cols, vals, .. } => {
+                    for (key, value) in cols.iter().zip(vals.iter()) {
+                        column_values
+                            .entry(key.clone())
+                            .and_modify(|v: &mut Vec<Value>| v.push(value.clone()))
+                            .or_insert_with(|| vec![value.clone()]);
+                    }
+                }
+                Value::Error { error } => return Err(*error.clone()),
+                _ => {
+                    //Turns out we are not dealing with a table
+                    return mf(values, val.expect_span(), &name);
+                }
             }
         }
     }
@@ -0,0 +1,178 @@
+use nu_engine::{eval_block_with_early_return, CallExt};
+use nu_protocol::{
+    ast::Call,
+    engine::{Closure, Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, LazyRecord, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone)]
+pub struct LazyMake;
+
+impl Command for LazyMake {
+    fn name(&self) -> &str {
+        "lazy make"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("lazy make")
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .required_named(
+                "columns",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "Closure that gets called when the LazyRecord needs to list the available column names",
+                Some('c')
+            )
+            .required_named(
+                "get-value",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::String])),
+                "Closure to call when a value needs to be produced on demand",
+                Some('g')
+            )
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "Create a lazy record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Lazy records are like regular records, but their values are computed on-demand instead of when the record is created. This is useful when fields are expensive to compute and the consumer may only look at some of them."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["deferred", "record", "memoize"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let columns: Vec<Spanned<String>> = call
+            .get_flag(engine_state, stack, "columns")?
+            .expect("required flag");
+        let get_value: Closure = call
+            .get_flag(engine_state, stack, "get-value")?
+            .expect("required flag");
+
+        let mut seen = vec![];
+        for column in &columns {
+            if seen.contains(&&column.item) {
+                return Err(ShellError::ColumnDefinedTwice {
+                    second_use: column.span,
+                    first_use: columns[seen
+                        .iter()
+                        .position(|seen| *seen == &column.item)
+                        .expect("column is in seen")]
+                    .span,
+                });
+            }
+            seen.push(&column.item);
+        }
+
+        Ok(Value::LazyRecord {
+            val: Box::new(NuLazyRecord {
+                engine_state: engine_state.clone(),
+                stack: stack.clone(),
+                columns: columns.into_iter().map(|spanned| spanned.item).collect(),
+                get_value,
+                span,
+            }),
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Create a lazy record",
+                example: r#"lazy make --columns ["haskell", "futures", "nushell"] --get-value { |lazything| $lazything + "!" }"#,
+                result: None,
+            },
+            Example {
+                description: "Test the laziness of lazy records",
+                example: r#"lazy make --columns ["hello"] --get-value { |key| print $"getting ($key)!"; $key | str upcase }"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+// Note: NuLazyRecord is not meaningfully serializable, this #[derive] is a lie to satisfy the
+// type checker. Make sure to collect() the record before serializing it.
+#[derive(Serialize, Deserialize)]
+struct NuLazyRecord {
+    #[serde(skip)]
+    engine_state: EngineState,
+    #[serde(skip)]
+    stack: Stack,
+    columns: Vec<String>,
+    #[serde(skip, default = "default_closure")]
+    get_value: Closure,
+    span: Span,
+}
+
+fn default_closure() -> Closure {
+    Closure {
+        block_id: 0,
+        captures: std::collections::HashMap::new(),
+    }
+}
+
+impl LazyRecord for NuLazyRecord {
+    fn column_names(&self) -> Vec<String> {
+        self.columns.clone()
+    }
+
+    fn get_column_value(&self, column: &str) -> Result<Value, ShellError> {
+        let block = self.engine_state.get_block(self.get_value.block_id);
+        let mut stack = self.stack.captures_to_stack(&self.get_value.captures);
+        let column_value = Value::string(column, self.span);
+
+        if let Some(var) = block.signature.get_positional(0) {
+            if let Some(var_id) = &var.var_id {
+                stack.add_var(*var_id, column_value.clone());
+            }
+        }
+
+        let pipeline_result = eval_block_with_early_return(
+            &self.engine_state,
+            &mut stack,
+            block,
+            PipelineData::Value(column_value, None),
+            false,
+            false,
+        )?;
+
+        Ok(pipeline_result.into_value(self.span))
+    }
+
+    fn span(&self) -> Span {
+        self.span
+    }
+
+    fn typetag_name(&self) -> &'static str {
+        "lazy_record"
+    }
+
+    fn typetag_deserialize(&self) {
+        unimplemented!("typetag_deserialize")
+    }
+}
+
+// manually implemented so we can skip engine_state which doesn't implement Debug
+impl fmt::Debug for NuLazyRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NuLazyRecord")
+            .field("columns", &self.columns)
+            .field("span", &self.span)
+            .finish_non_exhaustive()
+    }
+}
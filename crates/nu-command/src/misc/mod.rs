@@ -1,3 +1,5 @@
+mod lazy_make;
 mod tutor;
 
+pub use lazy_make::LazyMake;
 pub use tutor::Tutor;
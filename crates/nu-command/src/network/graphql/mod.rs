@@ -0,0 +1,5 @@
+mod graphql_;
+mod query;
+
+pub use graphql_::Graphql;
+pub use query::SubCommand as GraphqlQuery;
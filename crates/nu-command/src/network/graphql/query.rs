@@ -0,0 +1,318 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+use crate::network::http::client::{
+    http_client, request_add_authorization_header, request_add_custom_headers, send_request,
+    RedirectMode, ShellErrorOrRequestError,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "graphql query"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("graphql query")
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .required("URL", SyntaxShape::String, "the GraphQL endpoint")
+            .required_named("query", SyntaxShape::String, "the GraphQL query", Some('q'))
+            .named(
+                "variables",
+                SyntaxShape::Any,
+                "a record of query variables",
+                Some('v'),
+            )
+            .named(
+                "bearer",
+                SyntaxShape::String,
+                "the bearer token when authenticating",
+                None,
+            )
+            .named(
+                "headers",
+                SyntaxShape::Any,
+                "custom headers you want to add ",
+                Some('H'),
+            )
+            .named(
+                "paginate",
+                SyntaxShape::String,
+                "follow cursors through the connection at this dotted path in the data, collecting all nodes",
+                None,
+            )
+            .named(
+                "cursor-var",
+                SyntaxShape::String,
+                "the query variable that receives the cursor while paginating (defaults to 'cursor')",
+                None,
+            )
+            .named(
+                "max-pages",
+                SyntaxShape::Int,
+                "stop paginating after this many pages",
+                None,
+            )
+            .switch(
+                "insecure",
+                "allow insecure server connections when using SSL",
+                Some('k'),
+            )
+            .filter()
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Run a GraphQL query and return the data payload as structured values."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The query and variables are shaped into the standard GraphQL POST
+body; errors in the response are turned into shell errors instead of
+being left for the caller to dig out.
+
+With --paginate the named field must be a connection with `nodes` (or
+`edges`) and `pageInfo { hasNextPage endCursor }`, and the query must
+accept the cursor variable; the command keeps requesting pages until
+`hasNextPage` is false and returns all collected entries."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["network", "api", "request", "cursor"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let url: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let query: String = call
+            .get_flag(engine_state, stack, "query")?
+            .expect("required flag");
+        let variables: Option<Value> = call.get_flag(engine_state, stack, "variables")?;
+        let bearer: Option<String> = call.get_flag(engine_state, stack, "bearer")?;
+        let headers: Option<Value> = call.get_flag(engine_state, stack, "headers")?;
+        let paginate: Option<String> = call.get_flag(engine_state, stack, "paginate")?;
+        let cursor_var: Option<String> = call.get_flag(engine_state, stack, "cursor-var")?;
+        let max_pages: Option<i64> = call.get_flag(engine_state, stack, "max-pages")?;
+        let insecure = call.has_flag("insecure");
+
+        let variables = match variables {
+            Some(Value::Record { cols, vals, .. }) => (cols.to_vec(), vals),
+            Some(other) => {
+                return Err(ShellError::TypeMismatch {
+                    err_message: "expected a record of query variables".to_string(),
+                    span: other.expect_span(),
+                })
+            }
+            None => (vec![], vec![]),
+        };
+
+        let request = RequestSetup {
+            url: url.item,
+            query,
+            bearer,
+            headers,
+            insecure,
+            span: head,
+        };
+
+        let Some(path) = paginate else {
+            let data = execute(engine_state, &request, &variables)?;
+            return Ok(data.into_pipeline_data());
+        };
+
+        let cursor_var = cursor_var.unwrap_or_else(|| "cursor".to_string());
+        let max_pages = max_pages.map(|n| n.max(0) as usize).unwrap_or(usize::MAX);
+        let mut collected = Vec::new();
+        let mut cursor: Option<String> = None;
+        let mut pages = 0;
+
+        while pages < max_pages {
+            if nu_utils::ctrl_c::was_pressed(&engine_state.ctrlc) {
+                return Err(ShellError::InterruptedByUser { span: Some(head) });
+            }
+            let mut variables = variables.clone();
+            if let Some(cursor) = &cursor {
+                set_variable(&mut variables, &cursor_var, Value::string(cursor, head));
+            }
+            let data = execute(engine_state, &request, &variables)?;
+            let connection = follow_path(&data, &path, head)?;
+            let entries = connection
+                .get_data_by_key("nodes")
+                .or_else(|| connection.get_data_by_key("edges"))
+                .ok_or_else(|| {
+                    graphql_error(
+                        format!("'{path}' has neither 'nodes' nor 'edges' to collect"),
+                        head,
+                    )
+                })?;
+            collected.extend(entries.as_list()?.iter().cloned());
+            pages += 1;
+
+            let page_info = connection.get_data_by_key("pageInfo").ok_or_else(|| {
+                graphql_error(format!("'{path}' has no 'pageInfo' to paginate with"), head)
+            })?;
+            let has_next = matches!(
+                page_info.get_data_by_key("hasNextPage"),
+                Some(Value::Bool { val: true, .. })
+            );
+            cursor = match page_info.get_data_by_key("endCursor") {
+                Some(Value::String { val, .. }) => Some(val),
+                _ => None,
+            };
+            if !has_next || cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(Value::list(collected, head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: r#"graphql query https://api.example.com/graphql -q '{ viewer { login } }'"#,
+                description: "Run a query and get the data payload back",
+                result: None,
+            },
+            Example {
+                example: r#"graphql query https://api.example.com/graphql -q 'query ($name: String!) { user(login: $name) { id } }' -v {name: octocat}"#,
+                description: "Pass query variables as a record",
+                result: None,
+            },
+            Example {
+                example: r#"graphql query https://api.example.com/graphql -q 'query ($cursor: String) { items(first: 100, after: $cursor) { nodes { id } pageInfo { hasNextPage endCursor } } }' --paginate items"#,
+                description: "Collect every node by following the connection's cursors",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct RequestSetup {
+    url: String,
+    query: String,
+    bearer: Option<String>,
+    headers: Option<Value>,
+    insecure: bool,
+    span: Span,
+}
+
+fn graphql_error(msg: impl Into<String>, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "GraphQL error".into(),
+        msg.into(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+fn set_variable(variables: &mut (Vec<String>, Vec<Value>), name: &str, value: Value) {
+    if let Some(at) = variables.0.iter().position(|col| col == name) {
+        variables.1[at] = value;
+    } else {
+        variables.0.push(name.to_string());
+        variables.1.push(value);
+    }
+}
+
+fn follow_path(data: &Value, path: &str, span: Span) -> Result<Value, ShellError> {
+    let mut current = data.clone();
+    for part in path.split('.') {
+        current = current.get_data_by_key(part).ok_or_else(|| {
+            graphql_error(
+                format!("the data has no '{part}' field (from '{path}')"),
+                span,
+            )
+        })?;
+    }
+    Ok(current)
+}
+
+/// One round trip: POST the query, unwrap errors, return the data payload.
+fn execute(
+    engine_state: &EngineState,
+    setup: &RequestSetup,
+    variables: &(Vec<String>, Vec<Value>),
+) -> Result<Value, ShellError> {
+    let span = setup.span;
+    let body = Value::record(
+        vec!["query".into(), "variables".into()],
+        vec![
+            Value::string(&setup.query, span),
+            Value::record(variables.0.clone(), variables.1.clone(), span),
+        ],
+        span,
+    );
+
+    let client = http_client(setup.insecure, RedirectMode::Follow);
+    let mut request = client.post(&setup.url).set("Accept", "application/json");
+    request = request_add_authorization_header(None, None, setup.bearer.clone(), request);
+    request = request_add_custom_headers(setup.headers.clone(), request)?;
+
+    let response = send_request(
+        request,
+        Some(body),
+        Some("application/json".to_string()),
+        engine_state.ctrlc.clone(),
+    );
+    // GraphQL endpoints answer errors as JSON too, often with a 4xx status
+    let body = match response {
+        Ok(response) => response.into_string(),
+        Err(ShellErrorOrRequestError::RequestError(_, ureq::Error::Status(_, response))) => {
+            response.into_string()
+        }
+        Err(ShellErrorOrRequestError::RequestError(url, err)) => {
+            return Err(ShellError::NetworkFailure(
+                format!("Cannot make request to {url}: {err}"),
+                span,
+            ))
+        }
+        Err(ShellErrorOrRequestError::ShellError(err)) => return Err(err),
+    }
+    .map_err(|err| ShellError::NetworkFailure(err.to_string(), span))?;
+
+    let response = crate::formats::convert_string_to_value(body, span)?;
+    if let Some(errors) = response.get_data_by_key("errors") {
+        let messages = errors
+            .as_list()?
+            .iter()
+            .map(|error| match error.get_data_by_key("message") {
+                Some(message) => message.as_string(),
+                None => error.as_string(),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if !messages.is_empty() {
+            return Err(graphql_error(messages.join("; "), span));
+        }
+    }
+    response
+        .get_data_by_key("data")
+        .ok_or_else(|| graphql_error("the response has no data payload", span))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
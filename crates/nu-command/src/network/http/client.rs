@@ -5,17 +5,20 @@ use base64::{alphabet, Engine};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{EngineState, Stack};
 use nu_protocol::{
-    BufferedReader, IntoPipelineData, PipelineData, RawStream, ShellError, Span, Value,
+    BufferedReader, IntoPipelineData, LazyRecord, PipelineData, RawStream, ShellError, Span,
+    Spanned, Value,
 };
+use serde::{Deserialize, Serialize};
 use ureq::{Error, ErrorKind, Request, Response};
 
+use flate2::read::{MultiGzDecoder, ZlibDecoder};
 use std::collections::HashMap;
-use std::io::BufReader;
+use std::io::{BufReader, Read};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::atomic::AtomicBool;
 use std::sync::mpsc::{self, RecvTimeoutError};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use url::Url;
 
@@ -26,9 +29,31 @@ pub enum BodyType {
     Unknown,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RedirectMode {
+    Follow,
+    Manual,
+    Error,
+}
+
+pub fn parse_redirect_mode(flag: Option<Spanned<String>>) -> Result<RedirectMode, ShellError> {
+    match flag {
+        None => Ok(RedirectMode::Follow),
+        Some(mode) => match mode.item.as_str() {
+            "follow" | "f" => Ok(RedirectMode::Follow),
+            "manual" | "m" => Ok(RedirectMode::Manual),
+            "error" | "e" => Ok(RedirectMode::Error),
+            _ => Err(ShellError::TypeMismatch {
+                err_message: "expected one of 'follow', 'manual', or 'error'".to_string(),
+                span: mode.span,
+            }),
+        },
+    }
+}
+
 // Only panics if the user agent is invalid but we define it statically so either
 // it always or never fails
-pub fn http_client(allow_insecure: bool) -> ureq::Agent {
+pub fn http_client(allow_insecure: bool, redirect: RedirectMode) -> ureq::Agent {
     let tls = native_tls::TlsConnector::builder()
         .danger_accept_invalid_certs(allow_insecure)
         .build()
@@ -37,9 +62,37 @@ pub fn http_client(allow_insecure: bool) -> ureq::Agent {
     ureq::builder()
         .user_agent("nushell")
         .tls_connector(std::sync::Arc::new(tls))
+        .redirects(if redirect == RedirectMode::Follow {
+            5
+        } else {
+            0
+        })
         .build()
 }
 
+/// With 'manual' the redirect response passes through untouched; with 'error'
+/// it is turned into a hard failure.
+pub fn check_response_redirection(
+    redirect_mode: RedirectMode,
+    span: Span,
+    response: &Result<Response, ShellErrorOrRequestError>,
+) -> Result<(), ShellError> {
+    if let (RedirectMode::Error, Ok(resp)) = (redirect_mode, response) {
+        if (300..400).contains(&resp.status()) {
+            return Err(ShellError::NetworkFailure(
+                format!(
+                    "Redirect ({} {}) to {:?}",
+                    resp.status(),
+                    resp.status_text(),
+                    resp.header("location").unwrap_or("")
+                ),
+                span,
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub fn http_parse_url(
     call: &Call,
     span: Span,
@@ -62,10 +115,32 @@ pub fn http_parse_url(
     Ok((requested_url, url))
 }
 
+// Wraps the response body reader so a progress bar follows how much of the
+// body has actually been consumed by the rest of the pipeline.
+struct ProgressReader<R: Read> {
+    reader: R,
+    bar: crate::progress_bar::NuProgressBar,
+    bytes_read: u64,
+}
+
+impl<R: Read> Read for ProgressReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let bytes = self.reader.read(buf)?;
+        if bytes == 0 {
+            self.bar.pb.finish_and_clear();
+        } else {
+            self.bytes_read += bytes as u64;
+            self.bar.update_bar(self.bytes_read);
+        }
+        Ok(bytes)
+    }
+}
+
 pub fn response_to_buffer(
     response: Response,
     engine_state: &EngineState,
     span: Span,
+    progress: bool,
 ) -> PipelineData {
     // Try to get the size of the file to be downloaded.
     // This is helpful to show the progress of the stream.
@@ -82,7 +157,27 @@ pub fn response_to_buffer(
         _ => None,
     };
 
-    let reader = response.into_reader();
+    let content_encoding = response
+        .header("content-encoding")
+        .map(|encoding| encoding.to_lowercase());
+
+    let reader: Box<dyn Read + Send + Sync> = if progress {
+        Box::new(ProgressReader {
+            reader: response.into_reader(),
+            bar: crate::progress_bar::NuProgressBar::new(buffer_size),
+            bytes_read: 0,
+        })
+    } else {
+        Box::new(response.into_reader())
+    };
+    // The content length describes the compressed body, so it no longer
+    // matches once the stream is decompressed
+    let (reader, buffer_size): (Box<dyn Read + Send + Sync>, Option<u64>) =
+        match content_encoding.as_deref() {
+            Some("gzip") | Some("x-gzip") => (Box::new(MultiGzDecoder::new(reader)), None),
+            Some("deflate") => (Box::new(ZlibDecoder::new(reader)), None),
+            _ => (reader, buffer_size),
+        };
     let buffered_input = BufReader::new(reader);
 
     PipelineData::ExternalStream {
@@ -105,8 +200,14 @@ pub fn response_to_buffer(
 pub fn request_add_authorization_header(
     user: Option<String>,
     password: Option<String>,
+    bearer: Option<String>,
     mut request: Request,
 ) -> Request {
+    // An explicit bearer token wins over basic credentials
+    if let Some(token) = bearer {
+        return request.set("Authorization", &format!("Bearer {token}"));
+    }
+
     let base64_engine = GeneralPurpose::new(&alphabet::STANDARD, PAD);
 
     let login = match (user, password) {
@@ -148,11 +249,16 @@ impl From<ShellError> for ShellErrorOrRequestError {
 }
 
 pub fn send_request(
-    request: Request,
+    mut request: Request,
     body: Option<Value>,
     content_type: Option<String>,
     ctrl_c: Option<Arc<AtomicBool>>,
 ) -> Result<Response, ShellErrorOrRequestError> {
+    // Advertise the encodings response_to_buffer can undo, unless the
+    // caller asked for something else explicitly
+    if request.header("accept-encoding").is_none() {
+        request = request.set("Accept-Encoding", "gzip, deflate");
+    }
     let request_url = request.url().to_string();
     if body.is_none() {
         return send_cancellable_request(&request_url, Box::new(|| request.call()), ctrl_c);
@@ -383,6 +489,7 @@ pub struct RequestFlags {
     pub allow_errors: bool,
     pub raw: bool,
     pub full: bool,
+    pub progress: bool,
 }
 
 #[allow(clippy::needless_return)]
@@ -429,7 +536,7 @@ fn transform_response_using_content_type(
         _ => Some(content_type.subtype().to_string()),
     };
 
-    let output = response_to_buffer(resp, engine_state, span);
+    let output = response_to_buffer(resp, engine_state, span, flags.progress);
     if flags.raw {
         return Ok(output);
     } else if let Some(ext) = ext {
@@ -474,23 +581,21 @@ fn request_handle_response_content(
             resp,
             &content_type,
         ),
-        None => Ok(response_to_buffer(resp, engine_state, span)),
+        None => Ok(response_to_buffer(resp, engine_state, span, flags.progress)),
     };
     if flags.full {
-        let full_response = Value::Record {
-            cols: vec![
-                "headers".to_string(),
-                "body".to_string(),
-                "status".to_string(),
-            ],
-            vals: vec![
-                match response_headers {
+        // The body stays a lazy stream until someone actually asks for it
+        let full_response = Value::LazyRecord {
+            val: Box::new(LazyFullResponse {
+                span,
+                status: response_status as i64,
+                headers: match response_headers {
                     Some(headers) => headers.into_value(span),
                     None => Value::nothing(span),
                 },
-                formatted_content?.into_value(span),
-                Value::int(response_status as i64, span),
-            ],
+                body: Mutex::new(Some(formatted_content?)),
+                collected_body: Mutex::new(None),
+            }),
             span,
         }
         .into_pipeline_data();
@@ -500,6 +605,78 @@ fn request_handle_response_content(
     }
 }
 
+// Note: like other lazy records this is not meaningfully serializable; the
+// #[derive] only satisfies the trait bounds. Collect it before serializing.
+#[derive(Serialize, Deserialize)]
+struct LazyFullResponse {
+    span: Span,
+    status: i64,
+    #[serde(skip, default = "default_value")]
+    headers: Value,
+    #[serde(skip)]
+    body: Mutex<Option<PipelineData>>,
+    #[serde(skip)]
+    collected_body: Mutex<Option<Value>>,
+}
+
+fn default_value() -> Value {
+    Value::nothing(Span::unknown())
+}
+
+impl std::fmt::Debug for LazyFullResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyFullResponse")
+            .field("status", &self.status)
+            .finish()
+    }
+}
+
+impl LazyRecord for LazyFullResponse {
+    fn column_names(&self) -> Vec<String> {
+        vec!["headers".into(), "body".into(), "status".into()]
+    }
+
+    fn get_column_value(&self, column: &str) -> Result<Value, ShellError> {
+        match column {
+            "headers" => Ok(self.headers.clone()),
+            "status" => Ok(Value::int(self.status, self.span)),
+            "body" => {
+                let mut cache = self
+                    .collected_body
+                    .lock()
+                    .expect("response body cache poisoned");
+                if let Some(value) = cache.as_ref() {
+                    return Ok(value.clone());
+                }
+                let body = self.body.lock().expect("response body poisoned").take();
+                let value = match body {
+                    Some(data) => data.into_value(self.span),
+                    None => Value::nothing(self.span),
+                };
+                *cache = Some(value.clone());
+                Ok(value)
+            }
+            _ => Err(ShellError::LazyRecordAccessFailed {
+                message: format!("Could not find column '{column}'"),
+                column_name: column.to_string(),
+                span: self.span,
+            }),
+        }
+    }
+
+    fn span(&self) -> Span {
+        self.span
+    }
+
+    fn typetag_name(&self) -> &'static str {
+        "http_full_response"
+    }
+
+    fn typetag_deserialize(&self) {
+        unimplemented!("typetag_deserialize")
+    }
+}
+
 pub fn request_handle_response(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -562,7 +739,12 @@ pub fn request_handle_response_headers_raw(
         }
     }
 
-    Ok(Value::Record { cols, vals, span }.into_pipeline_data())
+    Ok(Value::Record {
+        cols: Arc::new(cols),
+        vals,
+        span,
+    }
+    .into_pipeline_data())
 }
 
 pub fn request_handle_response_headers(
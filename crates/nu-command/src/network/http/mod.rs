@@ -1,4 +1,4 @@
-mod client;
+pub(crate) mod client;
 mod delete;
 mod get;
 mod head;
@@ -6,6 +6,8 @@ mod http_;
 mod patch;
 mod post;
 mod put;
+mod session;
+mod token;
 
 pub use delete::SubCommand as HttpDelete;
 pub use get::SubCommand as HttpGet;
@@ -14,3 +16,5 @@ pub use http_::Http;
 pub use patch::SubCommand as HttpPatch;
 pub use post::SubCommand as HttpPost;
 pub use put::SubCommand as HttpPut;
+pub use session::SubCommand as HttpSession;
+pub use token::SubCommand as HttpToken;
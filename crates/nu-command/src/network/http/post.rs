@@ -2,15 +2,17 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type, Value,
 };
 
 use crate::network::http::client::{
-    http_client, http_parse_url, request_add_authorization_header, request_add_custom_headers,
-    request_handle_response, request_set_timeout, send_request,
+    check_response_redirection, http_client, http_parse_url, parse_redirect_mode,
+    request_add_authorization_header, request_add_custom_headers, request_handle_response,
+    request_set_timeout, send_request,
 };
 
 use super::client::RequestFlags;
+use super::session::http_session;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -38,6 +40,18 @@ impl Command for SubCommand {
                 "the password when authenticating",
                 Some('p'),
             )
+            .named(
+                "bearer",
+                SyntaxShape::String,
+                "the bearer token when authenticating",
+                None,
+            )
+            .named(
+                "session",
+                SyntaxShape::String,
+                "the named session to use for cookies, default headers, and base URL",
+                None,
+            )
             .named(
                 "content-type",
                 SyntaxShape::Any,
@@ -50,6 +64,12 @@ impl Command for SubCommand {
                 "timeout period in seconds",
                 Some('m'),
             )
+            .named(
+                "redirect-mode",
+                SyntaxShape::String,
+                "what to do when the server redirects: 'follow' (default), 'manual', or 'error'",
+                None,
+            )
             .named(
                 "headers",
                 SyntaxShape::Any,
@@ -137,7 +157,9 @@ struct Arguments {
     insecure: bool,
     user: Option<String>,
     password: Option<String>,
+    bearer: Option<String>,
     timeout: Option<Value>,
+    redirect: Option<Spanned<String>>,
     full: bool,
     allow_errors: bool,
 }
@@ -157,7 +179,9 @@ fn run_post(
         insecure: call.has_flag("insecure"),
         user: call.get_flag(engine_state, stack, "user")?,
         password: call.get_flag(engine_state, stack, "password")?,
+        bearer: call.get_flag(engine_state, stack, "bearer")?,
         timeout: call.get_flag(engine_state, stack, "max-time")?,
+        redirect: call.get_flag(engine_state, stack, "redirect-mode")?,
         full: call.has_flag("full"),
         allow_errors: call.has_flag("allow-errors"),
     };
@@ -175,21 +199,38 @@ fn helper(
 ) -> Result<PipelineData, ShellError> {
     let span = args.url.span()?;
     let ctrl_c = engine_state.ctrlc.clone();
-    let (requested_url, _) = http_parse_url(call, span, args.url)?;
+    let mut session = http_session(engine_state, stack, call)?;
+    let raw_url = match &session {
+        Some(session) => session.resolve_url(args.url),
+        None => args.url,
+    };
+    let (requested_url, url) = http_parse_url(call, span, raw_url)?;
 
-    let client = http_client(args.insecure);
+    let redirect_mode = parse_redirect_mode(args.redirect)?;
+    let client = http_client(args.insecure, redirect_mode);
     let mut request = client.post(&requested_url);
 
     request = request_set_timeout(args.timeout, request)?;
-    request = request_add_authorization_header(args.user, args.password, request);
+    if let Some(session) = &session {
+        request = session.prepare_request(&url, request);
+    }
+    request = request_add_authorization_header(args.user, args.password, args.bearer, request);
     request = request_add_custom_headers(args.headers, request)?;
 
     let response = send_request(request, Some(args.data), args.content_type, ctrl_c);
+    if let Some(session) = session.as_mut() {
+        session.record_response(&url, &response);
+    }
+    if let Some(session) = session.take() {
+        session.save(span)?;
+    }
+    check_response_redirection(redirect_mode, span, &response)?;
 
     let request_flags = RequestFlags {
         raw: args.raw,
         full: args.full,
         allow_errors: args.allow_errors,
+        progress: false,
     };
 
     request_handle_response(
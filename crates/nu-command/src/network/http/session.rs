@@ -0,0 +1,389 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, SyntaxShape, Type, Value,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use ureq::{Request, Response};
+
+use super::client::ShellErrorOrRequestError;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "http session"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("http session")
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .optional("name", SyntaxShape::String, "the session to show or change")
+            .named(
+                "base-url",
+                SyntaxShape::String,
+                "prefix for requests made with a relative URL",
+                None,
+            )
+            .named(
+                "headers",
+                SyntaxShape::Any,
+                "default headers sent with every request of this session",
+                Some('H'),
+            )
+            .switch("clear-cookies", "empty the session's cookie jar", None)
+            .switch("drop", "delete the session entirely", None)
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Show or configure named sessions for the http commands."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"A session keeps cookies, default headers, and a base URL across
+calls: `http get --session work /api/user`. Cookies set by responses
+are stored automatically; use this command to set up the rest.
+
+Sessions are kept in the nushell config directory; set
+$env.NU_HTTP_SESSIONS to use a different file."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["network", "cookie", "jar", "state"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let name: Option<String> = call.opt(engine_state, stack, 0)?;
+        let base_url: Option<String> = call.get_flag(engine_state, stack, "base-url")?;
+        let headers: Option<Value> = call.get_flag(engine_state, stack, "headers")?;
+
+        let path = sessions_file(engine_state, stack, head)?;
+        let mut sessions = read_sessions(&path, head)?;
+
+        let Some(name) = name else {
+            // Without a name, list what exists
+            let rows = sessions
+                .iter()
+                .map(|(name, data)| {
+                    Value::record(
+                        vec!["name".into(), "base_url".into(), "cookies".into()],
+                        vec![
+                            Value::string(name, head),
+                            match &data.base_url {
+                                Some(base_url) => Value::string(base_url, head),
+                                None => Value::nothing(head),
+                            },
+                            Value::int(
+                                data.cookies.values().map(|jar| jar.len() as i64).sum(),
+                                head,
+                            ),
+                        ],
+                        head,
+                    )
+                })
+                .collect::<Vec<_>>();
+            return Ok(rows.into_pipeline_data(engine_state.ctrlc.clone()));
+        };
+
+        if call.has_flag("drop") {
+            sessions.remove(&name);
+            write_sessions(&path, &sessions, head)?;
+            return Ok(PipelineData::empty());
+        }
+
+        let changing = base_url.is_some() || headers.is_some() || call.has_flag("clear-cookies");
+        let entry = sessions.entry(name.clone()).or_default();
+        if changing {
+            if base_url.is_some() {
+                entry.base_url = base_url;
+            }
+            if let Some(headers) = headers {
+                entry.headers = headers_to_map(&headers)?;
+            }
+            if call.has_flag("clear-cookies") {
+                entry.cookies.clear();
+            }
+            write_sessions(&path, &sessions, head)?;
+            return Ok(PipelineData::empty());
+        }
+
+        Ok(entry.clone().into_value(head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example:
+                    "http session work --base-url https://api.example.com --headers {x-app: demo}",
+                description: "Set up a session with a base URL and a default header",
+                result: None,
+            },
+            Example {
+                example: "http get --session work /user",
+                description:
+                    "Make a request through the session; its cookies are kept for later calls",
+                result: None,
+            },
+            Example {
+                example: "http session work --drop",
+                description: "Delete the session and its cookies",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+struct SessionData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base_url: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    headers: BTreeMap<String, String>,
+    // host -> cookie name -> value
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    cookies: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl SessionData {
+    fn into_value(self, span: Span) -> Value {
+        let headers = Value::record(
+            self.headers.keys().cloned().collect(),
+            self.headers
+                .values()
+                .map(|v| Value::string(v, span))
+                .collect(),
+            span,
+        );
+        let cookies = Value::record(
+            self.cookies.keys().cloned().collect(),
+            self.cookies
+                .values()
+                .map(|jar| {
+                    Value::record(
+                        jar.keys().cloned().collect(),
+                        jar.values().map(|v| Value::string(v, span)).collect(),
+                        span,
+                    )
+                })
+                .collect(),
+            span,
+        );
+        Value::record(
+            vec!["base_url".into(), "headers".into(), "cookies".into()],
+            vec![
+                match self.base_url {
+                    Some(base_url) => Value::string(base_url, span),
+                    None => Value::nothing(span),
+                },
+                headers,
+                cookies,
+            ],
+            span,
+        )
+    }
+}
+
+fn headers_to_map(headers: &Value) -> Result<BTreeMap<String, String>, ShellError> {
+    match headers {
+        Value::Record { cols, vals, .. } => {
+            let mut map = BTreeMap::new();
+            for (col, val) in cols.iter().zip(vals.iter()) {
+                map.insert(col.clone(), val.as_string()?);
+            }
+            Ok(map)
+        }
+        other => Err(ShellError::CantConvert {
+            to_type: "record of header names and values".into(),
+            from_type: other.get_type().to_string(),
+            span: other.expect_span(),
+            help: None,
+        }),
+    }
+}
+
+fn session_error(msg: impl Into<String>, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "HTTP session error".into(),
+        msg.into(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+fn sessions_file(
+    engine_state: &EngineState,
+    stack: &Stack,
+    span: Span,
+) -> Result<PathBuf, ShellError> {
+    if let Some(path) = stack.get_env_var(engine_state, "NU_HTTP_SESSIONS") {
+        return Ok(PathBuf::from(path.as_string()?));
+    }
+
+    match nu_path::config_dir() {
+        Some(mut path) => {
+            path.push("nushell");
+            path.push("http-sessions.json");
+            Ok(path)
+        }
+        None => Err(ShellError::GenericError(
+            "Could not find config directory".into(),
+            "could not resolve the session file".into(),
+            Some(span),
+            Some("set $env.NU_HTTP_SESSIONS to pick a session file explicitly".into()),
+            Vec::new(),
+        )),
+    }
+}
+
+fn read_sessions(path: &PathBuf, span: Span) -> Result<BTreeMap<String, SessionData>, ShellError> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| session_error(format!("cannot read {}: {err}", path.display()), span))?;
+    serde_json::from_str(&text).map_err(|err| {
+        session_error(
+            format!("{} is not a valid session file: {err}", path.display()),
+            span,
+        )
+    })
+}
+
+fn write_sessions(
+    path: &PathBuf,
+    sessions: &BTreeMap<String, SessionData>,
+    span: Span,
+) -> Result<(), ShellError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| {
+            session_error(format!("cannot create {}: {err}", parent.display()), span)
+        })?;
+    }
+    let text = serde_json::to_string_pretty(sessions)
+        .map_err(|err| session_error(err.to_string(), span))?;
+    std::fs::write(path, text)
+        .map_err(|err| session_error(format!("cannot write {}: {err}", path.display()), span))
+}
+
+/// The session a request runs in, resolved from the --session flag.
+pub(crate) struct Session {
+    name: String,
+    path: PathBuf,
+    data: SessionData,
+}
+
+pub(crate) fn http_session(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<Option<Session>, ShellError> {
+    let name: Option<String> = call.get_flag(engine_state, stack, "session")?;
+    let Some(name) = name else {
+        return Ok(None);
+    };
+    let path = sessions_file(engine_state, stack, call.head)?;
+    let sessions = read_sessions(&path, call.head)?;
+    // A session that was never configured starts out empty; it still
+    // collects cookies from here on
+    let data = sessions.get(&name).cloned().unwrap_or_default();
+    Ok(Some(Session { name, path, data }))
+}
+
+impl Session {
+    /// Prefix a relative URL with the session's base URL.
+    pub(crate) fn resolve_url(&self, raw_url: Value) -> Value {
+        if let (Ok(url), Some(base_url)) = (raw_url.as_string(), &self.data.base_url) {
+            if url.starts_with('/') {
+                let span = raw_url.span().unwrap_or(Span::unknown());
+                return Value::string(format!("{}{url}", base_url.trim_end_matches('/')), span);
+            }
+        }
+        raw_url
+    }
+
+    /// Add the session's default headers and cookies. Runs before the
+    /// explicit flags so `-H` and `--user` can override them.
+    pub(crate) fn prepare_request(&self, url: &url::Url, mut request: Request) -> Request {
+        for (name, value) in &self.data.headers {
+            request = request.set(name, value);
+        }
+        if let Some(jar) = url.host_str().and_then(|host| self.data.cookies.get(host)) {
+            if !jar.is_empty() {
+                let cookies = jar
+                    .iter()
+                    .map(|(name, value)| format!("{name}={value}"))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                request = request.set("Cookie", &cookies);
+            }
+        }
+        request
+    }
+
+    /// Store the cookies a response set, whatever its status.
+    pub(crate) fn record_response(
+        &mut self,
+        url: &url::Url,
+        response: &Result<Response, ShellErrorOrRequestError>,
+    ) {
+        let response = match response {
+            Ok(response) => response,
+            Err(ShellErrorOrRequestError::RequestError(_, ureq::Error::Status(_, response))) => {
+                response
+            }
+            Err(_) => return,
+        };
+        let Some(host) = url.host_str() else {
+            return;
+        };
+        for header in response.all("set-cookie") {
+            let Some((name, value)) = header
+                .split(';')
+                .next()
+                .and_then(|pair| pair.split_once('='))
+            else {
+                continue;
+            };
+            let jar = self.data.cookies.entry(host.to_string()).or_default();
+            let (name, value) = (name.trim(), value.trim());
+            if value.is_empty() {
+                jar.remove(name);
+            } else {
+                jar.insert(name.to_string(), value.to_string());
+            }
+        }
+    }
+
+    pub(crate) fn save(self, span: Span) -> Result<(), ShellError> {
+        let mut sessions = read_sessions(&self.path, span)?;
+        sessions.insert(self.name, self.data);
+        write_sessions(&self.path, &sessions, span)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,484 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use super::client::{http_client, RedirectMode};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "http token"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("http token")
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .required(
+                "name",
+                SyntaxShape::String,
+                "the name the tokens are cached under",
+            )
+            .named(
+                "client-id",
+                SyntaxShape::String,
+                "the OAuth2 client id",
+                None,
+            )
+            .named(
+                "client-secret",
+                SyntaxShape::String,
+                "the OAuth2 client secret, for providers that require one",
+                None,
+            )
+            .named(
+                "token-url",
+                SyntaxShape::String,
+                "the token endpoint used for the device flow and for refreshing",
+                None,
+            )
+            .named(
+                "device-auth-url",
+                SyntaxShape::String,
+                "the device authorization endpoint; starts the interactive device flow",
+                None,
+            )
+            .named(
+                "scope",
+                SyntaxShape::String,
+                "the scopes to request, space separated",
+                None,
+            )
+            .named(
+                "cache-file",
+                SyntaxShape::Filepath,
+                "where to cache tokens (defaults to the nushell config directory)",
+                None,
+            )
+            .switch(
+                "refresh",
+                "refresh the token even if the cached one has not expired",
+                None,
+            )
+            .switch("clear", "forget the cached tokens for this name", None)
+            .switch(
+                "full",
+                "return the full token record instead of only the access token",
+                Some('f'),
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Get an OAuth2 access token, logging in with the device flow when needed."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Tokens are cached per name, so scripts can ask for a token without
+hand-rolling headers: `http get --bearer (http token github) ...`.
+
+The first call needs --client-id, --device-auth-url, and --token-url;
+it prints a verification URL and code, then waits for you to approve
+the login in a browser (RFC 8628). Later calls return the cached
+access token, refreshing it through the token endpoint when it has
+expired and the provider issued a refresh token."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["network", "oauth", "auth", "bearer", "login"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let args = Arguments {
+            client_id: call.get_flag(engine_state, stack, "client-id")?,
+            client_secret: call.get_flag(engine_state, stack, "client-secret")?,
+            token_url: call.get_flag(engine_state, stack, "token-url")?,
+            device_auth_url: call.get_flag(engine_state, stack, "device-auth-url")?,
+            scope: call.get_flag(engine_state, stack, "scope")?,
+            cache_file: call.get_flag(engine_state, stack, "cache-file")?,
+            refresh: call.has_flag("refresh"),
+            full: call.has_flag("full"),
+        };
+
+        let cache_file = cache_file_path(args.cache_file.clone(), head)?;
+        let mut cache = read_cache(&cache_file, head)?;
+
+        if call.has_flag("clear") {
+            cache.remove(&name.item);
+            write_cache(&cache_file, &cache, head)?;
+            return Ok(PipelineData::empty());
+        }
+
+        let entry = resolve_token(engine_state, &name, &args, cache.get(&name.item), head)?;
+        cache.insert(name.item.clone(), entry.clone());
+        write_cache(&cache_file, &cache, head)?;
+
+        let value = if args.full {
+            entry.into_value(head)
+        } else {
+            Value::string(entry.access_token, head)
+        };
+        Ok(value.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "http token github --client-id Iv1.abcd --device-auth-url https://github.com/login/device/code --token-url https://github.com/login/oauth/access_token",
+                description: "Log in once with the device flow; the tokens are cached under 'github'",
+                result: None,
+            },
+            Example {
+                example: "http get --bearer (http token github) https://api.github.com/user",
+                description: "Use the cached token to authenticate a request",
+                result: None,
+            },
+            Example {
+                example: "http token github --clear",
+                description: "Forget the cached tokens",
+                result: None,
+            },
+        ]
+    }
+}
+
+struct Arguments {
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    token_url: Option<String>,
+    device_auth_url: Option<String>,
+    scope: Option<String>,
+    cache_file: Option<Spanned<String>>,
+    refresh: bool,
+    full: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct TokenEntry {
+    access_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    refresh_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+}
+
+impl TokenEntry {
+    fn expired(&self) -> bool {
+        // A minute of leeway so a token does not expire mid-request
+        match self.expires_at {
+            Some(expires_at) => expires_at - 60 <= chrono::Utc::now().timestamp(),
+            None => false,
+        }
+    }
+
+    fn into_value(self, span: Span) -> Value {
+        let mut cols = vec!["access_token".to_string()];
+        let mut vals = vec![Value::string(&self.access_token, span)];
+        if let Some(refresh_token) = &self.refresh_token {
+            cols.push("refresh_token".into());
+            vals.push(Value::string(refresh_token, span));
+        }
+        if let Some(expires_at) = self.expires_at {
+            cols.push("expires_at".into());
+            vals.push(Value::int(expires_at, span));
+        }
+        if let Some(scope) = &self.scope {
+            cols.push("scope".into());
+            vals.push(Value::string(scope, span));
+        }
+        Value::record(cols, vals, span)
+    }
+}
+
+fn oauth_error(msg: impl Into<String>, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "OAuth2 error".into(),
+        msg.into(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+fn cache_file_path(flag: Option<Spanned<String>>, span: Span) -> Result<PathBuf, ShellError> {
+    if let Some(path) = flag {
+        return Ok(PathBuf::from(path.item));
+    }
+
+    match nu_path::config_dir() {
+        Some(mut path) => {
+            path.push("nushell");
+            path.push("oauth-tokens.json");
+            Ok(path)
+        }
+        None => Err(ShellError::GenericError(
+            "Could not find config directory".into(),
+            "could not resolve the token cache file".into(),
+            Some(span),
+            Some("use --cache-file to pick a cache location explicitly".into()),
+            Vec::new(),
+        )),
+    }
+}
+
+fn read_cache(path: &PathBuf, span: Span) -> Result<BTreeMap<String, TokenEntry>, ShellError> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| oauth_error(format!("cannot read {}: {err}", path.display()), span))?;
+    serde_json::from_str(&text).map_err(|err| {
+        oauth_error(
+            format!("{} is not a valid token cache: {err}", path.display()),
+            span,
+        )
+    })
+}
+
+fn write_cache(
+    path: &PathBuf,
+    cache: &BTreeMap<String, TokenEntry>,
+    span: Span,
+) -> Result<(), ShellError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| {
+            oauth_error(format!("cannot create {}: {err}", parent.display()), span)
+        })?;
+    }
+    let text =
+        serde_json::to_string_pretty(cache).map_err(|err| oauth_error(err.to_string(), span))?;
+    std::fs::write(path, text)
+        .map_err(|err| oauth_error(format!("cannot write {}: {err}", path.display()), span))
+}
+
+fn resolve_token(
+    engine_state: &EngineState,
+    name: &Spanned<String>,
+    args: &Arguments,
+    cached: Option<&TokenEntry>,
+    span: Span,
+) -> Result<TokenEntry, ShellError> {
+    if let Some(entry) = cached {
+        if !entry.expired() && !args.refresh {
+            return Ok(entry.clone());
+        }
+        // Prefer a silent refresh over asking the user to log in again
+        let token_url = args.token_url.clone().or_else(|| entry.token_url.clone());
+        let client_id = args.client_id.clone().or_else(|| entry.client_id.clone());
+        if let (Some(refresh_token), Some(token_url)) = (&entry.refresh_token, token_url) {
+            return refresh_entry(entry, refresh_token, &token_url, client_id, args, span);
+        }
+    }
+
+    match (&args.client_id, &args.device_auth_url, &args.token_url) {
+        (Some(client_id), Some(device_auth_url), Some(token_url)) => device_flow(
+            engine_state,
+            client_id,
+            device_auth_url,
+            token_url,
+            args,
+            span,
+        ),
+        _ => Err(ShellError::GenericError(
+            format!("there is no usable cached token for '{}'", name.item),
+            "log in first with the device flow".into(),
+            Some(span),
+            Some("pass --client-id, --device-auth-url, and --token-url to start a login".into()),
+            Vec::new(),
+        )),
+    }
+}
+
+fn refresh_entry(
+    entry: &TokenEntry,
+    refresh_token: &str,
+    token_url: &str,
+    client_id: Option<String>,
+    args: &Arguments,
+    span: Span,
+) -> Result<TokenEntry, ShellError> {
+    let mut form = vec![
+        ("grant_type", "refresh_token".to_string()),
+        ("refresh_token", refresh_token.to_string()),
+    ];
+    if let Some(client_id) = &client_id {
+        form.push(("client_id", client_id.clone()));
+    }
+    if let Some(client_secret) = &args.client_secret {
+        form.push(("client_secret", client_secret.clone()));
+    }
+
+    let response = post_form(token_url, &form, span)?;
+    let access_token = response
+        .get("access_token")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| oauth_error("the token endpoint sent no access token", span))?;
+
+    Ok(TokenEntry {
+        access_token: access_token.to_string(),
+        refresh_token: response
+            .get("refresh_token")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .or_else(|| Some(refresh_token.to_string())),
+        expires_at: expires_at_from(&response),
+        token_url: Some(token_url.to_string()),
+        client_id,
+        scope: entry.scope.clone(),
+    })
+}
+
+fn device_flow(
+    engine_state: &EngineState,
+    client_id: &str,
+    device_auth_url: &str,
+    token_url: &str,
+    args: &Arguments,
+    span: Span,
+) -> Result<TokenEntry, ShellError> {
+    let mut form = vec![("client_id", client_id.to_string())];
+    if let Some(scope) = &args.scope {
+        form.push(("scope", scope.clone()));
+    }
+    let response = post_form(device_auth_url, &form, span)?;
+
+    let device_code = response
+        .get("device_code")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| oauth_error("the device endpoint sent no device code", span))?
+        .to_string();
+    let user_code = response.get("user_code").and_then(|v| v.as_str());
+    let verification_uri = response
+        .get("verification_uri_complete")
+        .or_else(|| response.get("verification_uri"))
+        .and_then(|v| v.as_str());
+    let mut interval = response
+        .get("interval")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(5);
+    let expires_in = response
+        .get("expires_in")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(900);
+
+    match (verification_uri, user_code) {
+        (Some(uri), Some(code)) => eprintln!("To log in, open {uri} and enter the code {code}"),
+        (Some(uri), None) => eprintln!("To log in, open {uri}"),
+        _ => {}
+    }
+
+    let deadline = std::time::Instant::now() + Duration::from_secs(expires_in);
+    loop {
+        if nu_utils::ctrl_c::was_pressed(&engine_state.ctrlc) {
+            return Err(ShellError::InterruptedByUser { span: Some(span) });
+        }
+        if std::time::Instant::now() >= deadline {
+            return Err(oauth_error(
+                "the login timed out before it was approved",
+                span,
+            ));
+        }
+        std::thread::sleep(Duration::from_secs(interval));
+
+        let mut form = vec![
+            (
+                "grant_type",
+                "urn:ietf:params:oauth:grant-type:device_code".to_string(),
+            ),
+            ("device_code", device_code.clone()),
+            ("client_id", client_id.to_string()),
+        ];
+        if let Some(client_secret) = &args.client_secret {
+            form.push(("client_secret", client_secret.clone()));
+        }
+        let response = post_form(token_url, &form, span)?;
+
+        if let Some(access_token) = response.get("access_token").and_then(|v| v.as_str()) {
+            return Ok(TokenEntry {
+                access_token: access_token.to_string(),
+                refresh_token: response
+                    .get("refresh_token")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+                expires_at: expires_at_from(&response),
+                token_url: Some(token_url.to_string()),
+                client_id: Some(client_id.to_string()),
+                scope: args.scope.clone(),
+            });
+        }
+        match response.get("error").and_then(|v| v.as_str()) {
+            Some("authorization_pending") => continue,
+            // The server asked us to back off (RFC 8628 section 3.5)
+            Some("slow_down") => interval += 5,
+            Some(error) => return Err(oauth_error(format!("the login failed: {error}"), span)),
+            None => return Err(oauth_error("the token endpoint sent no access token", span)),
+        }
+    }
+}
+
+// Token endpoints answer polling errors with a 4xx status and a JSON body,
+// so error responses are parsed just like successful ones.
+fn post_form(
+    url: &str,
+    form: &[(&str, String)],
+    span: Span,
+) -> Result<serde_json::Value, ShellError> {
+    let form: Vec<(&str, &str)> = form.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    let response = http_client(false, RedirectMode::Follow)
+        .post(url)
+        .set("Accept", "application/json")
+        .send_form(&form);
+
+    let body = match response {
+        Ok(response) => response.into_string(),
+        Err(ureq::Error::Status(_, response)) => response.into_string(),
+        Err(err) => return Err(ShellError::NetworkFailure(err.to_string(), span)),
+    }
+    .map_err(|err| ShellError::NetworkFailure(err.to_string(), span))?;
+
+    serde_json::from_str(&body)
+        .map_err(|_| oauth_error(format!("{url} did not answer with JSON"), span))
+}
+
+fn expires_at_from(response: &serde_json::Value) -> Option<i64> {
+    response
+        .get("expires_in")
+        .and_then(|v| v.as_i64())
+        .map(|expires_in| chrono::Utc::now().timestamp() + expires_in)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,8 +1,14 @@
+mod graphql;
 mod http;
+mod net;
 mod port;
 mod url;
+mod ws;
 
+pub use self::graphql::*;
 pub use self::http::*;
+pub use self::net::*;
 pub use self::url::*;
+pub use self::ws::*;
 
 pub use port::SubCommand as Port;
@@ -0,0 +1,211 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, RawStream, ShellError, Signature, Span, Spanned, SyntaxShape,
+    Type, Value,
+};
+use std::io::{Read, Write};
+use std::net::{Shutdown, TcpStream};
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "net connect"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("net connect")
+            .input_output_types(vec![
+                (Type::Nothing, Type::Any),
+                (Type::String, Type::Any),
+                (Type::Binary, Type::Any),
+            ])
+            .required("host", SyntaxShape::String, "the host to connect to")
+            .required("port", SyntaxShape::Int, "the TCP port to connect to")
+            .named(
+                "max-time",
+                SyntaxShape::Int,
+                "timeout period in seconds for connecting and reading",
+                Some('m'),
+            )
+            .filter()
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Open a TCP connection, send the pipeline input, and stream back what the peer sends."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"String or binary input is written to the socket first and the write
+half is then shut down, so servers that read until EOF see the end of
+the request. The response is streamed as raw bytes; pipe it into
+`decode`, `lines`, or a `from ...` command as needed."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["network", "tcp", "socket", "netcat"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let ctrlc = engine_state.ctrlc.clone();
+        let host: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let port: Spanned<i64> = call.req(engine_state, stack, 1)?;
+        let max_time: Option<i64> = call.get_flag(engine_state, stack, "max-time")?;
+        let timeout = max_time.map(|seconds| Duration::from_secs(seconds.max(0) as u64));
+
+        let port = u16::try_from(port.item).map_err(|_| {
+            net_error(
+                format!("{} is not a valid port number", port.item),
+                port.span,
+            )
+        })?;
+
+        let mut stream = match timeout {
+            Some(timeout) => {
+                let address = resolve_address(&host.item, port, host.span)?;
+                TcpStream::connect_timeout(&address, timeout)
+            }
+            None => TcpStream::connect((host.item.as_str(), port)),
+        }
+        .map_err(|err| {
+            net_error(
+                format!("cannot connect to {}:{port}: {err}", host.item),
+                head,
+            )
+        })?;
+        stream
+            .set_read_timeout(timeout)
+            .map_err(|err| net_error(err.to_string(), head))?;
+
+        if let Some(payload) = payload_bytes(input, head)? {
+            stream
+                .write_all(&payload)
+                .and_then(|_| stream.flush())
+                .map_err(|err| net_error(err.to_string(), head))?;
+            let _ = stream.shutdown(Shutdown::Write);
+        }
+
+        Ok(PipelineData::ExternalStream {
+            stdout: Some(RawStream::new(
+                Box::new(ChunkReader { stream, span: head }),
+                ctrlc,
+                head,
+                None,
+            )),
+            stderr: None,
+            exit_code: None,
+            span: head,
+            metadata: None,
+            trim_end_newline: false,
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "\"PING\\r\\n\" | net connect localhost 6379 | decode utf-8",
+                description: "Send a raw command to a server and decode the reply",
+                result: None,
+            },
+            Example {
+                example: "open request.bin | net connect 127.0.0.1 9000",
+                description: "Send binary data and stream back the raw response",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub(super) fn net_error(msg: impl Into<String>, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Network failure".into(),
+        msg.into(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+pub(super) fn resolve_address(
+    host: &str,
+    port: u16,
+    span: Span,
+) -> Result<std::net::SocketAddr, ShellError> {
+    use std::net::ToSocketAddrs;
+
+    (host, port)
+        .to_socket_addrs()
+        .map_err(|err| net_error(format!("cannot resolve {host}: {err}"), span))?
+        .next()
+        .ok_or_else(|| net_error(format!("cannot resolve {host}"), span))
+}
+
+pub(super) fn payload_bytes(
+    input: PipelineData,
+    head: Span,
+) -> Result<Option<Vec<u8>>, ShellError> {
+    match input {
+        PipelineData::Empty | PipelineData::Value(Value::Nothing { .. }, _) => Ok(None),
+        PipelineData::Value(Value::String { val, .. }, _) => Ok(Some(val.into_bytes())),
+        PipelineData::Value(Value::Binary { val, .. }, _) => Ok(Some(val)),
+        PipelineData::Value(Value::Error { error }, _) => Err(*error),
+        PipelineData::Value(other, _) => Err(ShellError::OnlySupportsThisInputType {
+            exp_input_type: "string or binary".into(),
+            wrong_type: other.get_type().to_string(),
+            dst_span: head,
+            src_span: other.expect_span(),
+        }),
+        stream => {
+            let mut payload = Vec::new();
+            for value in stream {
+                match value {
+                    Value::String { val, .. } => payload.extend_from_slice(val.as_bytes()),
+                    Value::Binary { val, .. } => payload.extend_from_slice(&val),
+                    Value::Error { error } => return Err(*error),
+                    other => {
+                        return Err(ShellError::OnlySupportsThisInputType {
+                            exp_input_type: "string or binary".into(),
+                            wrong_type: other.get_type().to_string(),
+                            dst_span: head,
+                            src_span: other.expect_span(),
+                        })
+                    }
+                }
+            }
+            Ok(Some(payload))
+        }
+    }
+}
+
+struct ChunkReader {
+    stream: TcpStream,
+    span: Span,
+}
+
+impl Iterator for ChunkReader {
+    type Item = Result<Vec<u8>, ShellError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = vec![0u8; 8192];
+        match self.stream.read(&mut chunk) {
+            Ok(0) => None,
+            Ok(read) => {
+                chunk.truncate(read);
+                Some(Ok(chunk))
+            }
+            Err(err) => Some(Err(net_error(err.to_string(), self.span))),
+        }
+    }
+}
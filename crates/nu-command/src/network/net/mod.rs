@@ -0,0 +1,7 @@
+mod connect;
+mod net_;
+mod send_udp;
+
+pub use connect::SubCommand as NetConnect;
+pub use net_::Net;
+pub use send_udp::SubCommand as NetSendUdp;
@@ -0,0 +1,89 @@
+use super::connect::{net_error, payload_bytes, resolve_address};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+use std::net::UdpSocket;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "net send-udp"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("net send-udp")
+            .input_output_types(vec![
+                (Type::String, Type::Nothing),
+                (Type::Binary, Type::Nothing),
+            ])
+            .required("host", SyntaxShape::String, "the host to send to")
+            .required("port", SyntaxShape::Int, "the UDP port to send to")
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Send the pipeline input as a UDP datagram."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "String or binary input becomes the payload of a single datagram. UDP gives no delivery guarantee, so nothing is returned."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["network", "socket", "datagram"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let host: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let port: Spanned<i64> = call.req(engine_state, stack, 1)?;
+
+        let port = u16::try_from(port.item).map_err(|_| {
+            net_error(
+                format!("{} is not a valid port number", port.item),
+                port.span,
+            )
+        })?;
+        let payload = payload_bytes(input, head)?
+            .ok_or_else(|| net_error("there is no input to send", head))?;
+
+        let address = resolve_address(&host.item, port, host.span)?;
+        let socket = UdpSocket::bind(if address.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        })
+        .map_err(|err| net_error(err.to_string(), head))?;
+        socket
+            .send_to(&payload, address)
+            .map_err(|err| net_error(err.to_string(), head))?;
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "\"status\" | net send-udp 127.0.0.1 8125",
+                description: "Send a text datagram to a local service",
+                result: None,
+            },
+            Example {
+                example: "0x[dead beef] | net send-udp 10.0.0.5 4000",
+                description: "Send binary data as a datagram",
+                result: None,
+            },
+        ]
+    }
+}
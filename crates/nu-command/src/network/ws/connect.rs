@@ -0,0 +1,505 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "ws connect"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ws connect")
+            .input_output_types(vec![
+                (Type::Nothing, Type::Any),
+                (Type::String, Type::Any),
+                (Type::List(Box::new(Type::String)), Type::Any),
+            ])
+            .required("URL", SyntaxShape::String, "the ws:// or wss:// URL")
+            .named(
+                "max-messages",
+                SyntaxShape::Int,
+                "close the connection after receiving this many messages",
+                Some('n'),
+            )
+            .filter()
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Connect to a WebSocket endpoint and stream the incoming messages."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Pipeline input is sent first: a string input becomes one text message
+and a list of strings becomes one message per item. Incoming text
+frames come back as strings and binary frames as binary values; pings
+are answered automatically and the stream ends when the server closes
+the connection."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["network", "websocket", "socket", "stream"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let ctrlc = engine_state.ctrlc.clone();
+        let url: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let max_messages: Option<i64> = call.get_flag(engine_state, stack, "max-messages")?;
+        let max_messages = max_messages
+            .map(|n| n.max(0) as usize)
+            .unwrap_or(usize::MAX);
+
+        let outgoing = collect_outgoing(input, head)?;
+        let mut connection = WsConnection::open(&url.item, url.span)?;
+        for message in outgoing {
+            connection.send_text(&message)?;
+        }
+
+        Ok(MessageStream {
+            connection,
+            remaining: max_messages,
+            span: head,
+        }
+        .into_pipeline_data(ctrlc))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "ws connect wss://echo.example.com -n 5",
+                description: "Stream the first five messages from an endpoint",
+                result: None,
+            },
+            Example {
+                example: "'{\"subscribe\": \"trades\"}' | ws connect wss://feed.example.com",
+                description: "Send a subscription message, then stream what comes back",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn collect_outgoing(input: PipelineData, head: Span) -> Result<Vec<String>, ShellError> {
+    let mut outgoing = Vec::new();
+    match input {
+        PipelineData::Empty | PipelineData::Value(Value::Nothing { .. }, _) => {}
+        other => {
+            for value in other {
+                match value {
+                    Value::String { val, .. } => outgoing.push(val),
+                    Value::Error { error } => return Err(*error),
+                    other => {
+                        return Err(ShellError::OnlySupportsThisInputType {
+                            exp_input_type: "string".into(),
+                            wrong_type: other.get_type().to_string(),
+                            dst_span: head,
+                            src_span: other.expect_span(),
+                        })
+                    }
+                }
+            }
+        }
+    }
+    Ok(outgoing)
+}
+
+fn ws_error(msg: impl Into<String>, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "WebSocket error".into(),
+        msg.into(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+enum WsStream {
+    Plain(TcpStream),
+    Tls(Box<native_tls::TlsStream<TcpStream>>),
+}
+
+impl Read for WsStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            WsStream::Plain(stream) => stream.read(buf),
+            WsStream::Tls(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for WsStream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            WsStream::Plain(stream) => stream.write(buf),
+            WsStream::Tls(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            WsStream::Plain(stream) => stream.flush(),
+            WsStream::Tls(stream) => stream.flush(),
+        }
+    }
+}
+
+struct WsConnection {
+    stream: WsStream,
+    span: Span,
+    closed: bool,
+}
+
+impl WsConnection {
+    fn open(raw_url: &str, span: Span) -> Result<Self, ShellError> {
+        let url = url::Url::parse(raw_url)
+            .map_err(|_| ws_error(format!("'{raw_url}' is not a valid URL"), span))?;
+        let tls = match url.scheme() {
+            "ws" => false,
+            "wss" => true,
+            other => {
+                return Err(ws_error(
+                    format!("unsupported scheme '{other}', expected ws:// or wss://"),
+                    span,
+                ))
+            }
+        };
+        let host = url
+            .host_str()
+            .ok_or_else(|| ws_error("the URL has no host", span))?
+            .to_string();
+        let port = url.port().unwrap_or(if tls { 443 } else { 80 });
+
+        let tcp = TcpStream::connect((host.as_str(), port))
+            .map_err(|err| ws_error(format!("cannot connect to {host}:{port}: {err}"), span))?;
+        let stream = if tls {
+            let connector =
+                native_tls::TlsConnector::new().map_err(|err| ws_error(err.to_string(), span))?;
+            let tls_stream = connector
+                .connect(&host, tcp)
+                .map_err(|err| ws_error(err.to_string(), span))?;
+            WsStream::Tls(Box::new(tls_stream))
+        } else {
+            WsStream::Plain(tcp)
+        };
+
+        let mut connection = Self {
+            stream,
+            span,
+            closed: false,
+        };
+        connection.handshake(&url, &host, port)?;
+        Ok(connection)
+    }
+
+    fn handshake(&mut self, url: &url::Url, host: &str, port: u16) -> Result<(), ShellError> {
+        let key_bytes: [u8; 16] = rand::random();
+        let key = STANDARD.encode(key_bytes);
+        let mut resource = url.path().to_string();
+        if let Some(query) = url.query() {
+            resource.push('?');
+            resource.push_str(query);
+        }
+
+        let request = format!(
+            "GET {resource} HTTP/1.1\r\n\
+             Host: {host}:{port}\r\n\
+             Upgrade: websocket\r\n\
+             Connection: Upgrade\r\n\
+             Sec-WebSocket-Key: {key}\r\n\
+             Sec-WebSocket-Version: 13\r\n\r\n"
+        );
+        self.stream
+            .write_all(request.as_bytes())
+            .map_err(|err| ws_error(err.to_string(), self.span))?;
+
+        let response = self.read_handshake_response()?;
+        let (status_line, headers) = response.split_once("\r\n").unwrap_or((&response, ""));
+        if !status_line.contains("101") {
+            return Err(ws_error(
+                format!("the server did not upgrade the connection: {status_line}"),
+                self.span,
+            ));
+        }
+
+        let expected = STANDARD.encode(sha1(
+            format!("{key}258EAFA5-E914-47DA-95CA-C5AB0DC85B11").as_bytes(),
+        ));
+        let accept = headers.lines().find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.trim()
+                .eq_ignore_ascii_case("Sec-WebSocket-Accept")
+                .then(|| value.trim().to_string())
+        });
+        if accept.as_deref() != Some(expected.as_str()) {
+            return Err(ws_error(
+                "the server sent a wrong Sec-WebSocket-Accept key",
+                self.span,
+            ));
+        }
+        Ok(())
+    }
+
+    fn read_handshake_response(&mut self) -> Result<String, ShellError> {
+        let mut response = Vec::new();
+        let mut byte = [0u8; 1];
+        while !response.ends_with(b"\r\n\r\n") {
+            if response.len() > 16 * 1024 {
+                return Err(ws_error("the handshake response is too large", self.span));
+            }
+            match self.stream.read(&mut byte) {
+                Ok(1) => response.push(byte[0]),
+                Ok(_) => break,
+                Err(err) => return Err(ws_error(err.to_string(), self.span)),
+            }
+        }
+        Ok(String::from_utf8_lossy(&response).into_owned())
+    }
+
+    fn send_text(&mut self, text: &str) -> Result<(), ShellError> {
+        self.send_frame(0x1, text.as_bytes())
+    }
+
+    // Client frames must be masked per RFC 6455
+    fn send_frame(&mut self, opcode: u8, payload: &[u8]) -> Result<(), ShellError> {
+        let mut frame = vec![0x80 | opcode];
+        match payload.len() {
+            len if len < 126 => frame.push(0x80 | len as u8),
+            len if len <= u16::MAX as usize => {
+                frame.push(0x80 | 126);
+                frame.extend_from_slice(&(len as u16).to_be_bytes());
+            }
+            len => {
+                frame.push(0x80 | 127);
+                frame.extend_from_slice(&(len as u64).to_be_bytes());
+            }
+        }
+        let mask: [u8; 4] = rand::random();
+        frame.extend_from_slice(&mask);
+        frame.extend(
+            payload
+                .iter()
+                .enumerate()
+                .map(|(at, byte)| byte ^ mask[at % 4]),
+        );
+        self.stream
+            .write_all(&frame)
+            .and_then(|_| self.stream.flush())
+            .map_err(|err| ws_error(err.to_string(), self.span))
+    }
+
+    // The next complete (defragmented) data frame, or None on close.
+    fn next_message(&mut self) -> Result<Option<Value>, ShellError> {
+        let mut message: Option<(u8, Vec<u8>)> = None;
+        loop {
+            let (fin, opcode, payload) = self.read_frame()?;
+            match opcode {
+                // Continuation of a fragmented message
+                0x0 => {
+                    if let Some((_, buffer)) = message.as_mut() {
+                        buffer.extend_from_slice(&payload);
+                    }
+                }
+                0x1 | 0x2 => message = Some((opcode, payload)),
+                0x8 => {
+                    let _ = self.send_frame(0x8, &payload);
+                    self.closed = true;
+                    return Ok(None);
+                }
+                0x9 => {
+                    self.send_frame(0xA, &payload)?;
+                    continue;
+                }
+                _ => continue,
+            }
+            if fin {
+                return Ok(message.map(|(opcode, payload)| match opcode {
+                    0x1 => Value::string(String::from_utf8_lossy(&payload), self.span),
+                    _ => Value::Binary {
+                        val: payload,
+                        span: self.span,
+                    },
+                }));
+            }
+        }
+    }
+
+    fn read_frame(&mut self) -> Result<(bool, u8, Vec<u8>), ShellError> {
+        let mut header = [0u8; 2];
+        self.read_exact(&mut header)?;
+        let fin = header[0] & 0x80 != 0;
+        let opcode = header[0] & 0x0f;
+        let masked = header[1] & 0x80 != 0;
+        let length = match header[1] & 0x7f {
+            126 => {
+                let mut extended = [0u8; 2];
+                self.read_exact(&mut extended)?;
+                u16::from_be_bytes(extended) as usize
+            }
+            127 => {
+                let mut extended = [0u8; 8];
+                self.read_exact(&mut extended)?;
+                u64::from_be_bytes(extended) as usize
+            }
+            length => length as usize,
+        };
+        let mask = if masked {
+            let mut mask = [0u8; 4];
+            self.read_exact(&mut mask)?;
+            Some(mask)
+        } else {
+            None
+        };
+        let mut payload = vec![0u8; length];
+        self.read_exact(&mut payload)?;
+        if let Some(mask) = mask {
+            for (at, byte) in payload.iter_mut().enumerate() {
+                *byte ^= mask[at % 4];
+            }
+        }
+        Ok((fin, opcode, payload))
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), ShellError> {
+        self.stream
+            .read_exact(buf)
+            .map_err(|err| ws_error(err.to_string(), self.span))
+    }
+
+    fn close(&mut self) {
+        if !self.closed {
+            let _ = self.send_frame(0x8, &1000u16.to_be_bytes());
+            self.closed = true;
+        }
+    }
+}
+
+impl Drop for WsConnection {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+struct MessageStream {
+    connection: WsConnection,
+    remaining: usize,
+    span: Span,
+}
+
+impl Iterator for MessageStream {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 || self.connection.closed {
+            self.connection.close();
+            return None;
+        }
+        match self.connection.next_message() {
+            Ok(Some(value)) => {
+                self.remaining -= 1;
+                Some(value)
+            }
+            Ok(None) => None,
+            Err(err) => {
+                self.connection.closed = true;
+                Some(Value::Error {
+                    error: Box::new(ws_error(err.to_string(), self.span)),
+                })
+            }
+        }
+    }
+}
+
+pub(super) fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    for chunk in message.chunks(64) {
+        let mut w = [0u32; 80];
+        for (at, word) in chunk.chunks(4).enumerate() {
+            w[at] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for at in 16..80 {
+            w[at] = (w[at - 3] ^ w[at - 8] ^ w[at - 14] ^ w[at - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (at, word) in w.iter().enumerate() {
+            let (f, k) = match at {
+                0..=19 => ((b & c) | (!b & d), 0x5a827999),
+                20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        state[4] = state[4].wrapping_add(e);
+    }
+
+    let mut digest = [0u8; 20];
+    for (at, word) in state.iter().enumerate() {
+        digest[at * 4..at * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha1_matches_known_digests() {
+        // Test vectors from RFC 3174
+        assert_eq!(
+            sha1(b"abc"),
+            [
+                0xa9, 0x99, 0x3e, 0x36, 0x47, 0x06, 0x81, 0x6a, 0xba, 0x3e, 0x25, 0x71, 0x78, 0x50,
+                0xc2, 0x6c, 0x9c, 0xd0, 0xd8, 0x9d
+            ]
+        );
+        assert_eq!(
+            sha1(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            [
+                0x84, 0x98, 0x3e, 0x44, 0x1c, 0x3b, 0xd2, 0x6e, 0xba, 0xae, 0x4a, 0xa1, 0xf9, 0x51,
+                0x29, 0xe5, 0xe5, 0x46, 0x70, 0xf1
+            ]
+        );
+    }
+}
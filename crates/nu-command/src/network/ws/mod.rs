@@ -0,0 +1,5 @@
+mod connect;
+mod ws_;
+
+pub use connect::SubCommand as WsConnect;
+pub use ws_::Ws;
@@ -9,6 +9,7 @@ use nu_protocol::{
 };
 
 use super::PathSubcommandArguments;
+use std::sync::Arc;
 
 struct Arguments {
     columns: Option<Vec<String>>,
@@ -123,7 +124,7 @@ impl Command for SubCommand {
                 example: "[[name];[/home/joe]] | path basename -c [ name ]",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["name".to_string()],
+                        cols: Arc::new(vec!["name".to_string()]),
                         vals: vec![Value::test_string("joe")],
                         span: Span::test_data(),
                     }],
@@ -12,6 +12,7 @@ use nu_protocol::{
 };
 
 use super::PathSubcommandArguments;
+use std::sync::Arc;
 
 struct Arguments {
     columns: Option<Vec<String>>,
@@ -214,7 +215,7 @@ fn join_record(cols: &[String], vals: &[Value], head: Span, span: Span, args: &A
             &join_single,
             args,
             Value::Record {
-                cols: cols.to_vec(),
+                cols: Arc::new(cols.to_vec()),
                 vals: vals.to_vec(),
                 span,
             },
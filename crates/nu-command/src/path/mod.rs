@@ -23,6 +23,7 @@ pub use relative_to::SubCommand as PathRelativeTo;
 pub use split::SubCommand as PathSplit;
 
 use nu_protocol::{ShellError, Span, Value};
+use std::sync::Arc;
 
 #[cfg(windows)]
 const ALLOWED_COLUMNS: [&str; 4] = ["prefix", "parent", "stem", "extension"];
@@ -74,7 +75,7 @@ where
             }
 
             Value::Record {
-                cols: output_cols,
+                cols: Arc::new(output_cols),
                 vals: output_vals,
                 span,
             }
@@ -10,6 +10,7 @@ use nu_protocol::{
 };
 
 use super::PathSubcommandArguments;
+use std::sync::Arc;
 
 struct Arguments {
     columns: Option<Vec<String>>,
@@ -140,7 +141,7 @@ On Windows, an extra 'prefix' column is added."#
                 description: "Parse a path",
                 example: r"'/home/viking/spam.txt' | path parse",
                 result: Some(Value::Record {
-                    cols: vec!["parent".into(), "stem".into(), "extension".into()],
+                    cols: Arc::new(vec!["parent".into(), "stem".into(), "extension".into()]),
                     vals: vec![
                         Value::test_string("/home/viking"),
                         Value::test_string("spam"),
@@ -158,7 +159,7 @@ On Windows, an extra 'prefix' column is added."#
                 description: "Ignore the extension",
                 example: r"'/etc/conf.d' | path parse -e ''",
                 result: Some(Value::Record {
-                    cols: vec!["parent".into(), "stem".into(), "extension".into()],
+                    cols: Arc::new(vec!["parent".into(), "stem".into(), "extension".into()]),
                     vals: vec![
                         Value::test_string("/etc"),
                         Value::test_string("conf.d"),
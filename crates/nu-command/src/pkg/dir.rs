@@ -0,0 +1,51 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+use super::pkg_dir;
+
+#[derive(Clone)]
+pub struct PkgDir;
+
+impl Command for PkgDir {
+    fn name(&self) -> &str {
+        "pkg dir"
+    }
+
+    fn usage(&self) -> &str {
+        "Show the directory packages are installed into."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pkg dir")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .category(Category::Experimental)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["package", "path"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let root = pkg_dir(engine_state, stack, span)?;
+
+        Ok(Value::string(root.to_string_lossy(), span).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show the package directory",
+            example: "pkg dir",
+            result: None,
+        }]
+    }
+}
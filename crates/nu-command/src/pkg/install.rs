@@ -0,0 +1,286 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+use super::{
+    add_to_lib_dirs, pkg_dir, read_lockfile, run_git, write_lockfile, LockedPackage, MANIFEST_NAME,
+};
+
+#[derive(Clone)]
+pub struct PkgInstall;
+
+impl Command for PkgInstall {
+    fn name(&self) -> &str {
+        "pkg install"
+    }
+
+    fn usage(&self) -> &str {
+        "Install a module package from a git URL into the package directory."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pkg install")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .required("url", SyntaxShape::String, "the git URL of the package")
+            .named(
+                "revision",
+                SyntaxShape::String,
+                "a branch, tag or commit to check out instead of the default branch",
+                Some('r'),
+            )
+            .switch(
+                "force",
+                "reinstall the package if it is already present",
+                Some('f'),
+            )
+            .category(Category::Experimental)
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The package is cloned with git and recorded, pinned to its exact revision,
+in the `pkg.lock` lockfile next to it. If the package ships a `package.nuon`
+manifest, its `dependencies` are installed as well.
+
+The package directory is appended to `$env.NU_LIB_DIRS` so installed modules
+can be imported with `use`. Since `use` resolves modules at parse time, import
+freshly installed packages from the next prompt or script, not from the same
+one that ran `pkg install`."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["package", "module", "add"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let url: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let revision: Option<String> = call.get_flag(engine_state, stack, "revision")?;
+        let force = call.has_flag("force");
+        let span = call.head;
+
+        let root = pkg_dir(engine_state, stack, span)?;
+        std::fs::create_dir_all(&root).map_err(|err| {
+            ShellError::GenericError(
+                format!("Cannot create {}", root.display()),
+                err.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let mut installed = vec![];
+        let mut visited = HashSet::new();
+        install_package(
+            engine_state,
+            &url.item,
+            revision.as_deref(),
+            &root,
+            force,
+            false,
+            url.span,
+            &mut installed,
+            &mut visited,
+        )?;
+
+        let mut lockfile = read_lockfile(engine_state, &root, span)?;
+        lockfile.retain(|entry| !visited.contains(&entry.name));
+        lockfile.extend(installed.iter().cloned());
+        lockfile.sort_by(|a, b| a.name.cmp(&b.name));
+        write_lockfile(&lockfile, &root, span)?;
+
+        // Register the package directory itself and each installed package, so
+        // both `use spam/spam.nu` and `use spam.nu` resolve
+        add_to_lib_dirs(engine_state, stack, &root, span);
+        for package in &installed {
+            add_to_lib_dirs(engine_state, stack, &root.join(&package.name), span);
+        }
+
+        Ok(Value::List {
+            vals: installed
+                .into_iter()
+                .map(|package| package.into_value(span))
+                .collect(),
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Install a package from a git URL",
+                example: "pkg install https://example.com/spam.git",
+                result: None,
+            },
+            Example {
+                description: "Install a specific tag of a package",
+                example: "pkg install --revision v1.2.3 https://example.com/spam.git",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// The directory name a package URL installs into: the last path component
+/// with any `.git` suffix removed.
+pub(super) fn package_name_from_url(url: &str, span: Span) -> Result<String, ShellError> {
+    let name = url
+        .trim_end_matches('/')
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or("")
+        .trim_end_matches(".git");
+
+    // The name becomes a directory under the package root, so it must be a
+    // plain file name: `.` or `..` (or a stray separator) would make the
+    // install target escape the root
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+        Err(ShellError::GenericError(
+            format!("Cannot derive a package name from '{url}'"),
+            "expected a URL ending in a repository name".into(),
+            Some(span),
+            None,
+            Vec::new(),
+        ))
+    } else {
+        Ok(name.to_string())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn install_package(
+    engine_state: &EngineState,
+    url: &str,
+    revision: Option<&str>,
+    root: &Path,
+    force: bool,
+    is_dependency: bool,
+    span: Span,
+    installed: &mut Vec<LockedPackage>,
+    visited: &mut HashSet<String>,
+) -> Result<(), ShellError> {
+    let name = package_name_from_url(url, span)?;
+    if !visited.insert(name.clone()) {
+        return Ok(());
+    }
+
+    let target = root.join(&name);
+    if target.exists() {
+        if force {
+            std::fs::remove_dir_all(&target).map_err(|err| {
+                ShellError::GenericError(
+                    format!("Cannot remove {}", target.display()),
+                    err.to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+        } else if is_dependency {
+            // An already installed dependency counts as satisfied
+            visited.remove(&name);
+            return Ok(());
+        } else {
+            return Err(ShellError::GenericError(
+                format!("Package '{name}' is already installed"),
+                "already installed".into(),
+                Some(span),
+                Some("use `--force` to reinstall it".into()),
+                Vec::new(),
+            ));
+        }
+    }
+
+    let target_str = target.to_string_lossy().to_string();
+    run_git(
+        &[
+            "clone".to_string(),
+            "--quiet".to_string(),
+            // `--` keeps a dash-prefixed URL from being read as a git option
+            "--".to_string(),
+            url.to_string(),
+            target_str.clone(),
+        ],
+        span,
+    )?;
+
+    if let Some(revision) = revision {
+        run_git(
+            &[
+                "-C".to_string(),
+                target_str.clone(),
+                "checkout".to_string(),
+                "--quiet".to_string(),
+                revision.to_string(),
+            ],
+            span,
+        )?;
+    }
+
+    let revision = run_git(
+        &[
+            "-C".to_string(),
+            target_str,
+            "rev-parse".to_string(),
+            "HEAD".to_string(),
+        ],
+        span,
+    )?;
+
+    let mut version = None;
+    let manifest_path = target.join(MANIFEST_NAME);
+    if manifest_path.exists() {
+        let contents = std::fs::read_to_string(&manifest_path).map_err(|err| {
+            ShellError::GenericError(
+                format!("Cannot read {}", manifest_path.display()),
+                err.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+        let manifest = crate::formats::from_nuon_string(engine_state, contents, span)?;
+
+        version = manifest
+            .get_data_by_key("version")
+            .and_then(|version| version.as_string().ok());
+
+        if let Some(Value::Record { vals, .. }) = manifest.get_data_by_key("dependencies") {
+            for dep_url in vals {
+                install_package(
+                    engine_state,
+                    &dep_url.as_string()?,
+                    None,
+                    root,
+                    false,
+                    true,
+                    span,
+                    installed,
+                    visited,
+                )?;
+            }
+        }
+    }
+
+    installed.push(LockedPackage {
+        name,
+        url: url.to_string(),
+        revision,
+        version,
+    });
+
+    Ok(())
+}
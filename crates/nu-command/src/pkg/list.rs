@@ -0,0 +1,59 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+use super::{pkg_dir, read_lockfile};
+
+#[derive(Clone)]
+pub struct PkgList;
+
+impl Command for PkgList {
+    fn name(&self) -> &str {
+        "pkg list"
+    }
+
+    fn usage(&self) -> &str {
+        "List the installed packages recorded in the lockfile."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pkg list")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .category(Category::Experimental)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["package", "module"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let root = pkg_dir(engine_state, stack, span)?;
+        let packages = read_lockfile(engine_state, &root, span)?;
+
+        Ok(Value::List {
+            vals: packages
+                .into_iter()
+                .map(|package| package.into_value(span))
+                .collect(),
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List installed packages",
+            example: "pkg list",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,226 @@
+mod dir;
+mod install;
+mod list;
+mod pkg_;
+mod remove;
+
+pub use dir::PkgDir;
+pub use install::PkgInstall;
+pub use list::PkgList;
+pub use pkg_::Pkg;
+pub use remove::PkgRemove;
+
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{ShellError, Span, Value};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub(crate) const LOCKFILE_NAME: &str = "pkg.lock";
+pub(crate) const MANIFEST_NAME: &str = "package.nuon";
+
+/// One entry of the lockfile: a package pinned to the exact revision that was
+/// installed.
+#[derive(Clone)]
+pub(crate) struct LockedPackage {
+    pub name: String,
+    pub url: String,
+    pub revision: String,
+    pub version: Option<String>,
+}
+
+impl LockedPackage {
+    pub(crate) fn into_value(self, span: Span) -> Value {
+        Value::Record {
+            cols: Arc::new(vec![
+                "name".to_string(),
+                "version".to_string(),
+                "url".to_string(),
+                "revision".to_string(),
+            ]),
+            vals: vec![
+                Value::string(self.name, span),
+                match self.version {
+                    Some(version) => Value::string(version, span),
+                    None => Value::Nothing { span },
+                },
+                Value::string(self.url, span),
+                Value::string(self.revision, span),
+            ],
+            span,
+        }
+    }
+}
+
+/// Where installed packages live. `$env.NU_PKG_DIR` overrides the default
+/// location under the config directory.
+pub(crate) fn pkg_dir(
+    engine_state: &EngineState,
+    stack: &Stack,
+    span: Span,
+) -> Result<PathBuf, ShellError> {
+    if let Some(dir) = stack.get_env_var(engine_state, "NU_PKG_DIR") {
+        return Ok(PathBuf::from(dir.as_string()?));
+    }
+
+    match nu_path::config_dir() {
+        Some(mut path) => {
+            path.push("nushell");
+            path.push("pkgs");
+            Ok(path)
+        }
+        None => Err(ShellError::GenericError(
+            "Could not find config directory".into(),
+            "could not resolve the package directory".into(),
+            Some(span),
+            Some("set $env.NU_PKG_DIR to pick a package directory explicitly".into()),
+            Vec::new(),
+        )),
+    }
+}
+
+pub(crate) fn read_lockfile(
+    engine_state: &EngineState,
+    root: &Path,
+    span: Span,
+) -> Result<Vec<LockedPackage>, ShellError> {
+    let lockfile_path = root.join(LOCKFILE_NAME);
+
+    if !lockfile_path.exists() {
+        return Ok(vec![]);
+    }
+
+    let contents = std::fs::read_to_string(&lockfile_path).map_err(|err| {
+        ShellError::GenericError(
+            format!("Cannot read {}", lockfile_path.display()),
+            err.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    let value = crate::formats::from_nuon_string(engine_state, contents, span)?;
+
+    let mut packages = vec![];
+    if let Value::Record { cols, vals, .. } = value {
+        for (name, entry) in cols.iter().zip(vals) {
+            let url = match entry.get_data_by_key("url") {
+                Some(url) => url.as_string()?,
+                None => String::new(),
+            };
+            let revision = match entry.get_data_by_key("revision") {
+                Some(revision) => revision.as_string()?,
+                None => String::new(),
+            };
+            let version = entry
+                .get_data_by_key("version")
+                .and_then(|version| version.as_string().ok());
+
+            packages.push(LockedPackage {
+                name: name.clone(),
+                url,
+                revision,
+                version,
+            });
+        }
+    }
+
+    Ok(packages)
+}
+
+pub(crate) fn write_lockfile(
+    packages: &[LockedPackage],
+    root: &Path,
+    span: Span,
+) -> Result<(), ShellError> {
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for package in packages {
+        let mut entry_cols = vec!["url".to_string(), "revision".to_string()];
+        let mut entry_vals = vec![
+            Value::string(&package.url, span),
+            Value::string(&package.revision, span),
+        ];
+        if let Some(version) = &package.version {
+            entry_cols.push("version".to_string());
+            entry_vals.push(Value::string(version, span));
+        }
+
+        cols.push(package.name.clone());
+        vals.push(Value::Record {
+            cols: Arc::new(entry_cols),
+            vals: entry_vals,
+            span,
+        });
+    }
+
+    let value = Value::Record {
+        cols: Arc::new(cols),
+        vals,
+        span,
+    };
+    let contents = crate::formats::value_to_string(&value, span, 0, &Some("  ".to_string()))?;
+
+    let lockfile_path = root.join(LOCKFILE_NAME);
+    std::fs::write(&lockfile_path, contents + "\n").map_err(|err| {
+        ShellError::GenericError(
+            format!("Cannot write {}", lockfile_path.display()),
+            err.to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    })
+}
+
+pub(crate) fn run_git(args: &[String], span: Span) -> Result<String, ShellError> {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|err| {
+            ShellError::GenericError(
+                "Failed to run git".into(),
+                err.to_string(),
+                Some(span),
+                Some("`pkg` requires git to be installed".into()),
+                Vec::new(),
+            )
+        })?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(ShellError::GenericError(
+            "git command failed".into(),
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        ))
+    }
+}
+
+/// Append the package directory to `$env.NU_LIB_DIRS` for this session so
+/// `use` can find installed modules.
+pub(crate) fn add_to_lib_dirs(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    root: &Path,
+    span: Span,
+) {
+    let root_str = root.to_string_lossy().to_string();
+
+    let mut dirs = match stack.get_env_var(engine_state, "NU_LIB_DIRS") {
+        Some(Value::List { vals, .. }) => vals,
+        _ => vec![],
+    };
+
+    if !dirs
+        .iter()
+        .any(|dir| dir.as_string().map(|s| s == root_str).unwrap_or(false))
+    {
+        dirs.push(Value::string(root_str, span));
+        stack.add_env_var("NU_LIB_DIRS".to_string(), Value::List { vals: dirs, span });
+    }
+}
@@ -0,0 +1,82 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Type,
+};
+
+use super::{pkg_dir, read_lockfile, write_lockfile};
+
+#[derive(Clone)]
+pub struct PkgRemove;
+
+impl Command for PkgRemove {
+    fn name(&self) -> &str {
+        "pkg remove"
+    }
+
+    fn usage(&self) -> &str {
+        "Remove an installed package and its lockfile entry."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pkg remove")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("name", SyntaxShape::String, "the name of the package")
+            .category(Category::Experimental)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["package", "module", "uninstall", "delete"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let span = call.head;
+
+        let root = pkg_dir(engine_state, stack, span)?;
+        let mut lockfile = read_lockfile(engine_state, &root, span)?;
+        let target = root.join(&name.item);
+
+        if !lockfile.iter().any(|entry| entry.name == name.item) && !target.exists() {
+            return Err(ShellError::GenericError(
+                format!("Package '{}' is not installed", name.item),
+                "not installed".into(),
+                Some(name.span),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        if target.exists() {
+            std::fs::remove_dir_all(&target).map_err(|err| {
+                ShellError::GenericError(
+                    format!("Cannot remove {}", target.display()),
+                    err.to_string(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                )
+            })?;
+        }
+
+        lockfile.retain(|entry| entry.name != name.item);
+        write_lockfile(&lockfile, &root, span)?;
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Remove an installed package",
+            example: "pkg remove spam",
+            result: None,
+        }]
+    }
+}
@@ -7,6 +7,7 @@ use nu_protocol::{
 };
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct AnsiCommand;
@@ -820,7 +821,7 @@ fn generate_ansi_code_list(
                 vec![name, short_name, code]
             };
             Value::Record {
-                cols,
+                cols: Arc::new(cols),
                 vals,
                 span: call_span,
             }
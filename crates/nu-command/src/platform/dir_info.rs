@@ -1,6 +1,7 @@
 use filesize::file_real_size_fast;
 use nu_glob::Pattern;
 use nu_protocol::{ShellError, Span, Value};
+use rayon::prelude::*;
 use std::path::PathBuf;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -12,6 +13,7 @@ pub struct DirBuilder {
     pub deref: bool,
     pub exclude: Option<Pattern>,
     pub all: bool,
+    pub parallel: bool,
 }
 
 impl DirBuilder {
@@ -21,6 +23,7 @@ impl DirBuilder {
         deref: bool,
         exclude: Option<Pattern>,
         all: bool,
+        parallel: bool,
     ) -> DirBuilder {
         DirBuilder {
             tag,
@@ -28,6 +31,7 @@ impl DirBuilder {
             deref,
             exclude,
             all,
+            parallel,
         }
     }
 }
@@ -105,6 +109,7 @@ impl DirInfo {
 
         match std::fs::read_dir(&s.path) {
             Ok(d) => {
+                let mut subdirs = Vec::new();
                 for f in d {
                     if nu_utils::ctrl_c::was_pressed(&ctrl_c) {
                         break;
@@ -113,7 +118,11 @@ impl DirInfo {
                     match f {
                         Ok(i) => match i.file_type() {
                             Ok(t) if t.is_dir() => {
-                                s = s.add_dir(i.path(), depth, params, ctrl_c.clone())
+                                if params.parallel {
+                                    subdirs.push(i.path());
+                                } else {
+                                    s = s.add_dir(i.path(), depth, params, ctrl_c.clone())
+                                }
                             }
                             Ok(_t) => s = s.add_file(i.path(), params),
                             Err(e) => s = s.add_error(e.into()),
@@ -121,6 +130,10 @@ impl DirInfo {
                         Err(e) => s = s.add_error(e.into()),
                     }
                 }
+
+                if !subdirs.is_empty() {
+                    s = s.add_dirs_parallel(subdirs, depth, params, ctrl_c);
+                }
             }
             Err(e) => s = s.add_error(e.into()),
         }
@@ -149,6 +162,34 @@ impl DirInfo {
         self
     }
 
+    fn add_dirs_parallel(
+        mut self,
+        paths: Vec<PathBuf>,
+        mut depth: Option<u64>,
+        params: &DirBuilder,
+        ctrl_c: Option<Arc<AtomicBool>>,
+    ) -> Self {
+        if let Some(current) = depth {
+            if let Some(new) = current.checked_sub(1) {
+                depth = Some(new);
+            } else {
+                return self;
+            }
+        }
+
+        let subdirs: Vec<DirInfo> = paths
+            .into_par_iter()
+            .map(|p| DirInfo::new(p, params, depth, ctrl_c.clone()))
+            .collect();
+
+        for d in subdirs {
+            self.size += d.size;
+            self.blocks += d.blocks;
+            self.dirs.push(d);
+        }
+        self
+    }
+
     fn add_file(mut self, f: impl Into<PathBuf>, params: &DirBuilder) -> Self {
         let f = f.into();
         let include = params
@@ -224,7 +265,7 @@ impl From<DirInfo> for Value {
         // }
 
         Value::Record {
-            cols,
+            cols: Arc::new(cols),
             vals,
             span: d.tag,
         }
@@ -264,7 +305,7 @@ impl From<FileInfo> for Value {
         // vals.push(Value::nothing(Span::unknown()));
 
         Value::Record {
-            cols,
+            cols: Arc::new(cols),
             vals,
             span: f.tag,
         }
@@ -4,8 +4,8 @@ use nu_glob::{GlobError, MatchOptions, Pattern};
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Spanned,
-    SyntaxShape, Type, Value,
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
 };
 use serde::Deserialize;
 use std::path::PathBuf;
@@ -74,6 +74,12 @@ impl Command for Du {
                 "Exclude files below this size",
                 Some('m'),
             )
+            .named(
+                "threads",
+                SyntaxShape::Int,
+                "The number of threads used to scan directories in parallel",
+                Some('t'),
+            )
             .category(Category::Core)
     }
 
@@ -87,6 +93,7 @@ impl Command for Du {
         let tag = call.head;
         let min_size: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "min-size")?;
         let max_depth: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "max-depth")?;
+        let threads: Option<usize> = call.get_flag(engine_state, stack, "threads")?;
         if let Some(ref max_depth) = max_depth {
             if max_depth.item < 0 {
                 return Err(ShellError::NeedsPositiveValue(max_depth.span));
@@ -119,7 +126,7 @@ impl Command for Du {
         })?;
 
         let include_files = args.all;
-        let mut paths = match args.path {
+        let paths = match args.path {
             Some(p) => {
                 let item = p.item.to_str().expect("Why isn't this encoded properly?");
                 match nu_glob::glob_with(item, GLOB_PARAMS) {
@@ -164,38 +171,68 @@ impl Command for Du {
             deref,
             exclude,
             all,
+            parallel: threads.is_some(),
         };
 
-        let mut output: Vec<Value> = vec![];
-        for p in paths.by_ref() {
-            match p {
-                Ok(a) => {
-                    if a.is_dir() {
-                        output.push(
-                            DirInfo::new(a, &params, max_depth, engine_state.ctrlc.clone()).into(),
-                        );
-                    } else if let Ok(v) = FileInfo::new(a, deref, tag) {
-                        output.push(v.into());
+        let ctrlc = engine_state.ctrlc.clone();
+        let collect = move || {
+            let mut output: Vec<Value> = vec![];
+            for p in paths {
+                match p {
+                    Ok(a) => {
+                        if a.is_dir() {
+                            output.push(DirInfo::new(a, &params, max_depth, ctrlc.clone()).into());
+                        } else if let Ok(v) = FileInfo::new(a, deref, tag) {
+                            output.push(v.into());
+                        }
+                    }
+                    Err(e) => {
+                        output.push(Value::Error { error: Box::new(e) });
                     }
-                }
-                Err(e) => {
-                    output.push(Value::Error { error: Box::new(e) });
                 }
             }
-        }
+            output
+        };
+
+        let output = match threads {
+            Some(threads) => create_pool(threads)?.install(collect),
+            None => collect(),
+        };
 
         Ok(output.into_pipeline_data(engine_state.ctrlc.clone()))
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Disk usage of the current directory",
-            example: "du",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Disk usage of the current directory",
+                example: "du",
+                result: None,
+            },
+            Example {
+                description: "Disk usage of the current directory, scanning with four threads",
+                example: "du --threads 4",
+                result: None,
+            },
+        ]
     }
 }
 
+fn create_pool(num_threads: usize) -> Result<rayon::ThreadPool, ShellError> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .map_err(|e| {
+            ShellError::GenericError(
+                "Error creating thread pool".into(),
+                e.to_string(),
+                Some(Span::unknown()),
+                None,
+                Vec::new(),
+            )
+        })
+}
+
 fn glob_err_into(e: GlobError) -> ShellError {
     let e = e.into_error();
     ShellError::from(e)
@@ -1,7 +1,7 @@
 use crossterm::event::{Event, KeyCode, KeyModifiers};
-use nu_engine::CallExt;
+use nu_engine::{eval_block, CallExt};
 use nu_protocol::ast::Call;
-use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
     Value,
@@ -39,6 +39,29 @@ impl Command for Input {
                 Some('u'),
             )
             .switch("suppress-output", "don't print keystroke values", Some('s'))
+            .switch(
+                "password",
+                "don't echo the input and prompt for it like a password",
+                Some('p'),
+            )
+            .named(
+                "choices",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "render an interactive menu and return the picked choice",
+                Some('c'),
+            )
+            .named(
+                "default",
+                SyntaxShape::String,
+                "value to return when nothing is entered",
+                Some('d'),
+            )
+            .named(
+                "validate",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "closure that must return true before the input is accepted",
+                Some('v'),
+            )
             .category(Category::Platform)
     }
 
@@ -52,6 +75,10 @@ impl Command for Input {
         let prompt: Option<String> = call.opt(engine_state, stack, 0)?;
         let bytes_until: Option<String> = call.get_flag(engine_state, stack, "bytes-until")?;
         let suppress_output = call.has_flag("suppress-output");
+        let password = call.has_flag("password");
+        let choices: Option<Vec<String>> = call.get_flag(engine_state, stack, "choices")?;
+        let default: Option<String> = call.get_flag(engine_state, stack, "default")?;
+        let validate: Option<Closure> = call.get_flag(engine_state, stack, "validate")?;
 
         if let Some(bytes_until) = bytes_until {
             let _ = crossterm::terminal::enable_raw_mode();
@@ -96,81 +123,185 @@ impl Command for Input {
                     "input can't stop on this byte".to_string(),
                 ))
             }
-        } else {
-            if let Some(prompt) = prompt {
-                print!("{prompt}");
-                let _ = std::io::stdout().flush();
+        } else if let Some(choices) = choices {
+            if choices.is_empty() {
+                return Err(ShellError::GenericError(
+                    "No choices to pick from".into(),
+                    "the choices list is empty".into(),
+                    Some(call.head),
+                    None,
+                    Vec::new(),
+                ));
             }
 
-            let mut buf = String::new();
+            let mut menu = dialoguer::Select::new();
+            menu.items(&choices).default(0);
+            if let Some(prompt) = &prompt {
+                menu.with_prompt(prompt.as_str());
+            }
+            if let Some(default) = &default {
+                if let Some(index) = choices.iter().position(|choice| choice == default) {
+                    menu.default(index);
+                }
+            }
 
-            if suppress_output {
-                crossterm::terminal::enable_raw_mode()?;
-                loop {
-                    match crossterm::event::read() {
-                        Ok(Event::Key(k)) => match k.code {
-                            // TODO: maintain keycode parity with existing command
-                            KeyCode::Char(c) => {
-                                if k.modifiers == KeyModifiers::ALT
-                                    || k.modifiers == KeyModifiers::CONTROL
-                                {
-                                    if k.modifiers == KeyModifiers::CONTROL && c == 'c' {
-                                        crossterm::terminal::disable_raw_mode()?;
-                                        return Err(ShellError::IOError("SIGINT".to_string()));
-                                    }
-                                    continue;
-                                }
-
-                                buf.push(c);
-                            }
-                            KeyCode::Backspace => {
-                                let _ = buf.pop();
+            let index = menu
+                .interact()
+                .map_err(|err| ShellError::IOError(err.to_string()))?;
+
+            Ok(Value::String {
+                val: choices[index].clone(),
+                span: call.head,
+            }
+            .into_pipeline_data())
+        } else {
+            loop {
+                let mut buf = {
+                    if let Some(prompt) = &prompt {
+                        print!("{prompt}");
+                        let _ = std::io::stdout().flush();
+                    }
+
+                    if password {
+                        // read without echoing the keystrokes, then move past
+                        // the prompt line since raw mode swallowed the Enter
+                        let secret = read_suppressed()?;
+                        println!();
+                        secret
+                    } else if suppress_output {
+                        read_suppressed()?
+                    } else {
+                        // Just read a normal line of text, and trim the newline at the end
+                        let mut buf = String::new();
+                        if let Err(err) = std::io::stdin().read_line(&mut buf) {
+                            return Err(ShellError::IOError(err.to_string()));
+                        }
+                        if buf.ends_with('\n') {
+                            buf.pop();
+                            if buf.ends_with('\r') {
+                                buf.pop();
                             }
-                            KeyCode::Enter => break,
-                            _ => continue,
-                        },
-                        Ok(_) => continue,
-                        Err(event_error) => {
-                            crossterm::terminal::disable_raw_mode()?;
-                            return Err(event_error.into());
                         }
+                        buf
+                    }
+                };
+
+                if buf.is_empty() {
+                    if let Some(default) = &default {
+                        buf = default.clone();
                     }
                 }
-                crossterm::terminal::disable_raw_mode()?;
-                return Ok(Value::String {
+
+                let value = Value::String {
                     val: buf,
                     span: call.head,
+                };
+                match &validate {
+                    Some(closure) if !is_accepted(engine_state, stack, closure, &value, call) => {
+                        println!("That input is not valid, please try again.");
+                    }
+                    _ => return Ok(value.into_pipeline_data()),
                 }
-                .into_pipeline_data());
             }
+        }
+    }
 
-            // Just read a normal line of text, and trim the newline at the end
-            let input = std::io::stdin().read_line(&mut buf);
-            if buf.ends_with('\n') {
-                buf.pop();
-                if buf.ends_with('\r') {
-                    buf.pop();
-                }
-            }
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get input from the user, and assign to a variable",
+                example: "let user_input = (input)",
+                result: None,
+            },
+            Example {
+                description: "Get a password without echoing it",
+                example: "let secret = (input --password 'api token: ')",
+                result: None,
+            },
+            Example {
+                description: "Pick one entry from an interactive menu",
+                example: "input 'which environment? ' --choices [dev staging prod]",
+                result: None,
+            },
+            Example {
+                description: "Fall back to a default when nothing is entered",
+                example: "input 'name: ' --default anonymous",
+                result: None,
+            },
+            Example {
+                description: "Keep prompting until the input passes a closure",
+                example: "input 'port: ' --validate {|it| $it =~ '^[0-9]+$' }",
+                result: None,
+            },
+        ]
+    }
+}
 
-            match input {
-                Ok(_) => Ok(Value::String {
-                    val: buf,
-                    span: call.head,
+fn read_suppressed() -> Result<String, ShellError> {
+    let mut buf = String::new();
+
+    crossterm::terminal::enable_raw_mode()?;
+    loop {
+        match crossterm::event::read() {
+            Ok(Event::Key(k)) => match k.code {
+                // TODO: maintain keycode parity with existing command
+                KeyCode::Char(c) => {
+                    if k.modifiers == KeyModifiers::ALT || k.modifiers == KeyModifiers::CONTROL {
+                        if k.modifiers == KeyModifiers::CONTROL && c == 'c' {
+                            crossterm::terminal::disable_raw_mode()?;
+                            return Err(ShellError::IOError("SIGINT".to_string()));
+                        }
+                        continue;
+                    }
+
+                    buf.push(c);
+                }
+                KeyCode::Backspace => {
+                    let _ = buf.pop();
                 }
-                .into_pipeline_data()),
-                Err(err) => Err(ShellError::IOError(err.to_string())),
+                KeyCode::Enter => break,
+                _ => continue,
+            },
+            Ok(_) => continue,
+            Err(event_error) => {
+                crossterm::terminal::disable_raw_mode()?;
+                return Err(event_error.into());
             }
         }
     }
+    crossterm::terminal::disable_raw_mode()?;
 
-    fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Get input from the user, and assign to a variable",
-            example: "let user_input = (input)",
-            result: None,
-        }]
+    Ok(buf)
+}
+
+fn is_accepted(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    closure: &Closure,
+    value: &Value,
+    call: &Call,
+) -> bool {
+    let block = engine_state.get_block(closure.block_id);
+    let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
+
+    let mut callee_stack = stack.captures_to_stack(&closure.captures);
+    if let Some(var_id) = var_id {
+        callee_stack.add_var(var_id, value.clone());
     }
+
+    // an error from the closure counts as a rejection, so the prompt loops
+    // instead of bailing out of the script
+    eval_block(
+        engine_state,
+        &mut callee_stack,
+        block,
+        value.clone().into_pipeline_data(),
+        call.redirect_stdout,
+        call.redirect_stderr,
+    )
+    .map(|data| data.into_value(call.head))
+    .map(|result| result.is_true())
+    .unwrap_or(false)
 }
 
 #[cfg(test)]
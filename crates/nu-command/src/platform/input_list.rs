@@ -0,0 +1,219 @@
+use dialoguer::{FuzzySelect, MultiSelect, Select};
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct InputList;
+
+impl Command for InputList {
+    fn name(&self) -> &str {
+        "input list"
+    }
+
+    fn usage(&self) -> &str {
+        "Interactively pick rows from the input with a list menu."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The original rows are returned, not their rendered menu entries, so the picker can sit in the middle of a pipeline. Abort the menu with the escape key to return nothing."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["prompt", "ask", "menu", "pick", "fuzzy"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("input list")
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Any)), Type::Any),
+                (Type::Table(vec![]), Type::Any),
+            ])
+            .allow_variants_without_examples(true)
+            .optional("prompt", SyntaxShape::String, "prompt to show the user")
+            .switch("multi", "allow picking more than one row", Some('m'))
+            .switch("fuzzy", "filter the menu with fuzzy search", Some('f'))
+            .named(
+                "display",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "closure that renders a row as its menu entry",
+                Some('d'),
+            )
+            .category(Category::Platform)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let prompt: Option<String> = call.opt(engine_state, stack, 0)?;
+        let multi = call.has_flag("multi");
+        let fuzzy = call.has_flag("fuzzy");
+        let display: Option<Closure> = call.get_flag(engine_state, stack, "display")?;
+
+        if multi && fuzzy {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: "multi".to_string(),
+                left_span: flag_span(call, "multi", head)?,
+                right_message: "fuzzy search only supports a single pick".to_string(),
+                right_span: flag_span(call, "fuzzy", head)?,
+            });
+        }
+
+        let rows: Vec<Value> = input.into_iter().collect();
+        if rows.is_empty() {
+            return Err(ShellError::GenericError(
+                "No rows to pick from".into(),
+                "the input is empty".into(),
+                Some(head),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        let config = engine_state.get_config();
+        let entries = rows
+            .iter()
+            .map(|row| match &display {
+                Some(closure) => render_row(engine_state, stack, closure, row, call),
+                None => Ok(row.clone().into_string(", ", config)),
+            })
+            .collect::<Result<Vec<String>, ShellError>>()?;
+
+        let interact_error = |err: std::io::Error| ShellError::IOError(err.to_string());
+        let picked = if multi {
+            let mut menu = MultiSelect::new();
+            menu.items(&entries);
+            if let Some(prompt) = &prompt {
+                menu.with_prompt(prompt.as_str());
+            }
+            menu.interact_opt().map_err(interact_error)?
+        } else if fuzzy {
+            let mut menu = FuzzySelect::new();
+            menu.items(&entries).default(0);
+            if let Some(prompt) = &prompt {
+                menu.with_prompt(prompt.as_str());
+            }
+            menu.interact_opt()
+                .map_err(interact_error)?
+                .map(|index| vec![index])
+        } else {
+            let mut menu = Select::new();
+            menu.items(&entries).default(0);
+            if let Some(prompt) = &prompt {
+                menu.with_prompt(prompt.as_str());
+            }
+            menu.interact_opt()
+                .map_err(interact_error)?
+                .map(|index| vec![index])
+        };
+
+        let Some(picked) = picked else {
+            // the menu was aborted with the escape key
+            return Ok(Value::nothing(head).into_pipeline_data());
+        };
+
+        let mut rows = rows;
+        if multi {
+            let mut selected = vec![];
+            for index in picked.into_iter().rev() {
+                selected.push(rows.swap_remove(index));
+            }
+            selected.reverse();
+            Ok(Value::List {
+                vals: selected,
+                span: head,
+            }
+            .into_pipeline_data())
+        } else {
+            Ok(rows.swap_remove(picked[0]).into_pipeline_data())
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Pick a file from the current directory",
+                example: "ls | input list 'open which file?'",
+                result: None,
+            },
+            Example {
+                description: "Pick several packages with fuzzy search off",
+                example: "[cargo rustc clippy] | input list --multi",
+                result: None,
+            },
+            Example {
+                description: "Fuzzy search a large list",
+                example: "ls /usr/bin | input list --fuzzy 'run what?'",
+                result: None,
+            },
+            Example {
+                description: "Render each row through a closure",
+                example: "ls | input list --display {|row| $\"($row.name) [($row.size)]\" }",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn flag_span(call: &Call, flag: &str, head: Span) -> Result<Span, ShellError> {
+    Ok(call
+        .get_named_arg(flag)
+        .ok_or_else(|| {
+            ShellError::GenericError(
+                "Flag error".into(),
+                format!("flag {flag} not found"),
+                Some(head),
+                None,
+                Vec::new(),
+            )
+        })?
+        .span)
+}
+
+fn render_row(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    closure: &Closure,
+    row: &Value,
+    call: &Call,
+) -> Result<String, ShellError> {
+    let block = engine_state.get_block(closure.block_id);
+    let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
+
+    let mut callee_stack = stack.captures_to_stack(&closure.captures);
+    if let Some(var_id) = var_id {
+        callee_stack.add_var(var_id, row.clone());
+    }
+
+    let value = eval_block(
+        engine_state,
+        &mut callee_stack,
+        block,
+        row.clone().into_pipeline_data(),
+        call.redirect_stdout,
+        call.redirect_stderr,
+    )?
+    .into_value(call.head);
+
+    Ok(value.into_string(", ", engine_state.get_config()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputList;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::test_examples;
+        test_examples(InputList {})
+    }
+}
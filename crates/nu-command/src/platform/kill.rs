@@ -3,9 +3,9 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{ast::Call, span};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
-    Signature, Spanned, SyntaxShape, Type, Value,
+    Signature, SyntaxShape, Type, Value,
 };
-use std::process::{Command as CommandSys, Stdio};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Kill;
@@ -16,7 +16,7 @@ impl Command for Kill {
     }
 
     fn usage(&self) -> &str {
-        "Kill a process using the process id."
+        "Send a signal to processes and report the result per process."
     }
 
     fn signature(&self) -> Signature {
@@ -37,16 +37,26 @@ impl Command for Kill {
             return signature;
         }
 
-        signature.named(
-            "signal",
-            SyntaxShape::Int,
-            "signal decimal number to be sent instead of the default 15 (unsupported on Windows)",
-            Some('s'),
-        )
+        signature
+            .named(
+                "signal",
+                SyntaxShape::Any,
+                "name or number of the signal to send instead of the default TERM (unsupported on Windows)",
+                Some('s'),
+            )
+            .switch(
+                "group",
+                "treat every id as a process group id and signal the whole group (unsupported on Windows)",
+                Some('g'),
+            )
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Returns one row per process with its pid, whether the signal was delivered, and the error otherwise."
     }
 
     fn search_terms(&self) -> Vec<&str> {
-        vec!["stop", "end", "close"]
+        vec!["stop", "end", "close", "signal"]
     }
 
     fn run(
@@ -56,131 +66,60 @@ impl Command for Kill {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
         let pid: i64 = call.req(engine_state, stack, 0)?;
         let rest: Vec<i64> = call.rest(engine_state, stack, 1)?;
         let force: bool = call.has_flag("force");
-        let signal: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "signal")?;
+        let signal: Option<Value> = call.get_flag(engine_state, stack, "signal")?;
         let quiet: bool = call.has_flag("quiet");
 
-        let mut cmd = if cfg!(windows) {
-            let mut cmd = CommandSys::new("taskkill");
-
-            if force {
-                cmd.arg("/F");
-            }
-
-            cmd.arg("/PID");
-            cmd.arg(pid.to_string());
-
-            // each pid must written as `/PID 0` otherwise
-            // taskkill will act as `killall` unix command
-            for id in &rest {
-                cmd.arg("/PID");
-                cmd.arg(id.to_string());
-            }
-
-            cmd
-        } else {
-            let mut cmd = CommandSys::new("kill");
-            if force {
-                if let Some(Spanned {
-                    item: _,
-                    span: signal_span,
-                }) = signal
-                {
-                    return Err(ShellError::IncompatibleParameters {
-                        left_message: "force".to_string(),
-                        left_span: call
-                            .get_named_arg("force")
+        if force {
+            if let Some(signal) = &signal {
+                return Err(ShellError::IncompatibleParameters {
+                    left_message: "force".to_string(),
+                    left_span: call
+                        .get_named_arg("force")
+                        .ok_or_else(|| {
+                            ShellError::GenericError(
+                                "Flag error".into(),
+                                "flag force not found".into(),
+                                Some(head),
+                                None,
+                                Vec::new(),
+                            )
+                        })?
+                        .span,
+                    right_message: "signal".to_string(),
+                    right_span: span(&[
+                        call.get_named_arg("signal")
                             .ok_or_else(|| {
                                 ShellError::GenericError(
                                     "Flag error".into(),
-                                    "flag force not found".into(),
-                                    Some(call.head),
+                                    "flag signal not found".into(),
+                                    Some(head),
                                     None,
                                     Vec::new(),
                                 )
                             })?
                             .span,
-                        right_message: "signal".to_string(),
-                        right_span: span(&[
-                            call.get_named_arg("signal")
-                                .ok_or_else(|| {
-                                    ShellError::GenericError(
-                                        "Flag error".into(),
-                                        "flag signal not found".into(),
-                                        Some(call.head),
-                                        None,
-                                        Vec::new(),
-                                    )
-                                })?
-                                .span,
-                            signal_span,
-                        ]),
-                    });
-                }
-                cmd.arg("-9");
-            } else if let Some(signal_value) = signal {
-                cmd.arg(format!("-{}", signal_value.item));
+                        signal.expect_span(),
+                    ]),
+                });
             }
-
-            cmd.arg(pid.to_string());
-
-            cmd.args(rest.iter().map(move |id| id.to_string()));
-
-            cmd
-        };
-
-        // pipe everything to null
-        if quiet {
-            cmd.stdin(Stdio::null())
-                .stdout(Stdio::null())
-                .stderr(Stdio::null());
         }
 
-        let output = cmd.output().map_err(|e| {
-            ShellError::GenericError(
-                "failed to execute shell command".into(),
-                e.to_string(),
-                Some(call.head),
-                None,
-                Vec::new(),
-            )
-        })?;
+        let results = send_signals(
+            call,
+            std::iter::once(pid).chain(rest),
+            signal.as_ref(),
+            force,
+        )?;
 
-        if !quiet && !output.status.success() {
-            return Err(ShellError::GenericError(
-                "process didn't terminate successfully".into(),
-                String::from_utf8(output.stderr).unwrap_or_default(),
-                Some(call.head),
-                None,
-                Vec::new(),
-            ));
-        }
-
-        let val = String::from(
-            String::from_utf8(output.stdout)
-                .map_err(|e| {
-                    ShellError::GenericError(
-                        "failed to convert output to string".into(),
-                        e.to_string(),
-                        Some(call.head),
-                        None,
-                        Vec::new(),
-                    )
-                })?
-                .trim_end(),
-        );
-        if val.is_empty() {
-            Ok(Value::Nothing { span: call.head }.into_pipeline_data())
-        } else {
-            Ok(vec![Value::String {
-                val,
-                span: call.head,
-            }]
-            .into_iter()
-            .into_pipeline_data(engine_state.ctrlc.clone()))
+        if quiet {
+            return Ok(Value::nothing(head).into_pipeline_data());
         }
+        Ok(results.into_pipeline_data(engine_state.ctrlc.clone()))
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -197,14 +136,156 @@ impl Command for Kill {
             },
             #[cfg(not(target_os = "windows"))]
             Example {
-                description: "Send INT signal",
-                example: "kill -s 2 12345",
+                description: "Send the INT signal by name",
+                example: "kill -s int 12345",
+                result: None,
+            },
+            #[cfg(not(target_os = "windows"))]
+            Example {
+                description: "Stop a whole process group",
+                example: "kill --group -s stop 12345",
                 result: None,
             },
         ]
     }
 }
 
+// one row per target: its pid, whether the signal was delivered, and the error otherwise
+fn target_record(pid: i64, error: Option<String>, span: nu_protocol::Span) -> Value {
+    Value::Record {
+        cols: Arc::new(vec!["pid".into(), "success".into(), "error".into()]),
+        vals: vec![
+            Value::Int { val: pid, span },
+            Value::Bool {
+                val: error.is_none(),
+                span,
+            },
+            match error {
+                Some(val) => Value::String { val, span },
+                None => Value::nothing(span),
+            },
+        ],
+        span,
+    }
+}
+
+#[cfg(unix)]
+fn send_signals(
+    call: &Call,
+    targets: impl Iterator<Item = i64>,
+    signal: Option<&Value>,
+    force: bool,
+) -> Result<Vec<Value>, ShellError> {
+    let head = call.head;
+
+    let signal = match signal {
+        _ if force => libc::SIGKILL,
+        Some(signal) => signal_number(signal)?,
+        None => libc::SIGTERM,
+    };
+    let group: bool = call.has_flag("group");
+
+    let mut results = vec![];
+    for pid in targets {
+        let target = if group { -pid } else { pid };
+        // SAFETY: kill has no effect on our own memory
+        let error = if unsafe { libc::kill(target as libc::pid_t, signal) } == 0 {
+            None
+        } else {
+            let err = std::io::Error::last_os_error();
+            Some(match err.raw_os_error() {
+                Some(libc::EPERM) => {
+                    "permission denied: the process belongs to another user".into()
+                }
+                Some(libc::ESRCH) => "no such process".into(),
+                _ => err.to_string(),
+            })
+        };
+        results.push(target_record(pid, error, head));
+    }
+    Ok(results)
+}
+
+#[cfg(windows)]
+fn send_signals(
+    call: &Call,
+    targets: impl Iterator<Item = i64>,
+    _signal: Option<&Value>,
+    force: bool,
+) -> Result<Vec<Value>, ShellError> {
+    let head = call.head;
+
+    let mut results = vec![];
+    for pid in targets {
+        let mut cmd = std::process::Command::new("taskkill");
+        if force {
+            cmd.arg("/F");
+        }
+        cmd.arg("/PID");
+        cmd.arg(pid.to_string());
+
+        let output = cmd.output().map_err(|e| {
+            ShellError::GenericError(
+                "failed to execute taskkill".into(),
+                e.to_string(),
+                Some(head),
+                None,
+                Vec::new(),
+            )
+        })?;
+        let error = if output.status.success() {
+            None
+        } else {
+            Some(String::from_utf8_lossy(&output.stderr).trim().to_string())
+        };
+        results.push(target_record(pid, error, head));
+    }
+    Ok(results)
+}
+
+#[cfg(unix)]
+const SIGNALS: [(&str, libc::c_int); 16] = [
+    ("HUP", libc::SIGHUP),
+    ("INT", libc::SIGINT),
+    ("QUIT", libc::SIGQUIT),
+    ("ABRT", libc::SIGABRT),
+    ("KILL", libc::SIGKILL),
+    ("USR1", libc::SIGUSR1),
+    ("USR2", libc::SIGUSR2),
+    ("PIPE", libc::SIGPIPE),
+    ("ALRM", libc::SIGALRM),
+    ("TERM", libc::SIGTERM),
+    ("CHLD", libc::SIGCHLD),
+    ("CONT", libc::SIGCONT),
+    ("STOP", libc::SIGSTOP),
+    ("TSTP", libc::SIGTSTP),
+    ("WINCH", libc::SIGWINCH),
+    ("URG", libc::SIGURG),
+];
+
+#[cfg(unix)]
+fn signal_number(signal: &Value) -> Result<libc::c_int, ShellError> {
+    match signal {
+        Value::Int { val, .. } => Ok(*val as libc::c_int),
+        Value::String { val, .. } => {
+            let upper = val.to_uppercase();
+            let name = upper.strip_prefix("SIG").unwrap_or(&upper);
+            SIGNALS
+                .into_iter()
+                .find(|(signal, _)| *signal == name)
+                .map(|(_, number)| number)
+                .ok_or_else(|| ShellError::TypeMismatch {
+                    err_message: format!("unknown signal name '{val}'"),
+                    span: signal.expect_span(),
+                })
+        }
+        other => Err(ShellError::TypeMismatch {
+            err_message: format!("expected a signal name or number, got {}", other.get_type()),
+            span: other.expect_span(),
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Kill;
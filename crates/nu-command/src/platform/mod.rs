@@ -3,15 +3,23 @@ mod clear;
 mod dir_info;
 mod du;
 mod input;
+mod input_list;
 mod kill;
 mod sleep;
+mod term;
 mod term_size;
+#[cfg(unix)]
+mod ulimit;
 
 pub use ansi::{Ansi, AnsiGradient, AnsiLink, AnsiStrip};
 pub use clear::Clear;
 pub use dir_info::{DirBuilder, DirInfo, FileInfo};
 pub use du::Du;
 pub use input::Input;
+pub use input_list::InputList;
 pub use kill::Kill;
 pub use sleep::Sleep;
+pub use term::{Term, TermOnResize, TermQuery};
 pub use term_size::TermSize;
+#[cfg(unix)]
+pub use ulimit::Ulimit;
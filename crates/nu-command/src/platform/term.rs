@@ -0,0 +1,366 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crossterm::event::{Event, KeyCode, KeyModifiers};
+use nu_engine::{eval_block, get_full_help, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Closure, Command, EngineState, Stack, StateWorkingSet};
+use nu_protocol::{
+    format_error, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+const CHECK_CTRL_C_FREQUENCY: Duration = Duration::from_millis(100);
+
+#[derive(Clone)]
+pub struct Term;
+
+impl Command for Term {
+    fn name(&self) -> &str {
+        "term"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("term")
+            .category(Category::Platform)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn usage(&self) -> &str {
+        "Inspect and react to the terminal the shell runs in."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &Self.signature(),
+                &Self.examples(),
+                engine_state,
+                stack,
+                self.is_parser_keyword(),
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct TermQuery;
+
+impl Command for TermQuery {
+    fn name(&self) -> &str {
+        "term query"
+    }
+
+    fn usage(&self) -> &str {
+        "Report the capabilities of the terminal and the cursor position."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Some capabilities can only be discovered by asking the terminal and waiting
+briefly for an answer. When the shell is not attached to a terminal, or the
+terminal does not answer, those columns are null."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["capabilities", "truecolor", "sixel", "cursor", "tty"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("term query")
+            .input_output_types(vec![(Type::Nothing, Type::Record(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::Platform)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let truecolor = std::env::var("COLORTERM")
+            .map(|colorterm| colorterm == "truecolor" || colorterm == "24bit")
+            .unwrap_or(false);
+
+        // DECRQM for mode 2026, the synchronized output extension
+        let synchronized_output = match query_terminal(b"\x1b[?2026$p", b'y') {
+            Some(reply) => {
+                let reply = String::from_utf8_lossy(&reply);
+                Value::Bool {
+                    val: reply.contains("2026;1") || reply.contains("2026;2"),
+                    span: head,
+                }
+            }
+            None => Value::nothing(head),
+        };
+
+        let mut graphics = vec![];
+        // attribute 4 in the primary device attributes reply means sixel
+        if let Some(reply) = query_terminal(b"\x1b[c", b'c') {
+            let reply = String::from_utf8_lossy(&reply);
+            if reply
+                .trim_start_matches("\x1b[?")
+                .trim_end_matches('c')
+                .split(';')
+                .any(|attribute| attribute == "4")
+            {
+                graphics.push(Value::String {
+                    val: "sixel".into(),
+                    span: head,
+                });
+            }
+        }
+        // the kitty graphics protocol is advertised through the environment
+        if std::env::var("KITTY_WINDOW_ID").is_ok()
+            || std::env::var("TERM").map_or(false, |term| term == "xterm-kitty")
+        {
+            graphics.push(Value::String {
+                val: "kitty".into(),
+                span: head,
+            });
+        }
+
+        let cursor = match crossterm::cursor::position() {
+            Ok((x, y)) => Value::Record {
+                cols: Arc::new(vec!["x".into(), "y".into()]),
+                vals: vec![Value::int(x as i64, head), Value::int(y as i64, head)],
+                span: head,
+            },
+            Err(_) => Value::nothing(head),
+        };
+
+        Ok(Value::Record {
+            cols: Arc::new(vec![
+                "truecolor".into(),
+                "synchronized_output".into(),
+                "graphics".into(),
+                "cursor".into(),
+            ]),
+            vals: vec![
+                Value::Bool {
+                    val: truecolor,
+                    span: head,
+                },
+                synchronized_output,
+                Value::List {
+                    vals: graphics,
+                    span: head,
+                },
+                cursor,
+            ],
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Check whether the terminal renders 24-bit color",
+                example: "(term query).truecolor",
+                result: None,
+            },
+            Example {
+                description: "Get the position of the cursor",
+                example: "(term query).cursor",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[derive(Clone)]
+pub struct TermOnResize;
+
+impl Command for TermOnResize {
+    fn name(&self) -> &str {
+        "term on-resize"
+    }
+
+    fn usage(&self) -> &str {
+        "Execute Nu code whenever the terminal is resized."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["event", "hook", "watch", "sigwinch"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("term on-resize")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Record])),
+                "Some Nu code to run for every resize. The closure is passed the new `{columns, rows}` record",
+            )
+            .category(Category::Platform)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let capture_block: Closure = call.req(engine_state, stack, 0)?;
+        let block = engine_state.get_block(capture_block.block_id);
+        let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
+        let ctrlc_ref = &engine_state.ctrlc.clone();
+
+        eprintln!("Now watching for resize events. Press ctrl+c to abort.");
+
+        loop {
+            if nu_utils::ctrl_c::was_pressed(ctrlc_ref) {
+                break;
+            }
+
+            match crossterm::event::poll(CHECK_CTRL_C_FREQUENCY) {
+                Ok(false) => continue,
+                Ok(true) => {}
+                Err(err) => return Err(err.into()),
+            }
+
+            match crossterm::event::read() {
+                Ok(Event::Resize(columns, rows)) => {
+                    let size = size_record(columns, rows, head);
+                    let stack = &mut stack.clone();
+                    let mut callee_stack = stack.captures_to_stack(&capture_block.captures);
+                    if let Some(var_id) = var_id {
+                        callee_stack.add_var(var_id, size.clone());
+                    }
+
+                    let eval_result = eval_block(
+                        engine_state,
+                        &mut callee_stack,
+                        block,
+                        size.into_pipeline_data(),
+                        call.redirect_stdout,
+                        call.redirect_stderr,
+                    );
+
+                    match eval_result {
+                        Ok(val) => {
+                            val.print(engine_state, &mut callee_stack, false, false)?;
+                        }
+                        Err(err) => {
+                            let working_set = StateWorkingSet::new(engine_state);
+                            eprintln!("{}", format_error(&working_set, &err));
+                        }
+                    }
+                }
+                Ok(Event::Key(key)) => {
+                    if key.modifiers == KeyModifiers::CONTROL && key.code == KeyCode::Char('c') {
+                        break;
+                    }
+                }
+                Ok(_) => continue,
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Log every new terminal size",
+                example: "term on-resize {|size| $\"($size.columns) x ($size.rows)\" }",
+                result: None,
+            },
+            Example {
+                description: "Redraw a banner to fit the terminal",
+                example: "term on-resize {|size| 'welcome' | fill --width $size.columns --alignment center }",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn size_record(columns: u16, rows: u16, span: Span) -> Value {
+    Value::Record {
+        cols: Arc::new(vec!["columns".into(), "rows".into()]),
+        vals: vec![
+            Value::int(columns as i64, span),
+            Value::int(rows as i64, span),
+        ],
+        span,
+    }
+}
+
+/// Write an escape sequence to the terminal and collect the reply up to and
+/// including `terminator`, or `None` when there is no terminal or no answer.
+#[cfg(unix)]
+fn query_terminal(query: &[u8], terminator: u8) -> Option<Vec<u8>> {
+    use std::io::{Read, Write};
+
+    if !atty::is(atty::Stream::Stdin) || !atty::is(atty::Stream::Stdout) {
+        return None;
+    }
+
+    crossterm::terminal::enable_raw_mode().ok()?;
+    let reply = (|| {
+        let mut stdout = std::io::stdout();
+        stdout.write_all(query).ok()?;
+        stdout.flush().ok()?;
+
+        let mut stdin = std::io::stdin();
+        let mut buf = [0u8; 1];
+        let mut reply = vec![];
+        loop {
+            let mut pollfd = libc::pollfd {
+                fd: libc::STDIN_FILENO,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            // SAFETY: the pollfd stays valid for the duration of the call
+            if unsafe { libc::poll(&mut pollfd, 1, 100) } <= 0 {
+                // terminals that don't understand the query never answer
+                return None;
+            }
+            stdin.read_exact(&mut buf).ok()?;
+            reply.push(buf[0]);
+            if buf[0] == terminator {
+                return Some(reply);
+            }
+            if reply.len() > 256 {
+                return None;
+            }
+        }
+    })();
+    let _ = crossterm::terminal::disable_raw_mode();
+    reply
+}
+
+#[cfg(not(unix))]
+fn query_terminal(_query: &[u8], _terminator: u8) -> Option<Vec<u8>> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TermQuery;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::test_examples;
+        test_examples(TermQuery {})
+    }
+}
@@ -3,6 +3,7 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
 };
+use std::sync::Arc;
 use terminal_size::{terminal_size, Height, Width};
 
 #[derive(Clone)]
@@ -64,7 +65,7 @@ impl Command for TermSize {
         };
 
         Ok(Value::Record {
-            cols: vec!["columns".into(), "rows".into()],
+            cols: Arc::new(vec!["columns".into(), "rows".into()]),
             vals: vec![
                 Value::int(cols.0 as i64, head),
                 Value::int(rows.0 as i64, head),
@@ -0,0 +1,262 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Ulimit;
+
+impl Command for Ulimit {
+    fn name(&self) -> &str {
+        "ulimit"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ulimit")
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .optional(
+                "name",
+                SyntaxShape::String,
+                "the resource limit to show or set",
+            )
+            .optional(
+                "limit",
+                SyntaxShape::Any,
+                "the new limit, as a number or 'unlimited'",
+            )
+            .switch(
+                "hard",
+                "adjust the hard limit instead of the soft limit",
+                Some('H'),
+            )
+            .category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "Show or adjust the resource limits of the shell and the processes it starts."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "A limit of null means unlimited. Raising a hard limit usually requires elevated permissions."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["resource", "rlimit", "nofile", "core"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let name: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+        let limit: Option<Value> = call.opt(engine_state, stack, 1)?;
+        let hard = call.has_flag("hard");
+
+        let Some(name) = name else {
+            let mut output = vec![];
+            for (name, resource, description) in LIMITS {
+                output.push(limit_record(name, description, resource, head)?);
+            }
+            return Ok(Value::List {
+                vals: output,
+                span: head,
+            }
+            .into_pipeline_data());
+        };
+
+        let Some((limit_name, resource, description)) =
+            LIMITS.into_iter().find(|(n, ..)| *n == name.item)
+        else {
+            return Err(ShellError::GenericError(
+                "Unknown resource limit".into(),
+                format!("no limit named '{}'", name.item),
+                Some(name.span),
+                Some(format!(
+                    "the limits are: {}",
+                    LIMITS.map(|(name, ..)| name).join(", ")
+                )),
+                Vec::new(),
+            ));
+        };
+
+        match limit {
+            Some(limit) => {
+                set_limit(resource, &limit, hard)?;
+                Ok(Value::nothing(head).into_pipeline_data())
+            }
+            None => Ok(limit_record(limit_name, description, resource, head)?.into_pipeline_data()),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show all resource limits",
+                example: "ulimit",
+                result: None,
+            },
+            Example {
+                description: "Show the open file limit",
+                example: "ulimit nofile",
+                result: None,
+            },
+            Example {
+                description: "Lower the soft open file limit",
+                example: "ulimit nofile 1024",
+                result: None,
+            },
+            Example {
+                description: "Disable core dumps",
+                example: "ulimit core 0",
+                result: None,
+            },
+        ]
+    }
+}
+
+const LIMITS: [(&str, i32, &str); 8] = [
+    (
+        "core",
+        libc::RLIMIT_CORE as i32,
+        "the maximum size of core files created, in bytes",
+    ),
+    (
+        "cpu",
+        libc::RLIMIT_CPU as i32,
+        "the maximum amount of cpu time, in seconds",
+    ),
+    (
+        "data",
+        libc::RLIMIT_DATA as i32,
+        "the maximum size of the data segment, in bytes",
+    ),
+    (
+        "fsize",
+        libc::RLIMIT_FSIZE as i32,
+        "the maximum size of files written, in bytes",
+    ),
+    (
+        "memlock",
+        libc::RLIMIT_MEMLOCK as i32,
+        "the maximum amount of locked memory, in bytes",
+    ),
+    (
+        "nofile",
+        libc::RLIMIT_NOFILE as i32,
+        "the maximum number of open file descriptors",
+    ),
+    (
+        "nproc",
+        libc::RLIMIT_NPROC as i32,
+        "the maximum number of processes of the user",
+    ),
+    (
+        "stack",
+        libc::RLIMIT_STACK as i32,
+        "the maximum stack size, in bytes",
+    ),
+];
+
+fn get_limits(resource: i32, span: Span) -> Result<libc::rlimit, ShellError> {
+    let mut rlim = libc::rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+    // SAFETY: getrlimit only writes into the rlimit we pass
+    if unsafe { libc::getrlimit(resource as _, &mut rlim) } != 0 {
+        return Err(ShellError::GenericError(
+            "Failed to read the resource limit".into(),
+            std::io::Error::last_os_error().to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+    Ok(rlim)
+}
+
+fn set_limit(resource: i32, limit: &Value, hard: bool) -> Result<(), ShellError> {
+    let limit_span = limit.expect_span();
+    let new = match limit {
+        Value::Int { val, .. } if *val >= 0 => *val as libc::rlim_t,
+        Value::String { val, .. } if val == "unlimited" => libc::RLIM_INFINITY,
+        other => {
+            return Err(ShellError::TypeMismatch {
+                err_message: format!(
+                    "expected a non-negative number or 'unlimited', got {}",
+                    other.get_type()
+                ),
+                span: limit_span,
+            })
+        }
+    };
+
+    let mut rlim = get_limits(resource, limit_span)?;
+    if hard {
+        rlim.rlim_max = new;
+    } else {
+        rlim.rlim_cur = new;
+    }
+    // SAFETY: setrlimit reads the rlimit we pass and touches nothing else
+    if unsafe { libc::setrlimit(resource as _, &rlim) } != 0 {
+        return Err(ShellError::GenericError(
+            "Failed to set the resource limit".into(),
+            std::io::Error::last_os_error().to_string(),
+            Some(limit_span),
+            Some("the soft limit cannot exceed the hard limit, and raising the hard limit usually requires elevated permissions".into()),
+            Vec::new(),
+        ));
+    }
+    Ok(())
+}
+
+fn limit_record(
+    name: &str,
+    description: &str,
+    resource: i32,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let rlim = get_limits(resource, span)?;
+    let limit_value = |val: libc::rlim_t| {
+        if val == libc::RLIM_INFINITY {
+            Value::nothing(span)
+        } else {
+            Value::Int {
+                val: val as i64,
+                span,
+            }
+        }
+    };
+
+    Ok(Value::Record {
+        cols: Arc::new(vec![
+            "name".into(),
+            "description".into(),
+            "soft".into(),
+            "hard".into(),
+        ]),
+        vals: vec![
+            Value::String {
+                val: name.into(),
+                span,
+            },
+            Value::String {
+                val: description.into(),
+                span,
+            },
+            limit_value(rlim.rlim_cur),
+            limit_value(rlim.rlim_max),
+        ],
+        span,
+    })
+}
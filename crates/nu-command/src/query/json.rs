@@ -0,0 +1,326 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct QueryJson;
+
+impl Command for QueryJson {
+    fn name(&self) -> &str {
+        "query json"
+    }
+
+    fn usage(&self) -> &str {
+        "Extract values from structured data with a JSONPath expression."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Supported syntax: $.key, $["key"], $[0], $[-1], wildcards ($[*], $.*),
+slices ($[1:3]) and recursive descent ($..key, $..*). A query made only of
+keys and indexes returns the single value it names; anything with a
+wildcard, slice, or descent returns the list of every match.
+
+String input is parsed as JSON first, so 'open --raw file.json' works too."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["jsonpath", "jq", "path", "extract"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("query json")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required("query", SyntaxShape::String, "the JSONPath expression")
+            .category(Category::Filters)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Pick one value out of nested records",
+                example: "{a: {b: 1}} | query json '$.a.b'",
+                result: Some(Value::test_int(1)),
+            },
+            Example {
+                description: "Collect a column with a wildcard",
+                example: "[{name: a, price: 1} {name: b, price: 2}] | query json '$[*].price'",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Find every 'id' field, however deeply nested",
+                example: "{a: {id: 1, b: {id: 2}}} | query json '$..id'",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Query a raw JSON string directly",
+                example: r#"'{"a": [1, 2, 3]}' | query json '$.a[-1]'"#,
+                result: Some(Value::test_int(3)),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let query: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let steps = parse_query(&query.item, query.span)?;
+
+        let value = match input.into_value(head) {
+            Value::String { val, span } => crate::formats::convert_string_to_value(val, span)?,
+            Value::Error { error } => return Err(*error),
+            other => other,
+        };
+
+        let mut matches = vec![value];
+        for step in &steps {
+            matches = apply_step(matches, step);
+        }
+
+        // Literal paths act like cell paths: one value or an error. Anything
+        // with a wildcard, slice, or descent returns every match.
+        let literal = steps
+            .iter()
+            .all(|step| matches!(step, Step::Key(_) | Step::Index(_)));
+        if literal {
+            match matches.into_iter().next() {
+                Some(value) => Ok(value.into_pipeline_data()),
+                None => Err(ShellError::GenericError(
+                    "query matched nothing".into(),
+                    format!("'{}' names a value the input does not have", query.item),
+                    Some(query.span),
+                    None,
+                    Vec::new(),
+                )),
+            }
+        } else {
+            Ok(Value::List {
+                vals: matches,
+                span: head,
+            }
+            .into_pipeline_data())
+        }
+    }
+}
+
+enum Step {
+    Key(String),
+    Index(i64),
+    Wildcard,
+    Slice(Option<i64>, Option<i64>),
+    RecursiveKey(String),
+    RecursiveWildcard,
+}
+
+fn parse_query(query: &str, span: Span) -> Result<Vec<Step>, ShellError> {
+    let bad_query = |message: String| {
+        ShellError::GenericError(
+            "invalid JSONPath query".into(),
+            message,
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    };
+
+    let mut chars = query.chars().peekable();
+    if chars.peek() == Some(&'$') {
+        chars.next();
+    }
+
+    let mut steps = Vec::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => {
+                if chars.peek() == Some(&'.') {
+                    chars.next();
+                    if chars.peek() == Some(&'*') {
+                        chars.next();
+                        steps.push(Step::RecursiveWildcard);
+                    } else {
+                        let name = read_name(&mut chars);
+                        if name.is_empty() {
+                            return Err(bad_query("expected a name after '..'".into()));
+                        }
+                        steps.push(Step::RecursiveKey(name));
+                    }
+                } else if chars.peek() == Some(&'*') {
+                    chars.next();
+                    steps.push(Step::Wildcard);
+                } else {
+                    let name = read_name(&mut chars);
+                    if name.is_empty() {
+                        return Err(bad_query("expected a name after '.'".into()));
+                    }
+                    steps.push(Step::Key(name));
+                }
+            }
+            '[' => {
+                let mut inner = String::new();
+                loop {
+                    match chars.next() {
+                        Some(']') => break,
+                        Some(c) => inner.push(c),
+                        None => return Err(bad_query("unclosed '['".into())),
+                    }
+                }
+                let inner = inner.trim();
+                if let Some(quoted) = inner
+                    .strip_prefix('\'')
+                    .and_then(|s| s.strip_suffix('\''))
+                    .or_else(|| inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+                {
+                    steps.push(Step::Key(quoted.to_string()));
+                } else if inner == "*" {
+                    steps.push(Step::Wildcard);
+                } else if let Some((start, end)) = inner.split_once(':') {
+                    let bound = |s: &str| -> Result<Option<i64>, ShellError> {
+                        if s.trim().is_empty() {
+                            Ok(None)
+                        } else {
+                            s.trim()
+                                .parse()
+                                .map(Some)
+                                .map_err(|_| bad_query(format!("'{s}' is not a slice bound")))
+                        }
+                    };
+                    steps.push(Step::Slice(bound(start)?, bound(end)?));
+                } else {
+                    let index = inner
+                        .parse()
+                        .map_err(|_| bad_query(format!("'{inner}' is not an index")))?;
+                    steps.push(Step::Index(index));
+                }
+            }
+            c => return Err(bad_query(format!("unexpected character '{c}'"))),
+        }
+    }
+
+    Ok(steps)
+}
+
+fn read_name(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut name = String::new();
+    while let Some(c) = chars.peek() {
+        if c.is_alphanumeric() || *c == '_' || *c == '-' {
+            name.push(*c);
+            chars.next();
+        } else {
+            break;
+        }
+    }
+    name
+}
+
+// JSONPath semantics: a step that does not apply to a value simply drops it
+// instead of erroring, so wildcard queries skip non-matching branches.
+fn apply_step(values: Vec<Value>, step: &Step) -> Vec<Value> {
+    let mut out = Vec::new();
+    for value in values {
+        match step {
+            Step::Key(name) => {
+                if let Some(found) = value.get_data_by_key(name) {
+                    out.push(found);
+                }
+            }
+            Step::Index(index) => {
+                if let Value::List { vals, .. } = value {
+                    if let Some(idx) = normalize_index(*index, vals.len()) {
+                        out.push(vals[idx].clone());
+                    }
+                }
+            }
+            Step::Wildcard => match value {
+                Value::List { vals, .. } => out.extend(vals),
+                Value::Record { vals, .. } => out.extend(vals.iter().cloned()),
+                _ => {}
+            },
+            Step::Slice(start, end) => {
+                if let Value::List { vals, .. } = value {
+                    let len = vals.len();
+                    let start = start.map_or(0, |s| clamp_bound(s, len));
+                    let end = end.map_or(len, |e| clamp_bound(e, len));
+                    if start < end {
+                        out.extend_from_slice(&vals[start..end]);
+                    }
+                }
+            }
+            Step::RecursiveKey(name) => for_each_descendant(&value, &mut |descendant| {
+                if matches!(descendant, Value::Record { .. }) {
+                    if let Some(found) = descendant.get_data_by_key(name) {
+                        out.push(found);
+                    }
+                }
+            }),
+            Step::RecursiveWildcard => for_each_descendant(&value, &mut |descendant| {
+                out.push(descendant.clone());
+            }),
+        }
+    }
+    out
+}
+
+fn normalize_index(index: i64, len: usize) -> Option<usize> {
+    let idx = if index < 0 { index + len as i64 } else { index };
+    if idx >= 0 && (idx as usize) < len {
+        Some(idx as usize)
+    } else {
+        None
+    }
+}
+
+fn clamp_bound(bound: i64, len: usize) -> usize {
+    let bound = if bound < 0 { bound + len as i64 } else { bound };
+    bound.clamp(0, len as i64) as usize
+}
+
+fn for_each_descendant(value: &Value, f: &mut impl FnMut(&Value)) {
+    f(value);
+    match value {
+        Value::List { vals, .. } => {
+            for val in vals {
+                for_each_descendant(val, f);
+            }
+        }
+        Value::Record { vals, .. } => {
+            for val in vals.iter() {
+                for_each_descendant(val, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(QueryJson {})
+    }
+
+    #[test]
+    fn bad_queries_error() {
+        assert!(parse_query("$.", Span::test_data()).is_err());
+        assert!(parse_query("$[1", Span::test_data()).is_err());
+        assert!(parse_query("$[one]", Span::test_data()).is_err());
+        assert!(parse_query("$ .a", Span::test_data()).is_err());
+    }
+}
@@ -0,0 +1,10 @@
+mod json;
+mod query_;
+mod web;
+mod web_tables;
+mod xml;
+
+pub use json::QueryJson;
+pub use query_::Query;
+pub use web::QueryWeb;
+pub use xml::QueryXml;
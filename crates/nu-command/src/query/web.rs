@@ -1,98 +1,143 @@
-use crate::web_tables::WebTable;
-use nu_plugin::{EvaluatedCall, LabeledError};
-use nu_protocol::{Span, Value};
+use super::web_tables::WebTable;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
 use scraper::{Html, Selector as ScraperSelector};
+use std::sync::Arc;
 
-pub struct Selector {
-    pub query: String,
-    pub as_html: bool,
-    pub attribute: String,
-    pub as_table: Value,
-    pub inspect: bool,
-}
+#[derive(Clone)]
+pub struct QueryWeb;
 
-impl Selector {
-    pub fn new() -> Selector {
-        Selector {
-            query: String::new(),
-            as_html: false,
-            attribute: String::new(),
-            as_table: Value::string("".to_string(), Span::unknown()),
-            inspect: false,
-        }
+impl Command for QueryWeb {
+    fn name(&self) -> &str {
+        "query web"
     }
-}
 
-impl Default for Selector {
-    fn default() -> Self {
-        Self::new()
+    fn usage(&self) -> &str {
+        "Extract values from HTML with CSS selectors."
     }
-}
 
-pub fn parse_selector_params(call: &EvaluatedCall, input: &Value) -> Result<Value, LabeledError> {
-    let head = call.head;
-    let query: String = match call.get_flag("query")? {
-        Some(q2) => q2,
-        None => "".to_string(),
-    };
-    let as_html = call.has_flag("as-html");
-    let attribute = call.get_flag("attribute")?.unwrap_or_default();
-    let as_table: Value = call
-        .get_flag("as-table")?
-        .unwrap_or_else(|| Value::nothing(head));
-
-    let inspect = call.has_flag("inspect");
-
-    if !&query.is_empty() && ScraperSelector::parse(&query).is_err() {
-        return Err(LabeledError {
-            msg: "Cannot parse this query as a valid css selector".to_string(),
-            label: "Parse error".to_string(),
-            span: Some(head),
-        });
+    fn extra_usage(&self) -> &str {
+        r#"With --query, every element matching the CSS selector is returned as its
+text content (or its raw HTML with --as-html, or one attribute with
+--attribute). With --as-table, the page's <table> elements are matched
+against the given column header list and parsed into a nushell table."#
     }
 
-    let selector = Selector {
-        query,
-        as_html,
-        attribute,
-        as_table,
-        inspect,
-    };
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["scrape", "html", "css", "selector"]
+    }
 
-    match input {
-        Value::String { val, span } => Ok(begin_selector_query(val.to_string(), selector, *span)),
-        _ => Err(LabeledError {
-            label: "requires text input".to_string(),
-            msg: "Expected text from pipeline".to_string(),
-            span: Some(input.span()?),
-        }),
+    fn signature(&self) -> Signature {
+        Signature::build("query web")
+            .input_output_types(vec![(Type::String, Type::Any)])
+            .named("query", SyntaxShape::String, "selector query", Some('q'))
+            .switch("as-html", "return the query output as html", Some('m'))
+            .named(
+                "attribute",
+                SyntaxShape::String,
+                "downselect based on the given attribute",
+                Some('a'),
+            )
+            .named(
+                "as-table",
+                SyntaxShape::Table,
+                "find table based on column header list",
+                Some('t'),
+            )
+            .switch(
+                "inspect",
+                "run in inspect mode to provide more information for determining column headers",
+                Some('i'),
+            )
+            .category(Category::Network)
     }
-}
 
-fn begin_selector_query(input_html: String, selector: Selector, span: Span) -> Value {
-    if let Value::List { .. } = selector.as_table {
-        return retrieve_tables(
-            input_html.as_str(),
-            &selector.as_table,
-            selector.inspect,
-            span,
-        );
-    } else if selector.attribute.is_empty() {
-        execute_selector_query(
-            input_html.as_str(),
-            selector.query.as_str(),
-            selector.as_html,
-            selector.inspect,
-            span,
-        )
-    } else {
-        execute_selector_query_with_attribute(
-            input_html.as_str(),
-            selector.query.as_str(),
-            selector.attribute.as_str(),
-            selector.inspect,
-            span,
-        )
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Collect the text of every matching element",
+                example: "'<ul><li>Coffee</li><li>Tea</li></ul>' | query web --query li",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("Coffee"), Value::test_string("Tea")],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Retrieve all `<header>` elements from phoronix.com website",
+                example: "http get https://phoronix.com | query web --query 'header'",
+                result: None,
+            },
+            Example {
+                description: "Retrieve a html table from Wikipedia and parse it into a nushell table using table headers as guides",
+                example: "http get https://en.wikipedia.org/wiki/List_of_cities_in_India_by_population
+    | query web --as-table [Rank City 'Population(2011)[3]' 'Population(2001)' 'State or union territory']",
+                result: None,
+            },
+            Example {
+                description: "Retrieve a specific html attribute instead of the default text",
+                example: "http get https://example.org | query web --query a --attribute href",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let query: String = call
+            .get_flag(engine_state, stack, "query")?
+            .unwrap_or_default();
+        let as_html = call.has_flag("as-html");
+        let attribute: String = call
+            .get_flag(engine_state, stack, "attribute")?
+            .unwrap_or_default();
+        let as_table: Value = call
+            .get_flag(engine_state, stack, "as-table")?
+            .unwrap_or_else(|| Value::nothing(head));
+        let inspect = call.has_flag("inspect");
+
+        if !query.is_empty() && ScraperSelector::parse(&query).is_err() {
+            return Err(ShellError::GenericError(
+                "Cannot parse this query as a valid CSS selector".into(),
+                "Parse error".into(),
+                Some(head),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        let html = match input.into_value(head) {
+            Value::String { val, .. } => val,
+            Value::Error { error } => return Err(*error),
+            other => {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "string".into(),
+                    wrong_type: other.get_type().to_string(),
+                    dst_span: head,
+                    src_span: other.expect_span(),
+                })
+            }
+        };
+
+        let output = if let Value::List { .. } = as_table {
+            retrieve_tables(&html, &as_table, inspect, head)
+        } else if attribute.is_empty() {
+            execute_selector_query(&html, &query, as_html, inspect, head)
+        } else {
+            execute_selector_query_with_attribute(&html, &query, &attribute, inspect, head)
+        };
+
+        Ok(output.into_pipeline_data())
     }
 }
 
@@ -106,7 +151,6 @@ pub fn retrieve_tables(
     let mut cols: Vec<String> = Vec::new();
     if let Value::List { vals, .. } = &columns {
         for x in vals {
-            // TODO Find a way to get the Config object here
             if let Value::String { val, .. } = x {
                 cols.push(val.to_string())
             }
@@ -147,7 +191,6 @@ fn retrieve_table(mut table: WebTable, columns: &Value, span: Span) -> Value {
     let mut cols: Vec<String> = Vec::new();
     if let Value::List { vals, .. } = &columns {
         for x in vals {
-            // TODO Find a way to get the Config object here
             if let Value::String { val, .. } = x {
                 cols.push(val.to_string())
             }
@@ -178,11 +221,15 @@ fn retrieve_table(mut table: WebTable, columns: &Value, span: Span) -> Value {
                 vals.push(Value::string(cell.to_string(), span))
             }
         }
-        table_out.push(Value::Record { cols, vals, span })
+        table_out.push(Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        })
     } else {
+        let record_cols = Arc::new(cols.clone());
         for row in &table {
             let mut vals = vec![];
-            let record_cols = &cols;
             for col in &cols {
                 let val = row
                     .get(col)
@@ -195,7 +242,7 @@ fn retrieve_table(mut table: WebTable, columns: &Value, span: Span) -> Value {
                 vals.push(Value::string(val, span));
             }
             table_out.push(Value::Record {
-                cols: record_cols.to_vec(),
+                cols: record_cols.clone(),
                 vals,
                 span,
             })
@@ -209,7 +256,6 @@ fn retrieve_table(mut table: WebTable, columns: &Value, span: Span) -> Value {
         table.data = vec![data2];
         return retrieve_table(table, columns, span);
     }
-    // table_out
 
     Value::List {
         vals: table_out,
@@ -288,6 +334,13 @@ mod tests {
          </ul>
      "#;
 
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(QueryWeb {})
+    }
+
     #[test]
     fn test_first_child_is_not_empty() {
         assert!(!execute_selector_query(
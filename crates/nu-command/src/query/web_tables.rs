@@ -1,4 +1,4 @@
-use crate::query_web::css;
+use super::web::css;
 use scraper::{element_ref::ElementRef, Html, Selector as ScraperSelector};
 use std::collections::HashMap;
 
@@ -1150,75 +1150,3 @@ mod tests {
         assert_eq!(None, iter_2.next());
     }
 }
-/*
-    #[test]
-    fn test_wikipedia_swapped_rows_columns() {
-        // empty columns
-        let cols = nu_protocol::value::Value {
-            value: nu_protocol::UntaggedValue::Primitive(nu_protocol::Primitive::String(
-                "".to_string(),
-            )),
-            tag: nu_source::Tag::unknown(),
-        };
-
-        // this table is taken straight from wikipedia with no changes
-        let table = retrieve_tables(HTML_TABLE_WIKIPEDIA_COLUMNS_AS_ROWS, &cols, true);
-
-        let expected = vec![UntaggedValue::row(indexmap! {
-                 "Stable release".to_string() => UntaggedValue::string("\n          2103 (16.0.13901.20400) / April\u{a0}13, 2021; 4 months ago\u{a0}(2021-04-13)[1]\n        ").into(),
-                 "Developer(s)".to_string() => UntaggedValue::string("Microsoft").into(),
-                 "Operating system".to_string() => UntaggedValue::string("Microsoft Windows").into(),
-                 "Type".to_string() => UntaggedValue::string("Spreadsheet").into(),
-                 "License".to_string() => UntaggedValue::string("Trialware[2]").into(),
-                 "".to_string() => UntaggedValue::string("").into(),
-                 "Website".to_string() => UntaggedValue::string("products.office.com/en-us/excel").into(),
-                 "Initial release".to_string() => UntaggedValue::string("1987; 34\u{a0}years ago\u{a0}(1987)").into(),
-             }).into()];
-
-        assert_eq!(table, expected);
-    }
-
-    #[test]
-    fn test_wikipedia_table_with_column_headers() {
-        let cols = UntaggedValue::table(&[
-            UntaggedValue::string("Format".to_string()).into(),
-            UntaggedValue::string("Extension".to_string()).into(),
-            UntaggedValue::string("Description".to_string()).into(),
-        ])
-        .into();
-
-        // this table is taken straight from wikipedia with no changes
-        let table = retrieve_tables(HTML_TABLE_WIKIPEDIA_WITH_COLUMN_NAMES, &cols, true);
-        let expected = vec![
-                 UntaggedValue::row(indexmap! {
-                     "Format".to_string() => UntaggedValue::string("Excel Workbook").into(),
-                     "Extension".to_string() => UntaggedValue::string(".xlsx").into(),
-                     "Description".to_string() => UntaggedValue::string("The default Excel 2007 and later workbook format. In reality, a Zip compressed archive with a directory structure of XML text documents. Functions as the primary
-
-    //replacement for the former binary .xls format, although it does not support Excel macros for security reasons. Saving as .xlsx offers file size reduction over .xls[38]").into(),
-                 }).into(),
-                 UntaggedValue::row(indexmap! {
-                     "Format".to_string() => UntaggedValue::string("Excel Macro-enabled Workbook").into(),
-                     "Extension".to_string() => UntaggedValue::string(".xlsm").into(),
-                     "Description".to_string() => UntaggedValue::string("As Excel Workbook, but with macro support.").into(),
-                 }).into(),
-                 UntaggedValue::row(indexmap! {
-                     "Format".to_string() => UntaggedValue::string("Excel Binary Workbook").into(),
-                     "Extension".to_string() => UntaggedValue::string(".xlsb").into(),
-                     "Description".to_string() => UntaggedValue::string("As Excel Macro-enabled Workbook, but storing information in binary form rather than XML documents for opening and saving documents more quickly and efficiently. Intended especially for very large documents with tens of thousands of rows, and/or several hundreds of columns. This format is very useful for shrinking large Excel files as is often the case when doing data analysis.").into(),
-                 }).into(),
-                 UntaggedValue::row(indexmap! {
-                     "Format".to_string() => UntaggedValue::string("Excel Macro-enabled Template").into(),
-                     "Extension".to_string() => UntaggedValue::string(".xltm").into(),
-                     "Description".to_string() => UntaggedValue::string("A template document that forms a basis for actual workbooks, with macro support. The replacement for the old .xlt format.").into(),
-                 }).into(),
-                 UntaggedValue::row(indexmap! {
-                     "Format".to_string() => UntaggedValue::string("Excel Add-in").into(),
-                     "Extension".to_string() => UntaggedValue::string(".xlam").into(),
-                     "Description".to_string() => UntaggedValue::string("Excel add-in to add extra functionality and tools. Inherent macro support because of the file purpose.").into(),
-                 }).into(),
-             ];
-
-        assert_eq!(table, expected);
-    }
-*/
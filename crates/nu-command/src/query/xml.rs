@@ -0,0 +1,523 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, RawStream,
+    ShellError, Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct QueryXml;
+
+impl Command for QueryXml {
+    fn name(&self) -> &str {
+        "query xml"
+    }
+
+    fn usage(&self) -> &str {
+        "Extract values from XML with an XPath-like expression."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Supported syntax: /name and //name steps, * wildcards, 1-based [n]
+indexes, and a trailing @attr or text() selector. Names match either the
+local tag name or the prefixed name, so '//item' finds '<ns:item>' too.
+Matched elements are returned in the same record shape 'from xml' uses.
+
+With --stream, external input is not read into memory at once: the stream
+is scanned for complete fragments of the element named by the first step,
+each fragment is parsed on its own, and matches are emitted as they are
+found. The scan matches literal tag names and does not understand CDATA
+sections or comments that contain tags, so keep the first step simple
+(e.g. '//record' over a large export)."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["xpath", "path", "extract"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("query xml")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required("query", SyntaxShape::String, "the XPath expression")
+            .switch(
+                "stream",
+                "scan external input fragment by fragment instead of loading it whole",
+                None,
+            )
+            .category(Category::Filters)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Collect the text of every matching element",
+                example: "'<a><b>1</b><b>2</b></a>' | query xml '//b/text()'",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("1"), Value::test_string("2")],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Read an attribute off a nested element",
+                example: r#"'<a><b x="1"/></a>' | query xml '/a/b/@x'"#,
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("1")],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Pick the second element, 1-based as in XPath",
+                example: "'<a><b>1</b><b>2</b></a>' | query xml '/a/b[2]/text()'",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("2")],
+                    span: Span::test_data(),
+                }),
+            },
+            Example {
+                description: "Stream matches out of a file too big to load at once",
+                example: "open --raw export.xml | query xml --stream '//record/id/text()'",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let ctrlc = engine_state.ctrlc.clone();
+        let query: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let stream = call.has_flag("stream");
+        let path = parse_path(&query.item, query.span)?;
+
+        if stream {
+            if let PipelineData::ExternalStream {
+                stdout: Some(raw), ..
+            } = input
+            {
+                let fragments = FragmentScanner::new(raw, path, head, query.span)?;
+                return Ok(fragments.into_pipeline_data(ctrlc));
+            }
+        }
+
+        let text = match input.into_value(head) {
+            Value::String { val, .. } => val,
+            Value::Error { error } => return Err(*error),
+            other => {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "string or raw data".into(),
+                    wrong_type: other.get_type().to_string(),
+                    dst_span: head,
+                    src_span: other.expect_span(),
+                })
+            }
+        };
+
+        let document = roxmltree::Document::parse(&text).map_err(|err| bad_xml(err, head))?;
+        let matches = eval_path(&document.root(), &path.steps, &path.selector, head);
+
+        Ok(Value::List {
+            vals: matches,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+}
+
+enum NameTest {
+    Wildcard,
+    Name(String),
+}
+
+enum Axis {
+    Child,
+    Descendant,
+}
+
+struct PathStep {
+    axis: Axis,
+    name: NameTest,
+    index: Option<usize>,
+}
+
+enum Selector {
+    Element,
+    Attribute(String),
+    Text,
+}
+
+struct XmlPath {
+    steps: Vec<PathStep>,
+    selector: Selector,
+}
+
+fn parse_path(query: &str, span: Span) -> Result<XmlPath, ShellError> {
+    let bad_query = |message: String| {
+        ShellError::GenericError(
+            "invalid XPath query".into(),
+            message,
+            Some(span),
+            None,
+            Vec::new(),
+        )
+    };
+
+    let mut rest = query.trim();
+    if !rest.starts_with('/') {
+        return Err(bad_query("the query must start with '/' or '//'".into()));
+    }
+
+    let mut steps = Vec::new();
+    let mut selector = Selector::Element;
+    while !rest.is_empty() {
+        let axis = if let Some(stripped) = rest.strip_prefix("//") {
+            rest = stripped;
+            Axis::Descendant
+        } else if let Some(stripped) = rest.strip_prefix('/') {
+            rest = stripped;
+            Axis::Child
+        } else {
+            return Err(bad_query(format!("expected '/' before '{rest}'")));
+        };
+
+        let end = rest.find('/').unwrap_or(rest.len());
+        let (step, remainder) = rest.split_at(end);
+        rest = remainder;
+        if step.is_empty() {
+            return Err(bad_query("expected a name after '/'".into()));
+        }
+
+        if let Some(attr) = step.strip_prefix('@') {
+            if attr.is_empty() {
+                return Err(bad_query("expected an attribute name after '@'".into()));
+            }
+            if !rest.is_empty() {
+                return Err(bad_query("'@' must be the last step".into()));
+            }
+            selector = Selector::Attribute(attr.to_string());
+            break;
+        }
+        if step == "text()" {
+            if !rest.is_empty() {
+                return Err(bad_query("'text()' must be the last step".into()));
+            }
+            selector = Selector::Text;
+            break;
+        }
+
+        let (name, index) = match step.split_once('[') {
+            Some((name, index)) => {
+                let index = index
+                    .strip_suffix(']')
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| bad_query(format!("'[{index}' is not a 1-based index")))?;
+                (name, Some(index))
+            }
+            None => (step, None),
+        };
+        let name = match name {
+            "*" => NameTest::Wildcard,
+            "" => return Err(bad_query("expected a name before '['".into())),
+            name => NameTest::Name(name.to_string()),
+        };
+        steps.push(PathStep { axis, name, index });
+    }
+
+    if steps.is_empty() {
+        return Err(bad_query("the query names no element".into()));
+    }
+    Ok(XmlPath { steps, selector })
+}
+
+fn name_matches(n: &roxmltree::Node, test: &NameTest) -> bool {
+    match test {
+        NameTest::Wildcard => true,
+        NameTest::Name(name) => match name.split_once(':') {
+            // A prefixed test matches against the prefix the document uses
+            Some((prefix, local)) => {
+                n.tag_name().name() == local
+                    && n.tag_name()
+                        .namespace()
+                        .and_then(|uri| n.lookup_prefix(uri))
+                        == Some(prefix)
+            }
+            // A plain test is namespace-agnostic
+            None => n.tag_name().name() == name,
+        },
+    }
+}
+
+fn eval_path(
+    root: &roxmltree::Node,
+    steps: &[PathStep],
+    selector: &Selector,
+    span: Span,
+) -> Vec<Value> {
+    let mut context: Vec<roxmltree::Node> = vec![*root];
+    for step in steps {
+        let mut next = Vec::new();
+        for node in &context {
+            let mut found: Vec<roxmltree::Node> = match step.axis {
+                Axis::Child => node
+                    .children()
+                    .filter(|c| c.is_element() && name_matches(c, &step.name))
+                    .collect(),
+                Axis::Descendant => node
+                    .descendants()
+                    .filter(|c| c.is_element() && *c != *node && name_matches(c, &step.name))
+                    .collect(),
+            };
+            if let Some(index) = step.index {
+                found = found.into_iter().skip(index - 1).take(1).collect();
+            }
+            next.extend(found);
+        }
+        context = next;
+    }
+
+    context
+        .iter()
+        .filter_map(|node| match selector {
+            Selector::Element => Some(crate::formats::xml_element_to_value(node, span)),
+            Selector::Attribute(name) => node
+                .attributes()
+                .find(|a| a.name() == attribute_local_name(name))
+                .map(|a| Value::string(a.value(), span)),
+            Selector::Text => {
+                let text: String = node
+                    .descendants()
+                    .filter_map(|d| if d.is_text() { d.text() } else { None })
+                    .collect();
+                let text = text.trim();
+                if text.is_empty() {
+                    None
+                } else {
+                    Some(Value::string(text, span))
+                }
+            }
+        })
+        .collect()
+}
+
+fn attribute_local_name(name: &str) -> &str {
+    name.split_once(':').map_or(name, |(_, local)| local)
+}
+
+fn bad_xml(err: roxmltree::Error, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Failed to parse XML".into(),
+        err.to_string(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+// Pulls complete `<tag>...</tag>` fragments out of a raw stream, parses each
+// one on its own and yields the query's matches. Only the fragment currently
+// being assembled is held in memory.
+struct FragmentScanner {
+    inner: RawStream,
+    inner_complete: bool,
+    buffer: String,
+    tag: String,
+    steps: Vec<PathStep>,
+    selector: Selector,
+    pending: Vec<Value>,
+    span: Span,
+}
+
+impl FragmentScanner {
+    fn new(
+        inner: RawStream,
+        path: XmlPath,
+        span: Span,
+        query_span: Span,
+    ) -> Result<Self, ShellError> {
+        let mut steps = path.steps;
+        let tag = match &steps[0].name {
+            NameTest::Name(name) => name.clone(),
+            NameTest::Wildcard => {
+                return Err(ShellError::GenericError(
+                    "cannot stream this query".into(),
+                    "the first step must name an element, not '*'".into(),
+                    Some(query_span),
+                    None,
+                    Vec::new(),
+                ))
+            }
+        };
+        // The first step is consumed by the fragment scan itself: each
+        // fragment is a standalone document rooted at that element.
+        let first = steps.remove(0);
+        if let Some(index) = first.index {
+            return Err(ShellError::GenericError(
+                "cannot stream this query".into(),
+                format!("an index on the first step ('[{index}]') is not supported with --stream"),
+                Some(query_span),
+                None,
+                Vec::new(),
+            ));
+        }
+        Ok(Self {
+            inner,
+            inner_complete: false,
+            buffer: String::new(),
+            tag,
+            steps,
+            selector: path.selector,
+            pending: Vec::new(),
+            span,
+        })
+    }
+
+    // Find the next complete fragment in the buffer, or None if more input
+    // is needed. Nested elements with the same tag are depth-counted.
+    fn take_fragment(&mut self) -> Option<String> {
+        let open = format!("<{}", self.tag);
+        let close = format!("</{}", self.tag);
+
+        let start = find_tag(&self.buffer, &open, 0)?;
+        let mut depth = 0usize;
+        let mut at = start;
+        loop {
+            let next_open = find_tag(&self.buffer, &open, at);
+            let next_close = find_tag(&self.buffer, &close, at);
+            match (next_open, next_close) {
+                (Some(o), c) if c.map_or(true, |c| o < c) => {
+                    // An opening tag; self-closing ones do not add depth
+                    let end = self.buffer[o..].find('>')? + o;
+                    if !self.buffer[o..end].ends_with('/') {
+                        depth += 1;
+                    }
+                    at = end + 1;
+                    if depth == 0 {
+                        // Self-closing at top level is a whole fragment
+                        let fragment = self.buffer[start..at].to_string();
+                        self.buffer.drain(..at);
+                        return Some(fragment);
+                    }
+                }
+                (_, Some(c)) => {
+                    let end = self.buffer[c..].find('>')? + c;
+                    at = end + 1;
+                    depth = depth.checked_sub(1)?;
+                    if depth == 0 {
+                        let fragment = self.buffer[start..at].to_string();
+                        self.buffer.drain(..at);
+                        return Some(fragment);
+                    }
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+// Finds `pattern` at a tag-name boundary, so searching for `<b` does not
+// stop at `<branch`.
+fn find_tag(haystack: &str, pattern: &str, from: usize) -> Option<usize> {
+    let mut at = from;
+    while let Some(found) = haystack[at..].find(pattern) {
+        let found = at + found;
+        let after = haystack[found + pattern.len()..].chars().next();
+        match after {
+            Some(c) if c.is_whitespace() || c == '>' || c == '/' => return Some(found),
+            None => return None,
+            _ => at = found + pattern.len(),
+        }
+    }
+    None
+}
+
+impl Iterator for FragmentScanner {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if !self.pending.is_empty() {
+                return Some(self.pending.remove(0));
+            }
+
+            if let Some(fragment) = self.take_fragment() {
+                match roxmltree::Document::parse(&fragment) {
+                    Ok(document) => {
+                        let root = document.root_element();
+                        self.pending = eval_path(&root, &self.steps, &self.selector, self.span);
+                    }
+                    Err(err) => {
+                        return Some(Value::Error {
+                            error: Box::new(bad_xml(err, self.span)),
+                        })
+                    }
+                }
+                continue;
+            }
+
+            if self.inner_complete {
+                return None;
+            }
+            match self.inner.next() {
+                Some(Ok(Value::String { val, .. })) => self.buffer.push_str(&val),
+                Some(Ok(Value::Binary { val, .. })) => {
+                    self.buffer.push_str(&String::from_utf8_lossy(&val))
+                }
+                Some(Ok(Value::Error { error })) => return Some(Value::Error { error }),
+                Some(Err(error)) => {
+                    return Some(Value::Error {
+                        error: Box::new(error),
+                    })
+                }
+                Some(Ok(_)) => {}
+                None => self.inner_complete = true,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(QueryXml {})
+    }
+
+    #[test]
+    fn bad_queries_error() {
+        assert!(parse_path("b", Span::test_data()).is_err());
+        assert!(parse_path("/", Span::test_data()).is_err());
+        assert!(parse_path("/a/@x/b", Span::test_data()).is_err());
+        assert!(parse_path("/a/b[0]", Span::test_data()).is_err());
+        assert!(parse_path("/a/b[one]", Span::test_data()).is_err());
+    }
+
+    #[test]
+    fn prefixed_names_match_the_documents_prefix() {
+        let document = roxmltree::Document::parse(
+            "<r xmlns:n=\"http://example.com/n\"><n:b>1</n:b><b>2</b></r>",
+        )
+        .expect("valid XML");
+        let span = Span::test_data();
+
+        let path = parse_path("//n:b/text()", span).expect("valid query");
+        let matches = eval_path(&document.root(), &path.steps, &path.selector, span);
+        assert_eq!(matches, vec![Value::test_string("1")]);
+
+        let path = parse_path("//b/text()", span).expect("valid query");
+        let matches = eval_path(&document.root(), &path.steps, &path.selector, span);
+        assert_eq!(
+            matches,
+            vec![Value::test_string("1"), Value::test_string("2")]
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type,
+};
+
+use super::SecretValue;
+
+#[derive(Clone)]
+pub struct SecretGetFrom;
+
+impl Command for SecretGetFrom {
+    fn name(&self) -> &str {
+        "secret get-from"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("secret get-from")
+            .input_output_types(vec![(Type::Nothing, Type::Custom("secret".into()))])
+            .required(
+                "store",
+                SyntaxShape::String,
+                "the secret store to read from (currently only 'keyring')",
+            )
+            .required("name", SyntaxShape::String, "the name of the secret")
+            .category(Category::Experimental)
+    }
+
+    fn usage(&self) -> &str {
+        "Read a secret from an external secret store."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The 'keyring' store reads from the OS keychain: `secret-tool` (libsecret) on
+Linux and the `security` command on macOS. The result is already wrapped, so
+the secret never passes through the session as a plain string."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["keyring", "keychain", "password", "credential"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let store: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let name: Spanned<String> = call.req(engine_state, stack, 1)?;
+
+        match store.item.as_str() {
+            "keyring" => {
+                let secret = keyring_lookup(&name.item, name.span)?;
+                Ok(SecretValue::new(secret)
+                    .into_value(call.head)
+                    .into_pipeline_data())
+            }
+            other => Err(ShellError::GenericError(
+                format!("Unsupported secret store: {other}"),
+                "expected 'keyring'".into(),
+                Some(store.span),
+                None,
+                Vec::new(),
+            )),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Read a secret from the OS keychain",
+            example: "secret get-from keyring my-db-password",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn keyring_lookup(name: &str, span: Span) -> Result<String, ShellError> {
+    keyring_command("secret-tool", &["lookup", "service", name], span)
+}
+
+#[cfg(target_os = "macos")]
+fn keyring_lookup(name: &str, span: Span) -> Result<String, ShellError> {
+    keyring_command(
+        "security",
+        &["find-generic-password", "-s", name, "-w"],
+        span,
+    )
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn keyring_lookup(_name: &str, span: Span) -> Result<String, ShellError> {
+    Err(ShellError::GenericError(
+        "The keyring store is not supported on this platform".into(),
+        "only Linux (secret-tool) and macOS (security) are supported".into(),
+        Some(span),
+        None,
+        Vec::new(),
+    ))
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn keyring_command(program: &str, args: &[&str], span: Span) -> Result<String, ShellError> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|err| {
+            ShellError::GenericError(
+                format!("Failed to run {program}"),
+                err.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+    if !output.status.success() {
+        return Err(ShellError::GenericError(
+            "Failed to read the secret from the keyring".into(),
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+
+    let secret = String::from_utf8_lossy(&output.stdout);
+
+    Ok(secret.trim_end_matches(['\r', '\n']).to_string())
+}
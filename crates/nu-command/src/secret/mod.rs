@@ -0,0 +1,74 @@
+mod get_from;
+mod secret_;
+mod unwrap;
+mod wrap;
+
+pub use get_from::SecretGetFrom;
+pub use secret_::Secret;
+pub use unwrap::SecretUnwrap;
+pub use wrap::SecretWrap;
+
+use nu_protocol::{CustomValue, ShellError, Span, Value};
+use serde::{Deserialize, Serialize};
+
+/// A string wrapped so that it cannot show up in rendered output.
+///
+/// Tables, string conversions and error messages all go through either
+/// `to_base_value` or `value_string`, both of which redact the contents. The
+/// only way to read the wrapped string back is an explicit `secret unwrap`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SecretValue {
+    // Skipped so the secret cannot leak through a serialized form either
+    #[serde(skip)]
+    secret: String,
+}
+
+impl SecretValue {
+    pub fn new(secret: String) -> Self {
+        Self { secret }
+    }
+
+    pub fn reveal(&self) -> &str {
+        &self.secret
+    }
+
+    pub fn into_value(self, span: Span) -> Value {
+        Value::CustomValue {
+            val: Box::new(self),
+            span,
+        }
+    }
+}
+
+// The derived Debug would print the wrapped string
+impl std::fmt::Debug for SecretValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretValue(<redacted>)")
+    }
+}
+
+impl CustomValue for SecretValue {
+    fn clone_value(&self, span: Span) -> Value {
+        self.clone().into_value(span)
+    }
+
+    fn value_string(&self) -> String {
+        "<redacted>".to_string()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        Ok(Value::string("<redacted>", span))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn typetag_name(&self) -> &'static str {
+        "secret"
+    }
+
+    fn typetag_deserialize(&self) {
+        unimplemented!("typetag_deserialize")
+    }
+}
@@ -0,0 +1,54 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Secret;
+
+impl Command for Secret {
+    fn name(&self) -> &str {
+        "secret"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("secret")
+            .category(Category::Experimental)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn usage(&self) -> &str {
+        "Various commands for working with secret values."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"A secret is a wrapped string that renders as `<redacted>` in tables, string
+conversions and error messages. The wrapped string can only be read back with
+an explicit `secret unwrap`.
+
+You must use one of the following subcommands. Using this command as-is will
+only produce this help message."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &Secret.signature(),
+                &Secret.examples(),
+                engine_state,
+                stack,
+                self.is_parser_keyword(),
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
@@ -0,0 +1,69 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+use super::SecretValue;
+
+#[derive(Clone)]
+pub struct SecretUnwrap;
+
+impl Command for SecretUnwrap {
+    fn name(&self) -> &str {
+        "secret unwrap"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("secret unwrap")
+            .input_output_types(vec![(Type::Custom("secret".into()), Type::String)])
+            .category(Category::Experimental)
+    }
+
+    fn usage(&self) -> &str {
+        "Read the string back out of a secret value."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "This is the only way to get at the wrapped string, so every place a secret is
+actually used is an explicit `secret unwrap` call that can be audited."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["password", "reveal", "sensitive"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        match input.into_value(call.head) {
+            Value::CustomValue { val, span } => match val.as_any().downcast_ref::<SecretValue>() {
+                Some(secret) => Ok(Value::string(secret.reveal(), span).into_pipeline_data()),
+                None => Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "secret".into(),
+                    wrong_type: val.value_string(),
+                    dst_span: call.head,
+                    src_span: span,
+                }),
+            },
+            other => Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "secret".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: call.head,
+                src_span: other.expect_span(),
+            }),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Use a wrapped password at the one place it is needed",
+            example: r#"$env.DB_PASSWORD | secret unwrap"#,
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,58 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+use super::SecretValue;
+
+#[derive(Clone)]
+pub struct SecretWrap;
+
+impl Command for SecretWrap {
+    fn name(&self) -> &str {
+        "secret wrap"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("secret wrap")
+            .input_output_types(vec![(Type::String, Type::Custom("secret".into()))])
+            .category(Category::Experimental)
+    }
+
+    fn usage(&self) -> &str {
+        "Wrap a string into a secret value that renders as `<redacted>`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["password", "redact", "sensitive"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        match input.into_value(call.head) {
+            Value::String { val, span } => {
+                Ok(SecretValue::new(val).into_value(span).into_pipeline_data())
+            }
+            other => Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: call.head,
+                src_span: other.expect_span(),
+            }),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Wrap a password so it cannot end up in output or logs",
+            example: r#""hunter2" | secret wrap"#,
+            result: None,
+        }]
+    }
+}
@@ -15,6 +15,7 @@ use nu_protocol::engine::{EngineState, Stack};
 use nu_protocol::{IntoInterruptiblePipelineData, PipelineData, ShellError, Span, Value};
 pub use p::PrevShell;
 pub use shells_::Shells;
+use std::sync::Arc;
 
 enum SwitchTo {
     Next,
@@ -133,7 +134,7 @@ fn list_shells(
         .into_iter()
         .enumerate()
         .map(move |(idx, val)| Value::Record {
-            cols: vec!["active".to_string(), "path".to_string()],
+            cols: Arc::new(vec!["active".to_string(), "path".to_string()]),
             vals: vec![
                 Value::Bool {
                     val: idx == current_shell,
@@ -0,0 +1,163 @@
+// This module holds a constant-memory quantile sketch used by the --approx
+// modes of `histogram` and `math median`. It is the streaming histogram of
+// Ben-Haim & Tom-Tov: at most `max_bins` (mean, weight) pairs are kept, and
+// inserting into a full sketch merges the two closest bins. Memory stays
+// bounded no matter how long the input stream is, at the cost of the answers
+// being estimates.
+
+/// Bin budget used by the `--approx` modes. More bins mean better estimates
+/// and proportionally more (but still constant) memory.
+pub const DEFAULT_SKETCH_BINS: usize = 100;
+
+/// A bounded-memory summary of a stream of numbers that can answer quantile
+/// queries approximately.
+pub struct QuantileSketch {
+    max_bins: usize,
+    /// `(mean, weight)` pairs, sorted by mean
+    bins: Vec<(f64, u64)>,
+    count: u64,
+    min: f64,
+    max: f64,
+}
+
+impl QuantileSketch {
+    pub fn new(max_bins: usize) -> Self {
+        QuantileSketch {
+            max_bins: max_bins.max(2),
+            bins: Vec::new(),
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Add one observation. NaN is ignored, since it can't be ordered into
+    /// the bins.
+    pub fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+
+        // duplicates get their own bin; once the budget is hit they are the
+        // zero-gap pair and merge_closest collapses them first
+        let idx = self.bins.partition_point(|(mean, _)| *mean < value);
+        self.bins.insert(idx, (value, 1));
+        if self.bins.len() > self.max_bins {
+            self.merge_closest();
+        }
+    }
+
+    /// Merge the two bins whose means are closest together
+    fn merge_closest(&mut self) {
+        let mut best = 0;
+        let mut best_gap = f64::INFINITY;
+        for i in 0..self.bins.len() - 1 {
+            let gap = self.bins[i + 1].0 - self.bins[i].0;
+            if gap < best_gap {
+                best_gap = gap;
+                best = i;
+            }
+        }
+
+        let (right_mean, right_weight) = self.bins.remove(best + 1);
+        let (left_mean, left_weight) = &mut self.bins[best];
+        let total = *left_weight + right_weight;
+        *left_mean =
+            (*left_mean * *left_weight as f64 + right_mean * right_weight as f64) / total as f64;
+        *left_weight = total;
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// The `(mean, weight)` bins, sorted by mean
+    pub fn bins(&self) -> &[(f64, u64)] {
+        &self.bins
+    }
+
+    /// Estimate the value at quantile `q` (clamped to `0..=1`) by
+    /// interpolating between bin means; exact while no bins have been merged.
+    /// Returns `None` for an empty sketch.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let q = q.clamp(0.0, 1.0);
+        let target = q * self.count as f64;
+
+        // Each bin's mean is taken to sit at the midpoint of its weight; walk
+        // the cumulative weights and interpolate between neighboring means,
+        // with the observed extremes as the outermost points.
+        let mut prev_pos = 0.0;
+        let mut prev_val = self.min;
+        let mut cumulative = 0.0;
+        for (mean, weight) in &self.bins {
+            let pos = cumulative + *weight as f64 / 2.0;
+            if target <= pos {
+                let t = if pos > prev_pos {
+                    (target - prev_pos) / (pos - prev_pos)
+                } else {
+                    0.0
+                };
+                return Some(prev_val + (mean - prev_val) * t);
+            }
+            cumulative += *weight as f64;
+            prev_pos = pos;
+            prev_val = *mean;
+        }
+
+        let end = self.count as f64;
+        let t = if end > prev_pos {
+            (target - prev_pos) / (end - prev_pos)
+        } else {
+            0.0
+        };
+        Some(prev_val + (self.max - prev_val) * t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_inputs_are_exact() {
+        let mut sketch = QuantileSketch::new(100);
+        for v in [3.0, 8.0, 9.0, 12.0, 12.0, 15.0] {
+            sketch.push(v);
+        }
+
+        let median = sketch.quantile(0.5).expect("sketch is not empty");
+        assert!((median - 10.5).abs() < 1e-9, "median was {median}");
+        assert_eq!(sketch.quantile(0.0), Some(3.0));
+        assert_eq!(sketch.quantile(1.0), Some(15.0));
+    }
+
+    #[test]
+    fn bins_stay_bounded_and_estimates_stay_close() {
+        let mut sketch = QuantileSketch::new(32);
+        // feed a uniform stream far larger than the bin budget
+        for v in 0..100_000 {
+            sketch.push(v as f64);
+        }
+
+        assert!(sketch.bins().len() <= 32);
+        assert_eq!(sketch.count(), 100_000);
+
+        let median = sketch.quantile(0.5).expect("sketch is not empty");
+        assert!(
+            (median - 50_000.0).abs() < 2_000.0,
+            "median estimate was {median}"
+        );
+        let p90 = sketch.quantile(0.9).expect("sketch is not empty");
+        assert!((p90 - 90_000.0).abs() < 2_000.0, "p90 estimate was {p90}");
+    }
+}
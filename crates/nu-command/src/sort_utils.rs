@@ -1,12 +1,85 @@
 use alphanumeric_sort::compare_str;
 use nu_engine::column::nonexistent_column;
-use nu_protocol::{ShellError, Span, Value};
+use nu_protocol::{Config, ShellError, Span, Value};
 use std::cmp::Ordering;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Seek, Write};
+use std::path::PathBuf;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
 
 // This module includes sorting functionality that is useful in sort-by and elsewhere.
 // Eventually it would be nice to find a better home for it; sorting logic is only coupled
 // to commands for historical reasons.
 
+/// How string values in a sort column are compared.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Comparator {
+    /// Ordinary value comparison.
+    Default,
+    /// Alphanumeric comparison, so file2 sorts before file10.
+    Natural,
+    /// Locale-style collation: case-insensitive, with accented letters next
+    /// to their base letter instead of after 'z'.
+    Locale,
+    /// Semantic version comparison, so 1.2.0 sorts before 1.10.0 and
+    /// pre-releases sort before their release.
+    Version,
+}
+
+impl Comparator {
+    pub fn from_name(name: &str, span: Span) -> Result<Comparator, ShellError> {
+        match name {
+            "default" => Ok(Comparator::Default),
+            "natural" => Ok(Comparator::Natural),
+            "locale" => Ok(Comparator::Locale),
+            "version" => Ok(Comparator::Version),
+            _ => Err(ShellError::TypeMismatch {
+                err_message: format!(
+                    "'{name}' is not a comparator (expected default, natural, locale, or version)"
+                ),
+                span,
+            }),
+        }
+    }
+
+    pub fn compare_strings(&self, left: &str, right: &str) -> Ordering {
+        match self {
+            Comparator::Default => left.cmp(right),
+            Comparator::Natural => compare_str(left, right),
+            Comparator::Locale => collation_key(left)
+                .cmp(&collation_key(right))
+                .then_with(|| left.cmp(right)),
+            Comparator::Version => compare_versions(left, right),
+        }
+    }
+}
+
+/// A column to sort by, with the comparator to use for its string values.
+#[derive(Clone)]
+pub struct SortColumn {
+    pub name: String,
+    pub comparator: Comparator,
+}
+
+// Case-insensitive base-letter key, so accented letters collate next to
+// their unaccented form. Ties are broken on the raw string by the caller.
+fn collation_key(s: &str) -> String {
+    s.nfkd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn compare_versions(left: &str, right: &str) -> Ordering {
+    let parse = |s: &str| semver::Version::parse(s.trim().trim_start_matches('v'));
+    match (parse(left), parse(right)) {
+        (Ok(left), Ok(right)) => left.cmp(&right),
+        // Not full semver; fall back to comparing numeric segments naturally
+        _ => compare_str(left, right),
+    }
+}
+
 /// Sort a value. This only makes sense for lists and list-like things,
 /// so for everything else we just return the value as-is.
 /// CustomValues are converted to their base value and then sorted.
@@ -20,7 +93,12 @@ pub fn sort_value(
     match val {
         Value::List { vals, span } => {
             let mut vals = vals.clone();
-            sort(&mut vals, sort_columns, *span, insensitive, natural)?;
+            sort(
+                &mut vals,
+                columns_with_comparator(sort_columns, natural),
+                *span,
+                insensitive,
+            )?;
 
             if !ascending {
                 vals.reverse();
@@ -46,7 +124,12 @@ pub fn sort_value_in_place(
     natural: bool,
 ) -> Result<(), ShellError> {
     if let Value::List { vals, span } = val {
-        sort(vals, sort_columns, *span, insensitive, natural)?;
+        sort(
+            vals,
+            columns_with_comparator(sort_columns, natural),
+            *span,
+            insensitive,
+        )?;
         if !ascending {
             vals.reverse();
         }
@@ -54,12 +137,23 @@ pub fn sort_value_in_place(
     Ok(())
 }
 
+fn columns_with_comparator(sort_columns: Vec<String>, natural: bool) -> Vec<SortColumn> {
+    let comparator = if natural {
+        Comparator::Natural
+    } else {
+        Comparator::Default
+    };
+    sort_columns
+        .into_iter()
+        .map(|name| SortColumn { name, comparator })
+        .collect()
+}
+
 pub fn sort(
     vec: &mut [Value],
-    sort_columns: Vec<String>,
+    sort_columns: Vec<SortColumn>,
     span: Span,
     insensitive: bool,
-    natural: bool,
 ) -> Result<(), ShellError> {
     match vec.first() {
         Some(Value::Record {
@@ -78,7 +172,9 @@ pub fn sort(
                 ));
             }
 
-            if let Some(nonexistent) = nonexistent_column(sort_columns.clone(), cols.to_vec()) {
+            let column_names: Vec<String> =
+                sort_columns.iter().map(|col| col.name.clone()).collect();
+            if let Some(nonexistent) = nonexistent_column(column_names, cols.to_vec()) {
                 return Err(ShellError::CantFindColumn {
                     col_name: nonexistent,
                     span,
@@ -94,139 +190,393 @@ pub fn sort(
             for item in vec.iter() {
                 for col in &sort_columns {
                     let val = item
-                        .get_data_by_key(col)
+                        .get_data_by_key(&col.name)
                         .unwrap_or_else(|| Value::nothing(Span::unknown()));
                     vals.push(val);
                 }
             }
 
-            let should_sort_case_insensitively = insensitive
-                && vals
-                    .iter()
-                    .all(|x| matches!(x.get_type(), nu_protocol::Type::String));
-
-            let should_sort_case_naturally = natural
-                && vals
-                    .iter()
-                    .all(|x| matches!(x.get_type(), nu_protocol::Type::String));
-
-            vec.sort_by(|a, b| {
-                compare(
-                    a,
-                    b,
-                    &sort_columns,
-                    span,
-                    should_sort_case_insensitively,
-                    should_sort_case_naturally,
-                )
-            });
+            let all_strings = vals
+                .iter()
+                .all(|x| matches!(x.get_type(), nu_protocol::Type::String));
+
+            let should_sort_case_insensitively = insensitive && all_strings;
+
+            // String comparators only make sense when every value is a
+            // string; otherwise fall back to ordinary comparison
+            let sort_columns: Vec<SortColumn> = if all_strings {
+                sort_columns
+            } else {
+                sort_columns
+                    .into_iter()
+                    .map(|col| SortColumn {
+                        comparator: Comparator::Default,
+                        ..col
+                    })
+                    .collect()
+            };
+
+            vec.sort_by(|a, b| compare(a, b, &sort_columns, span, should_sort_case_insensitively));
         }
         _ => {
-            vec.sort_by(|a, b| {
-                if insensitive {
-                    let lowercase_left = match a {
-                        Value::String { val, span } => Value::String {
-                            val: val.to_ascii_lowercase(),
-                            span: *span,
-                        },
-                        _ => a.clone(),
-                    };
+            let comparator = sort_columns
+                .first()
+                .map(|col| col.comparator)
+                .unwrap_or(Comparator::Default);
+            vec.sort_by(|a, b| compare_values(a, b, insensitive, comparator));
+        }
+    }
+    Ok(())
+}
 
-                    let lowercase_right = match b {
-                        Value::String { val, span } => Value::String {
-                            val: val.to_ascii_lowercase(),
-                            span: *span,
-                        },
-                        _ => b.clone(),
-                    };
+/// Compare two values directly, the way [`sort`] compares non-record values.
+pub fn compare_values(a: &Value, b: &Value, insensitive: bool, comparator: Comparator) -> Ordering {
+    if insensitive {
+        let lowercase_left = match a {
+            Value::String { val, span } => Value::String {
+                val: val.to_ascii_lowercase(),
+                span: *span,
+            },
+            _ => a.clone(),
+        };
 
-                    if natural {
-                        match (lowercase_left.as_string(), lowercase_right.as_string()) {
-                            (Ok(left), Ok(right)) => compare_str(left, right),
-                            _ => Ordering::Equal,
-                        }
-                    } else {
-                        lowercase_left
-                            .partial_cmp(&lowercase_right)
-                            .unwrap_or(Ordering::Equal)
-                    }
-                } else if natural {
-                    match (a.as_string(), b.as_string()) {
-                        (Ok(left), Ok(right)) => compare_str(left, right),
-                        _ => Ordering::Equal,
-                    }
-                } else {
-                    a.partial_cmp(b).unwrap_or(Ordering::Equal)
-                }
-            });
+        let lowercase_right = match b {
+            Value::String { val, span } => Value::String {
+                val: val.to_ascii_lowercase(),
+                span: *span,
+            },
+            _ => b.clone(),
+        };
+
+        if comparator != Comparator::Default {
+            match (lowercase_left.as_string(), lowercase_right.as_string()) {
+                (Ok(left), Ok(right)) => comparator.compare_strings(&left, &right),
+                _ => Ordering::Equal,
+            }
+        } else {
+            lowercase_left
+                .partial_cmp(&lowercase_right)
+                .unwrap_or(Ordering::Equal)
         }
+    } else if comparator != Comparator::Default {
+        match (a.as_string(), b.as_string()) {
+            (Ok(left), Ok(right)) => comparator.compare_strings(&left, &right),
+            _ => Ordering::Equal,
+        }
+    } else {
+        a.partial_cmp(b).unwrap_or(Ordering::Equal)
     }
-    Ok(())
 }
 
 pub fn compare(
     left: &Value,
     right: &Value,
-    columns: &[String],
+    columns: &[SortColumn],
     span: Span,
     insensitive: bool,
-    natural: bool,
 ) -> Ordering {
     for column in columns {
-        let left_value = left.get_data_by_key(column);
+        let left_value = left.get_data_by_key(&column.name);
 
         let left_res = match left_value {
             Some(left_res) => left_res,
             None => Value::Nothing { span },
         };
 
-        let right_value = right.get_data_by_key(column);
+        let right_value = right.get_data_by_key(&column.name);
 
         let right_res = match right_value {
             Some(right_res) => right_res,
             None => Value::Nothing { span },
         };
 
-        let result = if insensitive {
-            let lowercase_left = match left_res {
-                Value::String { val, span } => Value::String {
-                    val: val.to_ascii_lowercase(),
-                    span,
-                },
-                _ => left_res,
-            };
+        let result = compare_values(&left_res, &right_res, insensitive, column.comparator);
+        if result != Ordering::Equal {
+            return result;
+        }
+    }
 
-            let lowercase_right = match right_res {
-                Value::String { val, span } => Value::String {
-                    val: val.to_ascii_lowercase(),
-                    span,
-                },
-                _ => right_res,
-            };
-            if natural {
-                match (lowercase_left.as_string(), lowercase_right.as_string()) {
-                    (Ok(left), Ok(right)) => compare_str(left, right),
-                    _ => Ordering::Equal,
-                }
+    Ordering::Equal
+}
+
+/// A merge sort that spills sorted runs to disk once the values buffered in
+/// memory exceed a byte budget, so `sort-by` can handle inputs larger than
+/// RAM. The budget and spill directory come from the `sort_spill_threshold`
+/// and `sort_tmp_dir` config settings; a threshold of 0 (or less) disables
+/// spilling. Inputs that fit within the budget are sorted entirely in memory,
+/// exactly as [`sort`] would.
+pub struct ExternalSorter {
+    sort_columns: Vec<SortColumn>,
+    span: Span,
+    insensitive: bool,
+    reverse: bool,
+    memory_budget: usize,
+    tmp_dir: PathBuf,
+    buffer: Vec<Value>,
+    buffered_bytes: usize,
+    runs: Vec<File>,
+}
+
+impl ExternalSorter {
+    pub fn new(
+        sort_columns: Vec<SortColumn>,
+        span: Span,
+        insensitive: bool,
+        reverse: bool,
+        config: &Config,
+    ) -> Self {
+        ExternalSorter {
+            sort_columns,
+            span,
+            insensitive,
+            reverse,
+            memory_budget: if config.sort_spill_threshold > 0 {
+                config.sort_spill_threshold as usize
             } else {
-                lowercase_left
-                    .partial_cmp(&lowercase_right)
-                    .unwrap_or(Ordering::Equal)
-            }
-        } else if natural {
-            match (left_res.as_string(), right_res.as_string()) {
-                (Ok(left), Ok(right)) => compare_str(left, right),
-                _ => Ordering::Equal,
+                usize::MAX
+            },
+            tmp_dir: if config.sort_tmp_dir.is_empty() {
+                std::env::temp_dir()
+            } else {
+                PathBuf::from(&config.sort_tmp_dir)
+            },
+            buffer: Vec::new(),
+            buffered_bytes: 0,
+            runs: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, value: Value) -> Result<(), ShellError> {
+        self.buffered_bytes += estimate_value_bytes(&value);
+        self.buffer.push(value);
+        if self.buffered_bytes > self.memory_budget {
+            self.spill_run()?;
+        }
+        Ok(())
+    }
+
+    /// Sort the buffered values and write them to a fresh temp file, one JSON
+    /// value per line. The file is unlinked as soon as it is created, so spill
+    /// data is cleaned up even if the shell dies mid-sort.
+    fn spill_run(&mut self) -> Result<(), ShellError> {
+        sort(
+            &mut self.buffer,
+            self.sort_columns.clone(),
+            self.span,
+            self.insensitive,
+        )?;
+        // Runs are written in output order, so a reversed sort spills each
+        // run descending; the merge relies on every head being the next
+        // value its run will emit
+        if self.reverse {
+            self.buffer.reverse();
+        }
+
+        let file =
+            tempfile::tempfile_in(&self.tmp_dir).map_err(|err| spill_error(err, self.span))?;
+        let mut writer = BufWriter::new(file);
+        for value in self.buffer.drain(..) {
+            let line = serde_json::to_string(&value).map_err(|err| spill_error(err, self.span))?;
+            writer
+                .write_all(line.as_bytes())
+                .and_then(|_| writer.write_all(b"\n"))
+                .map_err(|err| spill_error(err, self.span))?;
+        }
+        let mut file = writer
+            .into_inner()
+            .map_err(|err| spill_error(err, self.span))?;
+        file.rewind().map_err(|err| spill_error(err, self.span))?;
+
+        self.runs.push(file);
+        self.buffered_bytes = 0;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<Box<dyn Iterator<Item = Value> + Send>, ShellError> {
+        if self.runs.is_empty() {
+            // Everything fit in memory; this is the ordinary sort-by path
+            sort(
+                &mut self.buffer,
+                self.sort_columns,
+                self.span,
+                self.insensitive,
+            )?;
+            if self.reverse {
+                self.buffer.reverse();
             }
-        } else {
-            left_res.partial_cmp(&right_res).unwrap_or(Ordering::Equal)
+            return Ok(Box::new(self.buffer.into_iter()));
+        }
+
+        if !self.buffer.is_empty() {
+            self.spill_run()?;
+        }
+
+        let mut runs: Vec<RunCursor> = self
+            .runs
+            .into_iter()
+            .map(|file| RunCursor {
+                reader: BufReader::new(file),
+                head: None,
+            })
+            .collect();
+        for run in &mut runs {
+            run.advance(self.span);
+        }
+
+        Ok(Box::new(MergeIterator {
+            runs,
+            sort_columns: self.sort_columns,
+            span: self.span,
+            insensitive: self.insensitive,
+            reverse: self.reverse,
+        }))
+    }
+}
+
+struct RunCursor {
+    reader: BufReader<File>,
+    head: Option<Value>,
+}
+
+impl RunCursor {
+    /// Take the current head value and read the next one from the run file.
+    fn advance(&mut self, span: Span) -> Option<Value> {
+        let mut line = String::new();
+        let next = match self.reader.read_line(&mut line) {
+            Ok(0) => None,
+            Ok(_) => match serde_json::from_str(line.trim_end()) {
+                Ok(value) => Some(value),
+                Err(err) => Some(Value::Error {
+                    error: Box::new(spill_error(err, span)),
+                }),
+            },
+            Err(err) => Some(Value::Error {
+                error: Box::new(spill_error(err, span)),
+            }),
         };
-        if result != Ordering::Equal {
-            return result;
+        std::mem::replace(&mut self.head, next)
+    }
+}
+
+struct MergeIterator {
+    runs: Vec<RunCursor>,
+    sort_columns: Vec<SortColumn>,
+    span: Span,
+    insensitive: bool,
+    reverse: bool,
+}
+
+impl Iterator for MergeIterator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        let mut best: Option<usize> = None;
+        for idx in 0..self.runs.len() {
+            if self.runs[idx].head.is_none() {
+                continue;
+            }
+            match best {
+                None => best = Some(idx),
+                Some(prev) => {
+                    let ordering = match (&self.runs[idx].head, &self.runs[prev].head) {
+                        (Some(candidate), Some(current)) => row_compare(
+                            candidate,
+                            current,
+                            &self.sort_columns,
+                            self.span,
+                            self.insensitive,
+                        ),
+                        _ => Ordering::Equal,
+                    };
+                    // Runs are stored in output order (descending when
+                    // reversed), so take whichever head comes first in
+                    // that order
+                    let better = if self.reverse {
+                        ordering == Ordering::Greater
+                    } else {
+                        ordering == Ordering::Less
+                    };
+                    if better {
+                        best = Some(idx);
+                    }
+                }
+            }
         }
+
+        self.runs[best?].advance(self.span)
     }
+}
 
-    Ordering::Equal
+// Mirror the two comparator branches in `sort`: records compare on the sort
+// columns, everything else compares directly.
+fn row_compare(
+    left: &Value,
+    right: &Value,
+    sort_columns: &[SortColumn],
+    span: Span,
+    insensitive: bool,
+) -> Ordering {
+    if matches!(left, Value::Record { .. }) || matches!(right, Value::Record { .. }) {
+        compare(left, right, sort_columns, span, insensitive)
+    } else {
+        let comparator = sort_columns
+            .first()
+            .map(|col| col.comparator)
+            .unwrap_or(Comparator::Default);
+        compare_values(left, right, insensitive, comparator)
+    }
+}
+
+// Rough estimate of how much memory a value keeps alive, for the spill budget.
+fn estimate_value_bytes(value: &Value) -> usize {
+    let mut bytes = std::mem::size_of::<Value>();
+    match value {
+        Value::String { val, .. } => bytes += val.capacity(),
+        Value::Binary { val, .. } => bytes += val.capacity(),
+        Value::Record { cols, vals, .. } => {
+            bytes += cols.iter().map(|col| col.capacity()).sum::<usize>();
+            bytes += vals.iter().map(estimate_value_bytes).sum::<usize>();
+        }
+        Value::List { vals, .. } => bytes += vals.iter().map(estimate_value_bytes).sum::<usize>(),
+        _ => {}
+    }
+    bytes
+}
+
+fn spill_error(err: impl std::fmt::Display, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "sort-by failed to spill to disk".into(),
+        err.to_string(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
+#[test]
+fn test_comparators() {
+    assert_eq!(
+        Comparator::Natural.compare_strings("file2", "file10"),
+        Ordering::Less
+    );
+    assert_eq!(
+        Comparator::Version.compare_strings("1.10.0", "1.2.0"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        Comparator::Version.compare_strings("1.2.0-rc.1", "1.2.0"),
+        Ordering::Less
+    );
+    // Locale collation puts accented letters next to their base letter
+    // instead of after 'z'
+    assert_eq!(
+        Comparator::Locale.compare_strings("éclair", "zebra"),
+        Ordering::Less
+    );
+    assert_eq!(
+        Comparator::Default.compare_strings("éclair", "zebra"),
+        Ordering::Greater
+    );
 }
 
 #[test]
@@ -360,3 +710,114 @@ fn test_sort_value_in_place() {
         }
     );
 }
+
+#[test]
+fn test_external_sort_spills_and_merges() {
+    let mut config = Config::default();
+    // Spill a run after every row so the merge path is exercised
+    config.sort_spill_threshold = 1;
+
+    let mut sorter = ExternalSorter::new(
+        vec![SortColumn {
+            name: "count".to_string(),
+            comparator: Comparator::Default,
+        }],
+        Span::test_data(),
+        false,
+        false,
+        &config,
+    );
+    for count in [3, 1, 2] {
+        sorter
+            .push(Value::test_record(
+                vec!["count"],
+                vec![Value::test_int(count)],
+            ))
+            .unwrap();
+    }
+
+    let counts: Vec<i64> = sorter
+        .finish()
+        .unwrap()
+        .map(|row| {
+            row.get_data_by_key("count")
+                .expect("row should have a count column")
+                .as_integer()
+                .expect("count should be an int")
+        })
+        .collect();
+    assert_eq!(counts, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_external_sort_reversed() {
+    let mut config = Config::default();
+    config.sort_spill_threshold = 1;
+
+    let mut sorter = ExternalSorter::new(
+        vec![SortColumn {
+            name: "count".to_string(),
+            comparator: Comparator::Default,
+        }],
+        Span::test_data(),
+        false,
+        true,
+        &config,
+    );
+    for count in [3, 1, 2] {
+        sorter
+            .push(Value::test_record(
+                vec!["count"],
+                vec![Value::test_int(count)],
+            ))
+            .unwrap();
+    }
+
+    let counts: Vec<i64> = sorter
+        .finish()
+        .unwrap()
+        .map(|row| {
+            row.get_data_by_key("count")
+                .expect("row should have a count column")
+                .as_integer()
+                .expect("count should be an int")
+        })
+        .collect();
+    assert_eq!(counts, vec![3, 2, 1]);
+}
+
+#[test]
+fn test_external_sort_reversed_with_multi_element_runs() {
+    let row = |count| Value::test_record(vec!["count"], vec![Value::test_int(count)]);
+
+    let mut config = Config::default();
+    // Budget two rows per run, so each spilled run holds several elements
+    // and the merge has to look past the first value of every run
+    config.sort_spill_threshold = (2 * estimate_value_bytes(&row(0))) as i64;
+
+    let mut sorter = ExternalSorter::new(
+        vec![SortColumn {
+            name: "count".to_string(),
+            comparator: Comparator::Default,
+        }],
+        Span::test_data(),
+        false,
+        true,
+        &config,
+    );
+    for count in [5, 1, 4, 2, 6, 3] {
+        sorter.push(row(count)).unwrap();
+    }
+
+    let counts: Vec<i64> = sorter
+        .finish()
+        .unwrap()
+        .map(|row| {
+            row.get_data_by_key("count")
+                .expect("row should have a count column")
+                .as_integer()
+                .expect("count should be an int")
+        })
+        .collect();
+    assert_eq!(counts, vec![6, 5, 4, 3, 2, 1]);
+}
@@ -7,6 +7,7 @@ use nu_protocol::{
     PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value,
 };
 use once_cell::sync::Lazy;
+use std::sync::Arc;
 
 // Character used to separate directories in a Path Environment variable on windows is ";"
 #[cfg(target_family = "windows")]
@@ -240,7 +241,7 @@ impl Command for Char {
                     );
                     let vals = vec![name, character, unicode];
                     Value::Record {
-                        cols,
+                        cols: Arc::new(cols),
                         vals,
                         span: call_span,
                     }
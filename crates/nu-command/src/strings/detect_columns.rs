@@ -8,6 +8,7 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
     Spanned, SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 type Input<'t> = Peekable<CharIndices<'t>>;
 
@@ -58,11 +59,11 @@ impl Command for DetectColumns {
                 example: "'a b c' | detect columns -n",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec![
+                        cols: Arc::new(vec![
                             "column0".to_string(),
                             "column1".to_string(),
                             "column2".to_string(),
-                        ],
+                        ]),
                         vals: vec![
                             Value::test_string("a"),
                             Value::test_string("b"),
@@ -173,7 +174,7 @@ fn detect_columns(
             }
 
             Value::Record {
-                cols,
+                cols: Arc::new(cols),
                 vals,
                 span: name_span,
             }
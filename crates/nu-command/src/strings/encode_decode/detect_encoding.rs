@@ -0,0 +1,235 @@
+use encoding_rs::Encoding;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct DetectEncoding;
+
+impl Command for DetectEncoding {
+    fn name(&self) -> &str {
+        "detect encoding"
+    }
+
+    fn usage(&self) -> &str {
+        "Guess the text encoding of binary data."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Detection is heuristic: a byte order mark or valid UTF-8 is reported with
+high confidence, UTF-16 without a BOM and legacy multi-byte encodings with
+medium confidence, and the windows-1252 fallback with low confidence. Pass
+the result to decode to read the file."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["charset", "bom", "utf", "guess"]
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("detect encoding")
+            .input_output_types(vec![(Type::Binary, Type::Record(vec![]))])
+            .category(Category::Strings)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Detect the encoding of a legacy file and decode it",
+                example: "open --raw legacy.txt | decode (open --raw legacy.txt | detect encoding | get encoding)",
+                result: None,
+            },
+            Example {
+                description: "A UTF-8 byte order mark is recognized",
+                example: "0x[EF BB BF 68 69] | detect encoding",
+                result: Some(Value::test_record(
+                    vec!["encoding", "bom", "confidence"],
+                    vec![
+                        Value::test_string("utf-8"),
+                        Value::test_bool(true),
+                        Value::test_string("high"),
+                    ],
+                )),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let bytes = match input {
+            PipelineData::ExternalStream { stdout: None, .. } => Vec::new(),
+            PipelineData::ExternalStream {
+                stdout: Some(stream),
+                ..
+            } => stream.into_bytes()?.item,
+            PipelineData::Value(Value::Binary { val, .. }, ..) => val,
+            PipelineData::Value(Value::Error { error }, ..) => return Err(*error),
+            PipelineData::Value(v, ..) => {
+                return Err(ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "binary".into(),
+                    wrong_type: v.get_type().to_string(),
+                    dst_span: head,
+                    src_span: v.expect_span(),
+                })
+            }
+            _ => {
+                return Err(ShellError::UnsupportedInput(
+                    "non-binary input".into(),
+                    "value originates from here".into(),
+                    head,
+                    input.span().unwrap_or(head),
+                ))
+            }
+        };
+
+        let detected = detect(&bytes);
+        Ok(Value::record(
+            vec!["encoding".into(), "bom".into(), "confidence".into()],
+            vec![
+                Value::string(detected.encoding, head),
+                Value::boolean(detected.bom, head),
+                Value::string(detected.confidence, head),
+            ],
+            head,
+        )
+        .into_pipeline_data())
+    }
+}
+
+struct Detected {
+    encoding: &'static str,
+    bom: bool,
+    confidence: &'static str,
+}
+
+fn detect(bytes: &[u8]) -> Detected {
+    let certain = |encoding| Detected {
+        encoding,
+        bom: true,
+        confidence: "high",
+    };
+    if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        return certain("utf-8");
+    }
+    if bytes.starts_with(&[0xFF, 0xFE]) {
+        return certain("utf-16le");
+    }
+    if bytes.starts_with(&[0xFE, 0xFF]) {
+        return certain("utf-16be");
+    }
+
+    // ASCII-heavy UTF-16 text has a zero byte in every other position. This
+    // must run before the UTF-8 check: NUL bytes are valid UTF-8, so BOM-less
+    // UTF-16 of ASCII text would otherwise pass as UTF-8.
+    if let Some(encoding) = detect_utf16_without_bom(bytes) {
+        return Detected {
+            encoding,
+            bom: false,
+            confidence: "medium",
+        };
+    }
+
+    if std::str::from_utf8(bytes).is_ok() {
+        return Detected {
+            encoding: "utf-8",
+            bom: false,
+            confidence: "high",
+        };
+    }
+
+    // Try the legacy multi-byte encodings; the first one the data is valid
+    // in wins. They overlap, so this is only a medium-confidence guess.
+    for label in ["shift_jis", "euc-jp", "euc-kr", "gbk", "big5"] {
+        if let Some(encoding) = Encoding::for_label(label.as_bytes()) {
+            if encoding
+                .decode_without_bom_handling_and_without_replacement(bytes)
+                .is_some()
+            {
+                return Detected {
+                    encoding: label,
+                    bom: false,
+                    confidence: "medium",
+                };
+            }
+        }
+    }
+
+    // Every byte sequence is valid windows-1252, so this is a last resort
+    Detected {
+        encoding: "windows-1252",
+        bom: false,
+        confidence: "low",
+    }
+}
+
+fn detect_utf16_without_bom(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.len() < 4 || bytes.len() % 2 != 0 {
+        return None;
+    }
+
+    let pairs = bytes.len() / 2;
+    let zero_odd = bytes.iter().skip(1).step_by(2).filter(|b| **b == 0).count();
+    let zero_even = bytes.iter().step_by(2).filter(|b| **b == 0).count();
+
+    if zero_odd * 2 > pairs && zero_even == 0 {
+        Some("utf-16le")
+    } else if zero_even * 2 > pairs && zero_odd == 0 {
+        Some("utf-16be")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        crate::test_examples(DetectEncoding)
+    }
+
+    #[test]
+    fn detects_boms() {
+        let le = detect(&[0xFF, 0xFE, b'h', 0x00]);
+        assert_eq!((le.encoding, le.bom), ("utf-16le", true));
+
+        let be = detect(&[0xFE, 0xFF, 0x00, b'h']);
+        assert_eq!((be.encoding, be.bom), ("utf-16be", true));
+    }
+
+    #[test]
+    fn detects_utf16_without_bom() {
+        let bytes: Vec<u8> = "hello world"
+            .encode_utf16()
+            .flat_map(|unit| unit.to_le_bytes())
+            .collect();
+        let detected = detect(&bytes);
+        assert_eq!(detected.encoding, "utf-16le");
+        assert!(!detected.bom);
+    }
+
+    #[test]
+    fn detects_shift_jis() {
+        let (bytes, ..) = encoding_rs::SHIFT_JIS.encode("何だと？……無駄な努力だ？");
+        let detected = detect(&bytes);
+        assert_eq!(detected.encoding, "shift_jis");
+        assert_eq!(detected.confidence, "medium");
+    }
+
+    #[test]
+    fn falls_back_to_windows_1252() {
+        let detected = detect(&[b'h', 0xFF, 0xFE, 0xFD, b'i']);
+        assert_eq!(detected.encoding, "windows-1252");
+        assert_eq!(detected.confidence, "low");
+    }
+}
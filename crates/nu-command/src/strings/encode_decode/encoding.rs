@@ -26,12 +26,31 @@ pub fn encode(
     s_span: Span,
     ignore_errors: bool,
 ) -> Result<Value, ShellError> {
-    // Workaround for a bug in the Encodings Specification.
-    let encoding = if encoding_name.item.to_lowercase() == "utf16" {
-        parse_encoding(encoding_name.span, "utf-16")
-    } else {
-        parse_encoding(encoding_name.span, &encoding_name.item)
-    }?;
+    // encoding_rs can only produce UTF-8-compatible output; it silently maps
+    // the UTF-16 labels to UTF-8 when encoding, so handle those ourselves.
+    // The plain utf-16 label gets a BOM, the endian-specific ones do not.
+    match encoding_name.item.to_lowercase().as_str() {
+        "utf16" | "utf-16" => {
+            return Ok(Value::Binary {
+                val: encode_utf16(s, true, true),
+                span: head,
+            })
+        }
+        "utf-16le" => {
+            return Ok(Value::Binary {
+                val: encode_utf16(s, true, false),
+                span: head,
+            })
+        }
+        "utf-16be" => {
+            return Ok(Value::Binary {
+                val: encode_utf16(s, false, false),
+                span: head,
+            })
+        }
+        _ => {}
+    }
+    let encoding = parse_encoding(encoding_name.span, &encoding_name.item)?;
     let (result, _actual_encoding, replacements) = encoding.encode(s);
     // Because encoding_rs is a Web-facing crate, it defaults to replacing unknowns with HTML entities.
     // This behaviour can be enabled with -i. Otherwise, it becomes an error.
@@ -52,6 +71,27 @@ pub fn encode(
     }
 }
 
+fn encode_utf16(s: &str, little_endian: bool, bom: bool) -> Vec<u8> {
+    let mut out = Vec::with_capacity(s.len() * 2 + 2);
+    let units = s.encode_utf16();
+    if little_endian {
+        if bom {
+            out.extend_from_slice(&[0xFF, 0xFE]);
+        }
+        for unit in units {
+            out.extend_from_slice(&unit.to_le_bytes());
+        }
+    } else {
+        if bom {
+            out.extend_from_slice(&[0xFE, 0xFF]);
+        }
+        for unit in units {
+            out.extend_from_slice(&unit.to_be_bytes());
+        }
+    }
+    out
+}
+
 fn parse_encoding(span: Span, label: &str) -> Result<&'static Encoding, ShellError> {
     // Workaround for a bug in the Encodings Specification.
     let label = if label.to_lowercase() == "utf16" {
@@ -92,6 +132,10 @@ mod test {
     #[case::utf_hyphen_16("utf-16", "")]
     #[case::utf8("utf8", "")]
     #[case::utf_hyphen_8("utf-8", "")]
+    // UTF-16 is encoded by hand, so round-trip real text in all three flavors
+    #[case::utf16_with_bom("utf-16", "Some ¼½¿ Data 🇯🇵")]
+    #[case::utf16le("utf-16le", "Some ¼½¿ Data 🇯🇵")]
+    #[case::utf16be("utf-16be", "Some ¼½¿ Data 🇯🇵")]
     fn smoke(#[case] encoding: String, #[case] expected: &str) {
         let test_span = Span::test_data();
         let encoding = Spanned {
@@ -107,4 +151,25 @@ mod test {
 
         assert_eq!(decoded, expected);
     }
+
+    #[test]
+    fn utf16_bom_only_for_the_plain_label() {
+        let test_span = Span::test_data();
+        let spanned = |label: &str| Spanned {
+            item: label.to_string(),
+            span: test_span,
+        };
+
+        let with_bom = encode(test_span, spanned("utf-16"), "hi", test_span, false).unwrap();
+        assert_eq!(
+            with_bom.as_binary().unwrap(),
+            &[0xFF, 0xFE, b'h', 0x00, b'i', 0x00]
+        );
+
+        let le = encode(test_span, spanned("utf-16le"), "hi", test_span, false).unwrap();
+        assert_eq!(le.as_binary().unwrap(), &[b'h', 0x00, b'i', 0x00]);
+
+        let be = encode(test_span, spanned("utf-16be"), "hi", test_span, false).unwrap();
+        assert_eq!(be.as_binary().unwrap(), &[0x00, b'h', 0x00, b'i']);
+    }
 }
@@ -2,6 +2,7 @@ mod base64;
 mod decode;
 mod decode_base64;
 mod decode_hex;
+mod detect_encoding;
 mod encode;
 mod encode_base64;
 mod encode_hex;
@@ -11,6 +12,7 @@ mod hex;
 pub use self::decode::Decode;
 pub use self::decode_base64::DecodeBase64;
 pub use self::decode_hex::DecodeHex;
+pub use self::detect_encoding::DetectEncoding;
 pub use self::encode::Encode;
 pub use self::encode_base64::EncodeBase64;
 pub use self::encode_hex::EncodeHex;
@@ -40,3 +40,46 @@ pub fn grapheme_flags(call: &Call) -> Result<bool, ShellError> {
     // Grapheme cluster usage is decided by the non-default -g flag
     Ok(g_flag)
 }
+
+/// How a string command counts, slices, or reverses its input.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SegmentationMode {
+    GraphemeClusters,
+    Utf8Bytes,
+    CodePoints,
+}
+
+// For handling the three segmentation flags on commands that support all of
+// them. The flags are mutually exclusive; each command keeps its historical
+// default when none is given.
+pub fn segmentation_mode(
+    call: &Call,
+    default: SegmentationMode,
+) -> Result<SegmentationMode, ShellError> {
+    let flags = [
+        (
+            "grapheme-clusters",
+            "-g",
+            SegmentationMode::GraphemeClusters,
+        ),
+        ("utf-8-bytes", "-b", SegmentationMode::Utf8Bytes),
+        ("code-points", "-c", SegmentationMode::CodePoints),
+    ];
+
+    let mut chosen: Option<(&str, &str, SegmentationMode)> = None;
+    for (name, short, mode) in flags {
+        if call.has_flag(name) {
+            if let Some((other_name, other_short, _)) = chosen {
+                return Err(ShellError::IncompatibleParametersSingle {
+                    msg: format!(
+                        "Incompatible flags: --{other_name} ({other_short}) and --{name} ({short})"
+                    ),
+                    span: call.head,
+                });
+            }
+            chosen = Some((name, short, mode));
+        }
+    }
+
+    Ok(chosen.map_or(default, |(_, _, mode)| mode))
+}
@@ -6,6 +6,7 @@ use nu_protocol::{
     Category, Example, ListStream, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
     Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Parse;
@@ -38,7 +39,7 @@ impl Command for Parse {
     fn examples(&self) -> Vec<Example> {
         let result = Value::List {
             vals: vec![Value::Record {
-                cols: vec!["foo".to_string(), "bar".to_string()],
+                cols: Arc::new(vec!["foo".to_string(), "bar".to_string()]),
                 vals: vec![Value::test_string("hi"), Value::test_string("there")],
                 span: Span::test_data(),
             }],
@@ -61,7 +62,7 @@ impl Command for Parse {
                 example: "\"foo bar.\" | parse -r '\\s*(?<name>\\w+)(?=\\.)'",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["name".to_string()],
+                        cols: Arc::new(vec!["name".to_string()]),
                         vals: vec![Value::test_string("bar")],
                         span: Span::test_data(),
                     }],
@@ -74,12 +75,12 @@ impl Command for Parse {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["capture0".to_string(), "capture1".to_string()],
+                            cols: Arc::new(vec!["capture0".to_string(), "capture1".to_string()]),
                             vals: vec![Value::test_string(""), Value::test_string("foo")],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["capture0".to_string(), "capture1".to_string()],
+                            cols: Arc::new(vec!["capture0".to_string(), "capture1".to_string()]),
                             vals: vec![Value::test_string("bar"), Value::test_string("")],
                             span: Span::test_data(),
                         },
@@ -93,7 +94,7 @@ impl Command for Parse {
                     "\" @another(foo bar)   \" | parse -r '\\s*(?<=[() ])(@\\w+)(\\([^)]*\\))?\\s*'",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["capture0".to_string(), "capture1".to_string()],
+                        cols: Arc::new(vec!["capture0".to_string(), "capture1".to_string()]),
                         vals: vec![
                             Value::test_string("@another"),
                             Value::test_string("(foo bar)"),
@@ -108,7 +109,7 @@ impl Command for Parse {
                 example: "\"abcd\" | parse -r '^a(bc(?=d)|b)cd$'",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["capture0".to_string()],
+                        cols: Arc::new(vec!["capture0".to_string()]),
                         vals: vec![Value::test_string("b")],
                         span: Span::test_data(),
                     }],
@@ -149,7 +150,7 @@ fn operate(
         build_regex(&pattern_item, pattern_span)?
     };
 
-    let regex_pattern = Regex::new(&item_to_parse).map_err(|err| {
+    let regex_pattern = engine_state.compiled_regex(&item_to_parse).map_err(|err| {
         ShellError::GenericError(
             "Error with regular expression".into(),
             err.to_string(),
@@ -197,7 +198,7 @@ fn operate(
                             }
 
                             parsed.push(Value::Record {
-                                cols,
+                                cols: Arc::new(cols),
                                 vals,
                                 span: head,
                             });
@@ -439,7 +440,11 @@ fn stream_helper(
             });
         }
 
-        excess.push(Value::Record { cols, vals, span });
+        excess.push(Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        });
     }
 
     if !excess.is_empty() {
@@ -3,6 +3,7 @@ use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Span, Type, Value};
 use std::collections::BTreeMap;
+use std::sync::Arc;
 use std::{fmt, str};
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -47,13 +48,13 @@ impl Command for Size {
                 description: "Count the number of words in a string",
                 example: r#""There are seven words in this sentence" | size"#,
                 result: Some(Value::Record {
-                    cols: vec![
+                    cols: Arc::new(vec![
                         "lines".into(),
                         "words".into(),
                         "bytes".into(),
                         "chars".into(),
                         "graphemes".into(),
-                    ],
+                    ]),
                     vals: vec![
                         Value::test_int(1),
                         Value::test_int(7),
@@ -68,13 +69,13 @@ impl Command for Size {
                 description: "Counts unicode characters",
                 example: r#"'今天天气真好' | size "#,
                 result: Some(Value::Record {
-                    cols: vec![
+                    cols: Arc::new(vec![
                         "lines".into(),
                         "words".into(),
                         "bytes".into(),
                         "chars".into(),
                         "graphemes".into(),
-                    ],
+                    ]),
                     vals: vec![
                         Value::test_int(1),
                         Value::test_int(6),
@@ -89,13 +90,13 @@ impl Command for Size {
                 description: "Counts Unicode characters correctly in a string",
                 example: r#""Amélie Amelie" | size"#,
                 result: Some(Value::Record {
-                    cols: vec![
+                    cols: Arc::new(vec![
                         "lines".into(),
                         "words".into(),
                         "bytes".into(),
                         "chars".into(),
                         "graphemes".into(),
-                    ],
+                    ]),
                     vals: vec![
                         Value::test_int(1),
                         Value::test_int(2),
@@ -193,7 +194,11 @@ fn counter(contents: &str, span: Span) -> Value {
         span,
     });
 
-    Value::Record { cols, vals, span }
+    Value::Record {
+        cols: Arc::new(cols),
+        vals,
+        span,
+    }
 }
 
 /// Take all the counts in `other_counts` and sum them into `accum`.
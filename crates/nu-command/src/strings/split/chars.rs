@@ -1,4 +1,4 @@
-use crate::grapheme_flags;
+use crate::{segmentation_mode, SegmentationMode};
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
@@ -18,6 +18,11 @@ impl Command for SubCommand {
         Signature::build("split chars")
             .input_output_types(vec![(Type::String, Type::List(Box::new(Type::String)))])
             .switch("grapheme-clusters", "split on grapheme clusters", Some('g'))
+            .switch(
+                "utf-8-bytes",
+                "split on UTF-8 bytes (non-ASCII bytes become the replacement character)",
+                Some('b'),
+            )
             .switch(
                 "code-points",
                 "split on code points (default; splits combined characters)",
@@ -63,6 +68,14 @@ impl Command for SubCommand {
                     span: Span::test_data(),
                 }),
             },
+            Example {
+                description: "Split on UTF-8 bytes",
+                example: "'ab' | split chars -b",
+                result: Some(Value::List {
+                    vals: vec![Value::test_string("a"), Value::test_string("b")],
+                    span: Span::test_data(),
+                }),
+            },
         ]
     }
 
@@ -84,29 +97,29 @@ fn split_chars(
 ) -> Result<PipelineData, ShellError> {
     let span = call.head;
 
-    let graphemes = grapheme_flags(call)?;
+    let mode = segmentation_mode(call, SegmentationMode::CodePoints)?;
     input.flat_map(
-        move |x| split_chars_helper(&x, span, graphemes),
+        move |x| split_chars_helper(&x, span, mode),
         engine_state.ctrlc.clone(),
     )
 }
 
-fn split_chars_helper(v: &Value, name: Span, graphemes: bool) -> Vec<Value> {
+fn split_chars_helper(v: &Value, name: Span, mode: SegmentationMode) -> Vec<Value> {
     match v.span() {
         Ok(v_span) => {
             if let Ok(s) = v.as_string() {
-                if graphemes {
-                    s.graphemes(true)
-                        .collect::<Vec<_>>()
-                        .into_iter()
-                        .map(move |x| Value::string(x, v_span))
-                        .collect()
-                } else {
-                    s.chars()
-                        .collect::<Vec<_>>()
-                        .into_iter()
-                        .map(move |x| Value::string(x, v_span))
-                        .collect()
+                match mode {
+                    SegmentationMode::GraphemeClusters => s
+                        .graphemes(true)
+                        .map(|x| Value::string(x, v_span))
+                        .collect(),
+                    SegmentationMode::CodePoints => {
+                        s.chars().map(|x| Value::string(x, v_span)).collect()
+                    }
+                    SegmentationMode::Utf8Bytes => s
+                        .bytes()
+                        .map(|b| Value::string(String::from_utf8_lossy(&[b]), v_span))
+                        .collect(),
                 }
             } else {
                 vec![Value::Error {
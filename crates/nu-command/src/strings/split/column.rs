@@ -6,6 +6,7 @@ use nu_protocol::{
     Value,
 };
 use regex::Regex;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -65,11 +66,11 @@ impl Command for SubCommand {
                 example: "'a--b--c' | split column '--'",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec![
+                        cols: Arc::new(vec![
                             "column1".to_string(),
                             "column2".to_string(),
                             "column3".to_string(),
-                        ],
+                        ]),
                         vals: vec![
                             Value::test_string("a"),
                             Value::test_string("b"),
@@ -85,11 +86,11 @@ impl Command for SubCommand {
                 example: "'abc' | split column -c ''",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec![
+                        cols: Arc::new(vec![
                             "column1".to_string(),
                             "column2".to_string(),
                             "column3".to_string(),
-                        ],
+                        ]),
                         vals: vec![
                             Value::test_string("a"),
                             Value::test_string("b"),
@@ -106,12 +107,12 @@ impl Command for SubCommand {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["column1".to_string(), "column2".to_string()],
+                            cols: Arc::new(vec!["column1".to_string(), "column2".to_string()]),
                             vals: vec![Value::test_string("a"), Value::test_string("b")],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["column1".to_string(), "column2".to_string()],
+                            cols: Arc::new(vec!["column1".to_string(), "column2".to_string()]),
                             vals: vec![Value::test_string("c"), Value::test_string("d")],
                             span: Span::test_data(),
                         },
@@ -125,12 +126,12 @@ impl Command for SubCommand {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["column1".to_string(), "column2".to_string()],
+                            cols: Arc::new(vec!["column1".to_string(), "column2".to_string()]),
                             vals: vec![Value::test_string("a"), Value::test_string("b")],
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec!["column1".to_string(), "column2".to_string()],
+                            cols: Arc::new(vec!["column1".to_string(), "column2".to_string()]),
                             vals: vec![Value::test_string("c"), Value::test_string("d")],
                             span: Span::test_data(),
                         },
@@ -209,7 +210,7 @@ fn split_column_helper(
             }
         }
         vec![Value::Record {
-            cols,
+            cols: Arc::new(cols),
             vals,
             span: head,
         }]
@@ -4,6 +4,7 @@ use nu_protocol::ast::CellPath;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::Category;
 use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -61,7 +62,7 @@ impl Command for SubCommand {
                 result: Some(Value::List {
                     vals: vec![Value::Record {
                         span: Span::test_data(),
-                        cols: vec!["lang".to_string(), "gems".to_string()],
+                        cols: Arc::new(vec!["lang".to_string(), "gems".to_string()]),
                         vals: vec![Value::test_string("Nu_test"), Value::test_int(100)],
                     }],
                     span: Span::test_data(),
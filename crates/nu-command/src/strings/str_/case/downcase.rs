@@ -4,6 +4,7 @@ use nu_protocol::ast::CellPath;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::Category;
 use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -60,7 +61,7 @@ impl Command for SubCommand {
                 example: "[[ColA ColB]; [Test ABC]] | str downcase ColA",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ColA".to_string(), "ColB".to_string()],
+                        cols: Arc::new(vec!["ColA".to_string(), "ColB".to_string()]),
                         vals: vec![Value::test_string("test"), Value::test_string("ABC")],
                         span: Span::test_data(),
                     }],
@@ -72,7 +73,7 @@ impl Command for SubCommand {
                 example: "[[ColA ColB]; [Test ABC]] | str downcase ColA ColB",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ColA".to_string(), "ColB".to_string()],
+                        cols: Arc::new(vec!["ColA".to_string(), "ColB".to_string()]),
                         vals: vec![Value::test_string("test"), Value::test_string("abc")],
                         span: Span::test_data(),
                     }],
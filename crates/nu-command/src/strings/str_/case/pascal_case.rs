@@ -6,6 +6,7 @@ use nu_protocol::{
 };
 
 use crate::operate;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -68,7 +69,7 @@ impl Command for SubCommand {
                 result: Some(Value::List {
                     vals: vec![Value::Record {
                         span: Span::test_data(),
-                        cols: vec!["lang".to_string(), "gems".to_string()],
+                        cols: Arc::new(vec!["lang".to_string(), "gems".to_string()]),
                         vals: vec![Value::test_string("NuTest"), Value::test_int(100)],
                     }],
                     span: Span::test_data(),
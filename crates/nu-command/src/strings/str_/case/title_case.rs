@@ -6,6 +6,7 @@ use nu_protocol::{
 };
 
 use crate::operate;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -63,7 +64,7 @@ impl Command for SubCommand {
                 result: Some(Value::List {
                     vals: vec![Value::Record {
                         span: Span::test_data(),
-                        cols: vec!["title".to_string(), "count".to_string()],
+                        cols: Arc::new(vec!["title".to_string(), "count".to_string()]),
                         vals: vec![Value::test_string("Nu Test"), Value::test_int(100)],
                     }],
                     span: Span::test_data(),
@@ -5,6 +5,7 @@ use nu_protocol::ast::CellPath;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::Category;
 use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -85,7 +86,7 @@ impl Command for SubCommand {
                 example: " [[ColA ColB]; [test 100]] | str contains 'e' ColA",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ColA".to_string(), "ColB".to_string()],
+                        cols: Arc::new(vec!["ColA".to_string(), "ColB".to_string()]),
                         vals: vec![Value::test_bool(true), Value::test_int(100)],
                         span: Span::test_data(),
                     }],
@@ -97,7 +98,7 @@ impl Command for SubCommand {
                 example: " [[ColA ColB]; [test 100]] | str contains -i 'E' ColA",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ColA".to_string(), "ColB".to_string()],
+                        cols: Arc::new(vec!["ColA".to_string(), "ColB".to_string()]),
                         vals: vec![Value::test_bool(true), Value::test_int(100)],
                         span: Span::test_data(),
                     }],
@@ -109,7 +110,7 @@ impl Command for SubCommand {
                 example: " [[ColA ColB]; [test hello]] | str contains 'e' ColA ColB",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ColA".to_string(), "ColB".to_string()],
+                        cols: Arc::new(vec!["ColA".to_string(), "ColB".to_string()]),
                         vals: vec![Value::test_bool(true), Value::test_bool(true)],
                         span: Span::test_data(),
                     }],
@@ -6,6 +6,7 @@ use nu_protocol::{
     levenshtein_distance, Category, Example, PipelineData, ShellError, Signature, Span,
     SyntaxShape, Type, Value,
 };
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -79,7 +80,7 @@ impl Command for SubCommand {
             result: Some(Value::List {
                 vals: vec![
                     Value::Record {
-                        cols: vec!["a".to_string(), "b".to_string()],
+                        cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                         vals: vec![Value::test_int(1), Value::test_int(4)],
                         span: Span::test_data(),
                     }
@@ -1,5 +1,5 @@
-use crate::grapheme_flags;
 use crate::input_handler::{operate, CmdArgument};
+use crate::{segmentation_mode, SegmentationMode};
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::ast::CellPath;
@@ -10,7 +10,7 @@ use unicode_segmentation::UnicodeSegmentation;
 
 struct Arguments {
     cell_paths: Option<Vec<CellPath>>,
-    graphemes: bool,
+    mode: SegmentationMode,
 }
 
 impl CmdArgument for Arguments {
@@ -41,6 +41,11 @@ impl Command for SubCommand {
                 "count length using UTF-8 bytes (default; all non-ASCII chars have length 2+)",
                 Some('b'),
             )
+            .switch(
+                "code-points",
+                "count length using code points (combined characters count separately)",
+                Some('c'),
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -67,7 +72,7 @@ impl Command for SubCommand {
         let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
         let args = Arguments {
             cell_paths: (!cell_paths.is_empty()).then_some(cell_paths),
-            graphemes: grapheme_flags(call)?,
+            mode: segmentation_mode(call, SegmentationMode::Utf8Bytes)?,
         };
         operate(action, args, input, call.head, engine_state.ctrlc.clone())
     }
@@ -84,6 +89,11 @@ impl Command for SubCommand {
                 example: "'🇯🇵ほげ ふが ぴよ' | str length -g",
                 result: Some(Value::test_int(9)),
             },
+            Example {
+                description: "Count length using code points",
+                example: "'🇯🇵ほげ ふが ぴよ' | str length -c",
+                result: Some(Value::test_int(10)),
+            },
             Example {
                 description: "Return the lengths of multiple strings",
                 example: "['hi' 'there'] | str length",
@@ -99,10 +109,10 @@ impl Command for SubCommand {
 fn action(input: &Value, arg: &Arguments, head: Span) -> Value {
     match input {
         Value::String { val, .. } => Value::int(
-            if arg.graphemes {
-                val.graphemes(true).count()
-            } else {
-                val.len()
+            match arg.mode {
+                SegmentationMode::GraphemeClusters => val.graphemes(true).count(),
+                SegmentationMode::Utf8Bytes => val.len(),
+                SegmentationMode::CodePoints => val.chars().count(),
             } as i64,
             head,
         ),
@@ -131,7 +141,7 @@ mod test {
 
         let options = Arguments {
             cell_paths: None,
-            graphemes: false,
+            mode: SegmentationMode::Utf8Bytes,
         };
 
         let actual = action(&word, &options, Span::test_data());
@@ -7,6 +7,7 @@ use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Type,
     Value,
 };
+use std::sync::Arc;
 
 struct Arguments {
     all: bool,
@@ -14,7 +15,8 @@ struct Arguments {
     replace: Spanned<String>,
     cell_paths: Option<Vec<CellPath>>,
     literal_replace: bool,
-    no_regex: bool,
+    // `None` means plain string replacement (the --string flag)
+    regex: Option<Regex>,
 }
 
 impl CmdArgument for Arguments {
@@ -78,13 +80,25 @@ impl Command for SubCommand {
         let literal_replace = call.has_flag("no-expand");
         let no_regex = call.has_flag("string");
 
+        // Compile (and cache) the regex once here rather than per row
+        let regex = if no_regex {
+            None
+        } else {
+            Some(engine_state.compiled_regex(&find.item).map_err(|e| {
+                ShellError::IncorrectValue {
+                    msg: format!("Regex error: {e}"),
+                    span: find.span,
+                }
+            })?)
+        };
+
         let args = Arguments {
             all: call.has_flag("all"),
             find,
             replace,
             cell_paths,
             literal_replace,
-            no_regex,
+            regex,
         };
         operate(action, args, input, call.head, engine_state.ctrlc.clone())
     }
@@ -107,7 +121,7 @@ impl Command for SubCommand {
                     "[[ColA ColB ColC]; [abc abc ads]] | str replace -a 'b' 'z' ColA ColC",
                 result: Some(Value::List {
                     vals: vec![Value::Record {
-                        cols: vec!["ColA".to_string(), "ColB".to_string(), "ColC".to_string()],
+                        cols: Arc::new(vec!["ColA".to_string(), "ColB".to_string(), "ColC".to_string()]),
                         vals: vec![
                             Value::test_string("azc"),
                             Value::test_string("abc"),
@@ -157,7 +171,7 @@ fn action(
         replace,
         all,
         literal_replace,
-        no_regex,
+        regex,
         ..
     }: &Arguments,
     head: Span,
@@ -165,55 +179,46 @@ fn action(
     match input {
         Value::String { val, .. } => {
             let FindReplace(find_str, replace_str) = FindReplace(&find.item, &replace.item);
-            if *no_regex {
-                // just use regular string replacement vs regular expressions
-                if *all {
-                    Value::String {
-                        val: val.replace(find_str, replace_str),
-                        span: head,
-                    }
-                } else {
-                    Value::String {
-                        val: val.replacen(find_str, replace_str, 1),
-                        span: head,
+            match regex {
+                None => {
+                    // just use regular string replacement vs regular expressions
+                    if *all {
+                        Value::String {
+                            val: val.replace(find_str, replace_str),
+                            span: head,
+                        }
+                    } else {
+                        Value::String {
+                            val: val.replacen(find_str, replace_str, 1),
+                            span: head,
+                        }
                     }
                 }
-            } else {
-                // use regular expressions to replace strings
-                let regex = Regex::new(find_str);
-
-                match regex {
-                    Ok(re) => {
-                        if *all {
-                            Value::String {
-                                val: {
-                                    if *literal_replace {
-                                        re.replace_all(val, NoExpand(replace_str)).to_string()
-                                    } else {
-                                        re.replace_all(val, replace_str).to_string()
-                                    }
-                                },
-                                span: head,
-                            }
-                        } else {
-                            Value::String {
-                                val: {
-                                    if *literal_replace {
-                                        re.replace(val, NoExpand(replace_str)).to_string()
-                                    } else {
-                                        re.replace(val, replace_str).to_string()
-                                    }
-                                },
-                                span: head,
-                            }
+                Some(re) => {
+                    // use the regular expression compiled in `run` to replace strings
+                    if *all {
+                        Value::String {
+                            val: {
+                                if *literal_replace {
+                                    re.replace_all(val, NoExpand(replace_str)).to_string()
+                                } else {
+                                    re.replace_all(val, replace_str).to_string()
+                                }
+                            },
+                            span: head,
+                        }
+                    } else {
+                        Value::String {
+                            val: {
+                                if *literal_replace {
+                                    re.replace(val, NoExpand(replace_str)).to_string()
+                                } else {
+                                    re.replace(val, replace_str).to_string()
+                                }
+                            },
+                            span: head,
                         }
                     }
-                    Err(e) => Value::Error {
-                        error: Box::new(ShellError::IncorrectValue {
-                            msg: format!("Regex error: {e}"),
-                            span: find.span,
-                        }),
-                    },
                 }
             }
         }
@@ -258,7 +263,7 @@ mod tests {
             cell_paths: None,
             literal_replace: false,
             all: false,
-            no_regex: false,
+            regex: Some(Regex::new("Cargo.(.+)").expect("valid regex")),
         };
 
         let actual = action(&word, &options, Span::test_data());
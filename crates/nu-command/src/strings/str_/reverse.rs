@@ -1,10 +1,23 @@
-use crate::input_handler::{operate, CellPathOnlyArgs};
+use crate::input_handler::{operate, CmdArgument};
+use crate::{segmentation_mode, SegmentationMode};
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::ast::CellPath;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::Category;
 use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Type, Value};
+use unicode_segmentation::UnicodeSegmentation;
+
+struct Arguments {
+    cell_paths: Option<Vec<CellPath>>,
+    mode: SegmentationMode,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -18,6 +31,21 @@ impl Command for SubCommand {
         Signature::build("str reverse")
             .input_output_types(vec![(Type::String, Type::String)])
             .vectorizes_over_list(true)
+            .switch(
+                "grapheme-clusters",
+                "reverse by grapheme clusters (keeps combined characters and emoji intact)",
+                Some('g'),
+            )
+            .switch(
+                "utf-8-bytes",
+                "reverse by UTF-8 bytes (invalid sequences become the replacement character)",
+                Some('b'),
+            )
+            .switch(
+                "code-points",
+                "reverse by code points (default; combined characters come apart)",
+                Some('c'),
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -42,7 +70,10 @@ impl Command for SubCommand {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
-        let args = CellPathOnlyArgs::from(cell_paths);
+        let args = Arguments {
+            cell_paths: (!cell_paths.is_empty()).then_some(cell_paths),
+            mode: segmentation_mode(call, SegmentationMode::CodePoints)?,
+        };
         operate(action, args, input, call.head, engine_state.ctrlc.clone())
     }
 
@@ -53,6 +84,11 @@ impl Command for SubCommand {
                 example: "'Nushell' | str reverse",
                 result: Some(Value::test_string("llehsuN")),
             },
+            Example {
+                description: "Reverse by grapheme clusters, keeping emoji intact",
+                example: "'🇯🇵ほげ' | str reverse -g",
+                result: Some(Value::test_string("げほ🇯🇵")),
+            },
             Example {
                 description: "Reverse multiple strings in a list",
                 example: "['Nushell' 'is' 'cool'] | str reverse",
@@ -69,10 +105,18 @@ impl Command for SubCommand {
     }
 }
 
-fn action(input: &Value, _arg: &CellPathOnlyArgs, head: Span) -> Value {
+fn action(input: &Value, arg: &Arguments, head: Span) -> Value {
     match input {
         Value::String { val, .. } => Value::String {
-            val: val.chars().rev().collect::<String>(),
+            val: match arg.mode {
+                SegmentationMode::GraphemeClusters => val.graphemes(true).rev().collect::<String>(),
+                SegmentationMode::CodePoints => val.chars().rev().collect::<String>(),
+                SegmentationMode::Utf8Bytes => {
+                    let mut bytes = val.clone().into_bytes();
+                    bytes.reverse();
+                    String::from_utf8_lossy(&bytes).to_string()
+                }
+            },
             span: head,
         },
         Value::Error { .. } => input.clone(),
@@ -1,5 +1,5 @@
 use crate::input_handler::{operate, CmdArgument};
-use crate::{grapheme_flags, util};
+use crate::{segmentation_mode, util, SegmentationMode};
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::ast::CellPath;
@@ -16,7 +16,7 @@ pub struct SubCommand;
 struct Arguments {
     indexes: Substring,
     cell_paths: Option<Vec<CellPath>>,
-    graphemes: bool,
+    mode: SegmentationMode,
 }
 
 impl CmdArgument for Arguments {
@@ -53,6 +53,11 @@ impl Command for SubCommand {
                 "count indexes and split using UTF-8 bytes (default; non-ASCII chars have length 2+)",
                 Some('b'),
             )
+            .switch(
+                "code-points",
+                "count indexes and split using code points (combined characters count separately)",
+                Some('c'),
+            )
             .required(
                 "range",
                 SyntaxShape::Any,
@@ -94,7 +99,7 @@ impl Command for SubCommand {
         let args = Arguments {
             indexes,
             cell_paths,
-            graphemes: grapheme_flags(call)?,
+            mode: segmentation_mode(call, SegmentationMode::Utf8Bytes)?,
         };
         operate(action, args, input, call.head, engine_state.ctrlc.clone())
     }
@@ -112,6 +117,11 @@ impl Command for SubCommand {
                 example: " '🇯🇵ほげ ふが ぴよ' | str substring -g 4..6",
                 result: Some(Value::test_string("ふが")),
             },
+            Example {
+                description: "Count indexes and split using code points",
+                example: " '🇯🇵ほげ ふが ぴよ' | str substring -c 2..4",
+                result: Some(Value::test_string("ほげ")),
+            },
         ]
     }
 }
@@ -120,7 +130,13 @@ fn action(input: &Value, args: &Arguments, head: Span) -> Value {
     let options = &args.indexes;
     match input {
         Value::String { val: s, .. } => {
-            let len: isize = s.len() as isize;
+            // Normalize negative indexes against the length in the unit
+            // the indexes are counted in
+            let len: isize = match args.mode {
+                SegmentationMode::GraphemeClusters => s.graphemes(true).count(),
+                SegmentationMode::Utf8Bytes => s.len(),
+                SegmentationMode::CodePoints => s.chars().count(),
+            } as isize;
 
             let start: isize = if options.0 < 0 {
                 options.0 + len
@@ -144,32 +160,29 @@ fn action(input: &Value, args: &Arguments, head: Span) -> Value {
                     },
                     Ordering::Less => Value::String {
                         val: {
-                            if end == isize::max_value() {
-                                if args.graphemes {
-                                    s.graphemes(true)
-                                        .skip(start as usize)
-                                        .collect::<Vec<&str>>()
-                                        .join("")
-                                } else {
-                                    String::from_utf8_lossy(
-                                        &s.bytes().skip(start as usize).collect::<Vec<_>>(),
-                                    )
-                                    .to_string()
-                                }
-                            } else if args.graphemes {
-                                s.graphemes(true)
-                                    .skip(start as usize)
-                                    .take((end - start) as usize)
-                                    .collect::<Vec<&str>>()
-                                    .join("")
+                            let take = if end == isize::max_value() {
+                                usize::max_value()
                             } else {
-                                String::from_utf8_lossy(
+                                (end - start) as usize
+                            };
+                            match args.mode {
+                                SegmentationMode::GraphemeClusters => s
+                                    .graphemes(true)
+                                    .skip(start as usize)
+                                    .take(take)
+                                    .collect::<String>(),
+                                SegmentationMode::CodePoints => s
+                                    .chars()
+                                    .skip(start as usize)
+                                    .take(take)
+                                    .collect::<String>(),
+                                SegmentationMode::Utf8Bytes => String::from_utf8_lossy(
                                     &s.bytes()
                                         .skip(start as usize)
-                                        .take((end - start) as usize)
+                                        .take(take)
                                         .collect::<Vec<_>>(),
                                 )
-                                .to_string()
+                                .to_string(),
                             }
                         },
                         span: head,
@@ -195,7 +208,7 @@ fn action(input: &Value, args: &Arguments, head: Span) -> Value {
 
 #[cfg(test)]
 mod tests {
-    use super::{action, Arguments, Span, SubCommand, Substring, Value};
+    use super::{action, Arguments, SegmentationMode, Span, SubCommand, Substring, Value};
 
     #[test]
     fn test_examples() {
@@ -258,7 +271,7 @@ mod tests {
                 &Arguments {
                     indexes: expectation.options(),
                     cell_paths: None,
-                    graphemes: false,
+                    mode: SegmentationMode::Utf8Bytes,
                 },
                 Span::test_data(),
             );
@@ -277,7 +290,7 @@ mod tests {
         let options = Arguments {
             cell_paths: None,
             indexes: Substring(4, 5),
-            graphemes: false,
+            mode: SegmentationMode::Utf8Bytes,
         };
 
         let actual = action(&word, &options, Span::test_data());
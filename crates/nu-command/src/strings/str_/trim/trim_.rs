@@ -174,7 +174,7 @@ fn action(input: &Value, arg: &Arguments, head: Span) -> Value {
                     let new_vals = vals.iter().map(|v| action(v, arg, head)).collect();
 
                     Value::Record {
-                        cols: cols.to_vec(),
+                        cols: cols.clone(),
                         vals: new_vals,
                         span: *span,
                     }
@@ -230,6 +230,7 @@ fn trim(s: &str, char_: Option<char>, trim_side: &TrimSide) -> String {
 mod tests {
     use crate::strings::str_::trim::trim_::*;
     use nu_protocol::{Span, Value};
+    use std::sync::Arc;
 
     #[test]
     fn test_examples() {
@@ -240,7 +241,7 @@ mod tests {
 
     fn make_record(cols: Vec<&str>, vals: Vec<&str>) -> Value {
         Value::Record {
-            cols: cols.iter().map(|x| x.to_string()).collect(),
+            cols: Arc::new(cols.iter().map(|x| x.to_string()).collect()),
             vals: vals
                 .iter()
                 .map(|x| Value::test_string(x.to_string()))
@@ -4,6 +4,7 @@ use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Type, Value,
 };
 
+use std::sync::Arc;
 use std::thread;
 
 #[derive(Clone)]
@@ -113,7 +114,7 @@ impl Command for Complete {
                 }
 
                 Ok(Value::Record {
-                    cols,
+                    cols: Arc::new(cols),
                     vals,
                     span: call.head,
                 }
@@ -0,0 +1,286 @@
+use std::process::Command as CommandSys;
+
+use nu_engine::{get_full_help, CallExt};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Defaults;
+
+impl Command for Defaults {
+    fn name(&self) -> &str {
+        "defaults"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("defaults")
+            .category(Category::System)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn usage(&self) -> &str {
+        "Read and write macOS preference domains."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &Self.signature(),
+                &Self.examples(),
+                engine_state,
+                stack,
+                self.is_parser_keyword(),
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct DefaultsDomains;
+
+impl Command for DefaultsDomains {
+    fn name(&self) -> &str {
+        "defaults domains"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("defaults domains")
+            .input_output_types(vec![(Type::Nothing, Type::List(Box::new(Type::String)))])
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "List the preference domains of the current user."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let output = run_defaults(&["domains".to_string()], head)?;
+
+        Ok(Value::List {
+            vals: output
+                .trim()
+                .split(", ")
+                .filter(|domain| !domain.is_empty())
+                .map(|domain| Value::string(domain, head))
+                .collect(),
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Find the domains of a vendor",
+            example: "defaults domains | where $it =~ apple",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct DefaultsRead;
+
+impl Command for DefaultsRead {
+    fn name(&self) -> &str {
+        "defaults read"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("defaults read")
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .required(
+                "domain",
+                SyntaxShape::String,
+                "the preference domain to read",
+            )
+            .optional(
+                "key",
+                SyntaxShape::String,
+                "optionally read only this key of the domain",
+            )
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Read a preference domain as a record."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let domain: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let key: Option<Spanned<String>> = call.opt(engine_state, stack, 1)?;
+
+        // 'defaults export' keeps the value types that 'defaults read' prints away
+        let plist = run_defaults(&["export".to_string(), domain.item, "-".to_string()], head)?;
+        let value = crate::formats::from_xml_plist(&plist, head)?;
+
+        match key {
+            Some(key) => match value.get_data_by_key(&key.item) {
+                Some(value) => Ok(value.into_pipeline_data()),
+                None => Err(ShellError::GenericError(
+                    "Unable to find preference key".into(),
+                    format!("the domain has no key {}", key.item),
+                    Some(key.span),
+                    None,
+                    Vec::new(),
+                )),
+            },
+            None => Ok(value.into_pipeline_data()),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Read the whole dock configuration",
+                example: "defaults read com.apple.dock",
+                result: None,
+            },
+            Example {
+                description: "Read one key of a domain",
+                example: "defaults read com.apple.dock tilesize",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[derive(Clone)]
+pub struct DefaultsWrite;
+
+impl Command for DefaultsWrite {
+    fn name(&self) -> &str {
+        "defaults write"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("defaults write")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "domain",
+                SyntaxShape::String,
+                "the preference domain to write to",
+            )
+            .required("key", SyntaxShape::String, "the key to write")
+            .required("value", SyntaxShape::Any, "the value to store")
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Write a typed value into a preference domain."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let domain: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let key: Spanned<String> = call.req(engine_state, stack, 1)?;
+        let value: Value = call.req(engine_state, stack, 2)?;
+
+        let mut args = vec!["write".to_string(), domain.item, key.item];
+        args.extend(value_to_defaults_args(&value)?);
+        run_defaults(&args, head)?;
+
+        Ok(Value::nothing(head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Store an integer preference",
+                example: "defaults write com.apple.dock tilesize 48",
+                result: None,
+            },
+            Example {
+                description: "Store a boolean preference",
+                example: "defaults write com.apple.dock autohide true",
+                result: None,
+            },
+        ]
+    }
+}
+
+// defaults(1) wants every value prefixed with its type
+fn value_to_defaults_args(value: &Value) -> Result<Vec<String>, ShellError> {
+    Ok(match value {
+        Value::String { val, .. } => vec!["-string".to_string(), val.clone()],
+        Value::Int { val, .. } => vec!["-integer".to_string(), val.to_string()],
+        Value::Float { val, .. } => vec!["-float".to_string(), val.to_string()],
+        Value::Bool { val, .. } => vec!["-boolean".to_string(), val.to_string()],
+        Value::List { vals, .. } => {
+            let mut args = vec!["-array".to_string()];
+            for val in vals {
+                args.push(val.as_string()?);
+            }
+            args
+        }
+        other => {
+            return Err(ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string, int, float, bool or list".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: other.expect_span(),
+                src_span: other.expect_span(),
+            })
+        }
+    })
+}
+
+pub(super) fn run_defaults(args: &[String], span: Span) -> Result<String, ShellError> {
+    let output = CommandSys::new("defaults")
+        .args(args)
+        .output()
+        .map_err(|err| {
+            ShellError::GenericError(
+                "Failed to start defaults".into(),
+                err.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+    if !output.status.success() {
+        return Err(ShellError::GenericError(
+            "defaults failed".into(),
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
@@ -0,0 +1,280 @@
+use std::process::Command as CommandSys;
+
+use chrono::DateTime;
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct EventLog;
+
+impl Command for EventLog {
+    fn name(&self) -> &str {
+        "eventlog"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("eventlog")
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .required(
+                "channel",
+                SyntaxShape::String,
+                "the event log channel to query, like System or Application",
+            )
+            .named(
+                "level",
+                SyntaxShape::String,
+                "only events at this level (name or number) or more severe",
+                Some('l'),
+            )
+            .named(
+                "since",
+                SyntaxShape::Duration,
+                "only events created within this duration",
+                None,
+            )
+            .named(
+                "max-events",
+                SyntaxShape::Int,
+                "show only the most recent events",
+                Some('n'),
+            )
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Query a Windows event log and return structured events."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The events are read from wevtutil, newest first. Currently supported only on Windows systems."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["windows", "log", "wevtutil", "event"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let channel: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let level: Option<Spanned<String>> = call.get_flag(engine_state, stack, "level")?;
+        let since: Option<Value> = call.get_flag(engine_state, stack, "since")?;
+        let max_events: Option<i64> = call.get_flag(engine_state, stack, "max-events")?;
+
+        let mut conditions = vec![];
+        if let Some(level) = level {
+            let level = parse_level(&level)?;
+            conditions.push(format!("Level > 0 and Level <= {level}"));
+        }
+        if let Some(since) = since {
+            let millis = match since {
+                Value::Duration { val, .. } => val / 1_000_000,
+                other => {
+                    return Err(ShellError::TypeMismatch {
+                        err_message: "expected a duration".to_string(),
+                        span: other.expect_span(),
+                    })
+                }
+            };
+            conditions.push(format!("TimeCreated[timediff(@SystemTime) <= {millis}]"));
+        }
+
+        let mut args = vec![
+            "qe".to_string(),
+            channel.item,
+            "/f:renderedxml".to_string(),
+            "/e:Events".to_string(),
+            "/rd:true".to_string(),
+        ];
+        if !conditions.is_empty() {
+            args.push(format!("/q:*[System[{}]]", conditions.join(" and ")));
+        }
+        if let Some(max_events) = max_events {
+            args.push(format!("/c:{max_events}"));
+        }
+
+        let output = CommandSys::new("wevtutil")
+            .args(args)
+            .output()
+            .map_err(|err| {
+                ShellError::GenericError(
+                    "Failed to start wevtutil".into(),
+                    err.to_string(),
+                    Some(head),
+                    Some("the eventlog command reads events through wevtutil".into()),
+                    Vec::new(),
+                )
+            })?;
+        if !output.status.success() {
+            return Err(ShellError::GenericError(
+                "wevtutil failed".into(),
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+                Some(channel.span),
+                None,
+                Vec::new(),
+            ));
+        }
+
+        let xml = String::from_utf8_lossy(&output.stdout).to_string();
+        let events = events_from_xml(&xml, head)?;
+        Ok(events.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the most recent events of the System channel",
+                example: "eventlog System -n 50",
+                result: None,
+            },
+            Example {
+                description: "Show errors of the last day in the Application channel",
+                example: "eventlog Application -l error --since 1day",
+                result: None,
+            },
+        ]
+    }
+}
+
+const LEVELS: [&str; 6] = [
+    "log-always",
+    "critical",
+    "error",
+    "warning",
+    "information",
+    "verbose",
+];
+
+fn parse_level(level: &Spanned<String>) -> Result<usize, ShellError> {
+    if let Some(level) = LEVELS.iter().position(|name| *name == level.item) {
+        return Ok(level);
+    }
+    match level.item.parse() {
+        Ok(level @ 0..=5) => Ok(level),
+        _ => Err(ShellError::TypeMismatch {
+            err_message: format!(
+                "expected an event level between 0 and 5 or one of: {}",
+                LEVELS.join(", ")
+            ),
+            span: level.span,
+        }),
+    }
+}
+
+fn events_from_xml(xml: &str, head: Span) -> Result<Vec<Value>, ShellError> {
+    let document = roxmltree::Document::parse(xml).map_err(|err| {
+        ShellError::GenericError(
+            "Failed to parse the wevtutil output".into(),
+            err.to_string(),
+            Some(head),
+            None,
+            Vec::new(),
+        )
+    })?;
+
+    Ok(document
+        .root_element()
+        .children()
+        .filter(|node| node.has_tag_name("Event"))
+        .map(|event| event_to_value(&event, head))
+        .collect())
+}
+
+fn event_to_value(event: &roxmltree::Node, head: Span) -> Value {
+    let system = event.children().find(|n| n.has_tag_name("System"));
+    let system_field = |name: &str| {
+        system
+            .and_then(|s| s.children().find(|n| n.has_tag_name(name)))
+            .and_then(|n| n.text())
+            .map(|text| Value::string(text, head))
+            .unwrap_or_else(|| Value::nothing(head))
+    };
+
+    let timestamp = system
+        .and_then(|s| s.children().find(|n| n.has_tag_name("TimeCreated")))
+        .and_then(|n| n.attribute("SystemTime"))
+        .and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+        .map(|val| Value::Date { val, span: head })
+        .unwrap_or_else(|| Value::nothing(head));
+    let provider = system
+        .and_then(|s| s.children().find(|n| n.has_tag_name("Provider")))
+        .and_then(|n| n.attribute("Name"))
+        .map(|name| Value::string(name, head))
+        .unwrap_or_else(|| Value::nothing(head));
+    let event_id = match system_field("EventID") {
+        Value::String { val, .. } => match val.parse() {
+            Ok(val) => Value::Int { val, span: head },
+            Err(_) => Value::string(val, head),
+        },
+        other => other,
+    };
+    let level = match system_field("Level") {
+        Value::String { val, .. } => match val.parse::<usize>().ok().and_then(|l| LEVELS.get(l)) {
+            Some(level) => Value::string(*level, head),
+            None => Value::string(val, head),
+        },
+        other => other,
+    };
+
+    let (data_cols, data_vals) = event
+        .children()
+        .find(|n| n.has_tag_name("EventData"))
+        .map(|data| {
+            data.children()
+                .filter(|n| n.has_tag_name("Data"))
+                .enumerate()
+                .map(|(i, n)| {
+                    (
+                        n.attribute("Name")
+                            .map(str::to_string)
+                            .unwrap_or_else(|| i.to_string()),
+                        Value::string(n.text().unwrap_or_default(), head),
+                    )
+                })
+                .unzip()
+        })
+        .unwrap_or_else(|| (vec![], vec![]));
+    let message = event
+        .children()
+        .find(|n| n.has_tag_name("RenderingInfo"))
+        .and_then(|info| info.children().find(|n| n.has_tag_name("Message")))
+        .and_then(|n| n.text())
+        .map(|text| Value::string(text.trim(), head))
+        .unwrap_or_else(|| Value::nothing(head));
+
+    Value::record(
+        vec![
+            "timestamp".into(),
+            "channel".into(),
+            "provider".into(),
+            "event_id".into(),
+            "level".into(),
+            "computer".into(),
+            "message".into(),
+            "event_data".into(),
+        ],
+        vec![
+            timestamp,
+            system_field("Channel"),
+            provider,
+            event_id,
+            level,
+            system_field("Computer"),
+            message,
+            Value::record(data_cols, data_vals, head),
+        ],
+        head,
+    )
+}
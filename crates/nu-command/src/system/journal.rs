@@ -0,0 +1,257 @@
+use std::io::{BufRead, BufReader, Lines};
+use std::process::{Child, ChildStdout, Command as CommandSys, Stdio};
+
+use chrono::{FixedOffset, TimeZone, Utc};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Journal;
+
+impl Command for Journal {
+    fn name(&self) -> &str {
+        "journal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("journal")
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .named(
+                "unit",
+                SyntaxShape::String,
+                "only entries of this systemd unit",
+                Some('u'),
+            )
+            .named(
+                "priority",
+                SyntaxShape::String,
+                "only entries at this syslog priority (name or number) or more important",
+                Some('p'),
+            )
+            .named(
+                "since",
+                SyntaxShape::String,
+                "only entries on or newer than this date specification",
+                None,
+            )
+            .named(
+                "lines",
+                SyntaxShape::Int,
+                "show only the most recent entries",
+                Some('n'),
+            )
+            .switch("follow", "stream new entries as they are added", Some('f'))
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Query the systemd journal and stream structured entries."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The entries are read from journalctl, which must be available."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["systemd", "journald", "log", "journalctl"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let mut args = vec![
+            "--output=json".to_string(),
+            "--no-pager".to_string(),
+            "--quiet".to_string(),
+        ];
+        if let Some(unit) = call.get_flag::<String>(engine_state, stack, "unit")? {
+            args.push(format!("--unit={unit}"));
+        }
+        if let Some(priority) = call.get_flag::<String>(engine_state, stack, "priority")? {
+            args.push(format!("--priority={priority}"));
+        }
+        if let Some(since) = call.get_flag::<String>(engine_state, stack, "since")? {
+            args.push(format!("--since={since}"));
+        }
+        if let Some(lines) = call.get_flag::<i64>(engine_state, stack, "lines")? {
+            args.push(format!("--lines={lines}"));
+        }
+        if call.has_flag("follow") {
+            args.push("--follow".to_string());
+        }
+
+        let mut child = CommandSys::new("journalctl")
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|err| {
+                ShellError::GenericError(
+                    "Failed to start journalctl".into(),
+                    err.to_string(),
+                    Some(head),
+                    Some("the journal command reads entries through journalctl".into()),
+                    Vec::new(),
+                )
+            })?;
+        let stdout = child.stdout.take().expect("stdout was piped");
+
+        let entries = Entries {
+            child,
+            lines: BufReader::new(stdout).lines(),
+            head,
+        };
+        Ok(entries.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the most recent entries of a unit",
+                example: "journal -u sshd.service -n 50",
+                result: None,
+            },
+            Example {
+                description: "Show errors since the last hour",
+                example: "journal -p err --since -1h",
+                result: None,
+            },
+            Example {
+                description: "Stream entries as they are written, like journalctl -f",
+                example: "journal --follow | each {|entry| $entry.message }",
+                result: None,
+            },
+        ]
+    }
+}
+
+const SEVERITIES: [&str; 8] = [
+    "emerg", "alert", "crit", "err", "warning", "notice", "info", "debug",
+];
+
+struct Entries {
+    child: Child,
+    lines: Lines<BufReader<ChildStdout>>,
+    head: Span,
+}
+
+impl Iterator for Entries {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(_) => return None,
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            return Some(entry_to_value(&line, self.head));
+        }
+    }
+}
+
+impl Drop for Entries {
+    fn drop(&mut self) {
+        // stop a followed journal once the consumer is done with it
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn entry_to_value(line: &str, head: Span) -> Value {
+    let entry = match crate::formats::convert_string_to_value(line.to_string(), head) {
+        Ok(entry) => entry,
+        Err(err) => {
+            return Value::Error {
+                error: Box::new(err),
+            }
+        }
+    };
+
+    let field = |name: &str| match entry.get_data_by_key(name) {
+        Some(Value::String { val, .. }) => Value::string(val, head),
+        _ => Value::nothing(head),
+    };
+    let timestamp = match entry.get_data_by_key("__REALTIME_TIMESTAMP") {
+        Some(Value::String { val, .. }) => val
+            .parse()
+            .ok()
+            .and_then(|micros: i64| {
+                Utc.timestamp_opt(
+                    micros.div_euclid(1_000_000),
+                    (micros.rem_euclid(1_000_000) * 1_000) as u32,
+                )
+                .single()
+            })
+            .and_then(|utc| {
+                Some(Value::Date {
+                    val: utc.with_timezone(&FixedOffset::east_opt(0)?),
+                    span: head,
+                })
+            })
+            .unwrap_or_else(|| Value::nothing(head)),
+        _ => Value::nothing(head),
+    };
+    let priority = match entry.get_data_by_key("PRIORITY") {
+        Some(Value::String { val, .. }) => {
+            match val.parse::<usize>().ok().and_then(|p| SEVERITIES.get(p)) {
+                Some(severity) => Value::string(*severity, head),
+                None => Value::string(val, head),
+            }
+        }
+        _ => Value::nothing(head),
+    };
+    let pid = match entry.get_data_by_key("_PID") {
+        Some(Value::String { val, .. }) => match val.parse() {
+            Ok(val) => Value::Int { val, span: head },
+            Err(_) => Value::nothing(head),
+        },
+        _ => Value::nothing(head),
+    };
+
+    Value::record(
+        vec![
+            "timestamp".into(),
+            "unit".into(),
+            "priority".into(),
+            "host".into(),
+            "pid".into(),
+            "message".into(),
+        ],
+        vec![
+            timestamp,
+            field("_SYSTEMD_UNIT"),
+            priority,
+            field("_HOSTNAME"),
+            pid,
+            field("MESSAGE"),
+        ],
+        head,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Journal {})
+    }
+}
@@ -0,0 +1,222 @@
+use std::process::Command as CommandSys;
+
+use nu_engine::{get_full_help, CallExt};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Launchd;
+
+impl Command for Launchd {
+    fn name(&self) -> &str {
+        "launchd"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("launchd")
+            .category(Category::System)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn usage(&self) -> &str {
+        "List and control launchd services."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &Self.signature(),
+                &Self.examples(),
+                engine_state,
+                stack,
+                self.is_parser_keyword(),
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct LaunchdList;
+
+impl Command for LaunchdList {
+    fn name(&self) -> &str {
+        "launchd list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("launchd list")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "List the services loaded into launchd for the current user."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let output = run_launchctl(&["list"], head)?;
+
+        let mut services = vec![];
+        // the first line carries the 'PID Status Label' header
+        for line in output.lines().skip(1) {
+            let mut parts = line.splitn(3, '\t');
+            let pid = parts.next().unwrap_or("-");
+            let status = parts.next().unwrap_or("-");
+            let label = parts.next().unwrap_or("");
+            services.push(Value::record(
+                vec!["pid".into(), "status".into(), "label".into()],
+                vec![
+                    match pid.parse() {
+                        Ok(val) => Value::Int { val, span: head },
+                        Err(_) => Value::nothing(head),
+                    },
+                    match status.parse() {
+                        Ok(val) => Value::Int { val, span: head },
+                        Err(_) => Value::nothing(head),
+                    },
+                    Value::string(label, head),
+                ],
+                head,
+            ));
+        }
+        Ok(services.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Find the running services of a vendor",
+            example: "launchd list | where pid != null and label =~ apple",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct LaunchdStart;
+
+impl Command for LaunchdStart {
+    fn name(&self) -> &str {
+        "launchd start"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("launchd start")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("label", SyntaxShape::String, "the service to start")
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Start a launchd service."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let label: Spanned<String> = call.req(engine_state, stack, 0)?;
+        run_launchctl(&["start", &label.item], label.span)?;
+        Ok(Value::nothing(head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Start a service by its label",
+            example: "launchd start com.example.agent",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct LaunchdStop;
+
+impl Command for LaunchdStop {
+    fn name(&self) -> &str {
+        "launchd stop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("launchd stop")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("label", SyntaxShape::String, "the service to stop")
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Stop a launchd service."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let label: Spanned<String> = call.req(engine_state, stack, 0)?;
+        run_launchctl(&["stop", &label.item], label.span)?;
+        Ok(Value::nothing(head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Stop a service by its label",
+            example: "launchd stop com.example.agent",
+            result: None,
+        }]
+    }
+}
+
+fn run_launchctl(args: &[&str], span: Span) -> Result<String, ShellError> {
+    let output = CommandSys::new("launchctl")
+        .args(args)
+        .output()
+        .map_err(|err| {
+            ShellError::GenericError(
+                "Failed to start launchctl".into(),
+                err.to_string(),
+                Some(span),
+                None,
+                Vec::new(),
+            )
+        })?;
+    if !output.status.success() {
+        return Err(ShellError::GenericError(
+            "launchctl failed".into(),
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
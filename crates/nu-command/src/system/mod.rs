@@ -1,6 +1,14 @@
 mod complete;
+#[cfg(target_os = "macos")]
+mod defaults;
+#[cfg(windows)]
+mod eventlog;
 #[cfg(unix)]
 mod exec;
+#[cfg(target_os = "linux")]
+mod journal;
+#[cfg(target_os = "macos")]
+mod launchd;
 mod nu_check;
 #[cfg(any(
     target_os = "android",
@@ -10,14 +18,31 @@ mod nu_check;
 ))]
 mod ps;
 #[cfg(windows)]
+mod registry_delete;
+#[cfg(windows)]
 mod registry_query;
+#[cfg(windows)]
+mod registry_set;
+mod run_batched;
 mod run_external;
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+mod service;
 mod sys;
+#[cfg(unix)]
+mod users_;
 mod which_;
 
 pub use complete::Complete;
+#[cfg(target_os = "macos")]
+pub use defaults::{Defaults, DefaultsDomains, DefaultsRead, DefaultsWrite};
+#[cfg(windows)]
+pub use eventlog::EventLog;
 #[cfg(unix)]
 pub use exec::Exec;
+#[cfg(target_os = "linux")]
+pub use journal::Journal;
+#[cfg(target_os = "macos")]
+pub use launchd::{Launchd, LaunchdList, LaunchdStart, LaunchdStop};
 pub use nu_check::NuCheck;
 #[cfg(any(
     target_os = "android",
@@ -27,7 +52,16 @@ pub use nu_check::NuCheck;
 ))]
 pub use ps::Ps;
 #[cfg(windows)]
+pub use registry_delete::RegistryDelete;
+#[cfg(windows)]
 pub use registry_query::RegistryQuery;
+#[cfg(windows)]
+pub use registry_set::RegistrySet;
+pub use run_batched::RunBatched;
 pub use run_external::{External, ExternalCommand};
+#[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+pub use service::{Service, ServiceList, ServiceRestart, ServiceStart, ServiceStop};
 pub use sys::Sys;
+#[cfg(unix)]
+pub use users_::{Groups, Users, Whoami};
 pub use which_::Which;
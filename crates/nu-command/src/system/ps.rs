@@ -1,3 +1,4 @@
+use std::sync::Arc;
 use std::time::Duration;
 
 use nu_protocol::{
@@ -156,7 +157,11 @@ fn run_ps(engine_state: &EngineState, call: &Call) -> Result<PipelineData, Shell
             }
         }
 
-        output.push(Value::Record { cols, vals, span });
+        output.push(Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        });
     }
 
     Ok(output
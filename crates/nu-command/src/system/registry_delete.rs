@@ -0,0 +1,125 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Type, Value,
+};
+use winreg::{enums::*, RegKey};
+
+use super::registry_query::requested_hive;
+
+#[derive(Clone)]
+pub struct RegistryDelete;
+
+impl Command for RegistryDelete {
+    fn name(&self) -> &str {
+        "registry delete"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("registry delete")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .switch("hkcr", "delete from the hkey_classes_root hive", None)
+            .switch("hkcu", "delete from the hkey_current_user hive", None)
+            .switch("hklm", "delete from the hkey_local_machine hive", None)
+            .switch("hku", "delete from the hkey_users hive", None)
+            .switch("hkpd", "delete from the hkey_performance_data hive", None)
+            .switch("hkpt", "delete from the hkey_performance_text hive", None)
+            .switch(
+                "hkpnls",
+                "delete from the hkey_performance_nls_text hive",
+                None,
+            )
+            .switch("hkcc", "delete from the hkey_current_config hive", None)
+            .switch("hkdd", "delete from the hkey_dyn_data hive", None)
+            .switch(
+                "hkculs",
+                "delete from the hkey_current_user_local_settings hive",
+                None,
+            )
+            .required("key", SyntaxShape::String, "registry key to delete from")
+            .optional(
+                "value",
+                SyntaxShape::String,
+                "registry value to delete; the key itself is deleted when omitted",
+            )
+            .switch(
+                "recursive",
+                "delete the key and all of its subkeys",
+                Some('r'),
+            )
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Delete a key or value from the Windows registry."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Currently supported only on Windows systems."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let call_span = call.head;
+
+        let registry_key: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let registry_value: Option<Spanned<String>> = call.opt(engine_state, stack, 1)?;
+
+        let hive = requested_hive(call, call_span)?;
+        match registry_value {
+            Some(value) => {
+                let reg_key = RegKey::predef(hive)
+                    .open_subkey_with_flags(registry_key.item, KEY_READ | KEY_WRITE)?;
+                reg_key.delete_value(value.item.as_str()).map_err(|_| {
+                    ShellError::GenericError(
+                        "Unable to find registry key/value".to_string(),
+                        format!("Registry value: {} was not found", value.item),
+                        Some(value.span),
+                        None,
+                        Vec::new(),
+                    )
+                })?;
+            }
+            None if call.has_flag("recursive") => {
+                RegKey::predef(hive).delete_subkey_all(registry_key.item)?;
+            }
+            None => {
+                RegKey::predef(hive)
+                    .delete_subkey(registry_key.item)
+                    .map_err(|err| {
+                        ShellError::GenericError(
+                            "Unable to delete registry key".to_string(),
+                            err.to_string(),
+                            Some(registry_key.span),
+                            Some("a key with subkeys can be deleted with --recursive".into()),
+                            Vec::new(),
+                        )
+                    })?;
+            }
+        }
+
+        Ok(Value::nothing(call_span).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Delete a value from the HKEY_CURRENT_USER hive",
+                example: "registry delete --hkcu environment MY_VAR",
+                result: None,
+            },
+            Example {
+                description: "Delete a key and everything under it",
+                example: r"registry delete --hkcu 'Software\MyApp' --recursive",
+                result: None,
+            },
+        ]
+    }
+}
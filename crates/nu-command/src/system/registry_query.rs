@@ -5,25 +5,11 @@ use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
     Signature, Span, Spanned, SyntaxShape, Type, Value,
 };
-use winreg::{enums::*, RegKey};
+use winreg::{enums::*, RegKey, HKEY};
 
 #[derive(Clone)]
 pub struct RegistryQuery;
 
-struct RegistryQueryArgs {
-    hkcr: bool,
-    hkcu: bool,
-    hklm: bool,
-    hku: bool,
-    hkpd: bool,
-    hkpt: bool,
-    hkpnls: bool,
-    hkcc: bool,
-    hkdd: bool,
-    hkculs: bool,
-    key: String,
-}
-
 impl Command for RegistryQuery {
     fn name(&self) -> &str {
         "registry query"
@@ -100,21 +86,8 @@ fn registry_query(
     let registry_key_span = &registry_key.clone().span;
     let registry_value: Option<Spanned<String>> = call.opt(engine_state, stack, 1)?;
 
-    let reg_params = RegistryQueryArgs {
-        hkcr: call.has_flag("hkcr"),
-        hkcu: call.has_flag("hkcu"),
-        hklm: call.has_flag("hklm"),
-        hku: call.has_flag("hku"),
-        hkpd: call.has_flag("hkpd"),
-        hkpt: call.has_flag("hkpt"),
-        hkpnls: call.has_flag("hkpnls"),
-        hkcc: call.has_flag("hkcc"),
-        hkdd: call.has_flag("hkdd"),
-        hkculs: call.has_flag("hkculs"),
-        key: registry_key.item,
-    };
-
-    let reg_key = get_reg_key(reg_params, call_span)?;
+    let hive = requested_hive(call, call_span)?;
+    let reg_key = RegKey::predef(hive).open_subkey(registry_key.item)?;
 
     if registry_value.is_none() {
         let mut reg_values = vec![];
@@ -163,43 +136,25 @@ fn registry_query(
     }
 }
 
-fn get_reg_key(reg_params: RegistryQueryArgs, call_span: Span) -> Result<RegKey, ShellError> {
-    let mut key_count = 0;
-    let registry_key = if reg_params.hkcr {
-        key_count += 1;
-        RegKey::predef(HKEY_CLASSES_ROOT).open_subkey(reg_params.key)?
-    } else if reg_params.hkcu {
-        key_count += 1;
-        RegKey::predef(HKEY_CURRENT_USER).open_subkey(reg_params.key)?
-    } else if reg_params.hklm {
-        key_count += 1;
-        RegKey::predef(HKEY_LOCAL_MACHINE).open_subkey(reg_params.key)?
-    } else if reg_params.hku {
-        key_count += 1;
-        RegKey::predef(HKEY_USERS).open_subkey(reg_params.key)?
-    } else if reg_params.hkpd {
-        key_count += 1;
-        RegKey::predef(HKEY_PERFORMANCE_DATA).open_subkey(reg_params.key)?
-    } else if reg_params.hkpt {
-        key_count += 1;
-        RegKey::predef(HKEY_PERFORMANCE_TEXT).open_subkey(reg_params.key)?
-    } else if reg_params.hkpnls {
-        key_count += 1;
-        RegKey::predef(HKEY_PERFORMANCE_NLSTEXT).open_subkey(reg_params.key)?
-    } else if reg_params.hkcc {
-        key_count += 1;
-        RegKey::predef(HKEY_CURRENT_CONFIG).open_subkey(reg_params.key)?
-    } else if reg_params.hkdd {
-        key_count += 1;
-        RegKey::predef(HKEY_DYN_DATA).open_subkey(reg_params.key)?
-    } else if reg_params.hkculs {
-        key_count += 1;
-        RegKey::predef(HKEY_CURRENT_USER_LOCAL_SETTINGS).open_subkey(reg_params.key)?
-    } else {
-        RegKey::predef(HKEY_CURRENT_USER).open_subkey(reg_params.key)?
-    };
-
-    if key_count > 1 {
+pub(super) fn requested_hive(call: &Call, call_span: Span) -> Result<HKEY, ShellError> {
+    let hives = [
+        ("hkcr", HKEY_CLASSES_ROOT),
+        ("hkcu", HKEY_CURRENT_USER),
+        ("hklm", HKEY_LOCAL_MACHINE),
+        ("hku", HKEY_USERS),
+        ("hkpd", HKEY_PERFORMANCE_DATA),
+        ("hkpt", HKEY_PERFORMANCE_TEXT),
+        ("hkpnls", HKEY_PERFORMANCE_NLSTEXT),
+        ("hkcc", HKEY_CURRENT_CONFIG),
+        ("hkdd", HKEY_DYN_DATA),
+        ("hkculs", HKEY_CURRENT_USER_LOCAL_SETTINGS),
+    ];
+    let mut requested = hives.iter().filter(|(flag, _)| call.has_flag(flag));
+    let hive = requested
+        .next()
+        .map(|(_, hive)| *hive)
+        .unwrap_or(HKEY_CURRENT_USER);
+    if requested.next().is_some() {
         return Err(ShellError::GenericError(
             "Only one registry key can be specified".into(),
             "Only one registry key can be specified".into(),
@@ -208,7 +163,7 @@ fn get_reg_key(reg_params: RegistryQueryArgs, call_span: Span) -> Result<RegKey,
             Vec::new(),
         ));
     }
-    Ok(registry_key)
+    Ok(hive)
 }
 
 fn clean_string(string: &str) -> String {
@@ -233,17 +188,11 @@ fn reg_value_to_nu_value(
         ),
         REG_BINARY => (Value::binary(reg_value.bytes, call_span), reg_value.vtype),
         REG_DWORD => (
-            Value::int(
-                unsafe { *(reg_value.bytes.as_ptr() as *const u32) } as i64,
-                call_span,
-            ),
+            dword_to_nu_value(&reg_value.bytes, u32::from_le_bytes, call_span),
             reg_value.vtype,
         ),
         REG_DWORD_BIG_ENDIAN => (
-            Value::int(
-                unsafe { *(reg_value.bytes.as_ptr() as *const u32) } as i64,
-                call_span,
-            ),
+            dword_to_nu_value(&reg_value.bytes, u32::from_be_bytes, call_span),
             reg_value.vtype,
         ),
         REG_LINK => (
@@ -251,7 +200,7 @@ fn reg_value_to_nu_value(
             reg_value.vtype,
         ),
         REG_MULTI_SZ => (
-            Value::string(clean_string(&reg_value.to_string()), call_span),
+            multi_sz_to_nu_value(&reg_value.bytes, call_span),
             reg_value.vtype,
         ),
         REG_RESOURCE_LIST => (
@@ -267,11 +216,34 @@ fn reg_value_to_nu_value(
             reg_value.vtype,
         ),
         REG_QWORD => (
-            Value::int(
-                unsafe { *(reg_value.bytes.as_ptr() as *const u32) } as i64,
-                call_span,
-            ),
+            match reg_value.bytes.get(..8).and_then(|b| b.try_into().ok()) {
+                Some(bytes) => Value::int(u64::from_le_bytes(bytes) as i64, call_span),
+                None => Value::nothing(call_span),
+            },
             reg_value.vtype,
         ),
     }
 }
+
+fn dword_to_nu_value(bytes: &[u8], decode: fn([u8; 4]) -> u32, call_span: Span) -> Value {
+    match bytes.get(..4).and_then(|b| b.try_into().ok()) {
+        Some(bytes) => Value::int(decode(bytes) as i64, call_span),
+        None => Value::nothing(call_span),
+    }
+}
+
+// a REG_MULTI_SZ value is a sequence of NUL-terminated UTF-16 strings
+fn multi_sz_to_nu_value(bytes: &[u8], call_span: Span) -> Value {
+    let wide: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    Value::List {
+        vals: wide
+            .split(|c| *c == 0)
+            .filter(|s| !s.is_empty())
+            .map(|s| Value::string(String::from_utf16_lossy(s), call_span))
+            .collect(),
+        span: call_span,
+    }
+}
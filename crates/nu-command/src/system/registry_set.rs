@@ -0,0 +1,169 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Type, Value,
+};
+use winreg::{enums::*, RegKey, RegValue};
+
+use super::registry_query::requested_hive;
+
+#[derive(Clone)]
+pub struct RegistrySet;
+
+impl Command for RegistrySet {
+    fn name(&self) -> &str {
+        "registry set"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("registry set")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .switch("hkcr", "write to the hkey_classes_root hive", None)
+            .switch("hkcu", "write to the hkey_current_user hive", None)
+            .switch("hklm", "write to the hkey_local_machine hive", None)
+            .switch("hku", "write to the hkey_users hive", None)
+            .switch("hkpd", "write to the hkey_performance_data hive", None)
+            .switch("hkpt", "write to the hkey_performance_text hive", None)
+            .switch("hkpnls", "write to the hkey_performance_nls_text hive", None)
+            .switch("hkcc", "write to the hkey_current_config hive", None)
+            .switch("hkdd", "write to the hkey_dyn_data hive", None)
+            .switch(
+                "hkculs",
+                "write to the hkey_current_user_local_settings hive",
+                None,
+            )
+            .required("key", SyntaxShape::String, "registry key to write to")
+            .required("name", SyntaxShape::String, "registry value to write")
+            .required("value", SyntaxShape::Any, "the data to store")
+            .named(
+                "type",
+                SyntaxShape::String,
+                "the registry type to store the data as: 'sz', 'expand-sz', 'multi-sz', 'dword', 'qword', or 'binary' (inferred from the data by default)",
+                Some('t'),
+            )
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Write a value into the Windows registry."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The key is created when it does not exist yet. Currently supported only on Windows systems."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let call_span = call.head;
+
+        let registry_key: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let value_name: Spanned<String> = call.req(engine_state, stack, 1)?;
+        let data: Value = call.req(engine_state, stack, 2)?;
+        let reg_type: Option<Spanned<String>> = call.get_flag(engine_state, stack, "type")?;
+
+        let reg_value = data_to_reg_value(data, reg_type)?;
+
+        let hive = requested_hive(call, call_span)?;
+        let (reg_key, _disposition) = RegKey::predef(hive).create_subkey(registry_key.item)?;
+        reg_key.set_raw_value(value_name.item, &reg_value)?;
+
+        Ok(Value::nothing(call_span).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Store a string value in the HKEY_CURRENT_USER hive",
+                example: "registry set --hkcu environment MY_VAR 'some value'",
+                result: None,
+            },
+            Example {
+                description: "Store a number as a REG_DWORD",
+                example: "registry set --hkcu environment retries 3 -t dword",
+                result: None,
+            },
+            Example {
+                description: "Store a list of strings as a REG_MULTI_SZ",
+                example: r"registry set --hkcu 'Software\MyApp' paths ['C:\one' 'C:\two']",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn data_to_reg_value(
+    data: Value,
+    reg_type: Option<Spanned<String>>,
+) -> Result<RegValue, ShellError> {
+    let data_span = data.expect_span();
+    let requested = reg_type.as_ref().map(|t| t.item.as_str());
+
+    let (bytes, vtype) = match (requested, &data) {
+        (None | Some("sz"), Value::String { val, .. }) => (string_bytes(val), REG_SZ),
+        (Some("expand-sz"), Value::String { val, .. }) => (string_bytes(val), REG_EXPAND_SZ),
+        (None | Some("multi-sz"), Value::List { vals, .. }) => {
+            let mut bytes = vec![];
+            for val in vals {
+                bytes.extend(string_bytes(&val.as_string()?));
+            }
+            bytes.extend([0, 0]);
+            (bytes, REG_MULTI_SZ)
+        }
+        (None | Some("dword"), Value::Int { val, .. }) => {
+            let val = u32::try_from(*val).map_err(|_| {
+                ShellError::GenericError(
+                    "Value out of range".into(),
+                    format!("{val} does not fit in a REG_DWORD"),
+                    Some(data_span),
+                    Some("store it as a REG_QWORD with '-t qword'".into()),
+                    Vec::new(),
+                )
+            })?;
+            (val.to_le_bytes().to_vec(), REG_DWORD)
+        }
+        (Some("qword"), Value::Int { val, .. }) => {
+            ((*val as u64).to_le_bytes().to_vec(), REG_QWORD)
+        }
+        (None | Some("binary"), Value::Binary { val, .. }) => (val.clone(), REG_BINARY),
+        (Some(requested), _)
+            if !matches!(
+                requested,
+                "sz" | "expand-sz" | "multi-sz" | "dword" | "qword" | "binary"
+            ) =>
+        {
+            return Err(ShellError::TypeMismatch {
+                err_message: format!("unknown registry type '{requested}'"),
+                span: reg_type.expect("type was given").span,
+            })
+        }
+        (requested, data) => {
+            return Err(ShellError::TypeMismatch {
+                err_message: match requested {
+                    Some(requested) => {
+                        format!("{} cannot be stored as '{requested}'", data.get_type())
+                    }
+                    None => format!("{} cannot be stored in the registry", data.get_type()),
+                },
+                span: data_span,
+            })
+        }
+    };
+
+    Ok(RegValue { bytes, vtype })
+}
+
+// registry strings are NUL-terminated UTF-16
+fn string_bytes(string: &str) -> Vec<u8> {
+    string
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .flat_map(u16::to_le_bytes)
+        .collect()
+}
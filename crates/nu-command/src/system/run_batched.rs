@@ -0,0 +1,285 @@
+use super::run_external::ExternalCommand;
+use nu_engine::env_to_strings;
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+use std::thread;
+
+// A conservative cap on the total number of argument bytes passed to a single
+// invocation. The real limit (ARG_MAX on unix, 32767 UTF-16 chars on Windows)
+// varies per platform and also has to fit the environment, so stay well below it.
+const MAX_ARG_BYTES: usize = 128 * 1024;
+
+#[derive(Clone)]
+pub struct RunBatched;
+
+impl Command for RunBatched {
+    fn name(&self) -> &str {
+        "run-batched"
+    }
+
+    fn usage(&self) -> &str {
+        "Run an external command over the input values in batches, like xargs."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The input values are converted to strings and appended to the external's arguments in batches sized to stay within the operating system's argument length limits. With --placeholder, the batch replaces the matching argument instead of being appended."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(
+                Type::List(Box::new(Type::Any)),
+                Type::List(Box::new(Type::String)),
+            )])
+            .named(
+                "batch-size",
+                SyntaxShape::Int,
+                "maximum number of input values per invocation",
+                Some('n'),
+            )
+            .named(
+                "max-procs",
+                SyntaxShape::Int,
+                "number of invocations to run in parallel (default 1)",
+                Some('P'),
+            )
+            .named(
+                "placeholder",
+                SyntaxShape::String,
+                "argument to replace with the batch (like xargs -I)",
+                Some('I'),
+            )
+            .required("command", SyntaxShape::String, "external command to run")
+            .rest(
+                "args",
+                SyntaxShape::Any,
+                "arguments for the external command",
+            )
+            .category(Category::System)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["xargs", "chunk", "parallel", "external"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let args: Vec<Value> = call.rest(engine_state, stack, 1)?;
+        let batch_size: Option<usize> = call
+            .get_flag::<i64>(engine_state, stack, "batch-size")?
+            .map(|size| size.max(1) as usize);
+        let max_procs: usize = call
+            .get_flag::<i64>(engine_state, stack, "max-procs")?
+            .map(|procs| procs.max(1) as usize)
+            .unwrap_or(1);
+        let placeholder: Option<String> = call.get_flag(engine_state, stack, "placeholder")?;
+
+        let mut fixed_args = vec![];
+        for arg in args {
+            let span = arg.span()?;
+            let item = arg.as_string().map_err(|_| ShellError::ExternalCommand {
+                label: format!("Cannot convert {} to a string", arg.get_type()),
+                help: "All arguments to an external command need to be string-compatible".into(),
+                span,
+            })?;
+            fixed_args.push(Spanned { item, span });
+        }
+
+        if let Some(placeholder) = &placeholder {
+            if !fixed_args.iter().any(|arg| &arg.item == placeholder) {
+                return Err(ShellError::GenericError(
+                    "Placeholder not found in arguments".into(),
+                    format!("none of the arguments is '{placeholder}'"),
+                    Some(head),
+                    None,
+                    vec![],
+                ));
+            }
+        }
+
+        let mut items = vec![];
+        for value in input.into_iter() {
+            let span = value.span().unwrap_or(head);
+            match value.as_string() {
+                Ok(item) => items.push(item),
+                Err(_) => {
+                    return Err(ShellError::PipelineMismatch {
+                        exp_input_type: "string-compatible values".into(),
+                        dst_span: head,
+                        src_span: span,
+                    })
+                }
+            }
+        }
+
+        let env_vars = env_to_strings(engine_state, stack)?;
+        let fixed_bytes: usize = name.item.len()
+            + fixed_args
+                .iter()
+                .map(|arg| arg.item.len() + 1)
+                .sum::<usize>();
+
+        let mut batches: Vec<Vec<String>> = vec![];
+        let mut batch: Vec<String> = vec![];
+        let mut batch_bytes = fixed_bytes;
+        for item in items {
+            let item_bytes = item.len() + 1;
+            let full = !batch.is_empty()
+                && (batch_bytes + item_bytes > MAX_ARG_BYTES
+                    || batch_size.map_or(false, |size| batch.len() >= size));
+            if full {
+                batches.push(std::mem::take(&mut batch));
+                batch_bytes = fixed_bytes;
+            }
+            batch_bytes += item_bytes;
+            batch.push(item);
+        }
+        if !batch.is_empty() {
+            batches.push(batch);
+        }
+
+        let commands: Vec<ExternalCommand> = batches
+            .into_iter()
+            .map(|batch| {
+                let batch: Vec<Spanned<String>> = batch
+                    .into_iter()
+                    .map(|item| Spanned { item, span: head })
+                    .collect();
+
+                let mut args = vec![];
+                match &placeholder {
+                    Some(placeholder) => {
+                        for arg in &fixed_args {
+                            if &arg.item == placeholder {
+                                args.extend(batch.iter().cloned());
+                            } else {
+                                args.push(arg.clone());
+                            }
+                        }
+                    }
+                    None => {
+                        args.extend(fixed_args.iter().cloned());
+                        args.extend(batch);
+                    }
+                }
+
+                let arg_keep_raw = vec![true; args.len()];
+                ExternalCommand {
+                    name: name.clone(),
+                    args,
+                    arg_keep_raw,
+                    redirect_stdout: true,
+                    redirect_stderr: false,
+                    env_vars: env_vars.clone(),
+                    trim_end_newline: true,
+                    pty: false,
+                }
+            })
+            .collect();
+
+        let mut outputs = vec![];
+        for group in commands.chunks(max_procs) {
+            let results = thread::scope(|scope| {
+                let handles: Vec<_> = group
+                    .iter()
+                    .map(|command| {
+                        let mut stack = stack.clone();
+                        scope.spawn(move || run_batch(engine_state, &mut stack, command, head))
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle.join().unwrap_or_else(|_| {
+                            Err(ShellError::ExternalCommand {
+                                label: "external command thread panicked".into(),
+                                help: "".into(),
+                                span: head,
+                            })
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            });
+
+            for result in results {
+                outputs.push(result?);
+            }
+        }
+
+        Ok(outputs.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Remove files in batches instead of one `rm` per file",
+                example: r#"ls *.tmp | get name | run-batched rm"#,
+                result: None,
+            },
+            Example {
+                description: "Run four greps in parallel, substituting the batch for '{}'",
+                example: r#"open files.txt | lines | run-batched -P 4 -I '{}' grep "TODO" '{}'"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn run_batch(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    command: &ExternalCommand,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let output = command.run_with_input(engine_state, stack, PipelineData::empty(), false)?;
+
+    match output {
+        PipelineData::ExternalStream {
+            stdout, exit_code, ..
+        } => {
+            let mut stdout = match stdout {
+                Some(stream) => stream.into_string()?.item,
+                None => String::new(),
+            };
+
+            // ExternalStream's trim_end_newline is applied when it's collected into a
+            // value; do the same here since the stream is consumed directly.
+            if stdout.ends_with('\n') {
+                stdout.pop();
+                if stdout.ends_with('\r') {
+                    stdout.pop();
+                }
+            }
+
+            if let Some(exit_code) = exit_code {
+                if let Some(Value::Int { val, .. }) = exit_code.into_iter().last() {
+                    if val != 0 {
+                        return Err(ShellError::ExternalCommand {
+                            label: format!("External command failed with exit code {val}"),
+                            help: "one of the batched invocations failed".into(),
+                            span,
+                        });
+                    }
+                }
+            }
+
+            Ok(Value::string(stdout, span))
+        }
+        output => {
+            let config = engine_state.get_config();
+            Ok(Value::string(output.collect_string("", config)?, span))
+        }
+    }
+}
@@ -42,6 +42,11 @@ impl Command for External {
             .switch("redirect-stdout", "redirect stdout to the pipeline", None)
             .switch("redirect-stderr", "redirect stderr to the pipeline", None)
             .switch("trim-end-newline", "trimming end newlines", None)
+            .switch(
+                "pty",
+                "run the command in a pseudo-terminal while capturing its output (unix only)",
+                None,
+            )
             .required("command", SyntaxShape::Any, "external command to run")
             .rest("args", SyntaxShape::Any, "arguments for external command")
             .category(Category::System)
@@ -57,8 +62,20 @@ impl Command for External {
         let redirect_stdout = call.has_flag("redirect-stdout");
         let redirect_stderr = call.has_flag("redirect-stderr");
         let trim_end_newline = call.has_flag("trim-end-newline");
+        let pty = call.has_flag("pty");
 
-        let command = create_external_command(
+        #[cfg(not(unix))]
+        if pty {
+            return Err(ShellError::GenericError(
+                "--pty is not supported on this platform".into(),
+                "only unix-like platforms can allocate a pseudo-terminal".into(),
+                Some(call.head),
+                None,
+                vec![],
+            ));
+        }
+
+        let mut command = create_external_command(
             engine_state,
             stack,
             call,
@@ -66,6 +83,7 @@ impl Command for External {
             redirect_stderr,
             trim_end_newline,
         )?;
+        command.pty = pty;
 
         command.run_with_input(engine_state, stack, input, false)
     }
@@ -151,6 +169,7 @@ pub fn create_external_command(
         redirect_stderr,
         env_vars: env_vars_str,
         trim_end_newline,
+        pty: false,
     })
 }
 
@@ -163,6 +182,7 @@ pub struct ExternalCommand {
     pub redirect_stderr: bool,
     pub env_vars: HashMap<String, String>,
     pub trim_end_newline: bool,
+    pub pty: bool,
 }
 
 impl ExternalCommand {
@@ -175,6 +195,11 @@ impl ExternalCommand {
     ) -> Result<PipelineData, ShellError> {
         let head = self.name.span;
 
+        #[cfg(unix)]
+        if self.pty {
+            return self.run_with_pty(engine_state, stack, input, head);
+        }
+
         let ctrlc = engine_state.ctrlc.clone();
 
         let mut fg_process = ForegroundProcess::new(
@@ -543,6 +568,158 @@ impl ExternalCommand {
         }
     }
 
+    /// Run the external inside a pseudo-terminal, so that tools which check
+    /// `isatty` (colors, progress bars, pagers) behave as if run interactively,
+    /// while their output is still captured into the pipeline.
+    #[cfg(unix)]
+    fn run_with_pty(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        input: PipelineData,
+        head: Span,
+    ) -> Result<PipelineData, ShellError> {
+        use std::fs::File;
+        use std::os::unix::io::FromRawFd;
+        use std::os::unix::process::CommandExt;
+
+        let ctrlc = engine_state.ctrlc.clone();
+        let span = self.name.span;
+
+        let pty = nix::pty::openpty(None, None).map_err(|err| ShellError::ExternalCommand {
+            label: "Failed to open a pseudo-terminal".into(),
+            help: err.to_string(),
+            span,
+        })?;
+
+        let mut process = self.create_process(&input, false, head)?;
+
+        // The child gets the slave end as stdin/stdout/stderr and adopts it as its
+        // controlling terminal, so it is indistinguishable from an interactive run.
+        process.stdin(unsafe { Stdio::from_raw_fd(libc::dup(pty.slave)) });
+        process.stdout(unsafe { Stdio::from_raw_fd(libc::dup(pty.slave)) });
+        process.stderr(unsafe { Stdio::from_raw_fd(libc::dup(pty.slave)) });
+
+        unsafe {
+            process.pre_exec(|| {
+                if libc::setsid() < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                // The slave end was dup'd onto fd 0 by the time we exec
+                if libc::ioctl(0, libc::TIOCSCTTY as _, 0) < 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                Ok(())
+            });
+        }
+
+        let mut child = process.spawn().map_err(|err| ShellError::ExternalCommand {
+            label: "can't run executable".into(),
+            help: err.to_string(),
+            span,
+        })?;
+
+        // The slave end belongs to the child now
+        unsafe { libc::close(pty.slave) };
+
+        if !input.is_nothing() {
+            let mut engine_state = engine_state.clone();
+            let mut stack = stack.clone();
+            engine_state.config.use_ansi_coloring = false;
+
+            let mut master_write = unsafe { File::from_raw_fd(libc::dup(pty.master)) };
+            thread::Builder::new()
+                .name("external pty stdin worker".to_string())
+                .spawn(move || {
+                    let input = crate::Table::run(
+                        &crate::Table,
+                        &engine_state,
+                        &mut stack,
+                        &Call::new(head),
+                        input,
+                    );
+
+                    if let Ok(input) = input {
+                        for value in input.into_iter() {
+                            let buf = match value {
+                                Value::String { val, .. } => val.into_bytes(),
+                                Value::Binary { val, .. } => val,
+                                _ => return Err(()),
+                            };
+                            if master_write.write(&buf).is_err() {
+                                return Ok(());
+                            }
+                        }
+                    }
+
+                    Ok(())
+                })
+                .expect("Failed to create thread");
+        }
+
+        let (stdout_tx, stdout_rx) = mpsc::sync_channel(OUTPUT_BUFFERS_IN_FLIGHT);
+        let (exit_code_tx, exit_code_rx) = mpsc::channel();
+        let mut master_read = unsafe { File::from_raw_fd(pty.master) };
+        let read_ctrlc = ctrlc.clone();
+
+        thread::Builder::new()
+            .name("pty redirector + exit code waiter".to_string())
+            .spawn(move || {
+                let mut buf = [0u8; OUTPUT_BUFFER_SIZE];
+                loop {
+                    if nu_utils::ctrl_c::was_pressed(&read_ctrlc) {
+                        break;
+                    }
+                    match master_read.read(&mut buf) {
+                        Ok(0) => break,
+                        Ok(bytes_read) => {
+                            if stdout_tx.send(buf[..bytes_read].to_vec()).is_err() {
+                                break;
+                            }
+                        }
+                        // EIO here means the child closed the slave end: treat it as EOF
+                        Err(_) => break,
+                    }
+                }
+
+                match child.wait() {
+                    Err(err) => Err(ShellError::ExternalCommand {
+                        label: "External command exited with error".into(),
+                        help: err.to_string(),
+                        span,
+                    }),
+                    Ok(x) => {
+                        if let Some(code) = x.code() {
+                            let _ = exit_code_tx.send(Value::int(code as i64, head));
+                        } else if x.success() {
+                            let _ = exit_code_tx.send(Value::int(0, head));
+                        } else {
+                            let _ = exit_code_tx.send(Value::int(-1, head));
+                        }
+                        Ok(())
+                    }
+                }
+            })
+            .expect("Failed to create thread");
+
+        let stdout_receiver = ChannelReceiver::new(stdout_rx);
+        let exit_code_receiver = ValueReceiver::new(exit_code_rx);
+
+        Ok(PipelineData::ExternalStream {
+            stdout: Some(RawStream::new(
+                Box::new(stdout_receiver),
+                ctrlc.clone(),
+                head,
+                None,
+            )),
+            stderr: None,
+            exit_code: Some(ListStream::from_stream(Box::new(exit_code_receiver), ctrlc)),
+            span: head,
+            metadata: None,
+            trim_end_newline: self.trim_end_newline,
+        })
+    }
+
     fn create_process(
         &self,
         input: &PipelineData,
@@ -0,0 +1,381 @@
+use std::process::Command as CommandSys;
+
+use nu_engine::{get_full_help, CallExt};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, Spanned, SyntaxShape, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct Service;
+
+impl Command for Service {
+    fn name(&self) -> &str {
+        "service"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service")
+            .category(Category::System)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn usage(&self) -> &str {
+        "List and control the services of the system."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The services are managed through systemd, launchd, or the Windows service
+manager, depending on the platform.
+
+You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &Self.signature(),
+                &Self.examples(),
+                engine_state,
+                stack,
+                self.is_parser_keyword(),
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct ServiceList;
+
+impl Command for ServiceList {
+    fn name(&self) -> &str {
+        "service list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service list")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "List the services of the system with their status."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let services = list_services(call.head)?;
+        Ok(services.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show the services that are not running",
+            example: "service list | where status != running",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct ServiceStart;
+
+impl Command for ServiceStart {
+    fn name(&self) -> &str {
+        "service start"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service start")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("name", SyntaxShape::String, "the service to start")
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Start a service."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        control_service("start", &name)?;
+        Ok(Value::nothing(call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Start a service by name",
+            example: "service start sshd",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct ServiceStop;
+
+impl Command for ServiceStop {
+    fn name(&self) -> &str {
+        "service stop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service stop")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("name", SyntaxShape::String, "the service to stop")
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Stop a service."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        control_service("stop", &name)?;
+        Ok(Value::nothing(call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Stop a service by name",
+            example: "service stop sshd",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct ServiceRestart;
+
+impl Command for ServiceRestart {
+    fn name(&self) -> &str {
+        "service restart"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service restart")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("name", SyntaxShape::String, "the service to restart")
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Restart a service."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        control_service("restart", &name)?;
+        Ok(Value::nothing(call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Restart a service by name",
+            example: "service restart sshd",
+            result: None,
+        }]
+    }
+}
+
+fn service_record(name: &str, status: &str, description: Value, span: Span) -> Value {
+    Value::record(
+        vec!["name".into(), "status".into(), "description".into()],
+        vec![
+            Value::string(name, span),
+            Value::string(status, span),
+            description,
+        ],
+        span,
+    )
+}
+
+fn run_manager(manager: &str, args: &[&str], span: Span) -> Result<String, ShellError> {
+    let output = CommandSys::new(manager)
+        .args(args)
+        .output()
+        .map_err(|err| {
+            ShellError::GenericError(
+                format!("Failed to start {manager}"),
+                err.to_string(),
+                Some(span),
+                Some("the service commands manage services through it".into()),
+                Vec::new(),
+            )
+        })?;
+    if !output.status.success() {
+        return Err(ShellError::GenericError(
+            format!("{manager} failed"),
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            Some(span),
+            None,
+            Vec::new(),
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn list_services(span: Span) -> Result<Vec<Value>, ShellError> {
+    let output = run_manager(
+        "systemctl",
+        &[
+            "list-units",
+            "--type=service",
+            "--all",
+            "--no-legend",
+            "--no-pager",
+            "--plain",
+        ],
+        span,
+    )?;
+
+    Ok(output
+        .lines()
+        .filter_map(|line| {
+            // UNIT LOAD ACTIVE SUB DESCRIPTION
+            let mut parts = line.split_whitespace();
+            let unit = parts.next()?;
+            let _load = parts.next()?;
+            let _active = parts.next()?;
+            let sub = parts.next()?;
+            let description = parts.collect::<Vec<_>>().join(" ");
+            Some(service_record(
+                unit.trim_end_matches(".service"),
+                sub,
+                Value::string(description, span),
+                span,
+            ))
+        })
+        .collect())
+}
+
+#[cfg(target_os = "macos")]
+fn list_services(span: Span) -> Result<Vec<Value>, ShellError> {
+    let output = run_manager("launchctl", &["list"], span)?;
+
+    Ok(output
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            // PID STATUS LABEL
+            let mut parts = line.splitn(3, '\t');
+            let pid = parts.next()?;
+            let _status = parts.next()?;
+            let label = parts.next()?;
+            let status = if pid == "-" { "stopped" } else { "running" };
+            Some(service_record(label, status, Value::nothing(span), span))
+        })
+        .collect())
+}
+
+#[cfg(windows)]
+fn list_services(span: Span) -> Result<Vec<Value>, ShellError> {
+    let output = run_manager(
+        "sc.exe",
+        &["query", "type=", "service", "state=", "all"],
+        span,
+    )?;
+
+    let mut services = vec![];
+    let mut name: Option<String> = None;
+    let mut display_name = Value::nothing(span);
+    for line in output.lines() {
+        if let Some(value) = line.trim().strip_prefix("SERVICE_NAME:") {
+            name = Some(value.trim().to_string());
+            display_name = Value::nothing(span);
+        } else if let Some(value) = line.trim().strip_prefix("DISPLAY_NAME:") {
+            display_name = Value::string(value.trim(), span);
+        } else if let Some(value) = line.trim().strip_prefix("STATE") {
+            // 'STATE : 4 RUNNING'
+            let status = value
+                .rsplit_once(' ')
+                .map(|(_, status)| status.to_lowercase())
+                .unwrap_or_default();
+            if let Some(name) = name.take() {
+                services.push(service_record(
+                    &name,
+                    &status,
+                    std::mem::replace(&mut display_name, Value::nothing(span)),
+                    span,
+                ));
+            }
+        }
+    }
+    Ok(services)
+}
+
+#[cfg(target_os = "linux")]
+fn control_service(action: &str, name: &Spanned<String>) -> Result<(), ShellError> {
+    run_manager("systemctl", &[action, &name.item], name.span)?;
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn control_service(action: &str, name: &Spanned<String>) -> Result<(), ShellError> {
+    if action == "restart" {
+        run_manager("launchctl", &["stop", &name.item], name.span)?;
+        run_manager("launchctl", &["start", &name.item], name.span)?;
+    } else {
+        run_manager("launchctl", &[action, &name.item], name.span)?;
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn control_service(action: &str, name: &Spanned<String>) -> Result<(), ShellError> {
+    if action == "restart" {
+        run_manager("sc.exe", &["stop", &name.item], name.span)?;
+        run_manager("sc.exe", &["start", &name.item], name.span)?;
+    } else {
+        run_manager("sc.exe", &[action, &name.item], name.span)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(ServiceList {})
+    }
+}
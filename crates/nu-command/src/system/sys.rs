@@ -7,6 +7,7 @@ use nu_protocol::{
     Type, Value,
 };
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use std::time::{Duration, UNIX_EPOCH};
 use sysinfo::{
     ComponentExt, CpuExt, CpuRefreshKind, DiskExt, NetworkExt, System, SystemExt, UserExt,
@@ -74,8 +75,12 @@ pub struct SysResult {
 }
 
 impl LazyRecord for SysResult {
-    fn column_names(&self) -> Vec<&'static str> {
-        vec!["host", "cpu", "disks", "mem", "temp", "net"]
+    fn column_names(&self) -> Vec<String> {
+        #[allow(unused_mut)]
+        let mut columns = vec!["host", "cpu", "disks", "mem", "temp", "net"];
+        #[cfg(target_os = "linux")]
+        columns.extend(["routes", "connections", "hardware", "power"]);
+        columns.into_iter().map(String::from).collect()
     }
 
     fn get_column_value(&self, column: &str) -> Result<Value, ShellError> {
@@ -88,6 +93,14 @@ impl LazyRecord for SysResult {
             "mem" => Ok(mem(span)),
             "temp" => Ok(temp(span)),
             "net" => Ok(net(span)),
+            #[cfg(target_os = "linux")]
+            "routes" => Ok(routes(span)),
+            #[cfg(target_os = "linux")]
+            "connections" => Ok(connections(span)),
+            #[cfg(target_os = "linux")]
+            "hardware" => Ok(hardware(span)),
+            #[cfg(target_os = "linux")]
+            "power" => Ok(power(span)),
             _ => Err(ShellError::LazyRecordAccessFailed {
                 message: format!("Could not find column '{column}'"),
                 column_name: column.to_string(),
@@ -165,7 +178,11 @@ pub fn disks(span: Span) -> Value {
             span,
         });
 
-        output.push(Value::Record { cols, vals, span });
+        output.push(Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        });
     }
     Value::List { vals: output, span }
 }
@@ -175,17 +192,39 @@ pub fn net(span: Span) -> Value {
     sys.refresh_networks();
     sys.refresh_networks_list();
 
+    #[cfg(unix)]
+    let mut interfaces = interface_details(span);
+
     let mut output = vec![];
     for (iface, data) in sys.networks() {
         let mut cols = vec![];
         let mut vals = vec![];
 
+        let name = trim_cstyle_null(iface.to_string());
+
+        #[cfg(unix)]
+        let (state, addresses) = interfaces
+            .remove(&name)
+            .unwrap_or_else(|| ("unknown".to_string(), Value::List { vals: vec![], span }));
+
         cols.push("name".into());
+        vals.push(Value::String { val: name, span });
+
+        cols.push("mac".into());
         vals.push(Value::String {
-            val: trim_cstyle_null(iface.to_string()),
+            val: data.mac_address().to_string(),
             span,
         });
 
+        #[cfg(unix)]
+        {
+            cols.push("state".into());
+            vals.push(Value::String { val: state, span });
+
+            cols.push("addresses".into());
+            vals.push(addresses);
+        }
+
         cols.push("sent".into());
         vals.push(Value::Filesize {
             val: data.total_transmitted() as i64,
@@ -198,11 +237,543 @@ pub fn net(span: Span) -> Value {
             span,
         });
 
-        output.push(Value::Record { cols, vals, span });
+        cols.push("packets_sent".into());
+        vals.push(Value::Int {
+            val: data.total_packets_transmitted() as i64,
+            span,
+        });
+
+        cols.push("packets_recv".into());
+        vals.push(Value::Int {
+            val: data.total_packets_received() as i64,
+            span,
+        });
+
+        cols.push("errors_sent".into());
+        vals.push(Value::Int {
+            val: data.total_errors_on_transmitted() as i64,
+            span,
+        });
+
+        cols.push("errors_recv".into());
+        vals.push(Value::Int {
+            val: data.total_errors_on_received() as i64,
+            span,
+        });
+
+        output.push(Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        });
+    }
+    Value::List { vals: output, span }
+}
+
+// the link state and the addresses of every interface, keyed by its name
+#[cfg(unix)]
+fn interface_details(span: Span) -> std::collections::HashMap<String, (String, Value)> {
+    let mut interfaces: std::collections::HashMap<String, (String, Vec<Value>)> =
+        std::collections::HashMap::new();
+
+    let mut addrs: *mut libc::ifaddrs = std::ptr::null_mut();
+    // SAFETY: getifaddrs fills in the list on success and freeifaddrs releases it below
+    if unsafe { libc::getifaddrs(&mut addrs) } != 0 {
+        return std::collections::HashMap::new();
+    }
+    let mut current = addrs;
+    while !current.is_null() {
+        // SAFETY: current points into the list allocated by getifaddrs
+        let ifaddr = unsafe { &*current };
+        current = ifaddr.ifa_next;
+
+        // SAFETY: ifa_name is a NUL-terminated interface name
+        let name = unsafe { std::ffi::CStr::from_ptr(ifaddr.ifa_name) }
+            .to_string_lossy()
+            .to_string();
+        let state = if ifaddr.ifa_flags & libc::IFF_RUNNING as libc::c_uint != 0 {
+            "up"
+        } else {
+            "down"
+        };
+        let addresses = &mut interfaces
+            .entry(name)
+            .or_insert_with(|| (state.to_string(), vec![]))
+            .1;
+
+        // getifaddrs also yields link-level entries without an inet address
+        let Some(address) = sockaddr_string(ifaddr.ifa_addr) else {
+            continue;
+        };
+        let netmask = sockaddr_string(ifaddr.ifa_netmask)
+            .map(|val| Value::String { val, span })
+            .unwrap_or_else(|| Value::nothing(span));
+        addresses.push(Value::Record {
+            cols: Arc::new(vec!["address".into(), "netmask".into()]),
+            vals: vec![Value::String { val: address, span }, netmask],
+            span,
+        });
+    }
+    // SAFETY: addrs came from getifaddrs and nothing borrows the list anymore
+    unsafe { libc::freeifaddrs(addrs) };
+
+    interfaces
+        .into_iter()
+        .map(|(name, (state, vals))| (name, (state, Value::List { vals, span })))
+        .collect()
+}
+
+#[cfg(unix)]
+fn sockaddr_string(addr: *const libc::sockaddr) -> Option<String> {
+    if addr.is_null() {
+        return None;
+    }
+    // SAFETY: sa_family tells which concrete sockaddr type getifaddrs stored here
+    unsafe {
+        match (*addr).sa_family as libc::c_int {
+            libc::AF_INET => {
+                let addr = &*(addr as *const libc::sockaddr_in);
+                Some(std::net::Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr)).to_string())
+            }
+            libc::AF_INET6 => {
+                let addr = &*(addr as *const libc::sockaddr_in6);
+                Some(std::net::Ipv6Addr::from(addr.sin6_addr.s6_addr).to_string())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn routes(span: Span) -> Value {
+    let mut output = vec![];
+    if let Ok(routes) = procfs::net::route() {
+        for route in routes {
+            let mut cols = vec![];
+            let mut vals = vec![];
+
+            cols.push("destination".into());
+            vals.push(Value::String {
+                val: route.destination.to_string(),
+                span,
+            });
+
+            cols.push("mask".into());
+            vals.push(Value::String {
+                val: route.mask.to_string(),
+                span,
+            });
+
+            cols.push("gateway".into());
+            vals.push(Value::String {
+                val: route.gateway.to_string(),
+                span,
+            });
+
+            cols.push("metric".into());
+            vals.push(Value::Int {
+                val: route.metrics as i64,
+                span,
+            });
+
+            cols.push("interface".into());
+            vals.push(Value::String {
+                val: route.iface,
+                span,
+            });
+
+            output.push(Value::Record {
+                cols: Arc::new(cols),
+                vals,
+                span,
+            });
+        }
+    }
+    Value::List { vals: output, span }
+}
+
+#[cfg(target_os = "linux")]
+pub fn connections(span: Span) -> Value {
+    let owners = socket_owners();
+
+    let mut output = vec![];
+    for (protocol, entries) in [("tcp", procfs::net::tcp()), ("tcp6", procfs::net::tcp6())] {
+        for entry in entries.unwrap_or_default() {
+            output.push(connection_record(
+                protocol,
+                entry.local_address,
+                entry.remote_address,
+                format!("{:?}", entry.state).to_lowercase(),
+                entry.inode,
+                &owners,
+                span,
+            ));
+        }
+    }
+    for (protocol, entries) in [("udp", procfs::net::udp()), ("udp6", procfs::net::udp6())] {
+        for entry in entries.unwrap_or_default() {
+            output.push(connection_record(
+                protocol,
+                entry.local_address,
+                entry.remote_address,
+                format!("{:?}", entry.state).to_lowercase(),
+                entry.inode,
+                &owners,
+                span,
+            ));
+        }
+    }
+    Value::List { vals: output, span }
+}
+
+#[cfg(target_os = "linux")]
+fn connection_record(
+    protocol: &str,
+    local: std::net::SocketAddr,
+    remote: std::net::SocketAddr,
+    state: String,
+    inode: u64,
+    owners: &std::collections::HashMap<u64, (i32, String)>,
+    span: Span,
+) -> Value {
+    let (pid, process) = match owners.get(&inode) {
+        Some((pid, name)) => (
+            Value::Int {
+                val: *pid as i64,
+                span,
+            },
+            Value::String {
+                val: name.clone(),
+                span,
+            },
+        ),
+        // the owner is only visible for sockets of the current user
+        None => (Value::nothing(span), Value::nothing(span)),
+    };
+
+    Value::Record {
+        cols: Arc::new(vec![
+            "protocol".into(),
+            "local_address".into(),
+            "remote_address".into(),
+            "state".into(),
+            "pid".into(),
+            "process".into(),
+        ]),
+        vals: vec![
+            Value::String {
+                val: protocol.into(),
+                span,
+            },
+            Value::String {
+                val: local.to_string(),
+                span,
+            },
+            Value::String {
+                val: remote.to_string(),
+                span,
+            },
+            Value::String { val: state, span },
+            pid,
+            process,
+        ],
+        span,
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn hardware(span: Span) -> Value {
+    let dmi = |name: &str| match std::fs::read_to_string(format!("/sys/class/dmi/id/{name}")) {
+        Ok(val) => Value::String {
+            val: val.trim().to_string(),
+            span,
+        },
+        Err(_) => Value::nothing(span),
+    };
+
+    let motherboard = Value::Record {
+        cols: Arc::new(vec!["vendor".into(), "name".into(), "version".into()]),
+        vals: vec![dmi("board_vendor"), dmi("board_name"), dmi("board_version")],
+        span,
+    };
+    let bios = Value::Record {
+        cols: Arc::new(vec!["vendor".into(), "version".into(), "date".into()]),
+        vals: vec![dmi("bios_vendor"), dmi("bios_version"), dmi("bios_date")],
+        span,
+    };
+    // the serial is only readable with enough permissions
+    let system = Value::Record {
+        cols: Arc::new(vec![
+            "manufacturer".into(),
+            "product".into(),
+            "serial".into(),
+        ]),
+        vals: vec![
+            dmi("sys_vendor"),
+            dmi("product_name"),
+            dmi("product_serial"),
+        ],
+        span,
+    };
+
+    Value::Record {
+        cols: Arc::new(vec![
+            "motherboard".into(),
+            "bios".into(),
+            "system".into(),
+            "memory".into(),
+            "storage".into(),
+        ]),
+        vals: vec![
+            motherboard,
+            bios,
+            system,
+            memory_modules(span),
+            storage_devices(span),
+        ],
+        span,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn storage_devices(span: Span) -> Value {
+    let mut output = vec![];
+    if let Ok(entries) = std::fs::read_dir("/sys/block") {
+        let mut paths: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+        paths.sort();
+        for path in paths {
+            // virtual devices like loop and ram have no backing device
+            if !path.join("device").exists() {
+                continue;
+            }
+            let attribute = |name: &str| match std::fs::read_to_string(path.join(name)) {
+                Ok(val) => Value::String {
+                    val: val.trim().to_string(),
+                    span,
+                },
+                Err(_) => Value::nothing(span),
+            };
+            let size = match std::fs::read_to_string(path.join("size")) {
+                // the size is counted in 512 byte sectors regardless of the block size
+                Ok(sectors) => match sectors.trim().parse::<i64>() {
+                    Ok(sectors) => Value::Filesize {
+                        val: sectors * 512,
+                        span,
+                    },
+                    Err(_) => Value::nothing(span),
+                },
+                Err(_) => Value::nothing(span),
+            };
+
+            output.push(Value::Record {
+                cols: Arc::new(vec![
+                    "name".into(),
+                    "model".into(),
+                    "serial".into(),
+                    "size".into(),
+                ]),
+                vals: vec![
+                    Value::String {
+                        val: path
+                            .file_name()
+                            .map(|name| name.to_string_lossy().to_string())
+                            .unwrap_or_default(),
+                        span,
+                    },
+                    attribute("device/model"),
+                    attribute("device/serial"),
+                    size,
+                ],
+                span,
+            });
+        }
     }
     Value::List { vals: output, span }
 }
 
+#[cfg(target_os = "linux")]
+pub fn power(span: Span) -> Value {
+    let mut batteries = vec![];
+    let mut ac_online = None;
+    let mut discharging = false;
+
+    if let Ok(entries) = std::fs::read_dir("/sys/class/power_supply") {
+        let mut paths: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+        paths.sort();
+        for path in paths {
+            let attribute = |name: &str| {
+                std::fs::read_to_string(path.join(name))
+                    .map(|value| value.trim().to_string())
+                    .ok()
+            };
+            match attribute("type").as_deref() {
+                Some("Mains") => {
+                    let online = attribute("online").as_deref() == Some("1");
+                    ac_online = Some(ac_online.unwrap_or(false) || online);
+                }
+                Some("Battery") => {
+                    let state = attribute("status")
+                        .map(|status| status.to_lowercase())
+                        .unwrap_or_else(|| "unknown".to_string());
+                    discharging = discharging || state == "discharging";
+
+                    let percentage = attribute("capacity")
+                        .and_then(|capacity| capacity.parse().ok())
+                        .map(|val| Value::Int { val, span })
+                        .unwrap_or_else(|| Value::nothing(span));
+                    // energy is reported in µWh and the drain in µW; older
+                    // drivers report µAh and µA instead, the ratio is the same
+                    let time_to_empty = match (
+                        attribute("energy_now").or_else(|| attribute("charge_now")),
+                        attribute("power_now").or_else(|| attribute("current_now")),
+                    ) {
+                        (Some(energy), Some(power)) if state == "discharging" => {
+                            match (energy.parse::<f64>(), power.parse::<f64>()) {
+                                (Ok(energy), Ok(power)) if power > 0.0 => Value::Duration {
+                                    val: (energy / power * 3600.0 * 1_000_000_000.0) as i64,
+                                    span,
+                                },
+                                _ => Value::nothing(span),
+                            }
+                        }
+                        _ => Value::nothing(span),
+                    };
+
+                    batteries.push(Value::Record {
+                        cols: Arc::new(vec![
+                            "name".into(),
+                            "percentage".into(),
+                            "state".into(),
+                            "time_to_empty".into(),
+                        ]),
+                        vals: vec![
+                            Value::String {
+                                val: path
+                                    .file_name()
+                                    .map(|name| name.to_string_lossy().to_string())
+                                    .unwrap_or_default(),
+                                span,
+                            },
+                            percentage,
+                            Value::String { val: state, span },
+                            time_to_empty,
+                        ],
+                        span,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let profile = match std::fs::read_to_string("/sys/firmware/acpi/platform_profile") {
+        Ok(profile) => Value::String {
+            val: profile.trim().to_string(),
+            span,
+        },
+        Err(_) => Value::nothing(span),
+    };
+
+    Value::Record {
+        cols: Arc::new(vec![
+            "on_battery".into(),
+            "profile".into(),
+            "batteries".into(),
+        ]),
+        vals: vec![
+            Value::Bool {
+                // without an AC adapter entry, fall back to the battery state
+                val: ac_online.map(|online| !online).unwrap_or(discharging),
+                span,
+            },
+            profile,
+            Value::List {
+                vals: batteries,
+                span,
+            },
+        ],
+        span,
+    }
+}
+
+// memory modules are only exposed through the DMI tables, which dmidecode
+// can read with enough permissions; anything short of that yields no rows
+#[cfg(target_os = "linux")]
+fn memory_modules(span: Span) -> Value {
+    let Ok(output) = std::process::Command::new("dmidecode")
+        .args(["--type", "memory"])
+        .output()
+    else {
+        return Value::List { vals: vec![], span };
+    };
+    if !output.status.success() {
+        return Value::List { vals: vec![], span };
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut modules = vec![];
+    for block in stdout.split("\n\n").filter(|b| b.contains("Memory Device")) {
+        let field = |name: &str| {
+            block
+                .lines()
+                .filter_map(|line| line.trim().split_once(": "))
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.trim().to_string())
+        };
+        // empty slots report their size as 'No Module Installed'
+        let Some(size) = field("Size").filter(|size| size.ends_with('B')) else {
+            continue;
+        };
+
+        let string_or_nothing = |val: Option<String>| match val {
+            Some(val) => Value::String { val, span },
+            None => Value::nothing(span),
+        };
+        modules.push(Value::Record {
+            cols: Arc::new(vec![
+                "size".into(),
+                "type".into(),
+                "speed".into(),
+                "manufacturer".into(),
+                "part".into(),
+                "serial".into(),
+            ]),
+            vals: vec![
+                Value::String { val: size, span },
+                string_or_nothing(field("Type")),
+                string_or_nothing(field("Speed")),
+                string_or_nothing(field("Manufacturer")),
+                string_or_nothing(field("Part Number")),
+                string_or_nothing(field("Serial Number")),
+            ],
+            span,
+        });
+    }
+    Value::List {
+        vals: modules,
+        span,
+    }
+}
+
+// maps socket inodes to the pid and name of the owning process
+#[cfg(target_os = "linux")]
+fn socket_owners() -> std::collections::HashMap<u64, (i32, String)> {
+    let mut owners = std::collections::HashMap::new();
+    let Ok(processes) = procfs::process::all_processes() else {
+        return owners;
+    };
+    for process in processes.flatten() {
+        let Ok(fds) = process.fd() else { continue };
+        let name = process.stat().map(|stat| stat.comm).unwrap_or_default();
+        for fd in fds.flatten() {
+            if let procfs::process::FDTarget::Socket(inode) = fd.target {
+                owners.insert(inode, (process.pid(), name.clone()));
+            }
+        }
+    }
+    owners
+}
+
 pub fn cpu(span: Span) -> Value {
     let mut sys = System::new();
     sys.refresh_cpu_specifics(CpuRefreshKind::everything());
@@ -261,7 +832,11 @@ pub fn cpu(span: Span) -> Value {
             span,
         });
 
-        output.push(Value::Record { cols, vals, span });
+        output.push(Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        });
     }
 
     Value::List { vals: output, span }
@@ -325,7 +900,11 @@ pub fn mem(span: Span) -> Value {
         span,
     });
 
-    Value::Record { cols, vals, span }
+    Value::Record {
+        cols: Arc::new(cols),
+        vals,
+        span,
+    }
 }
 
 pub fn host(span: Span) -> Value {
@@ -414,7 +993,11 @@ pub fn host(span: Span) -> Value {
         cols.push("groups".into());
         vals.push(Value::List { vals: groups, span });
 
-        users.push(Value::Record { cols, vals, span });
+        users.push(Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        });
     }
 
     if !users.is_empty() {
@@ -422,7 +1005,11 @@ pub fn host(span: Span) -> Value {
         vals.push(Value::List { vals: users, span });
     }
 
-    Value::Record { cols, vals, span }
+    Value::Record {
+        cols: Arc::new(cols),
+        vals,
+        span,
+    }
 }
 
 pub fn temp(span: Span) -> Value {
@@ -461,7 +1048,11 @@ pub fn temp(span: Span) -> Value {
                 span,
             });
         }
-        output.push(Value::Record { cols, vals, span });
+        output.push(Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        });
     }
 
     Value::List { vals: output, span }
@@ -0,0 +1,299 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, Type, Value,
+};
+use std::ffi::CStr;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct Users;
+
+impl Command for Users {
+    fn name(&self) -> &str {
+        "users"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("users")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "List the user accounts of the system."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["passwd", "account", "uid"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(all_users(call.head).into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Find the accounts that can log into a shell",
+            example: "users | where shell !~ nologin",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct Groups;
+
+impl Command for Groups {
+    fn name(&self) -> &str {
+        "groups"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("groups")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .allow_variants_without_examples(true)
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "List the groups of the system with their members."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["gid", "members"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(all_groups(call.head).into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show the members of a group",
+            example: "groups | where name == wheel | get 0.members",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct Whoami;
+
+impl Command for Whoami {
+    fn name(&self) -> &str {
+        "whoami"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("whoami")
+            .input_output_types(vec![(Type::Nothing, Type::Any)])
+            .allow_variants_without_examples(true)
+            .switch(
+                "full",
+                "return a record with the uid, gid, home, shell, and groups as well",
+                Some('f'),
+            )
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Get the name of the current user, or the full account details."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["user", "current", "id"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        // SAFETY: geteuid cannot fail
+        let uid = unsafe { libc::geteuid() };
+        let Some(user) = all_users(head)
+            .into_iter()
+            .find(|user| matches!(user.get_data_by_key("uid"), Some(Value::Int { val, .. }) if val == uid as i64))
+        else {
+            return Err(ShellError::GenericError(
+                "Unable to find the current user".into(),
+                format!("no account with uid {uid}"),
+                Some(head),
+                None,
+                Vec::new(),
+            ));
+        };
+
+        if !call.has_flag("full") {
+            return Ok(user
+                .get_data_by_key("name")
+                .unwrap_or_else(|| Value::nothing(head))
+                .into_pipeline_data());
+        }
+
+        let name = match user.get_data_by_key("name") {
+            Some(Value::String { val, .. }) => val,
+            _ => String::new(),
+        };
+        let groups = all_groups(head)
+            .into_iter()
+            .filter(|group| match group.get_data_by_key("members") {
+                Some(Value::List { vals, .. }) => vals
+                    .iter()
+                    .any(|member| matches!(member, Value::String { val, .. } if *val == name)),
+                _ => false,
+            })
+            .filter_map(|group| group.get_data_by_key("name"))
+            .collect();
+
+        let (mut cols, mut vals) = match user {
+            Value::Record { cols, vals, .. } => (cols.as_ref().clone(), vals),
+            _ => (vec![], vec![]),
+        };
+        cols.push("groups".into());
+        vals.push(Value::List {
+            vals: groups,
+            span: head,
+        });
+
+        Ok(Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the name of the current user",
+                example: "whoami",
+                result: None,
+            },
+            Example {
+                description: "Get the home directory of the current user",
+                example: "(whoami --full).home",
+                result: None,
+            },
+        ]
+    }
+}
+
+// SAFETY: the pointer comes from a passwd or group entry and is NUL-terminated
+unsafe fn entry_string(ptr: *const libc::c_char, span: Span) -> Value {
+    if ptr.is_null() {
+        return Value::nothing(span);
+    }
+    Value::String {
+        val: CStr::from_ptr(ptr).to_string_lossy().to_string(),
+        span,
+    }
+}
+
+fn all_users(span: Span) -> Vec<Value> {
+    let cols = Arc::new(vec![
+        "name".into(),
+        "uid".into(),
+        "gid".into(),
+        "home".into(),
+        "shell".into(),
+        "description".into(),
+    ]);
+
+    let mut users = vec![];
+    // SAFETY: the entries stay valid until the next getpwent call, and we copy
+    // everything out of them before that
+    unsafe {
+        libc::setpwent();
+        loop {
+            let pw = libc::getpwent();
+            if pw.is_null() {
+                break;
+            }
+            let pw = &*pw;
+            users.push(Value::Record {
+                cols: cols.clone(),
+                vals: vec![
+                    entry_string(pw.pw_name, span),
+                    Value::Int {
+                        val: pw.pw_uid as i64,
+                        span,
+                    },
+                    Value::Int {
+                        val: pw.pw_gid as i64,
+                        span,
+                    },
+                    entry_string(pw.pw_dir, span),
+                    entry_string(pw.pw_shell, span),
+                    entry_string(pw.pw_gecos, span),
+                ],
+                span,
+            });
+        }
+        libc::endpwent();
+    }
+    users
+}
+
+fn all_groups(span: Span) -> Vec<Value> {
+    let cols = Arc::new(vec!["name".into(), "gid".into(), "members".into()]);
+
+    let mut groups = vec![];
+    // SAFETY: the entries stay valid until the next getgrent call, and we copy
+    // everything out of them before that
+    unsafe {
+        libc::setgrent();
+        loop {
+            let gr = libc::getgrent();
+            if gr.is_null() {
+                break;
+            }
+            let gr = &*gr;
+
+            let mut members = vec![];
+            let mut member = gr.gr_mem;
+            while !member.is_null() && !(*member).is_null() {
+                members.push(entry_string(*member, span));
+                member = member.add(1);
+            }
+
+            groups.push(Value::Record {
+                cols: cols.clone(),
+                vals: vec![
+                    entry_string(gr.gr_name, span),
+                    Value::Int {
+                        val: gr.gr_gid as i64,
+                        span,
+                    },
+                    Value::List {
+                        vals: members,
+                        span,
+                    },
+                ],
+                span,
+            });
+        }
+        libc::endgrent();
+    }
+    groups
+}
@@ -10,6 +10,7 @@ use nu_protocol::{
 
 use std::ffi::OsStr;
 use std::path::Path;
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct Which;
@@ -70,7 +71,11 @@ fn entry(arg: impl Into<String>, path: impl Into<String>, builtin: bool, span: S
     cols.push("built-in".to_string());
     vals.push(Value::Bool { val: builtin, span });
 
-    Value::Record { cols, vals, span }
+    Value::Record {
+        cols: Arc::new(cols),
+        vals,
+        span,
+    }
 }
 
 fn get_entry_in_commands(engine_state: &EngineState, name: &str, span: Span) -> Option<Value> {
@@ -0,0 +1,128 @@
+use nu_engine::env::current_dir;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct VcsBlame;
+
+impl Command for VcsBlame {
+    fn name(&self) -> &str {
+        "vcs blame"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("vcs blame")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .required("path", SyntaxShape::Filepath, "the file to blame")
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Show what commit last changed each line of a file, as a table."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let cwd = current_dir(engine_state, stack)?;
+        let repo = super::open_repo(&cwd, head)?;
+
+        let full_path = nu_path::expand_path_with(&path.item, &cwd);
+        let workdir = repo.workdir().ok_or_else(|| {
+            ShellError::GenericError(
+                "git error".into(),
+                "this repository has no working directory".into(),
+                Some(head),
+                None,
+                Vec::new(),
+            )
+        })?;
+        let relative = full_path.strip_prefix(workdir).map_err(|_| {
+            ShellError::GenericError(
+                "git error".into(),
+                format!("'{}' is outside this repository", path.item),
+                Some(path.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let blame = repo
+            .blame_file(relative, None)
+            .map_err(|err| super::git_error(err, path.span))?;
+        let content = std::fs::read_to_string(&full_path).map_err(|err| {
+            ShellError::GenericError(
+                "cannot read file".into(),
+                err.to_string(),
+                Some(path.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let vals = content
+            .lines()
+            .enumerate()
+            .map(|(at, text)| {
+                let line = at + 1;
+                let (commit, author, date) = match blame.get_line(line) {
+                    Some(hunk) => {
+                        let signature = hunk.final_signature();
+                        (
+                            Value::string(hunk.final_commit_id().to_string(), head),
+                            signature.name().map_or_else(
+                                || Value::nothing(head),
+                                |name| Value::string(name, head),
+                            ),
+                            super::log::commit_date(&signature, head),
+                        )
+                    }
+                    // Lines not yet committed have no hunk
+                    None => (
+                        Value::nothing(head),
+                        Value::nothing(head),
+                        Value::nothing(head),
+                    ),
+                };
+                Value::record(
+                    vec![
+                        "line".into(),
+                        "commit".into(),
+                        "author".into(),
+                        "date".into(),
+                        "text".into(),
+                    ],
+                    vec![
+                        Value::int(line as i64, head),
+                        commit,
+                        author,
+                        date,
+                        Value::string(text, head),
+                    ],
+                    head,
+                )
+            })
+            .collect();
+
+        Ok(Value::List { vals, span: head }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "vcs blame Cargo.toml | where author == 'JT'",
+            description: "Find the lines of a file last changed by one author",
+            result: None,
+        }]
+    }
+}
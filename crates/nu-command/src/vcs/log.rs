@@ -0,0 +1,100 @@
+use chrono::{DateTime, FixedOffset, TimeZone};
+use nu_engine::env::current_dir;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct VcsLog;
+
+impl Command for VcsLog {
+    fn name(&self) -> &str {
+        "vcs log"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("vcs log")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .named(
+                "max-count",
+                SyntaxShape::Int,
+                "limit the number of commits",
+                Some('n'),
+            )
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Show the commit history of the current git repository as a table."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let max_count: Option<i64> = call.get_flag(engine_state, stack, "max-count")?;
+        let max_count = max_count.map(|n| n.max(0) as usize).unwrap_or(usize::MAX);
+        let cwd = current_dir(engine_state, stack)?;
+        let repo = super::open_repo(&cwd, head)?;
+
+        let mut revwalk = repo.revwalk().map_err(|err| super::git_error(err, head))?;
+        revwalk
+            .push_head()
+            .map_err(|err| super::git_error(err, head))?;
+
+        let mut vals = Vec::new();
+        for oid in revwalk.take(max_count) {
+            let oid = oid.map_err(|err| super::git_error(err, head))?;
+            let commit = repo
+                .find_commit(oid)
+                .map_err(|err| super::git_error(err, head))?;
+            let author = commit.author();
+            vals.push(Value::record(
+                vec![
+                    "commit".into(),
+                    "author".into(),
+                    "email".into(),
+                    "date".into(),
+                    "message".into(),
+                ],
+                vec![
+                    Value::string(oid.to_string(), head),
+                    Value::string(String::from_utf8_lossy(author.name_bytes()), head),
+                    Value::string(String::from_utf8_lossy(author.email_bytes()), head),
+                    commit_date(&author, head),
+                    Value::string(String::from_utf8_lossy(commit.message_bytes()), head),
+                ],
+                head,
+            ));
+        }
+
+        Ok(Value::List { vals, span: head }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "vcs log -n 5 | select commit message",
+            description: "Show the five most recent commits",
+            result: None,
+        }]
+    }
+}
+
+pub(super) fn commit_date(signature: &git2::Signature, span: Span) -> Value {
+    let time = signature.when();
+    let timestamp: Option<DateTime<FixedOffset>> =
+        FixedOffset::east_opt(time.offset_minutes() * 60)
+            .and_then(|offset| offset.timestamp_opt(time.seconds(), 0).single());
+    match timestamp {
+        Some(val) => Value::Date { val, span },
+        None => Value::nothing(span),
+    }
+}
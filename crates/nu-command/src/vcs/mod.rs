@@ -0,0 +1,26 @@
+mod blame;
+mod log;
+mod status;
+mod vcs_;
+
+pub use blame::VcsBlame;
+pub use log::VcsLog;
+pub use status::VcsStatus;
+pub use vcs_::Vcs;
+
+use nu_protocol::{ShellError, Span};
+use std::path::Path;
+
+pub(crate) fn open_repo(cwd: &Path, span: Span) -> Result<git2::Repository, ShellError> {
+    git2::Repository::discover(cwd).map_err(|err| git_error(err, span))
+}
+
+pub(crate) fn git_error(err: git2::Error, span: Span) -> ShellError {
+    ShellError::GenericError(
+        "git error".into(),
+        err.message().into(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
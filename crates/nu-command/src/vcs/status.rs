@@ -0,0 +1,115 @@
+use git2::{Status, StatusOptions};
+use nu_engine::env::current_dir;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct VcsStatus;
+
+impl Command for VcsStatus {
+    fn name(&self) -> &str {
+        "vcs status"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("vcs status")
+            .input_output_types(vec![(Type::Nothing, Type::Table(vec![]))])
+            .category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Show the status of the current git working tree as a table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each changed file becomes a row with its state in the index and in
+the working tree ('new', 'modified', 'deleted', 'renamed', or
+'typechange'); untracked files show up as 'new' in the working tree."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let cwd = current_dir(engine_state, stack)?;
+        let repo = super::open_repo(&cwd, head)?;
+
+        let mut options = StatusOptions::new();
+        options.include_untracked(true).renames_head_to_index(true);
+        let statuses = repo
+            .statuses(Some(&mut options))
+            .map_err(|err| super::git_error(err, head))?;
+
+        let vals = statuses
+            .iter()
+            .map(|entry| {
+                let status = entry.status();
+                Value::record(
+                    vec!["name".into(), "index".into(), "worktree".into()],
+                    vec![
+                        Value::string(String::from_utf8_lossy(entry.path_bytes()), head),
+                        index_status(status, head),
+                        worktree_status(status, head),
+                    ],
+                    head,
+                )
+            })
+            .collect();
+
+        Ok(Value::List { vals, span: head }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            example: "vcs status | where worktree == modified",
+            description: "List the files with unstaged modifications",
+            result: None,
+        }]
+    }
+}
+
+fn index_status(status: Status, span: Span) -> Value {
+    status_label(
+        &[
+            (Status::INDEX_NEW, "new"),
+            (Status::INDEX_MODIFIED, "modified"),
+            (Status::INDEX_DELETED, "deleted"),
+            (Status::INDEX_RENAMED, "renamed"),
+            (Status::INDEX_TYPECHANGE, "typechange"),
+        ],
+        status,
+        span,
+    )
+}
+
+fn worktree_status(status: Status, span: Span) -> Value {
+    status_label(
+        &[
+            (Status::WT_NEW, "new"),
+            (Status::WT_MODIFIED, "modified"),
+            (Status::WT_DELETED, "deleted"),
+            (Status::WT_RENAMED, "renamed"),
+            (Status::WT_TYPECHANGE, "typechange"),
+            (Status::CONFLICTED, "conflict"),
+        ],
+        status,
+        span,
+    )
+}
+
+fn status_label(labels: &[(Status, &str)], status: Status, span: Span) -> Value {
+    labels
+        .iter()
+        .find(|(flag, _)| status.contains(*flag))
+        .map_or_else(
+            || Value::nothing(span),
+            |(_, label)| Value::string(*label, span),
+        )
+}
@@ -0,0 +1,287 @@
+use md5::Md5;
+use nu_engine::{current_dir, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
+};
+use rayon::prelude::*;
+use sha2::Sha256;
+use std::io::Read;
+use std::path::Path;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct VerifyFiles;
+
+impl Command for VerifyFiles {
+    fn name(&self) -> &str {
+        "verify files"
+    }
+
+    fn usage(&self) -> &str {
+        "Verify files against their expected hashes, in parallel."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Expected hashes come either from an input table with `path` and `hash` columns
+or from a checksum file in the `<hash>  <path>` format written by sha256sum.
+
+Each output row reports the file, the expected and actual digests, and whether
+they match; unreadable files fail with the error in the `error` column."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("verify files")
+            .input_output_types(vec![
+                (Type::Table(vec![]), Type::Table(vec![])),
+                (Type::Nothing, Type::Table(vec![])),
+            ])
+            .optional(
+                "checksumfile",
+                SyntaxShape::Filepath,
+                "a checksum file in the format written by sha256sum or md5sum",
+            )
+            .named(
+                "algorithm",
+                SyntaxShape::String,
+                "the hash algorithm to verify with: md5 or sha256 (the default)",
+                Some('a'),
+            )
+            .named(
+                "threads",
+                SyntaxShape::Int,
+                "the number of threads to use",
+                Some('t'),
+            )
+            .category(Category::Hash)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["checksum", "sha256", "md5", "integrity"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let checksum_file: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+        let algorithm_arg: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "algorithm")?;
+        let threads: Option<usize> = call.get_flag(engine_state, stack, "threads")?;
+        let cwd = current_dir(engine_state, stack)?;
+        let ctrlc = engine_state.ctrlc.clone();
+
+        let algorithm = match &algorithm_arg {
+            None => Algorithm::Sha256,
+            Some(arg) => match arg.item.as_str() {
+                "md5" => Algorithm::Md5,
+                "sha256" => Algorithm::Sha256,
+                _ => {
+                    return Err(ShellError::GenericError(
+                        format!("Unknown hash algorithm {}", arg.item),
+                        "not a supported algorithm".into(),
+                        Some(arg.span),
+                        Some("allowed algorithms: md5, sha256".into()),
+                        Vec::new(),
+                    ))
+                }
+            },
+        };
+
+        let entries = match checksum_file {
+            Some(file) => {
+                let path = nu_path::expand_path_with(&file.item, &cwd);
+                let contents = std::fs::read_to_string(&path).map_err(|err| {
+                    ShellError::GenericError(
+                        format!("Could not read {}", path.display()),
+                        err.to_string(),
+                        Some(file.span),
+                        None,
+                        Vec::new(),
+                    )
+                })?;
+                parse_checksum_file(&contents, file.span)?
+            }
+            None => {
+                let mut entries = Vec::new();
+                for value in input.into_iter() {
+                    let path = value
+                        .get_data_by_key("path")
+                        .and_then(|v| v.as_string().ok());
+                    let hash = value
+                        .get_data_by_key("hash")
+                        .and_then(|v| v.as_string().ok());
+                    match (path, hash) {
+                        (Some(path), Some(hash)) => entries.push((path, hash)),
+                        _ => {
+                            return Err(ShellError::GenericError(
+                                "Expected a table with path and hash columns".into(),
+                                "each row must name a file and its expected hash".into(),
+                                Some(span),
+                                Some("alternatively, pass a checksum file as an argument".into()),
+                                Vec::new(),
+                            ))
+                        }
+                    }
+                }
+                entries
+            }
+        };
+
+        let pool = create_pool(threads.unwrap_or(0))?;
+        Ok(pool.install(|| {
+            entries
+                .into_par_iter()
+                .map(move |(path, expected)| {
+                    let full_path = nu_path::expand_path_with(&path, &cwd);
+                    let outcome = match algorithm {
+                        Algorithm::Md5 => digest_file::<Md5>(&full_path),
+                        Algorithm::Sha256 => digest_file::<Sha256>(&full_path),
+                    };
+                    verify_row(path, &expected, outcome, span)
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .into_pipeline_data(ctrlc)
+        }))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Verify a table of files against their expected sha256 hashes",
+                example: "[[path hash]; [release.tar.gz 315f5bdb76d078c43b8ac0064e4a0164612b1fce77c869345bfc94c75894edd3]] | verify files",
+                result: None,
+            },
+            Example {
+                description: "Verify the files listed in a sha256sum-style checksum file",
+                example: "verify files SHA256SUMS",
+                result: None,
+            },
+            Example {
+                description: "Verify md5 hashes and keep only the failures",
+                example: "open checksums.csv | verify files --algorithm md5 | where not ok",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Algorithm {
+    Md5,
+    Sha256,
+}
+
+fn create_pool(num_threads: usize) -> Result<rayon::ThreadPool, ShellError> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .map_err(|e| {
+            ShellError::GenericError(
+                "Error creating thread pool".into(),
+                e.to_string(),
+                Some(Span::unknown()),
+                None,
+                Vec::new(),
+            )
+        })
+}
+
+fn parse_checksum_file(contents: &str, span: Span) -> Result<Vec<(String, String)>, ShellError> {
+    let mut entries = Vec::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        match line.split_once(char::is_whitespace) {
+            Some((hash, path)) if !path.trim_start().is_empty() => {
+                // sha256sum marks binary-mode entries with a leading `*`
+                let path = path.trim_start().trim_start_matches('*');
+                entries.push((path.to_string(), hash.to_string()));
+            }
+            _ => {
+                return Err(ShellError::GenericError(
+                    "Invalid checksum file".into(),
+                    format!("could not parse the line `{line}`"),
+                    Some(span),
+                    Some("expected lines in the `<hash>  <path>` format".into()),
+                    Vec::new(),
+                ))
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+fn digest_file<D>(path: &Path) -> std::io::Result<String>
+where
+    D: digest::Digest,
+    digest::Output<D>: core::fmt::LowerHex,
+{
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = D::new();
+    let mut buffer = [0u8; 65536];
+
+    loop {
+        let n = file.read(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn verify_row(path: String, expected: &str, outcome: std::io::Result<String>, span: Span) -> Value {
+    let (ok, actual, error) = match outcome {
+        Ok(actual) => {
+            let ok = actual == expected.to_ascii_lowercase();
+            (
+                ok,
+                Value::String { val: actual, span },
+                Value::Nothing { span },
+            )
+        }
+        Err(err) => (
+            false,
+            Value::Nothing { span },
+            Value::String {
+                val: err.to_string(),
+                span,
+            },
+        ),
+    };
+
+    Value::Record {
+        cols: Arc::new(vec![
+            "path".into(),
+            "ok".into(),
+            "expected".into(),
+            "actual".into(),
+            "error".into(),
+        ]),
+        vals: vec![
+            Value::String { val: path, span },
+            Value::Bool { val: ok, span },
+            Value::String {
+                val: expected.to_ascii_lowercase(),
+                span,
+            },
+            actual,
+            error,
+        ],
+        span,
+    }
+}
@@ -0,0 +1,5 @@
+mod files;
+mod verify_;
+
+pub use files::VerifyFiles;
+pub use verify_::Verify;
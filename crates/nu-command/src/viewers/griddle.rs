@@ -11,6 +11,7 @@ use nu_protocol::{
 };
 use nu_term_grid::grid::{Alignment, Cell, Direction, Filling, Grid, GridOptions};
 use nu_utils::get_ls_colors;
+use std::sync::Arc;
 use terminal_size::{Height, Width};
 #[derive(Clone)]
 pub struct Griddle;
@@ -114,7 +115,11 @@ prints out the list properly."#
                 // dbg!("value::record");
                 let mut items = vec![];
 
-                for (i, (c, v)) in cols.into_iter().zip(vals.into_iter()).enumerate() {
+                for (i, (c, v)) in Arc::unwrap_or_clone(cols)
+                    .into_iter()
+                    .zip(vals.into_iter())
+                    .enumerate()
+                {
                     items.push((i, c, v.into_string(", ", config)))
                 }
 
@@ -273,7 +278,7 @@ fn convert_to_list(
             } else {
                 for header in headers.iter().skip(1) {
                     let result = match item {
-                        Value::Record { .. } => item.clone().follow_cell_path(
+                        Value::Record { .. } => item.follow_cell_path(
                             &[PathMember::String {
                                 val: header.into(),
                                 span: head,
@@ -2,7 +2,9 @@ mod explore;
 mod griddle;
 mod icons;
 mod table;
+mod view_image;
 
 pub use explore::Explore;
 pub use griddle::Griddle;
 pub use table::Table;
+pub use view_image::ViewImage;
@@ -15,7 +15,7 @@ use nu_utils::get_ls_colors;
 use rayon::prelude::*;
 use std::sync::Arc;
 use std::time::Instant;
-use std::{cmp::max, path::PathBuf, sync::atomic::AtomicBool};
+use std::{cmp::max, collections::HashMap, path::PathBuf, sync::atomic::AtomicBool};
 use terminal_size::{Height, Width};
 use url::Url;
 
@@ -95,6 +95,11 @@ impl Command for Table {
                 "expand the table structure in collapse mode.\nBe aware collapse mode currently doesn't support width control",
                 Some('c'),
             )
+            .switch(
+                "tree",
+                "draw nested records and lists as a tree, one line per leaf.\nBe aware tree mode currently doesn't support width control",
+                Some('t'),
+            )
             .category(Category::Viewers)
     }
 
@@ -114,14 +119,16 @@ impl Command for Table {
         let expand: bool = call.has_flag("expand");
         let expand_limit: Option<usize> = call.get_flag(engine_state, stack, "expand-deep")?;
         let collapse: bool = call.has_flag("collapse");
+        let tree: bool = call.has_flag("tree") || engine_state.get_config().table_mode == "tree";
         let flatten: bool = call.has_flag("flatten");
         let flatten_separator: Option<String> =
             call.get_flag(engine_state, stack, "flatten-separator")?;
 
-        let table_view = match (expand, collapse) {
-            (false, false) => TableView::General,
-            (_, true) => TableView::Collapsed,
-            (true, _) => TableView::Expanded {
+        let table_view = match (expand, collapse, tree) {
+            (_, _, true) => TableView::Tree,
+            (false, false, _) => TableView::General,
+            (_, true, _) => TableView::Collapsed,
+            (true, _, _) => TableView::Expanded {
                 limit: expand_limit,
                 flatten,
                 flatten_separator,
@@ -169,12 +176,12 @@ impl Command for Table {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["a".to_string(), "b".to_string()],
+                            cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                             vals: vec![Value::test_int(1), Value::test_int(2)],
                             span,
                         },
                         Value::Record {
-                            cols: vec!["a".to_string(), "b".to_string()],
+                            cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                             vals: vec![Value::test_int(3), Value::test_int(4)],
                             span,
                         },
@@ -188,12 +195,12 @@ impl Command for Table {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["a".to_string(), "b".to_string()],
+                            cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                             vals: vec![Value::test_int(1), Value::test_int(2)],
                             span,
                         },
                         Value::Record {
-                            cols: vec!["a".to_string(), "b".to_string()],
+                            cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                             vals: vec![Value::test_int(3), Value::test_int(4)],
                             span,
                         },
@@ -207,12 +214,12 @@ impl Command for Table {
                 result: Some(Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec!["a".to_string(), "b".to_string()],
+                            cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                             vals: vec![Value::test_int(1), Value::test_int(2)],
                             span,
                         },
                         Value::Record {
-                            cols: vec!["a".to_string(), "b".to_string()],
+                            cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                             vals: vec![Value::test_int(3), Value::test_int(4)],
                             span,
                         },
@@ -281,7 +288,7 @@ fn handle_table_command(
             let term_width = get_width_param(term_width);
 
             handle_record(
-                cols,
+                cols.to_vec(),
                 vals,
                 span,
                 engine_state,
@@ -332,6 +339,7 @@ fn supported_table_modes() -> Vec<Value> {
         Value::test_string("basic"),
         Value::test_string("compact"),
         Value::test_string("compact_double"),
+        Value::test_string("custom"),
         Value::test_string("default"),
         Value::test_string("heavy"),
         Value::test_string("light"),
@@ -339,6 +347,7 @@ fn supported_table_modes() -> Vec<Value> {
         Value::test_string("reinforced"),
         Value::test_string("rounded"),
         Value::test_string("thin"),
+        Value::test_string("tree"),
         Value::test_string("with_love"),
     ]
 }
@@ -351,7 +360,7 @@ fn build_collapsed_table(
     term_width: usize,
 ) -> Result<Option<String>, ShellError> {
     let mut value = Value::Record {
-        cols,
+        cols: Arc::new(cols),
         vals,
         span: Span::new(0, 0),
     };
@@ -488,7 +497,7 @@ fn build_expanded_table(
                         wrap_text(&text, value_width, config)
                     } else {
                         let result = build_expanded_table(
-                            cols.clone(),
+                            cols.to_vec(),
                             vals.clone(),
                             span,
                             ctrlc.clone(),
@@ -618,6 +627,14 @@ fn handle_record(
             TableView::Collapsed => {
                 build_collapsed_table(style_computer, cols, vals, config, term_width)
             }
+            TableView::Tree => {
+                let value = Value::Record {
+                    cols: Arc::new(cols),
+                    vals,
+                    span,
+                };
+                Ok(Some(build_tree_table(&value, 0, config)))
+            }
         }?;
 
         let result = strip_output_color(result, config);
@@ -736,6 +753,7 @@ fn handle_row_stream(
     let width_param: Option<i64> = call.get_flag(engine_state, stack, "width")?;
 
     let collapse: bool = call.has_flag("collapse");
+    let tree: bool = call.has_flag("tree") || engine_state.get_config().table_mode == "tree";
 
     let expand: bool = call.has_flag("expand");
     let limit: Option<usize> = call.get_flag(engine_state, stack, "expand-deep")?;
@@ -743,9 +761,10 @@ fn handle_row_stream(
     let flatten_separator: Option<String> =
         call.get_flag(engine_state, stack, "flatten-separator")?;
 
-    let table_view = match (expand, collapse) {
-        (_, true) => TableView::Collapsed,
-        (true, _) => TableView::Expanded {
+    let table_view = match (expand, collapse, tree) {
+        (_, _, true) => TableView::Tree,
+        (_, true, _) => TableView::Collapsed,
+        (true, _, _) => TableView::Expanded {
             flatten,
             flatten_separator,
             limit,
@@ -803,33 +822,157 @@ fn make_clickable_link(
     }
 }
 
+// A single column's display rules from $env.config.table.columns.
+// The color (if any) is kept inside the StyleComputer under "table_column_{name}",
+// as it may be a closure which needs the engine to run.
+#[derive(Debug, Default, Clone)]
+struct ColumnFormat {
+    alignment: Option<Alignment>,
+    precision: Option<usize>,
+    max_width: Option<usize>,
+    ellipsis: EllipsisPosition,
+    has_color: bool,
+}
+
+// Where the "..." goes when a cell is cut down to a column's max_width.
+#[derive(Debug, Default, Clone, Copy)]
+enum EllipsisPosition {
+    Start,
+    Middle,
+    #[default]
+    End,
+}
+
+fn lookup_column_formats(config: &Config) -> HashMap<String, ColumnFormat> {
+    let mut formats = HashMap::new();
+
+    for (column, rule) in &config.table_columns {
+        let mut format = ColumnFormat::default();
+
+        if let Some(Ok(text)) = rule.get_data_by_key("alignment").map(|v| v.as_string()) {
+            format.alignment = match text.as_str() {
+                "left" => Some(Alignment::Left),
+                "center" => Some(Alignment::Center),
+                "right" => Some(Alignment::Right),
+                _ => None,
+            };
+        }
+
+        if let Some(Ok(precision)) = rule.get_data_by_key("precision").map(|v| v.as_integer()) {
+            if precision >= 0 {
+                format.precision = Some(precision as usize);
+            }
+        }
+
+        if let Some(Ok(width)) = rule.get_data_by_key("max_width").map(|v| v.as_integer()) {
+            if width > 0 {
+                format.max_width = Some(width as usize);
+            }
+        }
+
+        if let Some(Ok(text)) = rule.get_data_by_key("ellipsis").map(|v| v.as_string()) {
+            format.ellipsis = match text.as_str() {
+                "start" => EllipsisPosition::Start,
+                "middle" => EllipsisPosition::Middle,
+                _ => EllipsisPosition::End,
+            };
+        }
+
+        format.has_color = rule.get_data_by_key("color").is_some();
+
+        formats.insert(column.clone(), format);
+    }
+
+    formats
+}
+
+fn shorten_text(text: &str, max_width: usize, ellipsis: EllipsisPosition) -> String {
+    const ELLIPSIS: &str = "...";
+
+    let length = text.chars().count();
+    if length <= max_width {
+        return text.to_string();
+    }
+
+    if max_width <= ELLIPSIS.len() {
+        return text.chars().take(max_width).collect();
+    }
+
+    let keep = max_width - ELLIPSIS.len();
+    match ellipsis {
+        EllipsisPosition::Start => {
+            let tail = text.chars().skip(length - keep).collect::<String>();
+            format!("{ELLIPSIS}{tail}")
+        }
+        EllipsisPosition::Middle => {
+            let head = text.chars().take((keep + 1) / 2).collect::<String>();
+            let tail = text.chars().skip(length - keep / 2).collect::<String>();
+            format!("{head}{ELLIPSIS}{tail}")
+        }
+        EllipsisPosition::End => {
+            let head = text.chars().take(keep).collect::<String>();
+            format!("{head}{ELLIPSIS}")
+        }
+    }
+}
+
 // convert_to_table() defers all its style computations so that they can be run in parallel using par_extend().
 // This structure holds the intermediate computations.
 // Currently, the other table forms don't use this.
 // Because of how table-specific this is, I don't think this can be pushed into StyleComputer itself.
 enum DeferredStyleComputation {
-    Value { value: Value },
-    Header { text: String },
-    RowIndex { text: String },
+    Value {
+        value: Value,
+        column: Option<String>,
+    },
+    Header {
+        text: String,
+    },
+    RowIndex {
+        text: String,
+    },
     Empty {},
 }
 
 impl DeferredStyleComputation {
     // This is only run inside a par_extend().
-    fn compute(&self, config: &Config, style_computer: &StyleComputer) -> NuText {
+    fn compute(
+        &self,
+        config: &Config,
+        style_computer: &StyleComputer,
+        formats: &HashMap<String, ColumnFormat>,
+    ) -> NuText {
         match self {
-            DeferredStyleComputation::Value { value } => {
-                match value {
+            DeferredStyleComputation::Value { value, column } => {
+                let format = column.as_deref().and_then(|column| formats.get(column));
+                let precision = format
+                    .and_then(|format| format.precision)
+                    .unwrap_or(config.float_precision as usize);
+
+                let mut text = match value {
                     // Float precision is required here.
-                    Value::Float { val, .. } => (
-                        format!("{:.prec$}", val, prec = config.float_precision as usize),
-                        style_computer.style_primitive(value),
-                    ),
-                    _ => (
-                        value.into_abbreviated_string(config),
-                        style_computer.style_primitive(value),
-                    ),
+                    Value::Float { val, .. } => format!("{:.prec$}", val, prec = precision),
+                    _ => value.into_abbreviated_string(config),
+                };
+                let mut style = style_computer.style_primitive(value);
+
+                if let Some(format) = format {
+                    if let Some(max_width) = format.max_width {
+                        text = shorten_text(&text, max_width, format.ellipsis);
+                    }
+
+                    if format.has_color {
+                        let column = column.as_deref().unwrap_or_default();
+                        style.color_style =
+                            Some(style_computer.compute(&format!("table_column_{column}"), value));
+                    }
+
+                    if let Some(alignment) = format.alignment {
+                        style.alignment = alignment;
+                    }
                 }
+
+                (text, style)
             }
             DeferredStyleComputation::Header { text } => (
                 text.clone(),
@@ -926,6 +1069,7 @@ fn convert_to_table(
         if !with_header {
             row.push(DeferredStyleComputation::Value {
                 value: item.clone(),
+                column: None,
             });
         } else {
             let skip_num = usize::from(with_index);
@@ -939,15 +1083,19 @@ fn convert_to_table(
                                 span: head,
                                 optional: false,
                             };
-                            let val = item.clone().follow_cell_path(&[path], false);
+                            let val = item.follow_cell_path(&[path], false);
 
                             match val {
-                                Ok(val) => DeferredStyleComputation::Value { value: val },
+                                Ok(val) => DeferredStyleComputation::Value {
+                                    value: val,
+                                    column: Some(text.clone()),
+                                },
                                 Err(_) => DeferredStyleComputation::Empty {},
                             }
                         }
                         _ => DeferredStyleComputation::Value {
                             value: item.clone(),
+                            column: None,
                         },
                     });
                 }
@@ -959,6 +1107,8 @@ fn convert_to_table(
         data.push(row);
     }
 
+    let column_formats = lookup_column_formats(config);
+
     // All the computations are parallelised here.
     // NOTE: It's currently not possible to Ctrl-C out of this...
     let mut cells: Vec<Vec<_>> = Vec::with_capacity(data.len());
@@ -967,7 +1117,7 @@ fn convert_to_table(
             let mut new_row = Vec::with_capacity(row.len());
             row.into_par_iter()
                 .map(|deferred| {
-                    let pair = deferred.compute(config, style_computer);
+                    let pair = deferred.compute(config, style_computer, &column_formats);
 
                     NuTable::create_cell(pair.0, pair.1)
                 })
@@ -1330,7 +1480,7 @@ fn create_table2_entry(
                 span: head,
                 optional: false,
             };
-            let val = item.clone().follow_cell_path(&[path], false);
+            let val = item.follow_cell_path(&[path], false);
 
             match val {
                 Ok(val) => convert_to_table2_entry(
@@ -1394,7 +1544,7 @@ fn convert_to_table2_entry(
             // we verify what is the structure of a Record cause it might represent
 
             let table = build_expanded_table(
-                cols.clone(),
+                cols.to_vec(),
                 vals.clone(),
                 *span,
                 ctrlc.clone(),
@@ -1682,6 +1832,20 @@ impl PagingTableCreator {
         Ok(table.draw(term_width))
     }
 
+    fn build_tree(&mut self, batch: Vec<Value>) -> Result<Option<String>, ShellError> {
+        if batch.is_empty() {
+            return Ok(None);
+        }
+
+        let config = self.engine_state.get_config();
+        let value = Value::List {
+            vals: batch,
+            span: self.head,
+        };
+
+        Ok(Some(build_tree_table(&value, self.row_offset, config)))
+    }
+
     fn build_general(&mut self, batch: &[Value]) -> Result<Option<String>, ShellError> {
         let term_width = get_width_param(self.width_param);
         let config = &self.engine_state.get_config();
@@ -1770,6 +1934,7 @@ impl Iterator for PagingTableCreator {
         let table = match &self.view {
             TableView::General => self.build_general(&batch),
             TableView::Collapsed => self.build_collapsed(batch),
+            TableView::Tree => self.build_tree(batch),
             TableView::Expanded {
                 limit,
                 flatten,
@@ -1805,6 +1970,67 @@ impl Iterator for PagingTableCreator {
     }
 }
 
+// Render nested records and lists as a `cargo tree`-like tree, one line per
+// leaf, which stays readable where expanded nested tables do not.
+fn build_tree_table(value: &Value, row_offset: usize, config: &Config) -> String {
+    match tree_entries(value) {
+        Some(entries) => {
+            // When the input is streamed in batches, the top-level row numbers
+            // have to keep counting from where the previous batch stopped.
+            let entries: Vec<(String, &Value)> = if row_offset > 0 {
+                entries
+                    .into_iter()
+                    .enumerate()
+                    .map(|(row, (_, val))| ((row + row_offset).to_string(), val))
+                    .collect()
+            } else {
+                entries
+            };
+
+            let mut lines = Vec::new();
+            write_tree_entries(&entries, "", config, &mut lines);
+            lines.join("\n")
+        }
+        None => value.clone().into_abbreviated_string(config),
+    }
+}
+
+fn tree_entries(value: &Value) -> Option<Vec<(String, &Value)>> {
+    match value {
+        Value::Record { cols, vals, .. } => Some(cols.iter().cloned().zip(vals.iter()).collect()),
+        Value::List { vals, .. } => Some(
+            vals.iter()
+                .enumerate()
+                .map(|(row, val)| (row.to_string(), val))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn write_tree_entries(
+    entries: &[(String, &Value)],
+    prefix: &str,
+    config: &Config,
+    lines: &mut Vec<String>,
+) {
+    for (index, (name, value)) in entries.iter().enumerate() {
+        let is_last = index + 1 == entries.len();
+        let connector = if is_last { "└── " } else { "├── " };
+        match tree_entries(value) {
+            Some(children) if !children.is_empty() => {
+                lines.push(format!("{prefix}{connector}{name}"));
+                let child_prefix = format!("{prefix}{}", if is_last { "    " } else { "│   " });
+                write_tree_entries(&children, &child_prefix, config, lines);
+            }
+            _ => lines.push(format!(
+                "{prefix}{connector}{name}: {}",
+                value.clone().into_abbreviated_string(config)
+            )),
+        }
+    }
+}
+
 fn load_theme_from_config(config: &Config) -> TableTheme {
     match config.table_mode.as_str() {
         "basic" => TableTheme::basic(),
@@ -1817,10 +2043,25 @@ fn load_theme_from_config(config: &Config) -> TableTheme {
         "reinforced" => TableTheme::reinforced(),
         "heavy" => TableTheme::heavy(),
         "none" => TableTheme::none(),
+        "custom" => TableTheme::custom(&lookup_theme_chars(config)),
         _ => TableTheme::rounded(),
     }
 }
 
+// Collects the border characters a "custom" theme is made of from
+// $env.config.table.theme. Only the first character of each string is used.
+fn lookup_theme_chars(config: &Config) -> HashMap<String, char> {
+    config
+        .table_theme
+        .iter()
+        .filter_map(|(name, value)| {
+            let text = value.as_string().ok()?;
+            let c = text.chars().next()?;
+            Some((name.clone(), c))
+        })
+        .collect()
+}
+
 fn render_path_name(
     path: &str,
     config: &Config,
@@ -1868,6 +2109,7 @@ fn render_path_name(
 enum TableView {
     General,
     Collapsed,
+    Tree,
     Expanded {
         limit: Option<usize>,
         flatten: bool,
@@ -1961,7 +2203,7 @@ fn colorize_value(value: &mut Value, config: &Config, style_computer: &StyleComp
 
             let style = header_style(style_computer, "");
             if let Some(color) = style.color_style {
-                for header in cols {
+                for header in Arc::make_mut(cols) {
                     *header = color.paint(header.to_owned()).to_string();
                 }
             }
@@ -0,0 +1,165 @@
+use base64::engine::general_purpose::PAD;
+use base64::engine::GeneralPurpose;
+use base64::{alphabet, Engine};
+use nu_engine::{current_dir, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Type, Value,
+};
+
+#[derive(Clone)]
+pub struct ViewImage;
+
+impl Command for ViewImage {
+    fn name(&self) -> &str {
+        "view image"
+    }
+
+    fn usage(&self) -> &str {
+        "Display an image file inline in the terminal."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Requires a terminal implementing the kitty or iTerm2 inline image protocol (kitty, iTerm2, WezTerm, ...)."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("view image")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .required("path", SyntaxShape::Filepath, "the image file to display")
+            .category(Category::Viewers)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["picture", "png", "graphics", "kitty", "iterm"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let cwd = current_dir(engine_state, stack)?;
+        let full_path = nu_path::expand_path_with(&path.item, cwd);
+
+        let bytes = std::fs::read(&full_path).map_err(|err| {
+            ShellError::GenericError(
+                format!("Cannot read {:?}", full_path),
+                err.to_string(),
+                Some(path.span),
+                None,
+                Vec::new(),
+            )
+        })?;
+
+        let protocol = detect_graphics_protocol(engine_state, stack).ok_or_else(|| {
+            ShellError::GenericError(
+                "Terminal graphics not supported".into(),
+                "no inline image protocol detected".into(),
+                Some(call.head),
+                Some(
+                    "view image needs a terminal implementing the kitty or iTerm2 image protocol"
+                        .into(),
+                ),
+                Vec::new(),
+            )
+        })?;
+
+        let output = match protocol {
+            GraphicsProtocol::Kitty => {
+                // The kitty protocol only takes raw RGB data or PNG, and we
+                // don't decode image formats, so PNG it is.
+                if !bytes.starts_with(PNG_MAGIC) {
+                    return Err(ShellError::GenericError(
+                        "Unsupported image format".into(),
+                        "not a PNG file".into(),
+                        Some(path.span),
+                        Some("only PNG images can be sent over the kitty protocol".into()),
+                        Vec::new(),
+                    ));
+                }
+                kitty_encode(&bytes)
+            }
+            GraphicsProtocol::ITerm2 => iterm_encode(&bytes),
+        };
+
+        Ok(Value::string(output, call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Display an image in a supporting terminal",
+            example: "view image nushell.png",
+            result: None,
+        }]
+    }
+}
+
+const PNG_MAGIC: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+enum GraphicsProtocol {
+    Kitty,
+    ITerm2,
+}
+
+// Graphics support can't be queried without a round-trip to the terminal, so
+// this relies on the environment variables the supporting terminals are known
+// to set.
+fn detect_graphics_protocol(engine_state: &EngineState, stack: &Stack) -> Option<GraphicsProtocol> {
+    let env = |name: &str| {
+        stack
+            .get_env_var(engine_state, name)
+            .and_then(|v| v.as_string().ok())
+    };
+
+    if env("KITTY_WINDOW_ID").is_some() || env("TERM").map_or(false, |term| term.contains("kitty"))
+    {
+        return Some(GraphicsProtocol::Kitty);
+    }
+
+    if matches!(
+        env("TERM_PROGRAM").as_deref(),
+        Some("iTerm.app") | Some("WezTerm")
+    ) || env("LC_TERMINAL").as_deref() == Some("iTerm2")
+    {
+        return Some(GraphicsProtocol::ITerm2);
+    }
+
+    None
+}
+
+// The kitty protocol caps every escape sequence at 4096 bytes of payload; the
+// m key tells the terminal whether more chunks follow.
+const KITTY_CHUNK_SIZE: usize = 4096;
+
+fn kitty_encode(bytes: &[u8]) -> String {
+    let base64_engine = GeneralPurpose::new(&alphabet::STANDARD, PAD);
+    let encoded = base64_engine.encode(bytes);
+
+    let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(KITTY_CHUNK_SIZE).collect();
+    let mut output = String::new();
+    for (index, chunk) in chunks.iter().enumerate() {
+        let chunk = std::str::from_utf8(chunk).expect("base64 output is always ASCII");
+        let more = if index + 1 == chunks.len() { 0 } else { 1 };
+        if index == 0 {
+            output.push_str(&format!("\x1b_Ga=T,f=100,m={more};{chunk}\x1b\\"));
+        } else {
+            output.push_str(&format!("\x1b_Gm={more};{chunk}\x1b\\"));
+        }
+    }
+    output
+}
+
+fn iterm_encode(bytes: &[u8]) -> String {
+    let base64_engine = GeneralPurpose::new(&alphabet::STANDARD, PAD);
+    format!(
+        "\x1b]1337;File=inline=1;size={}:{}\x07",
+        bytes.len(),
+        base64_engine.encode(bytes)
+    )
+}
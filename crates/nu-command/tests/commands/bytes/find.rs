@@ -0,0 +1,41 @@
+use nu_test_support::nu;
+
+#[test]
+fn finds_every_occurrence() {
+    let actual = nu!(
+        cwd: ".",
+        "0x[10 AA 10 BB 10] | bytes find 0x[10] | to nuon"
+    );
+
+    assert_eq!(actual.out, "[0, 2, 4]");
+}
+
+#[test]
+fn finds_overlapping_matches() {
+    let actual = nu!(
+        cwd: ".",
+        "'aaa' | bytes find 'aa' | to nuon"
+    );
+
+    assert_eq!(actual.out, "[0, 1]");
+}
+
+#[test]
+fn finds_matches_in_a_stream() {
+    let actual = nu!(
+        cwd: ".",
+        "nu --testbin repeater (0x[01 02]) 3 | bytes find 0x[02 01] | to nuon"
+    );
+
+    assert_eq!(actual.out, "[1, 3]");
+}
+
+#[test]
+fn empty_pattern_errors() {
+    let actual = nu!(
+        cwd: ".",
+        "0x[10] | bytes find 0x[]"
+    );
+
+    assert!(actual.err.contains("pattern to find cannot be empty"));
+}
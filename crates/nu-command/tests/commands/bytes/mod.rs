@@ -1 +1,3 @@
+mod find;
 mod starts_with;
+mod view;
@@ -0,0 +1,34 @@
+use nu_test_support::nu;
+
+#[test]
+fn renders_hex_and_ascii_columns() {
+    let actual = nu!(
+        cwd: ".",
+        "0x[48 65 6C 6C 6F] | bytes view"
+    );
+
+    assert_eq!(
+        actual.out,
+        "00000000  48 65 6c 6c 6f                                    |Hello|"
+    );
+}
+
+#[test]
+fn keeps_absolute_offsets_when_slicing() {
+    let actual = nu!(
+        cwd: ".",
+        "0x[00 01 02 03 04 05 06 07 08 09 0A 0B 0C 0D 0E 0F 10 11] | bytes view --offset 16"
+    );
+
+    assert!(actual.out.starts_with("00000010"));
+}
+
+#[test]
+fn negative_offset_errors() {
+    let actual = nu!(
+        cwd: ".",
+        "0x[10] | bytes view --offset -1"
+    );
+
+    assert!(actual.err.contains("positive"));
+}
@@ -0,0 +1,44 @@
+use nu_test_support::nu;
+
+#[test]
+fn config_check_passes_valid_config() {
+    let actual = nu!(
+        cwd: ".",
+        "let-env config = { show_banner: true }; config check"
+    );
+
+    assert_eq!(actual.out, "No errors found in the config");
+}
+
+#[test]
+fn config_check_reports_invalid_entries() {
+    let actual = nu!(
+        cwd: ".",
+        "{ show_banner: 'yes' } | config check"
+    );
+
+    assert!(actual.err.contains("should be a bool"));
+}
+
+#[test]
+fn config_check_reports_unknown_settings() {
+    let actual = nu!(
+        cwd: ".",
+        "{ not_a_setting: 1 } | config check"
+    );
+
+    assert!(actual.err.contains("unknown config setting"));
+}
+
+#[test]
+fn config_diff_default_lists_changed_settings() {
+    let actual = nu!(
+        cwd: ".",
+        "let-env config = { float_precision: 4 }; config diff-default | where path == float_precision | to nuon"
+    );
+
+    assert_eq!(
+        actual.out,
+        "[[path, default, current]; [float_precision, 2, 4]]"
+    );
+}
@@ -579,3 +579,18 @@ fn copy_file_with_read_permission_impl(progress: bool) {
         );
     });
 }
+
+#[test]
+fn dry_run_reports_without_copying() {
+    Playground::setup("cp_test_dry_run", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("original.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "cp --dry-run original.txt copy.txt | get 0 | $'($in.operation):($in.destination | path basename)'"
+        );
+
+        assert_eq!(actual.out, "cp:copy.txt");
+        assert!(!dirs.test().join("copy.txt").exists());
+    })
+}
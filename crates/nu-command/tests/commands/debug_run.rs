@@ -0,0 +1,103 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn debug_run_stops_at_first_element_and_continues() {
+    Playground::setup("debug_run_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "script.nu",
+            r#"
+                let x = 40
+                print ($x + 2)
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                "c" | ^$nu.current-exe --no-std-lib --commands "debug run script.nu"
+            "#
+        ));
+
+        assert!(actual.err.contains("-> let x = 40"));
+        assert!(actual.err.contains("(nu-debug)"));
+        assert!(actual.out.contains("42"));
+    })
+}
+
+#[test]
+fn debug_run_quit_aborts_the_script() {
+    Playground::setup("debug_run_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "script.nu",
+            r#"
+                print "first"
+                print "second"
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                "q" | ^$nu.current-exe --no-std-lib --commands "debug run script.nu --break-commands [print]"
+            "#
+        ));
+
+        assert!(actual.err.contains("Stopped by the debugger"));
+        assert!(!actual.out.contains("first"));
+    })
+}
+
+#[test]
+fn debug_run_breaks_on_line_and_prints_vars() {
+    Playground::setup("debug_run_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "script.nu",
+            r#"
+                let greeting = "hello there"
+                print $greeting
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                "v\nc" | ^$nu.current-exe --no-std-lib --commands "debug run script.nu --break-lines [2]"
+            "#
+        ));
+
+        assert!(actual.err.contains("-> print $greeting"));
+        assert!(actual.err.contains("= hello there"));
+        assert!(actual.out.contains("hello there"));
+    })
+}
+
+#[test]
+fn debug_run_shows_pipeline_input() {
+    Playground::setup("debug_run_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "script.nu",
+            r#"
+                print ([1 2 3] | length)
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                "i\nc" | ^$nu.current-exe --no-std-lib --commands "debug run script.nu --break-commands ['length']"
+            "#
+        ));
+
+        assert!(actual.err.contains("[1, 2, 3]"));
+        assert!(actual.out.contains("3"));
+    })
+}
+
+#[test]
+fn debug_run_rejects_missing_script() {
+    let actual = nu!(cwd: ".", "debug run does_not_exist.nu");
+
+    assert!(actual.err.contains("does_not_exist.nu"));
+}
@@ -0,0 +1,24 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn du_with_threads_matches_the_sequential_sizes() {
+    Playground::setup("du_threads", |dirs, sandbox| {
+        sandbox
+            .within("sub")
+            .with_files(vec![FileWithContent("inner.txt", "0123456789")])
+            .back_to_playground()
+            .with_files(vec![FileWithContent("outer.txt", "01234")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                (du --threads 4 | get apparent | math sum)
+                == (du | get apparent | math sum)
+            "#
+        ));
+
+        assert_eq!(actual.out, "true");
+    })
+}
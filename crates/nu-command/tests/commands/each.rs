@@ -73,3 +73,13 @@ fn each_while_uses_enumerate_index() {
 
     assert_eq!(actual.out, "[0, 1, 2, 3]");
 }
+
+#[test]
+fn each_with_progress_bar() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats",
+        "echo [1 2 3] | each --progress { |it| 2 * $it } | to json -r"
+    );
+
+    assert_eq!(actual.out, "[2,4,6]");
+}
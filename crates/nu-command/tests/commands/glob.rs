@@ -118,3 +118,45 @@ fn glob_ignore_files() {
         );
     })
 }
+
+#[test]
+fn glob_subdirs_excluded_with_not() {
+    Playground::setup("glob_subdirs_not", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            EmptyFile("yehuda.txt"),
+            EmptyFile("jttxt"),
+            EmptyFile("andres.txt"),
+        ]);
+        sandbox.mkdir("children");
+        sandbox.within("children").with_files(vec![
+            EmptyFile("timothy.txt"),
+            EmptyFile("tiffany.txt"),
+            EmptyFile("trish.txt"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            pipeline("glob '**/*.txt' --not ['**/children/**'] | length"),
+        );
+
+        assert_eq!(
+            actual.out, "2",
+            "should only find the two top-level txt files"
+        );
+    })
+}
+
+#[test]
+fn glob_structured_rows_carry_type_and_matched_part() {
+    Playground::setup("glob_structured", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("yehuda.txt"), EmptyFile("andres.txt")]);
+        sandbox.mkdir("children");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            pipeline("glob '*' --structured | where type == file | get matched | sort | str join ','"),
+        );
+
+        assert_eq!(actual.out, "andres.txt,yehuda.txt");
+    })
+}
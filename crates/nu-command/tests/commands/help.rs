@@ -387,3 +387,144 @@ fn help_alias_before_command() {
 
     assert!(actual.out.contains("Alias"));
 }
+
+#[test]
+fn help_shows_doc_comment_examples() {
+    Playground::setup("help_doc_comment_examples", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "greet.nu",
+            r#"# Greet someone.
+#
+# Example: greet the world
+# > greet "world"
+def greet [name: string] { $"hi ($name)" }
+"#,
+        )]);
+
+        let actual = nu!(cwd: dirs.test(), "source greet.nu; help greet");
+
+        // The example code is syntax-highlighted, so only check for its parts
+        assert!(actual.out.contains("greet the world"));
+        assert!(actual.out.contains("world"));
+    })
+}
+
+#[test]
+fn help_usage_excludes_doc_comment_examples() {
+    Playground::setup("help_usage_no_examples", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "greet.nu",
+            r#"# Greet someone.
+#
+# Example: greet the world
+# > greet "world"
+def greet [name: string] { $"hi ($name)" }
+"#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "source greet.nu; $nu.scope.commands | where name == greet | get 0.usage"
+        );
+
+        assert_eq!(actual.out, "Greet someone.");
+    })
+}
+
+#[test]
+fn help_generate_emits_module_markdown() {
+    Playground::setup("help_generate_test", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "greetings.nu",
+            r#"# A module for greetings.
+
+# Greet someone.
+#
+# Example: greet the world
+# > greet "world"
+export def greet [name: string # who to greet
+] { $"hi ($name)" }
+"#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "use greetings.nu; help generate --format md greetings"
+        );
+
+        assert!(actual.out.contains("# Module `greetings`"));
+        assert!(actual.out.contains("A module for greetings."));
+        assert!(actual.out.contains("## `greetings greet`"));
+        assert!(actual.out.contains("who to greet"));
+        assert!(actual.out.contains(r#"greet "world""#));
+    })
+}
+
+#[test]
+fn help_generate_rejects_unknown_format() {
+    let actual = nu!(cwd: ".", "help generate --format html");
+
+    assert!(actual.err.contains("Unsupported format"));
+}
+
+#[test]
+fn help_generate_unknown_module_errors() {
+    let actual = nu!(cwd: ".", "help generate i_dont_exist");
+
+    assert!(actual.err.contains("not found"));
+}
+
+#[test]
+fn help_run_verifies_doc_comment_examples() {
+    Playground::setup("help_run_examples", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "greet.nu",
+            r#"# Greet someone.
+#
+# Example: greet the world
+# > greet "world"
+# => hi world
+#
+# Example: wrong expectation
+# > greet "moon"
+# => bye moon
+def greet [name: string] { $"hi ($name)" }
+"#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "source greet.nu; help greet --run | get passed | to nuon"
+        );
+
+        assert_eq!(actual.out, "[true, false]");
+    })
+}
+
+#[test]
+fn help_run_reports_eval_errors() {
+    Playground::setup("help_run_eval_error", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "greet.nu",
+            r#"# Example: bad example
+# > greet
+# => hi
+def greet [name: string] { $"hi ($name)" }
+"#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "source greet.nu; help greet --run | get 0.passed"
+        );
+
+        assert_eq!(actual.out, "false");
+    })
+}
+
+#[test]
+fn help_run_unknown_command_errors() {
+    let actual = nu!(cwd: ".", "help i_dont_exist --run");
+
+    assert!(actual.err.contains("not found"));
+}
@@ -0,0 +1,44 @@
+use nu_test_support::nu;
+
+#[test]
+fn only_the_accessed_column_is_evaluated() {
+    let actual = nu!(
+        cwd: ".",
+        r#"lazy make --columns [a b] --get-value {|key| print -e $"eval:($key)"; $key | str upcase} | get a"#
+    );
+
+    assert_eq!(actual.out, "A");
+    assert!(actual.err.contains("eval:a"));
+    assert!(!actual.err.contains("eval:b"));
+}
+
+#[test]
+fn describe_reports_the_column_types() {
+    let actual = nu!(
+        cwd: ".",
+        r#"lazy make --columns [a b] --get-value {|key| $key | str upcase} | describe"#
+    );
+
+    assert_eq!(actual.out, "record<a: string, b: string>");
+}
+
+#[test]
+fn collects_into_a_regular_record() {
+    let actual = nu!(
+        cwd: ".",
+        r#"lazy make --columns [a b] --get-value {|key| $key | str upcase} | to nuon"#
+    );
+
+    assert_eq!(actual.out, "{a: A, b: B}");
+}
+
+#[test]
+fn rejects_a_duplicate_column() {
+    let actual = nu!(
+        cwd: ".",
+        r#"lazy make --columns [a a] --get-value {|key| $key} | get a"#
+    );
+
+    assert!(!actual.err.is_empty());
+    assert_eq!(actual.out, "");
+}
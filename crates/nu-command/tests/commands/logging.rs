@@ -0,0 +1,69 @@
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn log_info_writes_to_stderr() {
+    let actual = nu!(cwd: ".", r#"log info "hello logs""#);
+
+    assert!(actual.err.contains("INF|"));
+    assert!(actual.err.contains("hello logs"));
+    assert!(!actual.out.contains("hello logs"));
+}
+
+#[test]
+fn log_debug_is_dropped_by_default() {
+    let actual = nu!(cwd: ".", r#"log debug "too detailed""#);
+
+    assert!(!actual.err.contains("too detailed"));
+}
+
+#[test]
+fn log_level_env_enables_debug() {
+    let actual = nu!(cwd: ".", r#"let-env NU_LOG_LEVEL = DEBUG; log debug "now visible""#);
+
+    assert!(actual.err.contains("DBG|"));
+    assert!(actual.err.contains("now visible"));
+}
+
+#[test]
+fn log_level_env_silences_lower_levels() {
+    let actual = nu!(cwd: ".", r#"let-env NU_LOG_LEVEL = ERROR; log warning "not important""#);
+
+    assert!(!actual.err.contains("not important"));
+}
+
+#[test]
+fn log_modules_env_filters_by_module() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let-env NU_LOG_MODULES = "db,fs"; log info --module net "dropped"; log info --module db "kept""#
+    );
+
+    assert!(!actual.err.contains("dropped"));
+    assert!(actual.err.contains("db|kept"));
+}
+
+#[test]
+fn log_ndjson_format() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let-env NU_LOG_FORMAT = ndjson; log error --module net "boom""#
+    );
+
+    assert!(actual.err.contains(r#""level":"ERROR""#));
+    assert!(actual.err.contains(r#""message":"boom""#));
+    assert!(actual.err.contains(r#""module":"net""#));
+    assert!(actual.err.contains(r#""timestamp""#));
+}
+
+#[test]
+fn log_file_target_appends_to_the_file() {
+    Playground::setup("log_file_target", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"let-env NU_LOG_TARGET = file; let-env NU_LOG_FILE = app.log; log info "first"; log info "second"; open app.log | lines | length"#
+        );
+
+        assert_eq!(actual.out, "2");
+    })
+}
@@ -569,3 +569,24 @@ fn list_unknown_flag() {
         .err
         .contains("Available flags: --help(-h), --all(-a),"));
 }
+
+#[test]
+fn list_with_threads_keeps_order() {
+    Playground::setup("ls_threads", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            EmptyFile("los.txt"),
+            EmptyFile("tres.txt"),
+            EmptyFile("amigos.txt"),
+            EmptyFile("arepas.clu"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                (ls | get name) == (ls --threads 2 | get name)
+            "#
+        ));
+
+        assert_eq!(actual.out, "true");
+    })
+}
@@ -126,3 +126,17 @@ fn creates_directory_three_dots_quotation_marks() {
         assert!(expected.exists());
     })
 }
+
+#[test]
+fn dry_run_reports_without_creating() {
+    Playground::setup("mkdir_test_dry_run", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "mkdir --dry-run dir_1 dir_2 | get target | path basename | str join ','"
+        );
+
+        assert_eq!(actual.out, "dir_1,dir_2");
+        assert!(!dirs.test().join("dir_1").exists());
+        assert!(!dirs.test().join("dir_2").exists());
+    })
+}
@@ -8,13 +8,16 @@ mod bytes;
 mod cal;
 mod cd;
 mod compact;
+mod config;
 mod continue_;
 mod cp;
 mod date;
+mod debug_run;
 mod def;
 mod default;
 mod do_;
 mod drop;
+mod du;
 mod each;
 mod echo;
 mod empty;
@@ -43,9 +46,11 @@ mod into_filesize;
 mod into_int;
 mod join;
 mod last;
+mod lazy_make;
 mod length;
 mod let_;
 mod lines;
+mod logging;
 mod loop_;
 mod ls;
 mod match_;
@@ -61,10 +66,11 @@ mod open;
 mod p;
 mod parse;
 mod path;
+mod pkg;
 mod platform;
 mod prepend;
 mod print;
-#[cfg(feature = "sqlite")]
+mod profile;
 mod query;
 mod random;
 mod range;
@@ -77,8 +83,10 @@ mod reverse;
 mod rm;
 mod roll;
 mod rotate;
+mod run_batched;
 mod run_external;
 mod save;
+mod secret;
 mod select;
 mod semicolon;
 mod seq;
@@ -91,6 +99,7 @@ mod source_env;
 mod split_by;
 mod split_column;
 mod split_row;
+mod stat;
 mod str_;
 mod table;
 mod take;
@@ -98,16 +107,22 @@ mod to_text;
 mod touch;
 mod transpose;
 mod try_;
+mod ulimit;
 mod uniq;
 mod uniq_by;
 mod update;
 mod upsert;
 mod url;
 mod use_;
+mod users;
+mod vcs;
+mod verify_files;
+mod view_source;
 mod where_;
 #[cfg(feature = "which-support")]
 mod which;
 mod while_;
 mod with_env;
 mod wrap;
+mod xattr;
 mod zip;
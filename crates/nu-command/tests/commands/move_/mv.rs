@@ -464,3 +464,21 @@ fn mv_change_case_of_file() {
         assert!(files_in_test_directory.contains(&new_file_name));
     })
 }
+
+#[test]
+fn dry_run_reports_without_moving() {
+    Playground::setup("mv_test_dry_run", |dirs, sandbox| {
+        sandbox
+            .with_files(vec![EmptyFile("andres.txt")])
+            .mkdir("expected");
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "mv --dry-run andres.txt expected | get 0.destination | path basename"
+        );
+
+        assert_eq!(actual.out, "andres.txt");
+        assert!(dirs.test().join("andres.txt").exists());
+        assert!(!dirs.test().join("expected/andres.txt").exists());
+    })
+}
@@ -0,0 +1,114 @@
+use mockito::Matcher;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn graphql_query_returns_the_data_payload() {
+    let mut server = mockito::Server::new();
+
+    let _mock = server
+        .mock("POST", "/")
+        .match_header("content-type", "application/json")
+        .match_body(Matcher::Json(serde_json::json!({
+            "query": "{ viewer { login } }",
+            "variables": {},
+        })))
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"data": {"viewer": {"login": "amtoine"}}}"#)
+        .create();
+
+    let actual = nu!(pipeline(
+        format!(
+            r#"graphql query {url} -q "{{ viewer {{ login }} }}" | get viewer.login"#,
+            url = server.url()
+        )
+        .as_str()
+    ));
+
+    assert_eq!(actual.out, "amtoine");
+}
+
+#[test]
+fn graphql_query_sends_the_variables() {
+    let mut server = mockito::Server::new();
+
+    let _mock = server
+        .mock("POST", "/")
+        .match_body(Matcher::Json(serde_json::json!({
+            "query": "query ($name: String!) { user(login: $name) { id } }",
+            "variables": {"name": "amtoine"},
+        })))
+        .with_body(r#"{"data": {"user": {"id": 42}}}"#)
+        .create();
+
+    let actual = nu!(pipeline(
+        format!(
+            r#"graphql query {url}
+                -q "query ($name: String!) {{ user(login: $name) {{ id }} }}"
+                -v {{name: amtoine}}
+            | get user.id"#,
+            url = server.url()
+        )
+        .as_str()
+    ));
+
+    assert_eq!(actual.out, "42");
+}
+
+#[test]
+fn graphql_query_unwraps_errors() {
+    let mut server = mockito::Server::new();
+
+    let _mock = server
+        .mock("POST", "/")
+        .with_body(r#"{"errors": [{"message": "Field 'nope' doesn't exist"}]}"#)
+        .create();
+
+    let actual = nu!(pipeline(
+        format!(r#"graphql query {url} -q "{{ nope }}""#, url = server.url()).as_str()
+    ));
+
+    assert!(actual.err.contains("Field 'nope' doesn't exist"));
+}
+
+#[test]
+fn graphql_query_paginates_through_cursors() {
+    let mut server = mockito::Server::new();
+    let query = "query ($cursor: String) { items(after: $cursor) { nodes { id } pageInfo { hasNextPage endCursor } } }";
+
+    let _first = server
+        .mock("POST", "/")
+        .match_body(Matcher::Json(serde_json::json!({
+            "query": query,
+            "variables": {},
+        })))
+        .with_body(
+            r#"{"data": {"items": {
+                "nodes": [{"id": 1}, {"id": 2}],
+                "pageInfo": {"hasNextPage": true, "endCursor": "CURSOR-1"}
+            }}}"#,
+        )
+        .create();
+    let _second = server
+        .mock("POST", "/")
+        .match_body(Matcher::Json(serde_json::json!({
+            "query": query,
+            "variables": {"cursor": "CURSOR-1"},
+        })))
+        .with_body(
+            r#"{"data": {"items": {
+                "nodes": [{"id": 3}],
+                "pageInfo": {"hasNextPage": false, "endCursor": null}
+            }}}"#,
+        )
+        .create();
+
+    let actual = nu!(pipeline(
+        format!(
+            r#"graphql query {url} -q "{query}" --paginate items | get id | to nuon"#,
+            url = server.url()
+        )
+        .as_str()
+    ));
+
+    assert_eq!(actual.out, "[1, 2, 3]");
+}
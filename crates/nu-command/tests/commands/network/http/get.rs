@@ -139,3 +139,99 @@ fn http_get_self_signed_override() {
     let actual = nu!("http get --insecure https://self-signed.badssl.com/");
     assert!(actual.out.contains("<html>"));
 }
+
+#[test]
+fn http_get_decompresses_gzip_bodies() {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(b"hello from gzip").unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let mut server = Server::new();
+    let _mock = server
+        .mock("GET", "/")
+        .with_header("content-encoding", "gzip")
+        .with_body(compressed)
+        .create();
+
+    let actual = nu!(pipeline(
+        format!(
+            r#"
+        http get {url}
+        "#,
+            url = server.url()
+        )
+        .as_str()
+    ));
+
+    assert_eq!(actual.out, "hello from gzip");
+}
+
+#[test]
+fn http_get_manual_redirect_mode_returns_the_redirect() {
+    let mut server = Server::new();
+    let _mock = server
+        .mock("GET", "/")
+        .with_status(302)
+        .with_header("location", "https://example.com/")
+        .create();
+
+    let actual = nu!(pipeline(
+        format!(
+            r#"
+        http get --redirect-mode manual --full {url} | get status
+        "#,
+            url = server.url()
+        )
+        .as_str()
+    ));
+
+    assert_eq!(actual.out, "302");
+}
+
+#[test]
+fn http_get_error_redirect_mode_fails_on_redirects() {
+    let mut server = Server::new();
+    let _mock = server
+        .mock("GET", "/")
+        .with_status(301)
+        .with_header("location", "https://example.com/")
+        .create();
+
+    let actual = nu!(pipeline(
+        format!(
+            r#"
+        http get --redirect-mode error {url}
+        "#,
+            url = server.url()
+        )
+        .as_str()
+    ));
+
+    assert!(actual.err.contains("Redirect"), "err: {}", actual.err);
+}
+
+#[test]
+fn http_get_full_response_has_record_headers() {
+    let mut server = Server::new();
+    let _mock = server
+        .mock("GET", "/")
+        .with_header("x-custom", "abc")
+        .with_body("body")
+        .create();
+
+    let actual = nu!(pipeline(
+        format!(
+            r#"
+        http get --full {url} | get headers.x-custom
+        "#,
+            url = server.url()
+        )
+        .as_str()
+    ));
+
+    assert_eq!(actual.out, "abc");
+}
@@ -4,3 +4,5 @@ mod head;
 mod patch;
 mod post;
 mod put;
+mod session;
+mod token;
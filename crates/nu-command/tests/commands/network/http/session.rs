@@ -0,0 +1,97 @@
+use mockito::Server;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn session_cookies_persist_across_calls() {
+    let mut server = Server::new();
+
+    let _login = server
+        .mock("GET", "/login")
+        .with_header("set-cookie", "sid=abc; Path=/; HttpOnly")
+        .with_body("ok")
+        .create();
+    let _me = server
+        .mock("GET", "/me")
+        .match_header("cookie", "sid=abc")
+        .with_body("it's me")
+        .create();
+
+    Playground::setup("http_session_test_1", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            format!(
+                r#"
+                    $env.NU_HTTP_SESSIONS = "sessions.json";
+                    http get --session work {url}/login | ignore;
+                    http get --session work {url}/me
+                "#,
+                url = server.url()
+            )
+            .as_str()
+        ));
+
+        assert_eq!(actual.out, "it's me");
+    })
+}
+
+#[test]
+fn session_base_url_and_default_headers_apply() {
+    let mut server = Server::new();
+
+    let _mock = server
+        .mock("GET", "/data")
+        .match_header("x-app", "demo")
+        .with_body("data")
+        .create();
+
+    Playground::setup("http_session_test_2", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            format!(
+                r#"
+                    $env.NU_HTTP_SESSIONS = "sessions.json";
+                    http session work --base-url {url} --headers {{x-app: demo}};
+                    http get --session work /data
+                "#,
+                url = server.url()
+            )
+            .as_str()
+        ));
+
+        assert_eq!(actual.out, "data");
+    })
+}
+
+#[test]
+fn session_command_shows_the_configuration() {
+    Playground::setup("http_session_test_3", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                $env.NU_HTTP_SESSIONS = "sessions.json";
+                http session work --base-url https://api.example.com;
+                http session work | get base_url
+            "#
+        ));
+
+        assert_eq!(actual.out, "https://api.example.com");
+    })
+}
+
+#[test]
+fn dropped_sessions_are_gone_from_the_listing() {
+    Playground::setup("http_session_test_4", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                $env.NU_HTTP_SESSIONS = "sessions.json";
+                http session work --base-url https://api.example.com;
+                http session work --drop;
+                http session | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "0");
+    })
+}
@@ -0,0 +1,141 @@
+use mockito::Server;
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn http_get_sends_the_bearer_token() {
+    let mut server = Server::new();
+
+    let _mock = server
+        .mock("GET", "/")
+        .match_header("authorization", "Bearer shhh")
+        .with_body("authorized")
+        .create();
+
+    let actual = nu!(pipeline(
+        format!(
+            r#"
+        http get --bearer shhh {url}
+        "#,
+            url = server.url()
+        )
+        .as_str()
+    ));
+
+    assert_eq!(actual.out, "authorized");
+}
+
+#[test]
+fn http_token_returns_the_cached_token() {
+    Playground::setup("http_token_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "tokens.json",
+            r#"{"myapi": {"access_token": "cached", "expires_at": 9999999999}}"#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                http token myapi --cache-file tokens.json
+            "#
+        ));
+
+        assert_eq!(actual.out, "cached");
+    })
+}
+
+#[test]
+fn http_token_refreshes_an_expired_token() {
+    let mut server = Server::new();
+
+    let _mock = server
+        .mock("POST", "/token")
+        .with_body(r#"{"access_token": "fresh", "expires_in": 3600}"#)
+        .create();
+
+    Playground::setup("http_token_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "tokens.json",
+            &format!(
+                r#"{{"myapi": {{"access_token": "stale", "refresh_token": "r1", "expires_at": 1, "token_url": "{}/token"}}}}"#,
+                server.url()
+            ),
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                http token myapi --cache-file tokens.json
+            "#
+        ));
+        assert_eq!(actual.out, "fresh");
+
+        // The refreshed token should have been written back to the cache
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open tokens.json | get myapi.access_token
+            "#
+        ));
+        assert_eq!(actual.out, "fresh");
+    })
+}
+
+#[test]
+fn http_token_clear_forgets_the_entry() {
+    Playground::setup("http_token_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "tokens.json",
+            r#"{"myapi": {"access_token": "cached", "expires_at": 9999999999}}"#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                http token myapi --cache-file tokens.json --clear;
+                http token myapi --cache-file tokens.json
+            "#
+        ));
+
+        assert!(
+            actual.err.contains("no usable cached token"),
+            "err: {}",
+            actual.err
+        );
+    })
+}
+
+#[test]
+fn http_token_runs_the_device_flow() {
+    let mut server = Server::new();
+
+    let _device = server
+        .mock("POST", "/device")
+        .with_body(r#"{"device_code": "d1", "user_code": "ABCD", "verification_uri": "https://example.com/activate", "interval": 0}"#)
+        .create();
+    let _token = server
+        .mock("POST", "/token")
+        .with_body(r#"{"access_token": "granted", "refresh_token": "r2", "expires_in": 3600}"#)
+        .create();
+
+    Playground::setup("http_token_test_4", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            format!(
+                r#"
+                    http token myapi --cache-file tokens.json --client-id c1 --device-auth-url {url}/device --token-url {url}/token
+                "#,
+                url = server.url()
+            )
+            .as_str()
+        ));
+
+        assert_eq!(actual.out, "granted");
+        assert!(
+            actual.err.contains("example.com/activate"),
+            "err: {}",
+            actual.err
+        );
+    })
+}
@@ -1,2 +1,5 @@
+mod graphql;
 mod http;
+mod net;
 mod port;
+mod ws;
@@ -0,0 +1,82 @@
+use nu_test_support::{nu, pipeline};
+use std::io::{Read, Write};
+use std::net::{TcpListener, UdpSocket};
+
+#[test]
+fn net_connect_sends_input_and_streams_the_reply() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("failed to pick a port");
+    let port = listener.local_addr().unwrap().port();
+
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("no connection");
+        let mut request = Vec::new();
+        stream.read_to_end(&mut request).expect("request read");
+        let reply = format!("you said {}", String::from_utf8_lossy(&request));
+        stream.write_all(reply.as_bytes()).expect("reply write");
+    });
+
+    let actual = nu!(
+        cwd: ".", pipeline(&format!(
+        r#"
+            "hello" | net connect 127.0.0.1 {port} | decode utf-8
+        "#
+    )));
+
+    server.join().expect("server thread panicked");
+    assert_eq!(actual.out, "you said hello");
+}
+
+#[test]
+fn net_connect_rejects_out_of_range_ports() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            net connect 127.0.0.1 70000
+        "#
+    ));
+
+    assert!(
+        actual.err.contains("not a valid port number"),
+        "err: {}",
+        actual.err
+    );
+}
+
+#[test]
+fn net_send_udp_delivers_the_payload() {
+    let socket = UdpSocket::bind("127.0.0.1:0").expect("failed to pick a port");
+    let port = socket.local_addr().unwrap().port();
+
+    let receiver = std::thread::spawn(move || {
+        let mut datagram = [0u8; 128];
+        let (received, _) = socket.recv_from(&mut datagram).expect("no datagram");
+        String::from_utf8_lossy(&datagram[..received]).into_owned()
+    });
+
+    let actual = nu!(
+        cwd: ".", pipeline(&format!(
+        r#"
+            "hello over udp" | net send-udp 127.0.0.1 {port}
+        "#
+    )));
+
+    let received = receiver.join().expect("receiver thread panicked");
+    assert_eq!(received, "hello over udp");
+    assert_eq!(actual.out, "");
+}
+
+#[test]
+fn net_send_udp_requires_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            net send-udp 127.0.0.1 9999
+        "#
+    ));
+
+    assert!(
+        actual.err.contains("no input to send"),
+        "err: {}",
+        actual.err
+    );
+}
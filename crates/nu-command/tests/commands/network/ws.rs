@@ -0,0 +1,145 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use nu_test_support::{nu, pipeline};
+use std::io::{Read, Write};
+use std::net::TcpListener;
+
+#[test]
+fn ws_connect_sends_input_and_streams_the_echo() {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("failed to pick a port");
+    let port = listener.local_addr().unwrap().port();
+
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("no connection");
+
+        // Handshake: read the request, answer with the accept key.
+        let mut request = Vec::new();
+        let mut byte = [0u8; 1];
+        while !request.ends_with(b"\r\n\r\n") {
+            stream.read_exact(&mut byte).expect("handshake read");
+            request.push(byte[0]);
+        }
+        let request = String::from_utf8_lossy(&request).into_owned();
+        let key = request
+            .lines()
+            .find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                name.trim()
+                    .eq_ignore_ascii_case("Sec-WebSocket-Key")
+                    .then(|| value.trim().to_string())
+            })
+            .expect("no Sec-WebSocket-Key header");
+        let accept = STANDARD.encode(sha1(
+            format!("{key}258EAFA5-E914-47DA-95CA-C5AB0DC85B11").as_bytes(),
+        ));
+        stream
+            .write_all(
+                format!(
+                    "HTTP/1.1 101 Switching Protocols\r\n\
+                     Upgrade: websocket\r\n\
+                     Connection: Upgrade\r\n\
+                     Sec-WebSocket-Accept: {accept}\r\n\r\n"
+                )
+                .as_bytes(),
+            )
+            .expect("handshake write");
+
+        // Read one small masked text frame and echo it back unmasked.
+        let mut header = [0u8; 2];
+        stream.read_exact(&mut header).expect("frame header");
+        assert_eq!(header[0], 0x81, "expected a final text frame");
+        let length = (header[1] & 0x7f) as usize;
+        let mut mask = [0u8; 4];
+        stream.read_exact(&mut mask).expect("frame mask");
+        let mut payload = vec![0u8; length];
+        stream.read_exact(&mut payload).expect("frame payload");
+        for (at, byte) in payload.iter_mut().enumerate() {
+            *byte ^= mask[at % 4];
+        }
+
+        let mut echo = vec![0x81, length as u8];
+        echo.extend_from_slice(&payload);
+        stream.write_all(&echo).expect("echo write");
+        let _ = stream.write_all(&[0x88, 0x02, 0x03, 0xe8]);
+    });
+
+    let actual = nu!(
+        cwd: ".", pipeline(&format!(
+        r#"
+            "hello" | ws connect ws://127.0.0.1:{port} -n 1 | first
+        "#
+    )));
+
+    server.join().expect("server thread panicked");
+    assert_eq!(actual.out, "hello");
+}
+
+#[test]
+fn ws_connect_rejects_other_schemes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            ws connect https://example.com
+        "#
+    ));
+
+    assert!(
+        actual.err.contains("unsupported scheme"),
+        "err: {}",
+        actual.err
+    );
+}
+
+// SHA-1 as in RFC 3174, enough to build the Sec-WebSocket-Accept key.
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    for chunk in message.chunks(64) {
+        let mut w = [0u32; 80];
+        for (at, word) in chunk.chunks(4).enumerate() {
+            w[at] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for at in 16..80 {
+            w[at] = (w[at - 3] ^ w[at - 8] ^ w[at - 14] ^ w[at - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (at, word) in w.iter().enumerate() {
+            let (f, k) = match at {
+                0..=19 => ((b & c) | (!b & d), 0x5a827999),
+                20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        state[0] = state[0].wrapping_add(a);
+        state[1] = state[1].wrapping_add(b);
+        state[2] = state[2].wrapping_add(c);
+        state[3] = state[3].wrapping_add(d);
+        state[4] = state[4].wrapping_add(e);
+    }
+
+    let mut digest = [0u8; 20];
+    for (at, word) in state.iter().enumerate() {
+        digest[at * 4..at * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
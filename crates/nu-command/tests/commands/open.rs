@@ -300,3 +300,133 @@ fn open_no_parameter() {
 
     assert!(actual.err.contains("needs filename"));
 }
+
+#[test]
+fn open_extensionless_file_detects_json() {
+    use nu_test_support::fs::Stub::FileWithContent;
+
+    Playground::setup("open_test_detect_json", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("payload", r#"{"name": "nushell"}"#)]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open payload | get name"
+        );
+
+        assert_eq!(actual.out, "nushell");
+    })
+}
+
+#[test]
+fn open_extensionless_file_without_known_format_stays_raw() {
+    use nu_test_support::fs::Stub::FileWithContent;
+
+    Playground::setup("open_test_detect_raw", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("gitconfig", "[core]\nbare = false\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open gitconfig | describe"
+        );
+
+        assert_eq!(actual.out, "raw input");
+    })
+}
+
+#[test]
+fn open_with_file_formats_config() {
+    use nu_test_support::fs::Stub::FileWithContent;
+    use nu_test_support::nu_repl_code;
+
+    Playground::setup("open_test_file_formats", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("sample.data", r#"{"a": 9}"#)]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            nu_repl_code(&[
+                r#"let-env config = { file_formats: { data: "json" } }"#,
+                "open sample.data | get a",
+            ])
+        );
+
+        assert_eq!(actual.out, "9");
+    })
+}
+
+#[test]
+fn open_follow_streams_the_existing_lines() {
+    use nu_test_support::fs::Stub::FileWithContent;
+
+    Playground::setup("open_follow_existing", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("app.log", "one\ntwo\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open --follow app.log | first 2 | str join ','"
+        );
+
+        assert_eq!(actual.out, "one,two");
+    })
+}
+
+#[test]
+fn open_follow_picks_up_appended_lines() {
+    use nu_test_support::fs::Stub::FileWithContent;
+    use std::io::Write;
+
+    Playground::setup("open_follow_append", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("app.log", "one\n")]);
+
+        let log = dirs.test().join("app.log");
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            let mut file = std::fs::OpenOptions::new()
+                .append(true)
+                .open(log)
+                .expect("could not open the log for appending");
+            writeln!(file, "two").expect("could not append to the log");
+        });
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open --follow app.log | first 2 | last"
+        );
+
+        writer.join().expect("the writer thread panicked");
+        assert_eq!(actual.out, "two");
+    })
+}
+
+#[test]
+fn open_follow_detects_rotation() {
+    use nu_test_support::fs::Stub::FileWithContent;
+
+    Playground::setup("open_follow_rotation", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("app.log", "one\n")]);
+
+        let log = dirs.test().join("app.log");
+        let rotated = dirs.test().join("app.log.1");
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            std::fs::rename(&log, rotated).expect("could not rotate the log");
+            std::fs::write(&log, "fresh\n").expect("could not write the new log");
+        });
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "open --follow app.log | first 2 | last"
+        );
+
+        writer.join().expect("the writer thread panicked");
+        assert_eq!(actual.out, "fresh");
+    })
+}
+
+#[test]
+fn open_follow_errors_on_a_missing_file() {
+    Playground::setup("open_follow_missing", |dirs, _sandbox| {
+        let actual = nu!(cwd: dirs.test(), "open --follow missing.log");
+
+        assert!(actual.err.contains("Could not open"));
+    })
+}
@@ -0,0 +1,201 @@
+use std::path::Path;
+
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, nu_repl_code};
+
+fn commit_all(repo: &Path) {
+    for args in [
+        vec!["init", "-q"],
+        vec!["add", "."],
+        vec![
+            "-c",
+            "user.name=nu",
+            "-c",
+            "user.email=nu@nushell.sh",
+            "commit",
+            "-q",
+            "-m",
+            "init",
+        ],
+    ] {
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(&args)
+            .status()
+            .expect("failed to run git");
+        assert!(status.success(), "git {args:?} failed in {repo:?}");
+    }
+}
+
+#[test]
+fn pkg_install_records_lockfile_and_dependencies() {
+    Playground::setup("pkg_install_test_1", |dirs, sandbox| {
+        sandbox
+            .mkdir("pkgs")
+            .mkdir("repos/eggs")
+            .mkdir("repos/spam")
+            .with_files(vec![
+                FileWithContent(
+                    "repos/eggs/eggs.nu",
+                    "export def scramble [] { 'scrambled' }",
+                ),
+                FileWithContent("repos/eggs/package.nuon", r#"{ version: "0.1.0" }"#),
+                FileWithContent("repos/spam/spam.nu", "export def foo [] { 'foo' }"),
+            ])
+            .with_files(vec![FileWithContent(
+                "repos/spam/package.nuon",
+                &format!(
+                    r#"{{ version: "1.2.3", dependencies: {{ eggs: "{}" }} }}"#,
+                    dirs.test().join("repos/eggs").display()
+                ),
+            )]);
+        commit_all(&dirs.test().join("repos/eggs"));
+        commit_all(&dirs.test().join("repos/spam"));
+
+        let set_dir = format!(
+            "let-env NU_PKG_DIR = '{}'",
+            dirs.test().join("pkgs").display()
+        );
+        let install = format!(
+            "pkg install '{}' | length",
+            dirs.test().join("repos/spam").display()
+        );
+        let inp = &[
+            set_dir.as_str(),
+            install.as_str(),
+            "pkg list | get name | str join ','",
+        ];
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+        assert_eq!(actual.out, "eggs,spam");
+        assert!(dirs.test().join("pkgs/spam/spam.nu").exists());
+        assert!(dirs.test().join("pkgs/eggs/eggs.nu").exists());
+        assert!(dirs.test().join("pkgs/pkg.lock").exists());
+    })
+}
+
+#[test]
+fn pkg_install_twice_errors_without_force() {
+    Playground::setup("pkg_install_test_2", |dirs, sandbox| {
+        sandbox
+            .mkdir("pkgs")
+            .mkdir("repos/spam")
+            .with_files(vec![FileWithContent(
+                "repos/spam/spam.nu",
+                "export def foo [] { 'foo' }",
+            )]);
+        commit_all(&dirs.test().join("repos/spam"));
+
+        let repo = dirs.test().join("repos/spam");
+        let set_dir = format!(
+            "let-env NU_PKG_DIR = '{}'",
+            dirs.test().join("pkgs").display()
+        );
+        let install = format!("pkg install '{}' | length", repo.display());
+        let reinstall = format!("pkg install '{}'", repo.display());
+        let inp = &[set_dir.as_str(), install.as_str(), reinstall.as_str()];
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+        assert!(actual.err.contains("already installed"));
+    })
+}
+
+#[test]
+fn pkg_install_rejects_a_url_ending_in_dot_dot() {
+    Playground::setup("pkg_install_test_3", |dirs, sandbox| {
+        sandbox.mkdir("pkgs");
+
+        // '..' as a package name would make the install target the parent of
+        // the package root, which `--force` would then delete
+        let set_dir = format!(
+            "let-env NU_PKG_DIR = '{}'",
+            dirs.test().join("pkgs").display()
+        );
+        let inp = &[
+            set_dir.as_str(),
+            "pkg install --force https://example.com/foo/..",
+        ];
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+        assert!(actual.err.contains("Cannot derive a package name"));
+        assert!(dirs.test().join("pkgs").exists());
+    })
+}
+
+#[test]
+fn pkg_remove_deletes_package_and_lockfile_entry() {
+    Playground::setup("pkg_remove_test_1", |dirs, sandbox| {
+        sandbox
+            .mkdir("pkgs")
+            .mkdir("repos/spam")
+            .with_files(vec![FileWithContent(
+                "repos/spam/spam.nu",
+                "export def foo [] { 'foo' }",
+            )]);
+        commit_all(&dirs.test().join("repos/spam"));
+
+        let set_dir = format!(
+            "let-env NU_PKG_DIR = '{}'",
+            dirs.test().join("pkgs").display()
+        );
+        let install = format!(
+            "pkg install '{}' | length",
+            dirs.test().join("repos/spam").display()
+        );
+        let inp = &[
+            set_dir.as_str(),
+            install.as_str(),
+            "pkg remove spam",
+            "pkg list | length",
+        ];
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+        assert_eq!(actual.out, "0");
+        assert!(!dirs.test().join("pkgs/spam").exists());
+    })
+}
+
+#[test]
+fn pkg_remove_unknown_package_errors() {
+    Playground::setup("pkg_remove_test_2", |dirs, sandbox| {
+        sandbox.mkdir("pkgs");
+
+        let set_dir = format!(
+            "let-env NU_PKG_DIR = '{}'",
+            dirs.test().join("pkgs").display()
+        );
+        let inp = &[set_dir.as_str(), "pkg remove spam"];
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+        assert!(actual.err.contains("not installed"));
+    })
+}
+
+#[test]
+fn pkg_install_wires_packages_into_lib_dirs() {
+    Playground::setup("pkg_use_test_1", |dirs, sandbox| {
+        sandbox
+            .mkdir("pkgs")
+            .mkdir("repos/spam")
+            .with_files(vec![FileWithContent(
+                "repos/spam/spam.nu",
+                "export def foo [] { 'foo from spam' }",
+            )]);
+        commit_all(&dirs.test().join("repos/spam"));
+
+        let set_dir = format!(
+            "let-env NU_PKG_DIR = '{}'",
+            dirs.test().join("pkgs").display()
+        );
+        let install = format!(
+            "pkg install '{}' | length",
+            dirs.test().join("repos/spam").display()
+        );
+        let inp = &[set_dir.as_str(), install.as_str(), "use spam.nu foo", "foo"];
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+        assert_eq!(actual.out, "foo from spam");
+    })
+}
@@ -3,7 +3,33 @@ use nu_test_support::nu;
 #[test]
 fn test_kill_invalid_pid() {
     let pid = i32::MAX;
-    let actual = nu!(format!("kill {pid}"));
+    let actual = nu!(format!("kill {pid} | get 0.success"));
 
-    assert!(actual.err.contains("process didn't terminate successfully"));
+    assert_eq!(actual.out, "false");
+}
+
+#[cfg(unix)]
+#[test]
+fn reports_the_error_per_target() {
+    let pid = i32::MAX;
+    let actual = nu!(format!("kill {pid} | get 0.error"));
+
+    assert_eq!(actual.out, "no such process");
+}
+
+#[cfg(unix)]
+#[test]
+fn sends_a_signal_by_name() {
+    // CHLD is ignored by default, so the shell signals itself unharmed
+    let actual = nu!("kill -s chld $nu.pid | get 0.success");
+
+    assert_eq!(actual.out, "true");
+}
+
+#[cfg(unix)]
+#[test]
+fn rejects_an_unknown_signal_name() {
+    let actual = nu!("kill -s bogus 12345");
+
+    assert!(actual.err.contains("unknown signal name 'bogus'"));
 }
@@ -0,0 +1,51 @@
+use nu_test_support::nu;
+
+#[test]
+fn profile_reports_time_allocs_and_rows() {
+    let actual = nu!(
+        cwd: ".",
+        "profile {|| [3 1 2] | sort } | columns | str join ','"
+    );
+
+    assert_eq!(
+        actual.out,
+        "pipeline_idx,element_idx,depth,span,time,allocs,rows"
+    );
+}
+
+#[test]
+fn profile_rows_counts_list_output() {
+    let actual = nu!(cwd: ".", "profile {|| [3 1 2] | first 2 } | get rows.0");
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn profile_summary_aggregates_by_source() {
+    let actual = nu!(
+        cwd: ".",
+        "profile {|| [3 1 2] | sort | sort } --summary | where source == '| sort' | get calls.0"
+    );
+
+    assert_eq!(actual.out, "2");
+}
+
+#[test]
+fn profile_summary_percentages_add_up() {
+    let actual = nu!(
+        cwd: ".",
+        "profile {|| [3 1 2] | sort | first 2 } --summary | get percent | math sum | math round"
+    );
+
+    assert_eq!(actual.out, "100");
+}
+
+#[test]
+fn profile_folded_writes_one_stack_per_element() {
+    let actual = nu!(
+        cwd: ".",
+        r#"def spam [] { [1 2] | reverse }; profile {|| spam } -d 2 --folded | lines | get 1 | str replace ' \d+$' ''"#
+    );
+
+    assert_eq!(actual.out, "spam;[1 2]");
+}
@@ -0,0 +1,73 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn literal_path_returns_the_single_value() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            {a: {b: [10 20]}} | query json '$.a.b[-1]'
+        "#
+    ));
+
+    assert_eq!(actual.out, "20");
+}
+
+#[test]
+fn wildcard_collects_every_match() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [{name: a, price: 1} {name: b, price: 2}] | query json '$[*].price' | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1, 2]");
+}
+
+#[test]
+fn recursive_descent_finds_nested_fields() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            {a: {id: 1, b: {id: 2}}} | query json '$..id' | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1, 2]");
+}
+
+#[test]
+fn string_input_is_parsed_as_json() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '{"a": [1, 2, 3]}' | query json '$.a[1:]' | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "[2, 3]");
+}
+
+#[test]
+fn missing_literal_path_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            {a: 1} | query json '$.b'
+        "#
+    ));
+
+    assert!(actual.err.contains("query matched nothing"));
+}
+
+#[test]
+fn malformed_query_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            {a: 1} | query json '$[1'
+        "#
+    ));
+
+    assert!(actual.err.contains("invalid JSONPath query"));
+}
@@ -1 +1,5 @@
+#[cfg(feature = "sqlite")]
 mod db;
+mod json;
+mod web;
+mod xml;
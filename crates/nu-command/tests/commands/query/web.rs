@@ -0,0 +1,55 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn selector_query_collects_element_text() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '<ul><li>Coffee</li><li>Tea</li></ul>' | query web --query li | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "[Coffee, Tea]");
+}
+
+#[test]
+fn attribute_flag_reads_the_attribute() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '<p><a href="https://example.org">site</a></p>'
+            | query web --query a --attribute href
+            | get 0
+        "#
+    ));
+
+    assert_eq!(actual.out, "https://example.org");
+}
+
+#[test]
+fn as_table_parses_a_html_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '<table><tr><th>Name</th><th>Age</th></tr><tr><td>May</td><td>8</td></tr></table>'
+            | query web --as-table [Name Age]
+            | get 0.Age
+        "#
+    ));
+
+    assert_eq!(actual.out, "8");
+}
+
+#[test]
+fn invalid_selector_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '<p>hi</p>' | query web --query '!!!'
+        "#
+    ));
+
+    assert!(actual
+        .err
+        .contains("Cannot parse this query as a valid CSS selector"));
+}
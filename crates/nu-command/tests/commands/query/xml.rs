@@ -0,0 +1,82 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn text_selector_collects_every_match() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '<a><b>1</b><b>2</b></a>' | query xml '//b/text()' | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["1", "2"]"#);
+}
+
+#[test]
+fn attribute_selector_reads_the_attribute() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '<a><b x="5"/></a>' | query xml '/a/b/@x' | get 0
+        "#
+    ));
+
+    assert_eq!(actual.out, "5");
+}
+
+#[test]
+fn elements_come_back_in_the_from_xml_shape() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '<a><b>hi</b></a>' | query xml '/a/b' | get 0.content.0.content
+        "#
+    ));
+
+    assert_eq!(actual.out, "hi");
+}
+
+#[test]
+fn indexes_are_one_based() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '<a><b>1</b><b>2</b><b>3</b></a>' | query xml '/a/b[3]/text()' | get 0
+        "#
+    ));
+
+    assert_eq!(actual.out, "3");
+}
+
+#[test]
+fn stream_mode_emits_matches_per_fragment() {
+    Playground::setup("query_xml_stream", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "export.xml",
+            "<dump><record><id>1</id></record><record><id>2</id></record><record><id>3</id></record></dump>",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open --raw export.xml | query xml --stream '//record/id/text()' | to nuon
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"["1", "2", "3"]"#);
+    })
+}
+
+#[test]
+fn malformed_query_errors() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '<a/>' | query xml 'a/b'
+        "#
+    ));
+
+    assert!(actual.err.contains("invalid XPath query"));
+}
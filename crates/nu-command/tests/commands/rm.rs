@@ -337,3 +337,19 @@ fn remove_ignores_ansi() {
         assert_eq!(actual.out, "true");
     });
 }
+
+#[test]
+fn dry_run_reports_without_deleting() {
+    Playground::setup("rm_test_dry_run", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("a.txt"), EmptyFile("b.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "rm --dry-run *.txt | get target | path basename | str join ','"
+        );
+
+        assert_eq!(actual.out, "a.txt,b.txt");
+        assert!(dirs.test().join("a.txt").exists());
+        assert!(dirs.test().join("b.txt").exists());
+    })
+}
@@ -0,0 +1,51 @@
+use nu_test_support::nu;
+
+#[test]
+fn appends_the_whole_input_as_one_batch() {
+    let actual = nu!(
+        cwd: ".",
+        "[a b c] | run-batched nu '--testbin' cococo | to json --raw"
+    );
+
+    assert_eq!(actual.out, r#"["a b c"]"#);
+}
+
+#[test]
+fn batch_size_splits_the_invocations() {
+    let actual = nu!(
+        cwd: ".",
+        "[a b c] | run-batched -n 2 nu '--testbin' cococo | to json --raw"
+    );
+
+    assert_eq!(actual.out, r#"["a b","c"]"#);
+}
+
+#[test]
+fn placeholder_replaces_the_matching_argument() {
+    let actual = nu!(
+        cwd: ".",
+        "[a b] | run-batched -n 1 -I '{}' nu '--testbin' cococo pre '{}' post | to json --raw"
+    );
+
+    assert_eq!(actual.out, r#"["pre a post","pre b post"]"#);
+}
+
+#[test]
+fn placeholder_must_appear_in_the_arguments() {
+    let actual = nu!(
+        cwd: ".",
+        "[a] | run-batched -I '{}' nu '--testbin' cococo"
+    );
+
+    assert!(actual.err.contains("Placeholder not found"));
+}
+
+#[test]
+fn max_procs_keeps_the_output_in_input_order() {
+    let actual = nu!(
+        cwd: ".",
+        "[a b c d] | run-batched -n 1 -P 2 nu '--testbin' cococo | to json --raw"
+    );
+
+    assert_eq!(actual.out, r#"["a","b","c","d"]"#);
+}
@@ -301,3 +301,40 @@ fn writes_out_range() {
         assert_eq!(actual, "[\n  1,\n  2,\n  3\n]")
     })
 }
+
+#[test]
+fn save_with_file_formats_config() {
+    use nu_test_support::nu_repl_code;
+
+    Playground::setup("save_test_file_formats", |dirs, sandbox| {
+        sandbox.with_files(vec![]);
+
+        let expected_file = dirs.test().join("sample.data");
+
+        nu!(
+            cwd: dirs.root(),
+            nu_repl_code(&[
+                r#"let-env config = { file_formats: { data: "json" } }"#,
+                "[[x]; [3]] | save save_test_file_formats/sample.data",
+            ])
+        );
+
+        let actual = file_contents(expected_file);
+        assert!(actual.contains("\"x\": 3"));
+    })
+}
+
+#[test]
+fn dry_run_reports_without_writing() {
+    Playground::setup("save_test_dry_run", |dirs, sandbox| {
+        sandbox.with_files(vec![]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "'hello' | save --dry-run out.txt | get 0.target | path basename"
+        );
+
+        assert_eq!(actual.out, "out.txt");
+        assert!(!dirs.test().join("out.txt").exists());
+    })
+}
@@ -0,0 +1,52 @@
+use nu_test_support::nu;
+
+#[test]
+fn secret_wrap_redacts_table_output() {
+    let actual = nu!(
+        cwd: ".",
+        "{user: bob, pass: ('hunter2' | secret wrap)} | to md"
+    );
+
+    assert!(actual.out.contains("<redacted>"));
+    assert!(!actual.out.contains("hunter2"));
+}
+
+#[test]
+fn secret_wrap_redacts_string_interpolation() {
+    let actual = nu!(
+        cwd: ".",
+        r#"let s = ('hunter2' | secret wrap); $"pw=($s)""#
+    );
+
+    assert_eq!(actual.out, "pw=<redacted>");
+}
+
+#[test]
+fn secret_unwrap_reveals_the_string() {
+    let actual = nu!(
+        cwd: ".",
+        "'hunter2' | secret wrap | secret unwrap"
+    );
+
+    assert_eq!(actual.out, "hunter2");
+}
+
+#[test]
+fn secret_unwrap_rejects_other_types() {
+    let actual = nu!(
+        cwd: ".",
+        "42 | secret unwrap"
+    );
+
+    assert!(actual.err.contains("only_supports_this_input_type"));
+}
+
+#[test]
+fn secret_get_from_rejects_unknown_stores() {
+    let actual = nu!(
+        cwd: ".",
+        "secret get-from vault my-password"
+    );
+
+    assert!(actual.err.contains("Unsupported secret store"));
+}
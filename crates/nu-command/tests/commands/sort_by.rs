@@ -133,3 +133,72 @@ fn fail_on_non_iterator() {
 
     assert!(actual.err.contains("only_supports_this_input_type"));
 }
+
+#[test]
+fn sorts_versions_with_the_version_comparator() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[ver]; [1.10.0] [1.2.0] [1.2.0-rc.1]]
+            | sort-by ver --version
+            | get ver
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, r#"["1.2.0-rc.1", "1.2.0", "1.10.0"]"#);
+}
+
+#[test]
+fn sorts_accented_strings_with_the_locale_comparator() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[word]; [zebra] [éclair] [apple]]
+            | sort-by word --locale
+            | get word
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "[apple, éclair, zebra]");
+}
+
+#[test]
+fn picks_a_comparator_per_column() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[pkg ver]; [b 1.10.0] [a 1.2.0]]
+            | sort-by {column: ver, comparator: version}
+            | get pkg
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "[a, b]");
+}
+
+#[test]
+fn comparator_flags_are_mutually_exclusive() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[a]; [1]] | sort-by a --natural --version
+        "#
+    ));
+
+    assert!(actual.err.contains("Incompatible flags"));
+}
+
+#[test]
+fn unknown_comparator_fails() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [[a]; [1]] | sort-by {column: a, comparator: bogus}
+        "#
+    ));
+
+    assert!(actual.err.contains("not a comparator"));
+}
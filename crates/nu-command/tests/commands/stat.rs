@@ -0,0 +1,41 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn stat_reports_file_metadata() {
+    Playground::setup("stat_file", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("file.txt", "123")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                stat file.txt
+                | select type size
+                | to nuon
+            "#
+        ));
+
+        assert_eq!(actual.out, "{type: file, size: 3b}");
+    })
+}
+
+#[test]
+fn stat_reports_a_directory() {
+    Playground::setup("stat_dir", |dirs, sandbox| {
+        sandbox.mkdir("sub");
+
+        let actual = nu!(cwd: dirs.test(), "stat sub | get type");
+
+        assert_eq!(actual.out, "dir");
+    })
+}
+
+#[test]
+fn stat_errors_on_a_missing_path() {
+    Playground::setup("stat_missing", |dirs, _sandbox| {
+        let actual = nu!(cwd: dirs.test(), "stat missing.txt");
+
+        assert!(actual.err.contains("Could not stat"));
+    })
+}
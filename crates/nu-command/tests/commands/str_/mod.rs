@@ -376,6 +376,54 @@ fn str_reverse() {
     assert!(actual.out.contains("llehsun"));
 }
 
+#[test]
+fn str_reverse_with_grapheme_clusters_keeps_combined_characters() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "🇯🇵ほげ" | str reverse -g
+        "#
+    ));
+
+    assert_eq!(actual.out, "げほ🇯🇵");
+}
+
+#[test]
+fn str_length_with_code_points() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "🇯🇵ほげ" | str length -c
+        "#
+    ));
+
+    assert_eq!(actual.out, "4");
+}
+
+#[test]
+fn str_substring_with_code_points() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "🇯🇵ほげ" | str substring -c 2..4
+        "#
+    ));
+
+    assert_eq!(actual.out, "ほげ");
+}
+
+#[test]
+fn str_length_rejects_multiple_segmentation_flags() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+        echo "hello" | str length -g -c
+        "#
+    ));
+
+    assert!(actual.err.contains("Incompatible flags"));
+}
+
 #[test]
 fn test_redirection_trim() {
     let actual = nu!(
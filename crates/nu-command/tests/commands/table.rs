@@ -1795,6 +1795,82 @@ fn table_expande_with_no_header_internally_1() {
     );
 }
 
+#[test]
+fn table_custom_theme() {
+    let actual = nu!(nu_repl_code(&[
+        r#"let-env config = { table: { mode: custom, theme: {
+            top: '=', bottom: '=', left: '|', right: '|', vertical: '|', horizontal: '-',
+            top_left: '+', top_right: '+', bottom_left: '+', bottom_right: '+',
+            top_intersection: '+', bottom_intersection: '+',
+            left_intersection: '+', right_intersection: '+', inner_intersection: '+',
+        } } };"#,
+        "[[a b]; [1 2] [3 4]] | table"
+    ]));
+    assert_eq!(
+        actual.out,
+        "+===+===+===+\
+         | # | a | b |\
+         +---+---+---+\
+         | 0 | 1 | 2 |\
+         | 1 | 3 | 4 |\
+         +===+===+===+"
+    );
+}
+
+#[test]
+fn table_column_format_rules() {
+    let actual = nu!(nu_repl_code(&[
+        r#"let-env config = { table: { columns: {
+            name: { max_width: 8, ellipsis: middle, alignment: right },
+            price: { precision: 2 },
+        } } };"#,
+        "[[name price]; [veryverylongname 3.14159] [short 9.5]] | table"
+    ]));
+    assert_eq!(
+        actual.out,
+        "╭───┬──────────┬───────╮\
+         │ # │   name   │ price │\
+         ├───┼──────────┼───────┤\
+         │ 0 │ ver...me │  3.14 │\
+         │ 1 │    short │  9.50 │\
+         ╰───┴──────────┴───────╯"
+    );
+}
+
+#[test]
+fn table_tree_view() {
+    let actual = nu!(cwd: ".", "{a: 1, b: {c: 2, d: [3 4]}} | table --tree");
+    assert_eq!(
+        actual.out,
+        join_lines([
+            "├── a: 1",
+            "└── b",
+            "    ├── c: 2",
+            "    └── d",
+            "        ├── 0: 3",
+            "        └── 1: 4",
+        ])
+    );
+}
+
+#[test]
+fn table_tree_view_from_config() {
+    let actual = nu!(nu_repl_code(&[
+        "let-env config = { table: { mode: tree } };",
+        "[[a b]; [1 [2 3]]] | table"
+    ]));
+    assert_eq!(
+        actual.out,
+        join_lines([
+            "└── 0",
+            "    ├── a: 1",
+            "    └── b",
+            "        ├── 0: 2",
+            "        └── 1: 3",
+        ])
+    );
+}
+
 fn join_lines(lines: impl IntoIterator<Item = impl AsRef<str>>) -> String {
     lines
         .into_iter()
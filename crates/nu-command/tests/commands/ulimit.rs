@@ -0,0 +1,43 @@
+#![cfg(unix)]
+
+use nu_test_support::nu;
+
+#[test]
+fn lists_all_limits_as_a_table() {
+    let actual = nu!(
+        cwd: ".",
+        "ulimit | where name == nofile | length"
+    );
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn lowered_soft_limit_applies_to_the_shell() {
+    let actual = nu!(
+        cwd: ".",
+        "ulimit nofile 128; (ulimit nofile).soft"
+    );
+
+    assert_eq!(actual.out, "128");
+}
+
+#[test]
+fn soft_limit_cannot_exceed_the_hard_limit() {
+    let actual = nu!(
+        cwd: ".",
+        "ulimit nofile 64 --hard; ulimit nofile 128"
+    );
+
+    assert!(actual.err.contains("Failed to set the resource limit"));
+}
+
+#[test]
+fn rejects_an_unknown_limit_name() {
+    let actual = nu!(
+        cwd: ".",
+        "ulimit turbo"
+    );
+
+    assert!(actual.err.contains("Unknown resource limit"));
+}
@@ -0,0 +1,31 @@
+#![cfg(unix)]
+
+use nu_test_support::nu;
+
+#[test]
+fn users_lists_the_root_account() {
+    let actual = nu!(cwd: ".", "users | where uid == 0 | get 0.name");
+
+    assert_eq!(actual.out, "root");
+}
+
+#[test]
+fn groups_has_a_members_column() {
+    let actual = nu!(cwd: ".", "groups | columns | str join ' '");
+
+    assert_eq!(actual.out, "name gid members");
+}
+
+#[test]
+fn whoami_matches_the_full_record() {
+    let actual = nu!(cwd: ".", "(whoami) == (whoami --full).name");
+
+    assert_eq!(actual.out, "true");
+}
+
+#[test]
+fn whoami_full_includes_the_home_directory() {
+    let actual = nu!(cwd: ".", "(whoami --full).home | is-empty");
+
+    assert_eq!(actual.out, "false");
+}
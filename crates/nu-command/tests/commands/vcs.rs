@@ -0,0 +1,73 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn status_reports_untracked_files_as_new() {
+    Playground::setup("vcs_status_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("readme.md", "hello\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                git init -q
+                ; vcs status
+                | where name == readme.md
+                | get 0.worktree
+            "#
+        ));
+
+        assert_eq!(actual.out, "new");
+    })
+}
+
+#[test]
+fn log_lists_commits_with_author_and_message() {
+    Playground::setup("vcs_log_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("readme.md", "hello\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                git init -q
+                ; git add readme.md
+                ; git -c user.name=tester -c user.email=tester@example.com commit -q -m initial
+                ; vcs log
+                | get 0
+                | $"($in.author) ($in.message | str trim)"
+            "#
+        ));
+
+        assert_eq!(actual.out, "tester initial");
+    })
+}
+
+#[test]
+fn blame_names_the_commit_that_added_a_line() {
+    Playground::setup("vcs_blame_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("readme.md", "hello\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                git init -q
+                ; git add readme.md
+                ; git -c user.name=tester -c user.email=tester@example.com commit -q -m initial
+                ; vcs blame readme.md
+                | get 0
+                | $"($in.line) ($in.author) ($in.text)"
+            "#
+        ));
+
+        assert_eq!(actual.out, "1 tester hello");
+    })
+}
+
+#[test]
+fn status_outside_a_repository_errors() {
+    Playground::setup("vcs_status_test_2", |dirs, _sandbox| {
+        let actual = nu!(cwd: dirs.test(), "vcs status");
+
+        assert!(actual.err.contains("git error"));
+    })
+}
@@ -0,0 +1,74 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn verify_files_reports_pass_fail_and_missing() {
+    Playground::setup("verify_files_table", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContent("good.txt", "hello world\n"),
+            FileWithContent("bad.txt", "tampered\n"),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                [[path hash];
+                 [good.txt (open good.txt | hash sha256)]
+                 [bad.txt "0000000000000000000000000000000000000000000000000000000000000000"]
+                 [missing.txt "0000000000000000000000000000000000000000000000000000000000000000"]]
+                | verify files
+                | get ok
+                | to json -r
+            "#
+        ));
+
+        assert_eq!(actual.out, "[true,false,false]");
+    })
+}
+
+#[test]
+fn verify_files_missing_file_reports_the_error() {
+    Playground::setup("verify_files_missing", |dirs, _sandbox| {
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                [[path hash];
+                 [missing.txt "0000000000000000000000000000000000000000000000000000000000000000"]]
+                | verify files
+                | get 0.error
+                | is-empty
+            "#
+        ));
+
+        assert_eq!(actual.out, "false");
+    })
+}
+
+#[test]
+fn verify_files_reads_a_checksum_file() {
+    Playground::setup("verify_files_checksums", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContent("good.txt", "hello world\n"),
+            FileWithContent(
+                "checksums.md5",
+                "6f5902ac237024bdd0c176cb93063dc4  good.txt\n\
+                 00000000000000000000000000000000 *missing.txt\n",
+            ),
+        ]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "verify files checksums.md5 --algorithm md5 | get ok | to json -r"
+        );
+
+        assert_eq!(actual.out, "[true,false]");
+    })
+}
+
+#[test]
+fn verify_files_rejects_an_unknown_algorithm() {
+    let actual = nu!(cwd: ".", "[[path hash]; [a.txt abc]] | verify files --algorithm sha1");
+
+    assert!(actual.err.contains("Unknown hash algorithm sha1"));
+}
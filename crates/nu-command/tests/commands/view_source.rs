@@ -0,0 +1,45 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn view_source_alias() {
+    let actual = nu!(cwd: ".", "alias hello = echo hi; view source hello");
+
+    assert_eq!(actual.out, "alias hello = echo hi");
+}
+
+#[test]
+fn view_source_extern() {
+    let actual = nu!(cwd: ".", "extern foo [a: string]; view source foo");
+
+    assert!(actual.out.contains("extern foo"));
+    assert!(actual.out.contains("a:string"));
+}
+
+#[test]
+fn view_source_record_has_file_and_line() {
+    Playground::setup("view_source_record", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "greet.nu",
+            "# dummy first line\ndef greet [name: string] { $\"hi ($name)\" }\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"source greet.nu; view source --record greet | $"($in.filename):($in.start_line)""#
+        );
+
+        assert_eq!(actual.out, "greet.nu:2");
+    })
+}
+
+#[test]
+fn view_source_record_of_closure() {
+    let actual = nu!(
+        cwd: ".",
+        "let c = {|| 1 + 2 }; view source --record $c | get source"
+    );
+
+    assert_eq!(actual.out, "{|| 1 + 2 }");
+}
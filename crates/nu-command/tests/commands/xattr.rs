@@ -0,0 +1,46 @@
+#![cfg(target_os = "linux")]
+
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::nu;
+use nu_test_support::playground::Playground;
+
+#[test]
+fn xattr_set_and_get_round_trip() {
+    Playground::setup("xattr_round_trip", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("file.txt", "content")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "xattr set file.txt user.comment reviewed; xattr get file.txt user.comment"
+        );
+
+        assert_eq!(actual.out, "reviewed");
+    })
+}
+
+#[test]
+fn stat_lists_the_attribute_names() {
+    Playground::setup("xattr_stat", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("file.txt", "content")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "xattr set file.txt user.comment reviewed; stat file.txt | get xattrs | to nuon"
+        );
+
+        assert_eq!(actual.out, "[user.comment]");
+    })
+}
+
+#[test]
+fn xattr_get_errors_on_a_missing_attribute() {
+    Playground::setup("xattr_missing", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("file.txt", "content")]);
+
+        let actual = nu!(cwd: dirs.test(), "xattr get file.txt user.missing");
+
+        assert!(actual
+            .err
+            .contains("Could not get the user.missing attribute"));
+    })
+}
@@ -0,0 +1,55 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn from_clf_reads_typed_columns() {
+    Playground::setup("from_clf_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "access.log",
+            r#"
+                127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif HTTP/1.0" 200 2326
+                10.0.0.7 - - [10/Oct/2000:13:55:38 -0700] "POST /login HTTP/1.1" 503 -
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open access.log
+                | from clf
+                | where status >= 500
+                | get 0
+                | $"($in.host) ($in.request) ($in.bytes == null)"
+            "#
+        ));
+
+        assert_eq!(actual.out, "10.0.0.7 POST /login HTTP/1.1 true");
+    })
+}
+
+#[test]
+fn from_clf_reads_combined_log_format() {
+    let actual = nu!(pipeline(
+        r#"
+            '127.0.0.1 - - [10/Oct/2000:13:55:36 -0700] "GET / HTTP/1.0" 200 2326 "http://www.example.com/start.html" "Mozilla/4.08 [en] (Win98; I ;Nav)"'
+            | from clf
+            | get 0
+            | $"($in.referer) | ($in.user_agent)"
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        "http://www.example.com/start.html | Mozilla/4.08 [en] (Win98; I ;Nav)"
+    );
+}
+
+#[test]
+fn from_clf_rejects_garbage() {
+    let actual = nu!(pipeline(r#"'this is not an access log' | from clf"#));
+
+    assert!(actual
+        .err
+        .contains("cannot be parsed as a common log format"));
+}
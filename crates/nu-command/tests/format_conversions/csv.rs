@@ -407,3 +407,69 @@ fn string_to_csv_error() {
 
     assert!(actual.err.contains("can't convert"))
 }
+
+#[test]
+fn from_csv_text_with_infer_limit() {
+    Playground::setup("filter_from_csv_test_infer_limit", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sample.csv",
+            r#"
+                name,code
+                caballero,1
+                arepa,x
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.csv --raw
+                | from csv --infer 1
+            "#
+        ));
+
+        assert!(actual
+            .err
+            .contains("invalid value 'x' at row 2, column 'code'"));
+    })
+}
+
+#[test]
+fn from_csv_text_with_type_overrides() {
+    Playground::setup("filter_from_csv_test_types", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "sample.csv",
+            r#"
+                zip,count
+                02110,1
+                10013,2
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.csv --raw
+                | from csv --types {zip: string}
+                | get zip
+                | str join "-"
+            "#
+        ));
+
+        assert_eq!(actual.out, "02110-10013");
+    })
+}
+
+#[test]
+fn from_csv_text_with_unknown_type_override() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            "a,b
+            1,2"
+            | from csv --types {a: widget}
+        "#
+    ));
+
+    assert!(actual.err.contains("'int', 'float', 'bool' and 'string'"));
+}
@@ -0,0 +1,54 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn from_eml_get_subject() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.eml
+            | get Subject
+        "#
+    ));
+
+    assert_eq!(actual.out, "Test Message");
+}
+
+#[test]
+fn from_eml_get_another_header_field() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.eml
+            | get Reply-To.Address
+        "#
+    ));
+
+    assert_eq!(actual.out, "replyto@example.com");
+}
+
+#[test]
+fn from_eml_body_is_the_first_text_part() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.eml
+            | get Body
+            | str trim
+        "#
+    ));
+
+    assert_eq!(actual.out, "Test Message");
+}
+
+#[test]
+fn from_eml_other_parts_become_attachments() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.eml
+            | get Attachments.0.Type
+        "#
+    ));
+
+    assert_eq!(actual.out, "text/html");
+}
@@ -87,3 +87,33 @@ fn test_list() {
         r##"{name: "C64", black: "#090300", red: "#883932", green: "#55a049", yellow: "#bfce72", blue: "#40318d", purple: "#8b3f96", cyan: "#67b6bd", white: "#ffffff", brightBlack: "#000000", brightRed: "#883932", brightGreen: "#55a049", brightYellow: "#bfce72", brightBlue: "#40318d", brightPurple: "#8b3f96", brightCyan: "#67b6bd", brightWhite: "#f7f7f7", background: "#40318d", foreground: "#7869c4"}"##
     );
 }
+
+#[test]
+fn out_html_table_no_escape() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": "<b>darren</b>"}' | from json | to html --escape none --partial
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        "<div style=\"background-color:white;color:black;\"><table><thead><tr><th>name</th></tr></thead><tbody><tr><td><b>darren</b></td></tr></tbody></table></div>"
+    );
+}
+
+#[test]
+fn out_html_table_expand_nested() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo '{"name": {"first": "darren"}}' | from json | to html --expand --partial
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        "<div style=\"background-color:white;color:black;\"><table><thead><tr><th>name</th></tr></thead><tbody><tr><td><details><summary>{record 1 field}</summary><table><tbody><tr><th>first</th><td>darren</td></tr></tbody></table></details></td></tr></tbody></table></div>"
+    );
+}
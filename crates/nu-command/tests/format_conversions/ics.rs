@@ -0,0 +1,67 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn from_ics_collects_the_events() {
+    Playground::setup("from_ics_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "calendar.ics",
+            r#"
+                BEGIN:VCALENDAR
+                BEGIN:VEVENT
+                DTSTART:20171007T200000Z
+                DTEND:20171007T233000Z
+                SUMMARY:Basketball game
+                END:VEVENT
+                BEGIN:VEVENT
+                DTSTART:20171008T200000Z
+                DTEND:20171008T233000Z
+                SUMMARY:Basketball game two
+                END:VEVENT
+                END:VCALENDAR
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open calendar.ics
+                | get 0.events
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[test]
+fn from_ics_text_to_table() {
+    Playground::setup("from_ics_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "calendar.txt",
+            r#"
+                BEGIN:VCALENDAR
+                BEGIN:VEVENT
+                DTSTART:20171007T200000Z
+                SUMMARY:Basketball game
+                END:VEVENT
+                END:VCALENDAR
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open calendar.txt
+                | from ics
+                | get 0.events.0.properties
+                | where name == SUMMARY
+                | get 0.value
+            "#
+        ));
+
+        assert_eq!(actual.out, "Basketball game");
+    })
+}
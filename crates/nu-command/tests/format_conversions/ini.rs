@@ -0,0 +1,74 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn parses_ini() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.ini
+            | get SectionTwo.integer
+        "#
+    ));
+
+    assert_eq!(actual.out, "5678");
+}
+
+#[test]
+fn strips_quotes_from_values() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.ini
+            | get SectionOne.string1
+        "#
+    ));
+
+    assert_eq!(actual.out, "Case 1");
+}
+
+#[test]
+fn read_ini_sections_in_file_order() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.ini
+            | columns
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "[SectionOne, SectionTwo]");
+}
+
+#[test]
+fn ini_round_trip_keeps_comments() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r##"
+            open sample.ini --raw
+            | from ini --keep-comments
+            | to ini
+            | from ini --keep-comments
+            | get SectionTwo."#comment1"
+        "##
+    ));
+
+    assert_eq!(actual.out, "; comment line");
+}
+
+#[test]
+fn ini_round_trip_keeps_duplicate_keys() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            "[deps]\ncrate = a\ncrate = b"
+            | from ini
+            | to ini
+            | from ini
+            | get deps.crate
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "[a, b]");
+}
@@ -70,6 +70,47 @@ fn from_json_text_recognizing_objects_independently_to_table() {
     })
 }
 
+#[test]
+fn from_json_text_stream_top_level_array() {
+    let actual = nu!(pipeline(
+        r#"
+            '[ {"name": "Yehuda"}, {"name": "JT"}, {"name": "Andres"} ]'
+            | from json --stream
+            | first 2
+            | get name
+            | str join ","
+        "#
+    ));
+
+    assert_eq!(actual.out, "Yehuda,JT");
+}
+
+#[test]
+fn from_json_stream_recognizing_objects_independently() {
+    Playground::setup("filter_from_json_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "katz.txt",
+            r#"
+                {"name":   "Yehuda", "rusty_luck": 1}
+                {"name": "JT", "rusty_luck": 1}
+                {"name":"GorbyPuff", "rusty_luck": 3}
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open katz.txt
+                | from json --stream
+                | where name == "GorbyPuff"
+                | get rusty_luck.0
+            "#
+        ));
+
+        assert_eq!(actual.out, "3");
+    })
+}
+
 #[test]
 fn table_to_json_text() {
     Playground::setup("filter_to_json_test", |dirs, sandbox| {
@@ -96,3 +96,27 @@ fn md_combined() {
         "# Nu top meals| dish  || ----- || Arepa || Taco  || Pizza |"
     );
 }
+
+#[test]
+fn md_table_aligned() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[foo bar]; [1 2]] | to md --pretty --align {foo: center, bar: right}
+        "#
+    ));
+
+    assert_eq!(actual.out, "| foo | bar || :-: | --: ||  1  |   2 |");
+}
+
+#[test]
+fn md_table_escapes_pipes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [[name]; ["a|b"]] | to md
+        "#
+    ));
+
+    assert_eq!(actual.out, "|name||-||a\\|b|");
+}
@@ -0,0 +1,55 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+const ARCHIVE: &str = "From alice Thu Jan  1 00:00:00 1970
+From: alice@example.com
+Subject: one
+
+hi there
+>From a quoted line
+From bob Thu Jan  1 00:01:00 1970
+From: bob@example.com
+Subject: two
+
+bye
+";
+
+#[test]
+fn from_mbox_splits_messages() {
+    Playground::setup("from_mbox_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("mail.mbox", ARCHIVE)]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open mail.mbox --raw
+                | from mbox
+                | get Subject
+                | to nuon
+            "#
+        ));
+
+        assert_eq!(actual.out, "[one, two]");
+    })
+}
+
+#[test]
+fn from_mbox_unescapes_quoted_from_lines() {
+    Playground::setup("from_mbox_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("mail.mbox", ARCHIVE)]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open mail.mbox --raw
+                | from mbox
+                | get 0.Body
+                | lines
+                | get 1
+            "#
+        ));
+
+        assert_eq!(actual.out, "From a quoted line");
+    })
+}
@@ -1,14 +1,25 @@
 mod bson;
+mod clf;
 mod csv;
+mod eml;
 mod html;
+mod ics;
+mod ini;
 mod json;
 mod markdown;
+mod mbox;
+mod ndjson;
 mod nuon;
 mod ods;
+mod plist;
+mod prometheus;
+mod properties;
 mod ssv;
+mod syslog;
 mod toml;
 mod tsv;
 mod url;
+mod vcf;
 mod xlsx;
 mod xml;
 mod yaml;
@@ -0,0 +1,58 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn from_ndjson_skips_blank_lines() {
+    Playground::setup("from_ndjson_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.ndjson",
+            "{\"a\": 1}\n\n{\"a\": 2}\n",
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.ndjson --raw
+                | from ndjson
+                | get a
+                | to nuon
+            "#
+        ));
+
+        assert_eq!(actual.out, "[1, 2]");
+    })
+}
+
+#[test]
+fn from_ndjson_errors_carry_the_line_number() {
+    Playground::setup("from_ndjson_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("sample.ndjson", "{\"a\": 1}\n{\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.ndjson --raw
+                | from ndjson
+                | get 1
+            "#
+        ));
+
+        assert!(actual.err.contains("line 2"), "err: {}", actual.err);
+    })
+}
+
+#[test]
+fn ndjson_round_trips() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            [{a: 1, b: hello} {a: 2, b: world}]
+            | to ndjson
+            | from ndjson
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "[[a, b]; [1, hello], [2, world]]");
+}
@@ -0,0 +1,58 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn table_to_plist_text_and_from_plist_text_back_into_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            {name: nushell, version: 78, stable: true, tags: [shell structured]}
+            | to plist
+            | from plist
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        "{name: nushell, version: 78, stable: true, tags: [shell, structured]}"
+    );
+}
+
+#[test]
+fn binary_plist_round_trips() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            {name: "héllo", count: 300, ratio: 1.5, data: 0x[01 02]}
+            | to plist --binary
+            | from plist
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        "{name: héllo, count: 300, ratio: 1.5, data: 0x[0102]}"
+    );
+}
+
+#[test]
+fn from_plist_reads_xml_with_doctype() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            '<?xml version="1.0" encoding="UTF-8"?>
+            <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+            <plist version="1.0">
+            <dict>
+                <key>CFBundleName</key>
+                <string>Example</string>
+            </dict>
+            </plist>'
+            | from plist
+            | get CFBundleName
+        "#
+    ));
+
+    assert_eq!(actual.out, "Example");
+}
@@ -0,0 +1,64 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn from_prometheus_filters_by_name_and_labels() {
+    Playground::setup("from_prometheus_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "metrics.txt",
+            r#"
+                # HELP http_requests_total The total number of HTTP requests.
+                # TYPE http_requests_total counter
+                http_requests_total{method="post",code="200"} 1027 1395066363000
+                http_requests_total{method="post",code="400"} 3 1395066363000
+                # TYPE process_open_fds gauge
+                process_open_fds 12
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open metrics.txt
+                | from prometheus
+                | where name == http_requests_total
+                | where labels.code == "200"
+                | get value.0
+            "#
+        ));
+
+        assert_eq!(actual.out, "1027");
+    })
+}
+
+#[test]
+fn from_prometheus_keeps_timestamps_and_escaped_labels() {
+    Playground::setup("from_prometheus_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "metrics.txt",
+            r#"
+                msdos_file_access_time_seconds{path="C:\\DIR\\FILE.TXT",error="Cannot find file:\n\"FILE.TXT\""} 1.458255915e9 1395066363000
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open metrics.txt
+                | from prometheus
+                | get 0
+                | $"($in.timestamp) ($in.labels.path)"
+            "#
+        ));
+
+        assert_eq!(actual.out, r#"1395066363000 C:\DIR\FILE.TXT"#);
+    })
+}
+
+#[test]
+fn from_prometheus_rejects_garbage() {
+    let actual = nu!(pipeline(r#"'{ not metrics }' | from prometheus"#));
+
+    assert!(actual.err.contains("cannot be parsed as metrics"));
+}
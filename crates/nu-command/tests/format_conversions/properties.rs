@@ -0,0 +1,29 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn from_properties_reads_flat_record() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r##"
+            "# database settings\nurl = jdbc:postgresql://localhost/app\nuser: admin"
+            | from properties
+            | get user
+        "##
+    ));
+
+    assert_eq!(actual.out, "admin");
+}
+
+#[test]
+fn from_properties_decodes_unicode_escapes() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            'greeting = h\u00e9llo'
+            | from properties
+            | get greeting
+        "#
+    ));
+
+    assert_eq!(actual.out, "héllo");
+}
@@ -0,0 +1,66 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn from_syslog_reads_rfc5424_fields() {
+    Playground::setup("from_syslog_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "system.log",
+            r#"
+                <34>1 2003-10-11T22:14:15.003Z mymachine.example.com su 123 ID47 - su root failed on /dev/pts/8
+                <165>1 2003-08-24T05:14:15.000003-07:00 192.0.2.1 myproc 8710 - - %% It's time to make the do-nuts.
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open system.log
+                | from syslog
+                | where severity == crit
+                | get 0
+                | $"($in.host) ($in.app)[($in.pid)]: ($in.message)"
+            "#
+        ));
+
+        assert_eq!(
+            actual.out,
+            "mymachine.example.com su[123]: su root failed on /dev/pts/8"
+        );
+    })
+}
+
+#[test]
+fn from_syslog_skips_structured_data() {
+    let actual = nu!(pipeline(
+        r#"
+            '<165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog - ID47 [exampleSDID@32473 iut="3" eventSource="Application"][examplePriority@32473 class="high"] An application event log entry'
+            | from syslog
+            | get 0.message
+        "#
+    ));
+
+    assert_eq!(actual.out, "An application event log entry");
+}
+
+#[test]
+fn from_syslog_reads_rfc3164_lines() {
+    let actual = nu!(pipeline(
+        r#"
+            'Oct 11 22:14:15 mymachine su[230]: su root failed for lonvick on /dev/pts/8'
+            | from syslog
+            | get 0
+            | $"($in.app) ($in.pid) ($in.timestamp | date format '%b %d %H:%M:%S')"
+        "#
+    ));
+
+    assert_eq!(actual.out, "su 230 Oct 11 22:14:15");
+}
+
+#[test]
+fn from_syslog_rejects_garbage() {
+    let actual = nu!(pipeline(r#"'<34>not a timestamp at all' | from syslog"#));
+
+    assert!(actual.err.contains("cannot be parsed as syslog"));
+}
@@ -0,0 +1,64 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn from_vcf_collects_a_contact_per_card() {
+    Playground::setup("from_vcf_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "contacts.vcf",
+            r#"
+                BEGIN:VCARD
+                VERSION:3.0
+                FN:John Doe
+                N:Doe;John;;;
+                EMAIL;TYPE=INTERNET:john.doe99@gmail.com
+                END:VCARD
+                BEGIN:VCARD
+                VERSION:3.0
+                FN:Jane Doe
+                N:Doe;Jane;;;
+                END:VCARD
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open contacts.vcf
+                | length
+            "#
+        ));
+
+        assert_eq!(actual.out, "2");
+    })
+}
+
+#[test]
+fn from_vcf_reads_typed_fields() {
+    Playground::setup("from_vcf_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "contacts.txt",
+            r#"
+                BEGIN:VCARD
+                VERSION:3.0
+                FN:John Doe
+                EMAIL;TYPE=INTERNET:john.doe99@gmail.com
+                END:VCARD
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open contacts.txt
+                | from vcf
+                | get 0.properties
+                | where name == EMAIL
+                | get 0.params.TYPE.0
+            "#
+        ));
+
+        assert_eq!(actual.out, "INTERNET");
+    })
+}
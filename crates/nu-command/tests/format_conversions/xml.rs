@@ -22,3 +22,20 @@ fn table_to_xml_text_and_from_xml_text_back_into_table() {
 
     assert_eq!(actual.out, "true");
 }
+
+#[test]
+fn from_xml_keep_ns_round_trips_namespaces() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            '<x:root xmlns:x="http://example.com/x"><x:item>1</x:item></x:root>'
+            | from xml --keep-ns
+            | to xml
+        "#
+    ));
+
+    assert_eq!(
+        actual.out,
+        r#"<x:root xmlns:x="http://example.com/x"><x:item>1</x:item></x:root>"#
+    );
+}
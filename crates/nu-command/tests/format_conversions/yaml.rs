@@ -51,3 +51,48 @@ fn convert_dict_to_yaml_with_integer_floats_key() {
     assert!(actual.out.contains("2.11"));
     assert!(actual.err.is_empty());
 }
+
+#[test]
+fn multi_document_stream_round_trips() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            "---\na: 1\n---\na: 2"
+            | from yaml
+            | to yaml --docs
+            | from yaml --docs
+            | get a
+            | to nuon
+        "#
+    ));
+
+    assert_eq!(actual.out, "[1, 2]");
+}
+
+#[test]
+fn docs_flag_always_returns_a_list() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            "a: 1"
+            | from yaml --docs
+            | length
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn aliases_are_resolved_to_the_anchored_value() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            "base: &b {x: 1}\nother: *b"
+            | from yaml
+            | get other.x
+        "#
+    ));
+
+    assert_eq!(actual.out, "1");
+}
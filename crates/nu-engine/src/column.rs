@@ -6,7 +6,7 @@ pub fn get_columns<'a>(input: impl IntoIterator<Item = &'a Value>) -> Vec<String
 
     for item in input {
         if let Value::Record { cols, .. } = item {
-            for col in cols {
+            for col in cols.iter() {
                 if !columns.contains(col) {
                     columns.push(col.to_string());
                 }
@@ -0,0 +1,228 @@
+use crate::scope::ScopeData;
+use nu_protocol::ast::PipelineElement;
+use nu_protocol::engine::{DebugCommand, DebugMode, DebugStop, EngineState, Stack};
+use nu_protocol::{PipelineData, ShellError, Span};
+use std::io::{BufRead, Write};
+
+/// Called by `eval_block` before each pipeline element. When the `debug run` debugger is
+/// active and either stepping or a breakpoint matches, prompt on stderr/stdin until the
+/// user resumes. Returns an error when the user quits the session.
+pub(crate) fn maybe_stop(
+    engine_state: &EngineState,
+    stack: &Stack,
+    element: &PipelineElement,
+    input: &PipelineData,
+) -> Result<(), ShellError> {
+    let mut state = engine_state.debug_state.lock().expect("debug state mutex");
+
+    if !state.active {
+        return Ok(());
+    }
+
+    let span = element.span();
+    let source = String::from_utf8_lossy(engine_state.get_span_contents(&span)).to_string();
+
+    let stepping = match state.mode {
+        DebugMode::Continue => false,
+        DebugMode::Step => true,
+        DebugMode::Next(depth) => state.depth <= depth,
+    };
+
+    let at_breakpoint = breaks_on_command(&state.break_commands, &source)
+        || state
+            .break_spans
+            .iter()
+            .any(|bp| span.start >= bp.start && span.start < bp.end);
+
+    if !stepping && !at_breakpoint {
+        return Ok(());
+    }
+
+    if let Some(controller) = state.controller.clone() {
+        let stop = DebugStop {
+            reason: if at_breakpoint { "breakpoint" } else { "step" },
+            file: containing_file(engine_state, span).unwrap_or_default(),
+            line: line_of_span(engine_state, span),
+            source: source.trim().to_string(),
+            input: render_input(engine_state, input),
+            vars: collect_var_strings(engine_state, stack, span),
+        };
+
+        // Drop the state lock while blocked so the frontend can inspect it meanwhile
+        drop(state);
+        let _ = controller.events.send(stop);
+        let command = {
+            let commands = controller.commands.lock().expect("debug commands mutex");
+            commands.recv()
+        };
+
+        let mut state = engine_state.debug_state.lock().expect("debug state mutex");
+        return match command {
+            Ok(DebugCommand::Step) => {
+                state.mode = DebugMode::Step;
+                Ok(())
+            }
+            Ok(DebugCommand::Next) => {
+                state.mode = DebugMode::Next(state.depth);
+                Ok(())
+            }
+            Ok(DebugCommand::Continue) => {
+                state.mode = DebugMode::Continue;
+                Ok(())
+            }
+            // A dropped command channel means the frontend is gone; stop the script
+            Ok(DebugCommand::Quit) | Err(_) => {
+                state.active = false;
+                Err(ShellError::GenericError(
+                    "Stopped by the debugger".into(),
+                    "the script was aborted by the debugger frontend".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ))
+            }
+        };
+    }
+
+    eprintln!("-> {}", source.trim());
+
+    let stdin = std::io::stdin();
+    let mut line = String::new();
+
+    loop {
+        eprint!("(nu-debug) ");
+        let _ = std::io::stderr().flush();
+
+        line.clear();
+        match stdin.lock().read_line(&mut line) {
+            // On EOF there is no way to answer further prompts; run the rest of the script
+            Ok(0) | Err(_) => {
+                state.active = false;
+                return Ok(());
+            }
+            Ok(_) => {}
+        }
+
+        match line.trim() {
+            "" | "s" | "step" => {
+                state.mode = DebugMode::Step;
+                return Ok(());
+            }
+            "n" | "next" => {
+                state.mode = DebugMode::Next(state.depth);
+                return Ok(());
+            }
+            "c" | "continue" => {
+                state.mode = DebugMode::Continue;
+                return Ok(());
+            }
+            "q" | "quit" => {
+                state.active = false;
+                return Err(ShellError::GenericError(
+                    "Stopped by the debugger".into(),
+                    "the script was aborted with 'quit'".into(),
+                    Some(span),
+                    None,
+                    Vec::new(),
+                ));
+            }
+            "i" | "input" => eprintln!("{}", render_input(engine_state, input)),
+            "v" | "vars" => print_vars(engine_state, stack, span),
+            _ => {
+                eprintln!("s(tep), n(ext), c(ontinue), q(uit), i(nput), v(ars)");
+            }
+        }
+    }
+}
+
+pub(crate) fn enter_block(engine_state: &EngineState) {
+    let mut state = engine_state.debug_state.lock().expect("debug state mutex");
+    if state.active {
+        state.depth += 1;
+    }
+}
+
+pub(crate) fn leave_block(engine_state: &EngineState) {
+    let mut state = engine_state.debug_state.lock().expect("debug state mutex");
+    if state.active {
+        state.depth = state.depth.saturating_sub(1);
+    }
+}
+
+fn breaks_on_command(break_commands: &[String], source: &str) -> bool {
+    if break_commands.is_empty() {
+        return false;
+    }
+
+    // Compare against the longest prefix of words so that subcommands like
+    // "str length" match as well
+    let words: Vec<&str> = source
+        .trim_start()
+        .trim_start_matches(['|', '^', '('])
+        .split_whitespace()
+        .collect();
+
+    break_commands.iter().any(|command| {
+        let break_words: Vec<&str> = command.split_whitespace().collect();
+        !break_words.is_empty() && words.starts_with(&break_words)
+    })
+}
+
+fn print_vars(engine_state: &EngineState, stack: &Stack, span: Span) {
+    for (name, value) in collect_var_strings(engine_state, stack, span) {
+        eprintln!("{name} = {value}");
+    }
+}
+
+fn collect_var_strings(
+    engine_state: &EngineState,
+    stack: &Stack,
+    span: Span,
+) -> Vec<(String, String)> {
+    let mut scope_data = ScopeData::new(engine_state, stack);
+    scope_data.populate_all();
+
+    scope_data
+        .collect_vars(span)
+        .iter()
+        .filter_map(
+            |var| match (var.get_data_by_key("name"), var.get_data_by_key("value")) {
+                (Some(name), Some(value)) => Some((
+                    name.as_string().unwrap_or_default(),
+                    value.debug_string(", ", engine_state.get_config()),
+                )),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+fn render_input(engine_state: &EngineState, input: &PipelineData) -> String {
+    match input {
+        PipelineData::Value(value, ..) => value.debug_string(", ", engine_state.get_config()),
+        PipelineData::Empty => "<empty>".into(),
+        PipelineData::ListStream(..) => "<list stream>".into(),
+        PipelineData::ExternalStream { .. } => "<external stream>".into(),
+    }
+}
+
+/// The name of the engine state file that contains the given span, if any.
+fn containing_file(engine_state: &EngineState, span: Span) -> Option<String> {
+    engine_state
+        .files()
+        .find(|(_, start, end)| span.start >= *start && span.start < *end)
+        .map(|(name, ..)| name.clone())
+}
+
+/// The 1-based line of the span within its file, or 0 when the file is unknown.
+fn line_of_span(engine_state: &EngineState, span: Span) -> usize {
+    let Some((_, file_start, _)) = engine_state
+        .files()
+        .find(|(_, start, end)| span.start >= *start && span.start < *end)
+    else {
+        return 0;
+    };
+
+    let preceding = engine_state.get_span_contents(&Span::new(*file_start, span.start));
+    preceding.iter().filter(|byte| **byte == b'\n').count() + 1
+}
@@ -2,14 +2,16 @@ use crate::{current_dir_str, get_full_help, nu_variable::NuVariable};
 use nu_path::expand_path_with;
 use nu_protocol::{
     ast::{
-        Argument, Assignment, Bits, Block, Boolean, Call, Comparison, Expr, Expression, Math,
-        Operator, PathMember, PipelineElement, Redirection,
+        Argument, Assignment, Bits, Block, Boolean, Call, CellPath, Comparison, Expr, Expression,
+        Math, Operator, PathMember, PipelineElement, Redirection,
     },
     engine::{EngineState, ProfilingConfig, Stack},
-    DataSource, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, PipelineMetadata,
-    Range, ShellError, Span, Spanned, Unit, Value, VarId, ENV_VARIABLE_ID,
+    DataSource, IntoInterruptiblePipelineData, IntoPipelineData, ListStream, PipelineData,
+    PipelineMetadata, Range, ShellError, Span, Spanned, Unit, Value, VarId, ENV_VARIABLE_ID,
 };
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 pub fn eval_operator(op: &Expression) -> Result<Operator, ShellError> {
@@ -36,6 +38,16 @@ pub fn eval_call(
     }
     let decl = engine_state.get_decl(call.decl_id);
 
+    if let Some(allowed) = &engine_state.restricted_commands {
+        // Commands defined with `def` can only call commands that pass this check themselves
+        if !decl.is_custom_command() && !allowed.iter().any(|name| name == decl.name()) {
+            return Err(ShellError::RestrictedCommand {
+                command: decl.name().to_string(),
+                span: call.head,
+            });
+        }
+    }
+
     if !decl.is_known_external() && call.named_iter().any(|(flag, _, _)| flag.item == "help") {
         let mut signature = decl.signature();
         signature.usage = decl.usage().to_string();
@@ -194,6 +206,15 @@ fn eval_external(
     redirect_stderr: bool,
     is_subexpression: bool,
 ) -> Result<PipelineData, ShellError> {
+    if engine_state.restricted_commands.is_some() {
+        return Err(ShellError::RestrictedCommand {
+            command: head
+                .as_string()
+                .unwrap_or_else(|| "external command".to_string()),
+            span: head.span,
+        });
+    }
+
     let decl_id = engine_state
         .find_decl("run-external".as_bytes(), &[])
         .ok_or(ShellError::ExternalNotSupported { span: head.span })?;
@@ -244,6 +265,59 @@ fn eval_external(
     command.run(engine_state, stack, &call, input)
 }
 
+/// Evaluate any computed members (like the `($key)` in `$record.($key)`) in a
+/// cell path down to plain string or int members. Paths without computed
+/// members are borrowed as-is.
+fn resolve_dynamic_members<'a>(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    members: &'a [PathMember],
+) -> Result<Cow<'a, [PathMember]>, ShellError> {
+    if !members
+        .iter()
+        .any(|member| matches!(member, PathMember::Dynamic { .. }))
+    {
+        return Ok(Cow::Borrowed(members));
+    }
+
+    members
+        .iter()
+        .map(|member| match member {
+            PathMember::Dynamic {
+                block_id,
+                span,
+                optional,
+            } => {
+                let block = engine_state.get_block(*block_id);
+                let value = eval_subexpression(engine_state, stack, block, PipelineData::empty())?
+                    .into_value(*span);
+
+                match value {
+                    Value::String { val, .. } => Ok(PathMember::String {
+                        val,
+                        span: *span,
+                        optional: *optional,
+                    }),
+                    Value::Int { val, .. } if val >= 0 => Ok(PathMember::Int {
+                        val: val as usize,
+                        span: *span,
+                        optional: *optional,
+                    }),
+                    other => Err(ShellError::TypeMismatch {
+                        err_message: format!(
+                            "cell path member must be a string or a non-negative int, found {}",
+                            other.get_type()
+                        ),
+                        span: *span,
+                    }),
+                }
+            }
+            member => Ok(member.clone()),
+        })
+        .collect::<Result<Vec<PathMember>, ShellError>>()
+        .map(Cow::Owned)
+}
+
 pub fn eval_expression(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -253,6 +327,7 @@ pub fn eval_expression(
         Expr::Bool(b) => Ok(Value::boolean(*b, expr.span)),
         Expr::Int(i) => Ok(Value::int(*i, expr.span)),
         Expr::Float(f) => Ok(Value::float(*f, expr.span)),
+        Expr::Decimal(d) => Ok(Value::decimal(*d, expr.span)),
         Expr::Binary(b) => Ok(Value::Binary {
             val: b.clone(),
             span: expr.span,
@@ -292,14 +367,21 @@ pub fn eval_expression(
         }
         Expr::Var(var_id) => eval_variable(engine_state, stack, *var_id, expr.span),
         Expr::VarDecl(_) => Ok(Value::Nothing { span: expr.span }),
-        Expr::CellPath(cell_path) => Ok(Value::CellPath {
-            val: cell_path.clone(),
-            span: expr.span,
-        }),
+        Expr::CellPath(cell_path) => {
+            let members = resolve_dynamic_members(engine_state, stack, &cell_path.members)?;
+
+            Ok(Value::CellPath {
+                val: CellPath {
+                    members: members.into_owned(),
+                },
+                span: expr.span,
+            })
+        }
         Expr::FullCellPath(cell_path) => {
+            let tail = resolve_dynamic_members(engine_state, stack, &cell_path.tail)?;
             let value = eval_expression(engine_state, stack, &cell_path.head)?;
 
-            value.follow_cell_path(&cell_path.tail, false)
+            value.follow_cell_path(&tail, false)
         }
         Expr::ImportPattern(_) => Ok(Value::Nothing { span: expr.span }),
         Expr::Overlay(_) => {
@@ -471,12 +553,17 @@ pub fn eval_expression(
                                 // As such, give it special treatment here.
                                 let is_env = var_id == &ENV_VARIABLE_ID;
                                 if is_env || engine_state.get_var(*var_id).mutable {
+                                    let tail = resolve_dynamic_members(
+                                        engine_state,
+                                        stack,
+                                        &cell_path.tail,
+                                    )?;
                                     let mut lhs =
                                         eval_expression(engine_state, stack, &cell_path.head)?;
 
-                                    lhs.upsert_data_at_cell_path(&cell_path.tail, rhs)?;
+                                    lhs.upsert_data_at_cell_path(&tail, rhs)?;
                                     if is_env {
-                                        if cell_path.tail.is_empty() {
+                                        if tail.is_empty() {
                                             return Err(ShellError::CannotReplaceEnv {
                                                 span: cell_path.head.span,
                                             });
@@ -485,11 +572,9 @@ pub fn eval_expression(
                                         // The special $env treatment: for something like $env.config.history.max_size = 2000,
                                         // get $env.config (or whichever one it is) AFTER the above mutation, and set it
                                         // as the "config" environment variable.
-                                        let vardata = lhs.follow_cell_path(
-                                            &[cell_path.tail[0].clone()],
-                                            false,
-                                        )?;
-                                        match &cell_path.tail[0] {
+                                        let vardata =
+                                            lhs.follow_cell_path(&[tail[0].clone()], false)?;
+                                        match &tail[0] {
                                             PathMember::String { val, .. } => {
                                                 stack.add_env_var(val.to_string(), vardata);
                                             }
@@ -497,6 +582,8 @@ pub fn eval_expression(
                                             PathMember::Int { val, .. } => {
                                                 stack.add_env_var(val.to_string(), vardata);
                                             }
+                                            // Computed members were already resolved above
+                                            PathMember::Dynamic { .. } => {}
                                         }
                                     } else {
                                         stack.add_var(*var_id, lhs);
@@ -573,7 +660,7 @@ pub fn eval_expression(
             }
 
             Ok(Value::Record {
-                cols,
+                cols: Arc::new(cols),
                 vals,
                 span: expr.span,
             })
@@ -584,6 +671,9 @@ pub fn eval_expression(
                 output_headers.push(eval_expression(engine_state, stack, expr)?.as_string()?);
             }
 
+            // every row shares one reference-counted copy of the header names
+            let output_headers = Arc::new(output_headers);
+
             let mut output_rows = vec![];
             for val in vals {
                 let mut row = vec![];
@@ -715,14 +805,13 @@ pub fn eval_expression_with_input(
                 span,
                 ..
             } => {
+                let tail = resolve_dynamic_members(engine_state, stack, &full_cell_path.tail)?;
                 let block = engine_state.get_block(*block_id);
 
                 // FIXME: protect this collect with ctrl-c
                 input = eval_subexpression(engine_state, stack, block, input)?;
                 let value = input.into_value(*span);
-                input = value
-                    .follow_cell_path(&full_cell_path.tail, false)?
-                    .into_pipeline_data()
+                input = value.follow_cell_path(&tail, false)?.into_pipeline_data()
             }
             _ => {
                 input = eval_expression(engine_state, stack, elem)?.into_pipeline_data();
@@ -1035,9 +1124,15 @@ pub fn eval_block(
         None
     };
 
+    crate::debugger::enter_block(engine_state);
+
     for (pipeline_idx, pipeline) in block.pipelines.iter().enumerate() {
         let mut i = 0;
 
+        // The exit code of every external that isn't the last element of the
+        // pipeline, so the whole pipeline's status can be reported once it finishes.
+        let mut stage_exit_codes: Vec<Arc<Mutex<StageExitCode>>> = vec![];
+
         while i < pipeline.elements.len() {
             let redirect_stderr = redirect_stderr
                 || ((i < pipeline.elements.len() - 1)
@@ -1048,11 +1143,14 @@ pub fn eval_block(
                             | PipelineElement::SeparateRedirection { .. }
                     )));
 
+            crate::debugger::maybe_stop(engine_state, stack, &pipeline.elements[i], &input)?;
+
             let start_time = if stack.profiling_config.should_debug() {
                 Some(Instant::now())
             } else {
                 None
             };
+            let start_allocs = start_time.map(|_| nu_utils::allocation_count());
 
             // if eval internal command failed, it can just make early return with `Err(ShellError)`.
             let eval_result = eval_element_with_input(
@@ -1081,6 +1179,9 @@ pub fn eval_block(
             if let (Some(start_time), Some(end_time), Some(input_metadata)) =
                 (start_time, end_time, input_metadata.as_deref_mut())
             {
+                let allocations = start_allocs
+                    .map(|start| nu_utils::allocation_count().saturating_sub(start))
+                    .unwrap_or(0);
                 let element_span = pipeline.elements[i].span();
                 let element_str = String::from_utf8_lossy(
                     engine_state.get_span_contents(&pipeline.elements[i].span()),
@@ -1094,6 +1195,7 @@ pub fn eval_block(
                     element_span,
                     start_time,
                     end_time,
+                    allocations,
                     &stack.profiling_config,
                     &eval_result,
                     input_metadata,
@@ -1126,39 +1228,126 @@ pub fn eval_block(
                         if stack.profiling_config.should_debug() {
                             stack.profiling_config.leave_block();
                         }
+                        crate::debugger::leave_block(engine_state);
                         return Ok(input);
                     }
                 }
             }
 
+            // When an external pipes into the next stage, the receiving command gets
+            // its exit code stream. Tee it so the per-stage statuses can still be
+            // gathered into $env.LAST_PIPELINE_STATUS below.
+            if (i < pipeline.elements.len() - 1)
+                && matches!(pipeline.elements[i + 1], PipelineElement::Expression(..))
+            {
+                if let Some(shared) = tee_exit_code(&mut input) {
+                    stage_exit_codes.push(shared);
+                }
+            }
+
             i += 1;
         }
 
         if pipeline_idx < (num_pipelines) - 1 {
+            let mut final_exit_code = None;
             match input {
                 PipelineData::Value(Value::Nothing { .. }, ..) => {}
                 PipelineData::ExternalStream {
                     ref mut exit_code, ..
                 } => {
-                    let exit_code = exit_code.take();
-
+                    final_exit_code = exit_code.take();
                     input.drain()?;
+                }
+                _ => input.drain()?,
+            }
+
+            // The pipeline has fully run by now, so collecting the stashed
+            // per-stage exit codes cannot block.
+            let mut statuses: Vec<Value> = stage_exit_codes
+                .drain(..)
+                .filter_map(|shared| recorded_exit_code(&shared))
+                .collect();
+
+            if let Some(exit_code) = final_exit_code {
+                let mut v: Vec<_> = exit_code.collect();
+
+                if let Some(v) = v.pop() {
+                    statuses.push(v.clone());
+                    stack.add_env_var("LAST_EXIT_CODE".into(), v);
+                }
+            }
+
+            if !statuses.is_empty() {
+                let failure = statuses
+                    .iter()
+                    .rev()
+                    .find(|v| !matches!(v, Value::Int { val: 0, .. }))
+                    .cloned();
 
-                    if let Some(exit_code) = exit_code {
-                        let mut v: Vec<_> = exit_code.collect();
+                stack.add_env_var(
+                    "LAST_PIPELINE_STATUS".into(),
+                    Value::list(statuses, Span::unknown()),
+                );
 
-                        if let Some(v) = v.pop() {
-                            stack.add_env_var("LAST_EXIT_CODE".into(), v);
+                // Like `set -o pipefail`: a failed intermediate external fails
+                // the whole pipeline, so don't run the remaining ones. The status
+                // is returned the same way a failed last-stage external would
+                // return it, so callers see the failure.
+                //
+                // `$env.config` is only merged into the engine state between repl
+                // entries, so check the stack for a mid-script change as well.
+                let pipefail = match stack.get_env_var(engine_state, "config") {
+                    Some(mut config) => config.into_config(engine_state.get_config()).0.pipefail,
+                    None => engine_state.get_config().pipefail,
+                };
+                if pipefail {
+                    if let Some(failure) = failure {
+                        stack.add_env_var("LAST_EXIT_CODE".into(), failure.clone());
+                        if stack.profiling_config.should_debug() {
+                            stack.profiling_config.leave_block();
                         }
+                        crate::debugger::leave_block(engine_state);
+                        return Ok(PipelineData::ExternalStream {
+                            stdout: None,
+                            stderr: None,
+                            exit_code: Some(ListStream::from_stream(
+                                std::iter::once(failure),
+                                None,
+                            )),
+                            span: block.span.unwrap_or_else(Span::unknown),
+                            metadata: None,
+                            trim_end_newline: false,
+                        });
                     }
                 }
-                _ => input.drain()?,
             }
 
             input = PipelineData::empty()
+        } else if !stage_exit_codes.is_empty() {
+            // The last pipeline's data is returned to the caller before the stashed
+            // stages have necessarily finished, so chain their exit codes in front of
+            // the final external's stream; whoever drains it reports the statuses.
+            if let PipelineData::ExternalStream {
+                ref mut exit_code, ..
+            } = input
+            {
+                if let Some(last) = exit_code.take() {
+                    let ctrlc = last.ctrlc.clone();
+                    let stages = std::mem::take(&mut stage_exit_codes);
+                    *exit_code = Some(ListStream::from_stream(
+                        stages
+                            .into_iter()
+                            .filter_map(|shared| recorded_exit_code(&shared))
+                            .chain(last),
+                        ctrlc,
+                    ));
+                }
+            }
         }
     }
 
+    crate::debugger::leave_block(engine_state);
+
     if stack.profiling_config.should_debug() {
         stack.profiling_config.leave_block();
         Ok(input.set_metadata(input_metadata))
@@ -1167,18 +1356,83 @@ pub fn eval_block(
     }
 }
 
+/// The bookkeeping half of an exit code stream that was handed on to the next
+/// pipeline element. Whatever the receiver pulls through the tee is recorded
+/// here; anything it leaves unconsumed can still be drained afterwards.
+struct StageExitCode {
+    stream: Option<ListStream>,
+    last: Option<Value>,
+}
+
+struct ExitCodeTee {
+    shared: Arc<Mutex<StageExitCode>>,
+}
+
+impl Iterator for ExitCodeTee {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        let mut shared = self.shared.lock().expect("exit code stream mutex");
+        let value = shared.stream.as_mut().and_then(|stream| stream.next());
+        if let Some(value) = &value {
+            shared.last = Some(value.clone());
+        }
+        value
+    }
+}
+
+/// Replace the exit code stream of an external with a tee, so the receiving
+/// command still sees the exit code while `eval_block` can report it in
+/// `$env.LAST_PIPELINE_STATUS`. Returns the bookkeeping half of the tee.
+fn tee_exit_code(input: &mut PipelineData) -> Option<Arc<Mutex<StageExitCode>>> {
+    let PipelineData::ExternalStream { exit_code, .. } = input else {
+        return None;
+    };
+    let stream = exit_code.take()?;
+    let ctrlc = stream.ctrlc.clone();
+    let shared = Arc::new(Mutex::new(StageExitCode {
+        stream: Some(stream),
+        last: None,
+    }));
+    *exit_code = Some(ListStream::from_stream(
+        ExitCodeTee {
+            shared: shared.clone(),
+        },
+        ctrlc,
+    ));
+    Some(shared)
+}
+
+/// Get the exit code of a teed stage, draining whatever the receiving command
+/// left unconsumed. Only call this once the pipeline has fully run, or the
+/// drain may block.
+fn recorded_exit_code(shared: &Arc<Mutex<StageExitCode>>) -> Option<Value> {
+    let mut shared = shared.lock().expect("exit code stream mutex");
+    if let Some(stream) = shared.stream.take() {
+        if let Some(value) = stream.last() {
+            shared.last = Some(value);
+        }
+    }
+    shared.last.clone()
+}
+
 pub fn eval_subexpression(
     engine_state: &EngineState,
     stack: &mut Stack,
     block: &Block,
     mut input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
+    crate::debugger::enter_block(engine_state);
+
     for pipeline in block.pipelines.iter() {
         for expr in pipeline.elements.iter() {
+            crate::debugger::maybe_stop(engine_state, stack, expr, &input)?;
             input = eval_element_with_input(engine_state, stack, expr, input, true, false)?.0
         }
     }
 
+    crate::debugger::leave_block(engine_state);
+
     Ok(input)
 }
 
@@ -1210,15 +1464,25 @@ pub fn eval_variable(
 
             pairs.sort_by(|a, b| a.0.cmp(&b.0));
 
-            let (env_columns, env_values) = pairs.into_iter().unzip();
+            let (env_columns, env_values): (Vec<String>, Vec<Value>) = pairs.into_iter().unzip();
 
             Ok(Value::Record {
-                cols: env_columns,
+                cols: Arc::new(env_columns),
                 vals: env_values,
                 span,
             })
         }
-        var_id => stack.get_var(var_id, span),
+        var_id => match stack.get_var(var_id, span) {
+            Ok(val) => Ok(val),
+            Err(err) => {
+                // Module constants only live in the engine state
+                if let Some(val) = engine_state.find_constant(var_id, &[]) {
+                    Ok(val.clone())
+                } else {
+                    Err(err)
+                }
+            }
+        },
     }
 }
 
@@ -1355,6 +1619,7 @@ fn collect_profiling_metadata(
     element_span: Span,
     start_time: Instant,
     end_time: Instant,
+    allocations: u64,
     profiling_config: &ProfilingConfig,
     eval_result: &Result<(PipelineData, bool), ShellError>,
     input_metadata: &mut PipelineMetadata,
@@ -1411,8 +1676,20 @@ fn collect_profiling_metadata(
         span: element_span,
     });
 
+    cols.push("allocs".to_string());
+    vals.push(Value::int(allocations as i64, element_span));
+
+    // The row count of the element's output, when it can be known without draining a stream
+    cols.push("rows".to_string());
+    vals.push(match &eval_result {
+        Ok((PipelineData::Value(Value::List { vals, .. }, ..), ..)) => {
+            Value::int(vals.len() as i64, element_span)
+        }
+        _ => Value::Nothing { span: element_span },
+    });
+
     let record = Value::Record {
-        cols,
+        cols: Arc::new(cols),
         vals,
         span: element_span,
     };
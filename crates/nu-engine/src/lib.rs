@@ -1,5 +1,6 @@
 mod call_ext;
 pub mod column;
+mod debugger;
 pub mod documentation;
 pub mod env;
 mod eval;
@@ -6,6 +6,7 @@ use nu_protocol::{
 };
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::sync::Arc;
 use sysinfo::SystemExt;
 
 // NuVariable: a LazyRecord for the special $nu variable
@@ -24,7 +25,7 @@ pub struct NuVariable {
 }
 
 impl LazyRecord for NuVariable {
-    fn column_names(&self) -> Vec<&'static str> {
+    fn column_names(&self) -> Vec<String> {
         let mut cols = vec!["config-path", "env-path", "history-path", "loginshell-path"];
 
         #[cfg(feature = "plugin")]
@@ -44,7 +45,7 @@ impl LazyRecord for NuVariable {
 
         cols.push("current-exe");
 
-        cols
+        cols.into_iter().map(String::from).collect()
     }
 
     fn get_column_value(&self, column: &str) -> Result<Value, ShellError> {
@@ -186,12 +187,12 @@ impl LazyRecord for NuVariable {
                 };
 
                 let os_record = Value::Record {
-                    cols: vec![
+                    cols: Arc::new(vec![
                         "name".into(),
                         "arch".into(),
                         "family".into(),
                         "kernel_version".into(),
-                    ],
+                    ]),
                     vals: vec![
                         Value::string(std::env::consts::OS, self.span()),
                         Value::string(std::env::consts::ARCH, self.span()),
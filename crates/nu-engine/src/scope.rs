@@ -5,6 +5,7 @@ use nu_protocol::{
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 pub fn create_scope(
     engine_state: &EngineState,
@@ -45,7 +46,11 @@ pub fn create_scope(
     cols.push("engine_state".to_string());
     vals.push(scope_data.collect_engine_state(span));
 
-    Ok(Value::Record { cols, vals, span })
+    Ok(Value::Record {
+        cols: Arc::new(cols),
+        vals,
+        span,
+    })
 }
 
 pub struct ScopeData<'e, 's> {
@@ -98,7 +103,11 @@ impl<'e, 's> ScopeData<'e, 's> {
             };
 
             vars.push(Value::Record {
-                cols: vec!["name".to_string(), "type".to_string(), "value".to_string()],
+                cols: Arc::new(vec![
+                    "name".to_string(),
+                    "type".to_string(),
+                    "value".to_string(),
+                ]),
                 vals: vec![var_name, var_type, var_value],
                 span,
             })
@@ -160,7 +169,11 @@ impl<'e, 's> ScopeData<'e, 's> {
                         .examples()
                         .into_iter()
                         .map(|x| Value::Record {
-                            cols: vec!["description".into(), "example".into(), "result".into()],
+                            cols: Arc::new(vec![
+                                "description".into(),
+                                "example".into(),
+                                "result".into(),
+                            ]),
                             vals: vec![
                                 Value::String {
                                     val: x.description.to_string(),
@@ -238,7 +251,11 @@ impl<'e, 's> ScopeData<'e, 's> {
                     span,
                 });
 
-                commands.push(Value::Record { cols, vals, span })
+                commands.push(Value::Record {
+                    cols: Arc::new(cols),
+                    vals,
+                    span,
+                })
             }
         }
 
@@ -291,8 +308,12 @@ impl<'e, 's> ScopeData<'e, 's> {
         // signature usually comes later in the input_output_types, so this will
         // remove them from the record.
         sigs.dedup_by(|(k1, _), (k2, _)| k1 == k2);
-        let (cols, vals) = sigs.into_iter().unzip();
-        Value::Record { cols, vals, span }
+        let (cols, vals): (Vec<String>, Vec<Value>) = sigs.into_iter().unzip();
+        Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        }
     }
 
     fn collect_signature_entries(
@@ -316,7 +337,7 @@ impl<'e, 's> ScopeData<'e, 's> {
 
         // input
         sig_records.push(Value::Record {
-            cols: sig_cols.clone(),
+            cols: Arc::new(sig_cols.clone()),
             vals: vec![
                 Value::nothing(span),
                 Value::string("input", span),
@@ -345,7 +366,7 @@ impl<'e, 's> ScopeData<'e, 's> {
             ];
 
             sig_records.push(Value::Record {
-                cols: sig_cols.clone(),
+                cols: Arc::new(sig_cols.clone()),
                 vals: sig_vals,
                 span,
             });
@@ -367,7 +388,7 @@ impl<'e, 's> ScopeData<'e, 's> {
             ];
 
             sig_records.push(Value::Record {
-                cols: sig_cols.clone(),
+                cols: Arc::new(sig_cols.clone()),
                 vals: sig_vals,
                 span,
             });
@@ -389,7 +410,7 @@ impl<'e, 's> ScopeData<'e, 's> {
             ];
 
             sig_records.push(Value::Record {
-                cols: sig_cols.clone(),
+                cols: Arc::new(sig_cols.clone()),
                 vals: sig_vals,
                 span,
             });
@@ -432,7 +453,7 @@ impl<'e, 's> ScopeData<'e, 's> {
             ];
 
             sig_records.push(Value::Record {
-                cols: sig_cols.clone(),
+                cols: Arc::new(sig_cols.clone()),
                 vals: sig_vals,
                 span,
             });
@@ -440,7 +461,7 @@ impl<'e, 's> ScopeData<'e, 's> {
 
         // output
         sig_records.push(Value::Record {
-            cols: sig_cols,
+            cols: Arc::new(sig_cols),
             vals: vec![
                 Value::nothing(span),
                 Value::string("output", span),
@@ -495,7 +516,11 @@ impl<'e, 's> ScopeData<'e, 's> {
                     span,
                 });
 
-                externals.push(Value::Record { cols, vals, span })
+                externals.push(Value::Record {
+                    cols: Arc::new(cols),
+                    vals,
+                    span,
+                })
             }
         }
 
@@ -513,7 +538,7 @@ impl<'e, 's> ScopeData<'e, 's> {
                     let key = sig.name;
 
                     aliases.push(Value::Record {
-                        cols: vec!["name".into(), "expansion".into(), "usage".into()],
+                        cols: Arc::new(vec!["name".into(), "expansion".into(), "usage".into()]),
                         vals: vec![
                             Value::String { val: key, span },
                             Value::String {
@@ -580,13 +605,13 @@ impl<'e, 's> ScopeData<'e, 's> {
                 .unwrap_or_default();
 
             modules.push(Value::Record {
-                cols: vec![
+                cols: Arc::new(vec![
                     "name".into(),
                     "commands".into(),
                     "aliases".into(),
                     "env_block".into(),
                     "usage".into(),
-                ],
+                ]),
                 vals: vec![
                     Value::string(String::from_utf8_lossy(module_name), span),
                     Value::List {
@@ -633,7 +658,7 @@ impl<'e, 's> ScopeData<'e, 's> {
             ),
         ];
         Value::Record {
-            cols: engine_state_cols,
+            cols: Arc::new(engine_state_cols),
             vals: engine_state_vals,
             span,
         }
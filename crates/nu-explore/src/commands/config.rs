@@ -12,6 +12,7 @@ use crate::{
 };
 
 use super::{default_color_list, ConfigOption, HelpManual, ViewCommand};
+use std::sync::Arc;
 
 #[derive(Default, Clone)]
 pub struct ConfigCmd {
@@ -159,7 +160,7 @@ fn create_default_value() -> Value {
     let span = NuSpan::unknown();
 
     let record = |i: usize| Value::Record {
-        cols: vec![String::from("key"), String::from("value")],
+        cols: Arc::new(vec![String::from("key"), String::from("value")]),
         vals: vec![nu_str(format!("key-{i}")), nu_str(format!("{i}"))],
         span,
     };
@@ -14,6 +14,7 @@ use crate::{
 };
 
 use super::{HelpExample, HelpManual, ViewCommand};
+use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct ConfigShowCmd {
@@ -177,7 +178,7 @@ fn convert_style_from_string(s: &str) -> Option<Value> {
     ];
 
     Some(Value::Record {
-        cols,
+        cols: Arc::new(cols),
         vals,
         span: NuSpan::unknown(),
     })
@@ -17,6 +17,7 @@ use crate::{
 };
 
 use super::{HelpExample, HelpManual, ViewCommand};
+use std::sync::Arc;
 
 #[derive(Debug, Default, Clone)]
 pub struct HelpCmd {
@@ -172,7 +173,7 @@ fn help_frame_data(
             let (cols, mut vals) = help_manual_data(manual, aliases);
             let vals = vals.remove(0);
             Value::Record {
-                cols,
+                cols: Arc::new(cols),
                 vals,
                 span: NuSpan::unknown(),
             }
@@ -195,7 +196,7 @@ fn help_manual_data(manual: &HelpManual, aliases: &[String]) -> (Vec<String>, Ve
         .arguments
         .iter()
         .map(|e| Value::Record {
-            cols: vec![String::from("example"), String::from("description")],
+            cols: Arc::new(vec![String::from("example"), String::from("description")]),
             vals: vec![nu_str(&e.example), nu_str(&e.description)],
             span: NuSpan::unknown(),
         })
@@ -210,7 +211,7 @@ fn help_manual_data(manual: &HelpManual, aliases: &[String]) -> (Vec<String>, Ve
         .examples
         .iter()
         .map(|e| Value::Record {
-            cols: vec![String::from("example"), String::from("description")],
+            cols: Arc::new(vec![String::from("example"), String::from("description")]),
             vals: vec![nu_str(&e.example), nu_str(&e.description)],
             span: NuSpan::unknown(),
         })
@@ -224,11 +225,11 @@ fn help_manual_data(manual: &HelpManual, aliases: &[String]) -> (Vec<String>, Ve
         .input
         .iter()
         .map(|e| Value::Record {
-            cols: vec![
+            cols: Arc::new(vec![
                 String::from("name"),
                 String::from("context"),
                 String::from("description"),
-            ],
+            ]),
             vals: vec![nu_str(&e.code), nu_str(&e.context), nu_str(&e.description)],
             span: NuSpan::unknown(),
         })
@@ -246,7 +247,7 @@ fn help_manual_data(manual: &HelpManual, aliases: &[String]) -> (Vec<String>, Ve
                 .values
                 .iter()
                 .map(|v| Value::Record {
-                    cols: vec![String::from("example"), String::from("description")],
+                    cols: Arc::new(vec![String::from("example"), String::from("description")]),
                     vals: vec![nu_str(&v.example), nu_str(&v.description)],
                     span: NuSpan::unknown(),
                 })
@@ -257,12 +258,12 @@ fn help_manual_data(manual: &HelpManual, aliases: &[String]) -> (Vec<String>, Ve
             };
 
             Value::Record {
-                cols: vec![
+                cols: Arc::new(vec![
                     String::from("name"),
                     String::from("context"),
                     String::from("description"),
                     String::from("values"),
-                ],
+                ]),
                 vals: vec![
                     nu_str(&o.group),
                     nu_str(&o.key),
@@ -75,6 +75,9 @@ impl ViewCommand for TableCmd {
             Shortcode::new("t",      "view",    "Transpose table, so that columns become rows and vice versa"),
             Shortcode::new("e",      "view",    "Open expand view (equivalent of :expand)"),
             Shortcode::new("Enter",  "cursor",  "In cursor mode, explore the data of the selected cell"),
+            Shortcode::new("E",      "cursor",  "Open the selected cell in $env.EDITOR; the edited text replaces the cell"),
+            Shortcode::new("H",      "cursor",  "Hide the column under the cursor"),
+            Shortcode::new("U",      "cursor",  "Show back all columns hidden with H"),
         ];
 
         #[rustfmt::skip]
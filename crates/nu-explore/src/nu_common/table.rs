@@ -23,7 +23,7 @@ pub fn try_build_table(
     match value {
         Value::List { vals, span } => try_build_list(vals, &ctrlc, config, span, style_computer),
         Value::Record { cols, vals, span } => {
-            try_build_map(cols, vals, span, style_computer, ctrlc, config)
+            try_build_map(cols.to_vec(), vals, span, style_computer, ctrlc, config)
         }
         val => value_to_styled_string(&val, config, style_computer).0,
     }
@@ -52,7 +52,16 @@ fn try_build_map(
     match result {
         Ok(Some(result)) => result,
         Ok(None) | Err(_) => {
-            value_to_styled_string(&Value::Record { cols, vals, span }, config, style_computer).0
+            value_to_styled_string(
+                &Value::Record {
+                    cols: Arc::new(cols),
+                    vals,
+                    span,
+                },
+                config,
+                style_computer,
+            )
+            .0
         }
     }
 }
@@ -196,7 +205,7 @@ fn build_expanded_table(
                 }
                 Value::Record { cols, vals, span } => {
                     let result = build_expanded_table(
-                        cols.clone(),
+                        cols.to_vec(),
                         vals.clone(),
                         span,
                         ctrlc.clone(),
@@ -604,7 +613,7 @@ fn create_table2_entry_basic(
                 span: head,
                 optional: false,
             };
-            let val = item.clone().follow_cell_path(&[path], false);
+            let val = item.follow_cell_path(&[path], false);
 
             match val {
                 Ok(val) => value_to_styled_string(&val, config, style_computer),
@@ -636,7 +645,7 @@ fn create_table2_entry(
                 span: head,
                 optional: false,
             };
-            let val = item.clone().follow_cell_path(&[path], false);
+            let val = item.follow_cell_path(&[path], false);
 
             match val {
                 Ok(val) => convert_to_table2_entry(
@@ -905,6 +914,16 @@ fn load_theme_from_config(config: &Config) -> TableTheme {
         "reinforced" => nu_table::TableTheme::reinforced(),
         "heavy" => nu_table::TableTheme::heavy(),
         "none" => nu_table::TableTheme::none(),
+        "custom" => nu_table::TableTheme::custom(
+            &config
+                .table_theme
+                .iter()
+                .filter_map(|(name, value)| {
+                    let text = value.as_string().ok()?;
+                    Some((name.clone(), text.chars().next()?))
+                })
+                .collect(),
+        ),
         _ => nu_table::TableTheme::rounded(),
     }
 }
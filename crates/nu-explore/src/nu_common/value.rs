@@ -4,6 +4,7 @@ use nu_engine::get_columns;
 use nu_protocol::{ast::PathMember, ListStream, PipelineData, PipelineMetadata, RawStream, Value};
 
 use super::NuSpan;
+use std::sync::Arc;
 
 pub fn collect_pipeline(input: PipelineData) -> (Vec<String>, Vec<Vec<Value>>) {
     match input {
@@ -81,7 +82,7 @@ fn collect_external_stream(
     }
     if metadata.is_some() {
         let val = Value::Record {
-            cols: vec![String::from("data_source")],
+            cols: Arc::new(vec![String::from("data_source")]),
             vals: vec![Value::String {
                 val: String::from("ls"),
                 span,
@@ -98,7 +99,7 @@ fn collect_external_stream(
 /// Try to build column names and a table grid.
 pub fn collect_input(value: Value) -> (Vec<String>, Vec<Vec<Value>>) {
     match value {
-        Value::Record { cols, vals, .. } => (cols, vec![vals]),
+        Value::Record { cols, vals, .. } => (cols.to_vec(), vec![vals]),
         Value::List { vals, .. } => {
             let mut columns = get_columns(&vals);
             let data = convert_records_to_dataset(&columns, vals);
@@ -204,7 +205,7 @@ pub fn map_into_value(hm: HashMap<String, Value>) -> Value {
     }
 
     Value::Record {
-        cols: columns,
+        cols: Arc::new(columns),
         vals: values,
         span: NuSpan::unknown(),
     }
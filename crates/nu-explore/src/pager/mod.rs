@@ -24,6 +24,7 @@ use nu_protocol::{
     engine::{EngineState, Stack},
     Value,
 };
+use regex::Regex;
 use tui::{backend::CrosstermBackend, layout::Rect, widgets::Block};
 
 use crate::{
@@ -42,6 +43,7 @@ use self::{
 use super::views::{Layout, View};
 
 use events::UIEvents;
+use std::sync::Arc;
 
 pub type Frame<'a> = tui::Frame<'a, CrosstermBackend<Stdout>>;
 pub type Terminal = tui::Terminal<CrosstermBackend<Stdout>>;
@@ -157,6 +159,9 @@ pub enum Transition {
     Ok,
     Exit,
     Cmd(String),
+    // an external program used the terminal; the screen must be repainted
+    // from scratch
+    Redraw,
 }
 
 #[derive(Debug, Clone)]
@@ -339,6 +344,9 @@ fn render_ui(
                         Err(err) => info.report = Some(Report::error(err)),
                     }
                 }
+                Transition::Redraw => {
+                    term.clear()?;
+                }
             }
         }
 
@@ -444,7 +452,7 @@ fn run_command(
                         Ok(false)
                     }
                     Transition::Exit => Ok(true),
-                    Transition::Cmd { .. } => todo!("not used so far"),
+                    Transition::Cmd { .. } | Transition::Redraw => todo!("not used so far"),
                 },
                 Err(err) => Err(format!("Error: command {args:?} failed: {err}")),
             }
@@ -615,15 +623,22 @@ fn highlight_search_results(f: &mut Frame, pager: &Pager, layout: &Layout, style
     }
 
     let highlight_block = Block::default().style(nu_style_to_tui(style));
+    let regex = Regex::new(&pager.search_buf.buf_cmd_input).ok();
 
     for e in &layout.data {
         let text = ansi_str::AnsiStr::ansi_strip(&e.text);
 
-        if let Some(p) = text.find(&pager.search_buf.buf_cmd_input) {
+        let found = match &regex {
+            Some(regex) => regex.find(&text).map(|m| (m.start(), m.end() - m.start())),
+            None => text
+                .find(&pager.search_buf.buf_cmd_input)
+                .map(|p| (p, pager.search_buf.buf_cmd_input.len())),
+        };
+
+        if let Some((p, len)) = found {
             let p = covert_bytes_to_chars(&text, p);
 
-            let w = pager.search_buf.buf_cmd_input.len() as u16;
-            let area = Rect::new(e.area.x + p as u16, e.area.y, w, 1);
+            let area = Rect::new(e.area.x + p as u16, e.area.y, len as u16, 1);
 
             f.render_widget(highlight_block.clone(), area);
         }
@@ -726,6 +741,7 @@ fn handle_event<V: View>(
         match t {
             Some(Transition::Exit) => return Some(Transition::Ok),
             Some(Transition::Cmd(cmd)) => return Some(Transition::Cmd(cmd)),
+            Some(Transition::Redraw) => return Some(Transition::Redraw),
             Some(Transition::Ok) => return None,
             None => {}
         }
@@ -897,9 +913,17 @@ fn search_input_key_event(
 }
 
 fn search_pattern(data: impl Iterator<Item = String>, pat: &str, rev: bool) -> Vec<usize> {
+    // a pattern which is not a valid regex is still usable as a plain
+    // substring search
+    let regex = Regex::new(pat).ok();
+
     let mut matches = Vec::new();
     for (row, text) in data.enumerate() {
-        if text.contains(pat) {
+        let is_match = match &regex {
+            Some(regex) => regex.is_match(&text),
+            None => text.contains(pat),
+        };
+        if is_match {
             matches.push(row);
         }
     }
@@ -988,7 +1012,7 @@ fn set_config(hm: &mut HashMap<String, Value>, path: &[&str], value: Value) -> b
         hm.insert(
             key.to_string(),
             Value::Record {
-                cols: vec![],
+                cols: Arc::new(vec![]),
                 vals: vec![],
                 span: NuSpan::unknown(),
             },
@@ -1017,7 +1041,7 @@ fn set_config(hm: &mut HashMap<String, Value>, path: &[&str], value: Value) -> b
                         vals[i] = value;
                     }
                     None => {
-                        cols.push(key.to_string());
+                        Arc::make_mut(cols).push(key.to_string());
                         vals.push(value);
                     }
                 }
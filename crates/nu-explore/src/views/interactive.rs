@@ -178,6 +178,7 @@ impl View for InteractiveView<'_> {
 
             return match result {
                 Some(Transition::Ok | Transition::Cmd { .. }) => Some(Transition::Ok),
+                Some(Transition::Redraw) => Some(Transition::Redraw),
                 Some(Transition::Exit) => {
                     self.view_mode = false;
                     Some(Transition::Ok)
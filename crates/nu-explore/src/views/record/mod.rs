@@ -2,7 +2,11 @@ mod tablew;
 
 use std::{borrow::Cow, collections::HashMap};
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::{
+    event::{KeyCode, KeyEvent},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
 use nu_color_config::{get_color_map, StyleComputer};
 use nu_protocol::{
     engine::{EngineState, Stack},
@@ -21,6 +25,7 @@ use crate::{
 };
 
 use self::tablew::{TableStyle, TableW, TableWState};
+use std::sync::Arc;
 
 use super::{
     cursor::XYCursor,
@@ -206,6 +211,68 @@ impl<'a> RecordView<'a> {
         layer.records[row][column].clone()
     }
 
+    pub fn set_current_value(&mut self, value: Value) {
+        let (row, column) = self.get_current_position();
+        let layer = self.get_layer_last_mut();
+
+        let (row, column) = match layer.orientation {
+            Orientation::Top | Orientation::Bottom => (row, column),
+            Orientation::Left | Orientation::Right => (column, row),
+        };
+
+        layer.records.to_mut()[row][column] = value;
+    }
+
+    /// Hide the column under the cursor, keeping it aside so
+    /// [`show_hidden_columns`](Self::show_hidden_columns) can bring it back.
+    pub fn hide_current_column(&mut self) -> bool {
+        let (row, column) = self.get_current_position();
+        let layer = self.get_layer_last_mut();
+
+        let column = match layer.orientation {
+            Orientation::Top | Orientation::Bottom => column,
+            Orientation::Left | Orientation::Right => row,
+        };
+
+        // the last column stays put; an empty table can't be navigated
+        if layer.columns.len() <= 1 || column >= layer.columns.len() {
+            return false;
+        }
+
+        let name = layer.columns.to_mut().remove(column);
+        let values = layer
+            .records
+            .to_mut()
+            .iter_mut()
+            .map(|row| row.remove(column))
+            .collect();
+
+        layer.hidden_columns.push((column, name, values));
+        layer.reset_cursor();
+
+        true
+    }
+
+    /// Put back all columns hidden on the current layer
+    pub fn show_hidden_columns(&mut self) -> bool {
+        let layer = self.get_layer_last_mut();
+        if layer.hidden_columns.is_empty() {
+            return false;
+        }
+
+        // restore in reverse so each stored index lands where it was removed
+        while let Some((index, name, values)) = layer.hidden_columns.pop() {
+            layer.columns.to_mut().insert(index, name);
+            for (row, value) in layer.records.to_mut().iter_mut().zip(values) {
+                row.insert(index, value);
+            }
+        }
+
+        layer.reset_cursor();
+
+        true
+    }
+
     fn create_tablew(&'a self, cfg: ViewConfig<'a>) -> TableW<'a> {
         let layer = self.get_layer_last();
         let mut data = convert_records_to_string(&layer.records, cfg.nu_config, cfg.style_computer);
@@ -283,15 +350,30 @@ impl View for RecordView<'_> {
 
     fn handle_input(
         &mut self,
-        _: &EngineState,
-        _: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         _: &Layout,
         info: &mut ViewInfo,
         key: KeyEvent,
     ) -> Option<Transition> {
         let result = match self.mode {
             UIMode::View => handle_key_event_view_mode(self, &key),
-            UIMode::Cursor => handle_key_event_cursor_mode(self, &key),
+            UIMode::Cursor => {
+                if key.code == KeyCode::Char('E') {
+                    return match edit_current_cell(self, engine_state, stack) {
+                        Ok(()) => {
+                            info.status = Some(self.create_records_report());
+                            Some(Transition::Redraw)
+                        }
+                        Err(msg) => {
+                            info.report = Some(Report::error(msg));
+                            Some(Transition::Redraw)
+                        }
+                    };
+                }
+
+                handle_key_event_cursor_mode(self, &key)
+            }
         };
 
         if matches!(&result, Some(Transition::Ok) | Some(Transition::Cmd { .. })) {
@@ -399,6 +481,9 @@ pub struct RecordLayer<'a> {
     name: Option<String>,
     was_transposed: bool,
     cursor: XYCursor,
+    // columns removed by the user, kept as `(index, name, values)` so they
+    // can be put back in place
+    hidden_columns: Vec<(usize, String, Vec<Value>)>,
 }
 
 impl<'a> RecordLayer<'a> {
@@ -417,6 +502,7 @@ impl<'a> RecordLayer<'a> {
             orientation: Orientation::Top,
             name: None,
             was_transposed: false,
+            hidden_columns: Vec::new(),
         }
     }
 
@@ -542,6 +628,16 @@ fn handle_key_event_cursor_mode(view: &mut RecordView, key: &KeyEvent) -> Option
 
             Some(Transition::Ok)
         }
+        KeyCode::Char('H') => {
+            view.hide_current_column();
+
+            Some(Transition::Ok)
+        }
+        KeyCode::Char('U') => {
+            view.show_hidden_columns();
+
+            Some(Transition::Ok)
+        }
         KeyCode::Enter => {
             let value = view.get_current_value();
             let is_record = matches!(value, Value::Record { .. });
@@ -563,6 +659,92 @@ fn handle_key_event_cursor_mode(view: &mut RecordView, key: &KeyEvent) -> Option
     }
 }
 
+/// Open the cell under the cursor in `$env.EDITOR` (or `$env.VISUAL`) and
+/// put the edited text back into the table.
+fn edit_current_cell(
+    view: &mut RecordView<'_>,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+) -> Result<(), String> {
+    let editor = stack
+        .get_env_var(engine_state, "EDITOR")
+        .map(|v| v.as_string().unwrap_or_default())
+        .filter(|v| !v.is_empty())
+        .or_else(|| {
+            stack
+                .get_env_var(engine_state, "VISUAL")
+                .map(|v| v.as_string().unwrap_or_default())
+                .filter(|v| !v.is_empty())
+        })
+        .ok_or_else(|| String::from("no $env.EDITOR or $env.VISUAL is set"))?;
+
+    let value = view.get_current_value();
+    let text = value.clone().into_string("\n", &NuConfig::default());
+
+    let mut path = std::env::temp_dir();
+    path.push(format!("nu-explore-cell-{}.txt", std::process::id()));
+    std::fs::write(&path, &text).map_err(|err| err.to_string())?;
+
+    // give the terminal to the editor, and take it back afterwards; the
+    // pager repaints from scratch on the Transition::Redraw that follows
+    disable_raw_mode().map_err(|err| err.to_string())?;
+    execute!(std::io::stdout(), LeaveAlternateScreen).map_err(|err| err.to_string())?;
+
+    let mut cmd_parts = editor.split_whitespace();
+    let cmd = cmd_parts.next().unwrap_or(&editor);
+    let status = std::process::Command::new(cmd)
+        .args(cmd_parts)
+        .arg(&path)
+        .status();
+
+    enable_raw_mode().map_err(|err| err.to_string())?;
+    execute!(std::io::stdout(), EnterAlternateScreen).map_err(|err| err.to_string())?;
+
+    let status = status.map_err(|err| format!("failed to run {editor:?}: {err}"))?;
+    if !status.success() {
+        let _ = std::fs::remove_file(&path);
+        return Err(format!("editor {editor:?} exited with an error"));
+    }
+
+    let new_text = std::fs::read_to_string(&path).map_err(|err| err.to_string())?;
+    let _ = std::fs::remove_file(&path);
+    let new_text = new_text
+        .strip_suffix('\n')
+        .map(ToString::to_string)
+        .unwrap_or(new_text);
+
+    view.set_current_value(reparse_value(&value, new_text));
+
+    Ok(())
+}
+
+/// Keep the cell's primitive type when the edited text still parses as one;
+/// anything else comes back as a string.
+fn reparse_value(old: &Value, text: String) -> Value {
+    let span = old.span().unwrap_or_else(|_| NuSpan::unknown());
+
+    match old {
+        Value::Int { .. } => {
+            if let Ok(val) = text.trim().parse::<i64>() {
+                return Value::Int { val, span };
+            }
+        }
+        Value::Float { .. } => {
+            if let Ok(val) = text.trim().parse::<f64>() {
+                return Value::Float { val, span };
+            }
+        }
+        Value::Bool { .. } => {
+            if let Ok(val) = text.trim().parse::<bool>() {
+                return Value::Bool { val, span };
+            }
+        }
+        _ => {}
+    }
+
+    Value::String { val: text, span }
+}
+
 fn create_layer(value: Value) -> RecordLayer<'static> {
     let (columns, values) = collect_input(value);
 
@@ -662,7 +844,7 @@ fn build_table_as_list(v: &RecordView) -> Value {
         .iter()
         .cloned()
         .map(|vals| Value::Record {
-            cols: headers.clone(),
+            cols: Arc::new(headers.clone()),
             vals,
             span: NuSpan::unknown(),
         })
@@ -681,7 +863,7 @@ fn build_table_as_record(v: &RecordView) -> Value {
     let vals = layer.records.get(0).map_or(Vec::new(), |row| row.clone());
 
     Value::Record {
-        cols,
+        cols: Arc::new(cols),
         vals,
         span: NuSpan::unknown(),
     }
@@ -1,8 +1,9 @@
 use nu_protocol::{
-    ast::{Expr, Expression},
+    ast::{Expr, Expression, PathMember, PipelineElement},
     engine::StateWorkingSet,
     ParseError, Span, Value,
 };
+use std::sync::Arc;
 
 /// Evaluate a constant value at parse time
 ///
@@ -27,11 +28,30 @@ pub fn eval_constant(
             Some(val) => Ok(val.clone()),
             None => Err(ParseError::NotAConstant(expr.span)),
         },
-        Expr::CellPath(cell_path) => Ok(Value::CellPath {
-            val: cell_path.clone(),
-            span: expr.span,
-        }),
+        Expr::CellPath(cell_path) => {
+            // Computed members need the evaluator to run their subexpressions
+            if cell_path
+                .members
+                .iter()
+                .any(|member| matches!(member, PathMember::Dynamic { .. }))
+            {
+                return Err(ParseError::NotAConstant(expr.span));
+            }
+
+            Ok(Value::CellPath {
+                val: cell_path.clone(),
+                span: expr.span,
+            })
+        }
         Expr::FullCellPath(cell_path) => {
+            if cell_path
+                .tail
+                .iter()
+                .any(|member| matches!(member, PathMember::Dynamic { .. }))
+            {
+                return Err(ParseError::NotAConstant(expr.span));
+            }
+
             let value = eval_constant(working_set, &cell_path.head)?;
 
             match value.follow_cell_path(&cell_path.tail, false) {
@@ -77,7 +97,7 @@ pub fn eval_constant(
             }
 
             Ok(Value::Record {
-                cols,
+                cols: Arc::new(cols),
                 vals,
                 span: expr.span,
             })
@@ -91,6 +111,9 @@ pub fn eval_constant(
                 )?);
             }
 
+            // every row shares one reference-counted copy of the header names
+            let output_headers = Arc::new(output_headers);
+
             let mut output_rows = vec![];
             for val in vals {
                 let mut row = vec![];
@@ -109,6 +132,32 @@ pub fn eval_constant(
             })
         }
         Expr::Keyword(_, _, expr) => eval_constant(working_set, expr),
+        Expr::Subexpression(block_id) => {
+            // Only a subexpression that is a single expression (e.g., `($x)` inside a string
+            // interpolation) can be a constant; anything involving commands needs the engine
+            let block = working_set.get_block(*block_id);
+
+            if let [pipeline] = &block.pipelines[..] {
+                if let [PipelineElement::Expression(_, expr)] = &pipeline.elements[..] {
+                    return eval_constant(working_set, expr);
+                }
+            }
+
+            Err(ParseError::NotAConstant(expr.span))
+        }
+        Expr::StringInterpolation(exprs) => {
+            let config = working_set.get_config();
+
+            let mut val = String::new();
+            for expr in exprs {
+                val.push_str(&eval_constant(working_set, expr)?.into_string("", config));
+            }
+
+            Ok(Value::String {
+                val,
+                span: expr.span,
+            })
+        }
         Expr::String(s) => Ok(Value::String {
             val: s.clone(),
             span: expr.span,
@@ -262,6 +262,9 @@ pub fn flatten_expression(
         Expr::Float(_) => {
             vec![(expr.span, FlatShape::Float)]
         }
+        Expr::Decimal(_) => {
+            vec![(expr.span, FlatShape::Float)]
+        }
         Expr::MatchPattern(pattern) => {
             // FIXME: do nicer flattening later
             flatten_pattern(pattern)
@@ -288,6 +291,9 @@ pub fn flatten_expression(
                 match path_element {
                     PathMember::String { span, .. } => output.push((*span, FlatShape::String)),
                     PathMember::Int { span, .. } => output.push((*span, FlatShape::Int)),
+                    PathMember::Dynamic { block_id, .. } => {
+                        output.extend(flatten_block(working_set, working_set.get_block(*block_id)))
+                    }
                 }
             }
             output
@@ -299,6 +305,9 @@ pub fn flatten_expression(
                 match path_element {
                     PathMember::String { span, .. } => output.push((*span, FlatShape::String)),
                     PathMember::Int { span, .. } => output.push((*span, FlatShape::Int)),
+                    PathMember::Dynamic { block_id, .. } => {
+                        output.extend(flatten_block(working_set, working_set.get_block(*block_id)))
+                    }
                 }
             }
             output
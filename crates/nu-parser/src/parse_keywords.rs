@@ -31,7 +31,12 @@ use crate::{
 };
 
 /// These parser keywords can be aliased
-pub const ALIASABLE_PARSER_KEYWORDS: &[&[u8]] = &[b"overlay hide", b"overlay new", b"overlay use"];
+pub const ALIASABLE_PARSER_KEYWORDS: &[&[u8]] = &[
+    b"overlay hide",
+    b"overlay new",
+    b"overlay restore",
+    b"overlay use",
+];
 
 /// These parser keywords cannot be aliased (either not possible, or support not yet added)
 pub const UNALIASABLE_PARSER_KEYWORDS: &[&[u8]] = &[
@@ -106,6 +111,7 @@ pub fn parse_keyword(
         match cmd.name() {
             "overlay hide" => parse_overlay_hide(working_set, call),
             "overlay new" => parse_overlay_new(working_set, call),
+            "overlay restore" => parse_overlay_restore(working_set, call),
             "overlay use" => parse_overlay_use(working_set, call),
             _ => Pipeline::from_vec(vec![call_expr]),
         }
@@ -300,7 +306,8 @@ pub fn parse_def(
 ) -> Pipeline {
     let spans = &lite_command.parts[..];
 
-    let (usage, extra_usage) = working_set.build_usage(&lite_command.comments);
+    let (usage, extra_usage, doc_examples) =
+        working_set.build_usage_and_examples(&lite_command.comments);
 
     // Checking that the function is used with the correct name
     // Maybe this is not necessary but it is a sanity check
@@ -426,6 +433,7 @@ pub fn parse_def(
             *signature = signature.add_help();
             signature.usage = usage;
             signature.extra_usage = extra_usage;
+            signature.doc_examples = doc_examples;
 
             *declaration = signature.clone().into_block_command(block_id);
 
@@ -832,7 +840,9 @@ pub fn parse_export_in_block(
     let full_name = if lite_command.parts.len() > 1 {
         let sub = working_set.get_span_contents(lite_command.parts[1]);
         match sub {
-            b"alias" | b"def" | b"def-env" | b"extern" | b"use" => [b"export ", sub].concat(),
+            b"alias" | b"const" | b"def" | b"def-env" | b"extern" | b"use" => {
+                [b"export ", sub].concat()
+            }
             _ => b"export".to_vec(),
         }
     } else {
@@ -889,6 +899,7 @@ pub fn parse_export_in_block(
 
     match full_name.as_slice() {
         b"export alias" => parse_alias(working_set, lite_command, None),
+        b"export const" => parse_let_or_const(working_set, &lite_command.parts[1..]),
         b"export def" | b"export def-env" => parse_def(working_set, lite_command, None),
         b"export use" => {
             let (pipeline, _) = parse_use(working_set, &lite_command.parts);
@@ -1193,6 +1204,66 @@ pub fn parse_export_in_module(
 
                 result
             }
+            b"const" => {
+                let lite_command = LiteCommand {
+                    comments: lite_command.comments.clone(),
+                    parts: spans[1..].to_vec(),
+                };
+                let pipeline = parse_let_or_const(working_set, &lite_command.parts);
+
+                let export_const_decl_id =
+                    if let Some(id) = working_set.find_decl(b"export const", &Type::Any) {
+                        id
+                    } else {
+                        working_set.error(ParseError::InternalError(
+                            "missing 'export const' command".into(),
+                            export_span,
+                        ));
+                        return (garbage_pipeline(spans), vec![]);
+                    };
+
+                // Trying to warp the 'const' call into the 'export const' in a very clumsy way
+                if let Some(PipelineElement::Expression(
+                    _,
+                    Expression {
+                        expr: Expr::Call(ref const_call),
+                        ..
+                    },
+                )) = pipeline.elements.get(0)
+                {
+                    call = const_call.clone();
+
+                    call.head = span(&spans[0..=1]);
+                    call.decl_id = export_const_decl_id;
+                } else {
+                    working_set.error(ParseError::InternalError(
+                        "unexpected output from parsing a definition".into(),
+                        span(&spans[1..]),
+                    ));
+                };
+
+                let mut result = vec![];
+
+                let const_name = match spans.get(2) {
+                    Some(span) => working_set.get_span_contents(*span),
+                    None => &[],
+                };
+                let const_name = trim_quotes(const_name);
+
+                if let Some(var_id) = working_set.find_variable(&[b"$", const_name].concat()) {
+                    result.push(Exportable::VarDecl {
+                        name: const_name.to_vec(),
+                        id: var_id,
+                    });
+                } else {
+                    working_set.error(ParseError::InternalError(
+                        "failed to find added constant".into(),
+                        span(&spans[1..]),
+                    ));
+                }
+
+                result
+            }
             b"use" => {
                 let lite_command = LiteCommand {
                     comments: lite_command.comments.clone(),
@@ -1236,7 +1307,7 @@ pub fn parse_export_in_module(
             _ => {
                 working_set.error(ParseError::Expected(
                     // TODO: Fill in more keywords as they come
-                    "def, def-env, alias, use, or extern keyword".into(),
+                    "def, def-env, alias, use, const, or extern keyword".into(),
                     spans[1],
                 ));
 
@@ -1245,9 +1316,9 @@ pub fn parse_export_in_module(
         }
     } else {
         working_set.error(ParseError::MissingPositional(
-            "def, def-env, alias, use, or extern keyword".into(), // TODO: keep filling more keywords as they come
+            "def, def-env, alias, use, const, or extern keyword".into(), // TODO: keep filling more keywords as they come
             Span::new(export_span.end, export_span.end),
-            "`def`, `def-env`, `alias`, use, or `extern` keyword.".to_string(),
+            "`def`, `def-env`, `alias`, `use`, `const`, or `extern` keyword.".to_string(),
         ));
 
         vec![]
@@ -1346,6 +1417,108 @@ pub fn parse_export_env(
     (pipeline, Some(block_id))
 }
 
+pub fn parse_export_meta(
+    working_set: &mut StateWorkingSet,
+    spans: &[Span],
+) -> (Pipeline, Option<String>) {
+    if !spans.is_empty() && working_set.get_span_contents(spans[0]) != b"export-meta" {
+        working_set.error(ParseError::UnknownState(
+            "internal error: Wrong call name for 'export-meta' command".into(),
+            span(spans),
+        ));
+        return (garbage_pipeline(spans), None);
+    }
+
+    if spans.len() < 2 {
+        working_set.error(ParseError::MissingPositional(
+            "metadata".into(),
+            span(spans),
+            "export-meta <record>".into(),
+        ));
+        return (garbage_pipeline(spans), None);
+    }
+
+    let call = match working_set.find_decl(b"export-meta", &Type::Any) {
+        Some(decl_id) => {
+            let ParsedInternalCall { call, output } =
+                parse_internal_call(working_set, spans[0], &[spans[1]], decl_id);
+            let decl = working_set.get_decl(decl_id);
+
+            let call_span = span(spans);
+
+            let starting_error_count = working_set.parse_errors.len();
+            check_call(working_set, call_span, &decl.signature(), &call);
+            if starting_error_count != working_set.parse_errors.len() || call.has_flag("help") {
+                return (
+                    Pipeline::from_vec(vec![Expression {
+                        expr: Expr::Call(call),
+                        span: call_span,
+                        ty: output,
+                        custom_completion: None,
+                    }]),
+                    None,
+                );
+            }
+
+            call
+        }
+        None => {
+            working_set.error(ParseError::UnknownState(
+                "internal error: 'export-meta' declaration not found".into(),
+                span(spans),
+            ));
+            return (garbage_pipeline(spans), None);
+        }
+    };
+
+    // The metadata has to be readable at parse time, so only a record of
+    // literals is accepted.
+    let mut version = None;
+
+    if let Some(metadata) = call.positional_nth(0) {
+        // Records with spaces after the colons parse wrapped in a cell path
+        let metadata = match &metadata.expr {
+            Expr::FullCellPath(cell_path) if cell_path.tail.is_empty() => &cell_path.head,
+            _ => metadata,
+        };
+
+        if let Expr::Record(entries) = &metadata.expr {
+            for (key, val) in entries {
+                match key.as_string().as_deref() {
+                    Some("version") => match val.as_string() {
+                        Some(val) => version = Some(val),
+                        None => {
+                            working_set.error(ParseError::LabeledError(
+                                "Invalid module metadata".into(),
+                                "'version' must be a string literal".into(),
+                                val.span,
+                            ));
+                        }
+                    },
+                    _ => {
+                        working_set.error(ParseError::LabeledError(
+                            "Invalid module metadata".into(),
+                            "unknown metadata key; expected 'version'".into(),
+                            key.span,
+                        ));
+                    }
+                }
+            }
+        } else {
+            working_set.error(ParseError::Expected("record".into(), metadata.span));
+        }
+    }
+
+    let pipeline = Pipeline::from_vec(vec![Expression {
+        expr: Expr::Call(call),
+        span: span(spans),
+        ty: Type::Any,
+        custom_completion: None,
+    }]);
+
+    (pipeline, version)
+}
+
 fn collect_first_comments(tokens: &[Token]) -> Vec<Span> {
     let mut comments = vec![];
 
@@ -1437,6 +1610,10 @@ pub fn parse_module_block(
 
                                 pipeline
                             }
+                            b"const" => {
+                                // Private to the module unless declared with `export const`
+                                parse_let_or_const(working_set, &command.parts)
+                            }
                             b"export" => {
                                 let (pipe, exportables) =
                                     parse_export_in_module(working_set, command, module_name);
@@ -1450,6 +1627,9 @@ pub fn parse_module_block(
                                                 module.add_decl(name, id);
                                             }
                                         }
+                                        Exportable::VarDecl { name, id } => {
+                                            module.add_variable(name, id);
+                                        }
                                     }
                                 }
 
@@ -1465,6 +1645,24 @@ pub fn parse_module_block(
 
                                 pipe
                             }
+                            b"export-meta" => {
+                                let (pipe, maybe_version) =
+                                    parse_export_meta(working_set, &command.parts);
+
+                                if let Some(version) = maybe_version {
+                                    if module.version.is_some() {
+                                        working_set.error(ParseError::LabeledError(
+                                            "Invalid module metadata".into(),
+                                            "module version was already declared".into(),
+                                            command.parts[0],
+                                        ));
+                                    } else {
+                                        module.version = Some(version);
+                                    }
+                                }
+
+                                pipe
+                            }
                             _ => {
                                 working_set.error(ParseError::ExpectedKeyword(
                                     "def or export keyword".into(),
@@ -1487,8 +1685,24 @@ pub fn parse_module_block(
         })
         .into();
 
+    // Constant values live in the scope frame that is about to be dropped; hoist the
+    // exported ones into the enclosing frame so they survive to the merged state.
+    let hoisted_constants: Vec<_> = module
+        .constants
+        .values()
+        .filter_map(|var_id| {
+            working_set
+                .find_constant(*var_id)
+                .map(|val| (*var_id, val.clone()))
+        })
+        .collect();
+
     working_set.exit_scope();
 
+    for (var_id, val) in hoisted_constants {
+        working_set.add_constant(var_id, val);
+    }
+
     (block, module, module_comments)
 }
 
@@ -1575,6 +1789,17 @@ pub fn parse_module(working_set: &mut StateWorkingSet, lite_command: &LiteComman
     }
 }
 
+fn is_version_constraint(name: &[u8]) -> bool {
+    name.iter().any(|b| b.is_ascii_digit()) && name.iter().all(|b| b.is_ascii_digit() || *b == b'.')
+}
+
+// A constraint matches when its dot-separated components are a prefix of the
+// declared version, so `1.2` accepts `1.2.0` through `1.2.x` but not `1.3.0`.
+fn version_satisfies(version: &str, requested: &str) -> bool {
+    let mut declared = version.split('.');
+    requested.split('.').all(|req| declared.next() == Some(req))
+}
+
 pub fn parse_use(working_set: &mut StateWorkingSet, spans: &[Span]) -> (Pipeline, Vec<Exportable>) {
     let (name_span, split_id) =
         if spans.len() > 1 && working_set.get_span_contents(spans[0]) == b"export" {
@@ -1635,7 +1860,49 @@ pub fn parse_use(working_set: &mut StateWorkingSet, spans: &[Span]) -> (Pipeline
         }
     };
 
-    let import_pattern_expr = parse_import_pattern(working_set, args_spans);
+    // A lazy import only records where the module file lives; the module is parsed on the
+    // first reference to one of its commands (see `maybe_load_lazy_module`).
+    if call.has_flag("lazy") {
+        return parse_lazy_use(working_set, call, spans, args_spans);
+    }
+
+    // A second argument that looks like `1.2` is a version constraint on the
+    // module rather than the name of an export.
+    let (version_constraint, pattern_spans) = match args_spans.get(1) {
+        Some(second) if is_version_constraint(working_set.get_span_contents(*second)) => {
+            let requested =
+                String::from_utf8_lossy(working_set.get_span_contents(*second)).to_string();
+
+            let mut pattern_spans = vec![args_spans[0]];
+            pattern_spans.extend_from_slice(&args_spans[2..]);
+
+            (Some((requested, *second)), pattern_spans)
+        }
+        _ => (None, args_spans.to_vec()),
+    };
+
+    // A trailing `as <name>` renames whatever the import brings into scope.
+    let (rename, pattern_spans) = match pattern_spans.len().checked_sub(2) {
+        Some(as_idx)
+            if as_idx > 0 && working_set.get_span_contents(pattern_spans[as_idx]) == b"as" =>
+        {
+            let new_name_span = pattern_spans[as_idx + 1];
+            let new_name = trim_quotes(working_set.get_span_contents(new_name_span)).to_vec();
+
+            (
+                Some((new_name, new_name_span)),
+                pattern_spans[..as_idx].to_vec(),
+            )
+        }
+        _ => (None, pattern_spans),
+    };
+
+    if pattern_spans.len() > 2 {
+        working_set.error(ParseError::WrongImportPattern(span(&pattern_spans[2..])));
+        return (garbage_pipeline(spans), vec![]);
+    }
+
+    let import_pattern_expr = parse_import_pattern(working_set, &pattern_spans);
 
     let import_pattern = if let Expression {
         expr: Expr::ImportPattern(import_pattern),
@@ -1793,22 +2060,91 @@ pub fn parse_use(working_set: &mut StateWorkingSet, spans: &[Span]) -> (Pipeline
         }
     };
 
+    if let Some((requested, constraint_span)) = version_constraint {
+        let module_name = String::from_utf8_lossy(&import_pattern.head.name).to_string();
+
+        match &module.version {
+            Some(version) if version_satisfies(version, &requested) => {}
+            Some(version) => {
+                working_set.error(ParseError::LabeledError(
+                        "Module version mismatch".into(),
+                        format!(
+                            "module '{module_name}' is version {version}, which does not match {requested}"
+                        ),
+                        constraint_span,
+                    ));
+            }
+            None => {
+                working_set.error(ParseError::LabeledError(
+                        "Module version mismatch".into(),
+                        format!(
+                            "module '{module_name}' does not declare a version; add `export-meta {{ version: \"...\" }}` to it"
+                        ),
+                        constraint_span,
+                    ));
+            }
+        }
+    }
+
+    let mut constants_to_use = vec![];
+
     let decls_to_use = if import_pattern.members.is_empty() {
-        module.decls_with_head(&import_pattern.head.name)
+        let head_name = rename
+            .as_ref()
+            .map(|(new_name, _)| new_name.clone())
+            .unwrap_or_else(|| import_pattern.head.name.clone());
+
+        constants_to_use.extend(module.consts());
+
+        module
+            .decls_with_head(&head_name)
+            .into_iter()
+            .map(|(name, id)| {
+                // `decls_with_head` names the `main` export after the module
+                if name == module.name {
+                    (head_name.clone(), id)
+                } else {
+                    (name, id)
+                }
+            })
+            .collect()
     } else {
         match &import_pattern.members[0] {
-            ImportPatternMember::Glob { .. } => module.decls(),
+            ImportPatternMember::Glob { .. } => {
+                if let Some((_, rename_span)) = &rename {
+                    working_set.error(ParseError::LabeledError(
+                        "Cannot rename import".into(),
+                        "`as` can only rename a single import".into(),
+                        *rename_span,
+                    ));
+                }
+
+                constants_to_use.extend(module.consts());
+                module.decls()
+            }
             ImportPatternMember::Name { name, span } => {
                 let mut decl_output = vec![];
 
+                let imported_name = rename
+                    .as_ref()
+                    .map(|(new_name, _)| new_name.clone())
+                    .unwrap_or_else(|| name.clone());
+
                 if name == b"main" {
                     if let Some(id) = &module.main {
-                        decl_output.push((import_pattern.head.name.clone(), *id));
+                        let main_name = rename
+                            .as_ref()
+                            .map(|(new_name, _)| new_name.clone())
+                            .unwrap_or_else(|| import_pattern.head.name.clone());
+
+                        decl_output.push((main_name, *id));
                     } else {
                         working_set.error(ParseError::ExportNotFound(*span));
                     }
                 } else if let Some(id) = module.get_decl_id(name) {
-                    decl_output.push((name.clone(), id));
+                    decl_output.push((imported_name, id));
+                } else if let Some(var_id) = module.get_var_id(name) {
+                    constants_to_use.push((imported_name, var_id));
                 } else {
                     working_set.error(ParseError::ExportNotFound(*span));
                 }
@@ -1816,6 +2152,14 @@ pub fn parse_use(working_set: &mut StateWorkingSet, spans: &[Span]) -> (Pipeline
                 decl_output
             }
             ImportPatternMember::List { names } => {
+                if let Some((_, rename_span)) = &rename {
+                    working_set.error(ParseError::LabeledError(
+                        "Cannot rename import".into(),
+                        "`as` can only rename a single import".into(),
+                        *rename_span,
+                    ));
+                }
+
                 let mut decl_output = vec![];
 
                 for (name, span) in names {
@@ -1827,6 +2171,8 @@ pub fn parse_use(working_set: &mut StateWorkingSet, spans: &[Span]) -> (Pipeline
                         }
                     } else if let Some(id) = module.get_decl_id(name) {
                         decl_output.push((name.clone(), id));
+                    } else if let Some(var_id) = module.get_var_id(name) {
+                        constants_to_use.push((name.clone(), var_id));
                     } else {
                         working_set.error(ParseError::ExportNotFound(*span));
                         break;
@@ -1838,7 +2184,7 @@ pub fn parse_use(working_set: &mut StateWorkingSet, spans: &[Span]) -> (Pipeline
         }
     };
 
-    let exportables = decls_to_use
+    let mut exportables: Vec<Exportable> = decls_to_use
         .iter()
         .map(|(name, decl_id)| Exportable::Decl {
             name: name.clone(),
@@ -1846,8 +2192,18 @@ pub fn parse_use(working_set: &mut StateWorkingSet, spans: &[Span]) -> (Pipeline
         })
         .collect();
 
+    exportables.extend(
+        constants_to_use
+            .iter()
+            .map(|(name, var_id)| Exportable::VarDecl {
+                name: name.clone(),
+                id: *var_id,
+            }),
+    );
+
     // Extend the current scope with the module's exportables
     working_set.use_decls(decls_to_use);
+    working_set.use_variables(constants_to_use);
 
     // Create a new Use command call to pass the new import pattern
     let import_pattern_expr = Expression {
@@ -1871,6 +2227,118 @@ pub fn parse_use(working_set: &mut StateWorkingSet, spans: &[Span]) -> (Pipeline
     )
 }
 
+fn parse_lazy_use(
+    working_set: &mut StateWorkingSet,
+    call: Box<Call>,
+    spans: &[Span],
+    args_spans: &[Span],
+) -> (Pipeline, Vec<Exportable>) {
+    let pipeline = Pipeline::from_vec(vec![Expression {
+        expr: Expr::Call(call),
+        span: span(spans),
+        ty: Type::Any,
+        custom_completion: None,
+    }]);
+
+    // `args_spans` still contains the `--lazy` flag itself
+    let pattern_spans: Vec<Span> = args_spans
+        .iter()
+        .copied()
+        .filter(|s| working_set.get_span_contents(*s) != b"--lazy")
+        .collect();
+
+    let name_span = if let [name_span] = pattern_spans[..] {
+        name_span
+    } else {
+        working_set.error(ParseError::LabeledError(
+            "Invalid lazy import".into(),
+            "`use --lazy` takes a single module file and cannot select members".into(),
+            span(spans),
+        ));
+        return (pipeline, vec![]);
+    };
+
+    let name_bytes = working_set.get_span_contents(name_span).to_vec();
+    let (module_filename, err) = unescape_unquote_string(&name_bytes, name_span);
+    if let Some(err) = err {
+        working_set.error(err);
+        return (pipeline, vec![]);
+    }
+
+    let cwd = working_set.get_cwd();
+
+    match find_in_dirs(&module_filename, working_set, &cwd, LIB_DIRS_VAR) {
+        Some(module_path) if module_path.is_file() => {
+            let module_name = if let Some(stem) = module_path.file_stem() {
+                stem.to_string_lossy().to_string()
+            } else {
+                working_set.error(ParseError::ModuleNotFound(name_span));
+                return (pipeline, vec![]);
+            };
+
+            working_set.add_lazy_module(module_name.as_bytes(), module_path);
+        }
+        _ => working_set.error(ParseError::ModuleNotFound(name_span)),
+    }
+
+    (pipeline, vec![])
+}
+
+/// Parse and import a module that was registered with `use --lazy`, if `head_span` names one.
+///
+/// Called when resolving a call head. The module's exports are brought into scope prefixed
+/// with the module name, exactly as an eager `use <module>` would have done, so the lookup
+/// that triggered the load can then succeed.
+pub fn maybe_load_lazy_module(working_set: &mut StateWorkingSet, head_span: Span) {
+    let head = working_set.get_span_contents(head_span).to_vec();
+
+    // Once loaded (or shadowed by a regular module of the same name), there is nothing to do
+    if working_set.find_module(&head).is_some() {
+        return;
+    }
+
+    let module_path = if let Some(module_path) = working_set.find_lazy_module(&head) {
+        module_path.clone()
+    } else {
+        return;
+    };
+
+    let module_name = String::from_utf8_lossy(&head).to_string();
+
+    if let Ok(contents) = std::fs::read(&module_path) {
+        let file_id = working_set.add_file(module_path.to_string_lossy().to_string(), &contents);
+        let new_span = working_set.get_span_for_file(file_id);
+
+        // Change the currently parsed directory
+        let prev_currently_parsed_cwd = if let Some(parent) = module_path.parent() {
+            let prev = working_set.currently_parsed_cwd.clone();
+
+            working_set.currently_parsed_cwd = Some(parent.into());
+
+            prev
+        } else {
+            working_set.currently_parsed_cwd.clone()
+        };
+
+        working_set.parsed_module_files.push(module_path);
+
+        let (block, module, module_comments) =
+            parse_module_block(working_set, new_span, module_name.as_bytes());
+
+        working_set.parsed_module_files.pop();
+
+        working_set.currently_parsed_cwd = prev_currently_parsed_cwd;
+
+        let _ = working_set.add_block(block);
+        let _ = working_set.add_module(&module_name, module.clone(), module_comments);
+
+        working_set.use_decls(module.decls_with_head(&head));
+        working_set.use_variables(module.consts());
+    } else {
+        working_set.error(ParseError::ModuleNotFound(head_span));
+    }
+}
+
 pub fn parse_hide(working_set: &mut StateWorkingSet, spans: &[Span]) -> Pipeline {
     if working_set.get_span_contents(spans[0]) != b"hide" {
         working_set.error(ParseError::UnknownState(
@@ -2393,6 +2861,82 @@ pub fn parse_overlay_hide(working_set: &mut StateWorkingSet, call: Box<Call>) ->
     pipeline
 }
 
+/// The file that `overlay save` and `overlay restore` persist the active overlay set to:
+/// `$env.NU_OVERLAY_STATE` if set, otherwise `overlay-state.txt` next to the rest of the config.
+fn overlay_state_file(working_set: &StateWorkingSet) -> Option<PathBuf> {
+    if let Some(path) = working_set.get_env_var("NU_OVERLAY_STATE") {
+        if let Ok(path) = path.as_string() {
+            return Some(PathBuf::from(path));
+        }
+    }
+
+    let mut path = nu_path::config_dir()?;
+    path.push("nushell");
+    path.push("overlay-state.txt");
+    Some(path)
+}
+
+pub fn parse_overlay_restore(working_set: &mut StateWorkingSet, call: Box<Call>) -> Pipeline {
+    let call_span = call.span();
+
+    let pipeline = Pipeline::from_vec(vec![Expression {
+        expr: Expr::Call(call),
+        span: call_span,
+        ty: Type::Any,
+        custom_completion: None,
+    }]);
+
+    let state_path = if let Some(path) = overlay_state_file(working_set) {
+        path
+    } else {
+        working_set.error(ParseError::LabeledError(
+            "Could not determine the overlay state file location".into(),
+            "set $env.NU_OVERLAY_STATE to a file path".into(),
+            call_span,
+        ));
+        return pipeline;
+    };
+
+    let contents = if let Ok(contents) = std::fs::read_to_string(&state_path) {
+        contents
+    } else {
+        working_set.error(ParseError::LabeledError(
+            "No saved overlay state".into(),
+            format!(
+                "run `overlay save` first; expected the overlay state at {}",
+                state_path.display()
+            ),
+            call_span,
+        ));
+        return pipeline;
+    };
+
+    for name in contents.lines().map(str::trim).filter(|s| !s.is_empty()) {
+        if let Some(overlay_frame) = working_set.find_overlay(name.as_bytes()) {
+            // Re-activate a known overlay; its definitions are already in place
+            let origin = overlay_frame.origin;
+            let prefixed = overlay_frame.prefixed;
+
+            working_set.add_overlay(name.as_bytes().to_vec(), origin, vec![], prefixed);
+        } else if let Some(module_id) = working_set.find_module(name.as_bytes()) {
+            // The overlay is not known in this session, but a module of that name is in
+            // scope, so create the overlay from it like `overlay use` would
+            let module = working_set.get_module(module_id).clone();
+
+            working_set.add_overlay(name.as_bytes().to_vec(), module_id, module.decls(), false);
+        } else {
+            working_set.error(ParseError::LabeledError(
+                "Cannot restore overlay".into(),
+                format!("'{name}' is neither a known overlay nor a module in scope"),
+                call_span,
+            ));
+            return pipeline;
+        }
+    }
+
+    pipeline
+}
+
 pub fn parse_let_or_const(working_set: &mut StateWorkingSet, spans: &[Span]) -> Pipeline {
     let name = working_set.get_span_contents(spans[0]);
 
@@ -2848,7 +3392,7 @@ pub fn parse_register(working_set: &mut StateWorkingSet, spans: &[Span]) -> Pipe
             let filename = value_as_string(val, expr.span)?;
 
             let Some(path) = find_in_dirs(&filename, working_set, &cwd, PLUGIN_DIRS_VAR) else {
-                return Err(ParseError::RegisteredFileNotFound(filename, expr.span))
+                return Err(ParseError::RegisteredFileNotFound(filename, expr.span));
             };
 
             if path.exists() && path.is_file() {
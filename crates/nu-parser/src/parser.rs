@@ -16,15 +16,15 @@ use nu_protocol::{
         Operator, PathMember, Pattern, Pipeline, PipelineElement, RangeInclusion, RangeOperator,
     },
     engine::StateWorkingSet,
-    span, BlockId, Flag, ParseError, PositionalArg, Signature, Span, Spanned, SyntaxShape, Type,
-    Unit, VarId, ENV_VARIABLE_ID, IN_VARIABLE_ID,
+    span, BlockId, Decimal, Flag, ParseError, PositionalArg, Signature, Span, Spanned, SyntaxShape,
+    Type, Unit, VarId, ENV_VARIABLE_ID, IN_VARIABLE_ID,
 };
 
 use crate::parse_keywords::{
-    find_dirs_var, is_unaliasable_parser_keyword, parse_alias, parse_def, parse_def_predecl,
-    parse_export_in_block, parse_extern, parse_for, parse_hide, parse_keyword, parse_let_or_const,
-    parse_module, parse_overlay_hide, parse_overlay_new, parse_overlay_use, parse_source,
-    parse_use, parse_where, parse_where_expr, LIB_DIRS_VAR,
+    find_dirs_var, is_unaliasable_parser_keyword, maybe_load_lazy_module, parse_alias, parse_def,
+    parse_def_predecl, parse_export_in_block, parse_extern, parse_for, parse_hide, parse_keyword,
+    parse_let_or_const, parse_module, parse_overlay_hide, parse_overlay_new, parse_overlay_restore,
+    parse_overlay_use, parse_source, parse_use, parse_where, parse_where_expr, LIB_DIRS_VAR,
 };
 
 use itertools::Itertools;
@@ -1031,6 +1031,10 @@ pub fn parse_call(
         return garbage(head);
     }
 
+    // A module imported with `use --lazy` is not parsed until one of its commands is
+    // referenced; if the head word names one, bring its exports into scope first
+    maybe_load_lazy_module(working_set, spans[0]);
+
     let mut pos = 0;
     let cmd_start = pos;
     let mut name_spans = vec![];
@@ -1353,6 +1357,26 @@ pub fn parse_float(working_set: &mut StateWorkingSet, span: Span) -> Expression
     }
 }
 
+pub fn parse_decimal(working_set: &mut StateWorkingSet, span: Span) -> Expression {
+    let token = working_set.get_span_contents(span);
+    let token = strip_underscores(token);
+
+    if let Some(digits) = token.strip_suffix('d') {
+        if let Ok(x) = digits.parse::<Decimal>() {
+            return Expression {
+                expr: Expr::Decimal(x),
+                span,
+                ty: Type::Decimal,
+                custom_completion: None,
+            };
+        }
+    }
+
+    working_set.error(ParseError::Expected("decimal".into(), span));
+
+    garbage(span)
+}
+
 pub fn parse_number(working_set: &mut StateWorkingSet, span: Span) -> Expression {
     let starting_error_count = working_set.parse_errors.len();
 
@@ -1367,6 +1391,17 @@ pub fn parse_number(working_set: &mut StateWorkingSet, span: Span) -> Expression
         working_set.parse_errors.truncate(starting_error_count);
     }
 
+    let result = parse_decimal(working_set, span);
+    if starting_error_count == working_set.parse_errors.len() {
+        return result;
+    } else if !matches!(
+        working_set.parse_errors.last(),
+        Some(ParseError::Expected(_, _))
+    ) {
+    } else {
+        working_set.parse_errors.truncate(starting_error_count);
+    }
+
     let result = parse_float(working_set, span);
 
     if starting_error_count == working_set.parse_errors.len() {
@@ -1378,6 +1413,36 @@ pub fn parse_number(working_set: &mut StateWorkingSet, span: Span) -> Expression
     garbage(span)
 }
 
+/// Parse one bound of a range. Bounds are usually numbers, but dates and single
+/// characters also form ranges.
+fn parse_range_bound(working_set: &mut StateWorkingSet, span: Span) -> Expression {
+    let starting_error_count = working_set.parse_errors.len();
+
+    let expr = parse_value(working_set, span, &SyntaxShape::Number);
+    if working_set.parse_errors.len() == starting_error_count {
+        return expr;
+    }
+    working_set.parse_errors.truncate(starting_error_count);
+
+    let expr = parse_value(working_set, span, &SyntaxShape::DateTime);
+    if working_set.parse_errors.len() == starting_error_count {
+        return expr;
+    }
+    working_set.parse_errors.truncate(starting_error_count);
+
+    let expr = parse_value(working_set, span, &SyntaxShape::String);
+    if working_set.parse_errors.len() == starting_error_count {
+        if matches!(&expr.expr, Expr::String(s) if s.chars().count() == 1) {
+            return expr;
+        }
+        working_set.error(ParseError::Expected(
+            "range bound: a number, date, or single character".into(),
+            span,
+        ));
+    }
+    expr
+}
+
 pub fn parse_range(working_set: &mut StateWorkingSet, span: Span) -> Expression {
     trace!("parsing: range");
 
@@ -1397,7 +1462,33 @@ pub fn parse_range(working_set: &mut StateWorkingSet, span: Span) -> Expression
         return garbage(span);
     };
 
-    if !token.contains("..") {
+    // First, figure out what exact operators are used and determine their positions.
+    // Only `..` occurrences outside of quotes count, so that a quoted string like
+    // '..' or "a..b" is not mistaken for a range.
+    let dotdot_pos: Vec<usize> = {
+        let bytes = token.as_bytes();
+        let mut positions = vec![];
+        let mut quote: Option<u8> = None;
+        let mut idx = 0;
+        while idx < bytes.len() {
+            let c = bytes[idx];
+            if let Some(q) = quote {
+                if c == q {
+                    quote = None;
+                }
+            } else if matches!(c, b'"' | b'\'' | b'`') {
+                quote = Some(c);
+            } else if c == b'.' && bytes.get(idx + 1) == Some(&b'.') {
+                positions.push(idx);
+                idx += 2;
+                continue;
+            }
+            idx += 1;
+        }
+        positions
+    };
+
+    if dotdot_pos.is_empty() {
         working_set.error(ParseError::Expected(
             "at least one range bound set".into(),
             span,
@@ -1405,9 +1496,6 @@ pub fn parse_range(working_set: &mut StateWorkingSet, span: Span) -> Expression
         return garbage(span);
     }
 
-    // First, figure out what exact operators are used and determine their positions
-    let dotdot_pos: Vec<_> = token.match_indices("..").map(|(pos, _)| pos).collect();
-
     let (next_op_pos, range_op_pos) = match dotdot_pos.len() {
         1 => (None, dotdot_pos[0]),
         2 => (Some(dotdot_pos[0]), dotdot_pos[1]),
@@ -1452,22 +1540,14 @@ pub fn parse_range(working_set: &mut StateWorkingSet, span: Span) -> Expression
         None
     } else {
         let from_span = Span::new(span.start, span.start + dotdot_pos[0]);
-        Some(Box::new(parse_value(
-            working_set,
-            from_span,
-            &SyntaxShape::Number,
-        )))
+        Some(Box::new(parse_range_bound(working_set, from_span)))
     };
 
     let to = if token.ends_with(range_op_str) {
         None
     } else {
         let to_span = Span::new(range_op_span.end, span.end);
-        Some(Box::new(parse_value(
-            working_set,
-            to_span,
-            &SyntaxShape::Number,
-        )))
+        Some(Box::new(parse_range_bound(working_set, to_span)))
     };
 
     trace!("-- from: {:?} to: {:?}", from, to);
@@ -1485,17 +1565,37 @@ pub fn parse_range(working_set: &mut StateWorkingSet, span: Span) -> Expression
         let next_span = Span::new(next_op_span.end, range_op_span.start);
 
         (
-            Some(Box::new(parse_value(
-                working_set,
-                next_span,
-                &SyntaxShape::Number,
-            ))),
+            Some(Box::new(parse_range_bound(working_set, next_span))),
             next_op_span,
         )
     } else {
         (None, span)
     };
 
+    // Mixed bound types (say, a number and a character) can never form a range,
+    // so reject them at parse time when both bounds are known.
+    fn bound_kind(ty: &Type) -> Option<u8> {
+        match ty {
+            Type::Int | Type::Float | Type::Number | Type::Decimal | Type::BigInt => Some(0),
+            Type::Date => Some(1),
+            Type::String => Some(2),
+            _ => None,
+        }
+    }
+    if let (Some(from), Some(to)) = (&from, &to) {
+        let (from_kind, to_kind) = (bound_kind(&from.ty), bound_kind(&to.ty));
+        // Date and char bounds must be paired with a bound of the same type; numeric
+        // bounds stay permissive, since the other side may be e.g. an untyped variable.
+        let date_or_char = |kind: Option<u8>| matches!(kind, Some(1) | Some(2));
+        if (date_or_char(from_kind) || date_or_char(to_kind)) && from_kind != to_kind {
+            working_set.error(ParseError::Expected(
+                "range bounds of the same type".into(),
+                span,
+            ));
+            return garbage(span);
+        }
+    }
+
     let range_op = RangeOperator {
         inclusion,
         span: range_op_span,
@@ -1854,17 +1954,23 @@ pub fn parse_cell_path(
     };
 
     let mut tail = vec![];
+    // Set when a `?` shows up before the dot (`$rec?.foo`), which marks the
+    // member that follows as optional, same as the trailing form (`$rec.foo?`)
+    let mut next_optional = false;
 
     for path_element in tokens {
         let bytes = working_set.get_span_contents(path_element.span);
 
         match expected_token {
             TokenType::Dot => {
-                if bytes.len() != 1 || bytes[0] != b'.' {
+                if bytes.len() == 1 && bytes[0] == b'?' {
+                    next_optional = true;
+                } else if bytes.len() != 1 || bytes[0] != b'.' {
                     working_set.error(ParseError::Expected('.'.into(), path_element.span));
                     return tail;
+                } else {
+                    expected_token = TokenType::PathMember;
                 }
-                expected_token = TokenType::PathMember;
             }
             TokenType::QuestionOrDot => {
                 if bytes.len() == 1 && bytes[0] == b'.' {
@@ -1878,6 +1984,9 @@ pub fn parse_cell_path(
                             PathMember::Int {
                                 ref mut optional, ..
                             } => *optional = true,
+                            PathMember::Dynamic {
+                                ref mut optional, ..
+                            } => *optional = true,
                         }
                     }
                     expected_token = TokenType::Dot;
@@ -1887,6 +1996,17 @@ pub fn parse_cell_path(
                 }
             }
             TokenType::PathMember => {
+                if bytes.starts_with(b"(") {
+                    tail.push(parse_dynamic_path_member(
+                        working_set,
+                        path_element.span,
+                        next_optional,
+                    ));
+                    next_optional = false;
+                    expected_token = TokenType::QuestionOrDot;
+                    continue;
+                }
+
                 let starting_error_count = working_set.parse_errors.len();
 
                 let expr = parse_int(working_set, path_element.span);
@@ -1900,7 +2020,7 @@ pub fn parse_cell_path(
                     } => tail.push(PathMember::Int {
                         val: val as usize,
                         span,
-                        optional: false,
+                        optional: next_optional,
                     }),
                     _ => {
                         let result = parse_string(working_set, path_element.span);
@@ -1913,7 +2033,7 @@ pub fn parse_cell_path(
                                 tail.push(PathMember::String {
                                     val: string,
                                     span,
-                                    optional: false,
+                                    optional: next_optional,
                                 });
                             }
                             _ => {
@@ -1926,6 +2046,7 @@ pub fn parse_cell_path(
                         }
                     }
                 }
+                next_optional = false;
                 expected_token = TokenType::QuestionOrDot;
             }
         }
@@ -1934,6 +2055,61 @@ pub fn parse_cell_path(
     tail
 }
 
+/// Parse a computed cell path member like the `($key)` in `$record.($key)`.
+/// The parenthesized subexpression must produce an int or a string; the
+/// evaluator runs it each time the path is followed.
+fn parse_dynamic_path_member(
+    working_set: &mut StateWorkingSet,
+    span: Span,
+    optional: bool,
+) -> PathMember {
+    let bytes = working_set.get_span_contents(span);
+
+    let mut start = span.start;
+    let mut end = span.end;
+
+    if bytes.starts_with(b"(") {
+        start += 1;
+    }
+    if bytes.ends_with(b")") {
+        end -= 1;
+    } else {
+        working_set.error(ParseError::Unclosed(")".into(), Span::new(end, end)));
+    }
+
+    let inner_span = Span::new(start, end);
+    let source = working_set.get_span_contents(inner_span);
+
+    let (output, err) = lex(source, inner_span.start, &[b'\n', b'\r'], &[], true);
+    if let Some(err) = err {
+        working_set.error(err)
+    }
+
+    let output = parse_block(working_set, &output, true, true);
+    working_set
+        .type_scope
+        .add_type(working_set.type_scope.get_last_output());
+
+    match working_set.type_scope.get_last_output() {
+        Type::Int | Type::Number | Type::String | Type::Any => {}
+        ty => {
+            working_set.error(ParseError::Mismatch(
+                "int or string".into(),
+                ty.to_string(),
+                span,
+            ));
+        }
+    }
+
+    let block_id = working_set.add_block(output);
+
+    PathMember::Dynamic {
+        block_id,
+        span,
+        optional,
+    }
+}
+
 pub fn parse_simple_cell_path(working_set: &mut StateWorkingSet, span: Span) -> Expression {
     let source = working_set.get_span_contents(span);
 
@@ -4911,6 +5087,15 @@ pub fn parse_expression(
 
         // For now, check for special parses of certain keywords
         match bytes.as_slice() {
+            // `module export-list` is a regular command, not the `module` keyword
+            b"module"
+                if spans
+                    .get(pos + 1)
+                    .map(|span| working_set.get_span_contents(*span) == b"export-list")
+                    .unwrap_or(false) =>
+            {
+                parse_call(working_set, &spans[pos..], spans[0], is_subexpression)
+            }
             b"def" | b"extern" | b"for" | b"module" | b"use" | b"source" | b"alias" | b"export"
             | b"hide" => {
                 working_set.error(ParseError::BuiltinCommandInPipeline(
@@ -5071,6 +5256,7 @@ pub fn parse_builtin_commands(
                     match cmd.name() {
                         "overlay hide" => return parse_overlay_hide(working_set, call),
                         "overlay new" => return parse_overlay_new(working_set, call),
+                        "overlay restore" => return parse_overlay_restore(working_set, call),
                         "overlay use" => return parse_overlay_use(working_set, call),
                         _ => { /* this alias is not a parser keyword */ }
                     }
@@ -5091,7 +5277,16 @@ pub fn parse_builtin_commands(
             Pipeline::from_vec(vec![expr])
         }
         b"alias" => parse_alias(working_set, lite_command, None),
-        b"module" => parse_module(working_set, lite_command),
+        b"module"
+            if lite_command
+                .parts
+                .get(1)
+                .map(|span| working_set.get_span_contents(*span) != b"export-list")
+                .unwrap_or(true) =>
+        {
+            // `module export-list` is a regular command, not the parser keyword
+            parse_module(working_set, lite_command)
+        }
         b"use" => {
             let (pipeline, _) = parse_use(working_set, &lite_command.parts);
             pipeline
@@ -5577,6 +5772,7 @@ pub fn discover_captures_in_expr(
         Expr::Filepath(_) => {}
         Expr::Directory(_) => {}
         Expr::Float(_) => {}
+        Expr::Decimal(_) => {}
         Expr::FullCellPath(cell_path) => {
             let result =
                 discover_captures_in_expr(working_set, &cell_path.head, seen, seen_blocks)?;
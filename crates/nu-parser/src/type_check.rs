@@ -45,6 +45,12 @@ pub fn math_result_type(
                 (Type::Float, Type::Int) => (Type::Float, None),
                 (Type::Int, Type::Float) => (Type::Float, None),
                 (Type::Float, Type::Float) => (Type::Float, None),
+                (Type::Decimal, Type::Int) => (Type::Decimal, None),
+                (Type::Int, Type::Decimal) => (Type::Decimal, None),
+                (Type::Decimal, Type::Decimal) => (Type::Decimal, None),
+                (Type::BigInt, Type::Int) => (Type::BigInt, None),
+                (Type::Int, Type::BigInt) => (Type::BigInt, None),
+                (Type::BigInt, Type::BigInt) => (Type::BigInt, None),
                 (Type::String, Type::String) => (Type::String, None),
                 (Type::Date, Type::Duration) => (Type::Date, None),
                 (Type::Duration, Type::Duration) => (Type::Duration, None),
@@ -141,6 +147,12 @@ pub fn math_result_type(
                 (Type::Float, Type::Int) => (Type::Float, None),
                 (Type::Int, Type::Float) => (Type::Float, None),
                 (Type::Float, Type::Float) => (Type::Float, None),
+                (Type::Decimal, Type::Int) => (Type::Decimal, None),
+                (Type::Int, Type::Decimal) => (Type::Decimal, None),
+                (Type::Decimal, Type::Decimal) => (Type::Decimal, None),
+                (Type::BigInt, Type::Int) => (Type::BigInt, None),
+                (Type::Int, Type::BigInt) => (Type::BigInt, None),
+                (Type::BigInt, Type::BigInt) => (Type::BigInt, None),
                 (Type::Date, Type::Date) => (Type::Duration, None),
                 (Type::Date, Type::Duration) => (Type::Date, None),
                 (Type::Duration, Type::Duration) => (Type::Duration, None),
@@ -183,6 +195,12 @@ pub fn math_result_type(
                 (Type::Float, Type::Int) => (Type::Float, None),
                 (Type::Int, Type::Float) => (Type::Float, None),
                 (Type::Float, Type::Float) => (Type::Float, None),
+                (Type::Decimal, Type::Int) => (Type::Decimal, None),
+                (Type::Int, Type::Decimal) => (Type::Decimal, None),
+                (Type::Decimal, Type::Decimal) => (Type::Decimal, None),
+                (Type::BigInt, Type::Int) => (Type::BigInt, None),
+                (Type::Int, Type::BigInt) => (Type::BigInt, None),
+                (Type::BigInt, Type::BigInt) => (Type::BigInt, None),
                 (Type::Filesize, Type::Int) => (Type::Filesize, None),
                 (Type::Int, Type::Filesize) => (Type::Filesize, None),
                 (Type::Filesize, Type::Float) => (Type::Filesize, None),
@@ -239,6 +257,8 @@ pub fn math_result_type(
                 (Type::Float, Type::Int) => (Type::Float, None),
                 (Type::Int, Type::Float) => (Type::Float, None),
                 (Type::Float, Type::Float) => (Type::Float, None),
+                (Type::Decimal, Type::Int) => (Type::Decimal, None),
+                (Type::BigInt, Type::Int) => (Type::BigInt, None),
 
                 (Type::Custom(a), Type::Custom(b)) if a == b => (Type::Custom(a.to_string()), None),
                 (Type::Custom(a), _) => (Type::Custom(a.to_string()), None),
@@ -278,6 +298,12 @@ pub fn math_result_type(
                 (Type::Float, Type::Int) => (Type::Float, None),
                 (Type::Int, Type::Float) => (Type::Float, None),
                 (Type::Float, Type::Float) => (Type::Float, None),
+                (Type::Decimal, Type::Int) => (Type::Decimal, None),
+                (Type::Int, Type::Decimal) => (Type::Decimal, None),
+                (Type::Decimal, Type::Decimal) => (Type::Decimal, None),
+                (Type::BigInt, Type::Int) => (Type::BigInt, None),
+                (Type::Int, Type::BigInt) => (Type::BigInt, None),
+                (Type::BigInt, Type::BigInt) => (Type::BigInt, None),
                 (Type::Filesize, Type::Filesize) => (Type::Float, None),
                 (Type::Filesize, Type::Int) => (Type::Filesize, None),
                 (Type::Filesize, Type::Float) => (Type::Filesize, None),
@@ -404,10 +430,7 @@ pub fn math_result_type(
                 }
             }
             Operator::Comparison(Comparison::LessThan) => match (&lhs.ty, &rhs.ty) {
-                (Type::Int, Type::Int) => (Type::Bool, None),
-                (Type::Float, Type::Int) => (Type::Bool, None),
-                (Type::Int, Type::Float) => (Type::Bool, None),
-                (Type::Float, Type::Float) => (Type::Bool, None),
+                (lhs, rhs) if lhs.is_numeric() && rhs.is_numeric() => (Type::Bool, None),
                 (Type::Duration, Type::Duration) => (Type::Bool, None),
                 (Type::Filesize, Type::Filesize) => (Type::Bool, None),
 
@@ -447,10 +470,7 @@ pub fn math_result_type(
                 }
             },
             Operator::Comparison(Comparison::LessThanOrEqual) => match (&lhs.ty, &rhs.ty) {
-                (Type::Int, Type::Int) => (Type::Bool, None),
-                (Type::Float, Type::Int) => (Type::Bool, None),
-                (Type::Int, Type::Float) => (Type::Bool, None),
-                (Type::Float, Type::Float) => (Type::Bool, None),
+                (lhs, rhs) if lhs.is_numeric() && rhs.is_numeric() => (Type::Bool, None),
                 (Type::Duration, Type::Duration) => (Type::Bool, None),
                 (Type::Filesize, Type::Filesize) => (Type::Bool, None),
 
@@ -490,10 +510,7 @@ pub fn math_result_type(
                 }
             },
             Operator::Comparison(Comparison::GreaterThan) => match (&lhs.ty, &rhs.ty) {
-                (Type::Int, Type::Int) => (Type::Bool, None),
-                (Type::Float, Type::Int) => (Type::Bool, None),
-                (Type::Int, Type::Float) => (Type::Bool, None),
-                (Type::Float, Type::Float) => (Type::Bool, None),
+                (lhs, rhs) if lhs.is_numeric() && rhs.is_numeric() => (Type::Bool, None),
                 (Type::Duration, Type::Duration) => (Type::Bool, None),
                 (Type::Filesize, Type::Filesize) => (Type::Bool, None),
 
@@ -533,10 +550,7 @@ pub fn math_result_type(
                 }
             },
             Operator::Comparison(Comparison::GreaterThanOrEqual) => match (&lhs.ty, &rhs.ty) {
-                (Type::Int, Type::Int) => (Type::Bool, None),
-                (Type::Float, Type::Int) => (Type::Bool, None),
-                (Type::Int, Type::Float) => (Type::Bool, None),
-                (Type::Float, Type::Float) => (Type::Bool, None),
+                (lhs, rhs) if lhs.is_numeric() && rhs.is_numeric() => (Type::Bool, None),
                 (Type::Duration, Type::Duration) => (Type::Bool, None),
                 (Type::Filesize, Type::Filesize) => (Type::Bool, None),
 
@@ -729,7 +743,16 @@ pub fn math_result_type(
             },
             Operator::Comparison(Comparison::In) => match (&lhs.ty, &rhs.ty) {
                 (t, Type::List(u)) if type_compatible(t, u) => (Type::Bool, None),
-                (Type::Int | Type::Float, Type::Range) => (Type::Bool, None),
+                (
+                    Type::Int
+                    | Type::Float
+                    | Type::Decimal
+                    | Type::BigInt
+                    | Type::Number
+                    | Type::Date
+                    | Type::String,
+                    Type::Range,
+                ) => (Type::Bool, None),
                 (Type::String, Type::String) => (Type::Bool, None),
                 (Type::String, Type::Record(_)) => (Type::Bool, None),
 
@@ -767,7 +790,16 @@ pub fn math_result_type(
             },
             Operator::Comparison(Comparison::NotIn) => match (&lhs.ty, &rhs.ty) {
                 (t, Type::List(u)) if type_compatible(t, u) => (Type::Bool, None),
-                (Type::Int | Type::Float, Type::Range) => (Type::Bool, None),
+                (
+                    Type::Int
+                    | Type::Float
+                    | Type::Decimal
+                    | Type::BigInt
+                    | Type::Number
+                    | Type::Date
+                    | Type::String,
+                    Type::Range,
+                ) => (Type::Bool, None),
                 (Type::String, Type::String) => (Type::Bool, None),
                 (Type::String, Type::Record(_)) => (Type::Bool, None),
 
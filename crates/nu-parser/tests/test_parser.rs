@@ -324,6 +324,55 @@ pub fn parse_int_with_underscores() {
     ))
 }
 
+#[test]
+pub fn parse_decimal_literal() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let block = parse(&mut working_set, None, b"1.10d", true);
+
+    assert!(working_set.parse_errors.is_empty());
+    assert_eq!(block.len(), 1);
+    let expressions = &block[0];
+    assert_eq!(expressions.len(), 1);
+    if let PipelineElement::Expression(
+        _,
+        Expression {
+            expr: Expr::Decimal(val),
+            ..
+        },
+    ) = &expressions[0]
+    {
+        // the scale of the literal is kept
+        assert_eq!(val.to_string(), "1.10")
+    } else {
+        panic!("not a decimal")
+    }
+}
+
+#[test]
+pub fn parse_decimal_requires_the_suffix() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let block = parse(&mut working_set, None, b"1.10", true);
+
+    assert!(working_set.parse_errors.is_empty());
+    assert_eq!(block.len(), 1);
+    let expressions = &block[0];
+    assert_eq!(expressions.len(), 1);
+    assert!(matches!(
+        expressions[0],
+        PipelineElement::Expression(
+            _,
+            Expression {
+                expr: Expr::Float(_),
+                ..
+            }
+        )
+    ))
+}
+
 #[test]
 pub fn parse_cell_path() {
     let engine_state = EngineState::new();
@@ -433,6 +482,142 @@ pub fn parse_cell_path_optional() {
     }
 }
 
+#[test]
+pub fn parse_cell_path_optional_prefix() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    working_set.add_variable(
+        "foo".to_string().into_bytes(),
+        Span::test_data(),
+        nu_protocol::Type::Record(vec![]),
+        false,
+    );
+
+    let block = parse(&mut working_set, None, b"$foo?.bar", true);
+
+    assert!(working_set.parse_errors.is_empty());
+    assert_eq!(block.len(), 1);
+    let expressions = &block[0];
+    assert_eq!(expressions.len(), 1);
+
+    if let PipelineElement::Expression(_, expr) = &expressions[0] {
+        if let Expr::FullCellPath(b) = &expr.expr {
+            if let [member] = &b.tail[..] {
+                if let PathMember::String { val, optional, .. } = member {
+                    assert_eq!(val, "bar");
+                    assert_eq!(optional, &true);
+                } else {
+                    panic!("wrong type")
+                }
+            } else {
+                panic!("cell path tail is unexpected")
+            }
+        } else {
+            panic!("Not a cell path");
+        }
+    } else {
+        panic!("Not an expression")
+    }
+}
+
+#[test]
+pub fn parse_cell_path_computed_member() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    working_set.add_variable(
+        "foo".to_string().into_bytes(),
+        Span::test_data(),
+        nu_protocol::Type::Record(vec![]),
+        false,
+    );
+    working_set.add_variable(
+        "key".to_string().into_bytes(),
+        Span::test_data(),
+        nu_protocol::Type::String,
+        false,
+    );
+
+    let block = parse(&mut working_set, None, b"$foo.($key)?.baz", true);
+
+    assert!(working_set.parse_errors.is_empty());
+    assert_eq!(block.len(), 1);
+    let expressions = &block[0];
+    assert_eq!(expressions.len(), 1);
+
+    if let PipelineElement::Expression(_, expr) = &expressions[0] {
+        if let Expr::FullCellPath(b) = &expr.expr {
+            if let [a, b] = &b.tail[..] {
+                // like `bar?.baz`, the `?` marks the member before it optional
+                if let PathMember::Dynamic { optional, .. } = a {
+                    assert_eq!(optional, &true);
+                } else {
+                    panic!("wrong type")
+                }
+
+                if let PathMember::String { val, optional, .. } = b {
+                    assert_eq!(val, "baz");
+                    assert_eq!(optional, &false);
+                } else {
+                    panic!("wrong type")
+                }
+            } else {
+                panic!("cell path tail is unexpected")
+            }
+        } else {
+            panic!("Not a cell path");
+        }
+    } else {
+        panic!("Not an expression")
+    }
+}
+
+#[test]
+pub fn parse_cell_path_with_quoted_dots() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    working_set.add_variable(
+        "foo".to_string().into_bytes(),
+        Span::test_data(),
+        nu_protocol::Type::Record(vec![]),
+        false,
+    );
+
+    // a quoted member that looks like a range must stay a member
+    let block = parse(&mut working_set, None, b"$foo.\"1.2\".\"bar.baz\"", true);
+
+    assert!(working_set.parse_errors.is_empty());
+    assert_eq!(block.len(), 1);
+    let expressions = &block[0];
+    assert_eq!(expressions.len(), 1);
+
+    if let PipelineElement::Expression(_, expr) = &expressions[0] {
+        if let Expr::FullCellPath(b) = &expr.expr {
+            if let [a, b] = &b.tail[..] {
+                if let PathMember::String { val, .. } = a {
+                    assert_eq!(val, "1.2");
+                } else {
+                    panic!("wrong type")
+                }
+
+                if let PathMember::String { val, .. } = b {
+                    assert_eq!(val, "bar.baz");
+                } else {
+                    panic!("wrong type")
+                }
+            } else {
+                panic!("cell path tail is unexpected")
+            }
+        } else {
+            panic!("Not a cell path");
+        }
+    } else {
+        panic!("Not an expression")
+    }
+}
+
 #[test]
 pub fn parse_binary_with_hex_format() {
     let engine_state = EngineState::new();
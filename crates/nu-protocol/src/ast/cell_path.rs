@@ -1,5 +1,5 @@
 use super::Expression;
-use crate::Span;
+use crate::{BlockId, Span};
 use serde::{Deserialize, Serialize};
 use std::fmt::Write;
 
@@ -15,6 +15,14 @@ pub enum PathMember {
         span: Span,
         optional: bool,
     },
+    /// A member computed by a subexpression, like `$record.($key)`. The
+    /// evaluator resolves it to a `String` or `Int` member before the path
+    /// is followed.
+    Dynamic {
+        block_id: BlockId,
+        span: Span,
+        optional: bool,
+    },
 }
 
 impl PartialEq for PathMember {
@@ -44,6 +52,18 @@ impl PartialEq for PathMember {
                     ..
                 },
             ) => l_val == r_val && l_opt == r_opt,
+            (
+                Self::Dynamic {
+                    block_id: l_block,
+                    optional: l_opt,
+                    ..
+                },
+                Self::Dynamic {
+                    block_id: r_block,
+                    optional: r_opt,
+                    ..
+                },
+            ) => l_block == r_block && l_opt == r_opt,
             _ => false,
         }
     }
@@ -67,6 +87,7 @@ impl CellPath {
                     let _ = write!(output, "{val}");
                 }
                 PathMember::String { val, .. } => output.push_str(val),
+                PathMember::Dynamic { .. } => output.push_str("(dynamic)"),
             }
         }
 
@@ -82,6 +103,9 @@ impl CellPath {
                 PathMember::Int {
                     ref mut optional, ..
                 } => *optional = true,
+                PathMember::Dynamic {
+                    ref mut optional, ..
+                } => *optional = true,
             }
         }
     }
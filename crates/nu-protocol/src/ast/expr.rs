@@ -3,12 +3,14 @@ use serde::{Deserialize, Serialize};
 
 use super::{Call, CellPath, Expression, FullCellPath, MatchPattern, Operator, RangeOperator};
 use crate::{ast::ImportPattern, BlockId, Signature, Span, Spanned, Unit, VarId};
+use rust_decimal::Decimal;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Expr {
     Bool(bool),
     Int(i64),
     Float(f64),
+    Decimal(Decimal),
     Binary(Vec<u8>),
     Range(
         Option<Box<Expression>>, // from
@@ -194,6 +194,7 @@ impl Expression {
             Expr::Filepath(_) => false,
             Expr::Directory(_) => false,
             Expr::Float(_) => false,
+            Expr::Decimal(_) => false,
             Expr::FullCellPath(full_cell_path) => {
                 if full_cell_path.head.has_in_variable(working_set) {
                     return true;
@@ -386,6 +387,7 @@ impl Expression {
             Expr::Filepath(_) => {}
             Expr::Directory(_) => {}
             Expr::Float(_) => {}
+            Expr::Decimal(_) => {}
             Expr::FullCellPath(full_cell_path) => {
                 full_cell_path
                     .head
@@ -548,6 +550,7 @@ impl Expression {
             Expr::Filepath(_) => {}
             Expr::Directory(_) => {}
             Expr::Float(_) => {}
+            Expr::Decimal(_) => {}
             Expr::FullCellPath(full_cell_path) => {
                 full_cell_path
                     .head
@@ -1,8 +1,10 @@
 use std::ops::{Index, IndexMut};
 
+use serde::{Deserialize, Serialize};
+
 use crate::{ast::Expression, engine::StateWorkingSet, Span, VarId};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Redirection {
     Stdout,
     Stderr,
@@ -10,7 +12,7 @@ pub enum Redirection {
 }
 
 // Note: Span in the below is for the span of the connector not the whole element
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PipelineElement {
     Expression(Option<Span>, Expression),
     Redirection(Span, Redirection, Expression),
@@ -106,7 +108,7 @@ impl PipelineElement {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Pipeline {
     pub elements: Vec<PipelineElement>,
 }
@@ -1,6 +1,7 @@
 use crate::{ShellError, Span, Value};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 const TRIM_STRATEGY_DEFAULT: TrimStrategy = TrimStrategy::Wrap {
     try_to_keep_words: true,
@@ -68,6 +69,8 @@ pub struct Config {
     pub filesize_metric: bool,
     pub table_mode: String,
     pub table_show_empty: bool,
+    pub table_theme: HashMap<String, Value>,
+    pub table_columns: HashMap<String, Value>,
     pub use_ls_colors: bool,
     pub color_config: HashMap<String, Value>,
     pub use_grid_icons: bool,
@@ -98,10 +101,16 @@ pub struct Config {
     pub show_banner: bool,
     pub show_clickable_links_in_ls: bool,
     pub render_right_prompt_on_last_line: bool,
+    pub pipefail: bool,
+    pub sort_spill_threshold: i64,
+    pub sort_tmp_dir: String,
     pub explore: HashMap<String, Value>,
+    pub file_formats: HashMap<String, String>,
     pub cursor_shape_vi_insert: NuCursorShape,
     pub cursor_shape_vi_normal: NuCursorShape,
     pub cursor_shape_emacs: NuCursorShape,
+    pub pager_enable: bool,
+    pub pager_command: String,
 }
 
 impl Default for Config {
@@ -110,6 +119,8 @@ impl Default for Config {
             filesize_metric: false,
             table_mode: "rounded".into(),
             table_show_empty: true,
+            table_theme: HashMap::new(),
+            table_columns: HashMap::new(),
             external_completer: None,
             use_ls_colors: true,
             color_config: HashMap::new(),
@@ -141,10 +152,19 @@ impl Default for Config {
             show_banner: true,
             show_clickable_links_in_ls: true,
             render_right_prompt_on_last_line: false,
+            pipefail: false,
+            // 512 MiB of buffered rows before sort-by spills runs to disk
+            sort_spill_threshold: 512 * 1024 * 1024,
+            sort_tmp_dir: String::new(),
             explore: HashMap::new(),
+            // Extra extension -> format mappings for bare `open` and `save`
+            file_formats: HashMap::new(),
             cursor_shape_vi_insert: NuCursorShape::Block,
             cursor_shape_vi_normal: NuCursorShape::UnderScore,
             cursor_shape_emacs: NuCursorShape::Line,
+            pager_enable: false,
+            // An empty command means the internal pager (`explore`)
+            pager_command: String::new(),
         }
     }
 }
@@ -276,7 +296,7 @@ impl Value {
                     Some("This value will not appear in your $env.config record.".into()),
                     vec![],
                 ));
-                $cols.remove($index);
+                Arc::make_mut($cols).remove($index);
                 $vals.remove($index);
             };
         }
@@ -336,6 +356,49 @@ impl Value {
                             );
                         }
                     }
+                    "pager" => {
+                        if let Value::Record { cols, vals, span } = &mut vals[index] {
+                            for index in (0..cols.len()).rev() {
+                                let value = &vals[index];
+                                let key2 = cols[index].as_str();
+                                match key2 {
+                                    "enable" => {
+                                        try_bool!(cols, vals, index, span, pager_enable)
+                                    }
+                                    "command" => {
+                                        if let Ok(v) = value.as_string() {
+                                            config.pager_command = v;
+                                        } else {
+                                            invalid!(Some(*span), "should be a string");
+                                            // Reconstruct
+                                            vals[index] =
+                                                Value::string(config.pager_command.clone(), *span);
+                                        }
+                                    }
+                                    x => {
+                                        invalid_key!(
+                                            cols,
+                                            vals,
+                                            index,
+                                            value.span().ok(),
+                                            "$env.config.{key}.{x} is an unknown config setting"
+                                        );
+                                    }
+                                }
+                            }
+                        } else {
+                            invalid!(vals[index].span().ok(), "should be a record");
+                            // Reconstruct
+                            vals[index] = Value::record(
+                                vec!["enable".into(), "command".into()],
+                                vec![
+                                    Value::boolean(config.pager_enable, *span),
+                                    Value::string(config.pager_command.clone(), *span),
+                                ],
+                                *span,
+                            );
+                        }
+                    }
                     "cd" => {
                         if let Value::Record { cols, vals, span } = &mut vals[index] {
                             for index in (0..cols.len()).rev() {
@@ -892,6 +955,30 @@ impl Value {
                                     "show_empty" => {
                                         try_bool!(cols, vals, index, span, table_show_empty)
                                     }
+                                    "theme" => {
+                                        if let Ok(map) = create_map(value) {
+                                            config.table_theme = map;
+                                        } else {
+                                            invalid!(Some(*span), "should be a record");
+                                            // Reconstruct
+                                            vals[index] = Value::record_from_hashmap(
+                                                &config.table_theme,
+                                                *span,
+                                            );
+                                        }
+                                    }
+                                    "columns" => {
+                                        if let Ok(map) = create_map(value) {
+                                            config.table_columns = map;
+                                        } else {
+                                            invalid!(Some(*span), "should be a record");
+                                            // Reconstruct
+                                            vals[index] = Value::record_from_hashmap(
+                                                &config.table_columns,
+                                                *span,
+                                            );
+                                        }
+                                    }
                                     x => {
                                         invalid_key!(
                                             cols,
@@ -971,6 +1058,39 @@ impl Value {
                             vals[index] = Value::record_from_hashmap(&config.explore, *span);
                         }
                     }
+                    "file_formats" => {
+                        let mut formats = None;
+                        if let Ok(map) = create_map(value) {
+                            let as_strings: Result<HashMap<String, String>, _> = map
+                                .iter()
+                                .map(|(ext, format)| {
+                                    format.as_string().map(|format| (ext.clone(), format))
+                                })
+                                .collect();
+                            if let Ok(as_strings) = as_strings {
+                                formats = Some(as_strings);
+                            }
+                        }
+                        match formats {
+                            Some(formats) => config.file_formats = formats,
+                            None => {
+                                invalid!(
+                                    vals[index].span().ok(),
+                                    "should be a record of extension to format name"
+                                );
+                                // Reconstruct
+                                vals[index] = Value::record(
+                                    config.file_formats.keys().cloned().collect(),
+                                    config
+                                        .file_formats
+                                        .values()
+                                        .map(|format| Value::string(format, *span))
+                                        .collect(),
+                                    *span,
+                                );
+                            }
+                        }
+                    }
                     // Misc. options
                     "color_config" => {
                         if let Ok(map) = create_map(value) {
@@ -1054,14 +1174,14 @@ impl Value {
                                              source,
                                          }| {
                                             Value::Record {
-                                                cols: vec![
+                                                cols: Arc::new(vec![
                                                     "name".into(),
                                                     "only_buffer_difference".into(),
                                                     "marker".into(),
                                                     "style".into(),
                                                     "type".into(),
                                                     "source".into(),
-                                                ],
+                                                ]),
                                                 vals: vec![
                                                     name.clone(),
                                                     only_buffer_difference.clone(),
@@ -1097,12 +1217,12 @@ impl Value {
                                              event,
                                          }| {
                                             Value::Record {
-                                                cols: vec![
+                                                cols: Arc::new(vec![
                                                     "modifier".into(),
                                                     "keycode".into(),
                                                     "mode".into(),
                                                     "event".into(),
-                                                ],
+                                                ]),
                                                 vals: vec![
                                                     modifier.clone(),
                                                     keycode.clone(),
@@ -1143,7 +1263,7 @@ impl Value {
                                 hook_vals.push(value.clone());
                             }
                             vals.push(Value::Record {
-                                cols: hook_cols,
+                                cols: Arc::new(hook_cols),
                                 vals: hook_vals,
                                 span: *span,
                             });
@@ -1165,6 +1285,19 @@ impl Value {
                     "render_right_prompt_on_last_line" => {
                         try_bool!(cols, vals, index, span, render_right_prompt_on_last_line);
                     }
+                    "pipefail" => {
+                        try_bool!(cols, vals, index, span, pipefail);
+                    }
+                    "sort_spill_threshold" => {
+                        try_int!(cols, vals, index, span, sort_spill_threshold);
+                    }
+                    "sort_tmp_dir" => {
+                        if let Ok(v) = value.as_string() {
+                            config.sort_tmp_dir = v;
+                        } else {
+                            invalid!(Some(*span), "should be a string");
+                        }
+                    }
                     // Legacy config options (deprecated as of 2022-11-02)
                     // Legacy options do NOT reconstruct their values on error
                     "use_ls_colors" => {
@@ -1281,7 +1414,7 @@ impl Value {
                             Ok(v) => config.trim_strategy = v,
                             Err(e) => {
                                 // try_parse_trim_strategy() already calls eprintln!() on error
-                                cols.remove(index);
+                                Arc::make_mut(cols).remove(index);
                                 vals.remove(index);
                                 errors.push(e);
                             }
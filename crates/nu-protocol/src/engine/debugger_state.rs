@@ -0,0 +1,85 @@
+use crate::Span;
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::{Arc, Mutex};
+
+/// What the step debugger does when it reaches the next pipeline element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DebugMode {
+    /// Run until a breakpoint matches.
+    Continue,
+    /// Stop at the next element, entering child blocks.
+    Step,
+    /// Stop at the next element that is not deeper than the given block depth.
+    Next(usize),
+}
+
+/// A resume instruction sent to a stopped debugger session by a frontend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DebugCommand {
+    Step,
+    Next,
+    Continue,
+    Quit,
+}
+
+/// A snapshot of the stopped element that the debugger reports to a frontend.
+#[derive(Clone, Debug)]
+pub struct DebugStop {
+    /// "step" or "breakpoint", matching the DAP stop reasons.
+    pub reason: &'static str,
+    /// The file the stopped element comes from; empty when unknown.
+    pub file: String,
+    /// The 1-based line of the stopped element; 0 when unknown.
+    pub line: usize,
+    pub source: String,
+    /// A rendering of the pipeline input of the stopped element.
+    pub input: String,
+    /// The variables in scope as (name, rendered value) pairs.
+    pub vars: Vec<(String, String)>,
+}
+
+/// Channels connecting the evaluation thread to a debugger frontend such as the DAP
+/// server. When a controller is set, the debugger reports stops and waits for commands
+/// on these channels instead of prompting on stdin.
+#[derive(Debug)]
+pub struct DebugController {
+    pub events: Sender<DebugStop>,
+    pub commands: Mutex<Receiver<DebugCommand>>,
+}
+
+/// The state of the `debug run` step debugger.
+///
+/// It lives behind an `Arc<Mutex<_>>` on the engine state so that every clone of the
+/// engine state and every callee stack shares the same session.
+#[derive(Debug)]
+pub struct DebugState {
+    pub active: bool,
+    pub mode: DebugMode,
+    /// The current block nesting depth, maintained by `eval_block`.
+    pub depth: usize,
+    /// Stop when the first word of an element matches one of these command names.
+    pub break_commands: Vec<String>,
+    /// Stop when an element starts inside one of these spans (one per breakpoint line).
+    pub break_spans: Vec<Span>,
+    /// When set, stops are reported to a frontend instead of the interactive prompt.
+    pub controller: Option<Arc<DebugController>>,
+}
+
+impl DebugState {
+    pub fn new() -> Self {
+        DebugState {
+            active: false,
+            mode: DebugMode::Continue,
+            depth: 0,
+            break_commands: Vec::new(),
+            break_spans: Vec::new(),
+            controller: None,
+        }
+    }
+}
+
+impl Default for DebugState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
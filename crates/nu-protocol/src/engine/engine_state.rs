@@ -1,13 +1,15 @@
+use super::debugger_state::DebugState;
 use fancy_regex::Regex;
 use lru::LruCache;
 
 use super::{Command, EnvVars, OverlayFrame, ScopeFrame, Stack, Visibility, DEFAULT_OVERLAY_NAME};
 use crate::{
-    ast::Block, BlockId, Config, DeclId, Example, Module, ModuleId, OverlayId, ShellError,
-    Signature, Span, Type, VarId, Variable,
+    ast::Block, BlockId, Config, DeclId, DocExample, Example, Module, ModuleId, OverlayId,
+    ShellError, Signature, Span, Type, VarId, Variable,
 };
 use crate::{ParseError, Value};
 use core::panic;
+use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::num::NonZeroUsize;
 use std::path::Path;
@@ -23,7 +25,7 @@ use std::{
 static PWD_ENV: &str = "PWD";
 
 /// Organizes usage messages for various primitives
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Usage {
     // TODO: Move decl usages here
     module_comments: HashMap<ModuleId, Vec<Span>>,
@@ -106,14 +108,24 @@ pub struct EngineState {
     decls: Vec<Box<dyn Command + 'static>>,
     blocks: Vec<Block>,
     modules: Vec<Module>,
+    // Modules registered with `use --lazy`: module name -> file path. The file is parsed on
+    // the first reference to one of the module's commands.
+    lazy_modules: HashMap<Vec<u8>, PathBuf>,
     usage: Usage,
     pub scope: ScopeFrame,
     pub ctrlc: Option<Arc<AtomicBool>>,
     pub env_vars: EnvVars,
     pub previous_env_vars: HashMap<String, Value>,
+    // Dependency snapshots for the computed env vars of NU_COMPUTED_ENV, keyed by "var:dep"
+    pub computed_env_deps: HashMap<String, Value>,
+    // When set (by `nu --restricted`), only these commands may run
+    pub restricted_commands: Option<Vec<String>>,
+    // When set (by `nu --dry-run`), filesystem commands report instead of acting
+    pub dry_run: bool,
     pub config: Config,
     pub pipeline_externals_state: Arc<(AtomicU32, AtomicU32)>,
     pub repl_buffer_state: Arc<Mutex<String>>,
+    pub debug_state: Arc<Mutex<DebugState>>,
     pub table_decl_id: Option<usize>,
     // A byte position, as `EditCommand::MoveToPosition` is also a byte position
     pub repl_cursor_pos: Arc<Mutex<usize>>,
@@ -154,6 +166,7 @@ impl EngineState {
             decls: vec![],
             blocks: vec![],
             modules: vec![Module::new(DEFAULT_OVERLAY_NAME.as_bytes().to_vec())],
+            lazy_modules: HashMap::new(),
             usage: Usage::new(),
             // make sure we have some default overlay:
             scope: ScopeFrame::with_empty_overlay(
@@ -164,9 +177,13 @@ impl EngineState {
             ctrlc: None,
             env_vars: EnvVars::from([(DEFAULT_OVERLAY_NAME.to_string(), HashMap::new())]),
             previous_env_vars: HashMap::new(),
+            computed_env_deps: HashMap::new(),
+            restricted_commands: None,
+            dry_run: false,
             config: Config::default(),
             pipeline_externals_state: Arc::new((AtomicU32::new(0), AtomicU32::new(0))),
             repl_buffer_state: Arc::new(Mutex::new("".to_string())),
+            debug_state: Arc::new(Mutex::new(DebugState::new())),
             repl_cursor_pos: Arc::new(Mutex::new(0)),
             table_decl_id: None,
             #[cfg(feature = "plugin")]
@@ -200,6 +217,7 @@ impl EngineState {
         self.vars.extend(delta.vars);
         self.blocks.extend(delta.blocks);
         self.modules.extend(delta.modules);
+        self.lazy_modules.extend(delta.lazy_modules);
         self.usage.merge_with(delta.usage);
 
         let first = delta.scope.remove(0);
@@ -719,6 +737,23 @@ impl EngineState {
         self.config = conf.clone();
     }
 
+    /// Fetch a compiled regex from the engine-wide cache, compiling and
+    /// caching it on a miss. Patterns are keyed as written, so inline flags
+    /// like `(?i)` produce distinct entries.
+    pub fn compiled_regex(&self, pattern: &str) -> Result<Regex, fancy_regex::Error> {
+        if let Ok(mut cache) = self.regex_cache.try_lock() {
+            if let Some(regex) = cache.get(pattern) {
+                return Ok(regex.clone());
+            }
+            let regex = Regex::new(pattern)?;
+            cache.put(pattern.to_string(), regex.clone());
+            Ok(regex)
+        } else {
+            // Another thread holds the cache; just compile without caching
+            Regex::new(pattern)
+        }
+    }
+
     pub fn get_var(&self, var_id: VarId) -> &Variable {
         self.vars
             .get(var_id)
@@ -994,19 +1029,114 @@ impl TypeScope {
 /// A delta (or change set) between the current global state and a possible future global state. Deltas
 /// can be applied to the global state to update it to contain both previous state and the state held
 /// within the delta.
+///
+/// A delta whose declarations all come from parsing (`def` bodies and aliases) can also be
+/// serialized, which lets the parse result of a fixed source like the standard library be cached
+/// on disk. Serializing a delta that holds a built-in command fails. Note that the ids and spans
+/// inside a delta are absolute, so a deserialized delta may only be merged into an engine state
+/// with exactly as much base state as the one it was rendered against.
+#[derive(Serialize, Deserialize)]
 pub struct StateDelta {
     files: Vec<(String, usize, usize)>,
     pub(crate) file_contents: Vec<(Vec<u8>, usize, usize)>,
-    vars: Vec<Variable>,          // indexed by VarId
+    vars: Vec<Variable>, // indexed by VarId
+    #[serde(with = "serialized_decls")]
     decls: Vec<Box<dyn Command>>, // indexed by DeclId
-    pub blocks: Vec<Block>,       // indexed by BlockId
-    modules: Vec<Module>,         // indexed by ModuleId
+    pub blocks: Vec<Block>, // indexed by BlockId
+    modules: Vec<Module>, // indexed by ModuleId
+    lazy_modules: HashMap<Vec<u8>, PathBuf>, // module name -> file, for `use --lazy`
     usage: Usage,
     pub scope: Vec<ScopeFrame>,
     #[cfg(feature = "plugin")]
+    #[serde(skip)]
     plugins_changed: bool, // marks whether plugin file should be updated
 }
 
+/// Serde adapter for `StateDelta::decls`. Only commands that are fully described by parser
+/// output can round-trip: block-backed custom commands and aliases. Anything else (built-in
+/// commands, plugins) has no serializable form, so serializing such a delta returns an error
+/// and callers are expected to fall back to re-parsing the source.
+mod serialized_decls {
+    use crate::ast::Expression;
+    use crate::engine::Command;
+    use crate::{Alias, BlockId, Signature};
+    use serde::ser::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    enum SerializedDecl {
+        Block {
+            signature: Box<Signature>,
+            block_id: BlockId,
+        },
+        Alias {
+            name: String,
+            command: Option<Box<SerializedDecl>>,
+            wrapped_call: Expression,
+        },
+    }
+
+    fn from_command<E: Error>(decl: &dyn Command) -> Result<SerializedDecl, E> {
+        if let Some(block_id) = decl.get_block_id() {
+            Ok(SerializedDecl::Block {
+                signature: Box::new(decl.signature()),
+                block_id,
+            })
+        } else if let Some(alias) = decl.as_alias() {
+            let command = match &alias.command {
+                Some(cmd) => Some(Box::new(from_command(cmd.as_ref())?)),
+                None => None,
+            };
+            Ok(SerializedDecl::Alias {
+                name: alias.name.clone(),
+                command,
+                wrapped_call: alias.wrapped_call.clone(),
+            })
+        } else {
+            Err(E::custom(format!(
+                "command `{}` cannot be serialized",
+                decl.name()
+            )))
+        }
+    }
+
+    fn into_command(decl: SerializedDecl) -> Box<dyn Command> {
+        match decl {
+            SerializedDecl::Block {
+                signature,
+                block_id,
+            } => signature.into_block_command(block_id),
+            SerializedDecl::Alias {
+                name,
+                command,
+                wrapped_call,
+            } => Box::new(Alias {
+                name,
+                command: command.map(|cmd| into_command(*cmd)),
+                wrapped_call,
+            }),
+        }
+    }
+
+    pub fn serialize<S: Serializer>(
+        decls: &[Box<dyn Command>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let decls: Vec<SerializedDecl> = decls
+            .iter()
+            .map(|decl| from_command(decl.as_ref()))
+            .collect::<Result<_, _>>()?;
+        decls.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Box<dyn Command>>, D::Error> {
+        let decls = Vec::<SerializedDecl>::deserialize(deserializer)?;
+        Ok(decls.into_iter().map(into_command).collect())
+    }
+}
+
 impl StateDelta {
     pub fn new(engine_state: &EngineState) -> Self {
         let last_overlay = engine_state.last_overlay(&[]);
@@ -1023,6 +1153,7 @@ impl StateDelta {
             decls: vec![],
             blocks: vec![],
             modules: vec![],
+            lazy_modules: HashMap::new(),
             scope: vec![scope_frame],
             usage: Usage::new(),
             #[cfg(feature = "plugin")]
@@ -1190,6 +1321,17 @@ impl<'a> StateWorkingSet<'a> {
         }
     }
 
+    pub fn use_variables(&mut self, variables: Vec<(Vec<u8>, VarId)>) {
+        let overlay_frame = self.last_overlay_mut();
+
+        for (mut name, var_id) in variables {
+            if !name.starts_with(b"$") {
+                name.insert(0, b'$');
+            }
+            overlay_frame.vars.insert(name, var_id);
+        }
+    }
+
     pub fn add_predecl(&mut self, decl: Box<dyn Command>) -> Option<DeclId> {
         let name = decl.name().as_bytes().to_vec();
 
@@ -1513,6 +1655,17 @@ impl<'a> StateWorkingSet<'a> {
         None
     }
 
+    pub fn add_lazy_module(&mut self, name: &[u8], path: PathBuf) {
+        self.delta.lazy_modules.insert(name.to_vec(), path);
+    }
+
+    pub fn find_lazy_module(&self, name: &[u8]) -> Option<&PathBuf> {
+        self.delta
+            .lazy_modules
+            .get(name)
+            .or_else(|| self.permanent_state.lazy_modules.get(name))
+    }
+
     pub fn contains_decl_partial_match(&self, name: &[u8]) -> bool {
         let mut removed_overlays = vec![];
 
@@ -1963,6 +2116,20 @@ impl<'a> StateWorkingSet<'a> {
             .collect();
         build_usage(&comment_lines)
     }
+
+    /// Like `build_usage`, but additionally parses out `# Example: <description>` lines and
+    /// their `# > <code>` lines as runnable examples, excluding them from the usage text.
+    pub fn build_usage_and_examples(&self, spans: &[Span]) -> (String, String, Vec<DocExample>) {
+        let comment_lines: Vec<&[u8]> = spans
+            .iter()
+            .map(|span| self.get_span_contents(*span))
+            .collect();
+
+        let (usage_lines, examples) = split_doc_examples(&comment_lines);
+        let (usage, extra_usage) = build_usage(&usage_lines);
+
+        (usage, extra_usage, examples)
+    }
 }
 
 impl Default for EngineState {
@@ -2101,6 +2268,83 @@ fn build_usage(comment_lines: &[&[u8]]) -> (String, String) {
     }
 }
 
+/// Split doc comment lines into usage lines and examples. An example is introduced by a line
+/// `# Example: <description>`; the `# > <code>` lines following it form its runnable code and
+/// the `# => <output>` lines after the code form its expected output. A `# > <code>` line
+/// outside of an `Example:` block becomes an example without a description.
+fn split_doc_examples<'a>(comment_lines: &[&'a [u8]]) -> (Vec<&'a [u8]>, Vec<DocExample>) {
+    let mut usage_lines = vec![];
+    let mut examples: Vec<DocExample> = vec![];
+    let mut in_example = false;
+
+    for contents in comment_lines {
+        // Skip the '#' and the spaces after it
+        let mut pos = 1;
+        while pos < contents.len() && contents[pos] == b' ' {
+            pos += 1;
+        }
+        let content = String::from_utf8_lossy(&contents[pos.min(contents.len())..]);
+
+        if let Some(description) = content.strip_prefix("Example:") {
+            examples.push(DocExample {
+                example: String::new(),
+                description: description.trim().to_string(),
+                result: None,
+            });
+            in_example = true;
+        } else if content == ">" || content.starts_with("> ") {
+            if !in_example {
+                examples.push(DocExample {
+                    example: String::new(),
+                    description: String::new(),
+                    result: None,
+                });
+                in_example = true;
+            }
+
+            let example = &mut examples
+                .last_mut()
+                .expect("already pushed an example")
+                .example;
+
+            if !example.is_empty() {
+                example.push('\n');
+            }
+            example.push_str(content[1..].trim_start());
+        } else if in_example && content.starts_with("=>") {
+            let result = &mut examples
+                .last_mut()
+                .expect("in_example implies an example was pushed")
+                .result;
+
+            let line = content[2..].trim_start();
+            match result {
+                Some(result) => {
+                    result.push('\n');
+                    result.push_str(line);
+                }
+                None => *result = Some(line.to_string()),
+            }
+        } else {
+            in_example = false;
+            usage_lines.push(*contents);
+        }
+    }
+
+    examples.retain(|example| !example.example.is_empty());
+
+    // Removing the examples can leave dangling empty lines at the end of the usage
+    while let Some(last) = usage_lines.last() {
+        if last.iter().skip(1).all(|byte| *byte == b' ') {
+            usage_lines.pop();
+        } else {
+            break;
+        }
+    }
+
+    (usage_lines, examples)
+}
+
 #[cfg(test)]
 mod engine_state_tests {
     use super::*;
@@ -1,6 +1,7 @@
 mod call_info;
 mod capture_block;
 mod command;
+mod debugger_state;
 mod engine_state;
 mod overlay;
 mod pattern_match;
@@ -9,6 +10,7 @@ mod stack;
 pub use call_info::*;
 pub use capture_block::*;
 pub use command::*;
+pub use debugger_state::*;
 pub use engine_state::*;
 pub use overlay::*;
 pub use pattern_match::*;
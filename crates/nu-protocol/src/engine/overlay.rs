@@ -1,4 +1,5 @@
 use crate::{DeclId, ModuleId, OverlayId, Type, Value, VarId};
+use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
@@ -6,7 +7,7 @@ use std::hash::{Hash, Hasher};
 pub static DEFAULT_OVERLAY_NAME: &str = "zero";
 
 /// Tells whether a decl is visible or not
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Visibility {
     decl_ids: HashMap<DeclId, bool>,
 }
@@ -45,7 +46,7 @@ impl Visibility {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScopeFrame {
     /// List of both active and inactive overlays in this ScopeFrame.
     ///
@@ -171,7 +172,7 @@ impl ScopeFrame {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OverlayFrame {
     pub vars: HashMap<Vec<u8>, VarId>,
     pub constants: HashMap<VarId, Value>,
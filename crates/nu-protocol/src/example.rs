@@ -9,6 +9,16 @@ pub struct Example<'a> {
     pub result: Option<Value>,
 }
 
+// DocExample is like struct `Example`, but it owns its strings because it is parsed out of the
+// doc comment of a custom command (`# Example: <description>` followed by `# > <code>` lines,
+// optionally with the expected output on `# => <output>` lines).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DocExample {
+    pub example: String,
+    pub description: String,
+    pub result: Option<String>,
+}
+
 // PluginExample is somehow like struct `Example`, but it owned a String for `example`
 // and `description` fields, because these information is fetched from plugin, a third party
 // binary, nushell have no way to construct it directly.
@@ -1,5 +1,6 @@
-use crate::DeclId;
+use crate::{DeclId, VarId};
 
 pub enum Exportable {
     Decl { name: Vec<u8>, id: DeclId },
+    VarDecl { name: Vec<u8>, id: VarId },
 }
@@ -1,14 +1,17 @@
-use crate::{BlockId, DeclId, Span};
+use crate::{BlockId, DeclId, Span, VarId};
 
 use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
 
 /// Collection of definitions that can be exported from a module
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Module {
     pub name: Vec<u8>,
     pub decls: IndexMap<Vec<u8>, DeclId>,
+    pub constants: IndexMap<Vec<u8>, VarId>,
     pub env_block: Option<BlockId>, // `export-env { ... }` block
     pub main: Option<DeclId>,       // `export def main`
+    pub version: Option<String>,    // `export-meta { version: ... }`
     pub span: Option<Span>,
 }
 
@@ -17,8 +20,10 @@ impl Module {
         Module {
             name,
             decls: IndexMap::new(),
+            constants: IndexMap::new(),
             env_block: None,
             main: None,
+            version: None,
             span: None,
         }
     }
@@ -27,8 +32,10 @@ impl Module {
         Module {
             name,
             decls: IndexMap::new(),
+            constants: IndexMap::new(),
             env_block: None,
             main: None,
+            version: None,
             span: Some(span),
         }
     }
@@ -37,22 +44,31 @@ impl Module {
         self.decls.insert(name, decl_id)
     }
 
+    pub fn add_variable(&mut self, name: Vec<u8>, var_id: VarId) -> Option<VarId> {
+        self.constants.insert(name, var_id)
+    }
+
     pub fn add_env_block(&mut self, block_id: BlockId) {
         self.env_block = Some(block_id);
     }
 
     pub fn extend(&mut self, other: &Module) {
         self.decls.extend(other.decls.clone());
+        self.constants.extend(other.constants.clone());
     }
 
     pub fn is_empty(&self) -> bool {
-        self.decls.is_empty()
+        self.decls.is_empty() && self.constants.is_empty()
     }
 
     pub fn get_decl_id(&self, name: &[u8]) -> Option<DeclId> {
         self.decls.get(name).copied()
     }
 
+    pub fn get_var_id(&self, name: &[u8]) -> Option<VarId> {
+        self.constants.get(name).copied()
+    }
+
     pub fn has_decl(&self, name: &[u8]) -> bool {
         if name == self.name && self.main.is_some() {
             return true;
@@ -133,4 +149,15 @@ impl Module {
 
         result
     }
+
+    pub fn consts(&self) -> Vec<(Vec<u8>, VarId)> {
+        self.constants
+            .iter()
+            .map(|(name, id)| (name.clone(), *id))
+            .collect()
+    }
+
+    pub fn const_names(&self) -> Vec<Vec<u8>> {
+        self.constants.keys().cloned().collect()
+    }
 }
@@ -751,7 +751,21 @@ impl PipelineData {
             ..
         } = self
         {
-            return print_if_stream(stream, stderr_stream, to_stderr, exit_code);
+            let (exit_code, statuses) =
+                print_if_stream(stream, stderr_stream, to_stderr, exit_code)?;
+            if !statuses.is_empty() {
+                stack.add_env_var(
+                    "LAST_PIPELINE_STATUS".to_string(),
+                    Value::list(
+                        statuses
+                            .into_iter()
+                            .map(|code| Value::int(code, Span::unknown()))
+                            .collect(),
+                        Span::unknown(),
+                    ),
+                );
+            }
+            return Ok(exit_code);
         }
 
         if let Some(decl_id) = engine_state.table_decl_id {
@@ -792,6 +806,7 @@ impl PipelineData {
         } = self
         {
             print_if_stream(stream, stderr_stream, to_stderr, exit_code)
+                .map(|(exit_code, _)| exit_code)
         } else {
             let config = engine_state.get_config();
             self.write_all_and_flush(engine_state, config, no_newline, to_stderr)
@@ -881,7 +896,7 @@ pub fn print_if_stream(
     stderr_stream: Option<RawStream>,
     to_stderr: bool,
     exit_code: Option<ListStream>,
-) -> Result<i64, ShellError> {
+) -> Result<(i64, Vec<i64>), ShellError> {
     // NOTE: currently we don't need anything from stderr
     // so we just consume and throw away `stderr_stream` to make sure the pipe doesn't fill up
 
@@ -904,10 +919,23 @@ pub fn print_if_stream(
 
     // Make sure everything has finished
     if let Some(exit_code) = exit_code {
-        return drain_exit_code(exit_code);
+        let mut exit_codes: Vec<_> = exit_code.into_iter().collect();
+        let statuses: Vec<i64> = exit_codes
+            .iter()
+            .filter_map(|code| match code {
+                Value::Int { val, .. } => Some(*val),
+                _ => None,
+            })
+            .collect();
+        return match exit_codes.pop() {
+            #[cfg(unix)]
+            Some(Value::Error { error }) => Err(*error),
+            Some(Value::Int { val, .. }) => Ok((val, statuses)),
+            _ => Ok((0, statuses)),
+        };
     }
 
-    Ok(0)
+    Ok((0, vec![]))
 }
 
 fn drain_exit_code(exit_code: ListStream) -> Result<i64, ShellError> {
@@ -226,6 +226,20 @@ pub enum ShellError {
         span: Span,
     },
 
+    /// The command is not in the restricted-mode allowlist.
+    ///
+    /// ## Resolution
+    ///
+    /// The session was started with `--restricted`, which only permits an allowlist of
+    /// commands. Add the command to `NU_RESTRICTED_ALLOW` or drop the `--restricted` flag.
+    #[error("Command not allowed in restricted mode.")]
+    #[diagnostic(code(nu::shell::restricted_command))]
+    RestrictedCommand {
+        command: String,
+        #[label("'{command}' is not allowed in restricted mode")]
+        span: Span,
+    },
+
     /// You're trying to run an unsupported external command.
     ///
     /// ## Resolution
@@ -7,10 +7,14 @@ use crate::engine::Command;
 use crate::engine::EngineState;
 use crate::engine::Stack;
 use crate::BlockId;
+use crate::DocExample;
+use crate::Example;
 use crate::PipelineData;
 use crate::ShellError;
+use crate::Span;
 use crate::SyntaxShape;
 use crate::Type;
+use crate::Value;
 use crate::VarId;
 use std::fmt::Write;
 
@@ -123,6 +127,8 @@ pub struct Signature {
     pub allows_unknown_args: bool,
     // Signature category used to classify commands stored in the list of declarations
     pub category: Category,
+    // Examples parsed from the doc comment of a custom command
+    pub doc_examples: Vec<DocExample>,
 }
 
 /// Format argument type for user readable output.
@@ -224,6 +230,7 @@ impl Signature {
             creates_scope: false,
             category: Category::Default,
             allows_unknown_args: false,
+            doc_examples: vec![],
         }
     }
 
@@ -729,6 +736,21 @@ impl Command for BlockCommand {
         &self.signature.extra_usage
     }
 
+    fn examples(&self) -> Vec<Example> {
+        self.signature
+            .doc_examples
+            .iter()
+            .map(|example| Example {
+                example: &example.example,
+                description: &example.description,
+                result: example
+                    .result
+                    .as_ref()
+                    .map(|text| Value::string(text, Span::unknown())),
+            })
+            .collect()
+    }
+
     fn run(
         &self,
         _engine_state: &EngineState,
@@ -8,6 +8,7 @@ use crate::SyntaxShape;
 #[derive(Clone, Debug, Default, EnumIter, PartialEq, Eq, Serialize, Deserialize, Hash)]
 pub enum Type {
     Any,
+    BigInt,
     Binary,
     Block,
     Bool,
@@ -15,6 +16,7 @@ pub enum Type {
     Closure,
     Custom(String),
     Date,
+    Decimal,
     Duration,
     Error,
     Filesize,
@@ -59,7 +61,10 @@ impl Type {
     }
 
     pub fn is_numeric(&self) -> bool {
-        matches!(self, Type::Int | Type::Float | Type::Number)
+        matches!(
+            self,
+            Type::Int | Type::BigInt | Type::Float | Type::Decimal | Type::Number
+        )
     }
 
     pub fn is_list(&self) -> bool {
@@ -74,7 +79,9 @@ impl Type {
     pub fn to_shape(&self) -> SyntaxShape {
         match self {
             Type::Int => SyntaxShape::Int,
+            Type::BigInt => SyntaxShape::Int,
             Type::Float => SyntaxShape::Number,
+            Type::Decimal => SyntaxShape::Number,
             Type::Range => SyntaxShape::Range,
             Type::Bool => SyntaxShape::Boolean,
             Type::String => SyntaxShape::String,
@@ -108,10 +115,12 @@ impl Type {
             Type::Bool => String::from("bool"),
             Type::CellPath => String::from("cell path"),
             Type::Date => String::from("date"),
+            Type::Decimal => String::from("decimal"),
             Type::Duration => String::from("duration"),
             Type::Filesize => String::from("filesize"),
             Type::Float => String::from("float"),
             Type::Int => String::from("int"),
+            Type::BigInt => String::from("bigint"),
             Type::Range => String::from("range"),
             Type::Record(_) => String::from("record"),
             Type::Table(_) => String::from("table"),
@@ -138,10 +147,12 @@ impl Display for Type {
             Type::Bool => write!(f, "bool"),
             Type::CellPath => write!(f, "cell path"),
             Type::Date => write!(f, "date"),
+            Type::Decimal => write!(f, "decimal"),
             Type::Duration => write!(f, "duration"),
             Type::Filesize => write!(f, "filesize"),
             Type::Float => write!(f, "float"),
             Type::Int => write!(f, "int"),
+            Type::BigInt => write!(f, "bigint"),
             Type::Range => write!(f, "range"),
             Type::Record(fields) => {
                 if fields.is_empty() {
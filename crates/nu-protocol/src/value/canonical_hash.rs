@@ -0,0 +1,307 @@
+use crate::ast::PathMember;
+use crate::{ShellError, Value};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A small FNV-1a hasher. `std`'s default hasher is only stable within a
+/// single process, while canonical hashes are meant to be reproducible across
+/// runs and platforms.
+struct Fnv1a {
+    state: u64,
+}
+
+impl Fnv1a {
+    fn new() -> Self {
+        Fnv1a {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= u64::from(*byte);
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u8(&mut self, val: u8) {
+        self.write(&[val])
+    }
+
+    fn write_u64(&mut self, val: u64) {
+        self.write(&val.to_le_bytes())
+    }
+
+    fn write_i64(&mut self, val: i64) {
+        self.write(&val.to_le_bytes())
+    }
+
+    fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+impl Value {
+    /// Compute a stable structural hash of this value.
+    ///
+    /// The hash is deterministic across runs and platforms, so it can be used
+    /// as a persistent key for deduplication or grouping. Record columns hash
+    /// order-insensitively (`{a: 1, b: 2}` and `{b: 2, a: 1}` hash alike)
+    /// unless `order_sensitive` is set; lists and all other compound types
+    /// always keep their order. Values of different types never share an
+    /// encoding, so `1`, `1.0`, and `"1"` all hash differently.
+    pub fn canonical_hash(&self, order_sensitive: bool) -> Result<u64, ShellError> {
+        let mut hasher = Fnv1a::new();
+        hash_value(self, order_sensitive, &mut hasher)?;
+        Ok(hasher.finish())
+    }
+}
+
+// Every variant writes a distinct tag byte first, and variable-length data is
+// length-prefixed, so two different values can't produce the same byte stream.
+fn hash_value(value: &Value, order_sensitive: bool, hasher: &mut Fnv1a) -> Result<(), ShellError> {
+    match value {
+        Value::Bool { val, .. } => {
+            hasher.write_u8(1);
+            hasher.write_u8(*val as u8);
+        }
+        Value::Int { val, .. } => {
+            hasher.write_u8(2);
+            hasher.write_i64(*val);
+        }
+        Value::Float { val, .. } => {
+            hasher.write_u8(3);
+            // Normalize so that 0.0/-0.0 and all NaN bit patterns hash alike
+            let val = if *val == 0.0 {
+                0.0
+            } else if val.is_nan() {
+                f64::NAN
+            } else {
+                *val
+            };
+            hasher.write_u64(val.to_bits());
+        }
+        Value::Decimal { val, .. } => {
+            hasher.write_u8(4);
+            // `normalize` strips trailing zeros so 1.50 and 1.5 hash alike,
+            // matching their equality
+            hasher.write(val.normalize().to_string().as_bytes());
+        }
+        Value::BigInt { val, .. } => {
+            hasher.write_u8(5);
+            hasher.write(val.to_string().as_bytes());
+        }
+        Value::Filesize { val, .. } => {
+            hasher.write_u8(6);
+            hasher.write_i64(*val);
+        }
+        Value::Duration { val, .. } => {
+            hasher.write_u8(7);
+            hasher.write_i64(*val);
+        }
+        Value::Date { val, .. } => {
+            hasher.write_u8(8);
+            // Hash the instant rather than the zoned representation, matching
+            // date equality
+            hasher.write_i64(val.timestamp());
+            hasher.write_u64(u64::from(val.timestamp_subsec_nanos()));
+        }
+        Value::Range { val, .. } => {
+            hasher.write_u8(9);
+            hash_value(&val.from, order_sensitive, hasher)?;
+            hash_value(&val.incr, order_sensitive, hasher)?;
+            hash_value(&val.to, order_sensitive, hasher)?;
+            hasher.write_u8(val.inclusion as u8);
+        }
+        Value::String { val, .. } => {
+            hasher.write_u8(10);
+            hasher.write_u64(val.len() as u64);
+            hasher.write(val.as_bytes());
+        }
+        Value::Record { cols, vals, .. } => {
+            hasher.write_u8(11);
+            hasher.write_u64(cols.len() as u64);
+            if order_sensitive {
+                for (col, val) in cols.iter().zip(vals) {
+                    hasher.write_u64(col.len() as u64);
+                    hasher.write(col.as_bytes());
+                    hash_value(val, order_sensitive, hasher)?;
+                }
+            } else {
+                // Fold the per-column hashes together commutatively so column
+                // order doesn't affect the result
+                let mut combined: u64 = 0;
+                for (col, val) in cols.iter().zip(vals) {
+                    let mut pair = Fnv1a::new();
+                    pair.write_u64(col.len() as u64);
+                    pair.write(col.as_bytes());
+                    hash_value(val, order_sensitive, &mut pair)?;
+                    combined = combined.wrapping_add(pair.finish());
+                }
+                hasher.write_u64(combined);
+            }
+        }
+        Value::LazyRecord { val, .. } => {
+            // Hash the collected record, so lazy and eager records with the
+            // same contents hash alike
+            hash_value(&val.collect()?, order_sensitive, hasher)?;
+        }
+        Value::List { vals, .. } => {
+            hasher.write_u8(12);
+            hasher.write_u64(vals.len() as u64);
+            for val in vals {
+                hash_value(val, order_sensitive, hasher)?;
+            }
+        }
+        Value::Block { val, .. } => {
+            hasher.write_u8(13);
+            hasher.write_u64(*val as u64);
+        }
+        Value::Closure { val, captures, .. } => {
+            hasher.write_u8(14);
+            hasher.write_u64(*val as u64);
+            let mut combined: u64 = 0;
+            for (var_id, value) in captures {
+                let mut pair = Fnv1a::new();
+                pair.write_u64(*var_id as u64);
+                hash_value(value, order_sensitive, &mut pair)?;
+                combined = combined.wrapping_add(pair.finish());
+            }
+            hasher.write_u64(combined);
+        }
+        Value::Nothing { .. } => {
+            hasher.write_u8(15);
+        }
+        Value::Error { error } => {
+            hasher.write_u8(16);
+            hasher.write(format!("{error:?}").as_bytes());
+        }
+        Value::Binary { val, .. } => {
+            hasher.write_u8(17);
+            hasher.write_u64(val.len() as u64);
+            hasher.write(val);
+        }
+        Value::CellPath { val, .. } => {
+            hasher.write_u8(18);
+            hasher.write_u64(val.members.len() as u64);
+            for member in &val.members {
+                match member {
+                    PathMember::String { val, optional, .. } => {
+                        hasher.write_u8(0);
+                        hasher.write_u8(*optional as u8);
+                        hasher.write_u64(val.len() as u64);
+                        hasher.write(val.as_bytes());
+                    }
+                    PathMember::Int { val, optional, .. } => {
+                        hasher.write_u8(1);
+                        hasher.write_u8(*optional as u8);
+                        hasher.write_u64(*val as u64);
+                    }
+                    PathMember::Dynamic {
+                        block_id, optional, ..
+                    } => {
+                        hasher.write_u8(2);
+                        hasher.write_u8(*optional as u8);
+                        hasher.write_u64(*block_id as u64);
+                    }
+                }
+            }
+        }
+        Value::CustomValue { val, span } => {
+            hasher.write_u8(19);
+            hash_value(&val.to_base_value(*span)?, order_sensitive, hasher)?;
+        }
+        Value::MatchPattern { val, .. } => {
+            hasher.write_u8(20);
+            hasher.write(format!("{val:?}").as_bytes());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Span, Value};
+    use std::sync::Arc;
+
+    fn record(cols: Vec<&str>, vals: Vec<Value>) -> Value {
+        Value::Record {
+            cols: Arc::new(cols.into_iter().map(String::from).collect()),
+            vals,
+            span: Span::test_data(),
+        }
+    }
+
+    #[test]
+    fn record_hash_ignores_column_order() {
+        let ab = record(vec!["a", "b"], vec![Value::test_int(1), Value::test_int(2)]);
+        let ba = record(vec!["b", "a"], vec![Value::test_int(2), Value::test_int(1)]);
+
+        assert_eq!(
+            ab.canonical_hash(false).unwrap(),
+            ba.canonical_hash(false).unwrap()
+        );
+        assert_ne!(
+            ab.canonical_hash(true).unwrap(),
+            ba.canonical_hash(true).unwrap()
+        );
+    }
+
+    #[test]
+    fn different_types_hash_differently() {
+        let int = Value::test_int(1);
+        let float = Value::test_float(1.0);
+        let string = Value::test_string("1");
+
+        let hashes = [
+            int.canonical_hash(false).unwrap(),
+            float.canonical_hash(false).unwrap(),
+            string.canonical_hash(false).unwrap(),
+        ];
+        assert_ne!(hashes[0], hashes[1]);
+        assert_ne!(hashes[0], hashes[2]);
+        assert_ne!(hashes[1], hashes[2]);
+    }
+
+    #[test]
+    fn list_hash_keeps_order() {
+        let one_two = Value::List {
+            vals: vec![Value::test_int(1), Value::test_int(2)],
+            span: Span::test_data(),
+        };
+        let two_one = Value::List {
+            vals: vec![Value::test_int(2), Value::test_int(1)],
+            span: Span::test_data(),
+        };
+
+        assert_ne!(
+            one_two.canonical_hash(false).unwrap(),
+            two_one.canonical_hash(false).unwrap()
+        );
+    }
+
+    #[test]
+    fn nested_records_hash_order_insensitively() {
+        let outer_ab = record(
+            vec!["outer"],
+            vec![record(
+                vec!["a", "b"],
+                vec![Value::test_int(1), Value::test_int(2)],
+            )],
+        );
+        let outer_ba = record(
+            vec!["outer"],
+            vec![record(
+                vec!["b", "a"],
+                vec![Value::test_int(2), Value::test_int(1)],
+            )],
+        );
+
+        assert_eq!(
+            outer_ab.canonical_hash(false).unwrap(),
+            outer_ba.canonical_hash(false).unwrap()
+        );
+    }
+}
@@ -494,7 +494,7 @@ impl FromValue for Vec<Value> {
 impl FromValue for (Vec<String>, Vec<Value>) {
     fn from_value(v: &Value) -> Result<Self, ShellError> {
         match v {
-            Value::Record { cols, vals, .. } => Ok((cols.clone(), vals.clone())),
+            Value::Record { cols, vals, .. } => Ok((cols.to_vec(), vals.clone())),
             v => Err(ShellError::CantConvert {
                 to_type: "Record".into(),
                 from_type: v.get_type().to_string(),
@@ -7,7 +7,7 @@ use std::fmt;
 #[typetag::serde(tag = "type")]
 pub trait LazyRecord: fmt::Debug + Send + Sync {
     // All column names
-    fn column_names(&self) -> Vec<&'static str>;
+    fn column_names(&self) -> Vec<String>;
 
     // Get 1 specific column value
     fn get_column_value(&self, column: &str) -> Result<Value, ShellError>;
@@ -20,13 +20,13 @@ pub trait LazyRecord: fmt::Debug + Send + Sync {
         let mut vals = vec![];
 
         for column in self.column_names() {
-            cols.push(column.into());
-            let val = self.get_column_value(column)?;
+            let val = self.get_column_value(&column)?;
+            cols.push(column);
             vals.push(val);
         }
 
         Ok(Value::Record {
-            cols,
+            cols: std::sync::Arc::new(cols),
             vals,
             span: self.span(),
         })
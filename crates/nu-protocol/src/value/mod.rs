@@ -1,3 +1,4 @@
+mod canonical_hash;
 mod custom_value;
 mod from;
 mod from_value;
@@ -15,13 +16,17 @@ use byte_unit::ByteUnit;
 use chrono::{DateTime, Duration, FixedOffset};
 use chrono_humanize::HumanTime;
 pub use custom_value::CustomValue;
-use fancy_regex::Regex;
 pub use from_value::FromValue;
 use indexmap::map::IndexMap;
 pub use lazy_record::LazyRecord;
 use nu_utils::get_system_locale;
+pub use num_bigint::BigInt;
 use num_format::ToFormattedString;
+use num_traits::Zero;
 pub use range::*;
+use rust_decimal::prelude::ToPrimitive;
+pub use rust_decimal::Decimal;
+use rust_decimal::MathematicalOps;
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
@@ -29,6 +34,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     iter,
     path::PathBuf,
+    sync::Arc,
     {cmp::Ordering, fmt::Debug},
 };
 pub use stream::*;
@@ -51,6 +57,14 @@ pub enum Value {
         val: f64,
         span: Span,
     },
+    Decimal {
+        val: Decimal,
+        span: Span,
+    },
+    BigInt {
+        val: BigInt,
+        span: Span,
+    },
     Filesize {
         val: i64,
         span: Span,
@@ -72,7 +86,7 @@ pub enum Value {
         span: Span,
     },
     Record {
-        cols: Vec<String>,
+        cols: Arc<Vec<String>>,
         vals: Vec<Value>,
         span: Span,
     },
@@ -141,6 +155,14 @@ impl Clone for Value {
                 span: *span,
             },
             Value::Float { val, span } => Value::float(*val, *span),
+            Value::Decimal { val, span } => Value::Decimal {
+                val: *val,
+                span: *span,
+            },
+            Value::BigInt { val, span } => Value::BigInt {
+                val: val.clone(),
+                span: *span,
+            },
             Value::String { val, span } => Value::String {
                 val: val.clone(),
                 span: *span,
@@ -381,6 +403,8 @@ impl Value {
             Value::Bool { span, .. } => Ok(*span),
             Value::Int { span, .. } => Ok(*span),
             Value::Float { span, .. } => Ok(*span),
+            Value::Decimal { span, .. } => Ok(*span),
+            Value::BigInt { span, .. } => Ok(*span),
             Value::Filesize { span, .. } => Ok(*span),
             Value::Duration { span, .. } => Ok(*span),
             Value::Date { span, .. } => Ok(*span),
@@ -412,6 +436,8 @@ impl Value {
             Value::Bool { span, .. } => *span = new_span,
             Value::Int { span, .. } => *span = new_span,
             Value::Float { span, .. } => *span = new_span,
+            Value::Decimal { span, .. } => *span = new_span,
+            Value::BigInt { span, .. } => *span = new_span,
             Value::Filesize { span, .. } => *span = new_span,
             Value::Duration { span, .. } => *span = new_span,
             Value::Date { span, .. } => *span = new_span,
@@ -439,6 +465,8 @@ impl Value {
             Value::Bool { .. } => Type::Bool,
             Value::Int { .. } => Type::Int,
             Value::Float { .. } => Type::Float,
+            Value::Decimal { .. } => Type::Decimal,
+            Value::BigInt { .. } => Type::BigInt,
             Value::Filesize { .. } => Type::Filesize,
             Value::Duration { .. } => Type::Duration,
             Value::Date { .. } => Type::Date,
@@ -540,6 +568,8 @@ impl Value {
             Value::Bool { val, .. } => val.to_string(),
             Value::Int { val, .. } => val.to_string(),
             Value::Float { val, .. } => val.to_string(),
+            Value::Decimal { val, .. } => val.to_string(),
+            Value::BigInt { val, .. } => val.to_string(),
             Value::Filesize { val, .. } => format_filesize_from_conf(*val, config),
             Value::Duration { val, .. } => format_duration(*val),
             Value::Date { val, .. } => format!("{} ({})", val.to_rfc2822(), HumanTime::from(*val)),
@@ -592,6 +622,8 @@ impl Value {
             Value::Bool { val, .. } => val.to_string(),
             Value::Int { val, .. } => val.to_string(),
             Value::Float { val, .. } => val.to_string(),
+            Value::Decimal { val, .. } => val.to_string(),
+            Value::BigInt { val, .. } => val.to_string(),
             Value::Filesize { val, .. } => format_filesize_from_conf(*val, config),
             Value::Duration { val, .. } => format_duration(*val),
             Value::Date { val, .. } => HumanTime::from(*val).to_string(),
@@ -649,6 +681,8 @@ impl Value {
             Value::Bool { val, .. } => val.to_string(),
             Value::Int { val, .. } => val.to_string(),
             Value::Float { val, .. } => val.to_string(),
+            Value::Decimal { val, .. } => val.to_string(),
+            Value::BigInt { val, .. } => val.to_string(),
             Value::Filesize { val, .. } => format_filesize_from_conf(*val, config),
             Value::Duration { val, .. } => format_duration(*val),
             Value::Date { val, .. } => format!("{val:?}"),
@@ -712,8 +746,11 @@ impl Value {
     }
 
     /// Follow a given cell path into the value: for example accessing select elements in a stream or list
+    ///
+    /// The traversal borrows its way down the path and clones only the value it finally
+    /// lands on, so extracting one cell from a large table does not copy the table.
     pub fn follow_cell_path(
-        self,
+        &self,
         cell_path: &[PathMember],
         insensitive: bool,
     ) -> Result<Value, ShellError> {
@@ -721,7 +758,7 @@ impl Value {
     }
 
     pub fn follow_cell_path_not_from_user_input(
-        self,
+        &self,
         cell_path: &[PathMember],
         insensitive: bool,
     ) -> Result<Value, ShellError> {
@@ -729,16 +766,18 @@ impl Value {
     }
 
     fn follow_cell_path_helper(
-        self,
+        &self,
         cell_path: &[PathMember],
         insensitive: bool,
         from_user_input: bool,
     ) -> Result<Value, ShellError> {
         let mut current = self;
 
-        for member in cell_path {
-            // FIXME: this uses a few extra clones for simplicity, but there may be a way
-            // to traverse the path without them
+        for (idx, member) in cell_path.iter().enumerate() {
+            // Some path members have to synthesize a value that does not live inside `self`
+            // (range elements, lazy record columns, custom values, table columns). When that
+            // happens the rest of the path is followed through the owned intermediate instead.
+            let rest = &cell_path[idx + 1..];
             match member {
                 PathMember::Int {
                     val: count,
@@ -746,10 +785,10 @@ impl Value {
                     optional,
                 } => {
                     // Treat a numeric path member as `select <val>`
-                    match &mut current {
+                    match current {
                         Value::List { vals: val, .. } => {
                             if let Some(item) = val.get(*count) {
-                                current = item.clone();
+                                current = item;
                             } else if *optional {
                                 return Ok(Value::nothing(*origin_span)); // short-circuit
                             } else if val.is_empty() {
@@ -760,7 +799,8 @@ impl Value {
                         }
                         Value::Binary { val, .. } => {
                             if let Some(item) = val.get(*count) {
-                                current = Value::int(*item as i64, *origin_span);
+                                return Value::int(*item as i64, *origin_span)
+                                    .follow_cell_path_helper(rest, insensitive, from_user_input);
                             } else if *optional {
                                 return Ok(Value::nothing(*origin_span)); // short-circuit
                             } else if val.is_empty() {
@@ -771,7 +811,7 @@ impl Value {
                         }
                         Value::Range { val, .. } => {
                             if let Some(item) = val.clone().into_range_iter(None)?.nth(*count) {
-                                current = item.clone();
+                                return item.follow_cell_path_helper(rest, insensitive, from_user_input);
                             } else if *optional {
                                 return Ok(Value::nothing(*origin_span)); // short-circuit
                             } else {
@@ -781,8 +821,14 @@ impl Value {
                             }
                         }
                         Value::CustomValue { val, .. } => {
-                            current = match val.follow_path_int(*count, *origin_span) {
-                                Ok(val) => val,
+                            match val.follow_path_int(*count, *origin_span) {
+                                Ok(val) => {
+                                    return val.follow_cell_path_helper(
+                                        rest,
+                                        insensitive,
+                                        from_user_input,
+                                    )
+                                }
                                 Err(err) => {
                                     if *optional {
                                         return Ok(Value::nothing(*origin_span));
@@ -804,7 +850,7 @@ impl Value {
                                 span: *origin_span,
                             })
                         }
-                        Value::Error { error } => return Err(*error.to_owned()),
+                        Value::Error { error } => return Err(*error.clone()),
                         x => {
                             return Err(ShellError::IncompatiblePathAccess { type_name:format!("{}",x.get_type()), span: *origin_span })
                         }
@@ -814,11 +860,8 @@ impl Value {
                     val: column_name,
                     span: origin_span,
                     optional,
-                } => match &mut current {
+                } => match current {
                     Value::Record { cols, vals, span } => {
-                        let cols = cols.clone();
-                        let span = *span;
-
                         // Make reverse iterate to avoid duplicate column leads to first value, actually last value is expected.
                         if let Some(found) = cols.iter().zip(vals.iter()).rev().find(|x| {
                             if insensitive {
@@ -827,27 +870,31 @@ impl Value {
                                 x.0 == column_name
                             }
                         }) {
-                            current = found.1.clone();
+                            current = found.1;
                         } else if *optional {
                             return Ok(Value::nothing(*origin_span)); // short-circuit
                         } else {
                             if from_user_input {
-                                if let Some(suggestion) = did_you_mean(&cols, column_name) {
+                                if let Some(suggestion) = did_you_mean(cols, column_name) {
                                     return Err(ShellError::DidYouMean(suggestion, *origin_span));
                                 }
                             }
                             return Err(ShellError::CantFindColumn {
                                 col_name: column_name.to_string(),
                                 span: *origin_span,
-                                src_span: span,
+                                src_span: *span,
                             });
                         }
                     }
                     Value::LazyRecord { val, span } => {
                         let columns = val.column_names();
 
-                        if columns.contains(&column_name.as_str()) {
-                            current = val.get_column_value(column_name)?;
+                        if columns.iter().any(|col| col == column_name) {
+                            return val.get_column_value(column_name)?.follow_cell_path_helper(
+                                rest,
+                                insensitive,
+                                from_user_input,
+                            );
                         } else if *optional {
                             return Ok(Value::nothing(*origin_span)); // short-circuit
                         } else {
@@ -872,7 +919,7 @@ impl Value {
                         for val in vals {
                             // only look in records; this avoids unintentionally recursing into deeply nested tables
                             if matches!(val, Value::Record { .. }) {
-                                if let Ok(result) = val.clone().follow_cell_path(
+                                if let Ok(result) = val.follow_cell_path(
                                     &[PathMember::String {
                                         val: column_name.clone(),
                                         span: *origin_span,
@@ -899,18 +946,25 @@ impl Value {
                             }
                         }
 
-                        current = Value::List {
+                        return Value::List {
                             vals: output,
                             span: *span,
-                        };
+                        }
+                        .follow_cell_path_helper(
+                            rest,
+                            insensitive,
+                            from_user_input,
+                        );
                     }
                     Value::CustomValue { val, .. } => {
-                        current = val.follow_path_string(column_name.clone(), *origin_span)?;
+                        return val
+                            .follow_path_string(column_name.clone(), *origin_span)?
+                            .follow_cell_path_helper(rest, insensitive, from_user_input);
                     }
                     Value::Nothing { .. } if *optional => {
                         return Ok(Value::nothing(*origin_span)); // short-circuit
                     }
-                    Value::Error { error } => return Err(*error.to_owned()),
+                    Value::Error { error } => return Err(*error.clone()),
                     x => {
                         return Err(ShellError::IncompatiblePathAccess {
                             type_name: format!("{}", x.get_type()),
@@ -918,14 +972,15 @@ impl Value {
                         })
                     }
                 },
+                PathMember::Dynamic { span, .. } => return Err(unresolved_dynamic_member(*span)),
             }
         }
         // If a single Value::Error was produced by the above (which won't happen if nullify_errors is true), unwrap it now.
         // Note that Value::Errors inside Lists remain as they are, so that the rest of the list can still potentially be used.
         if let Value::Error { error } = current {
-            Err(*error)
+            Err(*error.clone())
         } else {
-            Ok(current)
+            Ok(current.clone())
         }
     }
 
@@ -935,9 +990,7 @@ impl Value {
         cell_path: &[PathMember],
         callback: Box<dyn FnOnce(&Value) -> Value>,
     ) -> Result<(), ShellError> {
-        let orig = self.clone();
-
-        let new_val = callback(&orig.follow_cell_path(cell_path, false)?);
+        let new_val = callback(&self.follow_cell_path(cell_path, false)?);
 
         match new_val {
             Value::Error { error } => Err(*error),
@@ -973,12 +1026,12 @@ impl Value {
                                     }
                                     if !found {
                                         if cell_path.len() == 1 {
-                                            cols.push(col_name.clone());
+                                            Arc::make_mut(cols).push(col_name.clone());
                                             vals.push(new_val);
                                             break;
                                         } else {
                                             let mut new_col = Value::Record {
-                                                cols: vec![],
+                                                cols: Arc::new(vec![]),
                                                 vals: vec![],
                                                 span: new_val.span()?,
                                             };
@@ -1015,11 +1068,11 @@ impl Value {
                         }
                         if !found {
                             if cell_path.len() == 1 {
-                                cols.push(col_name.clone());
+                                Arc::make_mut(cols).push(col_name.clone());
                                 vals.push(new_val);
                             } else {
                                 let mut new_col = Value::Record {
-                                    cols: vec![],
+                                    cols: Arc::new(vec![]),
                                     vals: vec![],
                                     span: new_val.span()?,
                                 };
@@ -1062,6 +1115,7 @@ impl Value {
                         })
                     }
                 },
+                PathMember::Dynamic { span, .. } => return Err(unresolved_dynamic_member(*span)),
             },
             None => {
                 *self = new_val;
@@ -1076,9 +1130,7 @@ impl Value {
         cell_path: &[PathMember],
         callback: Box<dyn FnOnce(&Value) -> Value + 'a>,
     ) -> Result<(), ShellError> {
-        let orig = self.clone();
-
-        let new_val = callback(&orig.follow_cell_path(cell_path, false)?);
+        let new_val = callback(&self.follow_cell_path(cell_path, false)?);
 
         match new_val {
             Value::Error { error } => Err(*error),
@@ -1191,6 +1243,7 @@ impl Value {
                         })
                     }
                 },
+                PathMember::Dynamic { span, .. } => return Err(unresolved_dynamic_member(*span)),
             },
             None => {
                 *self = new_val;
@@ -1220,7 +1273,7 @@ impl Value {
                                     } => {
                                         let mut found = false;
                                         let mut index = 0;
-                                        cols.retain_mut(|col| {
+                                        Arc::make_mut(cols).retain_mut(|col| {
                                             if col == col_name {
                                                 found = true;
                                                 vals.remove(index);
@@ -1257,7 +1310,7 @@ impl Value {
                             let mut found = false;
                             for (i, col) in cols.clone().iter().enumerate() {
                                 if col == col_name {
-                                    cols.remove(i);
+                                    Arc::make_mut(cols).remove(i);
                                     vals.remove(i);
                                     found = true;
                                 }
@@ -1302,6 +1355,7 @@ impl Value {
                             src_span: v.span()?,
                         }),
                     },
+                    PathMember::Dynamic { span, .. } => Err(unresolved_dynamic_member(*span)),
                 }
             }
             _ => {
@@ -1399,6 +1453,7 @@ impl Value {
                             src_span: v.span()?,
                         }),
                     },
+                    PathMember::Dynamic { span, .. } => Err(unresolved_dynamic_member(*span)),
                 }
             }
         }
@@ -1443,7 +1498,7 @@ impl Value {
                                         }
                                     }
 
-                                    cols.push(col_name.clone());
+                                    Arc::make_mut(cols).push(col_name.clone());
                                     vals.push(new_val.clone());
                                 }
                                 // SIGH...
@@ -1482,7 +1537,7 @@ impl Value {
                             }
                         }
 
-                        cols.push(col_name.clone());
+                        Arc::make_mut(cols).push(col_name.clone());
                         vals.push(new_val);
                     }
                     other => {
@@ -1518,6 +1573,7 @@ impl Value {
                         })
                     }
                 },
+                PathMember::Dynamic { span, .. } => return Err(unresolved_dynamic_member(*span)),
             },
             None => {
                 *self = new_val;
@@ -1536,7 +1592,7 @@ impl Value {
 
     pub fn columns(&self) -> Vec<String> {
         match self {
-            Value::Record { cols, .. } => cols.clone(),
+            Value::Record { cols, .. } => cols.to_vec(),
             _ => vec![],
         }
     }
@@ -1563,12 +1619,24 @@ impl Value {
         Value::Float { val, span }
     }
 
+    pub fn decimal(val: Decimal, span: Span) -> Value {
+        Value::Decimal { val, span }
+    }
+
+    pub fn big_int(val: BigInt, span: Span) -> Value {
+        Value::BigInt { val, span }
+    }
+
     pub fn boolean(val: bool, span: Span) -> Value {
         Value::Bool { val, span }
     }
 
     pub fn record(cols: Vec<String>, vals: Vec<Value>, span: Span) -> Value {
-        Value::Record { cols, vals, span }
+        Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        }
     }
 
     pub fn record_from_hashmap(map: &HashMap<String, Value>, span: Span) -> Value {
@@ -1639,7 +1707,7 @@ impl Value {
     /// when used in errors.
     pub fn test_record(cols: Vec<impl Into<String>>, vals: Vec<Value>) -> Value {
         Value::Record {
-            cols: cols.into_iter().map(|s| s.into()).collect(),
+            cols: Arc::new(cols.into_iter().map(|s| s.into()).collect()),
             vals,
 
             span: Span::test_data(),
@@ -1678,11 +1746,28 @@ impl PartialOrd for Value {
             val.partial_cmp(&other)
         }
 
+        fn compare_decimal_to_bigint(val: &Decimal, other: &BigInt) -> Option<Ordering> {
+            match other
+                .to_i128()
+                .and_then(|other| Decimal::try_from(other).ok())
+            {
+                Some(other) => val.partial_cmp(&other),
+                // the big int is beyond Decimal's range, so only its sign matters
+                None => Some(if other.sign() == num_bigint::Sign::Minus {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                }),
+            }
+        }
+
         match (self, other) {
             (Value::Bool { val: lhs, .. }, rhs) => match rhs {
                 Value::Bool { val: rhs, .. } => lhs.partial_cmp(rhs),
                 Value::Int { .. } => Some(Ordering::Less),
                 Value::Float { .. } => Some(Ordering::Less),
+                Value::Decimal { .. } => Some(Ordering::Less),
+                Value::BigInt { .. } => Some(Ordering::Less),
                 Value::Filesize { .. } => Some(Ordering::Less),
                 Value::Duration { .. } => Some(Ordering::Less),
                 Value::Date { .. } => Some(Ordering::Less),
@@ -1704,6 +1789,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { val: rhs, .. } => lhs.partial_cmp(rhs),
                 Value::Float { val: rhs, .. } => compare_floats(*lhs as f64, *rhs),
+                Value::Decimal { val: rhs, .. } => Decimal::from(*lhs).partial_cmp(rhs),
+                Value::BigInt { val: rhs, .. } => BigInt::from(*lhs).partial_cmp(rhs),
                 Value::Filesize { .. } => Some(Ordering::Less),
                 Value::Duration { .. } => Some(Ordering::Less),
                 Value::Date { .. } => Some(Ordering::Less),
@@ -1725,6 +1812,69 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { val: rhs, .. } => compare_floats(*lhs, *rhs as f64),
                 Value::Float { val: rhs, .. } => compare_floats(*lhs, *rhs),
+                // lossy, see the comment on the mirrored Decimal arm below
+                Value::Decimal { val: rhs, .. } => {
+                    compare_floats(*lhs, rhs.to_f64().unwrap_or(f64::NAN))
+                }
+                Value::BigInt { val: rhs, .. } => {
+                    compare_floats(*lhs, rhs.to_f64().unwrap_or(f64::NAN))
+                }
+                Value::Filesize { .. } => Some(Ordering::Less),
+                Value::Duration { .. } => Some(Ordering::Less),
+                Value::Date { .. } => Some(Ordering::Less),
+                Value::Range { .. } => Some(Ordering::Less),
+                Value::String { .. } => Some(Ordering::Less),
+                Value::Record { .. } => Some(Ordering::Less),
+                Value::LazyRecord { .. } => Some(Ordering::Less),
+                Value::List { .. } => Some(Ordering::Less),
+                Value::Block { .. } => Some(Ordering::Less),
+                Value::Closure { .. } => Some(Ordering::Less),
+                Value::Nothing { .. } => Some(Ordering::Less),
+                Value::Error { .. } => Some(Ordering::Less),
+                Value::Binary { .. } => Some(Ordering::Less),
+                Value::CellPath { .. } => Some(Ordering::Less),
+                Value::CustomValue { .. } => Some(Ordering::Less),
+                Value::MatchPattern { .. } => Some(Ordering::Less),
+            },
+            (Value::Decimal { val: lhs, .. }, rhs) => match rhs {
+                Value::Bool { .. } => Some(Ordering::Greater),
+                Value::Int { val: rhs, .. } => lhs.partial_cmp(&Decimal::from(*rhs)),
+                // Comparing to a float goes through f64: the exact decimal
+                // expansion of a binary float needs far more digits than a
+                // Decimal holds, so values closer than f64 precision allows
+                // compare as equal.
+                Value::Float { val: rhs, .. } => {
+                    compare_floats(lhs.to_f64().unwrap_or(f64::NAN), *rhs)
+                }
+                Value::Decimal { val: rhs, .. } => lhs.partial_cmp(rhs),
+                Value::BigInt { val: rhs, .. } => compare_decimal_to_bigint(lhs, rhs),
+                Value::Filesize { .. } => Some(Ordering::Less),
+                Value::Duration { .. } => Some(Ordering::Less),
+                Value::Date { .. } => Some(Ordering::Less),
+                Value::Range { .. } => Some(Ordering::Less),
+                Value::String { .. } => Some(Ordering::Less),
+                Value::Record { .. } => Some(Ordering::Less),
+                Value::LazyRecord { .. } => Some(Ordering::Less),
+                Value::List { .. } => Some(Ordering::Less),
+                Value::Block { .. } => Some(Ordering::Less),
+                Value::Closure { .. } => Some(Ordering::Less),
+                Value::Nothing { .. } => Some(Ordering::Less),
+                Value::Error { .. } => Some(Ordering::Less),
+                Value::Binary { .. } => Some(Ordering::Less),
+                Value::CellPath { .. } => Some(Ordering::Less),
+                Value::CustomValue { .. } => Some(Ordering::Less),
+                Value::MatchPattern { .. } => Some(Ordering::Less),
+            },
+            (Value::BigInt { val: lhs, .. }, rhs) => match rhs {
+                Value::Bool { .. } => Some(Ordering::Greater),
+                Value::Int { val: rhs, .. } => lhs.partial_cmp(&BigInt::from(*rhs)),
+                Value::Float { val: rhs, .. } => {
+                    compare_floats(lhs.to_f64().unwrap_or(f64::NAN), *rhs)
+                }
+                Value::Decimal { val: rhs, .. } => {
+                    compare_decimal_to_bigint(rhs, lhs).map(Ordering::reverse)
+                }
+                Value::BigInt { val: rhs, .. } => lhs.partial_cmp(rhs),
                 Value::Filesize { .. } => Some(Ordering::Less),
                 Value::Duration { .. } => Some(Ordering::Less),
                 Value::Date { .. } => Some(Ordering::Less),
@@ -1746,6 +1896,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { val: rhs, .. } => lhs.partial_cmp(rhs),
                 Value::Duration { .. } => Some(Ordering::Less),
                 Value::Date { .. } => Some(Ordering::Less),
@@ -1767,6 +1919,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { val: rhs, .. } => lhs.partial_cmp(rhs),
                 Value::Date { .. } => Some(Ordering::Less),
@@ -1788,6 +1942,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { val: rhs, .. } => lhs.partial_cmp(rhs),
@@ -1809,6 +1965,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { .. } => Some(Ordering::Greater),
@@ -1830,6 +1988,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { .. } => Some(Ordering::Greater),
@@ -1858,6 +2018,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { .. } => Some(Ordering::Greater),
@@ -1904,6 +2066,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { .. } => Some(Ordering::Greater),
@@ -1925,6 +2089,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { .. } => Some(Ordering::Greater),
@@ -1946,6 +2112,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { .. } => Some(Ordering::Greater),
@@ -1967,6 +2135,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { .. } => Some(Ordering::Greater),
@@ -1988,6 +2158,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { .. } => Some(Ordering::Greater),
@@ -2009,6 +2181,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { .. } => Some(Ordering::Greater),
@@ -2030,6 +2204,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { .. } => Some(Ordering::Greater),
@@ -2059,6 +2235,8 @@ impl PartialOrd for Value {
                 Value::Bool { .. } => Some(Ordering::Greater),
                 Value::Int { .. } => Some(Ordering::Greater),
                 Value::Float { .. } => Some(Ordering::Greater),
+                Value::Decimal { .. } => Some(Ordering::Greater),
+                Value::BigInt { .. } => Some(Ordering::Greater),
                 Value::Filesize { .. } => Some(Ordering::Greater),
                 Value::Duration { .. } => Some(Ordering::Greater),
                 Value::Date { .. } => Some(Ordering::Greater),
@@ -2093,9 +2271,24 @@ impl Value {
                 if let Some(val) = lhs.checked_add(*rhs) {
                     Ok(Value::Int { val, span })
                 } else {
-                    Err(ShellError::OperatorOverflow { msg: "add operation overflowed".into(), span, help: "Consider using floating point values for increased range by promoting operand with 'into decimal'. Note: float has reduced precision!".into() })
+                    Ok(Value::BigInt {
+                        val: BigInt::from(*lhs) + BigInt::from(*rhs),
+                        span,
+                    })
                 }
             }
+            (Value::BigInt { val: lhs, .. }, Value::BigInt { val: rhs, .. }) => Ok(Value::BigInt {
+                val: lhs + rhs,
+                span,
+            }),
+            (Value::Int { val: lhs, .. }, Value::BigInt { val: rhs, .. }) => Ok(Value::BigInt {
+                val: BigInt::from(*lhs) + rhs,
+                span,
+            }),
+            (Value::BigInt { val: lhs, .. }, Value::Int { val: rhs, .. }) => Ok(Value::BigInt {
+                val: lhs + BigInt::from(*rhs),
+                span,
+            }),
             (Value::Int { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Float {
                 val: *lhs as f64 + *rhs,
                 span,
@@ -2108,6 +2301,39 @@ impl Value {
                 val: lhs + rhs,
                 span,
             }),
+            (Value::Decimal { val: lhs, .. }, Value::Decimal { val: rhs, .. }) => {
+                if let Some(val) = lhs.checked_add(*rhs) {
+                    Ok(Value::Decimal { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow {
+                        msg: "add operation overflowed".into(),
+                        span,
+                        help: "".into(),
+                    })
+                }
+            }
+            (Value::Int { val: lhs, .. }, Value::Decimal { val: rhs, .. }) => {
+                if let Some(val) = Decimal::from(*lhs).checked_add(*rhs) {
+                    Ok(Value::Decimal { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow {
+                        msg: "add operation overflowed".into(),
+                        span,
+                        help: "".into(),
+                    })
+                }
+            }
+            (Value::Decimal { val: lhs, .. }, Value::Int { val: rhs, .. }) => {
+                if let Some(val) = lhs.checked_add(Decimal::from(*rhs)) {
+                    Ok(Value::Decimal { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow {
+                        msg: "add operation overflowed".into(),
+                        span,
+                        help: "".into(),
+                    })
+                }
+            }
             (Value::String { val: lhs, .. }, Value::String { val: rhs, .. }) => Ok(Value::String {
                 val: lhs.to_string() + rhs,
                 span,
@@ -2203,9 +2429,24 @@ impl Value {
                 if let Some(val) = lhs.checked_sub(*rhs) {
                     Ok(Value::Int { val, span })
                 } else {
-                    Err(ShellError::OperatorOverflow { msg: "subtraction operation overflowed".into(), span, help: "Consider using floating point values for increased range by promoting operand with 'into decimal'. Note: float has reduced precision!".into() })
+                    Ok(Value::BigInt {
+                        val: BigInt::from(*lhs) - BigInt::from(*rhs),
+                        span,
+                    })
                 }
             }
+            (Value::BigInt { val: lhs, .. }, Value::BigInt { val: rhs, .. }) => Ok(Value::BigInt {
+                val: lhs - rhs,
+                span,
+            }),
+            (Value::Int { val: lhs, .. }, Value::BigInt { val: rhs, .. }) => Ok(Value::BigInt {
+                val: BigInt::from(*lhs) - rhs,
+                span,
+            }),
+            (Value::BigInt { val: lhs, .. }, Value::Int { val: rhs, .. }) => Ok(Value::BigInt {
+                val: lhs - BigInt::from(*rhs),
+                span,
+            }),
             (Value::Int { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Float {
                 val: *lhs as f64 - *rhs,
                 span,
@@ -2218,6 +2459,39 @@ impl Value {
                 val: lhs - rhs,
                 span,
             }),
+            (Value::Decimal { val: lhs, .. }, Value::Decimal { val: rhs, .. }) => {
+                if let Some(val) = lhs.checked_sub(*rhs) {
+                    Ok(Value::Decimal { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow {
+                        msg: "subtraction operation overflowed".into(),
+                        span,
+                        help: "".into(),
+                    })
+                }
+            }
+            (Value::Int { val: lhs, .. }, Value::Decimal { val: rhs, .. }) => {
+                if let Some(val) = Decimal::from(*lhs).checked_sub(*rhs) {
+                    Ok(Value::Decimal { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow {
+                        msg: "subtraction operation overflowed".into(),
+                        span,
+                        help: "".into(),
+                    })
+                }
+            }
+            (Value::Decimal { val: lhs, .. }, Value::Int { val: rhs, .. }) => {
+                if let Some(val) = lhs.checked_sub(Decimal::from(*rhs)) {
+                    Ok(Value::Decimal { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow {
+                        msg: "subtraction operation overflowed".into(),
+                        span,
+                        help: "".into(),
+                    })
+                }
+            }
             (Value::Date { val: lhs, .. }, Value::Date { val: rhs, .. }) => {
                 let result = lhs.signed_duration_since(*rhs);
 
@@ -2284,9 +2558,24 @@ impl Value {
                 if let Some(val) = lhs.checked_mul(*rhs) {
                     Ok(Value::Int { val, span })
                 } else {
-                    Err(ShellError::OperatorOverflow { msg: "multiply operation overflowed".into(), span, help: "Consider using floating point values for increased range by promoting operand with 'into decimal'. Note: float has reduced precision!".into() })
+                    Ok(Value::BigInt {
+                        val: BigInt::from(*lhs) * BigInt::from(*rhs),
+                        span,
+                    })
                 }
             }
+            (Value::BigInt { val: lhs, .. }, Value::BigInt { val: rhs, .. }) => Ok(Value::BigInt {
+                val: lhs * rhs,
+                span,
+            }),
+            (Value::Int { val: lhs, .. }, Value::BigInt { val: rhs, .. }) => Ok(Value::BigInt {
+                val: BigInt::from(*lhs) * rhs,
+                span,
+            }),
+            (Value::BigInt { val: lhs, .. }, Value::Int { val: rhs, .. }) => Ok(Value::BigInt {
+                val: lhs * BigInt::from(*rhs),
+                span,
+            }),
             (Value::Int { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Float {
                 val: *lhs as f64 * *rhs,
                 span,
@@ -2299,6 +2588,39 @@ impl Value {
                 val: lhs * rhs,
                 span,
             }),
+            (Value::Decimal { val: lhs, .. }, Value::Decimal { val: rhs, .. }) => {
+                if let Some(val) = lhs.checked_mul(*rhs) {
+                    Ok(Value::Decimal { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow {
+                        msg: "multiply operation overflowed".into(),
+                        span,
+                        help: "".into(),
+                    })
+                }
+            }
+            (Value::Int { val: lhs, .. }, Value::Decimal { val: rhs, .. }) => {
+                if let Some(val) = Decimal::from(*lhs).checked_mul(*rhs) {
+                    Ok(Value::Decimal { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow {
+                        msg: "multiply operation overflowed".into(),
+                        span,
+                        help: "".into(),
+                    })
+                }
+            }
+            (Value::Decimal { val: lhs, .. }, Value::Int { val: rhs, .. }) => {
+                if let Some(val) = lhs.checked_mul(Decimal::from(*rhs)) {
+                    Ok(Value::Decimal { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow {
+                        msg: "multiply operation overflowed".into(),
+                        span,
+                        help: "".into(),
+                    })
+                }
+            }
             (Value::Int { val: lhs, .. }, Value::Filesize { val: rhs, .. }) => {
                 Ok(Value::Filesize {
                     val: *lhs * *rhs,
@@ -2407,6 +2729,31 @@ impl Value {
                     Err(ShellError::DivisionByZero { span: op })
                 }
             }
+            (Value::BigInt { val: lhs, .. }, Value::BigInt { val: rhs, .. }) => {
+                if rhs.is_zero() {
+                    Err(ShellError::DivisionByZero { span: op })
+                } else if (lhs % rhs).is_zero() {
+                    Ok(Value::BigInt {
+                        val: lhs / rhs,
+                        span,
+                    })
+                } else {
+                    Ok(Value::Float {
+                        val: lhs.to_f64().unwrap_or(f64::NAN) / rhs.to_f64().unwrap_or(f64::NAN),
+                        span,
+                    })
+                }
+            }
+            (Value::Int { val: lhs, .. }, Value::BigInt { val: rhs, .. }) => Value::big_int(
+                BigInt::from(*lhs),
+                span,
+            )
+            .div(op, &Value::big_int(rhs.clone(), span), span),
+            (Value::BigInt { val: lhs, .. }, Value::Int { val: rhs, .. }) => Value::big_int(
+                lhs.clone(),
+                span,
+            )
+            .div(op, &Value::big_int(BigInt::from(*rhs), span), span),
             (Value::Int { val: lhs, .. }, Value::Float { val: rhs, .. }) => {
                 if *rhs != 0.0 {
                     Ok(Value::Float {
@@ -2437,6 +2784,42 @@ impl Value {
                     Err(ShellError::DivisionByZero { span: op })
                 }
             }
+            (Value::Decimal { val: lhs, .. }, Value::Decimal { val: rhs, .. }) => {
+                if !rhs.is_zero() {
+                    Ok(Value::Decimal {
+                        val: lhs
+                            .checked_div(*rhs)
+                            .ok_or(ShellError::DivisionByZero { span: op })?,
+                        span,
+                    })
+                } else {
+                    Err(ShellError::DivisionByZero { span: op })
+                }
+            }
+            (Value::Int { val: lhs, .. }, Value::Decimal { val: rhs, .. }) => {
+                if !rhs.is_zero() {
+                    Ok(Value::Decimal {
+                        val: Decimal::from(*lhs)
+                            .checked_div(*rhs)
+                            .ok_or(ShellError::DivisionByZero { span: op })?,
+                        span,
+                    })
+                } else {
+                    Err(ShellError::DivisionByZero { span: op })
+                }
+            }
+            (Value::Decimal { val: lhs, .. }, Value::Int { val: rhs, .. }) => {
+                if *rhs != 0 {
+                    Ok(Value::Decimal {
+                        val: lhs
+                            .checked_div(Decimal::from(*rhs))
+                            .ok_or(ShellError::DivisionByZero { span: op })?,
+                        span,
+                    })
+                } else {
+                    Err(ShellError::DivisionByZero { span: op })
+                }
+            }
             (Value::Filesize { val: lhs, .. }, Value::Filesize { val: rhs, .. }) => {
                 if *rhs != 0 {
                     if lhs % rhs == 0 {
@@ -3005,36 +3388,15 @@ impl Value {
                     span: rhs_span,
                 },
             ) => {
-                let is_match = match engine_state.regex_cache.try_lock() {
-                    Ok(mut cache) => {
-                        if let Some(regex) = cache.get(rhs) {
-                            regex.is_match(lhs)
-                        } else {
-                            let regex = Regex::new(rhs).map_err(|e| {
-                                ShellError::UnsupportedInput(
-                                    format!("{e}"),
-                                    "value originated from here".into(),
-                                    span,
-                                    *rhs_span,
-                                )
-                            })?;
-                            let ret = regex.is_match(lhs);
-                            cache.put(rhs.clone(), regex);
-                            ret
-                        }
-                    }
-                    Err(_) => {
-                        let regex = Regex::new(rhs).map_err(|e| {
-                            ShellError::UnsupportedInput(
-                                format!("{e}"),
-                                "value originated from here".into(),
-                                span,
-                                *rhs_span,
-                            )
-                        })?;
-                        regex.is_match(lhs)
-                    }
-                };
+                let regex = engine_state.compiled_regex(rhs).map_err(|e| {
+                    ShellError::UnsupportedInput(
+                        format!("{e}"),
+                        "value originated from here".into(),
+                        span,
+                        *rhs_span,
+                    )
+                })?;
+                let is_match = regex.is_match(lhs);
 
                 Ok(Value::Bool {
                     val: if invert {
@@ -3210,6 +3572,26 @@ impl Value {
                     Err(ShellError::DivisionByZero { span: op })
                 }
             }
+            (Value::BigInt { val: lhs, .. }, Value::BigInt { val: rhs, .. }) => {
+                if rhs.is_zero() {
+                    Err(ShellError::DivisionByZero { span: op })
+                } else {
+                    Ok(Value::BigInt {
+                        val: lhs % rhs,
+                        span,
+                    })
+                }
+            }
+            (Value::Int { val: lhs, .. }, Value::BigInt { val: rhs, .. }) => Value::big_int(
+                BigInt::from(*lhs),
+                span,
+            )
+            .modulo(op, &Value::big_int(rhs.clone(), span), span),
+            (Value::BigInt { val: lhs, .. }, Value::Int { val: rhs, .. }) => Value::big_int(
+                lhs.clone(),
+                span,
+            )
+            .modulo(op, &Value::big_int(BigInt::from(*rhs), span), span),
             (Value::Int { val: lhs, .. }, Value::Float { val: rhs, .. }) => {
                 if *rhs != 0.0 {
                     Ok(Value::Float {
@@ -3240,6 +3622,42 @@ impl Value {
                     Err(ShellError::DivisionByZero { span: op })
                 }
             }
+            (Value::Decimal { val: lhs, .. }, Value::Decimal { val: rhs, .. }) => {
+                if !rhs.is_zero() {
+                    Ok(Value::Decimal {
+                        val: lhs
+                            .checked_rem(*rhs)
+                            .ok_or(ShellError::DivisionByZero { span: op })?,
+                        span,
+                    })
+                } else {
+                    Err(ShellError::DivisionByZero { span: op })
+                }
+            }
+            (Value::Int { val: lhs, .. }, Value::Decimal { val: rhs, .. }) => {
+                if !rhs.is_zero() {
+                    Ok(Value::Decimal {
+                        val: Decimal::from(*lhs)
+                            .checked_rem(*rhs)
+                            .ok_or(ShellError::DivisionByZero { span: op })?,
+                        span,
+                    })
+                } else {
+                    Err(ShellError::DivisionByZero { span: op })
+                }
+            }
+            (Value::Decimal { val: lhs, .. }, Value::Int { val: rhs, .. }) => {
+                if *rhs != 0 {
+                    Ok(Value::Decimal {
+                        val: lhs
+                            .checked_rem(Decimal::from(*rhs))
+                            .ok_or(ShellError::DivisionByZero { span: op })?,
+                        span,
+                    })
+                } else {
+                    Err(ShellError::DivisionByZero { span: op })
+                }
+            }
             (Value::CustomValue { val: lhs, span }, rhs) => {
                 lhs.operation(*span, Operator::Math(Math::Modulo), op, rhs)
             }
@@ -3317,9 +3735,16 @@ impl Value {
                 if let Some(val) = lhs.checked_pow(*rhs as u32) {
                     Ok(Value::Int { val, span })
                 } else {
-                    Err(ShellError::OperatorOverflow { msg: "pow operation overflowed".into(), span, help: "Consider using floating point values for increased range by promoting operand with 'into decimal'. Note: float has reduced precision!".into() })
+                    Ok(Value::BigInt {
+                        val: num_traits::pow(BigInt::from(*lhs), *rhs as u32 as usize),
+                        span,
+                    })
                 }
             }
+            (Value::BigInt { val: lhs, .. }, Value::Int { val: rhs, .. }) => Ok(Value::BigInt {
+                val: num_traits::pow(lhs.clone(), *rhs as u32 as usize),
+                span,
+            }),
             (Value::Int { val: lhs, .. }, Value::Float { val: rhs, .. }) => Ok(Value::Float {
                 val: (*lhs as f64).powf(*rhs),
                 span,
@@ -3332,6 +3757,17 @@ impl Value {
                 val: lhs.powf(*rhs),
                 span,
             }),
+            (Value::Decimal { val: lhs, .. }, Value::Int { val: rhs, .. }) => {
+                if let Some(val) = lhs.checked_powi(*rhs) {
+                    Ok(Value::Decimal { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow {
+                        msg: "pow operation overflowed".into(),
+                        span,
+                        help: "".into(),
+                    })
+                }
+            }
             (Value::CustomValue { val: lhs, span }, rhs) => {
                 lhs.operation(*span, Operator::Math(Math::Pow), op, rhs)
             }
@@ -3347,6 +3783,19 @@ impl Value {
     }
 }
 
+// `PathMember::Dynamic` members are resolved down to string or int members by
+// the evaluator before a cell path reaches a `Value`; hitting one here means a
+// caller skipped that step.
+fn unresolved_dynamic_member(span: Span) -> ShellError {
+    ShellError::GenericError(
+        "Unresolved dynamic cell path member".into(),
+        "computed member was not evaluated before use".into(),
+        Some(span),
+        None,
+        Vec::new(),
+    )
+}
+
 fn reorder_record_inner(cols: &[String], vals: &[Value]) -> (Vec<String>, Vec<Value>) {
     let mut kv_pairs =
         iter::zip(cols.to_owned(), vals.to_owned()).collect::<Vec<(String, Value)>>();
@@ -3375,7 +3824,11 @@ impl From<Spanned<HashMap<String, Value>>> for Value {
                 acc
             });
 
-        Value::Record { cols, vals, span }
+        Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        }
     }
 }
 
@@ -3384,7 +3837,7 @@ fn type_compatible(a: Type, b: Type) -> bool {
         return true;
     }
 
-    matches!((a, b), (Type::Int, Type::Float) | (Type::Float, Type::Int))
+    a.is_numeric() && b.is_numeric()
 }
 
 /// Create a Value::Record from a spanned indexmap
@@ -3400,7 +3853,11 @@ impl From<Spanned<IndexMap<String, Value>>> for Value {
                 acc
             });
 
-        Value::Record { cols, vals, span }
+        Value::Record {
+            cols: Arc::new(cols),
+            vals,
+            span,
+        }
     }
 }
 
@@ -3709,6 +4166,7 @@ fn get_filesize_format(format_value: &str, filesize_metric: Option<bool>) -> (By
 mod tests {
 
     use super::{Span, Value};
+    use std::sync::Arc;
 
     mod is_empty {
         use super::*;
@@ -3737,22 +4195,22 @@ mod tests {
         #[test]
         fn test_record() {
             let no_columns_nor_cell_values = Value::Record {
-                cols: vec![],
+                cols: Arc::new(vec![]),
                 vals: vec![],
                 span: Span::unknown(),
             };
             let one_column_and_one_cell_value_with_empty_strings = Value::Record {
-                cols: vec![String::from("")],
+                cols: Arc::new(vec![String::from("")]),
                 vals: vec![Value::string("", Span::unknown())],
                 span: Span::unknown(),
             };
             let one_column_with_a_string_and_one_cell_value_with_empty_string = Value::Record {
-                cols: vec![String::from("column")],
+                cols: Arc::new(vec![String::from("column")]),
                 vals: vec![Value::string("", Span::unknown())],
                 span: Span::unknown(),
             };
             let one_column_with_empty_string_and_one_value_with_a_string = Value::Record {
-                cols: vec![String::from("")],
+                cols: Arc::new(vec![String::from("")]),
                 vals: vec![Value::string("text", Span::unknown())],
                 span: Span::unknown(),
             };
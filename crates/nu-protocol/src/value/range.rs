@@ -26,6 +26,15 @@ impl Range {
         to: Value,
         operator: &RangeOperator,
     ) -> Result<Range, ShellError> {
+        // Date and char ranges pick their bounds and increments differently from
+        // numeric ranges, so handle them before the numeric logic.
+        if matches!(from, Value::Date { .. }) || matches!(to, Value::Date { .. }) {
+            return Range::new_date_range(expr_span, from, next, to, operator);
+        }
+        if matches!(from, Value::String { .. }) || matches!(to, Value::String { .. }) {
+            return Range::new_char_range(expr_span, from, next, to, operator);
+        }
+
         // Select from & to values if they're not specified
         // TODO: Replace the placeholder values with proper min/max for range based on data type
         let from = if let Value::Nothing { .. } = from {
@@ -95,6 +104,102 @@ impl Range {
         })
     }
 
+    /// A range between two dates. The increment is a duration: either `next - from`, or
+    /// one day in the direction of the end bound. Open bounds clamp to the datetime range
+    /// chrono can represent.
+    fn new_date_range(
+        expr_span: Span,
+        from: Value,
+        next: Value,
+        to: Value,
+        operator: &RangeOperator,
+    ) -> Result<Range, ShellError> {
+        let utc = chrono::FixedOffset::east_opt(0).expect("utc is a valid offset");
+
+        let from = if let Value::Nothing { .. } = from {
+            Value::Date {
+                val: chrono::DateTime::<chrono::Utc>::MIN_UTC.with_timezone(&utc),
+                span: expr_span,
+            }
+        } else {
+            from
+        };
+
+        let to = if let Value::Nothing { .. } = to {
+            Value::Date {
+                val: chrono::DateTime::<chrono::Utc>::MAX_UTC.with_timezone(&utc),
+                span: expr_span,
+            }
+        } else {
+            to
+        };
+
+        if !matches!(from, Value::Date { .. }) || !matches!(to, Value::Date { .. }) {
+            return Err(ShellError::CannotCreateRange { span: expr_span });
+        }
+
+        let moves_up = matches!(
+            from.lte(expr_span, &to, expr_span),
+            Ok(Value::Bool { val: true, .. })
+        );
+
+        const DAY: i64 = 24 * 60 * 60 * 1_000_000_000;
+        let incr = if let Value::Nothing { .. } = next {
+            Value::Duration {
+                val: if moves_up { DAY } else { -DAY },
+                span: expr_span,
+            }
+        } else {
+            next.sub(operator.next_op_span, &from, expr_span)?
+        };
+
+        // The increment must be a non-zero duration pointing at the end bound,
+        // otherwise we iterate forever.
+        match incr {
+            Value::Duration { val, .. } if val != 0 && (val > 0) == moves_up => {}
+            _ => return Err(ShellError::CannotCreateRange { span: expr_span }),
+        }
+
+        Ok(Range {
+            from,
+            incr,
+            to,
+            inclusion: operator.inclusion,
+        })
+    }
+
+    /// A range between two single-character strings, stepping through the unicode
+    /// scalar values between them. Open bounds are not supported and neither is an
+    /// explicit `next` bound, since characters have no natural arithmetic.
+    fn new_char_range(
+        expr_span: Span,
+        from: Value,
+        next: Value,
+        to: Value,
+        operator: &RangeOperator,
+    ) -> Result<Range, ShellError> {
+        let is_char =
+            |v: &Value| matches!(v, Value::String { val, .. } if val.chars().count() == 1);
+
+        if !is_char(&from) || !is_char(&to) || !matches!(next, Value::Nothing { .. }) {
+            return Err(ShellError::CannotCreateRange { span: expr_span });
+        }
+
+        let moves_up = matches!(
+            from.lte(expr_span, &to, expr_span),
+            Ok(Value::Bool { val: true, .. })
+        );
+
+        let incr = Value::int(if moves_up { 1 } else { -1 }, expr_span);
+
+        Ok(Range {
+            from,
+            incr,
+            to,
+            inclusion: operator.inclusion,
+        })
+    }
+
     #[inline]
     fn moves_up(&self) -> bool {
         self.from <= self.to
@@ -230,7 +335,21 @@ impl Iterator for RangeIterator {
 
         if (ordering == desired_ordering) || (self.is_end_inclusive && ordering == Ordering::Equal)
         {
-            let next_value = self.curr.add(self.span, &self.incr, self.span);
+            // Char ranges step through unicode scalar values; everything else is
+            // ordinary `Value` arithmetic.
+            let next_value = if let (Value::String { val, .. }, Value::Int { val: step, .. }) =
+                (&self.curr, &self.incr)
+            {
+                match next_char(val, *step, self.span) {
+                    Some(next) => Ok(next),
+                    None => {
+                        self.done = true;
+                        return None;
+                    }
+                }
+            } else {
+                self.curr.add(self.span, &self.incr, self.span)
+            };
 
             let mut next = match next_value {
                 Ok(result) => result,
@@ -250,3 +369,21 @@ impl Iterator for RangeIterator {
         }
     }
 }
+
+/// Step a single-character string by `step` unicode scalar values, skipping over the
+/// surrogate gap. Returns None when the step leaves the valid `char` range.
+fn next_char(current: &str, step: i64, span: Span) -> Option<Value> {
+    let code = current.chars().next()? as i64 + step;
+    let code = match code {
+        0xD800..=0xDFFF => {
+            if step > 0 {
+                0xE000
+            } else {
+                0xD7FF
+            }
+        }
+        _ => code,
+    };
+    let next = char::from_u32(u32::try_from(code).ok()?)?;
+    Some(Value::string(next, span))
+}
@@ -1,6 +1,8 @@
 use crate::{Span, Type};
 
-#[derive(Clone, Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Variable {
     pub declaration_span: Span,
     pub ty: Type,
@@ -1,6 +1,12 @@
+use bincode::Options;
 use nu_parser::{parse, parse_module_block};
+use nu_protocol::engine::{EngineState, StateDelta, StateWorkingSet};
 use nu_protocol::report_error;
-use nu_protocol::{engine::StateWorkingSet, Module, ShellError, Span};
+use nu_protocol::{Module, ShellError, Span};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
 
 fn add_file(
     working_set: &mut StateWorkingSet,
@@ -59,26 +65,129 @@ fn load_prelude(working_set: &mut StateWorkingSet, prelude: Vec<(&str, &str)>, m
     working_set.use_decls(decls);
 }
 
-pub fn load_standard_library(
-    engine_state: &mut nu_protocol::engine::EngineState,
-) -> Result<(), miette::ErrReport> {
-    let delta = {
-        let name = "std".to_string();
-        let content = include_str!("../lib/mod.nu");
-
-        // these modules are loaded in the order they appear in this list
-        #[rustfmt::skip]
-        let submodules = vec![
-            // helper modules that could be used in other parts of the library
-            ("log", include_str!("../lib/log.nu")),
-            ("assert", include_str!("../lib/assert.nu")),
-
-            // the rest of the library
-            ("dirs", include_str!("../lib/dirs.nu")),
-            ("help", include_str!("../lib/help.nu")),
-            ("xml", include_str!("../lib/xml.nu")),
-        ];
+/// On-disk cache of the parsed standard library.
+///
+/// Parsing the library takes a noticeable slice of interactive startup, and its sources are
+/// embedded in the binary, so the parse result can be reused across launches as long as the
+/// sources and the engine state it was rendered against are unchanged. The delta embeds
+/// absolute ids and spans, so the base state counts are stored alongside it and checked
+/// before reuse; any mismatch (or any read/decode error) just falls back to parsing.
+#[derive(Serialize, Deserialize)]
+struct CachedStdLib<D> {
+    version: String,
+    source_hash: u64,
+    num_files: usize,
+    num_vars: usize,
+    num_decls: usize,
+    num_blocks: usize,
+    num_modules: usize,
+    next_span_start: usize,
+    delta: D,
+}
+
+/// Mirrors the encoding of `bincode::serialize`, but bounds allocations so that a cache
+/// written by a build with different struct layouts errors out instead of aborting on a
+/// nonsensical length prefix.
+fn cache_options() -> impl bincode::Options {
+    bincode::options()
+        .with_fixint_encoding()
+        .allow_trailing_bytes()
+        .with_limit(256 * 1024 * 1024)
+}
+
+fn stdlib_cache_path() -> Option<PathBuf> {
+    let mut path = nu_path::config_dir()?;
+    path.push("nushell");
+    path.push("stdlib-cache.bin");
+    Some(path)
+}
+
+fn source_hash(sources: &[(&str, &str)]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    sources.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn base_state_matches(cached: &CachedStdLib<StateDelta>, engine_state: &EngineState) -> bool {
+    cached.num_files == engine_state.num_files()
+        && cached.num_vars == engine_state.num_vars()
+        && cached.num_decls == engine_state.num_decls()
+        && cached.num_blocks == engine_state.num_blocks()
+        && cached.num_modules == engine_state.num_modules()
+        && cached.next_span_start == engine_state.next_span_start()
+}
+
+fn read_cached_delta(engine_state: &EngineState, source_hash: u64) -> Option<StateDelta> {
+    let bytes = std::fs::read(stdlib_cache_path()?).ok()?;
+    let cached: CachedStdLib<StateDelta> = cache_options().deserialize(&bytes).ok()?;
+
+    if cached.version == env!("CARGO_PKG_VERSION")
+        && cached.source_hash == source_hash
+        && base_state_matches(&cached, engine_state)
+    {
+        Some(cached.delta)
+    } else {
+        None
+    }
+}
+
+fn write_cached_delta(engine_state: &EngineState, source_hash: u64, delta: &StateDelta) {
+    let Some(path) = stdlib_cache_path() else {
+        return;
+    };
 
+    let cached = CachedStdLib {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        source_hash,
+        num_files: engine_state.num_files(),
+        num_vars: engine_state.num_vars(),
+        num_decls: engine_state.num_decls(),
+        num_blocks: engine_state.num_blocks(),
+        num_modules: engine_state.num_modules(),
+        next_span_start: engine_state.next_span_start(),
+        delta,
+    };
+
+    // Serialization fails if the delta somehow holds a non-parser decl; a stale or unwritable
+    // cache is never worth failing startup over, so all errors here are ignored
+    let Ok(bytes) = cache_options().serialize(&cached) else {
+        return;
+    };
+    if let Some(dir) = path.parent() {
+        if std::fs::create_dir_all(dir).is_err() {
+            return;
+        }
+    }
+    let _ = std::fs::write(path, bytes);
+}
+
+pub fn load_standard_library(engine_state: &mut EngineState) -> Result<(), miette::ErrReport> {
+    let name = "std".to_string();
+    let content = include_str!("../lib/mod.nu");
+
+    // these modules are loaded in the order they appear in this list
+    #[rustfmt::skip]
+    let submodules = vec![
+        // helper modules that could be used in other parts of the library
+        ("log", include_str!("../lib/log.nu")),
+        ("assert", include_str!("../lib/assert.nu")),
+
+        // the rest of the library
+        ("dirs", include_str!("../lib/dirs.nu")),
+        ("help", include_str!("../lib/help.nu")),
+        ("xml", include_str!("../lib/xml.nu")),
+    ];
+
+    let mut sources = submodules.clone();
+    sources.push(("std", content));
+    let source_hash = source_hash(&sources);
+
+    if let Some(delta) = read_cached_delta(engine_state, source_hash) {
+        engine_state.merge_delta(delta)?;
+        return Ok(());
+    }
+
+    let delta = {
         let prelude = vec![
             ("std help", "help"),
             ("std help commands", "help commands"),
@@ -103,6 +212,7 @@ pub fn load_standard_library(
         working_set.render()
     };
 
+    write_cached_delta(engine_state, source_hash, &delta);
     engine_state.merge_delta(delta)?;
 
     Ok(())
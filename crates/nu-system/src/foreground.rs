@@ -28,6 +28,8 @@ pub struct ForegroundProcess {
 pub struct ForegroundChild {
     inner: Child,
     pipeline_state: Arc<(AtomicU32, AtomicU32)>,
+    #[cfg(windows)]
+    job: Option<job_control::Job>,
 }
 
 impl ForegroundProcess {
@@ -50,9 +52,14 @@ impl ForegroundProcess {
                 if existing_pgrp == 0 {
                     pgrp.store(child.id(), Ordering::SeqCst);
                 }
+                #[cfg(windows)]
+                let job = job_control::Job::try_assign(&child);
+
                 ForegroundChild {
                     inner: child,
                     pipeline_state: self.pipeline_state.clone(),
+                    #[cfg(windows)]
+                    job,
                 }
             })
             .map_err(|e| {
@@ -62,6 +69,47 @@ impl ForegroundProcess {
     }
 }
 
+impl ForegroundChild {
+    /// Terminate the external together with every descendant it spawned.
+    ///
+    /// On unix this signals the child's process group (set up on spawn), on Windows it
+    /// terminates the job object the child was assigned to. Either way, grandchildren
+    /// that didn't detach themselves are taken down too, which a plain
+    /// [std::process::Child::kill] does not guarantee.
+    pub fn kill_tree(&mut self) -> std::io::Result<()> {
+        #[cfg(windows)]
+        if let Some(job) = &self.job {
+            if job.terminate().is_ok() {
+                let _ = self.inner.wait();
+                return Ok(());
+            }
+        }
+
+        #[cfg(target_family = "unix")]
+        {
+            use nix::sys::signal::{self, Signal};
+            use nix::unistd::{self, Pid};
+
+            let pid = Pid::from_raw(self.inner.id() as i32);
+            // Only signal the group if the child actually leads its own group;
+            // on platforms where we don't call setpgid (e.g. macos) the child still
+            // shares the shell's group and we must not kill ourselves.
+            if let Ok(pgid) = unistd::getpgid(Some(pid)) {
+                if pgid != unistd::getpgrp()
+                    && signal::kill(Pid::from_raw(-pgid.as_raw()), Signal::SIGKILL).is_ok()
+                {
+                    let _ = self.inner.wait();
+                    return Ok(());
+                }
+            }
+        }
+
+        self.inner.kill()?;
+        let _ = self.inner.wait();
+        Ok(())
+    }
+}
+
 impl AsMut<Child> for ForegroundChild {
     fn as_mut(&mut self) -> &mut Child {
         &mut self.inner
@@ -78,6 +126,75 @@ impl Drop for ForegroundChild {
     }
 }
 
+#[cfg(windows)]
+mod job_control {
+    use std::os::windows::io::AsRawHandle;
+    use winapi::shared::minwindef::FALSE;
+    use winapi::um::handleapi::CloseHandle;
+    use winapi::um::jobapi2::{
+        AssignProcessToJobObject, CreateJobObjectW, SetInformationJobObject, TerminateJobObject,
+    };
+    use winapi::um::winnt::{
+        JobObjectExtendedLimitInformation, HANDLE, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+        JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+    };
+
+    /// A job object the spawned external (and so all of its children) is assigned to.
+    ///
+    /// The job is configured to kill its processes when the last handle to it is
+    /// closed, so dropping this (e.g. when nushell exits) also cleans up the tree.
+    pub(super) struct Job(HANDLE);
+
+    // The handle is only ever used for job control calls, which are thread safe.
+    unsafe impl Send for Job {}
+
+    impl Job {
+        pub(super) fn try_assign(child: &std::process::Child) -> Option<Self> {
+            unsafe {
+                let handle = CreateJobObjectW(std::ptr::null_mut(), std::ptr::null());
+                if handle.is_null() {
+                    return None;
+                }
+                let job = Job(handle);
+
+                let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+                info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+                if SetInformationJobObject(
+                    handle,
+                    JobObjectExtendedLimitInformation,
+                    &mut info as *mut _ as *mut _,
+                    std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                ) == FALSE
+                {
+                    return None;
+                }
+
+                if AssignProcessToJobObject(handle, child.as_raw_handle() as HANDLE) == FALSE {
+                    return None;
+                }
+
+                Some(job)
+            }
+        }
+
+        pub(super) fn terminate(&self) -> Result<(), ()> {
+            if unsafe { TerminateJobObject(self.0, 1) } == FALSE {
+                Err(())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl Drop for Job {
+        fn drop(&mut self) {
+            unsafe {
+                CloseHandle(self.0);
+            }
+        }
+    }
+}
+
 // It's a simpler version of fish shell's external process handling.
 // Note: we exclude macos because the techniques below seem to have issues in macos 13 currently.
 #[cfg(all(target_family = "unix", not(target_os = "macos")))]
@@ -64,9 +64,9 @@ fn nu_protocol_value_to_json(value: Value, config: &Config, with_footer: bool) -
     match value {
         Value::Record { cols, vals, .. } => {
             let mut map = serde_json::Map::new();
-            for (key, value) in cols.into_iter().zip(vals) {
+            for (key, value) in cols.iter().zip(vals) {
                 let val = nu_protocol_value_to_json(value, config, false);
-                map.insert(key, val);
+                map.insert(key.clone(), val);
             }
 
             Json::Object(map)
@@ -77,7 +77,7 @@ fn nu_protocol_value_to_json(value: Value, config: &Config, with_footer: bool) -
                 match val {
                     Value::Record { cols, .. } => {
                         if let Some(_cols) = &used_cols {
-                            if _cols != cols {
+                            if *_cols != cols.as_slice() {
                                 used_cols = None;
                                 break;
                             }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use tabled::{
     style::RawStyle,
     style::{HorizontalLine, Line, Style},
@@ -187,6 +189,55 @@ impl TableTheme {
         }
     }
 
+    /// Builds a theme from a set of named border characters.
+    ///
+    /// Unknown names are ignored; borders which are not mentioned are not drawn.
+    /// The recognized names are `top`, `bottom`, `left`, `right`, `vertical`,
+    /// `horizontal`, the 4 corners (`top_left`, ...) and the 5 intersections
+    /// (`top_intersection`, `bottom_intersection`, `left_intersection`,
+    /// `right_intersection`, `inner_intersection`).
+    pub fn custom(chars: &HashMap<String, char>) -> TableTheme {
+        let get = |name: &str| chars.get(name).copied();
+
+        let mut theme: RawStyle = Style::blank().into();
+        theme.set_top(get("top"));
+        theme.set_bottom(get("bottom"));
+        theme.set_left(get("left"));
+        theme.set_right(get("right"));
+        theme.set_vertical(get("vertical"));
+        theme.set_top_left(get("top_left"));
+        theme.set_top_right(get("top_right"));
+        theme.set_bottom_left(get("bottom_left"));
+        theme.set_bottom_right(get("bottom_right"));
+        theme.set_top_split(get("top_intersection"));
+        theme.set_bottom_split(get("bottom_intersection"));
+
+        // `horizontal` is only drawn under the header, just like in the built in themes;
+        // the full theme (used by expanded views) draws it between every row.
+        let header_line = Line::new(
+            get("horizontal"),
+            get("inner_intersection"),
+            get("left_intersection"),
+            get("right_intersection"),
+        );
+
+        let mut full_theme = theme.clone();
+        if !header_line.is_empty() {
+            theme.set_horizontals(HashMap::from([(1, header_line)]));
+        }
+
+        full_theme.set_horizontal(get("horizontal"));
+        full_theme.set_internal_split(get("inner_intersection"));
+        full_theme.set_left_split(get("left_intersection"));
+        full_theme.set_right_split(get("right_intersection"));
+
+        Self {
+            theme,
+            full_theme: Some(full_theme),
+            has_inner: true,
+        }
+    }
+
     pub fn has_top_line(&self) -> bool {
         self.theme.get_top().is_some()
             || self.theme.get_top_intersection().is_some()
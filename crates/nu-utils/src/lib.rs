@@ -1,8 +1,10 @@
+pub mod allocation;
 pub mod ctrl_c;
 mod deansi;
 pub mod locale;
 pub mod utils;
 
+pub use allocation::{allocation_count, CountingAllocator};
 pub use locale::get_system_locale;
 pub use utils::{
     enable_vt_processing, get_default_config, get_default_env, get_ls_colors,
@@ -1,5 +1,6 @@
 use nu_plugin::{EvaluatedCall, LabeledError};
 use nu_protocol::Value;
+use std::sync::Arc;
 pub struct Example;
 
 impl Example {
@@ -68,7 +69,7 @@ impl Example {
                     .collect::<Vec<Value>>();
 
                 Value::Record {
-                    cols: cols.clone(),
+                    cols: Arc::new(cols.clone()),
                     vals,
                     span: call.head,
                 }
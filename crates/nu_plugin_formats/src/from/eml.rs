@@ -3,6 +3,7 @@ use eml_parser::EmlParser;
 use indexmap::map::IndexMap;
 use nu_plugin::{EvaluatedCall, LabeledError};
 use nu_protocol::{PluginExample, ShellError, Span, Spanned, Value};
+use std::sync::Arc;
 
 const DEFAULT_BODY_PREVIEW: usize = 50;
 pub const CMD_NAME: &str = "from eml";
@@ -25,16 +26,16 @@ To: someone@somewhere.com
 Test' | from eml"
                 .into(),
             result: Some(Value::Record {
-                cols: vec![
+                cols: Arc::new(vec![
                     "Subject".to_string(),
                     "From".to_string(),
                     "To".to_string(),
                     "Body".to_string(),
-                ],
+                ]),
                 vals: vec![
                     Value::test_string("Welcome"),
                     Value::Record {
-                        cols: vec!["Name".to_string(), "Address".to_string()],
+                        cols: Arc::new(vec!["Name".to_string(), "Address".to_string()]),
                         vals: vec![
                             Value::nothing(Span::test_data()),
                             Value::test_string("test@email.com"),
@@ -42,7 +43,7 @@ Test' | from eml"
                         span: Span::test_data(),
                     },
                     Value::Record {
-                        cols: vec!["Name".to_string(), "Address".to_string()],
+                        cols: Arc::new(vec!["Name".to_string(), "Address".to_string()]),
                         vals: vec![
                             Value::nothing(Span::test_data()),
                             Value::test_string("someone@somewhere.com"),
@@ -62,16 +63,16 @@ To: someone@somewhere.com
 Test' | from eml -b 1"
                 .into(),
             result: Some(Value::Record {
-                cols: vec![
+                cols: Arc::new(vec![
                     "Subject".to_string(),
                     "From".to_string(),
                     "To".to_string(),
                     "Body".to_string(),
-                ],
+                ]),
                 vals: vec![
                     Value::test_string("Welcome"),
                     Value::Record {
-                        cols: vec!["Name".to_string(), "Address".to_string()],
+                        cols: Arc::new(vec!["Name".to_string(), "Address".to_string()]),
                         vals: vec![
                             Value::nothing(Span::test_data()),
                             Value::test_string("test@email.com"),
@@ -79,7 +80,7 @@ Test' | from eml -b 1"
                         span: Span::test_data(),
                     },
                     Value::Record {
-                        cols: vec!["Name".to_string(), "Address".to_string()],
+                        cols: Arc::new(vec!["Name".to_string(), "Address".to_string()]),
                         vals: vec![
                             Value::nothing(Span::test_data()),
                             Value::test_string("someone@somewhere.com"),
@@ -116,7 +117,7 @@ fn emailaddress_to_value(span: Span, email_address: &EmailAddress) -> Value {
     };
 
     Value::Record {
-        cols: vec!["Name".to_string(), "Address".to_string()],
+        cols: Arc::new(vec!["Name".to_string(), "Address".to_string()]),
         vals: vec![n, a],
         span,
     }
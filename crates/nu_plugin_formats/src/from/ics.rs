@@ -4,6 +4,7 @@ use indexmap::map::IndexMap;
 use nu_plugin::{EvaluatedCall, LabeledError};
 use nu_protocol::{PluginExample, ShellError, Span, Spanned, Value};
 use std::io::BufReader;
+use std::sync::Arc;
 
 pub const CMD_NAME: &str = "from ics";
 
@@ -51,7 +52,7 @@ pub fn examples() -> Vec<PluginExample> {
         description: "Converts ics formatted string to table".into(),
         result: Some(Value::List {
             vals: vec![Value::Record {
-                cols: vec![
+                cols: Arc::new(vec![
                     "properties".to_string(),
                     "events".to_string(),
                     "alarms".to_string(),
@@ -59,7 +60,7 @@ pub fn examples() -> Vec<PluginExample> {
                     "journals".to_string(),
                     "free-busys".to_string(),
                     "timezones".to_string(),
-                ],
+                ]),
                 vals: vec![
                     Value::List {
                         vals: vec![],
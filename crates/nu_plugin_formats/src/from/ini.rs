@@ -1,5 +1,6 @@
 use nu_plugin::{EvaluatedCall, LabeledError};
 use nu_protocol::{PluginExample, ShellError, Span, Value};
+use std::sync::Arc;
 
 pub const CMD_NAME: &str = "from ini";
 
@@ -39,7 +40,7 @@ pub fn from_ini_call(call: &EvaluatedCall, input: &Value) -> Result<Value, Label
 
                 // section with its key value pairs
                 sections_key_value_pairs.push(Value::Record {
-                    cols: keys_for_section,
+                    cols: Arc::new(keys_for_section),
                     vals: values_for_section,
                     span,
                 });
@@ -47,7 +48,7 @@ pub fn from_ini_call(call: &EvaluatedCall, input: &Value) -> Result<Value, Label
 
             // all sections with all its key value pairs
             Ok(Value::Record {
-                cols: sections,
+                cols: Arc::new(sections),
                 vals: sections_key_value_pairs,
                 span,
             })
@@ -70,9 +71,9 @@ b=2' | from ini"
             .into(),
         description: "Converts ini formatted string to record".into(),
         result: Some(Value::Record {
-            cols: vec!["foo".to_string()],
+            cols: Arc::new(vec!["foo".to_string()]),
             vals: vec![Value::Record {
-                cols: vec!["a".to_string(), "b".to_string()],
+                cols: Arc::new(vec!["a".to_string(), "b".to_string()]),
                 vals: vec![Value::test_string("1"), Value::test_string("2")],
                 span: Span::test_data(),
             }],
@@ -3,6 +3,7 @@ use ical::property::Property;
 use indexmap::map::IndexMap;
 use nu_plugin::{EvaluatedCall, LabeledError};
 use nu_protocol::{PluginExample, ShellError, Span, Spanned, Value};
+use std::sync::Arc;
 
 pub const CMD_NAME: &str = "from vcf";
 
@@ -51,15 +52,15 @@ END:VCARD' | from vcf"
         description: "Converts ics formatted string to table".into(),
         result: Some(Value::List {
             vals: vec![Value::Record {
-                cols: vec!["properties".to_string()],
+                cols: Arc::new(vec!["properties".to_string()]),
                 vals: vec![Value::List {
                     vals: vec![
                         Value::Record {
-                            cols: vec![
+                            cols: Arc::new(vec![
                                 "name".to_string(),
                                 "value".to_string(),
                                 "params".to_string(),
-                            ],
+                            ]),
                             vals: vec![
                                 Value::test_string("N"),
                                 Value::test_string("Foo"),
@@ -70,11 +71,11 @@ END:VCARD' | from vcf"
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec![
+                            cols: Arc::new(vec![
                                 "name".to_string(),
                                 "value".to_string(),
                                 "params".to_string(),
-                            ],
+                            ]),
                             vals: vec![
                                 Value::test_string("FN"),
                                 Value::test_string("Bar"),
@@ -85,11 +86,11 @@ END:VCARD' | from vcf"
                             span: Span::test_data(),
                         },
                         Value::Record {
-                            cols: vec![
+                            cols: Arc::new(vec![
                                 "name".to_string(),
                                 "value".to_string(),
                                 "params".to_string(),
-                            ],
+                            ]),
                             vals: vec![
                                 Value::test_string("EMAIL"),
                                 Value::test_string("foo@bar.com"),
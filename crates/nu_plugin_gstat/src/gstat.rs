@@ -4,6 +4,7 @@ use nu_protocol::{Span, Spanned, Value};
 use std::fmt::Write;
 use std::ops::BitAnd;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 // git status
 // https://github.com/git/git/blob/9875c515535860450bafd1a177f64f0a478900fa/Documentation/git-status.txt
@@ -207,7 +208,7 @@ impl GStat {
         // })
 
         Ok(Value::Record {
-            cols,
+            cols: Arc::new(cols),
             vals,
             span: *span,
         })
@@ -257,7 +258,7 @@ impl GStat {
         vals.push(Value::string("no_remote", *span));
 
         Value::Record {
-            cols,
+            cols: Arc::new(cols),
             vals,
             span: *span,
         }
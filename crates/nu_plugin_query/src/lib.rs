@@ -1,12 +1,8 @@
 mod nu;
 mod query;
 mod query_json;
-mod query_web;
 mod query_xml;
-mod web_tables;
 
 pub use query::Query;
 pub use query_json::execute_json_query;
-pub use query_web::parse_selector_params;
 pub use query_xml::execute_xpath_query;
-pub use web_tables::WebTable;
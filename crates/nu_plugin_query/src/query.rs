@@ -1,5 +1,4 @@
 use crate::query_json::execute_json_query;
-use crate::query_web::parse_selector_params;
 use crate::query_xml::execute_xpath_query;
 use nu_engine::documentation::get_flags_section;
 use nu_plugin::{EvaluatedCall, LabeledError, Plugin};
@@ -38,15 +37,6 @@ impl Query {
     ) -> Result<Value, LabeledError> {
         execute_json_query(name, call, input, query)
     }
-    pub fn query_web(
-        &self,
-        _name: &str,
-        call: &EvaluatedCall,
-        input: &Value,
-        _rest: Option<Spanned<String>>,
-    ) -> Result<Value, LabeledError> {
-        parse_selector_params(call, input)
-    }
     pub fn query_xml(
         &self,
         name: &str,
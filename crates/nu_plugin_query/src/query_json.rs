@@ -1,6 +1,7 @@
 use gjson::Value as gjValue;
 use nu_plugin::{EvaluatedCall, LabeledError};
 use nu_protocol::{Span, Spanned, Value};
+use std::sync::Arc;
 
 pub fn execute_json_query(
     _name: &str,
@@ -102,7 +103,7 @@ fn convert_gjson_value_to_nu_value(v: &gjValue, span: &Span) -> Value {
                 true
             });
             Value::Record {
-                cols,
+                cols: Arc::new(cols),
                 vals,
                 span: *span,
             }
@@ -1,5 +1,6 @@
 use nu_plugin::{EvaluatedCall, LabeledError};
 use nu_protocol::{Span, Spanned, Value};
+use std::sync::Arc;
 use sxd_document::parser;
 use sxd_xpath::{Context, Factory};
 
@@ -82,7 +83,7 @@ pub fn execute_xpath_query(
             // for each item so we can then use a list to make a table
             for (k, v) in cols.iter().zip(vals.iter()) {
                 records.push(Value::Record {
-                    cols: vec![k.to_string()],
+                    cols: Arc::new(vec![k.to_string()]),
                     vals: vec![v.clone()],
                     span: call.head,
                 })
@@ -124,6 +125,7 @@ mod tests {
     use super::execute_xpath_query as query;
     use nu_plugin::EvaluatedCall;
     use nu_protocol::{Span, Spanned, Value};
+    use std::sync::Arc;
 
     #[test]
     fn position_function_in_predicate() {
@@ -146,7 +148,7 @@ mod tests {
         let actual = query("", &call, &text, Some(spanned_str)).expect("test should not fail");
         let expected = Value::List {
             vals: vec![Value::Record {
-                cols: vec!["count(//a/*[posit...".to_string()],
+                cols: Arc::new(vec!["count(//a/*[posit...".to_string()]),
                 vals: vec![Value::test_float(1.0)],
                 span: Span::test_data(),
             }],
@@ -177,7 +179,7 @@ mod tests {
         let actual = query("", &call, &text, Some(spanned_str)).expect("test should not fail");
         let expected = Value::List {
             vals: vec![Value::Record {
-                cols: vec!["count(//*[contain...".to_string()],
+                cols: Arc::new(vec!["count(//*[contain...".to_string()]),
                 vals: vec![Value::test_float(1.0)],
                 span: Span::test_data(),
             }],
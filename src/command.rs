@@ -33,13 +33,20 @@ pub(crate) fn gather_commandline_args() -> (Vec<String>, String, Vec<String>) {
 
         let flag_value = match arg.as_ref() {
             "--commands" | "-c" | "--table-mode" | "-m" | "-e" | "--execute" | "--config"
-            | "--env-config" => args.next().map(|a| escape_quote_string(&a)),
+            | "--env-config" | "--record" => args.next().map(|a| escape_quote_string(&a)),
             #[cfg(feature = "plugin")]
             "--plugin-config" => args.next().map(|a| escape_quote_string(&a)),
-            "--log-level" | "--log-target" | "--testbin" | "--threads" | "-t"
-            | "--include-path" | "-I" | "--ide-goto-def" | "--ide-hover" | "--ide-complete" => {
-                args.next()
-            }
+            "--log-level"
+            | "--log-target"
+            | "--testbin"
+            | "--threads"
+            | "-t"
+            | "--include-path"
+            | "-I"
+            | "--ide-goto-def"
+            | "--ide-hover"
+            | "--ide-complete"
+            | "--generate-completions" => args.next(),
             _ => None,
         };
 
@@ -98,6 +105,7 @@ pub(crate) fn parse_commandline_args(
             let ide_complete: Option<Value> =
                 call.get_flag(engine_state, &mut stack, "ide-complete")?;
             let ide_check = call.get_named_arg("ide-check");
+            let dap = call.get_named_arg("dap");
             let login_shell = call.get_named_arg("login");
             let interactive_shell = call.get_named_arg("interactive");
             let commands: Option<Expression> = call.get_flag_expr("commands");
@@ -105,6 +113,8 @@ pub(crate) fn parse_commandline_args(
             #[cfg(feature = "plugin")]
             let plugin_file: Option<Expression> = call.get_flag_expr("plugin-config");
             let no_config_file = call.get_named_arg("no-config-file");
+            let restricted = call.get_named_arg("restricted");
+            let dry_run = call.get_named_arg("dry-run");
             let no_std_lib = call.get_named_arg("no-std-lib");
             let config_file: Option<Expression> = call.get_flag_expr("config");
             let env_file: Option<Expression> = call.get_flag_expr("env-config");
@@ -112,6 +122,9 @@ pub(crate) fn parse_commandline_args(
             let log_target: Option<Expression> = call.get_flag_expr("log-target");
             let execute: Option<Expression> = call.get_flag_expr("execute");
             let include_path: Option<Expression> = call.get_flag_expr("include-path");
+            let record: Option<Expression> = call.get_flag_expr("record");
+            let generate_completions: Option<Expression> =
+                call.get_flag_expr("generate-completions");
             let table_mode: Option<Value> =
                 call.get_flag(engine_state, &mut stack, "table-mode")?;
 
@@ -146,6 +159,8 @@ pub(crate) fn parse_commandline_args(
             let log_target = extract_contents(log_target)?;
             let execute = extract_contents(execute)?;
             let include_path = extract_contents(include_path)?;
+            let record = extract_contents(record)?;
+            let generate_completions = extract_contents(generate_completions)?;
 
             let help = call.has_flag("help");
 
@@ -188,10 +203,15 @@ pub(crate) fn parse_commandline_args(
                 log_target,
                 execute,
                 include_path,
+                record,
+                restricted,
+                dry_run,
+                generate_completions,
                 ide_goto_def,
                 ide_hover,
                 ide_complete,
                 ide_check,
+                dap,
                 table_mode,
             });
         }
@@ -226,10 +246,15 @@ pub(crate) struct NushellCliArgs {
     pub(crate) execute: Option<Spanned<String>>,
     pub(crate) table_mode: Option<Value>,
     pub(crate) include_path: Option<Spanned<String>>,
+    pub(crate) record: Option<Spanned<String>>,
+    pub(crate) restricted: Option<Spanned<String>>,
+    pub(crate) dry_run: Option<Spanned<String>>,
+    pub(crate) generate_completions: Option<Spanned<String>>,
     pub(crate) ide_goto_def: Option<Value>,
     pub(crate) ide_hover: Option<Value>,
     pub(crate) ide_complete: Option<Value>,
     pub(crate) ide_check: Option<Spanned<String>>,
+    pub(crate) dap: Option<Spanned<String>>,
 }
 
 #[derive(Clone)]
@@ -275,6 +300,16 @@ impl Command for Nu {
                 Some('n'),
             )
             .switch("no-std-lib", "start with no standard library", None)
+            .switch(
+                "restricted",
+                "run in restricted mode: only allowlisted commands, no external commands and no filesystem writes",
+                None,
+            )
+            .switch(
+                "dry-run",
+                "make filesystem commands report their planned operations instead of performing them",
+                None,
+            )
             .named(
                 "threads",
                 SyntaxShape::Int,
@@ -294,6 +329,18 @@ impl Command for Nu {
                 "start with an alternate environment config file",
                 None,
             )
+            .named(
+                "record",
+                SyntaxShape::String,
+                "record every executed command to the given file as newline-delimited JSON",
+                None,
+            )
+            .named(
+                "generate-completions",
+                SyntaxShape::String,
+                "generate completions for the main commands of the given script (bash, zsh, fish or nu)",
+                None,
+            )
             .named(
                 "ide-goto-def",
                 SyntaxShape::Int,
@@ -316,6 +363,11 @@ impl Command for Nu {
                 "ide-check",
                 "run a diagnostic check on the given source",
                 None,
+            )
+            .switch(
+                "dap",
+                "serve the Debug Adapter Protocol on stdin/stdout so editors can debug scripts",
+                None,
             );
 
         #[cfg(feature = "plugin")]
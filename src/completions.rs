@@ -0,0 +1,296 @@
+use nu_parser::parse;
+use nu_protocol::report_error;
+use nu_protocol::{
+    engine::{EngineState, StateWorkingSet},
+    ShellError, Signature, Span, Spanned, Type,
+};
+use std::fmt::Write;
+
+/// Generate completion definitions for the `main` commands of a script, so a script-based
+/// CLI can be completed by bash, zsh, fish, or nushell itself.
+pub fn generate(engine_state: &mut EngineState, file_path: &String, shell: &Spanned<String>) {
+    let file = std::fs::read(file_path).unwrap_or_else(|e| {
+        let working_set = StateWorkingSet::new(engine_state);
+        report_error(
+            &working_set,
+            &ShellError::FileNotFoundCustom(
+                format!("Could not read file '{}': {:?}", file_path, e.to_string()),
+                Span::unknown(),
+            ),
+        );
+        std::process::exit(1);
+    });
+
+    engine_state.start_in_file(Some(file_path));
+
+    let mut working_set = StateWorkingSet::new(engine_state);
+    parse(&mut working_set, Some(file_path), &file, false);
+
+    if let Some(err) = working_set.parse_errors.first() {
+        report_error(&working_set, err);
+        std::process::exit(1);
+    }
+
+    // Completion is registered for the name the script is invoked by
+    let name = std::path::Path::new(file_path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| file_path.clone());
+
+    let mut main_names: Vec<Vec<u8>> = working_set
+        .find_commands_by_predicate(|cmd| cmd == b"main" || cmd.starts_with(b"main "))
+        .into_iter()
+        .map(|(cmd, _)| cmd)
+        .collect();
+    main_names.sort();
+    main_names.dedup();
+
+    // Pairs of the subcommand path after `main` (empty for `main` itself) and its signature
+    let mut commands: Vec<(String, Signature)> = vec![];
+
+    for main_name in main_names {
+        if let Some(decl_id) = working_set.find_decl(&main_name, &Type::Any) {
+            let subcommand = String::from_utf8_lossy(&main_name[b"main".len()..])
+                .trim()
+                .to_string();
+            commands.push((subcommand, working_set.get_decl(decl_id).signature()));
+        }
+    }
+
+    if commands.is_empty() {
+        report_error(
+            &working_set,
+            &ShellError::GenericError(
+                "No main command".to_string(),
+                format!("'{file_path}' does not define a main command to generate completions for"),
+                None,
+                None,
+                Vec::new(),
+            ),
+        );
+        std::process::exit(1);
+    }
+
+    let output = match shell.item.as_str() {
+        "bash" => generate_bash(&name, &commands),
+        "zsh" => generate_zsh(&name, &commands),
+        "fish" => generate_fish(&name, &commands),
+        "nu" => generate_nu(&name, &commands),
+        _ => {
+            report_error(
+                &working_set,
+                &ShellError::GenericError(
+                    format!("Unsupported shell: {}", shell.item),
+                    "expected one of 'bash', 'zsh', 'fish', or 'nu'".to_string(),
+                    Some(shell.span),
+                    None,
+                    Vec::new(),
+                ),
+            );
+            std::process::exit(1);
+        }
+    };
+
+    print!("{output}");
+}
+
+/// The script name turned into a valid shell function name.
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// The long and short flags of a signature as the words a shell would offer.
+fn flag_words(sig: &Signature) -> Vec<String> {
+    let mut words = vec![];
+
+    for flag in &sig.named {
+        words.push(format!("--{}", flag.long));
+        if let Some(short) = flag.short {
+            words.push(format!("-{short}"));
+        }
+    }
+
+    words
+}
+
+/// The words offered at the first argument position: the subcommands plus the flags of `main`.
+fn toplevel_words(commands: &[(String, Signature)]) -> Vec<String> {
+    let mut words = vec![];
+
+    for (subcommand, sig) in commands {
+        if subcommand.is_empty() {
+            words.append(&mut flag_words(sig));
+        } else {
+            words.push(subcommand.clone());
+        }
+    }
+
+    words
+}
+
+fn generate_bash(name: &str, commands: &[(String, Signature)]) -> String {
+    let func = format!("_{}", sanitize(name));
+    let mut out = String::new();
+
+    let _ = writeln!(out, "{func}() {{");
+    let _ = writeln!(out, "    local cur opts");
+    let _ = writeln!(out, "    cur=\"${{COMP_WORDS[COMP_CWORD]}}\"");
+    let _ = writeln!(out, "    case \"${{COMP_WORDS[1]}}\" in");
+    for (subcommand, sig) in commands {
+        if subcommand.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "        {subcommand})");
+        let _ = writeln!(out, "            opts=\"{}\"", flag_words(sig).join(" "));
+        let _ = writeln!(out, "            ;;");
+    }
+    let _ = writeln!(out, "        *)");
+    let _ = writeln!(
+        out,
+        "            opts=\"{}\"",
+        toplevel_words(commands).join(" ")
+    );
+    let _ = writeln!(out, "            ;;");
+    let _ = writeln!(out, "    esac");
+    let _ = writeln!(out, "    COMPREPLY=( $(compgen -W \"$opts\" -- \"$cur\") )");
+    let _ = writeln!(out, "}}");
+    let _ = writeln!(out, "complete -F {func} {name}");
+
+    out
+}
+
+fn generate_zsh(name: &str, commands: &[(String, Signature)]) -> String {
+    let func = format!("_{}", sanitize(name));
+    let mut out = String::new();
+
+    let _ = writeln!(out, "#compdef {name}");
+    let _ = writeln!(out, "{func}() {{");
+    let _ = writeln!(out, "    local -a opts");
+    let _ = writeln!(out, "    case \"${{words[2]}}\" in");
+    for (subcommand, sig) in commands {
+        if subcommand.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out, "        {subcommand})");
+        let _ = writeln!(out, "            opts=({})", flag_words(sig).join(" "));
+        let _ = writeln!(out, "            ;;");
+    }
+    let _ = writeln!(out, "        *)");
+    let _ = writeln!(
+        out,
+        "            opts=({})",
+        toplevel_words(commands).join(" ")
+    );
+    let _ = writeln!(out, "            ;;");
+    let _ = writeln!(out, "    esac");
+    let _ = writeln!(out, "    compadd -- $opts");
+    let _ = writeln!(out, "}}");
+    let _ = writeln!(out, "compdef {func} {name}");
+
+    out
+}
+
+/// Escape a description for use inside single quotes in fish.
+fn fish_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('\'', "\\'")
+}
+
+fn generate_fish(name: &str, commands: &[(String, Signature)]) -> String {
+    let has_subcommands = commands
+        .iter()
+        .any(|(subcommand, _)| !subcommand.is_empty());
+    let mut out = String::new();
+
+    let _ = writeln!(out, "complete -c {name} -f");
+    for (subcommand, sig) in commands {
+        let condition = if subcommand.is_empty() {
+            if has_subcommands {
+                " -n __fish_use_subcommand".to_string()
+            } else {
+                String::new()
+            }
+        } else {
+            let _ = write!(
+                out,
+                "complete -c {name} -n __fish_use_subcommand -a {subcommand}"
+            );
+            if !sig.usage.is_empty() {
+                let _ = write!(out, " -d '{}'", fish_escape(&sig.usage));
+            }
+            out.push('\n');
+            format!(" -n '__fish_seen_subcommand_from {subcommand}'")
+        };
+
+        for flag in &sig.named {
+            let _ = write!(out, "complete -c {name}{condition} -l {}", flag.long);
+            if let Some(short) = flag.short {
+                let _ = write!(out, " -s {short}");
+            }
+            if !flag.desc.is_empty() {
+                let _ = write!(out, " -d '{}'", fish_escape(&flag.desc));
+            }
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+fn generate_nu(name: &str, commands: &[(String, Signature)]) -> String {
+    let mut out = String::new();
+
+    for (subcommand, sig) in commands {
+        let full_name = if subcommand.is_empty() {
+            name.to_string()
+        } else {
+            format!("{name} {subcommand}")
+        };
+
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        if !sig.usage.is_empty() {
+            let _ = writeln!(out, "# {}", sig.usage);
+        }
+        let _ = writeln!(out, "export extern \"{full_name}\" [");
+        for arg in &sig.required_positional {
+            let _ = write!(out, "    {}: {}", arg.name, arg.shape);
+            if !arg.desc.is_empty() {
+                let _ = write!(out, " # {}", arg.desc);
+            }
+            out.push('\n');
+        }
+        for arg in &sig.optional_positional {
+            let _ = write!(out, "    {}?: {}", arg.name, arg.shape);
+            if !arg.desc.is_empty() {
+                let _ = write!(out, " # {}", arg.desc);
+            }
+            out.push('\n');
+        }
+        if let Some(rest) = &sig.rest_positional {
+            let _ = write!(out, "    ...{}: {}", rest.name, rest.shape);
+            if !rest.desc.is_empty() {
+                let _ = write!(out, " # {}", rest.desc);
+            }
+            out.push('\n');
+        }
+        for flag in &sig.named {
+            let _ = write!(out, "    --{}", flag.long);
+            if let Some(short) = flag.short {
+                let _ = write!(out, " (-{short})");
+            }
+            if let Some(arg) = &flag.arg {
+                let _ = write!(out, ": {arg}");
+            }
+            if !flag.desc.is_empty() {
+                let _ = write!(out, " # {}", flag.desc);
+            }
+            out.push('\n');
+        }
+        let _ = writeln!(out, "]");
+    }
+
+    out
+}
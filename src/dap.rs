@@ -0,0 +1,433 @@
+use nu_engine::eval_block;
+use nu_parser::parse;
+use nu_protocol::engine::{
+    DebugCommand, DebugController, DebugMode, DebugStop, EngineState, Stack, StateWorkingSet,
+};
+use nu_protocol::{PipelineData, Span};
+use serde_json::{json, Value as Json};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+
+/// Serve the Debug Adapter Protocol on stdin/stdout.
+///
+/// The client drives a single session: `initialize`, `launch` with a `program` script,
+/// `setBreakpoints`, then `configurationDone` starts the script on a worker thread that
+/// reports its stops through the engine's debug state. The final pipeline value is sent
+/// back as an `output` event; what the script prints directly still goes to the raw
+/// stdout stream between messages, which DAP clients skip as unframed content.
+pub(crate) fn serve(engine_state: &mut EngineState) {
+    let (incoming_tx, incoming_rx) = mpsc::channel();
+
+    let client_tx = incoming_tx.clone();
+    std::thread::spawn(move || {
+        let mut reader = BufReader::new(std::io::stdin());
+        while let Some(message) = read_message(&mut reader) {
+            if client_tx.send(Incoming::Client(message)).is_err() {
+                break;
+            }
+        }
+        let _ = client_tx.send(Incoming::Eof);
+    });
+
+    let mut session = Session {
+        engine_state,
+        seq: 0,
+        program: None,
+        stop_on_entry: false,
+        breakpoints: HashMap::new(),
+        configuration_done: false,
+        running: None,
+        last_stop: None,
+        incoming_tx,
+    };
+
+    while let Ok(incoming) = incoming_rx.recv() {
+        match incoming {
+            Incoming::Client(message) => {
+                if !session.handle_request(&message) {
+                    break;
+                }
+            }
+            Incoming::Stopped(stop) => {
+                let event = json!({
+                    "reason": stop.reason,
+                    "threadId": THREAD_ID,
+                    "allThreadsStopped": true,
+                });
+                session.last_stop = Some(stop);
+                session.send_event("stopped", event);
+            }
+            Incoming::Finished(result) => {
+                session.running = None;
+                session.last_stop = None;
+                let exit_code = match result {
+                    Ok(Some(output)) => {
+                        session.send_event("output", json!({ "output": output }));
+                        0
+                    }
+                    Ok(None) => 0,
+                    Err(message) => {
+                        session.send_event(
+                            "output",
+                            json!({ "category": "stderr", "output": message }),
+                        );
+                        1
+                    }
+                };
+                session.send_event("exited", json!({ "exitCode": exit_code }));
+                session.send_event("terminated", json!({}));
+            }
+            Incoming::Eof => break,
+        }
+    }
+}
+
+/// The single thread id reported to the client; scripts run on one thread.
+const THREAD_ID: i64 = 1;
+
+enum Incoming {
+    Client(Json),
+    Stopped(DebugStop),
+    Finished(Result<Option<String>, String>),
+    Eof,
+}
+
+struct Session<'a> {
+    engine_state: &'a mut EngineState,
+    seq: i64,
+    program: Option<String>,
+    stop_on_entry: bool,
+    /// Requested breakpoint lines per source path.
+    breakpoints: HashMap<String, Vec<i64>>,
+    configuration_done: bool,
+    /// The command channel of the running script, if one was launched.
+    running: Option<Sender<DebugCommand>>,
+    last_stop: Option<DebugStop>,
+    incoming_tx: Sender<Incoming>,
+}
+
+impl Session<'_> {
+    /// Handle one client request; returns false when the session should end.
+    fn handle_request(&mut self, request: &Json) -> bool {
+        let command = request["command"].as_str().unwrap_or("").to_string();
+        let arguments = &request["arguments"];
+
+        match command.as_str() {
+            "initialize" => {
+                self.respond(
+                    request,
+                    Ok(json!({
+                        "supportsConfigurationDoneRequest": true,
+                    })),
+                );
+                self.send_event("initialized", json!({}));
+            }
+            "launch" => {
+                self.program = arguments["program"].as_str().map(|s| s.to_string());
+                self.stop_on_entry = arguments["stopOnEntry"].as_bool().unwrap_or(false);
+                self.respond(request, Ok(json!({})));
+                self.maybe_launch();
+            }
+            "setBreakpoints" => {
+                let path = arguments["source"]["path"]
+                    .as_str()
+                    .unwrap_or("")
+                    .to_string();
+                let lines: Vec<i64> = arguments["breakpoints"]
+                    .as_array()
+                    .map(|breakpoints| {
+                        breakpoints
+                            .iter()
+                            .filter_map(|bp| bp["line"].as_i64())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let verified: Vec<Json> = lines
+                    .iter()
+                    .map(|line| json!({ "verified": true, "line": line }))
+                    .collect();
+                self.breakpoints.insert(path, lines);
+                self.respond(request, Ok(json!({ "breakpoints": verified })));
+            }
+            "configurationDone" => {
+                self.configuration_done = true;
+                self.respond(request, Ok(json!({})));
+                self.maybe_launch();
+            }
+            "threads" => {
+                self.respond(
+                    request,
+                    Ok(json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] })),
+                );
+            }
+            "stackTrace" => {
+                let frames: Vec<Json> = match &self.last_stop {
+                    Some(stop) => vec![json!({
+                        "id": 1,
+                        "name": stop.source,
+                        "source": { "path": stop.file },
+                        "line": stop.line,
+                        "column": 1,
+                    })],
+                    None => Vec::new(),
+                };
+                let total = frames.len();
+                self.respond(
+                    request,
+                    Ok(json!({ "stackFrames": frames, "totalFrames": total })),
+                );
+            }
+            "scopes" => {
+                self.respond(
+                    request,
+                    Ok(json!({
+                        "scopes": [{ "name": "Locals", "variablesReference": 1, "expensive": false }],
+                    })),
+                );
+            }
+            "variables" => {
+                let mut variables: Vec<Json> = Vec::new();
+                if let Some(stop) = &self.last_stop {
+                    variables.push(json!({
+                        "name": "$in",
+                        "value": stop.input,
+                        "variablesReference": 0,
+                    }));
+                    for (name, value) in &stop.vars {
+                        variables.push(json!({
+                            "name": name,
+                            "value": value,
+                            "variablesReference": 0,
+                        }));
+                    }
+                }
+                self.respond(request, Ok(json!({ "variables": variables })));
+            }
+            "continue" => {
+                self.resume(DebugCommand::Continue);
+                self.respond(request, Ok(json!({ "allThreadsContinued": true })));
+            }
+            "next" | "stepOut" => {
+                self.resume(DebugCommand::Next);
+                self.respond(request, Ok(json!({})));
+            }
+            "stepIn" => {
+                self.resume(DebugCommand::Step);
+                self.respond(request, Ok(json!({})));
+            }
+            "disconnect" | "terminate" => {
+                self.resume(DebugCommand::Quit);
+                self.respond(request, Ok(json!({})));
+                return false;
+            }
+            _ => {
+                self.respond(request, Err(format!("Unsupported request: {command}")));
+            }
+        }
+
+        true
+    }
+
+    /// Start the script once both `launch` and `configurationDone` have arrived.
+    fn maybe_launch(&mut self) {
+        if !self.configuration_done || self.running.is_some() {
+            return;
+        }
+        let Some(program) = self.program.clone() else {
+            return;
+        };
+
+        match self.launch(&program) {
+            Ok(commands) => self.running = Some(commands),
+            Err(message) => {
+                let _ = self.incoming_tx.send(Incoming::Finished(Err(message)));
+            }
+        }
+    }
+
+    fn launch(&mut self, program: &str) -> Result<Sender<DebugCommand>, String> {
+        let contents =
+            std::fs::read(program).map_err(|err| format!("Could not read {program}: {err}"))?;
+
+        let mut engine_state = self.engine_state.clone();
+        let (block, delta) = {
+            let mut working_set = StateWorkingSet::new(&engine_state);
+            let block = parse(&mut working_set, Some(program), &contents, false);
+
+            if let Some(err) = working_set.parse_errors.first() {
+                return Err(format!("Failed to parse {program}: {err}"));
+            }
+
+            (block, working_set.render())
+        };
+        engine_state
+            .merge_delta(delta)
+            .map_err(|err| err.to_string())?;
+
+        let file_start = engine_state
+            .files()
+            .find(|(name, ..)| name == program)
+            .map(|(_, start, _)| *start)
+            .unwrap_or(0);
+
+        // Only breakpoints in the launched script can be mapped to spans for now
+        let break_lines = self.breakpoints.get(program).cloned().unwrap_or_default();
+        let break_spans = line_spans(&contents, file_start, &break_lines);
+
+        let (events_tx, events_rx) = mpsc::channel();
+        let (commands_tx, commands_rx) = mpsc::channel();
+
+        {
+            let mut state = engine_state.debug_state.lock().expect("debug state mutex");
+            state.active = true;
+            state.mode = if self.stop_on_entry {
+                DebugMode::Step
+            } else {
+                DebugMode::Continue
+            };
+            state.depth = 0;
+            state.break_commands = Vec::new();
+            state.break_spans = break_spans;
+            state.controller = Some(Arc::new(DebugController {
+                events: events_tx,
+                commands: Mutex::new(commands_rx),
+            }));
+        }
+
+        let stops_tx = self.incoming_tx.clone();
+        std::thread::spawn(move || {
+            for stop in events_rx {
+                if stops_tx.send(Incoming::Stopped(stop)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        let finished_tx = self.incoming_tx.clone();
+        std::thread::spawn(move || {
+            let mut stack = Stack::new();
+            let result = eval_block(
+                &engine_state,
+                &mut stack,
+                &block,
+                PipelineData::empty(),
+                true,
+                false,
+            );
+
+            let result = match result {
+                Ok(data) => match data.into_value(Span::unknown()) {
+                    nu_protocol::Value::Nothing { .. } => Ok(None),
+                    value => Ok(Some(
+                        value.into_string("\n", engine_state.get_config()) + "\n",
+                    )),
+                },
+                Err(err) => Err(format!("{err}")),
+            };
+
+            {
+                let mut state = engine_state.debug_state.lock().expect("debug state mutex");
+                *state = nu_protocol::engine::DebugState::new();
+            }
+
+            let _ = finished_tx.send(Incoming::Finished(result));
+        });
+
+        Ok(commands_tx)
+    }
+
+    fn resume(&mut self, command: DebugCommand) {
+        if let Some(commands) = &self.running {
+            let _ = commands.send(command);
+        }
+    }
+
+    fn respond(&mut self, request: &Json, body: Result<Json, String>) {
+        self.seq += 1;
+        let mut response = json!({
+            "type": "response",
+            "seq": self.seq,
+            "request_seq": request["seq"],
+            "command": request["command"],
+        });
+        match body {
+            Ok(body) => {
+                response["success"] = json!(true);
+                response["body"] = body;
+            }
+            Err(message) => {
+                response["success"] = json!(false);
+                response["message"] = json!(message);
+            }
+        }
+        write_message(&response);
+    }
+
+    fn send_event(&mut self, event: &str, body: Json) {
+        self.seq += 1;
+        write_message(&json!({
+            "type": "event",
+            "seq": self.seq,
+            "event": event,
+            "body": body,
+        }));
+    }
+}
+
+/// Read one `Content-Length` framed JSON message; None on EOF or a malformed stream.
+fn read_message(reader: &mut impl BufRead) -> Option<Json> {
+    let mut content_length: Option<usize> = None;
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Content-Length:") {
+            content_length = value.trim().parse().ok();
+        }
+    }
+
+    let mut content = vec![0; content_length?];
+    reader.read_exact(&mut content).ok()?;
+    serde_json::from_slice(&content).ok()
+}
+
+fn write_message(message: &Json) {
+    let content = message.to_string();
+    let mut stdout = std::io::stdout().lock();
+    let _ = write!(
+        stdout,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    );
+    let _ = stdout.flush();
+}
+
+/// The absolute spans of the given 1-based lines of the file starting at `file_start`.
+fn line_spans(contents: &[u8], file_start: usize, lines: &[i64]) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut line_start = 0;
+    let mut line_num = 1;
+
+    for line in String::from_utf8_lossy(contents).split_inclusive('\n') {
+        if lines.contains(&line_num) {
+            spans.push(Span::new(
+                file_start + line_start,
+                file_start + line_start + line.len(),
+            ));
+        }
+        line_start += line.len();
+        line_num += 1;
+    }
+
+    spans
+}
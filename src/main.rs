@@ -1,5 +1,7 @@
 mod command;
+mod completions;
 mod config_files;
+mod dap;
 mod ide;
 mod logger;
 mod run;
@@ -24,6 +26,10 @@ use nu_protocol::{report_error_new, Value};
 use nu_protocol::{util::BufferedReader, PipelineData, RawStream};
 use nu_utils::utils::perf;
 use run::{run_commands, run_file, run_repl};
+
+// Count allocations so `profile` can report them per pipeline element
+#[global_allocator]
+static GLOBAL: nu_utils::CountingAllocator = nu_utils::CountingAllocator;
 use signals::{ctrlc_protection, sigquit_protection};
 use std::{
     io::BufReader,
@@ -151,6 +157,30 @@ fn main() -> Result<()> {
         engine_state.add_env_var("NU_LIB_DIRS".into(), Value::List { vals, span });
     }
 
+    if let Some(record_file) = &parsed_nu_cli_args.record {
+        engine_state.add_env_var(
+            "NU_RECORD_FILE".into(),
+            Value::String {
+                val: record_file.item.clone(),
+                span: record_file.span,
+            },
+        );
+    }
+
+    if parsed_nu_cli_args.restricted.is_some() {
+        engine_state.restricted_commands = Some(restricted_command_allowlist());
+    }
+
+    if parsed_nu_cli_args.dry_run.is_some() {
+        engine_state.dry_run = true;
+    }
+
+    if let Some(shell) = &parsed_nu_cli_args.generate_completions {
+        completions::generate(&mut engine_state, &script_name, shell);
+
+        return Ok(());
+    }
+
     // IDE commands
     if let Some(ide_goto_def) = parsed_nu_cli_args.ide_goto_def {
         ide::goto_def(&mut engine_state, &script_name, &ide_goto_def);
@@ -246,6 +276,12 @@ fn main() -> Result<()> {
         use_color,
     );
 
+    if parsed_nu_cli_args.dap.is_some() {
+        dap::serve(&mut engine_state);
+
+        return Ok(());
+    }
+
     if let Some(commands) = parsed_nu_cli_args.commands.clone() {
         run_commands(
             &mut engine_state,
@@ -269,3 +305,425 @@ fn main() -> Result<()> {
         run_repl(&mut engine_state, parsed_nu_cli_args, entire_start_time)
     }
 }
+
+// The allowlist for `--restricted`: only commands known not to write to the filesystem,
+// touch the network, or spawn processes, listed explicitly so the mode fails closed —
+// a new command stays denied until someone vets it and adds it here. Commands that look
+// read-only but can write or execute (`start`, `into sqlite`, `xattr set`, `config nu`,
+// `secret get-from`, the `pkg` family, `register`, ...) are deliberately absent.
+// `NU_RESTRICTED_ALLOW` (comma-separated) re-allows specific commands; it is read from
+// the process environment once, so an evaluated snippet cannot extend it.
+fn restricted_command_allowlist() -> Vec<String> {
+    const ALLOWED: &[&str] = &[
+        // language and control flow
+        "alias",
+        "break",
+        "commandline",
+        "const",
+        "continue",
+        "def",
+        "def-env",
+        "describe",
+        "do",
+        "echo",
+        "error make",
+        "export",
+        "export alias",
+        "export const",
+        "export def",
+        "export def-env",
+        "export extern",
+        "export old-alias",
+        "export use",
+        "export-env",
+        "export-meta",
+        "extern",
+        "for",
+        "help",
+        "help aliases",
+        "help commands",
+        "help externs",
+        "help generate",
+        "help modules",
+        "help operators",
+        "hide",
+        "hide-env",
+        "if",
+        "ignore",
+        "is-admin",
+        "lazy make",
+        "let",
+        "let-env",
+        "load-env",
+        "loop",
+        "match",
+        "module",
+        "module export-list",
+        "mut",
+        "old-alias",
+        "overlay",
+        "overlay hide",
+        "overlay list",
+        "overlay new",
+        "overlay restore",
+        "overlay save",
+        "overlay use",
+        "return",
+        "source",
+        "source-env",
+        "try",
+        "use",
+        "version",
+        "while",
+        "with-env",
+        // shell navigation
+        "cd",
+        "enter",
+        "exit",
+        "g",
+        "n",
+        "p",
+        "shells",
+        // read-only filesystem access
+        "du",
+        "glob",
+        "ls",
+        "open",
+        "stat",
+        "trash list",
+        "xattr get",
+        // filters and structured data
+        "all",
+        "any",
+        "append",
+        "collect",
+        "columns",
+        "compact",
+        "default",
+        "drop",
+        "drop column",
+        "drop nth",
+        "each",
+        "each while",
+        "enumerate",
+        "every",
+        "filter",
+        "find",
+        "first",
+        "flatten",
+        "get",
+        "group",
+        "group-by",
+        "headers",
+        "histogram",
+        "insert",
+        "is-empty",
+        "join",
+        "last",
+        "length",
+        "lines",
+        "merge",
+        "move",
+        "par-each",
+        "prepend",
+        "query",
+        "query json",
+        "query xml",
+        "range",
+        "reduce",
+        "reject",
+        "rename",
+        "reverse",
+        "roll",
+        "roll down",
+        "roll left",
+        "roll right",
+        "roll up",
+        "rotate",
+        "select",
+        "shuffle",
+        "skip",
+        "skip until",
+        "skip while",
+        "sort",
+        "sort-by",
+        "split list",
+        "split-by",
+        "take",
+        "take until",
+        "take while",
+        "transpose",
+        "uniq",
+        "uniq-by",
+        "update",
+        "update cells",
+        "upsert",
+        "values",
+        "where",
+        "window",
+        "wrap",
+        "zip",
+        // paths
+        "path",
+        "path basename",
+        "path dirname",
+        "path exists",
+        "path expand",
+        "path join",
+        "path parse",
+        "path relative-to",
+        "path split",
+        "path type",
+        // strings
+        "ansi",
+        "ansi gradient",
+        "ansi link",
+        "ansi strip",
+        "char",
+        "decode",
+        "decode base64",
+        "decode hex",
+        "detect columns",
+        "detect encoding",
+        "encode",
+        "encode base64",
+        "encode hex",
+        "fill",
+        "fmt",
+        "format",
+        "format filesize",
+        "nu-check",
+        "nu-highlight",
+        "parse",
+        "print",
+        "size",
+        "split",
+        "split chars",
+        "split column",
+        "split row",
+        "split words",
+        "str",
+        "str camel-case",
+        "str capitalize",
+        "str collect",
+        "str contains",
+        "str distance",
+        "str downcase",
+        "str ends-with",
+        "str find-replace",
+        "str index-of",
+        "str join",
+        "str kebab-case",
+        "str length",
+        "str lpad",
+        "str pascal-case",
+        "str replace",
+        "str reverse",
+        "str rpad",
+        "str screaming-snake-case",
+        "str snake-case",
+        "str starts-with",
+        "str substring",
+        "str title-case",
+        "str to-datetime",
+        "str to-decimal",
+        "str to-int",
+        "str trim",
+        "str upcase",
+        "url encode",
+        // math, bits, and bytes
+        "math",
+        "math abs",
+        "math arccos",
+        "math arccosh",
+        "math arcsin",
+        "math arcsinh",
+        "math arctan",
+        "math arctanh",
+        "math avg",
+        "math ceil",
+        "math cos",
+        "math cosh",
+        "math e",
+        "math eval",
+        "math exp",
+        "math floor",
+        "math ln",
+        "math log",
+        "math max",
+        "math median",
+        "math min",
+        "math mode",
+        "math pi",
+        "math product",
+        "math round",
+        "math sin",
+        "math sinh",
+        "math sqrt",
+        "math stddev",
+        "math sum",
+        "math tan",
+        "math tanh",
+        "math tau",
+        "math variance",
+        "bits",
+        "bits and",
+        "bits not",
+        "bits or",
+        "bits rol",
+        "bits ror",
+        "bits shl",
+        "bits shr",
+        "bits xor",
+        "bytes",
+        "bytes add",
+        "bytes at",
+        "bytes build",
+        "bytes collect",
+        "bytes ends-with",
+        "bytes find",
+        "bytes index-of",
+        "bytes length",
+        "bytes remove",
+        "bytes replace",
+        "bytes reverse",
+        "bytes starts-with",
+        "bytes view",
+        // dates
+        "date",
+        "date format",
+        "date humanize",
+        "date list-timezone",
+        "date now",
+        "date to-record",
+        "date to-table",
+        "date to-timezone",
+        // conversions (`into sqlite` writes a file and is excluded)
+        "into",
+        "into bigint",
+        "into binary",
+        "into bool",
+        "into datetime",
+        "into decimal",
+        "into duration",
+        "into filesize",
+        "into int",
+        "into record",
+        "into string",
+        // format conversions
+        "from",
+        "from clf",
+        "from csv",
+        "from eml",
+        "from ics",
+        "from ini",
+        "from json",
+        "from mbox",
+        "from ndjson",
+        "from nuon",
+        "from ods",
+        "from plist",
+        "from prometheus",
+        "from properties",
+        "from ssv",
+        "from syslog",
+        "from toml",
+        "from tsv",
+        "from url",
+        "from vcf",
+        "from xlsx",
+        "from xml",
+        "from yaml",
+        "from yml",
+        "to",
+        "to csv",
+        "to html",
+        "to ini",
+        "to json",
+        "to md",
+        "to ndjson",
+        "to nuon",
+        "to plist",
+        "to text",
+        "to toml",
+        "to tsv",
+        "to xml",
+        "to yaml",
+        // generators and random
+        "cal",
+        "seq",
+        "seq char",
+        "seq date",
+        "random",
+        "random bool",
+        "random chars",
+        "random decimal",
+        "random dice",
+        "random integer",
+        "random uuid",
+        // hashing
+        "hash",
+        "hash base64",
+        "hash md5",
+        "hash sha256",
+        "hash value",
+        "verify",
+        "verify files",
+        // url string handling (no network access)
+        "url",
+        "url build-query",
+        "url join",
+        "url parse",
+        // viewers
+        "explore",
+        "grid",
+        "table",
+        // introspection and debugging
+        "ast",
+        "debug",
+        "debug run",
+        "explain",
+        "inspect",
+        "log",
+        "log critical",
+        "log debug",
+        "log error",
+        "log info",
+        "log warning",
+        "metadata",
+        "profile",
+        "timeit",
+        "view",
+        "view files",
+        "view source",
+        "view span",
+        // terminal and input
+        "clear",
+        "input",
+        "input list",
+        "keybindings",
+        "keybindings default",
+        "keybindings list",
+        "keybindings listen",
+        "sleep",
+        "term",
+        "term on-resize",
+        "term query",
+        "term size",
+        // misc
+        "history",
+        "history session",
+        "secret",
+        "secret unwrap",
+        "secret wrap",
+        "tutor",
+    ];
+
+    let mut allowed: Vec<String> = ALLOWED.iter().map(|name| name.to_string()).collect();
+
+    if let Ok(extra) = std::env::var("NU_RESTRICTED_ALLOW") {
+        allowed.extend(extra.split(',').map(|name| name.trim().to_string()));
+    }
+
+    allowed
+}
@@ -1,7 +1,7 @@
 use std::io::{self, BufRead, Read, Write};
 
 use nu_command::create_default_context;
-use nu_command::hook::{eval_env_change_hook, eval_hook};
+use nu_command::hook::{eval_computed_env, eval_env_change_hook, eval_hook};
 use nu_engine::eval_block;
 use nu_parser::parse;
 use nu_protocol::engine::{EngineState, Stack, StateWorkingSet};
@@ -175,6 +175,14 @@ pub fn nu_repl() {
     let mut engine_state = nu_cli::add_cli_context(create_default_context());
     let mut stack = Stack::new();
 
+    // Optional config files so that `config reload` can be tested
+    if let Ok(path) = std::env::var("NU_CONFIG_PATH") {
+        engine_state.set_config_path("config-path", std::path::PathBuf::from(path));
+    }
+    if let Ok(path) = std::env::var("NU_ENV_PATH") {
+        engine_state.set_config_path("env-path", std::path::PathBuf::from(path));
+    }
+
     stack.add_env_var("PWD".to_string(), Value::test_string(cwd.to_string_lossy()));
 
     let mut last_output = String::new();
@@ -207,6 +215,20 @@ pub fn nu_repl() {
             outcome_err(&engine_state, &err);
         }
 
+        // Recompute the env vars whose NU_COMPUTED_ENV dependencies changed
+        if let Err(err) = eval_computed_env(&mut engine_state, &mut stack) {
+            outcome_err(&engine_state, &err);
+        }
+
+        // A `config reload` request re-sources env.nu and config.nu transactionally
+        if stack
+            .get_env_var(&engine_state, "NU_RELOAD_CONFIG")
+            .is_some()
+        {
+            stack.remove_env_var(&engine_state, "NU_RELOAD_CONFIG");
+            nu_cli::reload_config(&mut engine_state, &mut stack);
+        }
+
         // Check for pre_execution hook
         let config = engine_state.get_config();
 
@@ -244,6 +266,7 @@ pub fn nu_repl() {
         let input = PipelineData::empty();
         let config = engine_state.get_config();
 
+        let eval_start = std::time::Instant::now();
         match eval_block(&engine_state, &mut stack, &block, input, false, false) {
             Ok(pipeline_data) => match pipeline_data.collect_string("", config) {
                 Ok(s) => last_output = s,
@@ -252,6 +275,15 @@ pub fn nu_repl() {
             Err(err) => outcome_err(&engine_state, &err),
         }
 
+        nu_cli::record_entry(
+            &engine_state,
+            &stack,
+            line,
+            &cwd.to_string_lossy(),
+            eval_start.elapsed(),
+            Some(&last_output),
+        );
+
         if let Some(cwd) = stack.get_env_var(&engine_state, "PWD") {
             let path = cwd
                 .as_string()
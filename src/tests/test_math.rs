@@ -178,3 +178,35 @@ fn gte_null() -> TestResult {
     run_test("null >= 3 | to nuon", "null").unwrap();
     run_test("null >= null | to nuon", "null")
 }
+
+#[test]
+fn decimal_add_keeps_the_scale() -> TestResult {
+    run_test("1.10d + 2.20d", "3.30")
+}
+
+#[test]
+fn decimal_arithmetic_is_exact() -> TestResult {
+    run_test("0.1d + 0.2d == 0.3d", "true")
+}
+
+#[test]
+fn decimal_mixed_with_int() -> TestResult {
+    run_test("1.5d * 2", "3.0").unwrap();
+    run_test("2.5d > 2", "true")
+}
+
+#[test]
+fn decimal_compares_to_float() -> TestResult {
+    run_test("1.1d < 1.2", "true").unwrap();
+    run_test("1.1d == 1.1", "true")
+}
+
+#[test]
+fn decimal_compares_to_bigint() -> TestResult {
+    run_test("1.5d < 10 ** 40", "true")
+}
+
+#[test]
+fn decimal_sort() -> TestResult {
+    run_test("[3.1d 1.1d 2.1d] | sort | first", "1.1")
+}
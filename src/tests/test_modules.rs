@@ -111,3 +111,125 @@ fn export_alias() -> TestResult {
         "hello",
     )
 }
+
+#[test]
+fn module_version_match() -> TestResult {
+    run_test(
+        r#"module foo { export-meta { version: "1.2.3" }; export def a [] { 1 } }; use foo 1.2 a; a"#,
+        "1",
+    )
+}
+
+#[test]
+fn module_version_exact_match() -> TestResult {
+    run_test(
+        r#"module foo { export-meta { version: "1.2.3" }; export def a [] { 1 } }; use foo 1.2.3; foo a"#,
+        "1",
+    )
+}
+
+#[test]
+fn module_version_mismatch() -> TestResult {
+    fail_test(
+        r#"module foo { export-meta { version: "1.2.3" } }; use foo 1.3"#,
+        "does not match 1.3",
+    )
+}
+
+#[test]
+fn module_version_not_declared() -> TestResult {
+    fail_test(
+        r#"module foo { export def a [] { 1 } }; use foo 1.0"#,
+        "does not declare a version",
+    )
+}
+
+#[test]
+fn module_version_declared_twice() -> TestResult {
+    fail_test(
+        r#"module foo { export-meta { version: "1.0" }; export-meta { version: "2.0" } }"#,
+        "already declared",
+    )
+}
+
+#[test]
+fn module_meta_unknown_key() -> TestResult {
+    fail_test(
+        r#"module foo { export-meta { author: "spam" } }"#,
+        "unknown metadata key",
+    )
+}
+
+#[test]
+fn module_export_const() -> TestResult {
+    run_test(
+        r#"module spam { export const X = 10 }; use spam X; $X + 1"#,
+        "11",
+    )
+}
+
+#[test]
+fn module_export_const_whole_module_import() -> TestResult {
+    run_test(r#"module spam { export const X = 10 }; use spam; $X"#, "10")
+}
+
+#[test]
+fn module_private_const_not_importable() -> TestResult {
+    fail_test(
+        r#"module spam { const hidden = 5; export const X = 10 }; use spam hidden"#,
+        "Export not found",
+    )
+}
+
+#[test]
+fn use_member_rename() -> TestResult {
+    run_test(
+        r#"module spam { export def foo [] { "foo" } }; use spam foo as bar; bar"#,
+        "foo",
+    )
+}
+
+#[test]
+fn use_member_rename_hides_original_name() -> TestResult {
+    fail_test(
+        r#"module spam { export def foo [] { "foo" } }; use spam foo as bar; foo"#,
+        "External command",
+    )
+}
+
+#[test]
+fn use_module_rename() -> TestResult {
+    run_test(
+        r#"module spam { export def main [] { "m" }; export def foo [] { "foo" } }; use spam as sp; sp foo"#,
+        "foo",
+    )
+}
+
+#[test]
+fn use_rename_rejects_multiple_imports() -> TestResult {
+    fail_test(
+        r#"module spam { export def a [] {}; export def b [] {} }; use spam [a b] as c"#,
+        "rename a single import",
+    )
+}
+
+#[test]
+fn export_use_rename_chain() -> TestResult {
+    run_test(
+        r#"module inner { export def foo [] { "inner foo" } }; module outer { export use inner foo as bar }; use outer bar; bar"#,
+        "inner foo",
+    )
+}
+
+#[test]
+fn module_export_list_kinds() -> TestResult {
+    run_test(
+        r#"module spam { export def foo [] {}; export alias ll = ls -l; export const X = 1 }; module export-list spam | to nuon"#,
+        r#"[[name, kind]; [foo, command], [ll, alias], [X, const]]"#,
+    )
+}
+
+#[test]
+fn module_export_list_unknown_module() -> TestResult {
+    fail_test(r#"module export-list nope"#, "not found")
+}
@@ -16,8 +16,18 @@ fn int_in_exclusive_range() -> TestResult {
 }
 
 #[test]
-fn non_number_in_range() -> TestResult {
-    fail_test(r#"'a' in 1..3"#, "subset comparison is not supported")
+fn string_not_in_numeric_range() -> TestResult {
+    run_test(r#"'a' in 1..3"#, "false")
+}
+
+#[test]
+fn char_in_char_range() -> TestResult {
+    run_test(r#"'a' in 'a'..'z'"#, "true")
+}
+
+#[test]
+fn non_comparable_in_range() -> TestResult {
+    fail_test(r#"[1] in 1..3"#, "subset comparison is not supported")
 }
 
 #[test]
@@ -306,3 +306,37 @@ fn get_insensitive() -> TestResult {
         "a",
     )
 }
+
+#[test]
+fn cell_path_computed_member() -> TestResult {
+    run_test(r#"let k = "b"; {a: 1, b: 5}.($k)"#, "5")
+}
+
+#[test]
+fn cell_path_computed_member_on_subexpression() -> TestResult {
+    run_test(
+        r#"let col = "name"; ([[name]; [x] [y]]).($col) | to nuon"#,
+        "[x, y]",
+    )
+}
+
+#[test]
+fn cell_path_optional_prefix() -> TestResult {
+    run_test("{a: 1}?.b | to nuon", "null").unwrap();
+    run_test("{a: {b: 1}}?.c?.d | to nuon", "null")
+}
+
+#[test]
+fn cell_path_optional_computed_member() -> TestResult {
+    run_test(r#"let k = "b"; {a: 1}?.($k) | to nuon"#, "null")
+}
+
+#[test]
+fn cell_path_non_optional_still_errors() -> TestResult {
+    fail_test("{a: 1}.b", "cannot find column")
+}
+
+#[test]
+fn cell_path_quoted_dots() -> TestResult {
+    run_test(r#"{"a.b": 2}."a.b""#, "2")
+}
@@ -102,3 +102,56 @@ fn const_unsupported() {
 
     assert!(actual.err.contains("not_a_constant"));
 }
+
+#[test]
+fn const_string_interpolation() {
+    let inp = &[
+        r#"const x = "world""#,
+        r#"const y = $"hello ($x)""#,
+        r#"$y"#,
+    ];
+
+    let actual = nu!(cwd: "tests/const_", pipeline(&inp.join("; ")));
+
+    assert_eq!(actual.out, "hello world");
+}
+
+#[test]
+fn const_string_interpolation_of_record_member() {
+    let inp = &[
+        r#"const x = { a: 10, b: 20 }"#,
+        r#"const y = $"b is ($x.b)""#,
+        r#"$y"#,
+    ];
+
+    let actual = nu!(cwd: "tests/const_", pipeline(&inp.join("; ")));
+
+    assert_eq!(actual.out, "b is 20");
+}
+
+#[test]
+fn const_string_interpolation_inside_list() {
+    let inp = &[r#"const n = 2"#, r#"const x = [ $"a($n)" b ]"#, r#"$x.0"#];
+
+    let actual = nu!(cwd: "tests/const_", pipeline(&inp.join("; ")));
+
+    assert_eq!(actual.out, "a2");
+}
+
+#[test]
+fn const_subexpression() {
+    let inp = &[r#"const x = 10"#, r#"const y = ($x)"#, r#"$y"#];
+
+    let actual = nu!(cwd: "tests/const_", pipeline(&inp.join("; ")));
+
+    assert_eq!(actual.out, "10");
+}
+
+#[test]
+fn const_string_interpolation_of_command_is_unsupported() {
+    let inp = &[r#"const x = $"(whoami)""#];
+
+    let actual = nu!(cwd: "tests/const_", pipeline(&inp.join("; ")));
+
+    assert!(actual.err.contains("not_a_constant"));
+}
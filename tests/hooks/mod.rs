@@ -564,3 +564,99 @@ fn err_hook_dont_allow_string() {
     assert!(actual_repl.out.is_empty());
     assert!(actual_repl.err.contains("unsupported_config_value"));
 }
+
+#[test]
+fn env_change_glob_pattern() {
+    let inp = &[
+        &env_change_hook_code("'AWS_*'", r#"'let-env SEEN = $"($name)=($after)"'"#),
+        "let-env AWS_PROFILE = 'dev'",
+        "$env.SEEN",
+    ];
+
+    let actual_repl = nu!(cwd: "tests/hooks", nu_repl_code(inp));
+
+    assert_eq!(actual_repl.err, "");
+    assert_eq!(actual_repl.out, "AWS_PROFILE=dev");
+}
+
+#[test]
+fn env_change_glob_pattern_before_and_after() {
+    let inp = &[
+        &env_change_hook_code("'AWS_*'", r#"'let-env SEEN = $"($before)->($after)"'"#),
+        "let-env AWS_PROFILE = 'dev'",
+        "let-env AWS_PROFILE = 'prod'",
+        "$env.SEEN",
+    ];
+
+    let actual_repl = nu!(cwd: "tests/hooks", nu_repl_code(inp));
+
+    assert_eq!(actual_repl.err, "");
+    assert_eq!(actual_repl.out, "dev->prod");
+}
+
+#[test]
+fn env_change_glob_pattern_ignores_other_vars() {
+    let inp = &[
+        &env_change_hook_code("'AWS_*'", "'let-env SEEN = 1'"),
+        "let-env OTHER = 'x'",
+        "'SEEN' in $env",
+    ];
+
+    let actual_repl = nu!(cwd: "tests/hooks", nu_repl_code(inp));
+
+    assert_eq!(actual_repl.err, "");
+    assert_eq!(actual_repl.out, "false");
+}
+
+#[test]
+fn computed_env_evaluated_on_first_prompt() {
+    let inp = &[
+        "let-env NU_COMPUTED_ENV = { GREETING: { deps: [], code: {|| 'hello' } } }",
+        "$env.GREETING",
+    ];
+
+    let actual_repl = nu!(cwd: "tests/hooks", nu_repl_code(inp));
+
+    assert_eq!(actual_repl.err, "");
+    assert_eq!(actual_repl.out, "hello");
+}
+
+#[test]
+fn computed_env_recomputed_when_dep_changes() {
+    let inp = &[
+        "let-env NU_COMPUTED_ENV = { WORKSPACE: { deps: [PWD], code: {|| $env.PWD } } }",
+        "cd ..",
+        "$env.WORKSPACE == $env.PWD",
+    ];
+
+    let actual_repl = nu!(cwd: "tests/hooks", nu_repl_code(inp));
+
+    assert_eq!(actual_repl.err, "");
+    assert_eq!(actual_repl.out, "true");
+}
+
+#[test]
+fn computed_env_not_recomputed_without_dep_change() {
+    let inp = &[
+        "let-env NU_COMPUTED_ENV = { TOKEN: { deps: [], code: {|| random uuid } } }",
+        "let-env FIRST = $env.TOKEN",
+        "$env.TOKEN == $env.FIRST",
+    ];
+
+    let actual_repl = nu!(cwd: "tests/hooks", nu_repl_code(inp));
+
+    assert_eq!(actual_repl.err, "");
+    assert_eq!(actual_repl.out, "true");
+}
+
+#[test]
+fn computed_env_code_must_be_a_block() {
+    let inp = &[
+        "let-env NU_COMPUTED_ENV = { GREETING: { deps: [], code: 'hello' } }",
+        "",
+    ];
+
+    let actual_repl = nu!(cwd: "tests/hooks", nu_repl_code(inp));
+
+    assert!(actual_repl.err.contains("unsupported_config_value"));
+}
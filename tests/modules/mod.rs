@@ -563,3 +563,168 @@ fn main_inside_module_is_main() {
 
     assert_eq!(actual.out, "foo");
 }
+
+#[test]
+fn use_lazy_loads_module_on_first_use() {
+    Playground::setup("use_lazy_loads_module_on_first_use", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "spam.nu",
+            r#"
+                export def foo [] { "foo" }
+            "#,
+        )]);
+
+        let inp = &[r#"use --lazy spam.nu"#, r#"spam foo"#];
+
+        let actual = nu!(cwd: dirs.test(), pipeline(&inp.join("; ")));
+
+        assert_eq!(actual.out, "foo");
+    })
+}
+
+#[test]
+fn use_lazy_loads_module_main() {
+    Playground::setup("use_lazy_loads_module_main", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "spam.nu",
+            r#"
+                export def main [] { "main" }
+            "#,
+        )]);
+
+        let inp = &[r#"use --lazy spam.nu"#, r#"spam"#];
+
+        let actual = nu!(cwd: dirs.test(), pipeline(&inp.join("; ")));
+
+        assert_eq!(actual.out, "main");
+    })
+}
+
+#[test]
+fn use_lazy_imports_constants() {
+    Playground::setup("use_lazy_imports_constants", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "spam.nu",
+            r#"
+                export const X = 10
+                export def foo [] { "foo" }
+            "#,
+        )]);
+
+        let inp = &[r#"use --lazy spam.nu"#, r#"spam foo | ignore"#, r#"$X"#];
+
+        let actual = nu!(cwd: dirs.test(), pipeline(&inp.join("; ")));
+
+        assert_eq!(actual.out, "10");
+    })
+}
+
+#[test]
+fn use_lazy_defers_parsing_the_module() {
+    Playground::setup("use_lazy_defers_parsing_the_module", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "broken.nu",
+            r#"
+                export def foo [] { $unclosed
+            "#,
+        )]);
+
+        // The broken module is never referenced, so it is never parsed
+        let inp = &[r#"use --lazy broken.nu"#, r#"'ok'"#];
+
+        let actual = nu!(cwd: dirs.test(), pipeline(&inp.join("; ")));
+
+        assert_eq!(actual.out, "ok");
+    })
+}
+
+#[test]
+fn use_lazy_reports_errors_on_first_use() {
+    Playground::setup("use_lazy_reports_errors_on_first_use", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "broken.nu",
+            r#"
+                export def foo [] { $unclosed
+            "#,
+        )]);
+
+        let inp = &[r#"use --lazy broken.nu"#, r#"broken foo"#];
+
+        let actual = nu!(cwd: dirs.test(), pipeline(&inp.join("; ")));
+
+        assert!(actual.err.contains("unexpected_eof"));
+    })
+}
+
+#[test]
+fn use_lazy_rejects_import_members() {
+    Playground::setup("use_lazy_rejects_import_members", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "spam.nu",
+            r#"
+                export def foo [] { "foo" }
+            "#,
+        )]);
+
+        let inp = &[r#"use --lazy spam.nu foo"#];
+
+        let actual = nu!(cwd: dirs.test(), pipeline(&inp.join("; ")));
+
+        assert!(actual.err.contains("single module file"));
+    })
+}
+
+#[test]
+fn use_lazy_missing_module_errors_eagerly() {
+    let inp = &[r#"use --lazy i_dont_exist.nu"#];
+
+    let actual = nu!(cwd: ".", pipeline(&inp.join("; ")));
+
+    assert!(actual.err.contains("module_not_found"));
+}
+
+#[test]
+fn use_module_path_from_const_interpolation() {
+    Playground::setup("use_module_path_from_const", |dirs, sandbox| {
+        sandbox.mkdir("mods");
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "mods/spam.nu",
+            r#"
+                export def foo [] { "foo" }
+            "#,
+        )]);
+
+        let inp = &[
+            r#"const dir = "mods""#,
+            r#"use $"($dir)/spam.nu""#,
+            r#"spam foo"#,
+        ];
+
+        let actual = nu!(cwd: dirs.test(), pipeline(&inp.join("; ")));
+
+        assert_eq!(actual.out, "foo");
+    })
+}
+
+#[test]
+fn source_path_from_const_interpolation() {
+    Playground::setup("source_path_from_const", |dirs, sandbox| {
+        sandbox.mkdir("mods");
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "mods/spam.nu",
+            r#"
+                def foo [] { "foo" }
+            "#,
+        )]);
+
+        let inp = &[
+            r#"const dir = "mods""#,
+            r#"source $"($dir)/spam.nu""#,
+            r#"foo"#,
+        ];
+
+        let actual = nu!(cwd: dirs.test(), pipeline(&inp.join("; ")));
+
+        assert_eq!(actual.out, "foo");
+    })
+}
@@ -1312,3 +1312,135 @@ fn alias_overlay_new() {
     assert_eq!(actual.out, "eggs");
     assert_eq!(actual_repl.out, "eggs");
 }
+
+#[test]
+fn overlay_use_runs_on_activate_hook() {
+    let inp = &[
+        r#"module spam { export def on-activate [] { let-env FOO = "foo" } }"#,
+        r#"overlay use spam"#,
+        r#"$env.FOO"#,
+    ];
+
+    let actual = nu!(cwd: "tests/overlays", pipeline(&inp.join("; ")));
+    let actual_repl = nu!(cwd: "tests/overlays", nu_repl_code(inp));
+
+    assert_eq!(actual.out, "foo");
+    assert_eq!(actual_repl.out, "foo");
+}
+
+#[test]
+fn overlay_hide_runs_on_deactivate_hook() {
+    let inp = &[
+        r#"module spam { export def on-deactivate [] { let-env BYE = "bye" } }"#,
+        r#"overlay use spam"#,
+        r#"overlay hide spam"#,
+        r#"$env.BYE"#,
+    ];
+
+    let actual = nu!(cwd: "tests/overlays", pipeline(&inp.join("; ")));
+    let actual_repl = nu!(cwd: "tests/overlays", nu_repl_code(inp));
+
+    assert_eq!(actual.out, "bye");
+    assert_eq!(actual_repl.out, "bye");
+}
+
+#[test]
+fn overlay_save_and_restore() {
+    Playground::setup("overlay_save_and_restore", |dirs, _sandbox| {
+        let state_file = dirs.test().join("overlay-state.txt");
+        let let_env = format!(
+            r#"let-env NU_OVERLAY_STATE = '{}'"#,
+            state_file.to_string_lossy()
+        );
+
+        let inp = &[
+            let_env.as_str(),
+            r#"module spam { export def foo [] { "foo" } }"#,
+            r#"overlay use spam"#,
+            r#"overlay save"#,
+            r#"overlay hide spam"#,
+            r#"overlay restore"#,
+            r#"foo"#,
+        ];
+
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+        assert_eq!(actual.out, "foo");
+    })
+}
+
+#[test]
+fn overlay_restore_creates_overlay_from_module() {
+    Playground::setup(
+        "overlay_restore_creates_overlay_from_module",
+        |dirs, sandbox| {
+            sandbox.with_files(vec![FileWithContentToBeTrimmed(
+                "overlay-state.txt",
+                r#"
+                spam
+            "#,
+            )]);
+
+            let state_file = dirs.test().join("overlay-state.txt");
+            let let_env = format!(
+                r#"let-env NU_OVERLAY_STATE = '{}'"#,
+                state_file.to_string_lossy()
+            );
+
+            let inp = &[
+                let_env.as_str(),
+                r#"module spam { export def foo [] { "foo" }; export def on-activate [] { let-env FOO = "hooked" } }"#,
+                r#"overlay restore"#,
+                r#"$env.FOO"#,
+            ];
+
+            let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+            assert_eq!(actual.out, "hooked");
+        },
+    )
+}
+
+#[test]
+fn overlay_restore_unknown_name_errors() {
+    Playground::setup("overlay_restore_unknown_name_errors", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "overlay-state.txt",
+            r#"
+                no_such_overlay
+            "#,
+        )]);
+
+        let state_file = dirs.test().join("overlay-state.txt");
+        let let_env = format!(
+            r#"let-env NU_OVERLAY_STATE = '{}'"#,
+            state_file.to_string_lossy()
+        );
+
+        let inp = &[let_env.as_str(), r#"overlay restore"#];
+
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+        assert!(actual.err.contains("Cannot restore overlay"));
+    })
+}
+
+#[test]
+fn overlay_restore_without_saved_state_errors() {
+    Playground::setup(
+        "overlay_restore_without_saved_state_errors",
+        |dirs, _sandbox| {
+            let state_file = dirs.test().join("does-not-exist.txt");
+            let let_env = format!(
+                r#"let-env NU_OVERLAY_STATE = '{}'"#,
+                state_file.to_string_lossy()
+            );
+
+            let inp = &[let_env.as_str(), r#"overlay restore"#];
+
+            let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+            assert!(actual.err.contains("No saved overlay state"));
+        },
+    )
+}
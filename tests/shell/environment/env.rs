@@ -164,3 +164,124 @@ fn hides_env_in_block() {
     assert!(actual.err.contains("column_not_found"));
     assert!(actual_repl.err.contains("column_not_found"));
 }
+
+#[test]
+fn nu_env_trust_records_the_env_file() {
+    Playground::setup("nu_env_trust_test", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(".nu-env.nu", "let-env FOO = 'bar'\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"let-env NU_ENV_TRUST_FILE = 'trust.txt'; nu-env trust; open trust.txt | str contains '.nu-env.nu'"#
+        );
+
+        assert_eq!(actual.out, "true");
+    })
+}
+
+#[test]
+fn nu_env_untrust_removes_the_env_file() {
+    Playground::setup("nu_env_untrust_test", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(".nu-env.nu", "let-env FOO = 'bar'\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"let-env NU_ENV_TRUST_FILE = 'trust.txt'; nu-env trust; nu-env untrust; open trust.txt | str trim | str length"#
+        );
+
+        assert_eq!(actual.out, "0");
+    })
+}
+
+#[test]
+fn nu_env_untrust_errors_without_a_trusted_file() {
+    Playground::setup("nu_env_untrust_err_test", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(".nu-env.nu", "let-env FOO = 'bar'\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"let-env NU_ENV_TRUST_FILE = 'trust.txt'; nu-env untrust"#
+        );
+
+        assert!(actual.err.contains("is not trusted"));
+    })
+}
+
+#[test]
+fn nu_env_trust_errors_without_an_env_file() {
+    Playground::setup("nu_env_trust_err_test", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"let-env NU_ENV_TRUST_FILE = 'trust.txt'; nu-env trust"#
+        );
+
+        assert!(actual.err.contains("Could not read"));
+    })
+}
+
+#[test]
+fn config_reload_sources_the_config_file() {
+    Playground::setup("config_reload_test", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "config.nu",
+            "let-env FROM_CONFIG = 'v1'\n",
+        )]);
+
+        let inp = &["config reload", "$env.FROM_CONFIG"];
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            format!(
+                "with-env {{ NU_CONFIG_PATH: config.nu }} {{ {} }}",
+                nu_repl_code(inp)
+            )
+        );
+
+        assert_eq!(actual.out, "v1");
+    })
+}
+
+#[test]
+fn config_reload_sources_the_env_file_first() {
+    Playground::setup("config_reload_env_test", |dirs, sandbox| {
+        sandbox.with_files(vec![
+            FileWithContent("env.nu", "let-env FROM = 'env'\n"),
+            FileWithContent("config.nu", "let-env FROM = $env.FROM + '+config'\n"),
+        ]);
+
+        let inp = &["config reload", "$env.FROM"];
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            format!(
+                "with-env {{ NU_ENV_PATH: env.nu, NU_CONFIG_PATH: config.nu }} {{ {} }}",
+                nu_repl_code(inp)
+            )
+        );
+
+        assert_eq!(actual.out, "env+config");
+    })
+}
+
+#[test]
+fn config_reload_rolls_back_on_error() {
+    Playground::setup("config_reload_rollback_test", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "config.nu",
+            "let-env SHOULD_NOT_STAY = 1\nerror make {msg: 'broken config'}\n",
+        )]);
+
+        let inp = &["config reload", "'SHOULD_NOT_STAY' in $env"];
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            format!(
+                "with-env {{ NU_CONFIG_PATH: config.nu }} {{ {} }}",
+                nu_repl_code(inp)
+            )
+        );
+
+        assert!(actual.err.contains("broken config"));
+        assert_eq!(actual.out, "false");
+    })
+}
@@ -288,3 +288,539 @@ fn run_in_noninteractive_mode() {
 
     assert!(child_output.stderr.is_empty());
 }
+
+#[test]
+fn main_script_subcommands_dispatch_without_main() {
+    Playground::setup("main_script_subcommands", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "script.nu",
+            r#"
+                def "main build" [target: string] { $"building ($target)" }
+            "#,
+        )]);
+
+        let actual = nu!(cwd: dirs.test(), "nu script.nu build foo");
+
+        assert_eq!(actual.out, "building foo");
+    })
+}
+
+#[test]
+fn main_script_subcommands_typed_flags() {
+    Playground::setup("main_script_typed_flags", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "script.nu",
+            r#"
+                def "main count" [--n: int] { 1..$n | math sum }
+            "#,
+        )]);
+
+        let actual = nu!(cwd: dirs.test(), "nu script.nu count --n 4");
+
+        assert_eq!(actual.out, "10");
+    })
+}
+
+#[test]
+fn main_script_subcommands_aggregated_help() {
+    Playground::setup("main_script_help", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "script.nu",
+            r#"
+                # Build the project.
+                def "main build" [] { "building" }
+
+                # Test the project.
+                def "main test" [] { "testing" }
+            "#,
+        )]);
+
+        let actual = nu!(cwd: dirs.test(), "nu script.nu --help");
+
+        assert!(actual.out.contains("main build"));
+        assert!(actual.out.contains("Build the project."));
+        assert!(actual.out.contains("main test"));
+    })
+}
+
+#[test]
+fn generate_completions_bash() {
+    Playground::setup("generate_completions_bash", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "cli.nu",
+            r#"
+                def main [--verbose (-v)] { }
+
+                def "main build" [target: string, --release] { }
+            "#,
+        )]);
+
+        let actual = nu!(cwd: dirs.test(), "nu --generate-completions bash cli.nu");
+
+        assert!(actual.out.contains("complete -F _cli_nu cli.nu"));
+        assert!(actual.out.contains("--release"));
+        assert!(actual.out.contains("--verbose -v"));
+    })
+}
+
+#[test]
+fn generate_completions_nu_externs() {
+    Playground::setup("generate_completions_nu", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "cli.nu",
+            r#"
+                # Build the project.
+                def "main build" [target: string, --release] { }
+            "#,
+        )]);
+
+        let actual = nu!(cwd: dirs.test(), "nu --generate-completions nu cli.nu");
+
+        assert!(actual.out.contains(r#"export extern "cli.nu build" ["#));
+        assert!(actual.out.contains("target: string"));
+        assert!(actual.out.contains("# Build the project."));
+    })
+}
+
+#[test]
+fn generate_completions_unsupported_shell() {
+    Playground::setup("generate_completions_unsupported", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "cli.nu",
+            r#"
+                def main [] { }
+            "#,
+        )]);
+
+        let actual = nu!(cwd: dirs.test(), "nu --generate-completions elvish cli.nu");
+
+        assert!(actual.err.contains("Unsupported shell: elvish"));
+    })
+}
+
+#[test]
+fn generate_completions_without_main_errors() {
+    Playground::setup("generate_completions_no_main", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "cli.nu",
+            r#"
+                def helper [] { }
+            "#,
+        )]);
+
+        let actual = nu!(cwd: dirs.test(), "nu --generate-completions bash cli.nu");
+
+        assert!(actual.err.contains("No main command"));
+    })
+}
+
+#[test]
+fn record_flag_sets_the_record_file() {
+    let actual = nu!(cwd: ".", "nu --record session.ndjson -c '$env.NU_RECORD_FILE'");
+
+    assert_eq!(actual.out, "session.ndjson");
+}
+
+#[test]
+fn record_file_captures_commands() {
+    Playground::setup("record_commands", |dirs, _| {
+        let inp = &[
+            "let-env NU_RECORD_FILE = 'session.ndjson'",
+            "echo hello",
+            "open session.ndjson | get 1 | get command",
+        ];
+
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+        assert_eq!(actual.out, "echo hello");
+    })
+}
+
+#[test]
+fn record_file_captures_output() {
+    Playground::setup("record_output", |dirs, _| {
+        let inp = &[
+            "let-env NU_RECORD_FILE = 'session.ndjson'",
+            "echo hello",
+            "open session.ndjson | get 1 | get output",
+        ];
+
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+        assert_eq!(actual.out, "hello");
+    })
+}
+
+#[test]
+fn record_file_captures_timestamp_and_cwd() {
+    Playground::setup("record_timestamp_cwd", |dirs, _| {
+        let inp = &[
+            "let-env NU_RECORD_FILE = 'session.ndjson'",
+            "echo hello",
+            "let entry = (open session.ndjson | get 1)",
+            "($entry.timestamp | into datetime | describe) + ',' + ($entry.cwd | path basename)",
+        ];
+
+        let actual = nu!(cwd: dirs.test(), nu_repl_code(inp));
+
+        assert_eq!(actual.out, "date,record_timestamp_cwd");
+    })
+}
+
+#[test]
+fn restricted_mode_allows_pure_commands() {
+    let actual = nu!(cwd: ".", "nu --no-std-lib --restricted -c '[1 2 3] | math sum'");
+
+    assert_eq!(actual.out, "6");
+}
+
+#[test]
+fn restricted_mode_allows_custom_defs() {
+    let actual = nu!(cwd: ".", "nu --no-std-lib --restricted -c 'def f [] { 40 + 2 }; f'");
+
+    assert_eq!(actual.out, "42");
+}
+
+#[test]
+fn restricted_mode_blocks_external_commands() {
+    let actual = nu!(cwd: ".", "nu --no-std-lib --restricted -c '^echo hi'");
+
+    assert!(actual.err.contains("restricted_command"));
+}
+
+#[test]
+fn restricted_mode_blocks_filesystem_writes() {
+    Playground::setup("restricted_no_writes", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"nu --no-std-lib --restricted -c "'x' | save f.txt""#
+        );
+
+        assert!(actual.err.contains("restricted_command"));
+        assert!(!dirs.test().join("f.txt").exists());
+    })
+}
+
+#[test]
+fn restricted_mode_blocks_into_sqlite() {
+    Playground::setup("restricted_no_sqlite", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            "nu --no-std-lib --restricted -c '[[a]; [1]] | into sqlite hole.db'"
+        );
+
+        assert!(actual.err.contains("restricted_command"));
+        assert!(!dirs.test().join("hole.db").exists());
+    })
+}
+
+#[test]
+fn restricted_mode_blocks_start() {
+    let actual = nu!(cwd: ".", "nu --no-std-lib --restricted -c 'start .'");
+
+    assert!(actual.err.contains("restricted_command"));
+}
+
+#[test]
+fn restricted_mode_blocks_pkg_commands() {
+    let actual = nu!(
+        cwd: ".",
+        "nu --no-std-lib --restricted -c 'pkg install https://example.com/spam.git'"
+    );
+
+    assert!(actual.err.contains("restricted_command"));
+
+    let actual = nu!(cwd: ".", "nu --no-std-lib --restricted -c 'pkg remove spam'");
+
+    assert!(actual.err.contains("restricted_command"));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn restricted_mode_blocks_xattr_set() {
+    Playground::setup("restricted_no_xattr", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed("f.txt", "x")]);
+
+        let actual = nu!(
+            cwd: dirs.test(),
+            "nu --no-std-lib --restricted -c 'xattr set f.txt user.test spam'"
+        );
+
+        assert!(actual.err.contains("restricted_command"));
+    })
+}
+
+#[cfg(all(
+    feature = "trash-support",
+    any(
+        target_os = "windows",
+        all(
+            unix,
+            not(target_os = "macos"),
+            not(target_os = "ios"),
+            not(target_os = "android")
+        )
+    )
+))]
+#[test]
+fn restricted_mode_blocks_trash_restore() {
+    let actual = nu!(cwd: ".", "nu --no-std-lib --restricted -c 'trash restore spam'");
+
+    assert!(actual.err.contains("restricted_command"));
+}
+
+#[test]
+fn restricted_mode_allowlist_is_configurable() {
+    Playground::setup("restricted_allowlist", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"with-env { NU_RESTRICTED_ALLOW: save } { nu --no-std-lib --restricted -c "'x' | save f.txt; open f.txt" }"#
+        );
+
+        assert_eq!(actual.out, "x");
+    })
+}
+
+#[test]
+fn dap_runs_a_script_to_completion() {
+    Playground::setup("dap_completion", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "script.nu",
+            r#"
+                40 + 2
+            "#,
+        )]);
+
+        let mut session = DapSession::start(dirs.test());
+        session.request("initialize", serde_json::json!({}));
+        session.request(
+            "launch",
+            serde_json::json!({ "program": dirs.test().join("script.nu") }),
+        );
+        session.request("configurationDone", serde_json::json!({}));
+
+        let output = session.read_until_event("output");
+        assert!(output["body"]["output"]
+            .as_str()
+            .expect("output event")
+            .contains("42"));
+
+        let exited = session.read_until_event("exited");
+        assert_eq!(exited["body"]["exitCode"], 0);
+
+        session.read_until_event("terminated");
+        session.request("disconnect", serde_json::json!({}));
+        session.finish();
+    })
+}
+
+#[test]
+fn dap_stops_at_breakpoints_and_reports_variables() {
+    Playground::setup("dap_breakpoints", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "script.nu",
+            r#"
+                let x = 10
+                $x + 5
+            "#,
+        )]);
+
+        let program = dirs.test().join("script.nu");
+        let mut session = DapSession::start(dirs.test());
+        session.request("initialize", serde_json::json!({}));
+        session.request("launch", serde_json::json!({ "program": program }));
+        session.request(
+            "setBreakpoints",
+            serde_json::json!({
+                "source": { "path": program },
+                "breakpoints": [{ "line": 2 }],
+            }),
+        );
+        session.request("configurationDone", serde_json::json!({}));
+
+        let stopped = session.read_until_event("stopped");
+        assert_eq!(stopped["body"]["reason"], "breakpoint");
+
+        session.request("stackTrace", serde_json::json!({ "threadId": 1 }));
+        let stack = session.read_until_response("stackTrace");
+        assert_eq!(stack["body"]["stackFrames"][0]["line"], 2);
+
+        session.request("variables", serde_json::json!({ "variablesReference": 1 }));
+        let variables = session.read_until_response("variables");
+        let rendered = variables["body"]["variables"].to_string();
+        assert!(rendered.contains("$x"));
+        assert!(rendered.contains("10"));
+
+        session.request("continue", serde_json::json!({ "threadId": 1 }));
+        let output = session.read_until_event("output");
+        assert!(output["body"]["output"]
+            .as_str()
+            .expect("output event")
+            .contains("15"));
+
+        session.read_until_event("terminated");
+        session.request("disconnect", serde_json::json!({}));
+        session.finish();
+    })
+}
+
+/// A scripted client for the `nu --dap` tests speaking Content-Length framed JSON.
+struct DapSession {
+    process: std::process::Child,
+    reader: std::io::BufReader<std::process::ChildStdout>,
+    seq: i64,
+}
+
+impl DapSession {
+    fn start(cwd: &std::path::Path) -> Self {
+        let mut process = std::process::Command::new(nu_test_support::fs::executable_path())
+            .arg("--dap")
+            .current_dir(cwd)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .expect("can't run nu --dap");
+
+        let reader = std::io::BufReader::new(process.stdout.take().expect("missing stdout"));
+
+        DapSession {
+            process,
+            reader,
+            seq: 0,
+        }
+    }
+
+    fn request(&mut self, command: &str, arguments: serde_json::Value) {
+        use std::io::Write;
+
+        self.seq += 1;
+        let message = serde_json::json!({
+            "type": "request",
+            "seq": self.seq,
+            "command": command,
+            "arguments": arguments,
+        })
+        .to_string();
+
+        let stdin = self.process.stdin.as_mut().expect("missing stdin");
+        write!(
+            stdin,
+            "Content-Length: {}\r\n\r\n{}",
+            message.len(),
+            message
+        )
+        .expect("can't write to nu --dap");
+        stdin.flush().expect("can't flush to nu --dap");
+    }
+
+    fn read_message(&mut self) -> serde_json::Value {
+        use std::io::{BufRead, Read};
+
+        let mut content_length = None;
+        loop {
+            let mut line = String::new();
+            if self
+                .reader
+                .read_line(&mut line)
+                .expect("can't read from nu --dap")
+                == 0
+            {
+                panic!("nu --dap closed its output");
+            }
+            let line = line.trim_end();
+            if line.is_empty() && content_length.is_some() {
+                break;
+            }
+            if let Some(value) = line.strip_prefix("Content-Length:") {
+                content_length = value.trim().parse::<usize>().ok();
+            }
+        }
+
+        let mut content = vec![0; content_length.expect("missing Content-Length")];
+        self.reader
+            .read_exact(&mut content)
+            .expect("can't read from nu --dap");
+        serde_json::from_slice(&content).expect("invalid JSON from nu --dap")
+    }
+
+    fn read_until_event(&mut self, event: &str) -> serde_json::Value {
+        loop {
+            let message = self.read_message();
+            if message["type"] == "event" && message["event"] == event {
+                return message;
+            }
+        }
+    }
+
+    fn read_until_response(&mut self, command: &str) -> serde_json::Value {
+        loop {
+            let message = self.read_message();
+            if message["type"] == "response" && message["command"] == command {
+                return message;
+            }
+        }
+    }
+
+    fn finish(mut self) {
+        let _ = self.process.wait();
+    }
+}
+
+#[test]
+fn dry_run_flag_applies_to_filesystem_commands() {
+    Playground::setup("dry_run_engine_flag", |dirs, _| {
+        let actual = nu!(
+            cwd: dirs.test(),
+            r#"nu --no-std-lib --dry-run -c "'x' | save f.txt | get 0.operation""#
+        );
+
+        assert_eq!(actual.out, "save");
+        assert!(!dirs.test().join("f.txt").exists());
+    })
+}
+
+#[cfg(all(feature = "trash-support", target_os = "linux"))]
+#[test]
+fn trash_list_and_restore_round_trip() {
+    use nu_test_support::fs::Stub::FileWithContent;
+
+    Playground::setup("trash_round_trip", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent("victim.txt", "restore me\n")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                rm --trash victim.txt;
+                trash list
+                | where original_path == ($env.PWD | path join victim.txt)
+                | get 0.name
+            "#
+        ));
+
+        assert_eq!(actual.out, "victim.txt");
+        assert!(!dirs.test().join("victim.txt").exists());
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                trash restore (
+                    trash list
+                    | where original_path == ($env.PWD | path join victim.txt)
+                    | get 0.id
+                );
+                open victim.txt | str trim
+            "#
+        ));
+
+        assert_eq!(actual.out, "restore me");
+    })
+}
+
+#[cfg(all(feature = "trash-support", target_os = "linux"))]
+#[test]
+fn trash_restore_unknown_id_errors() {
+    let actual = nu!(cwd: ".", "trash restore not-a-real-trash-id");
+
+    assert!(actual.err.contains("No trash item with id"));
+}
@@ -553,3 +553,47 @@ mod external_command_arguments {
         assert_eq!(actual.out, "a;&$(hello)");
     }
 }
+
+mod pipeline_status {
+    use super::nu;
+
+    #[test]
+    fn records_every_stage() {
+        let actual = nu!(
+            cwd: ".",
+            "nu --testbin fail | nu --testbin cococo ok; $env.LAST_PIPELINE_STATUS | to json --raw"
+        );
+
+        assert!(actual.out.ends_with("[1,0]"));
+    }
+
+    #[test]
+    fn exit_code_still_reaches_complete() {
+        let actual = nu!(
+            cwd: ".",
+            "nu --testbin fail | complete | get exit_code"
+        );
+
+        assert_eq!(actual.out, "1");
+    }
+
+    #[test]
+    fn without_pipefail_the_block_keeps_running() {
+        let actual = nu!(
+            cwd: ".",
+            "nu --testbin fail | nu --testbin cococo ok; print done"
+        );
+
+        assert!(actual.out.ends_with("done"));
+    }
+
+    #[test]
+    fn pipefail_fails_the_block_on_an_intermediate_failure() {
+        let actual = nu!(
+            cwd: ".",
+            "let-env config = {pipefail: true}; nu --testbin fail | nu --testbin cococo ok; print done"
+        );
+
+        assert!(!actual.out.contains("done"));
+    }
+}